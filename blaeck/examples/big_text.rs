@@ -0,0 +1,16 @@
+//! BigText example - Large block-letter banners
+//!
+//! Run with: cargo run --example big_text
+
+#[path = "previews/mod.rs"]
+mod previews;
+
+use blaeck::Blaeck;
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut blaeck = Blaeck::new(io::stdout())?;
+    blaeck.render(previews::big_text::build_ui())?;
+    blaeck.unmount()?;
+    Ok(())
+}
@@ -0,0 +1,16 @@
+//! Canvas example - Free-form drawing with a closure
+//!
+//! Run with: cargo run --example canvas
+
+#[path = "previews/mod.rs"]
+mod previews;
+
+use blaeck::Blaeck;
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut blaeck = Blaeck::new(io::stdout())?;
+    blaeck.render(previews::canvas::build_ui())?;
+    blaeck.unmount()?;
+    Ok(())
+}
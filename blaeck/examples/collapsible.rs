@@ -0,0 +1,35 @@
+//! Collapsible example - Titled sections that fold away, driven as an accordion
+//!
+//! Run with: cargo run --example collapsible
+
+#[path = "previews/mod.rs"]
+mod previews;
+
+use blaeck::input::poll_key;
+use blaeck::Blaeck;
+use crossterm::event::KeyCode;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use std::io;
+use std::time::Duration;
+
+fn main() -> io::Result<()> {
+    let mut blaeck = Blaeck::new(io::stdout())?;
+    let mut state = previews::collapsible::initial_state();
+
+    enable_raw_mode()?;
+
+    loop {
+        blaeck.render(previews::collapsible::build_ui_with_state(&state))?;
+
+        if let Some(key) = poll_key(Duration::from_millis(50))? {
+            if key.is_ctrl_c() || key.code == KeyCode::Esc {
+                break;
+            }
+            state.handle_key(&key);
+        }
+    }
+
+    disable_raw_mode()?;
+    blaeck.unmount()?;
+    Ok(())
+}
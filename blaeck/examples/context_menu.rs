@@ -0,0 +1,36 @@
+//! ContextMenu example - Popup menu with separators and submenus
+//!
+//! Run with: cargo run --example context_menu
+
+#[path = "previews/mod.rs"]
+mod previews;
+
+use blaeck::input::poll_key;
+use blaeck::Blaeck;
+use crossterm::event::KeyCode;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use std::io;
+use std::time::Duration;
+
+fn main() -> io::Result<()> {
+    let mut blaeck = Blaeck::new(io::stdout())?;
+    let mut state = previews::context_menu::initial_state();
+
+    enable_raw_mode()?;
+
+    loop {
+        blaeck.render(previews::context_menu::build_ui_with_state(&state))?;
+
+        if let Some(key) = poll_key(Duration::from_millis(50))? {
+            // Esc closes an open menu before it quits
+            if key.is_ctrl_c() || (key.code == KeyCode::Esc && !state.menu.is_open()) {
+                break;
+            }
+            state.handle_key(&key);
+        }
+    }
+
+    disable_raw_mode()?;
+    blaeck.unmount()?;
+    Ok(())
+}
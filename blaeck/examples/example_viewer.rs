@@ -468,17 +468,13 @@ impl ViewerState {
             }
 
             // Navigate list
-            KeyCode::Up | KeyCode::Char('k') => {
-                if self.selected > 0 {
-                    self.selected -= 1;
-                    self.load_code();
-                }
+            KeyCode::Up | KeyCode::Char('k') if self.selected > 0 => {
+                self.selected -= 1;
+                self.load_code();
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if self.selected + 1 < self.examples.len() {
-                    self.selected += 1;
-                    self.load_code();
-                }
+            KeyCode::Down | KeyCode::Char('j') if self.selected + 1 < self.examples.len() => {
+                self.selected += 1;
+                self.load_code();
             }
 
             // Scroll code
@@ -514,11 +510,9 @@ impl ViewerState {
             }
 
             // Stop running example
-            KeyCode::Char('s') => {
-                if self.capture.is_running() {
-                    self.capture.kill();
-                    self.status_msg = "Stopped".into();
-                }
+            KeyCode::Char('s') if self.capture.is_running() => {
+                self.capture.kill();
+                self.status_msg = "Stopped".into();
             }
 
             // Build
@@ -566,27 +560,25 @@ impl ViewerState {
                     self.status_msg = format!("Delete {} files? Press 'y' to confirm", count);
                 }
             }
-            KeyCode::Char('y') => {
-                if self.status_msg.contains("Press 'y' to confirm") {
-                    let mut deleted = 0;
-                    let to_delete: Vec<PathBuf> = self
-                        .examples
-                        .iter()
-                        .filter(|e| e.marked)
-                        .map(|e| e.path.clone())
-                        .collect();
-                    for path in &to_delete {
-                        if std::fs::remove_file(path).is_ok() {
-                            deleted += 1;
-                        }
+            KeyCode::Char('y') if self.status_msg.contains("Press 'y' to confirm") => {
+                let mut deleted = 0;
+                let to_delete: Vec<PathBuf> = self
+                    .examples
+                    .iter()
+                    .filter(|e| e.marked)
+                    .map(|e| e.path.clone())
+                    .collect();
+                for path in &to_delete {
+                    if std::fs::remove_file(path).is_ok() {
+                        deleted += 1;
                     }
-                    self.examples.retain(|e| !e.marked);
-                    if self.selected >= self.examples.len() && !self.examples.is_empty() {
-                        self.selected = self.examples.len() - 1;
-                    }
-                    self.load_code();
-                    self.status_msg = format!("Deleted {} files", deleted);
                 }
+                self.examples.retain(|e| !e.marked);
+                if self.selected >= self.examples.len() && !self.examples.is_empty() {
+                    self.selected = self.examples.len() - 1;
+                }
+                self.load_code();
+                self.status_msg = format!("Deleted {} files", deleted);
             }
 
             _ => {}
@@ -713,6 +705,25 @@ fn try_render_preview(name: &str, timer: &AnimationTimer) -> Option<Element> {
         "syntax" => Some(previews::syntax::build_ui()),
         "table" => Some(previews::table::build_ui()),
         "tree" => Some(previews::tree::build_ui()),
+        "hyperlink" => Some(previews::hyperlink::build_ui()),
+        "scroll_view" => Some(previews::scroll_view::build_ui()),
+        "text_area" => Some(previews::text_area::build_ui()),
+        "radio_group" => Some(previews::radio_group::build_ui()),
+        "collapsible" => Some(previews::collapsible::build_ui()),
+        "time_picker" => Some(previews::time_picker::build_ui()),
+        "toast" => Some(previews::toast::build_ui()),
+        "context_menu" => Some(previews::context_menu::build_ui()),
+        "toc" => Some(previews::toc::build_ui()),
+        "scrollbar" => Some(previews::scrollbar::build_ui()),
+        "big_text" => Some(previews::big_text::build_ui()),
+        "truncate" => Some(previews::truncate::build_ui()),
+        "line_chart" => Some(previews::line_chart::build_ui()),
+        "gauge" => Some(previews::gauge::build_ui()),
+        "canvas" => Some(previews::canvas::build_ui()),
+        "reveal" => Some(previews::reveal::build_ui()),
+        "placeholder" => Some(previews::placeholder::build_ui()),
+        "image" => Some(previews::image::build_ui()),
+        "calendar" => Some(previews::calendar::build_ui()),
         "demo_inline" => Some(previews::demo_inline::build_ui_with_timer(timer)),
         "logbox" => Some(previews::logbox::build_ui_with_timer(timer)),
         "logbox_command" => Some(previews::logbox_command::build_ui_with_timer(timer)),
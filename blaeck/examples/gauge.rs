@@ -0,0 +1,16 @@
+//! Gauge example - Current utilization as a bar or an arc
+//!
+//! Run with: cargo run --example gauge
+
+#[path = "previews/mod.rs"]
+mod previews;
+
+use blaeck::Blaeck;
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut blaeck = Blaeck::new(io::stdout())?;
+    blaeck.render(previews::gauge::build_ui())?;
+    blaeck.unmount()?;
+    Ok(())
+}
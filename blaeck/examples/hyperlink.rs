@@ -0,0 +1,16 @@
+//! Hyperlink example - Clickable OSC 8 regions around any content
+//!
+//! Run with: cargo run --example hyperlink
+
+#[path = "previews/mod.rs"]
+mod previews;
+
+use blaeck::Blaeck;
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut blaeck = Blaeck::new(io::stdout())?;
+    blaeck.render(previews::hyperlink::build_ui())?;
+    blaeck.unmount()?;
+    Ok(())
+}
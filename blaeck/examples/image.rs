@@ -0,0 +1,16 @@
+//! Image example - Pictures inline, in whatever the terminal can show
+//!
+//! Run with: cargo run --example image
+
+#[path = "previews/mod.rs"]
+mod previews;
+
+use blaeck::Blaeck;
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut blaeck = Blaeck::new(io::stdout())?;
+    blaeck.render(previews::image::build_ui())?;
+    blaeck.unmount()?;
+    Ok(())
+}
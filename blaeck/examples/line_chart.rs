@@ -0,0 +1,16 @@
+//! LineChart example - Multi-series line charts with axes and a legend
+//!
+//! Run with: cargo run --example line_chart
+
+#[path = "previews/mod.rs"]
+mod previews;
+
+use blaeck::Blaeck;
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut blaeck = Blaeck::new(io::stdout())?;
+    blaeck.render(previews::line_chart::build_ui())?;
+    blaeck.unmount()?;
+    Ok(())
+}
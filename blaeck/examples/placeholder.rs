@@ -0,0 +1,16 @@
+//! Placeholder example - Empty, error and loading states
+//!
+//! Run with: cargo run --example placeholder
+
+#[path = "previews/mod.rs"]
+mod previews;
+
+use blaeck::Blaeck;
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut blaeck = Blaeck::new(io::stdout())?;
+    blaeck.render(previews::placeholder::build_ui())?;
+    blaeck.unmount()?;
+    Ok(())
+}
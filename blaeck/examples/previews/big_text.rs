@@ -0,0 +1,21 @@
+use blaeck::prelude::*;
+
+pub fn build_ui() -> Element {
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "BigText Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Text(content: "Block font, gradient:", dim: true)
+            #(Element::node::<BigText>(
+                BigTextProps::new("BLAECK").gradient(GradientPreset::Ocean),
+                vec![],
+            ))
+            Text(content: "")
+            Text(content: "Compact font, single color:", dim: true)
+            #(Element::node::<BigText>(
+                BigTextProps::new("12:45").font(BigFont::Compact).color(Color::Yellow),
+                vec![],
+            ))
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use blaeck::input::Key;
+use blaeck::prelude::*;
+use crossterm::event::KeyCode;
+
+/// A minimal date picker: the Calendar draws, this moves the selection.
+pub struct DatePicker {
+    pub selected: Date,
+}
+
+impl DatePicker {
+    /// Arrows move by a day or a week, PageUp/PageDown by a month.
+    pub fn handle_key(&mut self, key: &Key) {
+        self.selected = match key.code {
+            KeyCode::Left => self.selected.add_days(-1),
+            KeyCode::Right => self.selected.add_days(1),
+            KeyCode::Up => self.selected.add_days(-7),
+            KeyCode::Down => self.selected.add_days(7),
+            KeyCode::PageUp => self.selected.add_months(-1),
+            KeyCode::PageDown => self.selected.add_months(1),
+            _ => return,
+        };
+    }
+}
+
+pub fn initial_state() -> DatePicker {
+    DatePicker {
+        selected: Date::new(2026, 10, 14).expect("valid date"),
+    }
+}
+
+pub fn build_ui_with_state(state: &DatePicker) -> Element {
+    let date = state.selected;
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "Calendar Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            #(Element::node::<Calendar>(
+                CalendarProps::for_date(date)
+                    .today(Date::today())
+                    .selected(date.day)
+                    .highlight(1, Color::Yellow)
+                    .events([3, 15, 22]),
+                vec![],
+            ))
+            Text(content: "")
+            Text(content: format!("Selected: {}-{:02}-{:02} ({})", date.year, date.month, date.day, date.weekday().short_name()), color: Color::Green)
+            Text(content: "←/→ day · ↑/↓ week · PgUp/PgDn month · Esc quits", dim: true)
+        }
+    }
+}
+
+pub fn build_ui() -> Element {
+    build_ui_with_state(&initial_state())
+}
@@ -0,0 +1,40 @@
+use blaeck::prelude::*;
+
+pub fn build_ui() -> Element {
+    let canvas = CanvasProps::new(40, 10).draw(|p| {
+        let (w, h) = p.size();
+        let (w, h) = (w as isize, h as isize);
+        p.rect(0, 0, w, h, Color::DarkGray);
+
+        // A sine wave on the left
+        let mid = h as f64 / 2.0;
+        let mut last = None;
+        for x in 1..w - 28 {
+            let y = (mid + (mid - 4.0) * (x as f64 / 6.0).sin()) as isize;
+            if let Some(prev) = last {
+                p.line(prev, (x, y), Color::Cyan);
+            }
+            last = Some((x, y));
+        }
+
+        // A circle of dots on the right
+        let (cx, cy, r) = (w - 14, h / 2, 9.0);
+        for step in 0..64 {
+            let angle = f64::from(step) * std::f64::consts::TAU / 64.0;
+            let x = cx + (r * angle.cos()) as isize;
+            let y = cy + (r * angle.sin()) as isize;
+            p.point(x, y, Color::Yellow);
+        }
+
+        p.text(2, 0, " sin(x) ", Style::new().fg(Color::White));
+    });
+
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "Canvas Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Text(content: "Lines, points and text on braille dots:", dim: true)
+            #(Element::node::<Canvas>(canvas, vec![]))
+        }
+    }
+}
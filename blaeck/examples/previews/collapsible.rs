@@ -0,0 +1,46 @@
+use blaeck::prelude::*;
+
+pub fn initial_state() -> AccordionState {
+    let mut state = AccordionState::new(3);
+    state.open(0);
+    state
+}
+
+pub fn build_ui_with_state(state: &AccordionState) -> Element {
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "Collapsible Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            #(Element::node::<Collapsible>(
+                CollapsibleProps::new("General")
+                    .expanded(state.is_open(0))
+                    .focused(state.focused == 0),
+                vec![
+                    element! { Text(content: "Name: blaeck") },
+                    element! { Text(content: "Theme: dark") },
+                ],
+            ))
+            #(Element::node::<Collapsible>(
+                CollapsibleProps::new("Network")
+                    .expanded(state.is_open(1))
+                    .focused(state.focused == 1),
+                vec![
+                    element! { Text(content: "Proxy: none") },
+                    element! { Text(content: "Timeout: 30s") },
+                ],
+            ))
+            #(Element::node::<Collapsible>(
+                CollapsibleProps::new("Advanced")
+                    .expanded(state.is_open(2))
+                    .focused(state.focused == 2),
+                vec![element! { Text(content: "Threads: 4") }],
+            ))
+            Text(content: "")
+            Text(content: "↑/↓ move · Enter/Space toggle · Esc quits", dim: true)
+        }
+    }
+}
+
+pub fn build_ui() -> Element {
+    build_ui_with_state(&initial_state())
+}
@@ -0,0 +1,98 @@
+use blaeck::input::Key;
+use blaeck::prelude::*;
+use blaeck::Rect;
+
+const WIDTH: u16 = 56;
+const HEIGHT: u16 = 19;
+
+pub struct ContextMenuDemo {
+    pub menu: ContextMenuState,
+    pub items: Vec<MenuItem>,
+    pub last: String,
+}
+
+impl ContextMenuDemo {
+    /// Handle `m` to open the menu, otherwise pass the key to the menu.
+    pub fn handle_key(&mut self, key: &Key) {
+        if !self.menu.is_open() {
+            if key.is_char('m') {
+                self.menu.open(&self.items);
+            }
+            return;
+        }
+        match self.menu.handle_key(key, &self.items) {
+            ContextMenuAction::Selected(path) => self.last = self.label(&path),
+            ContextMenuAction::Closed => self.last = "(closed)".into(),
+            ContextMenuAction::Moved | ContextMenuAction::Ignored => {}
+        }
+    }
+
+    /// Labels along `path`, joined like "Open with > Editor".
+    fn label(&self, path: &[usize]) -> String {
+        let mut level = &self.items;
+        let mut labels = Vec::new();
+        for &i in path {
+            labels.push(level[i].label.clone());
+            level = &level[i].children;
+        }
+        labels.join(" > ")
+    }
+}
+
+pub fn initial_state() -> ContextMenuDemo {
+    let items = vec![
+        MenuItem::new("Open").shortcut("enter"),
+        MenuItem::submenu(
+            "Open with",
+            vec![MenuItem::new("Editor"), MenuItem::new("Viewer")],
+        ),
+        MenuItem::new("Rename").shortcut("r"),
+        MenuItem::separator(),
+        MenuItem::new("Share").disabled(true),
+        MenuItem::new("Delete").shortcut("del"),
+    ];
+    // Open with the "Open with" submenu showing
+    let menu = ContextMenuState { path: vec![1, 0] };
+    ContextMenuDemo {
+        menu,
+        items,
+        last: "(none)".into(),
+    }
+}
+
+pub fn build_ui_with_state(state: &ContextMenuDemo) -> Element {
+    // Inside the card's border
+    let bounds = Rect::new(1, 1, WIDTH - 2, HEIGHT - 2);
+    // Opens next to the highlighted file
+    let anchor = Rect::new(16, 9, 1, 1);
+    let card = element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round, width: f32::from(WIDTH), height: f32::from(HEIGHT)) {
+            Text(content: "ContextMenu Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Text(content: "m menu · ↑/↓ move · →/← submenu", dim: true)
+            Text(content: "Enter choose · Esc close/quit", dim: true)
+            Text(content: format!("Chosen: {}", state.last), color: Color::Green)
+            Text(content: "")
+            Text(content: "  notes.md", dim: true)
+            Text(content: "▸ report.pdf", bold: true)
+            Text(content: "  todo.txt", dim: true)
+        }
+    };
+    // The menu panels float, so they go straight into the root Box
+    let mut children = vec![card];
+    if let Element::Fragment(panels) = state.menu.render(&state.items, anchor, bounds) {
+        children.extend(panels);
+    }
+    Element::node::<Box>(
+        BoxProps {
+            width: Some(f32::from(WIDTH)),
+            height: Some(f32::from(HEIGHT)),
+            ..Default::default()
+        },
+        children,
+    )
+}
+
+pub fn build_ui() -> Element {
+    build_ui_with_state(&initial_state())
+}
@@ -0,0 +1,29 @@
+use blaeck::prelude::*;
+
+pub fn build_ui() -> Element {
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "Gauge Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Text(content: "Bars, colored by threshold:", dim: true)
+            #(Element::node::<Gauge>(GaugeProps::new(0.42).label("CPU ").width(30), vec![]))
+            #(Element::node::<Gauge>(GaugeProps::new(0.78).label("MEM ").width(30), vec![]))
+            #(Element::node::<Gauge>(GaugeProps::new(0.95).label("DISK").width(30), vec![]))
+            Text(content: "")
+            Text(content: "Thick bar with custom text:", dim: true)
+            #(Element::node::<Gauge>(
+                GaugeProps::new(0.6)
+                    .width(30)
+                    .thickness(2)
+                    .value_text("600 / 1000 requests"),
+                vec![],
+            ))
+            Text(content: "")
+            Text(content: "Arc:", dim: true)
+            #(Element::node::<Gauge>(
+                GaugeProps::new(0.66).shape(GaugeShape::Arc).width(16).label("Load"),
+                vec![],
+            ))
+        }
+    }
+}
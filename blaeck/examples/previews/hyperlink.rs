@@ -0,0 +1,26 @@
+use blaeck::prelude::*;
+
+pub fn build_ui() -> Element {
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "Hyperlink Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Text(content: "A badge and text that open one URL:", dim: true)
+            Hyperlink(url: "https://ci.example.com/build/42") {
+                Box(flex_direction: FlexDirection::Row) {
+                    Badge(text: "FAILED", color: Color::Red)
+                    Text(content: " build #42")
+                }
+            }
+            Text(content: "")
+            Text(content: "A bordered card, every cell linked:", dim: true)
+            Hyperlink(url: "https://github.com/gustafeden/blaeck") {
+                Box(border_style: BorderStyle::Single, padding_left: 1.0, padding_right: 1.0, width: 21.0) {
+                    Text(content: "gustafeden/blaeck", bold: true)
+                }
+            }
+            Text(content: "")
+            Text(content: "Terminals without OSC 8 show the content unlinked.", dim: true)
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use blaeck::prelude::*;
+use blaeck::{GraphicsProtocol, ImageData};
+
+/// A 32×32 sunset: a sky gradient with a sun, drawn pixel by pixel.
+fn sunset() -> ImageData {
+    let size = 32u32;
+    let mut image = ImageData::solid(size, size, [0, 0, 0, 255]);
+    for y in 0..size {
+        for x in 0..size {
+            let t = y * 255 / size;
+            let mut rgba = [40 + t as u8 / 2, 20 + t as u8 / 4, 120 - t as u8 / 3, 255];
+            let (dx, dy) = (x as i32 - 16, y as i32 - 20);
+            if dx * dx + dy * dy < 64 {
+                rgba = [255, 200, 60, 255];
+            }
+            if y > 24 {
+                rgba = [20, 30, 60, 255];
+            }
+            image.set_pixel(x, y, rgba);
+        }
+    }
+    image
+}
+
+pub fn build_ui() -> Element {
+    let image = sunset();
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "Image Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Box(flex_direction: FlexDirection::Row, gap: 3.0) {
+                Box(flex_direction: FlexDirection::Column) {
+                    Text(content: "Best protocol:", dim: true)
+                    #(Element::node::<Image>(ImageProps::new(image.clone()).width(16), vec![]))
+                }
+                Box(flex_direction: FlexDirection::Column) {
+                    Text(content: "Half blocks:", dim: true)
+                    #(Element::node::<Image>(
+                        ImageProps::new(image).width(16).protocol(GraphicsProtocol::HalfBlocks),
+                        vec![],
+                    ))
+                }
+                Box(flex_direction: FlexDirection::Column) {
+                    Text(content: "No image:", dim: true)
+                    #(Element::node::<Image>(ImageProps::default().alt("[logo]"), vec![]))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use blaeck::prelude::*;
+
+pub fn build_ui() -> Element {
+    // Two minutes of latency samples, one per second
+    let p50 = Series::new(
+        "p50",
+        (0..120).map(|t| 120.0 + 40.0 * (f64::from(t) / 12.0).sin()),
+    )
+    .color(Color::Cyan);
+    let p99 = Series::new(
+        "p99",
+        (0..120).map(|t| 260.0 + 80.0 * (f64::from(t) / 9.0).sin() + 0.8 * f64::from(t)),
+    )
+    .color(Color::Magenta);
+
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "LineChart Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Text(content: "Request latency (ms), braille:", dim: true)
+            #(Element::node::<LineChart>(
+                LineChartProps::new(vec![p50.clone(), p99.clone()])
+                    .width(50)
+                    .height(8)
+                    .y_range(0.0, 500.0),
+                vec![],
+            ))
+            Text(content: "")
+            Text(content: "Half blocks, no axes:", dim: true)
+            #(Element::node::<LineChart>(
+                LineChartProps::new(vec![p50])
+                    .width(50)
+                    .height(4)
+                    .marker(LineMarker::HalfBlock)
+                    .show_axes(false)
+                    .show_legend(false),
+                vec![],
+            ))
+        }
+    }
+}
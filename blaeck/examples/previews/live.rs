@@ -613,18 +613,14 @@ impl LivePreview for AsyncAppLive {
 
     fn handle_key(&mut self, key: &Key) -> bool {
         match key.code {
-            KeyCode::Enter => {
-                if !self.state.is_loading && !self.state.input.is_empty() {
-                    self.start_background_work();
-                }
+            KeyCode::Enter if !self.state.is_loading && !self.state.input.is_empty() => {
+                self.start_background_work();
             }
             KeyCode::Backspace => {
                 self.state.input.pop();
             }
-            KeyCode::Char(c) => {
-                if !self.state.is_loading {
-                    self.state.input.push(c);
-                }
+            KeyCode::Char(c) if !self.state.is_loading => {
+                self.state.input.push(c);
             }
             _ => {}
         }
@@ -699,7 +695,7 @@ impl LivePreview for ReactivePreviewLive {
 
 pub fn create_live_preview(name: &str) -> Option<StdBox<dyn LivePreview>> {
     match name {
-        // === Static (15) ===
+        // === Static (34) ===
         "banner" => Some(StdBox::new(StaticLive {
             build_fn: super::banner::build_ui,
         })),
@@ -742,6 +738,63 @@ pub fn create_live_preview(name: &str) -> Option<StdBox<dyn LivePreview>> {
         "tree" => Some(StdBox::new(StaticLive {
             build_fn: super::tree::build_ui,
         })),
+        "hyperlink" => Some(StdBox::new(StaticLive {
+            build_fn: super::hyperlink::build_ui,
+        })),
+        "scroll_view" => Some(StdBox::new(StaticLive {
+            build_fn: super::scroll_view::build_ui,
+        })),
+        "text_area" => Some(StdBox::new(StaticLive {
+            build_fn: super::text_area::build_ui,
+        })),
+        "radio_group" => Some(StdBox::new(StaticLive {
+            build_fn: super::radio_group::build_ui,
+        })),
+        "collapsible" => Some(StdBox::new(StaticLive {
+            build_fn: super::collapsible::build_ui,
+        })),
+        "time_picker" => Some(StdBox::new(StaticLive {
+            build_fn: super::time_picker::build_ui,
+        })),
+        "toast" => Some(StdBox::new(StaticLive {
+            build_fn: super::toast::build_ui,
+        })),
+        "context_menu" => Some(StdBox::new(StaticLive {
+            build_fn: super::context_menu::build_ui,
+        })),
+        "toc" => Some(StdBox::new(StaticLive {
+            build_fn: super::toc::build_ui,
+        })),
+        "scrollbar" => Some(StdBox::new(StaticLive {
+            build_fn: super::scrollbar::build_ui,
+        })),
+        "big_text" => Some(StdBox::new(StaticLive {
+            build_fn: super::big_text::build_ui,
+        })),
+        "truncate" => Some(StdBox::new(StaticLive {
+            build_fn: super::truncate::build_ui,
+        })),
+        "line_chart" => Some(StdBox::new(StaticLive {
+            build_fn: super::line_chart::build_ui,
+        })),
+        "gauge" => Some(StdBox::new(StaticLive {
+            build_fn: super::gauge::build_ui,
+        })),
+        "canvas" => Some(StdBox::new(StaticLive {
+            build_fn: super::canvas::build_ui,
+        })),
+        "reveal" => Some(StdBox::new(StaticLive {
+            build_fn: super::reveal::build_ui,
+        })),
+        "placeholder" => Some(StdBox::new(StaticLive {
+            build_fn: super::placeholder::build_ui,
+        })),
+        "image" => Some(StdBox::new(StaticLive {
+            build_fn: super::image::build_ui,
+        })),
+        "calendar" => Some(StdBox::new(StaticLive {
+            build_fn: super::calendar::build_ui,
+        })),
 
         // === Timer-animated (9) ===
         "animation" => Some(StdBox::new(TimerLive {
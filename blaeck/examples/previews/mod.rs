@@ -10,18 +10,27 @@ pub mod async_app;
 pub mod autocomplete;
 pub mod banner;
 pub mod barchart;
+pub mod big_text;
 pub mod borders;
 pub mod breadcrumbs;
+pub mod calendar;
+pub mod canvas;
+pub mod collapsible;
+pub mod context_menu;
 pub mod cube3d_braille;
 pub mod dashboard;
 pub mod demo_inline;
 pub mod diff;
 pub mod focus_demo;
 pub mod form_demo;
+pub mod gauge;
 pub mod gradient;
 pub mod hello;
+pub mod hyperlink;
+pub mod image;
 pub mod interactive;
 pub mod keyhints;
+pub mod line_chart;
 pub mod live;
 pub mod logbox;
 pub mod logbox_command;
@@ -29,13 +38,18 @@ pub mod markdown;
 pub mod menu;
 pub mod modal;
 pub mod multiselect;
+pub mod placeholder;
 pub mod plasma;
 pub mod polish_demo;
 pub mod preview;
 pub mod quickstart_interactive;
+pub mod radio_group;
 pub mod reactive_counter;
 pub mod reactive_list;
 pub mod reactive_timeline;
+pub mod reveal;
+pub mod scroll_view;
+pub mod scrollbar;
 pub mod select_demo;
 pub mod showcase;
 pub mod sparkline;
@@ -46,7 +60,12 @@ pub mod syntax;
 pub mod table;
 pub mod tabs;
 pub mod task_runner;
+pub mod text_area;
+pub mod time_picker;
 pub mod timeline_debug;
 pub mod timeline_demo;
 pub mod timer;
+pub mod toast;
+pub mod toc;
 pub mod tree;
+pub mod truncate;
@@ -0,0 +1,35 @@
+use blaeck::prelude::*;
+
+pub fn build_ui() -> Element {
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "EmptyState / ErrorState / LoadingState", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Box(flex_direction: FlexDirection::Column, gap: 1.0) {
+                Box(border_style: BorderStyle::Single, padding_left: 1.0, width: 34.0) {
+                    #(Element::node::<EmptyState>(
+                        PlaceholderProps::new("No matches")
+                            .description("Nothing matches \"deploy\".")
+                            .action("press / to search again"),
+                        vec![],
+                    ))
+                }
+                Box(border_style: BorderStyle::Single, padding_left: 1.0, width: 34.0) {
+                    #(Element::node::<ErrorState>(
+                        PlaceholderProps::new("Request failed")
+                            .description("Connection refused.")
+                            .action("press r to retry"),
+                        vec![],
+                    ))
+                }
+                Box(border_style: BorderStyle::Single, padding_left: 1.0, width: 34.0) {
+                    #(Element::node::<LoadingState>(
+                        PlaceholderProps::new("Fetching issues")
+                            .description("This can take a few seconds."),
+                        vec![],
+                    ))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use blaeck::prelude::*;
+
+const SIZES: [&str; 3] = ["Small", "Medium", "Large"];
+
+pub fn initial_state() -> RadioGroupState {
+    RadioGroupState::new(SIZES.len()).with_selected(1)
+}
+
+pub fn build_ui_with_state(state: &RadioGroupState) -> Element {
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "RadioGroup Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Text(content: "Vertical (focused):", dim: true)
+            #(Element::node::<RadioGroup>(
+                RadioGroupProps::new(SIZES)
+                    .selected(state.selected)
+                    .focused(true),
+                vec![],
+            ))
+            Text(content: "")
+            Text(content: "Horizontal:", dim: true)
+            #(Element::node::<RadioGroup>(
+                RadioGroupProps::new(SIZES)
+                    .selected(state.selected)
+                    .horizontal(),
+                vec![],
+            ))
+            Text(content: "")
+            Text(content: "Disabled:", dim: true)
+            #(Element::node::<RadioGroup>(
+                RadioGroupProps::new(["On", "Off"]).disabled(true),
+                vec![],
+            ))
+            Text(content: "")
+            Text(content: format!("Selected: {}", SIZES[state.selected]), color: Color::Green)
+            Text(content: "↑/↓ choose · Esc quits", dim: true)
+        }
+    }
+}
+
+pub fn build_ui() -> Element {
+    build_ui_with_state(&initial_state())
+}
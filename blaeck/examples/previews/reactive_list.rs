@@ -25,15 +25,11 @@ pub fn list_app(cx: Scope) -> Element {
         let len = current_items.len();
 
         match key.code {
-            KeyCode::Up => {
-                if current_selected > 0 {
-                    selected_handler.set(current_selected - 1);
-                }
+            KeyCode::Up if current_selected > 0 => {
+                selected_handler.set(current_selected - 1);
             }
-            KeyCode::Down => {
-                if len > 0 && current_selected < len - 1 {
-                    selected_handler.set(current_selected + 1);
-                }
+            KeyCode::Down if len > 0 && current_selected < len - 1 => {
+                selected_handler.set(current_selected + 1);
             }
             KeyCode::Char('a') => {
                 let mut new_items = current_items;
@@ -42,14 +38,12 @@ pub fn list_app(cx: Scope) -> Element {
                 items_handler.set(new_items);
                 item_counter_handler.set(counter + 1);
             }
-            KeyCode::Char('d') => {
-                if !current_items.is_empty() {
-                    let mut new_items = current_items;
-                    new_items.remove(current_selected);
-                    items_handler.set(new_items.clone());
-                    if current_selected >= new_items.len() && !new_items.is_empty() {
-                        selected_handler.set(new_items.len() - 1);
-                    }
+            KeyCode::Char('d') if !current_items.is_empty() => {
+                let mut new_items = current_items;
+                new_items.remove(current_selected);
+                items_handler.set(new_items.clone());
+                if current_selected >= new_items.len() && !new_items.is_empty() {
+                    selected_handler.set(new_items.len() - 1);
                 }
             }
             _ => {}
@@ -0,0 +1,39 @@
+use blaeck::input::Key;
+use blaeck::prelude::*;
+
+pub fn initial_state() -> RevealState {
+    RevealState::new(true).duration(300)
+}
+
+/// Toggle the details on `d`.
+pub fn handle_key(state: &mut RevealState, key: &Key) {
+    if key.is_char('d') {
+        state.toggle();
+    }
+}
+
+pub fn build_ui_with_state(state: &RevealState) -> Element {
+    let details = element! {
+        Box(flex_direction: FlexDirection::Column, border_style: BorderStyle::Single, padding_left: 1.0, width: 36.0) {
+            Text(content: "Region:   eu-north-1")
+            Text(content: "Replicas: 3")
+            Text(content: "Image:    app:2.4.1")
+            Text(content: "Uptime:   6d 4h")
+        }
+    };
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "Reveal Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Text(content: format!("{} api-server  running", if state.is_shown() { "▼" } else { "▶" }), bold: true)
+            #(Element::node::<Reveal>(RevealProps::new(state.progress()), vec![details]))
+            Text(content: "▶ worker      running", bold: true)
+            Text(content: "")
+            Text(content: "d show/hide details · Esc quits", dim: true)
+        }
+    }
+}
+
+pub fn build_ui() -> Element {
+    build_ui_with_state(&initial_state())
+}
@@ -0,0 +1,23 @@
+use blaeck::prelude::*;
+
+pub fn build_ui() -> Element {
+    let lines: Vec<Element> = (1..=30)
+        .map(|i| Element::text(format!("line {:>2} of the build log", i)))
+        .collect();
+
+    // Scrolled down a page, as if PageDown had been pressed
+    let mut scroll = ScrollState::new(lines.len(), 6);
+    scroll.page_down();
+
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "ScrollView Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Text(content: format!("Rows {}-{} of 30, with a scrollbar:", scroll.offset + 1, scroll.offset + 6), dim: true)
+            #(Element::node::<ScrollView>(
+                ScrollViewProps::new(6).width(32).offset(scroll.offset),
+                lines,
+            ))
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use blaeck::prelude::*;
+
+const ITEMS: usize = 40;
+const VISIBLE: usize = 8;
+
+pub fn initial_state() -> ScrollState {
+    let mut scroll = ScrollState::new(ITEMS, VISIBLE);
+    scroll.jump_to(12);
+    scroll
+}
+
+pub fn build_ui_with_state(scroll: &ScrollState) -> Element {
+    let rows: Vec<Element> = (scroll.offset..scroll.offset + VISIBLE)
+        .map(|i| Element::text(format!("package-{:02}  v1.{}.0", i + 1, i % 7)))
+        .collect();
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "Scrollbar Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Text(content: format!("Vertical, rows {}-{} of {}:", scroll.offset + 1, scroll.offset + VISIBLE, ITEMS), dim: true)
+            Box(flex_direction: FlexDirection::Row, gap: 1.0) {
+                #(Element::node::<Box>(
+                    BoxProps {
+                        width: Some(20.0),
+                        ..BoxProps::column()
+                    },
+                    rows,
+                ))
+                #(Element::node::<Scrollbar>(
+                    ScrollbarProps::new(ITEMS, VISIBLE, scroll.offset),
+                    vec![],
+                ))
+            }
+            Text(content: "")
+            Text(content: "Horizontal, 80 of 200 columns:", dim: true)
+            #(Element::node::<Scrollbar>(
+                ScrollbarProps::new(200, 80, 60).horizontal().length(24),
+                vec![],
+            ))
+            Text(content: "")
+            Text(content: "↑/↓ scroll · PgUp/PgDn page · Esc quits", dim: true)
+        }
+    }
+}
+
+pub fn build_ui() -> Element {
+    build_ui_with_state(&initial_state())
+}
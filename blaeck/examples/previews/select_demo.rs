@@ -66,45 +66,29 @@ impl AppState {
                     Screen::Result => {}
                 }
             }
-            crossterm::event::KeyCode::Left => {
-                if self.screen == Screen::Confirm {
-                    self.confirm_selected = true;
-                }
+            crossterm::event::KeyCode::Left if self.screen == Screen::Confirm => {
+                self.confirm_selected = true;
             }
-            crossterm::event::KeyCode::Right => {
-                if self.screen == Screen::Confirm {
-                    self.confirm_selected = false;
-                }
+            crossterm::event::KeyCode::Right if self.screen == Screen::Confirm => {
+                self.confirm_selected = false;
             }
-            crossterm::event::KeyCode::Char('y') => {
-                if self.screen == Screen::Confirm {
-                    self.confirm_selected = true;
-                }
+            crossterm::event::KeyCode::Char('y') if self.screen == Screen::Confirm => {
+                self.confirm_selected = true;
             }
-            crossterm::event::KeyCode::Char('n') => {
-                if self.screen == Screen::Confirm {
-                    self.confirm_selected = false;
-                }
+            crossterm::event::KeyCode::Char('n') if self.screen == Screen::Confirm => {
+                self.confirm_selected = false;
             }
-            crossterm::event::KeyCode::Home => {
-                if self.screen == Screen::Select {
-                    self.select_state.first();
-                }
+            crossterm::event::KeyCode::Home if self.screen == Screen::Select => {
+                self.select_state.first();
             }
-            crossterm::event::KeyCode::End => {
-                if self.screen == Screen::Select {
-                    self.select_state.last();
-                }
+            crossterm::event::KeyCode::End if self.screen == Screen::Select => {
+                self.select_state.last();
             }
-            crossterm::event::KeyCode::PageUp => {
-                if self.screen == Screen::Select {
-                    self.select_state.page_up();
-                }
+            crossterm::event::KeyCode::PageUp if self.screen == Screen::Select => {
+                self.select_state.page_up();
             }
-            crossterm::event::KeyCode::PageDown => {
-                if self.screen == Screen::Select {
-                    self.select_state.page_down();
-                }
+            crossterm::event::KeyCode::PageDown if self.screen == Screen::Select => {
+                self.select_state.page_down();
             }
             crossterm::event::KeyCode::Char(c)
                 if self.screen == Screen::Select && c.is_alphabetic() =>
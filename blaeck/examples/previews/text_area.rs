@@ -0,0 +1,32 @@
+use blaeck::prelude::*;
+
+pub fn initial_state() -> TextAreaState {
+    let mut state = TextAreaState::with_value(
+        "Fix resize flicker\n\nThe frame was cleared before the new size was known, so every resize painted one blank frame.",
+    );
+    state.set_size(40, 6);
+    state
+}
+
+pub fn build_ui_with_state(state: &TextAreaState) -> Element {
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "TextArea Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Text(content: "Commit message:", dim: true)
+            Box(border_style: BorderStyle::Single, width: 42.0) {
+                #(Element::node::<TextArea>(
+                    state.to_props().placeholder("Describe the change…"),
+                    vec![],
+                ))
+            }
+            Text(content: format!("{} characters", state.value().chars().count()), dim: true)
+            Text(content: "")
+            Text(content: "Type to edit · arrows move · Shift+arrows select · Esc quits", dim: true)
+        }
+    }
+}
+
+pub fn build_ui() -> Element {
+    build_ui_with_state(&initial_state())
+}
@@ -0,0 +1,40 @@
+use blaeck::prelude::*;
+use std::time::Duration;
+
+pub fn initial_state() -> TimePickerState {
+    TimePickerState::from_duration(Duration::from_secs(5 * 60)).max(Duration::from_secs(3600))
+}
+
+pub fn build_ui_with_state(state: &TimePickerState) -> Element {
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "TimePicker Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Text(content: "Countdown (max 1h):", dim: true)
+            #(Element::node::<TimePicker>(
+                TimePickerProps::new(state.value())
+                    .field(state.field)
+                    .focused(true)
+                    .invalid(!state.is_valid()),
+                vec![],
+            ))
+            #(if state.is_valid() {
+                element! { Text(content: format!("{} seconds", state.value().as_secs()), dim: true) }
+            } else {
+                element! { Text(content: "Longer than an hour", color: Color::Red) }
+            })
+            Text(content: "")
+            Text(content: "Alarm (clock, no seconds):", dim: true)
+            #(Element::node::<TimePicker>(
+                TimePickerProps::new(TimePickerState::clock(7, 30).value()).show_seconds(false),
+                vec![],
+            ))
+            Text(content: "")
+            Text(content: "←/→ field · ↑/↓ step · digits type · Esc quits", dim: true)
+        }
+    }
+}
+
+pub fn build_ui() -> Element {
+    build_ui_with_state(&initial_state())
+}
@@ -0,0 +1,63 @@
+use blaeck::input::Key;
+use blaeck::prelude::*;
+use blaeck::Rect;
+use crossterm::event::KeyCode;
+use std::time::{Duration, Instant};
+
+const WIDTH: u16 = 64;
+const HEIGHT: u16 = 14;
+
+pub fn initial_state() -> ToastManager {
+    let toasts = ToastManager::new()
+        .ttl(Duration::from_secs(4))
+        .width(28)
+        .max_visible(3);
+    toasts.success("Saved settings.toml");
+    toasts.warning("Disk 91% full");
+    toasts
+}
+
+/// Push a toast for `i`/`s`/`w`/`e`, clear the stack on `c`.
+pub fn handle_key(toasts: &ToastManager, key: &Key) {
+    match key.code {
+        KeyCode::Char('i') => {
+            toasts.info("Sync started");
+        }
+        KeyCode::Char('s') => {
+            toasts.success("Upload complete");
+        }
+        KeyCode::Char('w') => {
+            toasts.warning("Token expires soon");
+        }
+        KeyCode::Char('e') => {
+            toasts.error("Connection refused");
+        }
+        KeyCode::Char('c') => toasts.clear(),
+        _ => {}
+    }
+}
+
+pub fn build_ui_with_state(toasts: &ToastManager, now: Instant) -> Element {
+    // Inside the card's border
+    let bounds = Rect::new(1, 1, WIDTH - 2, HEIGHT - 2);
+    element! {
+        Box(width: f32::from(WIDTH), height: f32::from(HEIGHT)) {
+            Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round, width: f32::from(WIDTH), height: f32::from(HEIGHT)) {
+                Text(content: "Toast Component", bold: true, color: Color::Cyan)
+                Text(content: "")
+                Text(content: "i info · s success", dim: true)
+                Text(content: "w warning · e error", dim: true)
+                Text(content: "c clear · Esc quits", dim: true)
+                Text(content: "")
+                Text(content: format!("{} queued", toasts.len()), dim: true)
+            }
+            #(toasts.overlay(ToastCorner::TopRight, bounds, now))
+        }
+    }
+}
+
+pub fn build_ui() -> Element {
+    let toasts = initial_state();
+    // Past the fade-in, so the preview shows the toasts fully drawn
+    build_ui_with_state(&toasts, Instant::now() + Duration::from_secs(1))
+}
@@ -0,0 +1,105 @@
+use blaeck::input::Key;
+use blaeck::prelude::*;
+use crossterm::event::KeyCode;
+
+const MANUAL: &str = "\
+# Getting started
+Install with cargo add blaeck.
+Render an element tree with Blaeck::new.
+# Layout
+Boxes lay out children with flexbox.
+## Direction
+Row is the default direction.
+Column stacks children vertically.
+## Spacing
+Padding goes inside the border.
+Gap goes between children.
+# Input
+poll_key waits for a key press.
+Key::is_char matches printable keys.
+## Focus
+FocusManager tracks the focused widget.
+# Styling
+Colors fall back on older terminals.
+Modifiers add bold, dim and italic.";
+
+const HEIGHT: usize = 6;
+
+pub struct TocDemo {
+    pub toc: TocState,
+    pub scroll: ScrollState,
+    pub sections: Vec<Section>,
+}
+
+impl TocDemo {
+    /// Move through the list with the arrows and jump with Enter, jump to
+    /// the next or previous section with `]`/`[`, or scroll the text with
+    /// PageUp/PageDown.
+    pub fn handle_key(&mut self, key: &Key) {
+        if let Some(line) = self.toc.handle_key(key, &self.sections) {
+            self.scroll.jump_to(line);
+            return;
+        }
+        let target = match key.code {
+            KeyCode::Char(']') => next_section(&self.sections, self.scroll.offset),
+            KeyCode::Char('[') => prev_section(&self.sections, self.scroll.offset),
+            KeyCode::PageDown => {
+                self.scroll.page_down();
+                None
+            }
+            KeyCode::PageUp => {
+                self.scroll.page_up();
+                None
+            }
+            _ => return,
+        };
+        if let Some(line) = target {
+            self.scroll.jump_to(line);
+        }
+        self.toc.follow(&self.sections, self.scroll.offset);
+    }
+}
+
+pub fn initial_state() -> TocDemo {
+    let sections = marked_sections(MANUAL, "#");
+    let mut scroll = ScrollState::new(MANUAL.lines().count(), HEIGHT);
+    let mut toc = TocState::new();
+    // Start at "Layout > Direction"
+    scroll.jump_to(sections[2].line);
+    toc.follow(&sections, scroll.offset);
+    TocDemo {
+        toc,
+        scroll,
+        sections,
+    }
+}
+
+pub fn build_ui_with_state(state: &TocDemo) -> Element {
+    let lines: Vec<Element> = MANUAL.lines().map(Element::text).collect();
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "Toc Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Box(flex_direction: FlexDirection::Row, gap: 2.0) {
+                Box(border_style: BorderStyle::Single, width: 22.0) {
+                    #(Element::node::<Toc>(
+                        TocProps::new(state.sections.clone()).selected(Some(state.toc.selected)),
+                        vec![],
+                    ))
+                }
+                Box(border_style: BorderStyle::Single) {
+                    #(Element::node::<ScrollView>(
+                        ScrollViewProps::new(HEIGHT as u16).width(40).offset(state.scroll.offset),
+                        lines,
+                    ))
+                }
+            }
+            Text(content: "")
+            Text(content: "↑/↓ list · Enter jump · ]/[ next/prev · PgUp/PgDn scroll · Esc quits", dim: true)
+        }
+    }
+}
+
+pub fn build_ui() -> Element {
+    build_ui_with_state(&initial_state())
+}
@@ -0,0 +1,48 @@
+use blaeck::prelude::*;
+
+const TRACE: [&str; 14] = [
+    "thread 'main' panicked at src/config.rs:42:9:",
+    "missing field `port`",
+    "stack backtrace:",
+    "   0: rust_begin_unwind",
+    "   1: core::panicking::panic_fmt",
+    "   2: core::result::unwrap_failed",
+    "   3: app::config::Config::load",
+    "             at ./src/config.rs:42:9",
+    "   4: app::server::start",
+    "             at ./src/server.rs:17:18",
+    "   5: app::main",
+    "             at ./src/main.rs:8:5",
+    "   6: core::ops::function::FnOnce::call_once",
+    "note: run with `RUST_BACKTRACE=full` for more",
+];
+
+pub fn initial_state() -> TruncateState {
+    let mut state = TruncateState::new(8);
+    state.set_content_height(TRACE.len());
+    state
+}
+
+pub fn build_ui_with_state(state: &TruncateState) -> Element {
+    let lines: Vec<Element> = TRACE.iter().map(|line| Element::text(*line)).collect();
+    element! {
+        Box(flex_direction: FlexDirection::Column, padding: 1.0, border_style: BorderStyle::Round) {
+            Text(content: "Truncate Component", bold: true, color: Color::Cyan)
+            Text(content: "")
+            Text(content: "Test output:", dim: true)
+            #(Element::node::<Truncate>(
+                TruncateProps::new(4)
+                    .expanded(state.expanded)
+                    .max_height(8)
+                    .offset(state.scroll.offset),
+                lines,
+            ))
+            Text(content: "")
+            Text(content: "e expand/collapse · ↑/↓ scroll when expanded · Esc quits", dim: true)
+        }
+    }
+}
+
+pub fn build_ui() -> Element {
+    build_ui_with_state(&initial_state())
+}
@@ -10,10 +10,9 @@
 #[path = "previews/mod.rs"]
 mod previews;
 
-use blaeck::reactive::*;
-use std::io;
-
-fn main() -> io::Result<()> {
-    ReactiveApp::run(previews::reactive_counter::counter)?;
-    Ok(())
+blaeck::app! {
+    root: previews::reactive_counter::counter,
+    keys: {
+        "q": "quit" => |app| app.exit(),
+    },
 }
@@ -0,0 +1,36 @@
+//! Reveal example - Grows a section into view line by line
+//!
+//! Run with: cargo run --example reveal
+
+#[path = "previews/mod.rs"]
+mod previews;
+
+use blaeck::input::poll_key;
+use blaeck::Blaeck;
+use crossterm::event::KeyCode;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use std::io;
+use std::time::Duration;
+
+fn main() -> io::Result<()> {
+    let mut blaeck = Blaeck::new(io::stdout())?;
+    let mut state = previews::reveal::initial_state();
+
+    enable_raw_mode()?;
+
+    loop {
+        // Re-rendering on every poll keeps the animation moving
+        blaeck.render(previews::reveal::build_ui_with_state(&state))?;
+
+        if let Some(key) = poll_key(Duration::from_millis(16))? {
+            if key.is_ctrl_c() || key.code == KeyCode::Esc {
+                break;
+            }
+            previews::reveal::handle_key(&mut state, &key);
+        }
+    }
+
+    disable_raw_mode()?;
+    blaeck.unmount()?;
+    Ok(())
+}
@@ -0,0 +1,16 @@
+//! ScrollView example - A fixed-height container that scrolls its children
+//!
+//! Run with: cargo run --example scroll_view
+
+#[path = "previews/mod.rs"]
+mod previews;
+
+use blaeck::Blaeck;
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut blaeck = Blaeck::new(io::stdout())?;
+    blaeck.render(previews::scroll_view::build_ui())?;
+    blaeck.unmount()?;
+    Ok(())
+}
@@ -0,0 +1,37 @@
+//! Toast example - Notifications that stack in a corner and dismiss themselves
+//!
+//! Run with: cargo run --example toast
+
+#[path = "previews/mod.rs"]
+mod previews;
+
+use blaeck::input::poll_key;
+use blaeck::Blaeck;
+use crossterm::event::KeyCode;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use std::io;
+use std::time::{Duration, Instant};
+
+fn main() -> io::Result<()> {
+    let mut blaeck = Blaeck::new(io::stdout())?;
+    let toasts = previews::toast::initial_state();
+
+    enable_raw_mode()?;
+
+    loop {
+        let now = Instant::now();
+        toasts.tick(now);
+        blaeck.render(previews::toast::build_ui_with_state(&toasts, now))?;
+
+        if let Some(key) = poll_key(Duration::from_millis(50))? {
+            if key.is_ctrl_c() || key.code == KeyCode::Esc {
+                break;
+            }
+            previews::toast::handle_key(&toasts, &key);
+        }
+    }
+
+    disable_raw_mode()?;
+    blaeck.unmount()?;
+    Ok(())
+}
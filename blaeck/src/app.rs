@@ -62,12 +62,19 @@
 //!
 //! For async apps with background tasks, see `async_runtime.rs` instead.
 
+use crate::debounce::{resize_placeholder, ResizeDebouncer};
 use crate::element::Element;
-use crate::input::{poll_key, Key};
-use crate::renderer::Blaeck;
-use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use crate::error::{BlaeckError, Result};
+use crate::gestures::{Gesture, GestureDetector, Wheel};
+use crate::hotkeys::{HotkeyConflict, Hotkeys};
+use crate::idle::{IdleEvent, IdleTracker};
+use crate::input::{InputEvent, Key, KeyPatternError, Mouse};
+use crate::renderer::{Blaeck, TerminalModes};
+use crate::replay::{InputRecording, InputReplay, InputSource};
+use crossterm::terminal::enable_raw_mode;
+use std::collections::HashMap;
 use std::io::{self, Write};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Configuration for the App runtime.
 #[derive(Clone)]
@@ -76,6 +83,14 @@ pub struct AppConfig {
     pub poll_interval: Duration,
     /// Whether to exit on Ctrl+C (default: true)
     pub exit_on_ctrl_c: bool,
+    /// How long the terminal size must be stable before relayout; a
+    /// placeholder is shown meanwhile. Zero relayouts on every resize
+    /// event (default: 100ms)
+    pub resize_debounce: Duration,
+    /// Ask terminals with the kitty keyboard protocol to report key repeats
+    /// and releases too. The input handler then sees releases, so it should
+    /// check [`Key::is_press`] (default: false)
+    pub key_releases: bool,
 }
 
 impl Default for AppConfig {
@@ -83,6 +98,8 @@ impl Default for AppConfig {
         Self {
             poll_interval: Duration::from_millis(50),
             exit_on_ctrl_c: true,
+            resize_debounce: Duration::from_millis(100),
+            key_releases: false,
         }
     }
 }
@@ -91,6 +108,8 @@ impl Default for AppConfig {
 pub struct AppResult {
     /// How the app exited
     pub exit_reason: ExitReason,
+    /// The input, if the app was run [with recording](App::with_recording)
+    pub recording: Option<InputRecording>,
 }
 
 /// Why the app exited.
@@ -102,22 +121,48 @@ pub enum ExitReason {
     Completed,
 }
 
+/// A hotkey action run with the app.
+pub type HotkeyAction<W> = Box<dyn FnMut(&mut App<W>)>;
+
+/// Called when an [`App`] goes idle or wakes up.
+pub type IdleCallback<W> = Box<dyn FnMut(&mut App<W>, IdleEvent)>;
+
+/// Called with each mouse [`Gesture`] an [`App`] sees.
+pub type GestureCallback<W> = Box<dyn FnMut(&mut App<W>, Gesture)>;
+
+/// Called with each wheel notch over a named scrollable component.
+pub type ScrollCallback<W> = Box<dyn FnMut(&mut App<W>, Wheel)>;
+
+/// Called when the terminal window gains (true) or loses (false) focus.
+pub type TerminalFocusCallback<W> = Box<dyn FnMut(&mut App<W>, bool)>;
+
+/// Called with text pasted into an [`App`].
+pub type PasteCallback<W> = Box<dyn FnMut(&mut App<W>, String)>;
+
 /// Main application runtime.
 pub struct App<W: Write> {
     blaeck: Blaeck<W>,
     config: AppConfig,
     should_exit: bool,
     exit_reason: ExitReason,
+    hotkeys: Hotkeys<HotkeyAction<W>>,
+    idle: Option<(IdleTracker, IdleCallback<W>)>,
+    gestures: Option<(GestureDetector, GestureCallback<W>)>,
+    scroll_handlers: HashMap<String, ScrollCallback<W>>,
+    focus: Option<TerminalFocusCallback<W>>,
+    focused: bool,
+    paste: Option<PasteCallback<W>>,
+    input: InputSource,
 }
 
 impl App<io::Stdout> {
     /// Create a new App with stdout.
-    pub fn new() -> io::Result<Self> {
+    pub fn new() -> Result<Self> {
         Self::with_config(AppConfig::default())
     }
 
     /// Create a new App with custom config.
-    pub fn with_config(config: AppConfig) -> io::Result<Self> {
+    pub fn with_config(config: AppConfig) -> Result<Self> {
         let stdout = io::stdout();
         let blaeck = Blaeck::new(stdout)?;
         Ok(Self {
@@ -125,22 +170,56 @@ impl App<io::Stdout> {
             config,
             should_exit: false,
             exit_reason: ExitReason::Completed,
+            hotkeys: Hotkeys::new(),
+            idle: None,
+            gestures: None,
+            scroll_handlers: HashMap::new(),
+            focus: None,
+            focused: true,
+            paste: None,
+            input: InputSource::default(),
         })
     }
 }
 
 impl<W: Write> App<W> {
     /// Create an App with a custom writer.
-    pub fn with_writer(writer: W, config: AppConfig) -> io::Result<Self> {
+    pub fn with_writer(writer: W, config: AppConfig) -> Result<Self> {
         let blaeck = Blaeck::new(writer)?;
         Ok(Self {
             blaeck,
             config,
             should_exit: false,
             exit_reason: ExitReason::Completed,
+            hotkeys: Hotkeys::new(),
+            idle: None,
+            gestures: None,
+            scroll_handlers: HashMap::new(),
+            focus: None,
+            focused: true,
+            paste: None,
+            input: InputSource::default(),
         })
     }
 
+    /// Record the input; the recording is returned in [`AppResult`].
+    #[must_use]
+    pub fn with_recording(mut self) -> Self {
+        self.input.start_recording();
+        self
+    }
+
+    /// Take input from `replay` instead of the terminal.
+    ///
+    /// The app exits once the replay ends, unless it was made with
+    /// [`InputReplay::then_live`]. Raw mode is only enabled if the terminal
+    /// will be read. See [`crate::replay`].
+    #[must_use]
+    pub fn with_replay(mut self, replay: InputReplay) -> Self {
+        self.input.set_replay(replay);
+        self
+    }
+
     /// Request the app to exit.
     pub fn exit(&mut self) {
         self.should_exit = true;
@@ -162,26 +241,313 @@ impl<W: Write> App<W> {
         &mut self.blaeck
     }
 
+    /// Register a global hotkey such as `"ctrl+p"`.
+    ///
+    /// Hotkeys run before the input handler, and a key that triggers one is
+    /// not passed on. Returns the conflict if the binding was taken; the new
+    /// action replaces the old one. See [`crate::hotkeys`].
+    pub fn register_hotkey<F>(
+        &mut self,
+        pattern: &str,
+        description: impl Into<String>,
+        action: F,
+    ) -> std::result::Result<Option<HotkeyConflict>, KeyPatternError>
+    where
+        F: FnMut(&mut Self) + 'static,
+    {
+        self.hotkeys
+            .register(pattern, description, Box::new(action))
+    }
+
+    /// The registered hotkeys, e.g. for [`Hotkeys::hints`].
+    pub fn hotkeys(&self) -> &Hotkeys<HotkeyAction<W>> {
+        &self.hotkeys
+    }
+
+    /// Mutable access to the hotkeys, e.g. to unregister one.
+    pub fn hotkeys_mut(&mut self) -> &mut Hotkeys<HotkeyAction<W>> {
+        &mut self.hotkeys
+    }
+
+    /// Run the hotkey for `key`, if any. Returns whether one ran.
+    pub fn dispatch_hotkey(&mut self, key: &Key) -> bool {
+        // Take the registry so the action can borrow the app; hotkeys it
+        // registers land in the empty one and are merged back after.
+        let mut hotkeys = std::mem::take(&mut self.hotkeys);
+        let handled = match hotkeys.action_mut(key) {
+            Some(action) => {
+                action(self);
+                true
+            }
+            None => false,
+        };
+        let added = std::mem::replace(&mut self.hotkeys, hotkeys);
+        self.hotkeys.append(added);
+        handled
+    }
+
+    /// Call `callback` with [`IdleEvent::Idle`] after `timeout` without
+    /// input, and with [`IdleEvent::Active`] on the next key press, mouse
+    /// event or paste.
+    ///
+    /// The waking input is swallowed: it doesn't reach hotkeys or the input,
+    /// gesture, scroll or paste handlers. The UI is re-rendered after each
+    /// event. See [`crate::idle`].
+    pub fn on_idle<F>(&mut self, timeout: Duration, callback: F)
+    where
+        F: FnMut(&mut Self, IdleEvent) + 'static,
+    {
+        self.idle = Some((IdleTracker::new(timeout), Box::new(callback)));
+    }
+
+    /// Whether the app is idle (see [`on_idle`](Self::on_idle)).
+    pub fn is_idle(&self) -> bool {
+        self.idle
+            .as_ref()
+            .is_some_and(|(tracker, _)| tracker.is_idle())
+    }
+
+    /// Run the idle callback for the event `update` reports, if any.
+    /// Returns whether it ran.
+    fn update_idle<F>(&mut self, update: F) -> bool
+    where
+        F: FnOnce(&mut IdleTracker) -> Option<IdleEvent>,
+    {
+        let Some((mut tracker, mut callback)) = self.idle.take() else {
+            return false;
+        };
+        let event = update(&mut tracker);
+        if let Some(event) = event {
+            callback(self, event);
+        }
+        // The callback may have installed a new idle handler
+        self.idle.get_or_insert((tracker, callback));
+        event.is_some()
+    }
+
+    /// Call `callback` with each click, double-click and drag.
+    ///
+    /// Mouse capture is turned on while the app runs. The UI is re-rendered
+    /// after each gesture. See [`crate::gestures`].
+    pub fn on_gesture<F>(&mut self, detector: GestureDetector, callback: F)
+    where
+        F: FnMut(&mut Self, Gesture) + 'static,
+    {
+        self.gestures = Some((detector, Box::new(callback)));
+    }
+
+    /// Run the gesture callback for `mouse`, if it makes a gesture.
+    /// Returns whether it ran.
+    fn update_gestures(&mut self, mouse: &Mouse) -> bool {
+        let Some((mut detector, mut callback)) = self.gestures.take() else {
+            return false;
+        };
+        let gesture = detector.feed(mouse, Instant::now());
+        if let Some(gesture) = gesture {
+            callback(self, gesture);
+        }
+        // The callback may have installed a new gesture handler
+        self.gestures.get_or_insert((detector, callback));
+        gesture.is_some()
+    }
+
+    /// Call `callback` with each wheel notch over the scrollable component
+    /// named `id` — a [`ScrollView`](crate::ScrollView),
+    /// [`Select`](crate::Select) or [`Table`](crate::Table) given that
+    /// `scroll_id`.
+    ///
+    /// The wheel goes to the innermost named component under the pointer
+    /// only, so nested lists scroll independently of their container. Pass
+    /// it on with the state's `handle_wheel`. Mouse capture is turned on
+    /// while the app runs, and the UI is re-rendered after each notch.
+    pub fn on_scroll<F>(&mut self, id: impl Into<String>, callback: F)
+    where
+        F: FnMut(&mut Self, Wheel) + 'static,
+    {
+        self.scroll_handlers.insert(id.into(), Box::new(callback));
+    }
+
+    /// Run the scroll handler of the component under `mouse`, if it's a
+    /// wheel notch over one. Returns whether it ran.
+    fn update_scroll(&mut self, mouse: &Mouse) -> bool {
+        let Some(wheel) = Wheel::from_mouse(mouse) else {
+            return false;
+        };
+        let (column, row) = wheel.position;
+        let Some(id) = self.blaeck.scroll_target_at(column, row).map(str::to_owned) else {
+            return false;
+        };
+        let Some(mut callback) = self.scroll_handlers.remove(&id) else {
+            return false;
+        };
+        callback(self, wheel);
+        // The callback may have installed a new handler for this id
+        self.scroll_handlers.entry(id).or_insert(callback);
+        true
+    }
+
+    /// Call `callback` when the terminal window gains or loses focus, for
+    /// example to pause animations and dim the UI while in the background.
+    ///
+    /// Focus reporting is turned on while the app runs; terminals without
+    /// it never call back. The UI is re-rendered after each change.
+    pub fn on_focus<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut Self, bool) + 'static,
+    {
+        self.focus = Some(Box::new(callback));
+    }
+
+    /// Whether the terminal window has focus, as last reported. True until
+    /// the terminal says otherwise.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Record a focus change and run the focus callback.
+    /// Returns whether focus changed.
+    fn update_focus(&mut self, focused: bool) -> bool {
+        if focused == self.focused {
+            return false;
+        }
+        self.focused = focused;
+        if let Some(mut callback) = self.focus.take() {
+            callback(self, focused);
+            // The callback may have installed a new focus handler
+            self.focus.get_or_insert(callback);
+        }
+        true
+    }
+
+    /// Call `callback` with pasted text, in one piece.
+    ///
+    /// Bracketed paste is turned on while the app runs, so a paste's line
+    /// breaks don't arrive as Enter presses. Hand the text to a text input
+    /// with [`TextInputState::handle_paste`](crate::TextInputState::handle_paste).
+    /// The UI is re-rendered after each paste.
+    pub fn on_paste<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut Self, String) + 'static,
+    {
+        self.paste = Some(Box::new(callback));
+    }
+
     /// Run the app with a render function and input handler.
     ///
     /// The render function is called to get the UI element tree.
-    /// The input handler is called for each key press.
-    pub fn run<R, I>(mut self, mut render: R, mut on_input: I) -> io::Result<AppResult>
+    /// The input handler is called for each key press that isn't a
+    /// registered hotkey.
+    pub fn run<R, I>(mut self, mut render: R, mut on_input: I) -> Result<AppResult>
     where
         R: FnMut(&mut Self) -> Element,
         I: FnMut(&mut Self, Key),
     {
-        // Enable raw mode for input handling
-        enable_raw_mode()?;
+        // Whatever the loop turns on is turned off again, even if it fails
+        let mut modes = TerminalModes::default();
+        let looped = self.event_loop(&mut render, &mut on_input, &mut modes);
+        let restored = modes.restore(&mut self.blaeck);
+        looped.and(restored)?;
+        self.blaeck.unmount()?;
+
+        Ok(AppResult {
+            exit_reason: self.exit_reason,
+            recording: self.input.take_recording(),
+        })
+    }
+
+    /// Set up the terminal, render and handle input until the app exits,
+    /// recording in `modes` what was turned on.
+    fn event_loop<R, I>(
+        &mut self,
+        render: &mut R,
+        on_input: &mut I,
+        modes: &mut TerminalModes,
+    ) -> Result<()>
+    where
+        R: FnMut(&mut Self) -> Element,
+        I: FnMut(&mut Self, Key),
+    {
+        // Enable raw mode for input handling, unless it's all replayed
+        modes.raw_mode = self.input.reads_terminal();
+        if modes.raw_mode {
+            enable_raw_mode().map_err(|e| BlaeckError::terminal("enabling raw mode", e))?;
+        }
+        modes.mouse_capture = self.gestures.is_some() || !self.scroll_handlers.is_empty();
+        if modes.mouse_capture {
+            self.blaeck.set_mouse_capture(true)?;
+        }
+        modes.focus_reporting = self.focus.is_some();
+        if modes.focus_reporting {
+            self.blaeck.set_focus_reporting(true)?;
+        }
+        modes.bracketed_paste = self.paste.is_some();
+        if modes.bracketed_paste {
+            self.blaeck.set_bracketed_paste(true)?;
+        }
+        modes.enable_keyboard_enhancement(&mut self.blaeck, self.config.key_releases)?;
 
         // Initial render
-        let ui = render(&mut self);
+        let ui = render(self);
         self.blaeck.render(ui)?;
+        if modes.raw_mode && modes.mouse_capture {
+            // Mouse rows are relative to the screen, not the frame. If the
+            // terminal doesn't say where the cursor is, assume the top row.
+            let _ = self.blaeck.locate_frame();
+        }
+
+        let mut resize = ResizeDebouncer::new(self.config.resize_debounce);
 
         // Main event loop
-        while !self.should_exit {
-            // Poll for input
-            if let Some(key) = poll_key(self.config.poll_interval)? {
+        while !self.should_exit && !self.input.is_finished() {
+            // Poll for input, waking up when a resize settles
+            let timeout = resize.timeout(Instant::now(), self.config.poll_interval);
+            let key = match self.input.poll(timeout)? {
+                Some(InputEvent::Key(key)) => Some(key),
+                Some(InputEvent::Mouse(mouse)) => {
+                    // Mouse input that wakes the app does nothing else, like
+                    // a key
+                    let now = Instant::now();
+                    let handled = self.update_idle(|idle| idle.activity_event(now))
+                        || (self.update_scroll(&mouse) | self.update_gestures(&mouse));
+                    if handled && !resize.is_pending() {
+                        let ui = render(self);
+                        self.blaeck.render(ui)?;
+                    }
+                    None
+                }
+                Some(InputEvent::Resize(width, height)) => {
+                    if resize.resize(width, height, Instant::now()) {
+                        self.blaeck.handle_resize(width, height)?;
+                        self.blaeck.render(resize_placeholder(width, height))?;
+                    }
+                    None
+                }
+                Some(InputEvent::Focus(focused)) => {
+                    if self.update_focus(focused) && !resize.is_pending() {
+                        let ui = render(self);
+                        self.blaeck.render(ui)?;
+                    }
+                    None
+                }
+                Some(InputEvent::Paste(text)) => {
+                    // A paste that wakes the app is dropped, like a key
+                    let now = Instant::now();
+                    let woke = self.update_idle(|idle| idle.activity_event(now));
+                    let pasted = !woke && self.paste.is_some();
+                    if let Some(mut callback) = self.paste.take_if(|_| pasted) {
+                        callback(self, text);
+                        // The callback may have installed a new paste handler
+                        self.paste.get_or_insert(callback);
+                    }
+                    if (woke || pasted) && !resize.is_pending() {
+                        let ui = render(self);
+                        self.blaeck.render(ui)?;
+                    }
+                    None
+                }
+                None => None,
+            };
+            if let Some(key) = key {
                 // Handle Ctrl+C
                 if self.config.exit_on_ctrl_c && key.is_ctrl_c() {
                     self.should_exit = true;
@@ -189,27 +555,41 @@ impl<W: Write> App<W> {
                     break;
                 }
 
-                // Call user's input handler
-                on_input(&mut self, key);
+                // A key that wakes the app does nothing else; hotkeys take
+                // precedence over the input handler
+                let now = Instant::now();
+                if !self.update_idle(|idle| idle.activity_event(now)) && !self.dispatch_hotkey(&key)
+                {
+                    on_input(self, key);
+                }
+
+                // Re-render after input, unless a resize is settling
+                if !resize.is_pending() {
+                    let ui = render(self);
+                    self.blaeck.render(ui)?;
+                }
+            }
 
-                // Re-render after input
-                let ui = render(&mut self);
+            // One full relayout once the size stops changing
+            if let Some((width, height)) = resize.settled(Instant::now()) {
+                self.blaeck.handle_resize(width, height)?;
+                let ui = render(self);
                 self.blaeck.render(ui)?;
             }
-        }
 
-        // Cleanup
-        disable_raw_mode()?;
-        self.blaeck.unmount()?;
+            let now = Instant::now();
+            if self.update_idle(|idle| idle.check_event(now)) && !resize.is_pending() {
+                let ui = render(self);
+                self.blaeck.render(ui)?;
+            }
+        }
 
-        Ok(AppResult {
-            exit_reason: self.exit_reason,
-        })
+        Ok(())
     }
 
     /// Run with just a render function (no input handling).
     /// Exits on Ctrl+C.
-    pub fn run_simple<R>(self, render: R) -> io::Result<AppResult>
+    pub fn run_simple<R>(self, render: R) -> Result<AppResult>
     where
         R: FnMut(&mut Self) -> Element,
     {
@@ -220,6 +600,7 @@ impl<W: Write> App<W> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crossterm::event::KeyCode;
 
     #[test]
     fn test_app_config_default() {
@@ -233,6 +614,7 @@ mod tests {
         let config = AppConfig {
             poll_interval: Duration::from_millis(100),
             exit_on_ctrl_c: false,
+            ..Default::default()
         };
         assert_eq!(config.poll_interval, Duration::from_millis(100));
         assert!(!config.exit_on_ctrl_c);
@@ -295,14 +677,264 @@ mod tests {
         let _blaeck = app.blaeck_mut();
     }
 
+    #[test]
+    fn test_app_hotkey_dispatch() {
+        let mut app = App::with_writer(Vec::new(), AppConfig::default()).unwrap();
+        app.register_hotkey("ctrl+q", "quit", |app| {
+            app.exit();
+            app.register_hotkey("f1", "help", |_| {}).unwrap();
+        })
+        .unwrap();
+
+        assert!(!app.dispatch_hotkey(&Key::new(KeyCode::Char('q'))));
+        assert!(!app.should_exit());
+        assert!(app.dispatch_hotkey(&Key::with_ctrl(KeyCode::Char('q'))));
+        assert!(app.should_exit());
+        // Registered from inside the action
+        assert_eq!(app.hotkeys().len(), 2);
+    }
+
+    #[test]
+    fn test_app_idle_callback() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let mut app = App::with_writer(Vec::new(), AppConfig::default()).unwrap();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let seen = events.clone();
+        app.on_idle(Duration::from_secs(60), move |_, event| {
+            seen.borrow_mut().push(event)
+        });
+
+        let later = Instant::now() + Duration::from_secs(61);
+        assert!(!app.update_idle(|idle| idle.activity_event(later)));
+        assert!(!app.update_idle(|idle| idle.check_event(later)));
+        let later = later + Duration::from_secs(61);
+        assert!(app.update_idle(|idle| idle.check_event(later)));
+        assert!(app.is_idle());
+        assert!(app.update_idle(|idle| idle.activity_event(later)));
+        assert!(!app.is_idle());
+        assert_eq!(*events.borrow(), [IdleEvent::Idle, IdleEvent::Active]);
+    }
+
+    #[test]
+    fn test_app_mouse_and_paste_wake_from_idle() {
+        use crate::replay::{InputRecording, InputReplay};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        // With no timeout the app is idle again after every event, so the
+        // release and the paste each wake it and go no further: no click, no
+        // paste
+        let script =
+            InputRecording::parse("0 mouse left-down 3,1\n0 mouse left-up 3,1\n10 paste \"x\"")
+                .unwrap();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let log = events.clone();
+        let mut app = App::with_writer(Vec::new(), AppConfig::default())
+            .unwrap()
+            .with_replay(InputReplay::new(script).instant());
+        app.on_idle(Duration::ZERO, move |_, event| {
+            log.borrow_mut().push(format!("{event:?}"))
+        });
+        let log = events.clone();
+        app.on_gesture(GestureDetector::new(), move |_, gesture| {
+            log.borrow_mut().push(format!("{gesture:?}"))
+        });
+        let log = events.clone();
+        app.on_paste(move |_, text| log.borrow_mut().push(text));
+        app.run(|_| Element::text(""), |_, _| {}).unwrap();
+
+        assert_eq!(
+            *events.borrow(),
+            ["Idle", "Active", "Idle", "Active", "Idle"]
+        );
+    }
+
     #[test]
     fn test_app_result_exit_reason() {
         let result = AppResult {
             exit_reason: ExitReason::UserExit,
+            recording: None,
         };
         assert_eq!(result.exit_reason, ExitReason::UserExit);
     }
 
+    #[test]
+    fn test_app_replay_script() {
+        use crate::replay::{InputRecording, InputReplay};
+        use std::cell::Cell;
+
+        let script = InputRecording::parse("0 key j\n10 key j\n20 key k\n30 key j").unwrap();
+        let count = Cell::new(0);
+        let result = App::with_writer(Vec::new(), AppConfig::default())
+            .unwrap()
+            .with_replay(InputReplay::new(script.clone()).instant())
+            .with_recording()
+            .run(
+                |_| Element::text(format!("{}", count.get())),
+                |_, key| match key {
+                    k if k.is_char('j') => count.set(count.get() + 1),
+                    k if k.is_char('k') => count.set(count.get() - 1),
+                    _ => {}
+                },
+            )
+            .unwrap();
+        assert_eq!(count.get(), 2);
+        assert_eq!(result.exit_reason, ExitReason::Completed);
+        let recording = result.recording.unwrap();
+        assert_eq!(recording.len(), script.len());
+        assert_eq!(recording.events()[3].event, script.events()[3].event);
+    }
+
+    #[test]
+    fn test_app_gestures_from_replay() {
+        use crate::replay::{InputRecording, InputReplay};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let script = InputRecording::parse(
+            "0 mouse left-down 3,1\n0 mouse left-up 3,1\n10 mouse left-down 3,1\n\
+             10 mouse left-up 3,1\n20 mouse left-down 0,0\n30 mouse left-drag 2,0\n\
+             40 mouse left-up 2,0",
+        )
+        .unwrap();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let log = seen.clone();
+        let mut app = App::with_writer(Vec::new(), AppConfig::default())
+            .unwrap()
+            .with_replay(InputReplay::new(script).instant());
+        app.on_gesture(GestureDetector::new(), move |_, gesture| {
+            log.borrow_mut().push(gesture)
+        });
+        app.run(|_| Element::text(""), |_, _| {}).unwrap();
+
+        let seen = seen.borrow();
+        assert_eq!(seen.len(), 4);
+        assert!(matches!(seen[0], Gesture::Click { .. }));
+        assert!(matches!(seen[1], Gesture::DoubleClick { .. }));
+        assert!(matches!(seen[2], Gesture::DragStart { .. }));
+        assert_eq!(seen[3].origin(), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_app_scroll_goes_to_component_under_pointer() {
+        use crate::components::{Box as BoxComponent, BoxProps, Select, SelectProps, SelectState};
+        use crate::replay::{InputRecording, InputReplay};
+        use crate::FlexDirection;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        // A header line over two lists side by side:
+        //
+        //   header
+        //   ❯ a  ❯ a
+        //     b    b
+        //     c    c
+        let script = InputRecording::parse(
+            "0 mouse scroll-down 1,2\n10 mouse scroll-down 1,3\n20 mouse scroll-down 6,1\n\
+             30 mouse scroll-up 6,2\n40 mouse scroll-down 6,3\n50 mouse scroll-down 1,0",
+        )
+        .unwrap();
+        let left = Rc::new(RefCell::new(SelectState::new(3)));
+        let right = Rc::new(RefCell::new(SelectState::new(3)));
+        let mut app = App::with_writer(Vec::new(), AppConfig::default())
+            .unwrap()
+            .with_replay(InputReplay::new(script).instant());
+        let state = left.clone();
+        app.on_scroll("left", move |_, wheel| {
+            state.borrow_mut().handle_wheel(&wheel);
+        });
+        let state = right.clone();
+        app.on_scroll("right", move |_, wheel| {
+            state.borrow_mut().handle_wheel(&wheel);
+        });
+        let (l, r) = (left.clone(), right.clone());
+        app.run(
+            move |_| {
+                let list = |id: &str, selected: usize| {
+                    let props = SelectProps::new(vec!["a", "b", "c"])
+                        .selected(selected)
+                        .scroll_id(id);
+                    Element::node::<Select>(props, vec![])
+                };
+                let lists = Element::node::<BoxComponent>(
+                    BoxProps {
+                        flex_direction: FlexDirection::Row,
+                        gap: 2.0,
+                        ..Default::default()
+                    },
+                    vec![
+                        list("left", l.borrow().selected),
+                        list("right", r.borrow().selected),
+                    ],
+                );
+                Element::column(vec![Element::text("header"), lists])
+            },
+            |_, _| {},
+        )
+        .unwrap();
+
+        assert_eq!(left.borrow().selected, 2);
+        assert_eq!(right.borrow().selected, 1);
+    }
+
+    #[test]
+    fn test_app_focus_changes() {
+        use crate::replay::{InputRecording, InputReplay};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        // A repeated report isn't a change
+        let script =
+            InputRecording::parse("0 focus out\n10 focus out\n20 focus in\n30 key a").unwrap();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let log = seen.clone();
+        let rendered = Rc::new(RefCell::new(Vec::new()));
+        let frames = rendered.clone();
+        let mut app = App::with_writer(Vec::new(), AppConfig::default())
+            .unwrap()
+            .with_replay(InputReplay::new(script).instant());
+        app.on_focus(move |_, focused| log.borrow_mut().push(focused));
+        app.run(
+            move |app| {
+                frames.borrow_mut().push(app.is_focused());
+                Element::text("")
+            },
+            |_, _| {},
+        )
+        .unwrap();
+        assert_eq!(*seen.borrow(), vec![false, true]);
+        assert_eq!(*rendered.borrow(), vec![true, false, true, true]);
+    }
+
+    #[test]
+    fn test_app_paste_into_text_input() {
+        use crate::components::TextInputState;
+        use crate::replay::{InputRecording, InputReplay};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let script = InputRecording::parse("0 key a\n10 paste \"b c\\nd\\n\"\n20 key e").unwrap();
+        let input = Rc::new(RefCell::new(TextInputState::new()));
+        let state = input.clone();
+        let mut app = App::with_writer(Vec::new(), AppConfig::default())
+            .unwrap()
+            .with_replay(InputReplay::new(script).instant());
+        app.on_paste(move |_, text| {
+            state.borrow_mut().handle_paste(&text);
+        });
+        let state = input.clone();
+        app.run(
+            |_| Element::text(""),
+            move |_, key| {
+                state.borrow_mut().handle_key(&key);
+            },
+        )
+        .unwrap();
+        assert_eq!(input.borrow().value(), "ab c de");
+    }
+
     #[test]
     fn test_app_config_clone() {
         let config = AppConfig::default();
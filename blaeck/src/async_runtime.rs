@@ -19,6 +19,7 @@
 //! 4. Handle messages alongside keyboard events in the event loop
 
 use crate::element::Element;
+use crate::error::BlaeckError;
 use crate::input::Key;
 use crate::renderer::Blaeck;
 use crossterm::event::{Event, EventStream};
@@ -30,7 +31,7 @@ use tokio::sync::mpsc;
 use tokio::time::{interval, Interval};
 
 /// Result type for async operations.
-pub type Result<T> = std::io::Result<T>;
+pub use crate::error::Result;
 
 /// Messages that can be sent to the async app.
 #[derive(Debug)]
@@ -41,6 +42,9 @@ pub enum AppEvent<M> {
     Message(M),
     /// A tick event for periodic updates
     Tick,
+    /// The terminal window gained (true) or lost (false) focus, so the app
+    /// can pause animations or dim itself while in the background
+    Focus(bool),
     /// Request to exit the app
     Exit,
 }
@@ -86,6 +90,7 @@ impl Default for AsyncAppConfig {
 /// - Keyboard input
 /// - Messages from background async tasks
 /// - Periodic tick events
+/// - Terminal focus changes
 pub struct AsyncApp<W: Write, M: Send + 'static = ()> {
     blaeck: Blaeck<W>,
     config: AsyncAppConfig,
@@ -159,13 +164,15 @@ impl<W: Write, M: Send + 'static> AsyncApp<W, M> {
     /// Run the async event loop.
     ///
     /// - `render`: Function that returns the UI element tree
-    /// - `handle`: Function that handles events (keys, messages, ticks)
+    /// - `handle`: Function that handles events (keys, messages, ticks,
+    ///   focus changes)
     pub async fn run<R, H>(mut self, mut render: R, mut handle: H) -> Result<()>
     where
         R: FnMut(&mut Self) -> Element,
         H: FnMut(&mut Self, AppEvent<M>),
     {
-        enable_raw_mode()?;
+        enable_raw_mode().map_err(|e| BlaeckError::terminal("enabling raw mode", e))?;
+        self.blaeck.set_focus_reporting(true)?;
 
         // Initial render
         let ui = render(&mut self);
@@ -196,6 +203,8 @@ impl<W: Write, M: Send + 'static> AsyncApp<W, M> {
                             }
                             Some(AppEvent::Key(key))
                         }
+                        Some(Ok(Event::FocusGained)) => Some(AppEvent::Focus(true)),
+                        Some(Ok(Event::FocusLost)) => Some(AppEvent::Focus(false)),
                         Some(Ok(_)) => None, // Ignore other events (mouse, resize, etc.)
                         Some(Err(_)) => None,
                         None => {
@@ -232,7 +241,8 @@ impl<W: Write, M: Send + 'static> AsyncApp<W, M> {
         }
 
         // Cleanup
-        disable_raw_mode()?;
+        self.blaeck.set_focus_reporting(false)?;
+        disable_raw_mode().map_err(|e| BlaeckError::terminal("disabling raw mode", e))?;
         self.blaeck.unmount()?;
 
         Ok(())
@@ -244,7 +254,7 @@ impl<W: Write, M: Send + 'static> AsyncApp<W, M> {
         R: FnMut(&mut Self) -> Element,
         H: FnMut(&mut Self, Key),
     {
-        enable_raw_mode()?;
+        enable_raw_mode().map_err(|e| BlaeckError::terminal("enabling raw mode", e))?;
 
         let ui = render(&mut self);
         self.blaeck.render(ui)?;
@@ -273,7 +283,7 @@ impl<W: Write, M: Send + 'static> AsyncApp<W, M> {
             }
         }
 
-        disable_raw_mode()?;
+        disable_raw_mode().map_err(|e| BlaeckError::terminal("disabling raw mode", e))?;
         self.blaeck.unmount()?;
         Ok(())
     }
@@ -288,7 +298,7 @@ pub async fn poll_key_async(timeout: Duration) -> Result<Option<Key>> {
             match maybe_event {
                 Some(Ok(Event::Key(key_event))) => Ok(Some(Key::from(key_event))),
                 Some(Ok(_)) => Ok(None),
-                Some(Err(e)) => Err(e),
+                Some(Err(e)) => Err(e.into()),
                 None => Ok(None),
             }
         }
@@ -306,12 +316,11 @@ pub async fn read_key_async() -> Result<Key> {
         match event_stream.next().await {
             Some(Ok(Event::Key(key_event))) => return Ok(Key::from(key_event)),
             Some(Ok(_)) => continue,
-            Some(Err(e)) => return Err(e),
+            Some(Err(e)) => return Err(e.into()),
             None => {
-                return Err(io::Error::new(
-                    io::ErrorKind::UnexpectedEof,
-                    "Event stream ended",
-                ))
+                return Err(
+                    io::Error::new(io::ErrorKind::UnexpectedEof, "Event stream ended").into(),
+                )
             }
         }
     }
@@ -331,7 +340,7 @@ where
     R: FnMut(&S) -> Element,
     U: FnMut(&mut S) -> std::future::Ready<bool>,
 {
-    enable_raw_mode()?;
+    enable_raw_mode().map_err(|e| BlaeckError::terminal("enabling raw mode", e))?;
 
     let mut event_stream = EventStream::new();
     let mut tick = interval(Duration::from_millis(50));
@@ -360,7 +369,7 @@ where
         }
     }
 
-    disable_raw_mode()?;
+    disable_raw_mode().map_err(|e| BlaeckError::terminal("disabling raw mode", e))?;
     blaeck.unmount()?;
     Ok(())
 }
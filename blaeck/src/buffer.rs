@@ -67,6 +67,15 @@ impl Cell {
         self.symbol = symbol.to_string();
     }
 
+    /// Returns the cell's style (fg, bg, modifiers).
+    pub fn style(&self) -> Style {
+        Style {
+            fg: self.fg,
+            bg: self.bg,
+            modifiers: self.modifiers,
+        }
+    }
+
     /// Resets the cell to default state.
     pub fn reset(&mut self) {
         *self = Self::default();
@@ -139,15 +148,13 @@ impl Buffer {
     /// Each character in the string occupies one cell.
     /// Writing stops at the edge of the buffer.
     pub fn set_string(&mut self, x: u16, y: u16, text: &str, style: Style) {
-        let mut current_x = x;
-        for ch in text.chars() {
+        for (current_x, ch) in (x..).zip(text.chars()) {
             if current_x >= self.width {
                 break;
             }
             let cell = self.get_mut(current_x, y);
             cell.set_symbol(&ch.to_string());
             cell.set_style(style);
-            current_x += 1;
         }
     }
 
@@ -173,6 +180,67 @@ impl Buffer {
 
         changes
     }
+
+    /// Encodes the cells that changed between two frames as terminal output.
+    ///
+    /// The sequence assumes the frame occupies the `new.height()` lines
+    /// directly above the cursor, with the cursor at column 0 of the line
+    /// below it (where [`LogUpdate`](crate::log_update::LogUpdate) leaves it).
+    /// It moves to each run of changed cells, rewrites them, and returns the
+    /// cursor to where it started. Identical frames produce an empty string.
+    ///
+    /// Empty symbols mark the second column of a wide character and are skipped;
+    /// the wide character itself covers them.
+    pub fn diff_ansi(old: &Buffer, new: &Buffer) -> String {
+        let changes = Self::diff(old, new);
+        if changes.is_empty() {
+            return String::new();
+        }
+
+        let height = new.height;
+        let mut out = String::new();
+        let mut row = height;
+        let mut col: u16 = 0;
+        let mut current_style: Option<Style> = None;
+
+        for (x, y, cell) in changes {
+            if cell.symbol.is_empty() {
+                continue;
+            }
+            if y != row {
+                if y < row {
+                    out.push_str(&format!("\x1b[{}A", row - y));
+                } else {
+                    out.push_str(&format!("\x1b[{}B", y - row));
+                }
+                row = y;
+                col = u16::MAX;
+            }
+            if x != col {
+                out.push_str(&format!("\x1b[{}G", x + 1));
+            }
+
+            let style = cell.style();
+            if Some(style) != current_style {
+                if current_style.is_some() {
+                    out.push_str(&Style::reset_ansi());
+                }
+                out.push_str(&style.to_ansi_string());
+                current_style = Some(style);
+            }
+            out.push_str(&cell.symbol);
+            col = x + unicode_width::UnicodeWidthStr::width(cell.symbol.as_str()).max(1) as u16;
+        }
+
+        if current_style.is_some() {
+            out.push_str(&Style::reset_ansi());
+        }
+        if row < height {
+            out.push_str(&format!("\x1b[{}B", height - row));
+        }
+        out.push_str("\x1b[0G");
+        out
+    }
 }
 
 #[cfg(test)]
@@ -267,4 +335,26 @@ mod tests {
         let diff = Buffer::diff(&old, &new);
         assert_eq!(diff.len(), 3);
     }
+
+    #[test]
+    fn test_buffer_diff_ansi_identical() {
+        let a = Buffer::new(10, 3);
+        assert_eq!(Buffer::diff_ansi(&a, &a.clone()), "");
+    }
+
+    #[test]
+    fn test_buffer_diff_ansi_moves_and_returns() {
+        let old = Buffer::new(10, 3);
+        let mut new = Buffer::new(10, 3);
+        new.set_string(2, 1, "ab", Style::default());
+        new.set(0, 2, Cell::new("Z").fg(Color::Red));
+        let patch = Buffer::diff_ansi(&old, &new);
+        // Up two rows to row 1, column 3, write the run
+        assert!(patch.starts_with("\x1b[2A\x1b[3Gab"));
+        // Down to row 2, column 1, styled cell
+        assert!(patch.contains("\x1b[1B\x1b[1G"));
+        assert!(patch.contains("Z"));
+        // Back below the frame at column 0
+        assert!(patch.ends_with("\x1b[1B\x1b[0G"));
+    }
 }
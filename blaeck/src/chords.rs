@@ -0,0 +1,308 @@
+//! Multi-key bindings such as `g g`, `d d` or `space f`.
+//!
+//! A [`KeySequence`] is a list of [`KeyPattern`]s written with spaces
+//! between them. A [`ChordState`] remembers the keys pressed so far and
+//! decides, for each new key, whether it completes a sequence, starts or
+//! continues one, or has nothing to do with any of them:
+//!
+//! ```ignore
+//! let mut chords = ChordState::new().leader("space")?;
+//!
+//! match_key(&key, &mut app)
+//!     .on_sequences(&mut chords, &["g g", "d d", "leader f"], |app, i| match i {
+//!         0 => app.top(),
+//!         1 => app.delete_line(),
+//!         _ => app.find(),
+//!     })
+//!     .on_char('j', |app| app.down());
+//!
+//! // In the render function, show what's been typed so far
+//! Element::node::<PendingKeys>(chords.indicator(), vec![])
+//! ```
+//!
+//! Keys that start a sequence are consumed while it's pending. A pending
+//! sequence is dropped after the timeout (one second by default) or when a
+//! key doesn't continue it; that key is then tried on its own. A sequence
+//! completes as soon as its last key is pressed, so a sequence that is the
+//! start of a longer one (`g` and `g g`) shadows the longer one.
+
+use crate::components::PendingKeysProps;
+use crate::input::{Key, KeyPattern, KeyPatternError};
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Default time allowed between the keys of a sequence.
+pub const DEFAULT_CHORD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// A sequence of key bindings pressed one after another, such as `"g g"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeySequence(Vec<KeyPattern>);
+
+impl KeySequence {
+    /// A sequence of the given keys.
+    pub fn new(keys: Vec<KeyPattern>) -> Self {
+        Self(keys)
+    }
+
+    /// Parse a sequence in which the word `leader` stands for `leader`.
+    pub fn parse_with_leader(s: &str, leader: Option<KeyPattern>) -> Result<Self, KeyPatternError> {
+        let keys = s
+            .split_whitespace()
+            .map(|step| match (step.eq_ignore_ascii_case("leader"), leader) {
+                (true, Some(leader)) => Ok(leader),
+                (true, None) => Err(KeyPatternError(format!("no leader key set for {s:?}"))),
+                (false, _) => step.parse(),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if keys.is_empty() {
+            return Err(KeyPatternError("empty key sequence".into()));
+        }
+        Ok(Self(keys))
+    }
+
+    /// The keys, in the order they're pressed.
+    pub fn keys(&self) -> &[KeyPattern] {
+        &self.0
+    }
+
+    /// Whether `keys` are the start of this sequence (or all of it).
+    pub fn starts_with(&self, keys: &[Key]) -> bool {
+        keys.len() <= self.0.len() && self.0.iter().zip(keys).all(|(p, k)| p.matches(k))
+    }
+
+    /// Whether `keys` are exactly this sequence.
+    pub fn matches(&self, keys: &[Key]) -> bool {
+        keys.len() == self.0.len() && self.starts_with(keys)
+    }
+
+    /// Whether `other` is the same sequence spelled differently, such as
+    /// `"g G"` and `"g shift+g"`.
+    pub fn same_binding(&self, other: &KeySequence) -> bool {
+        self.0.len() == other.0.len() && self.is_prefix_of(other)
+    }
+
+    /// Whether pressing this sequence is also the start of `other` (or all
+    /// of it), so `other` can never complete when both are bound.
+    pub fn is_prefix_of(&self, other: &KeySequence) -> bool {
+        self.0.len() <= other.0.len() && self.0.iter().zip(&other.0).all(|(a, b)| a.same_binding(b))
+    }
+}
+
+impl FromStr for KeySequence {
+    type Err = KeyPatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_leader(s, None)
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, key) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{key}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<KeyPattern> for KeySequence {
+    fn from(pattern: KeyPattern) -> Self {
+        Self(vec![pattern])
+    }
+}
+
+/// What a key did to the pending sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChordMatch {
+    /// The key completed the sequence at this index.
+    Matched(usize),
+    /// The key started or continued a sequence; wait for more keys.
+    Pending,
+    /// The key isn't part of any sequence.
+    NoMatch,
+}
+
+/// Keys pressed so far towards a [`KeySequence`].
+#[derive(Debug, Clone)]
+pub struct ChordState {
+    pending: Vec<Key>,
+    last: Option<Instant>,
+    timeout: Duration,
+    leader: Option<KeyPattern>,
+}
+
+impl Default for ChordState {
+    fn default() -> Self {
+        Self {
+            pending: Vec::new(),
+            last: None,
+            timeout: DEFAULT_CHORD_TIMEOUT,
+            leader: None,
+        }
+    }
+}
+
+impl ChordState {
+    /// Create a state with nothing pending and the default timeout.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the time allowed between keys.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the key that `leader` stands for in sequences (e.g. `"space"`).
+    pub fn leader(mut self, pattern: &str) -> Result<Self, KeyPatternError> {
+        self.leader = Some(pattern.parse()?);
+        Ok(self)
+    }
+
+    /// The leader key, if set.
+    pub fn leader_key(&self) -> Option<KeyPattern> {
+        self.leader
+    }
+
+    /// Parse `s` as a sequence, with `leader` standing for the leader key.
+    pub fn sequence(&self, s: &str) -> Result<KeySequence, KeyPatternError> {
+        KeySequence::parse_with_leader(s, self.leader)
+    }
+
+    /// Keys pressed so far, oldest first.
+    pub fn pending(&self) -> &[Key] {
+        &self.pending
+    }
+
+    /// Whether a sequence has been started.
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Drop the pending keys.
+    pub fn clear(&mut self) {
+        self.pending.clear();
+        self.last = None;
+    }
+
+    /// Drop the pending keys if the timeout has passed since the last one.
+    /// Returns whether they were dropped, so the indicator can be hidden.
+    pub fn poll(&mut self, now: Instant) -> bool {
+        let expired = self
+            .last
+            .is_some_and(|last| now.saturating_duration_since(last) >= self.timeout);
+        if expired {
+            self.clear();
+        }
+        expired
+    }
+
+    /// Feed a key pressed at `now` and see what it does to `sequences`.
+    ///
+    /// Releases are ignored and leave the pending keys alone.
+    pub fn feed<'s>(
+        &mut self,
+        key: &Key,
+        now: Instant,
+        sequences: impl IntoIterator<Item = &'s KeySequence> + Clone,
+    ) -> ChordMatch {
+        if key.is_release() {
+            return ChordMatch::NoMatch;
+        }
+        self.poll(now);
+        let had_pending = self.is_pending();
+        self.pending.push(key.clone());
+        let mut result = self.step(sequences.clone());
+        if result == ChordMatch::NoMatch && had_pending {
+            // The key broke the sequence; try it on its own
+            self.pending = vec![key.clone()];
+            result = self.step(sequences);
+        }
+        match result {
+            ChordMatch::Pending => self.last = Some(now),
+            _ => self.clear(),
+        }
+        result
+    }
+
+    fn step<'s>(&self, sequences: impl IntoIterator<Item = &'s KeySequence>) -> ChordMatch {
+        let mut result = ChordMatch::NoMatch;
+        for (i, sequence) in sequences.into_iter().enumerate() {
+            if sequence.matches(&self.pending) {
+                return ChordMatch::Matched(i);
+            }
+            if sequence.starts_with(&self.pending) {
+                result = ChordMatch::Pending;
+            }
+        }
+        result
+    }
+
+    /// Props for a [`PendingKeys`](crate::components::PendingKeys)
+    /// indicator showing the keys pressed so far.
+    pub fn indicator(&self) -> PendingKeysProps {
+        PendingKeysProps::new(
+            self.pending
+                .iter()
+                .map(|key| KeyPattern::from(key).to_string()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyCode;
+
+    fn seqs(chords: &ChordState, list: &[&str]) -> Vec<KeySequence> {
+        list.iter().map(|s| chords.sequence(s).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_key_sequence_parse() {
+        let seq: KeySequence = "g  ctrl+x".parse().unwrap();
+        assert_eq!(seq.keys().len(), 2);
+        assert_eq!(seq.to_string(), "g ctrl+x");
+        assert!("".parse::<KeySequence>().is_err());
+        assert!("leader f".parse::<KeySequence>().is_err());
+
+        let chords = ChordState::new().leader("space").unwrap();
+        assert_eq!(chords.sequence("leader f").unwrap().to_string(), "space f");
+    }
+
+    #[test]
+    fn test_chord_state_feed() {
+        let mut chords = ChordState::new();
+        let list = seqs(&chords, &["g g", "d d", "x"]);
+        let t0 = Instant::now();
+        let g = Key::new(KeyCode::Char('g'));
+        let d = Key::new(KeyCode::Char('d'));
+
+        assert_eq!(chords.feed(&g, t0, &list), ChordMatch::Pending);
+        assert_eq!(chords.indicator().keys, ["g"]);
+        assert_eq!(chords.feed(&g, t0, &list), ChordMatch::Matched(0));
+        assert!(!chords.is_pending());
+
+        // A key that breaks the sequence starts over on its own
+        assert_eq!(chords.feed(&g, t0, &list), ChordMatch::Pending);
+        assert_eq!(chords.feed(&d, t0, &list), ChordMatch::Pending);
+        assert_eq!(chords.indicator().keys, ["d"]);
+        let x = Key::new(KeyCode::Char('x'));
+        assert_eq!(chords.feed(&x, t0, &list), ChordMatch::Matched(2));
+        let q = Key::new(KeyCode::Char('q'));
+        assert_eq!(chords.feed(&q, t0, &list), ChordMatch::NoMatch);
+
+        // Too slow: the first key is forgotten
+        assert_eq!(chords.feed(&d, t0, &list), ChordMatch::Pending);
+        let late = t0 + DEFAULT_CHORD_TIMEOUT;
+        assert_eq!(chords.feed(&d, late, &list), ChordMatch::Pending);
+        assert!(chords.poll(late + DEFAULT_CHORD_TIMEOUT));
+        assert!(!chords.is_pending());
+    }
+}
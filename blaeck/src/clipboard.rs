@@ -0,0 +1,69 @@
+//! Clipboard access through the terminal (OSC 52).
+//!
+//! Most modern terminals (kitty, iTerm2, WezTerm, foot, Windows Terminal,
+//! xterm with `allowWindowOps`, tmux with `set-clipboard on`) accept an
+//! OSC 52 escape that sets the system clipboard. This works over SSH too,
+//! since the escape travels with the output.
+//!
+//! ```ignore
+//! blaeck::clipboard::copy(&mut std::io::stdout(), "error: connection refused")?;
+//! ```
+//!
+//! Terminals without support ignore the sequence. [`is_supported`] spots
+//! the common ones (the Linux console, macOS Terminal), and
+//! [`Blaeck::copy_to_clipboard`](crate::Blaeck::copy_to_clipboard) reports
+//! them as unsupported instead of copying nothing.
+
+use crate::graphics::base64_encode;
+use std::io::{self, Write};
+
+/// Returns the OSC 52 escape that sets the clipboard to `text`.
+pub fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64_encode(text.as_bytes()))
+}
+
+/// Whether the current terminal may accept OSC 52, going by `TERM` and
+/// `TERM_PROGRAM`. See [`is_supported_by`].
+pub fn is_supported() -> bool {
+    is_supported_by(
+        std::env::var("TERM").ok().as_deref(),
+        std::env::var("TERM_PROGRAM").ok().as_deref(),
+    )
+}
+
+/// Whether a terminal with these `TERM` and `TERM_PROGRAM` values may
+/// accept OSC 52. Only terminals known to ignore it are ruled out.
+pub fn is_supported_by(term: Option<&str>, term_program: Option<&str>) -> bool {
+    !matches!(term, Some("dumb") | Some("linux")) && term_program != Some("Apple_Terminal")
+}
+
+/// Writes `text` to the system clipboard via OSC 52.
+pub fn copy<W: Write>(writer: &mut W, text: &str) -> io::Result<()> {
+    writer.write_all(osc52(text).as_bytes())?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osc52() {
+        assert_eq!(osc52("hi"), "\x1b]52;c;aGk=\x07");
+        let mut out = Vec::new();
+        copy(&mut out, "").unwrap();
+        assert_eq!(out, b"\x1b]52;c;\x07");
+    }
+
+    #[test]
+    fn test_is_supported_by() {
+        assert!(is_supported_by(Some("xterm-256color"), None));
+        assert!(is_supported_by(None, Some("iTerm.app")));
+        assert!(!is_supported_by(Some("linux"), None));
+        assert!(!is_supported_by(Some("dumb"), None));
+        assert!(!is_supported_by(
+            Some("xterm-256color"),
+            Some("Apple_Terminal")
+        ));
+    }
+}
@@ -4,6 +4,11 @@
 //! of suggestions that filter as you type. Supports multiple filter modes:
 //! Contains, StartsWith, Fuzzy, and None.
 //!
+//! By default the suggestions are drawn as rows under the input, pushing
+//! whatever follows down. With [`AutocompleteProps::floating`] the input
+//! renders alone and [`AutocompleteProps::render_suggestions`] floats the
+//! list over the UI next to it, above the input when there's no room below.
+//!
 //! ## When to use Autocomplete
 //!
 //! - Large option lists where typing is faster than scrolling
@@ -14,9 +19,12 @@
 //!
 //! - [`TextInput`](super::TextInput) — Plain text input (no suggestions)
 //! - [`Select`](super::Select) — Small fixed lists (no typing needed)
+//! - [`overlay`](crate::overlay) — The positioning used to float the list
 
 use crate::element::{Component, Element};
+use crate::overlay::{float, Positioner, Rect, Side};
 use crate::style::{Color, Style};
+use unicode_width::UnicodeWidthStr;
 
 /// A suggestion item for autocomplete.
 #[derive(Debug, Clone)]
@@ -102,6 +110,10 @@ pub struct AutocompleteProps {
     pub highlight_color: Option<Color>,
     /// Whether to highlight matched characters.
     pub highlight_matches: bool,
+    /// Whether the suggestions are floated with
+    /// [`AutocompleteProps::render_suggestions`] instead of drawn under the
+    /// input.
+    pub floating: bool,
 }
 
 impl Default for AutocompleteProps {
@@ -122,6 +134,7 @@ impl Default for AutocompleteProps {
             unselected_color: None,
             highlight_color: Some(Color::Yellow),
             highlight_matches: false,
+            floating: false,
         }
     }
 }
@@ -217,6 +230,14 @@ impl AutocompleteProps {
         self
     }
 
+    /// Float the suggestions instead of drawing them under the input. See
+    /// [`AutocompleteProps::render_suggestions`].
+    #[must_use]
+    pub fn floating(mut self, floating: bool) -> Self {
+        self.floating = floating;
+        self
+    }
+
     /// Get filtered suggestions based on current input.
     pub fn filtered_suggestions(&self) -> Vec<&AutocompleteItem> {
         if self.input.is_empty() || self.filter_mode == FilterMode::None {
@@ -275,26 +296,70 @@ impl AutocompleteProps {
         }
     }
 
+    /// Build the suggestion rows, if they're showing.
+    fn suggestion_lines(&self) -> Vec<String> {
+        if !self.show_suggestions || !self.focused {
+            return Vec::new();
+        }
+        self.filtered_suggestions()
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let indicator = if i == self.selected { "❯" } else { " " };
+                format!("{} {}", indicator, item.label)
+            })
+            .collect()
+    }
+
     /// Build the display strings for rendering.
     pub fn render_lines(&self) -> Vec<String> {
-        let mut lines = Vec::new();
-
-        // Input line
-        lines.push(self.render_input());
-
-        // Suggestions (if showing and there are matches)
-        if self.show_suggestions && self.focused {
-            let filtered = self.filtered_suggestions();
-            if !filtered.is_empty() {
-                for (i, item) in filtered.iter().enumerate() {
-                    let indicator = if i == self.selected { "❯" } else { " " };
-                    lines.push(format!("{} {}", indicator, item.label));
-                }
-            }
+        let mut lines = vec![self.render_input()];
+        if !self.floating {
+            lines.extend(self.suggestion_lines());
         }
-
         lines
     }
+
+    /// The suggestion list floated next to the input at `input` inside
+    /// `bounds`: below it, or above when there's more room there, and slid
+    /// left to stay on screen. When neither side fits the whole list it's
+    /// cut to the rows around the selection. Returns [`Element::Empty`] when
+    /// there's nothing to show.
+    ///
+    /// Use with [`AutocompleteProps::floating`]. The float must be a child
+    /// of a Box that starts at the origin of `bounds` (usually the root).
+    pub fn render_suggestions(&self, input: Rect, bounds: Rect) -> Element {
+        let lines = self.suggestion_lines();
+        if lines.is_empty() {
+            return Element::Empty;
+        }
+        let width = lines.iter().map(|line| line.width()).max().unwrap_or(0);
+        let size = (
+            width.min(usize::from(u16::MAX)) as u16,
+            lines.len().min(usize::from(u16::MAX)) as u16,
+        );
+        let placement = Positioner::new(Side::Bottom).place(input, size, bounds);
+
+        // Keep the selected row in view when the list had to be cut
+        let rows = usize::from(placement.rect.height);
+        let start = (self.selected + 1)
+            .saturating_sub(rows)
+            .min(lines.len().saturating_sub(rows));
+        let content = lines[start..][..rows.min(lines.len() - start)].join("\n");
+        float(
+            placement,
+            Element::styled_text(&content, self.suggestion_style()),
+        )
+    }
+
+    /// Style of the suggestion rows.
+    fn suggestion_style(&self) -> Style {
+        let mut style = Style::new();
+        if let Some(color) = self.selected_color {
+            style = style.fg(color);
+        }
+        style
+    }
 }
 
 /// Check if needle chars appear in haystack in order (fuzzy match).
@@ -345,32 +410,10 @@ impl Component for Autocomplete {
     type Props = AutocompleteProps;
 
     fn render(props: &Self::Props) -> Element {
-        let lines = props.render_lines();
-        let filtered = props.filtered_suggestions();
-
-        // Build content with each line
-        let mut content_lines = Vec::new();
-
-        // Input line (first line)
-        content_lines.push(lines[0].clone());
-
-        // Suggestion lines
-        if props.show_suggestions && props.focused && !filtered.is_empty() {
-            for (i, item) in filtered.iter().enumerate() {
-                let indicator = if i == props.selected { "❯" } else { " " };
-                content_lines.push(format!("{} {}", indicator, item.label));
-            }
-        }
-
-        let content = content_lines.join("\n");
+        let content = props.render_lines().join("\n");
 
         // Use selected color for overall style when focused
-        let mut style = Style::new();
-        if let Some(color) = props.selected_color {
-            style = style.fg(color);
-        }
-
-        Element::styled_text(&content, style)
+        Element::styled_text(&content, props.suggestion_style())
     }
 }
 
@@ -623,6 +666,51 @@ mod tests {
         assert!(lines.len() >= 2); // Input + at least one suggestion
     }
 
+    #[test]
+    fn test_floating_suggestions_flip_above_input_at_bottom_edge() {
+        let mut blaeck = crate::renderer::Blaeck::with_size(Vec::new(), 20, 5).unwrap();
+        let props = AutocompleteProps::new(vec!["apple", "apricot", "banana"])
+            .input("ap")
+            .selected(1)
+            .floating(true);
+        assert_eq!(props.render_lines(), vec!["ap▏"]);
+
+        // The input is on the last row, so the list goes above it
+        let mut rows: Vec<Element> = (0..4).map(|i| Element::text(format!("row {i}"))).collect();
+        rows.push(Element::node::<Autocomplete>(props.clone(), vec![]));
+        rows.push(props.render_suggestions(Rect::new(0, 4, 20, 1), Rect::new(0, 0, 20, 5)));
+        blaeck
+            .render(Element::node::<crate::components::Box>(
+                crate::components::BoxProps::column()
+                    .with_width(20.0)
+                    .with_height(5.0),
+                rows,
+            ))
+            .unwrap();
+        assert_eq!(
+            blaeck.frame_lines(),
+            vec!["row 0", "row 1", "  apple", "❯ apricot", "ap▏"]
+        );
+
+        // Too tall for either side: cut to the rows around the selection
+        let props = props.selected(2).filter_mode(FilterMode::None);
+        let Element::Node {
+            props: boxed,
+            children,
+            ..
+        } = props.render_suggestions(Rect::new(0, 1, 20, 1), Rect::new(0, 0, 20, 3))
+        else {
+            panic!("Expected Node");
+        };
+        let boxed = boxed.downcast_ref::<crate::components::BoxProps>().unwrap();
+        assert_eq!((boxed.inset_top, boxed.height), (Some(2.0), Some(1.0)));
+        assert!(matches!(&children[0], Element::Text { content, .. } if content == "❯ banana"));
+        assert!(AutocompleteProps::new(Vec::<&str>::new())
+            .floating(true)
+            .render_suggestions(Rect::new(0, 0, 1, 1), Rect::new(0, 0, 20, 5))
+            .is_empty());
+    }
+
     #[test]
     fn test_state_insert() {
         let mut state = AutocompleteState::new();
@@ -9,6 +9,9 @@
 //! - Category tags
 //! - Count indicators (e.g., notification count)
 //!
+//! [`BadgeStyle::Auto`] picks the colors from the label itself, so the same
+//! tag or log level always gets the same color without assigning one.
+//!
 //! ## See also
 //!
 //! - [`StatusBar`](super::StatusBar) — Multiple status segments together
@@ -31,6 +34,49 @@ pub enum BadgeStyle {
     Pill,
     /// Filled style with background: ` label ` (inverted colors)
     Filled,
+    /// Filled, with the background derived from the label's hash and black
+    /// or white text, whichever reads better on it
+    Auto,
+}
+
+/// A stable color for `label`, the same on every run and platform.
+///
+/// The hue comes from an FNV-1a hash of the label; saturation and
+/// lightness are fixed so every label gets an equally strong color.
+pub fn badge_color(label: &str) -> Color {
+    let hash = label.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    let hue = (hash % 360) as f64;
+    let (saturation, lightness) = (0.55, 0.5);
+
+    // HSL to RGB
+    let chroma = (1.0 - (2.0 * lightness - 1.0f64).abs()) * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u32 / 60 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    let channel = |v: f64| ((v + m) * 255.0).round() as u8;
+    Color::Rgb(channel(r), channel(g), channel(b))
+}
+
+/// Black or white, whichever contrasts more with `bg`.
+fn readable_on(bg: Color) -> Color {
+    let Color::Rgb(r, g, b) = bg else {
+        return Color::White;
+    };
+    let luma = 0.299 * f64::from(r) + 0.587 * f64::from(g) + 0.114 * f64::from(b);
+    if luma > 150.0 {
+        Color::Black
+    } else {
+        Color::White
+    }
 }
 
 /// Properties for the Badge component.
@@ -114,8 +160,21 @@ impl BadgeProps {
             BadgeStyle::Bracket => format!("[{}]", self.text),
             BadgeStyle::Round => format!("({})", self.text),
             BadgeStyle::Pill => format!("‹{}›", self.text),
-            BadgeStyle::Filled => format!(" {} ", self.text),
+            BadgeStyle::Filled | BadgeStyle::Auto => format!(" {} ", self.text),
+        }
+    }
+
+    /// Foreground and background, with [`BadgeStyle::Auto`] filling in
+    /// whichever wasn't set explicitly.
+    fn colors(&self) -> (Option<Color>, Option<Color>) {
+        if self.style != BadgeStyle::Auto {
+            return (self.color, self.bg_color);
         }
+        let bg = self.bg_color.unwrap_or_else(|| badge_color(&self.text));
+        (
+            Some(self.color.unwrap_or_else(|| readable_on(bg))),
+            Some(bg),
+        )
     }
 }
 
@@ -147,11 +206,12 @@ impl Component for Badge {
     fn render(props: &Self::Props) -> Element {
         let content = props.render_string();
 
+        let (color, bg_color) = props.colors();
         let mut style = Style::new();
-        if let Some(color) = props.color {
+        if let Some(color) = color {
             style = style.fg(color);
         }
-        if let Some(bg) = props.bg_color {
+        if let Some(bg) = bg_color {
             style = style.bg(bg);
         }
         if props.bold {
@@ -229,4 +289,30 @@ mod tests {
             _ => panic!("Expected Text element"),
         }
     }
+
+    #[test]
+    fn test_badge_color_is_stable() {
+        assert_eq!(badge_color("bug"), badge_color("bug"));
+        assert_ne!(badge_color("bug"), badge_color("feature"));
+        assert_eq!(badge_color(""), Color::Rgb(158, 198, 57));
+    }
+
+    #[test]
+    fn test_badge_auto() {
+        let props = BadgeProps::new("WARN").badge_style(BadgeStyle::Auto);
+        assert_eq!(props.render_string(), " WARN ");
+        let Element::Text { style, .. } = Badge::render(&props) else {
+            panic!("Expected Text element");
+        };
+        assert_eq!(style.bg, badge_color("WARN"));
+        assert_eq!(style.fg, readable_on(badge_color("WARN")));
+
+        // Explicit colors still win
+        let props = props.color(Color::Yellow);
+        let Element::Text { style, .. } = Badge::render(&props) else {
+            panic!("Expected Text element");
+        };
+        assert_eq!(style.fg, Color::Yellow);
+        assert_eq!(readable_on(Color::Rgb(250, 250, 200)), Color::Black);
+    }
 }
@@ -1,5 +1,11 @@
 //! Bar Chart component for horizontal bar visualization.
 //!
+//! A bar can carry one value per series ([`BarData::multi`]); the chart's
+//! [`BarLayout`] stacks them end to end or draws them side by side, and the
+//! series names become a legend. Negative values extend the other way from
+//! the zero line, and [`BarOrientation::Vertical`] draws columns instead of
+//! rows.
+//!
 //! ## When to use BarChart
 //!
 //! - Comparing values across categories
@@ -11,6 +17,7 @@
 //! - [`Sparkline`](super::Sparkline) — Compact inline trend chart
 //! - [`Progress`](super::Progress) — Single progress value
 //! - [`Table`](super::Table) — Tabular data without visual bars
+//! - [`charts`](super::charts) — The scale, axis and legend used here
 //!
 //! # Example
 //!
@@ -30,10 +37,30 @@
 //!         .show_values(true),
 //!     vec![],
 //! )
+//!
+//! // Stacked, with a legend
+//! BarChartProps::new(vec![
+//!     BarData::multi("Q1", vec![30.0, 12.0]),
+//!     BarData::multi("Q2", vec![42.0, 18.0]),
+//! ])
+//! .series("Online", Color::Cyan)
+//! .series("Retail", Color::Magenta);
 //! ```
 
+use crate::components::charts::{Axis, Legend, Scale};
 use crate::element::{Component, Element};
 use crate::style::{Color, Modifier, Style};
+use unicode_width::UnicodeWidthStr;
+
+/// Colors for series without one of their own, in order.
+const SERIES_COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Yellow,
+    Color::Green,
+    Color::Blue,
+    Color::Red,
+];
 
 /// Data for a single bar.
 #[derive(Debug, Clone)]
@@ -44,6 +71,8 @@ pub struct BarData {
     pub value: f64,
     /// Optional color for this bar.
     pub color: Option<Color>,
+    /// One value per series; when set, these are drawn instead of `value`.
+    pub values: Vec<f64>,
 }
 
 impl BarData {
@@ -53,6 +82,18 @@ impl BarData {
             label: label.into(),
             value,
             color: None,
+            values: Vec::new(),
+        }
+    }
+
+    /// Create a bar with one value per series, stacked or grouped by the
+    /// chart's [`BarLayout`]. `value` is their sum.
+    pub fn multi(label: impl Into<String>, values: Vec<f64>) -> Self {
+        Self {
+            label: label.into(),
+            value: values.iter().sum(),
+            color: None,
+            values,
         }
     }
 
@@ -155,6 +196,35 @@ impl BarStyle {
     }
 }
 
+/// How a bar with several values draws them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BarLayout {
+    /// End to end in one bar: positives away from zero, negatives the other way.
+    #[default]
+    Stacked,
+    /// One bar per series, side by side.
+    Grouped,
+}
+
+/// Which way bars grow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BarOrientation {
+    /// Rows growing to the right, labels on the left.
+    #[default]
+    Horizontal,
+    /// Columns growing up, labels underneath.
+    Vertical,
+}
+
+/// A named series of a multi-value chart.
+#[derive(Debug, Clone)]
+pub struct BarSeries {
+    /// Series name, shown in the legend.
+    pub name: String,
+    /// Series color.
+    pub color: Color,
+}
+
 /// Value display format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ValueFormat {
@@ -222,6 +292,17 @@ pub struct BarChartProps {
     pub min_label_width: Option<usize>,
     /// Show a legend/scale.
     pub show_scale: bool,
+    /// Scale for bar lengths and the scale line. If None, linear from 0 to
+    /// the effective max value.
+    pub scale: Option<Scale>,
+    /// Legend shown under the chart. If None, built from `series`.
+    pub legend: Option<Legend>,
+    /// Series of multi-value bars, in value order.
+    pub series: Vec<BarSeries>,
+    /// How multi-value bars combine their values.
+    pub layout: BarLayout,
+    /// Which way bars grow. Vertical charts use `bar_width` as the height.
+    pub orientation: BarOrientation,
 }
 
 impl Default for BarChartProps {
@@ -240,6 +321,11 @@ impl Default for BarChartProps {
             label_gap: 1,
             min_label_width: None,
             show_scale: false,
+            scale: None,
+            legend: None,
+            series: Vec::new(),
+            layout: BarLayout::Stacked,
+            orientation: BarOrientation::Horizontal,
         }
     }
 }
@@ -344,14 +430,150 @@ impl BarChartProps {
         self
     }
 
+    /// Set the scale used for bar lengths, e.g. [`Scale::log`] for values
+    /// spanning several orders of magnitude.
+    #[must_use]
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Show a legend under the chart.
+    #[must_use]
+    pub fn legend(mut self, legend: Legend) -> Self {
+        self.legend = Some(legend);
+        self
+    }
+
+    /// Add a series for multi-value bars.
+    #[must_use]
+    pub fn series(mut self, name: impl Into<String>, color: Color) -> Self {
+        self.series.push(BarSeries {
+            name: name.into(),
+            color,
+        });
+        self
+    }
+
+    /// Set how multi-value bars combine their values.
+    #[must_use]
+    pub fn layout(mut self, layout: BarLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Set which way bars grow.
+    #[must_use]
+    pub fn orientation(mut self, orientation: BarOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Get the effective scale.
+    pub fn effective_scale(&self) -> Scale {
+        self.scale
+            .unwrap_or_else(|| Scale::linear(self.effective_min(), self.effective_max()))
+    }
+
     /// Get the effective max value.
     fn effective_max(&self) -> f64 {
-        self.max_value.unwrap_or_else(|| {
-            self.data
-                .iter()
-                .map(|d| d.value)
-                .fold(0.0, f64::max)
-                .max(1.0)
+        self.max_value.unwrap_or_else(|| self.extent().1.max(1.0))
+    }
+
+    /// Get the effective min value: zero, or lower for negative values.
+    fn effective_min(&self) -> f64 {
+        self.extent().0.min(0.0)
+    }
+
+    /// Lowest and highest points any bar reaches.
+    fn extent(&self) -> (f64, f64) {
+        let mut extent = (0.0f64, 0.0f64);
+        for bar in &self.data {
+            let values = self.parts(bar).into_iter().map(|(value, _)| value);
+            if self.layout == BarLayout::Stacked {
+                let (neg, pos) = values.fold((0.0, 0.0), |(neg, pos), v| {
+                    if v < 0.0 {
+                        (neg + v, pos)
+                    } else {
+                        (neg, pos + v)
+                    }
+                });
+                extent = (extent.0.min(neg), extent.1.max(pos));
+            } else {
+                for v in values {
+                    extent = (extent.0.min(v), extent.1.max(v));
+                }
+            }
+        }
+        extent
+    }
+
+    /// Color of the series at `index`.
+    fn series_color(&self, index: usize) -> Color {
+        self.series
+            .get(index)
+            .map(|s| s.color)
+            .unwrap_or(SERIES_COLORS[index % SERIES_COLORS.len()])
+    }
+
+    /// A bar's values with their colors.
+    fn parts(&self, bar: &BarData) -> Vec<(f64, Option<Color>)> {
+        if bar.values.is_empty() {
+            return vec![(bar.value, bar.color.or(self.default_color))];
+        }
+        bar.values
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| (v, Some(self.series_color(i))))
+            .collect()
+    }
+
+    /// The bars a data point draws as: one per series when grouped, else
+    /// one holding every segment. Each is its runs and the value to show.
+    fn tracks(&self, bar: &BarData) -> Vec<(Vec<Run>, f64)> {
+        let parts = self.parts(bar);
+        if self.layout == BarLayout::Grouped || parts.len() == 1 {
+            return parts
+                .into_iter()
+                .map(|(v, color)| (vec![(v.min(0.0), v.max(0.0), color)], v))
+                .collect();
+        }
+        let (mut pos, mut neg) = (0.0, 0.0);
+        let runs = parts
+            .into_iter()
+            .map(|(v, color)| {
+                if v < 0.0 {
+                    neg += v;
+                    (neg, neg - v, color)
+                } else {
+                    pos += v;
+                    (pos - v, pos, color)
+                }
+            })
+            .collect();
+        vec![(runs, bar.value)]
+    }
+
+    /// Which run, if any, fills each of `len` cells along the value axis.
+    fn fill_cells(scale: &Scale, len: usize, runs: &[Run]) -> Vec<Cell> {
+        let cell = |v: f64| (scale.normalize(v) * len as f64).round() as usize;
+        let mut cells = vec![None; len];
+        for &(from, to, color) in runs {
+            for slot in cells.iter_mut().take(cell(to)).skip(cell(from)) {
+                *slot = Some(color);
+            }
+        }
+        cells
+    }
+
+    /// The legend to show: the explicit one, or one naming the series.
+    fn effective_legend(&self) -> Option<Legend> {
+        self.legend.clone().or_else(|| {
+            (!self.series.is_empty()).then(|| {
+                self.series
+                    .iter()
+                    .fold(Legend::new(), |legend, s| legend.item(&s.name, s.color))
+            })
         })
     }
 
@@ -362,109 +584,299 @@ impl BarChartProps {
     }
 }
 
+/// A stretch of a bar from one value to another, in one color.
+type Run = (f64, f64, Option<Color>);
+
+/// A cell along the value axis: empty, or filled in a run's color.
+type Cell = Option<Option<Color>>;
+
 /// A component that displays horizontal bar charts.
 pub struct BarChart;
 
-impl Component for BarChart {
-    type Props = BarChartProps;
-
-    fn render(props: &Self::Props) -> Element {
-        if props.data.is_empty() {
-            return Element::Empty;
+impl BarChart {
+    /// Style with the chart background applied.
+    fn with_bg(props: &BarChartProps, mut style: Style) -> Style {
+        if let Some(bg) = props.bg_color {
+            style = style.bg(bg);
         }
+        style
+    }
 
-        let max_value = props.effective_max();
-        let label_width = props.max_label_width();
-
-        // Helper to apply bg_color to a style
-        let with_bg = |mut style: Style| -> Style {
-            if let Some(bg) = props.bg_color {
-                style = style.bg(bg);
-            }
-            style
-        };
-
-        let mut lines: Vec<Element> = Vec::new();
+    fn color_style(props: &BarChartProps, color: Option<Color>) -> Style {
+        Self::with_bg(props, color.map(|c| Style::new().fg(c)).unwrap_or_default())
+    }
 
-        for bar in &props.data {
-            let ratio = if max_value > 0.0 {
-                bar.value / max_value
+    fn value_style(props: &BarChartProps) -> Style {
+        Self::with_bg(
+            props,
+            if let Some(color) = props.value_color {
+                Style::new().fg(color)
             } else {
-                0.0
+                Style::new().add_modifier(Modifier::DIM)
+            },
+        )
+    }
+
+    /// Spans for a row of cells, one per run of equal cells.
+    fn cell_spans(
+        props: &BarChartProps,
+        cells: &[Cell],
+        filled: char,
+        empty: char,
+    ) -> Vec<Element> {
+        let mut spans = Vec::new();
+        let mut i = 0;
+        while i < cells.len() {
+            let cell = cells[i];
+            let len = cells[i..].iter().take_while(|&&c| c == cell).count();
+            let (ch, style) = match cell {
+                Some(color) => (filled, Self::color_style(props, color)),
+                None => (empty, Self::with_bg(props, Style::new())),
             };
+            spans.push(Element::styled_text(
+                std::iter::repeat_n(ch, len).collect::<String>(),
+                style,
+            ));
+            i += len;
+        }
+        spans
+    }
 
-            // Build the line
-            let mut segments: Vec<Element> = Vec::new();
+    fn render_horizontal(props: &BarChartProps, lines: &mut Vec<Element>) {
+        let scale = props.effective_scale();
+        let max_value = scale.max;
+        let label_width = props.max_label_width();
+        let with_bg = |style: Style| Self::with_bg(props, style);
 
-            // Label (right-aligned to label_width)
-            let label_padded = format!("{:>width$}", bar.label, width = label_width);
-            let label_style = with_bg(if let Some(color) = props.label_color {
-                Style::new().fg(color)
-            } else {
-                Style::new()
-            });
-            segments.push(Element::styled_text(&label_padded, label_style));
-
-            // Gap
-            segments.push(Element::styled_text(
-                " ".repeat(props.label_gap),
-                with_bg(Style::new()),
-            ));
+        for bar in &props.data {
+            for (track, (runs, value)) in props.tracks(bar).into_iter().enumerate() {
+                // Build the line
+                let mut segments: Vec<Element> = Vec::new();
+
+                // Label (right-aligned to label_width), on a group's first bar only
+                let label = if track == 0 { bar.label.as_str() } else { "" };
+                let label_padded = format!("{:>width$}", label, width = label_width);
+                segments.push(Element::styled_text(
+                    &label_padded,
+                    Self::color_style(props, props.label_color),
+                ));
+
+                // Gap
+                segments.push(Element::styled_text(
+                    " ".repeat(props.label_gap),
+                    with_bg(Style::new()),
+                ));
+
+                // Opening bracket
+                if props.brackets {
+                    segments.push(Element::styled_text("[", with_bg(Style::new())));
+                }
 
-            // Opening bracket
-            if props.brackets {
-                segments.push(Element::styled_text("[", with_bg(Style::new())));
-            }
+                // Bar: a single run from a left-edge zero keeps the style's
+                // partial blocks; anything else is drawn cell by cell
+                match runs[..] {
+                    [(from, to, color)] if from == 0.0 && scale.normalize(0.0) == 0.0 => {
+                        let ratio = if max_value > 0.0 {
+                            scale.normalize(to)
+                        } else {
+                            0.0
+                        };
+                        let bar_str = props.style.render(props.bar_width, ratio);
+                        segments.push(Element::styled_text(
+                            &bar_str,
+                            Self::color_style(props, color),
+                        ));
+                    }
+                    _ => {
+                        let cells = BarChartProps::fill_cells(&scale, props.bar_width, &runs);
+                        segments.extend(Self::cell_spans(
+                            props,
+                            &cells,
+                            props.style.filled_char(),
+                            props.style.empty_char(),
+                        ));
+                    }
+                }
 
-            // Bar
-            let bar_str = props.style.render(props.bar_width, ratio);
-            let bar_color = bar.color.or(props.default_color);
-            let bar_style = with_bg(if let Some(color) = bar_color {
-                Style::new().fg(color)
-            } else {
-                Style::new()
-            });
-            segments.push(Element::styled_text(&bar_str, bar_style));
+                // Closing bracket
+                if props.brackets {
+                    segments.push(Element::styled_text("]", with_bg(Style::new())));
+                }
 
-            // Closing bracket
-            if props.brackets {
-                segments.push(Element::styled_text("]", with_bg(Style::new())));
-            }
+                // Value
+                if props.value_format != ValueFormat::None {
+                    let value_str = props.value_format.format(value, max_value);
+                    segments.push(Element::styled_text(" ", with_bg(Style::new())));
+                    segments.push(Element::styled_text(&value_str, Self::value_style(props)));
+                }
 
-            // Value
-            if props.value_format != ValueFormat::None {
-                let value_str = props.value_format.format(bar.value, max_value);
-                let value_style = with_bg(if let Some(color) = props.value_color {
-                    Style::new().fg(color)
-                } else {
-                    Style::new().add_modifier(Modifier::DIM)
-                });
-                segments.push(Element::styled_text(" ", with_bg(Style::new())));
-                segments.push(Element::styled_text(&value_str, value_style));
+                // Combine segments into a line
+                lines.push(Element::Fragment(segments));
             }
-
-            // Combine segments into a line
-            lines.push(Element::Fragment(segments));
         }
 
         // Scale line
         if props.show_scale {
             let scale_offset = label_width + props.label_gap + if props.brackets { 1 } else { 0 };
+            let axis = Axis::new(scale);
             let mut scale_line = " ".repeat(scale_offset);
-            scale_line.push_str(&format!(
-                "0{:>width$}",
-                max_value,
-                width = props.bar_width - 1
+            scale_line.push_str(&axis.horizontal_line(props.bar_width));
+            lines.push(Element::styled_text(&scale_line, axis.style()));
+        }
+    }
+
+    /// Columns `bar_width` rows tall, one slot per data point with its
+    /// label centered underneath and, if shown, its total on top.
+    fn render_vertical(props: &BarChartProps, lines: &mut Vec<Element>) {
+        let scale = props.effective_scale();
+        let height = props.bar_width;
+        let filled = match props.style {
+            BarStyle::Thin | BarStyle::Gradient => '█',
+            style => style.filled_char(),
+        };
+
+        // Each data point's columns, cells bottom to top
+        let tracks: Vec<Vec<(Vec<Run>, f64)>> =
+            props.data.iter().map(|bar| props.tracks(bar)).collect();
+        let columns: Vec<Vec<Vec<Cell>>> = tracks
+            .iter()
+            .map(|bar| {
+                bar.iter()
+                    .map(|(runs, _)| BarChartProps::fill_cells(&scale, height, runs))
+                    .collect()
+            })
+            .collect();
+
+        // Grouped values sit over their own column, so columns widen to
+        // fit them, a space apart
+        let grouped_values =
+            props.value_format != ValueFormat::None && props.layout == BarLayout::Grouped;
+        let (track_width, gap) = if grouped_values {
+            let widest = tracks
+                .iter()
+                .flatten()
+                .map(|(_, value)| props.value_format.format(*value, scale.max).width())
+                .max()
+                .unwrap_or(1);
+            (widest.max(1), 1)
+        } else {
+            (1, 0)
+        };
+        let group_width = |n: usize| (n * (track_width + gap)).saturating_sub(gap);
+        let slot = columns
+            .iter()
+            .map(|bar| group_width(bar.len()))
+            .max()
+            .unwrap_or(1)
+            .max(props.max_label_width());
+
+        // Scale labels down the left, if shown
+        let axis = Axis::new(scale);
+        let axis_rows = if props.show_scale {
+            axis.vertical_labels(height)
+        } else {
+            vec![String::new(); height]
+        };
+        let margin = match axis_rows.first() {
+            Some(row) if props.show_scale => row.chars().count() + 1,
+            _ => 0,
+        };
+
+        if props.value_format != ValueFormat::None && props.layout == BarLayout::Stacked {
+            let values: Vec<String> = props
+                .data
+                .iter()
+                .map(|bar| {
+                    let value = props.value_format.format(bar.value, scale.max);
+                    format!("{:^slot$}", value, slot = slot)
+                })
+                .collect();
+            lines.push(Element::styled_text(
+                format!("{}{}", " ".repeat(margin), values.join(" ")),
+                Self::value_style(props),
             ));
-            if props.brackets {
-                scale_line.push(' ');
-            }
+        } else if grouped_values {
+            let values: Vec<String> = tracks
+                .iter()
+                .map(|bar| {
+                    let lead = (slot - group_width(bar.len())) / 2;
+                    let group: Vec<String> = bar
+                        .iter()
+                        .map(|(_, value)| {
+                            let value = props.value_format.format(*value, scale.max);
+                            format!("{:^track_width$}", value, track_width = track_width)
+                        })
+                        .collect();
+                    format!(
+                        "{:<slot$}",
+                        format!("{}{}", " ".repeat(lead), group.join(&" ".repeat(gap))),
+                        slot = slot
+                    )
+                })
+                .collect();
             lines.push(Element::styled_text(
-                &scale_line,
-                Style::new().add_modifier(Modifier::DIM),
+                format!("{}{}", " ".repeat(margin), values.join(" ")),
+                Self::value_style(props),
             ));
         }
 
+        for (row, axis_label) in axis_rows.iter().enumerate() {
+            let level = height - 1 - row;
+            let mut spans = Vec::new();
+            if props.show_scale {
+                spans.push(Element::styled_text(
+                    format!("{} ", axis_label),
+                    axis.style(),
+                ));
+            }
+            for (i, bar) in columns.iter().enumerate() {
+                let lead = (slot - group_width(bar.len())) / 2;
+                let mut cells = vec![None; slot];
+                for (col, column) in bar.iter().enumerate() {
+                    let start = lead + col * (track_width + gap);
+                    cells[start..start + track_width].fill(column[level]);
+                }
+                if i > 0 {
+                    spans.push(Element::styled_text(
+                        " ",
+                        Self::with_bg(props, Style::new()),
+                    ));
+                }
+                spans.extend(Self::cell_spans(props, &cells, filled, ' '));
+            }
+            lines.push(Element::Fragment(spans));
+        }
+
+        let labels: Vec<String> = props
+            .data
+            .iter()
+            .map(|bar| format!("{:^slot$}", bar.label, slot = slot))
+            .collect();
+        lines.push(Element::styled_text(
+            format!("{}{}", " ".repeat(margin), labels.join(" ")),
+            Self::color_style(props, props.label_color),
+        ));
+    }
+}
+
+impl Component for BarChart {
+    type Props = BarChartProps;
+
+    fn render(props: &Self::Props) -> Element {
+        if props.data.is_empty() {
+            return Element::Empty;
+        }
+
+        let mut lines: Vec<Element> = Vec::new();
+        match props.orientation {
+            BarOrientation::Horizontal => Self::render_horizontal(props, &mut lines),
+            BarOrientation::Vertical => Self::render_vertical(props, &mut lines),
+        }
+
+        if let Some(legend) = props.effective_legend().filter(|l| !l.is_empty()) {
+            lines.push(legend.render());
+        }
+
         if lines.len() == 1 {
             lines.remove(0)
         } else {
@@ -601,6 +1013,32 @@ mod tests {
         assert!(elem.is_fragment());
     }
 
+    #[test]
+    fn test_bar_chart_scale_line_and_legend() {
+        let data = vec![BarData::new("a", 10.0), BarData::new("b", 1000.0)];
+        let props = BarChartProps::new(data)
+            .scale(Scale::log(1.0, 1000.0))
+            .bar_width(12)
+            .show_scale(true)
+            .legend(Legend::new().item("req/s", Color::Cyan));
+        let Element::Fragment(lines) = BarChart::render(&props) else {
+            panic!("expected lines");
+        };
+        assert_eq!(lines.len(), 4);
+        // log10(10) is a third of the way to log10(1000)
+        let Element::Fragment(bar) = &lines[0] else {
+            panic!("expected a bar line");
+        };
+        match &bar[2] {
+            Element::Text { content, .. } => assert_eq!(content, "████        "),
+            _ => panic!("expected bar text"),
+        }
+        match &lines[2] {
+            Element::Text { content, .. } => assert_eq!(content, "  1  10 100 1k"),
+            _ => panic!("expected scale line"),
+        }
+    }
+
     #[test]
     fn test_bar_chart_helper() {
         let data = vec![BarData::new("Test", 50.0)];
@@ -614,4 +1052,107 @@ mod tests {
         let elem = bar_chart_with_values(data, 100.0);
         assert!(elem.is_fragment());
     }
+
+    fn line_text(line: &Element) -> String {
+        match line {
+            Element::Text { content, .. } => content.clone(),
+            Element::Fragment(spans) => spans.iter().map(line_text).collect(),
+            _ => String::new(),
+        }
+    }
+
+    fn chart_lines(props: &BarChartProps) -> Vec<String> {
+        match BarChart::render(props) {
+            Element::Fragment(lines) => lines.iter().map(line_text).collect(),
+            other => vec![line_text(&other)],
+        }
+    }
+
+    #[test]
+    fn test_bar_chart_stacked_with_legend() {
+        let props = BarChartProps::new(vec![
+            BarData::multi("a", vec![2.0, 3.0]),
+            BarData::multi("b", vec![1.0, 1.0]),
+        ])
+        .series("x", Color::Red)
+        .series("y", Color::Blue)
+        .style(BarStyle::Hash)
+        .bar_width(10)
+        .show_values();
+        let Element::Fragment(lines) = BarChart::render(&props) else {
+            panic!("expected lines");
+        };
+        assert_eq!(line_text(&lines[0]), "a ########## 5");
+        assert_eq!(line_text(&lines[1]), "b ####       2");
+        let Element::Fragment(spans) = &lines[0] else {
+            panic!("expected a bar line");
+        };
+        match (&spans[2], &spans[3]) {
+            (
+                Element::Text {
+                    content: x,
+                    style: sx,
+                },
+                Element::Text {
+                    content: y,
+                    style: sy,
+                },
+            ) => {
+                assert_eq!((x.as_str(), sx.fg), ("####", Color::Red));
+                assert_eq!((y.as_str(), sy.fg), ("######", Color::Blue));
+            }
+            _ => panic!("expected segment spans"),
+        }
+        assert_eq!(line_text(&lines[2]), "■ x  ■ y");
+    }
+
+    #[test]
+    fn test_bar_chart_grouped() {
+        let props = BarChartProps::new(vec![BarData::multi("a", vec![4.0, 2.0])])
+            .layout(BarLayout::Grouped)
+            .style(BarStyle::Hash)
+            .bar_width(4);
+        // One bar per series, labelled once; no series names, no legend
+        assert_eq!(chart_lines(&props), vec!["a ####", "  ##  "]);
+    }
+
+    #[test]
+    fn test_bar_chart_negative_values() {
+        let props = BarChartProps::new(vec![BarData::new("up", 20.0), BarData::new("dn", -10.0)])
+            .style(BarStyle::Hash)
+            .bar_width(12);
+        assert_eq!(props.effective_scale(), Scale::linear(-10.0, 20.0));
+        assert_eq!(
+            chart_lines(&props),
+            vec!["up     ########", "dn ####        "]
+        );
+    }
+
+    #[test]
+    fn test_bar_chart_vertical() {
+        let props = BarChartProps::new(vec![BarData::new("a", 2.0), BarData::new("b", 4.0)])
+            .orientation(BarOrientation::Vertical)
+            .style(BarStyle::Hash)
+            .bar_width(4);
+        assert_eq!(chart_lines(&props), vec!["  #", "  #", "# #", "# #", "a b"]);
+    }
+
+    #[test]
+    fn test_bar_chart_vertical_grouped_values() {
+        let props = BarChartProps::new(vec![
+            BarData::multi("a", vec![3.0, 5.0]),
+            BarData::multi("b", vec![2.0, 1.0]),
+        ])
+        .layout(BarLayout::Grouped)
+        .orientation(BarOrientation::Vertical)
+        .style(BarStyle::Hash)
+        .max_value(5.0)
+        .show_values()
+        .bar_width(5);
+        // One value over each column
+        assert_eq!(
+            chart_lines(&props),
+            vec!["3 5 2 1", "  #    ", "  #    ", "# #    ", "# # #  ", "# # # #", " a   b ",]
+        );
+    }
 }
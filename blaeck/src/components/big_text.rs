@@ -0,0 +1,351 @@
+//! BigText component - large block-letter banners.
+//!
+//! BigText draws text in letters five rows tall (or three with
+//! [`BigFont::Compact`]), like a figlet banner, in a single color or a
+//! horizontal gradient across the whole banner. Letters, digits and common
+//! punctuation are built in; lowercase is drawn as uppercase and anything
+//! else as `?`. Each line of the content becomes its own banner.
+//!
+//! ## When to use BigText
+//!
+//! - Splash screens and app logos
+//! - Big numbers on a dashboard (clocks, counters)
+//!
+//! ## See also
+//!
+//! - [`Gradient`](super::Gradient) — Gradient on normal-sized text
+//!
+//! # Example
+//!
+//! ```ignore
+//! Element::node::<BigText>(
+//!     BigTextProps::new("BLAECK").gradient(GradientPreset::Ocean),
+//!     vec![],
+//! )
+//!
+//! // Three rows tall, single color
+//! Element::node::<BigText>(
+//!     BigTextProps::new("12:45").font(BigFont::Compact).color(Color::Cyan),
+//!     vec![],
+//! )
+//! ```
+
+use crate::components::gradient::{ColorStop, GradientPreset, GradientProps};
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::style::{Color, Modifier, Style};
+
+/// Built-in BigText fonts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BigFont {
+    /// Five rows of full blocks (`#` with ASCII glyphs).
+    #[default]
+    Block,
+    /// Three rows of half blocks. Falls back to [`BigFont::Block`] with
+    /// ASCII glyphs.
+    Compact,
+}
+
+/// Five-row bitmaps, `#` for a filled pixel.
+fn glyph(c: char) -> &'static [&'static str; 5] {
+    match c.to_ascii_uppercase() {
+        'A' => &[".##.", "#..#", "####", "#..#", "#..#"],
+        'B' => &["###.", "#..#", "###.", "#..#", "###."],
+        'C' => &[".###", "#...", "#...", "#...", ".###"],
+        'D' => &["###.", "#..#", "#..#", "#..#", "###."],
+        'E' => &["####", "#...", "###.", "#...", "####"],
+        'F' => &["####", "#...", "###.", "#...", "#..."],
+        'G' => &[".###", "#...", "#.##", "#..#", ".###"],
+        'H' => &["#..#", "#..#", "####", "#..#", "#..#"],
+        'I' => &["###", ".#.", ".#.", ".#.", "###"],
+        'J' => &["...#", "...#", "...#", "#..#", ".##."],
+        'K' => &["#..#", "#.#.", "##..", "#.#.", "#..#"],
+        'L' => &["#...", "#...", "#...", "#...", "####"],
+        'M' => &["#...#", "##.##", "#.#.#", "#...#", "#...#"],
+        'N' => &["#...#", "##..#", "#.#.#", "#..##", "#...#"],
+        'O' => &[".##.", "#..#", "#..#", "#..#", ".##."],
+        'P' => &["###.", "#..#", "###.", "#...", "#..."],
+        'Q' => &[".##.", "#..#", "#..#", "#.#.", ".#.#"],
+        'R' => &["###.", "#..#", "###.", "#.#.", "#..#"],
+        'S' => &[".###", "#...", ".##.", "...#", "###."],
+        'T' => &["#####", "..#..", "..#..", "..#..", "..#.."],
+        'U' => &["#..#", "#..#", "#..#", "#..#", ".##."],
+        'V' => &["#...#", "#...#", "#...#", ".#.#.", "..#.."],
+        'W' => &["#...#", "#...#", "#.#.#", "##.##", "#...#"],
+        'X' => &["#...#", ".#.#.", "..#..", ".#.#.", "#...#"],
+        'Y' => &["#...#", ".#.#.", "..#..", "..#..", "..#.."],
+        'Z' => &["####", "...#", "..#.", ".#..", "####"],
+        '0' => &[".##.", "#.##", "##.#", "#..#", ".##."],
+        '1' => &[".#.", "##.", ".#.", ".#.", "###"],
+        '2' => &["###.", "...#", ".##.", "#...", "####"],
+        '3' => &["###.", "...#", ".##.", "...#", "###."],
+        '4' => &["#..#", "#..#", "####", "...#", "...#"],
+        '5' => &["####", "#...", "###.", "...#", "###."],
+        '6' => &[".##.", "#...", "###.", "#..#", ".##."],
+        '7' => &["####", "...#", "..#.", ".#..", ".#.."],
+        '8' => &[".##.", "#..#", ".##.", "#..#", ".##."],
+        '9' => &[".##.", "#..#", ".###", "...#", ".##."],
+        ' ' => &["..", "..", "..", "..", ".."],
+        '.' => &[".", ".", ".", ".", "#"],
+        ',' => &["..", "..", "..", ".#", "#."],
+        ':' => &[".", "#", ".", "#", "."],
+        '!' => &["#", "#", "#", ".", "#"],
+        '-' => &["...", "...", "###", "...", "..."],
+        '_' => &["....", "....", "....", "....", "####"],
+        '\'' => &["#", "#", ".", ".", "."],
+        '/' => &["...#", "..#.", ".#..", "#...", "#..."],
+        _ => &["###.", "...#", ".##.", "....", ".#.."],
+    }
+}
+
+/// Properties for the BigText component.
+#[derive(Debug, Clone)]
+pub struct BigTextProps {
+    /// The text to draw.
+    pub content: String,
+    /// Font to draw it in.
+    pub font: BigFont,
+    /// Color of the letters, when there's no gradient.
+    pub color: Option<Color>,
+    /// Gradient across the banner, overriding `color`.
+    pub gradient: Vec<ColorStop>,
+    /// Blank columns between letters.
+    pub spacing: usize,
+    /// Whether the letters are bold.
+    pub bold: bool,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for BigTextProps {
+    fn default() -> Self {
+        Self {
+            content: String::new(),
+            font: BigFont::Block,
+            color: None,
+            gradient: Vec::new(),
+            spacing: 1,
+            bold: false,
+            glyphs: None,
+        }
+    }
+}
+
+impl BigTextProps {
+    /// Create props for a banner of `content`.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the font.
+    #[must_use]
+    pub fn font(mut self, font: BigFont) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Set a single color.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Color the banner with a preset gradient.
+    #[must_use]
+    pub fn gradient(mut self, preset: GradientPreset) -> Self {
+        self.gradient = preset.stops();
+        self
+    }
+
+    /// Color the banner with a gradient from `start` to `end`.
+    #[must_use]
+    pub fn two_colors(mut self, start: Color, end: Color) -> Self {
+        self.gradient = vec![ColorStop::new(0.0, start), ColorStop::new(1.0, end)];
+        self
+    }
+
+    /// Color the banner with custom gradient stops.
+    #[must_use]
+    pub fn stops(mut self, stops: Vec<ColorStop>) -> Self {
+        self.gradient = stops;
+        self
+    }
+
+    /// Set the blank columns between letters.
+    #[must_use]
+    pub fn spacing(mut self, spacing: usize) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Draw the letters bold.
+    #[must_use]
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// The banner as plain rows of text.
+    pub fn render_lines(&self) -> Vec<String> {
+        let ascii = GlyphSet::resolve(self.glyphs).is_ascii();
+        let mut rows = Vec::new();
+        for (i, line) in self.content.lines().enumerate() {
+            if i > 0 {
+                rows.push(String::new());
+            }
+            // Five rows of pixels, letters separated by blank columns
+            let mut pixels = vec![String::new(); 5];
+            for (j, c) in line.chars().enumerate() {
+                for (row, bits) in pixels.iter_mut().zip(glyph(c)) {
+                    if j > 0 {
+                        row.push_str(&".".repeat(self.spacing));
+                    }
+                    row.push_str(bits);
+                }
+            }
+            match (self.font, ascii) {
+                (_, true) => rows.extend(pixels),
+                (BigFont::Block, false) => {
+                    rows.extend(pixels.iter().map(|row| row.replace('#', "█")))
+                }
+                (BigFont::Compact, false) => {
+                    // Pair up pixel rows into half-block cells
+                    for pair in pixels.chunks(2) {
+                        let top = pair[0].as_bytes();
+                        let bottom = pair.get(1).map(|r| r.as_bytes());
+                        let row = (0..top.len())
+                            .map(|x| {
+                                let lower = bottom.is_some_and(|b| b[x] == b'#');
+                                match (top[x] == b'#', lower) {
+                                    (true, true) => '█',
+                                    (true, false) => '▀',
+                                    (false, true) => '▄',
+                                    (false, false) => '.',
+                                }
+                            })
+                            .collect();
+                        rows.push(row);
+                    }
+                }
+            }
+        }
+        rows.into_iter().map(|row| row.replace('.', " ")).collect()
+    }
+}
+
+/// A component that draws text as large block letters.
+pub struct BigText;
+
+impl Component for BigText {
+    type Props = BigTextProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let lines = props.render_lines();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        if width == 0 {
+            return Element::text("");
+        }
+
+        let mut base = Style::new();
+        if props.bold {
+            base = base.add_modifier(Modifier::BOLD);
+        }
+        if props.gradient.is_empty() {
+            if let Some(color) = props.color {
+                base = base.fg(color);
+            }
+            return Element::Fragment(
+                lines
+                    .into_iter()
+                    .map(|line| Element::styled_text(line, base))
+                    .collect(),
+            );
+        }
+
+        let mut stops = props.gradient.clone();
+        stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+        let color_at = |x: usize| {
+            let position = if width > 1 {
+                x as f32 / (width - 1) as f32
+            } else {
+                0.0
+            };
+            GradientProps::interpolate_color(&stops, position)
+        };
+        let lines = lines
+            .into_iter()
+            .map(|line| {
+                // Blanks stay unstyled; each filled cell gets its column's color
+                let cells = line
+                    .chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        if c == ' ' {
+                            Element::text(" ")
+                        } else {
+                            Element::styled_text(c.to_string(), base.fg(color_at(x)))
+                        }
+                    })
+                    .collect();
+                Element::Fragment(cells)
+            })
+            .collect();
+        Element::Fragment(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_big_text_lines() {
+        let props = BigTextProps::new("Hi").glyphs(GlyphSet::Ascii);
+        assert_eq!(
+            props.render_lines(),
+            ["#  # ###", "#  #  # ", "####  # ", "#  #  # ", "#  # ###"]
+        );
+
+        let compact = BigTextProps::new("I")
+            .font(BigFont::Compact)
+            .glyphs(GlyphSet::Unicode);
+        assert_eq!(compact.render_lines(), ["▀█▀", " █ ", "▀▀▀"]);
+
+        let two = BigTextProps::new("A\nB")
+            .glyphs(GlyphSet::Ascii)
+            .render_lines();
+        assert_eq!(two.len(), 11);
+        assert_eq!(two[5], "");
+    }
+
+    #[test]
+    fn test_big_text_gradient() {
+        let props = BigTextProps::new("T")
+            .two_colors(Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0))
+            .glyphs(GlyphSet::Unicode);
+        let Element::Fragment(lines) = BigText::render(&props) else {
+            panic!("Expected Fragment");
+        };
+        assert_eq!(lines.len(), 5);
+        let Element::Fragment(cells) = &lines[0] else {
+            panic!("Expected inline cells");
+        };
+        let fg = |cell: &Element| match cell {
+            Element::Text { style, .. } => style.fg,
+            _ => panic!("Expected Text"),
+        };
+        assert_eq!(fg(&cells[0]), Color::Rgb(0, 0, 0));
+        assert_eq!(fg(&cells[4]), Color::Rgb(200, 0, 0));
+    }
+}
@@ -61,7 +61,8 @@
 //! This is similar to CSS `visibility: hidden` - the element takes up space but
 //! renders nothing, preventing layout shifts when it appears.
 
-use crate::element::{Component, Element};
+use crate::element::{Component, Element, ElementBuilder};
+use crate::glyphs::GlyphSet;
 use crate::layout::{
     AlignContent, AlignItems, AlignSelf, Display, FlexDirection, FlexWrap, GridAutoFlow,
     GridPlacement, JustifyContent, LayoutStyle, Overflow, Position, TrackSize,
@@ -167,6 +168,33 @@ impl BorderStyle {
         }
     }
 
+    /// The style to draw with under `glyphs`: line-drawing styles fall back
+    /// to [`BorderStyle::Classic`] when only ASCII is available. Custom
+    /// borders made of ASCII characters are kept.
+    pub fn for_glyphs(self, glyphs: GlyphSet) -> Self {
+        if !glyphs.is_ascii() {
+            return self;
+        }
+        match self {
+            BorderStyle::None | BorderStyle::Classic => self,
+            BorderStyle::Custom(c)
+                if [
+                    c.top_left,
+                    c.top_right,
+                    c.bottom_left,
+                    c.bottom_right,
+                    c.horizontal,
+                    c.vertical,
+                ]
+                .iter()
+                .all(char::is_ascii) =>
+            {
+                self
+            }
+            _ => BorderStyle::Classic,
+        }
+    }
+
     /// Returns true if this border style has a visible border.
     pub fn has_border(self) -> bool {
         !matches!(self, BorderStyle::None)
@@ -336,6 +364,14 @@ pub struct BoxProps {
     pub flex_grow: f32,
     /// How much this box should shrink relative to siblings
     pub flex_shrink: f32,
+    /// Share of the parent's space, like CSS `flex: N` (0 = not set).
+    ///
+    /// A positive value sets grow to `flex`, shrink to 1 and basis to 0, so
+    /// siblings split the space by weight regardless of their content:
+    /// panels with `flex: 1.0` and `flex: 2.0` get a third and two thirds.
+    /// Overrides `flex_grow`, `flex_shrink` and `flex_basis`. As in CSS, a
+    /// box's own border and padding come on top of its share.
+    pub flex: f32,
     /// Padding on all sides (characters horizontally, lines vertically)
     pub padding: f32,
     /// Padding on the left side (characters)
@@ -421,6 +457,10 @@ pub struct BoxProps {
     pub border_sides: Option<BorderSides>,
     /// Dim the border color (renders border with dim style)
     pub border_dim: bool,
+    /// Glyph set override for the border (`None` = detect from the terminal)
+    pub glyphs: Option<GlyphSet>,
+    /// Title drawn into the top border (needs a border with a top side)
+    pub title: Option<String>,
 
     // Background
     /// Background color (optional)
@@ -435,6 +475,17 @@ pub struct BoxProps {
     ///
     /// Default is `true`.
     pub visible: bool,
+
+    // Caching
+    /// Reuse the painted cells of this box across frames (`None` = repaint
+    /// every frame).
+    ///
+    /// The key names what is inside the box: while it, the box's size and
+    /// its own props stay the same, the renderer copies the cells it painted
+    /// last time without looking at the children. Change the key (e.g.
+    /// include a version number) whenever anything inside changes. Children
+    /// that overflow the box are clipped to it.
+    pub cache_key: Option<String>,
 }
 
 impl Default for BoxProps {
@@ -449,6 +500,7 @@ impl Default for BoxProps {
             flex_direction: FlexDirection::default(),
             flex_grow: 0.0,
             flex_shrink: 0.0,
+            flex: 0.0,
             padding: 0.0,
             padding_left: None,
             padding_right: None,
@@ -486,8 +538,11 @@ impl Default for BoxProps {
             border_colors: BorderColors::default(),
             border_sides: None,
             border_dim: false,
+            glyphs: None,
+            title: None,
             background_color: None,
             visible: true, // Default to visible
+            cache_key: None,
         }
     }
 }
@@ -556,6 +611,12 @@ impl BoxProps {
         self
     }
 
+    /// Take a weighted share of the parent's space (see [`BoxProps::flex`]).
+    pub fn with_flex(mut self, flex: f32) -> Self {
+        self.flex = flex;
+        self
+    }
+
     /// Set the width.
     pub fn with_width(mut self, width: f32) -> Self {
         self.width = Some(width);
@@ -568,6 +629,26 @@ impl BoxProps {
         self
     }
 
+    /// Set a title drawn into the top border, e.g. `┌ Logs ─────┐`.
+    ///
+    /// Titles wider than the box are truncated.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the background color.
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    /// Override the detected glyph set used for the border.
+    pub fn with_glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
     /// Set visibility. When false, the box takes up space but renders nothing.
     pub fn with_visible(mut self, visible: bool) -> Self {
         self.visible = visible;
@@ -580,6 +661,12 @@ impl BoxProps {
         self
     }
 
+    /// Cache the painted box under `key`. See [`BoxProps::cache_key`].
+    pub fn with_cache_key(mut self, key: impl Into<String>) -> Self {
+        self.cache_key = Some(key.into());
+        self
+    }
+
     // ============ Query Methods ============
 
     /// Get the effective border sides (which sides should show a border).
@@ -621,6 +708,8 @@ impl BoxProps {
         let border_right: f32 = if sides.right { 1.0 } else { 0.0 };
 
         // For the base padding, we use the maximum border size if no per-side padding is set
+        let weighted = self.flex > 0.0;
+
         let max_border = border_top
             .max(border_bottom)
             .max(border_left)
@@ -637,8 +726,8 @@ impl BoxProps {
 
             // Flexbox
             flex_direction: self.flex_direction,
-            flex_grow: self.flex_grow,
-            flex_shrink: self.flex_shrink,
+            flex_grow: if weighted { self.flex } else { self.flex_grow },
+            flex_shrink: if weighted { 1.0 } else { self.flex_shrink },
 
             // Padding (add border to padding based on which sides have borders)
             padding: self.padding + max_border,
@@ -667,7 +756,7 @@ impl BoxProps {
             display: self.display,
             position: self.position,
             flex_wrap: self.flex_wrap,
-            flex_basis: self.flex_basis,
+            flex_basis: if weighted { Some(0.0) } else { self.flex_basis },
             aspect_ratio: self.aspect_ratio,
             overflow_x: self.overflow_x,
             overflow_y: self.overflow_y,
@@ -718,6 +807,114 @@ impl Component for Box {
     }
 }
 
+/// Shorthand setters for building a Box without the macro.
+///
+/// ```ignore
+/// Box::builder()
+///     .column()
+///     .padding(1.0)
+///     .border(BorderStyle::Round)
+///     .child(Text::builder().content("Hello"))
+///     .build()
+/// ```
+impl ElementBuilder<Box> {
+    /// Stack children vertically.
+    #[must_use]
+    pub fn column(self) -> Self {
+        self.flex_direction(FlexDirection::Column)
+    }
+
+    /// Arrange children horizontally.
+    #[must_use]
+    pub fn row(self) -> Self {
+        self.flex_direction(FlexDirection::Row)
+    }
+
+    /// Set the flex direction.
+    #[must_use]
+    pub fn flex_direction(self, direction: FlexDirection) -> Self {
+        self.set(|p| p.flex_direction = direction)
+    }
+
+    /// Set the gap between children.
+    #[must_use]
+    pub fn gap(self, gap: f32) -> Self {
+        self.with(|p| p.with_gap(gap))
+    }
+
+    /// Set padding on all sides.
+    #[must_use]
+    pub fn padding(self, padding: f32) -> Self {
+        self.with(|p| p.with_padding(padding))
+    }
+
+    /// Set margin on all sides.
+    #[must_use]
+    pub fn margin(self, margin: f32) -> Self {
+        self.set(|p| p.margin = margin)
+    }
+
+    /// Set the border style.
+    #[must_use]
+    pub fn border(self, style: BorderStyle) -> Self {
+        self.with(|p| p.with_border(style))
+    }
+
+    /// Set the border color.
+    #[must_use]
+    pub fn border_color(self, color: Color) -> Self {
+        self.set(|p| p.border_color = Some(color))
+    }
+
+    /// Set a title drawn into the top border.
+    #[must_use]
+    pub fn title(self, title: impl Into<String>) -> Self {
+        self.with(|p| p.with_title(title))
+    }
+
+    /// Set the width.
+    #[must_use]
+    pub fn width(self, width: f32) -> Self {
+        self.with(|p| p.with_width(width))
+    }
+
+    /// Set the height.
+    #[must_use]
+    pub fn height(self, height: f32) -> Self {
+        self.with(|p| p.with_height(height))
+    }
+
+    /// Take a weighted share of the parent's space (see [`BoxProps::flex`]).
+    #[must_use]
+    pub fn flex(self, flex: f32) -> Self {
+        self.with(|p| p.with_flex(flex))
+    }
+
+    /// Set how children align on the cross axis.
+    #[must_use]
+    pub fn align_items(self, align: AlignItems) -> Self {
+        self.set(|p| p.align_items = Some(align))
+    }
+
+    /// Set how children are distributed along the main axis.
+    #[must_use]
+    pub fn justify_content(self, justify: JustifyContent) -> Self {
+        self.set(|p| p.justify_content = Some(justify))
+    }
+
+    /// Set the background color.
+    #[must_use]
+    pub fn background(self, color: Color) -> Self {
+        self.with(|p| p.with_background(color))
+    }
+
+    /// Cache the painted box under `key`.
+    #[must_use]
+    pub fn cache_key(self, key: impl Into<String>) -> Self {
+        self.with(|p| p.with_cache_key(key))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -934,4 +1131,53 @@ mod tests {
         assert_eq!(layout.padding_left, Some(1.0)); // 1.0 + 0.0
         assert_eq!(layout.padding_right, Some(1.0)); // 1.0 + 0.0
     }
+
+    #[test]
+    fn test_box_props_flex_shares_space() {
+        use crate::layout::LayoutTree;
+
+        let mut tree = LayoutTree::new();
+        let left = tree
+            .new_leaf(BoxProps::new().with_flex(1.0).to_layout_style())
+            .unwrap();
+        let right = tree
+            .new_leaf(BoxProps::new().with_flex(2.0).to_layout_style())
+            .unwrap();
+        let root = tree
+            .new_with_children(
+                BoxProps::row().with_width(30.0).to_layout_style(),
+                &[left, right],
+            )
+            .unwrap();
+        tree.compute(root, 30.0, 10.0);
+        assert_eq!(tree.get_layout(left).width, 10.0);
+        assert_eq!(tree.get_layout(right).width, 20.0);
+
+        let layout = BoxProps::new().with_flex(2.0).to_layout_style();
+        assert_eq!(
+            (layout.flex_grow, layout.flex_shrink, layout.flex_basis),
+            (2.0, 1.0, Some(0.0))
+        );
+    }
+
+    #[test]
+    fn test_box_builder() {
+        use crate::components::Text;
+        let elem = Box::builder()
+            .column()
+            .padding(1.0)
+            .border(BorderStyle::Round)
+            .title("Logs")
+            .child(Text::builder().content("a").bold())
+            .child(Text::builder().content("b"))
+            .build();
+        assert_eq!(elem.children().len(), 2);
+        let Element::Node { props, .. } = &elem else {
+            panic!("expected node");
+        };
+        let props = props.downcast_ref::<BoxProps>().unwrap();
+        assert_eq!(props.flex_direction, FlexDirection::Column);
+        assert_eq!(props.border_style, BorderStyle::Round);
+        assert_eq!(props.title.as_deref(), Some("Logs"));
+    }
 }
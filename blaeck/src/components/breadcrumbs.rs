@@ -3,6 +3,12 @@
 //! The Breadcrumbs component displays a hierarchical navigation path,
 //! commonly used to show the current location in a file system or menu.
 //!
+//! Given a [`max_width`](BreadcrumbsProps::max_width), a path that doesn't
+//! fit collapses: by default the middle crumbs fold into the ellipsis (`…`,
+//! or `...` on ASCII terminals), keeping the first and as many of the last
+//! as fit (see [`BreadcrumbCollapse`]). If even that is too wide, the last
+//! label is cut.
+//!
 //! ## When to use Breadcrumbs
 //!
 //! - File path display (Home > Documents > File.txt)
@@ -16,6 +22,8 @@
 
 use crate::element::{Component, Element};
 use crate::style::{Color, Modifier, Style};
+use crate::truncation::Truncation;
+use unicode_width::UnicodeWidthStr;
 
 /// A single item in the breadcrumb path.
 #[derive(Debug, Clone)]
@@ -98,6 +106,18 @@ impl BreadcrumbSeparator {
     }
 }
 
+/// Which crumbs fold into the ellipsis when the path is too wide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BreadcrumbCollapse {
+    /// Hide crumbs after the first ones: `home / … / docs / file`.
+    #[default]
+    Middle,
+    /// Hide crumbs from the start: `… / docs / file`.
+    Start,
+    /// Never collapse; the path overflows.
+    None,
+}
+
 /// Properties for the Breadcrumbs component.
 #[derive(Debug, Clone)]
 pub struct BreadcrumbsProps {
@@ -117,12 +137,20 @@ pub struct BreadcrumbsProps {
     pub dim_inactive: bool,
     /// Maximum number of crumbs to show (0 = all).
     pub max_items: usize,
-    /// Text shown when items are collapsed.
+    /// Text shown when items are collapsed (default [`Truncation::detect`]).
     pub ellipsis: String,
     /// Whether to show a root indicator at the start.
     pub show_root: bool,
     /// Root indicator text.
     pub root_text: String,
+    /// Width to fit the path into (0 = no limit).
+    pub max_width: usize,
+    /// How the path collapses when wider than `max_width`.
+    pub collapse: BreadcrumbCollapse,
+    /// Crumbs always kept at the start when collapsing the middle.
+    pub keep_first: usize,
+    /// Crumbs always kept at the end when collapsing.
+    pub keep_last: usize,
 }
 
 impl Default for BreadcrumbsProps {
@@ -136,9 +164,13 @@ impl Default for BreadcrumbsProps {
             bold_active: true,
             dim_inactive: false,
             max_items: 0,
-            ellipsis: "...".into(),
+            ellipsis: Truncation::detect().ellipsis,
             show_root: false,
             root_text: "~".into(),
+            max_width: 0,
+            collapse: BreadcrumbCollapse::Middle,
+            keep_first: 1,
+            keep_last: 1,
         }
     }
 }
@@ -249,28 +281,113 @@ impl BreadcrumbsProps {
         self
     }
 
-    /// Get the items to display (handles truncation).
-    fn display_items(&self) -> Vec<&Crumb> {
-        if self.max_items == 0 || self.crumbs.len() <= self.max_items {
-            self.crumbs.iter().collect()
+    /// Set the width to fit the path into (0 = no limit).
+    #[must_use]
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Set how the path collapses when too wide.
+    #[must_use]
+    pub fn collapse(mut self, collapse: BreadcrumbCollapse) -> Self {
+        self.collapse = collapse;
+        self
+    }
+
+    /// Set how many crumbs are always kept at the start and end. The last
+    /// crumb is always kept.
+    #[must_use]
+    pub fn keep(mut self, first: usize, last: usize) -> Self {
+        self.keep_first = first;
+        self.keep_last = last;
+        self
+    }
+
+    /// The first `head` and last `tail` crumbs, with `None` for the
+    /// ellipsis if any are hidden between them.
+    fn shown(&self, head: usize, tail: usize) -> Vec<Option<&Crumb>> {
+        let len = self.crumbs.len();
+        if head + tail >= len {
+            return self.crumbs.iter().map(Some).collect();
+        }
+        let mut items: Vec<_> = self.crumbs[..head].iter().map(Some).collect();
+        items.push(None);
+        items.extend(self.crumbs[len - tail..].iter().map(Some));
+        items
+    }
+
+    /// Display width of the path made of `items`, root included.
+    fn width_of(&self, items: &[Option<&Crumb>]) -> usize {
+        let labels: usize = items
+            .iter()
+            .map(|item| item.map_or(self.ellipsis.width(), |c| c.label.width()))
+            .sum();
+        let root = if self.show_root {
+            self.root_text.width()
+        } else {
+            0
+        };
+        let seps = items.len() - 1 + usize::from(self.show_root);
+        root + labels + seps * self.separator.as_str().width()
+    }
+
+    /// Get the items to display, with `None` where crumbs were collapsed
+    /// into the ellipsis, by `max_items` and then to fit `max_width`.
+    fn display_items(&self) -> Vec<Option<&Crumb>> {
+        let len = self.crumbs.len();
+        let (mut head, mut tail) = if self.max_items == 0 || len <= self.max_items {
+            (len, 0)
         } else {
             // Keep first item and last (max_items - 1) items
-            let mut items = Vec::with_capacity(self.max_items + 1);
-            if !self.crumbs.is_empty() {
-                items.push(&self.crumbs[0]);
+            (1, self.max_items - 1)
+        };
+        // A single crumb can't collapse; `label` cuts it instead
+        if self.max_width == 0 || self.collapse == BreadcrumbCollapse::None || len < 2 {
+            return self.shown(head, tail);
+        }
+
+        // The last crumb is never hidden, whatever `keep` asks for
+        let keep_first = if self.collapse == BreadcrumbCollapse::Start {
+            0
+        } else {
+            self.keep_first.min(len - 2)
+        };
+        let keep_last = self.keep_last.clamp(1, len - keep_first);
+        loop {
+            let items = self.shown(head, tail);
+            if self.width_of(&items) <= self.max_width {
+                return items;
             }
-            // Skip items in the middle, take last (max_items - 1)
-            let skip = self.crumbs.len() - (self.max_items - 1);
-            for crumb in self.crumbs.iter().skip(skip) {
-                items.push(crumb);
+            // Hide one more crumb, from just after the kept start
+            if head + tail >= len {
+                head = keep_first;
+                tail = len - keep_first - 1;
+            } else if head > keep_first {
+                head = keep_first;
+            } else if tail > keep_last {
+                tail -= 1;
+            } else {
+                return items;
             }
-            items
         }
     }
 
-    /// Check if truncation is needed.
-    fn is_truncated(&self) -> bool {
-        self.max_items > 0 && self.crumbs.len() > self.max_items
+    /// The label of `crumb`, cut to fit `max_width` if it's the last crumb
+    /// and the collapsed path is still too wide.
+    fn label(&self, crumb: &Crumb, items: &[Option<&Crumb>]) -> String {
+        let is_last = items
+            .last()
+            .and_then(|item| *item)
+            .is_some_and(|last| std::ptr::eq(last, crumb));
+        let width = self.width_of(items);
+        if !is_last || self.max_width == 0 || width <= self.max_width {
+            return crumb.label.clone();
+        }
+        let room = crumb.label.width().saturating_sub(width - self.max_width);
+        Truncation::new(self.ellipsis.clone())
+            .truncate(&crumb.label, room.max(1))
+            .into_owned()
     }
 
     /// Render the breadcrumbs as a string.
@@ -284,7 +401,6 @@ impl BreadcrumbsProps {
 
         let sep = self.separator.as_str();
         let items = self.display_items();
-        let truncated = self.is_truncated();
 
         let mut parts = Vec::new();
 
@@ -292,12 +408,11 @@ impl BreadcrumbsProps {
             parts.push(self.root_text.clone());
         }
 
-        for (i, crumb) in items.iter().enumerate() {
-            // Add ellipsis after first item if truncated
-            if truncated && i == 1 {
-                parts.push(self.ellipsis.clone());
+        for item in &items {
+            match item {
+                Some(crumb) => parts.push(self.label(crumb, &items)),
+                None => parts.push(self.ellipsis.clone()),
             }
-            parts.push(crumb.label.clone());
         }
 
         parts.join(sep)
@@ -345,7 +460,6 @@ impl Component for Breadcrumbs {
 
         let sep = props.separator.as_str();
         let items = props.display_items();
-        let truncated = props.is_truncated();
 
         let mut children = Vec::new();
 
@@ -368,22 +482,23 @@ impl Component for Breadcrumbs {
             children.push(Element::styled_text(sep, sep_style));
         }
 
-        for (i, crumb) in items.iter().enumerate() {
+        for (i, item) in items.iter().enumerate() {
+            let mut sep_style = Style::new();
+            if let Some(color) = props.separator_color {
+                sep_style = sep_style.fg(color);
+            }
+
             // Add separator before item (except first)
             if i > 0 {
-                let mut sep_style = Style::new();
-                if let Some(color) = props.separator_color {
-                    sep_style = sep_style.fg(color);
-                }
                 children.push(Element::styled_text(sep, sep_style));
-
-                // Add ellipsis after first separator if truncated
-                if truncated && i == 1 {
-                    children.push(Element::styled_text(&props.ellipsis, sep_style));
-                    children.push(Element::styled_text(sep, sep_style));
-                }
             }
 
+            // Collapsed crumbs show as the ellipsis
+            let Some(crumb) = item else {
+                children.push(Element::styled_text(&props.ellipsis, sep_style));
+                continue;
+            };
+
             // Add crumb
             let mut style = Style::new();
             if crumb.active {
@@ -401,7 +516,7 @@ impl Component for Breadcrumbs {
                     style = style.fg(color);
                 }
             }
-            children.push(Element::styled_text(&crumb.label, style));
+            children.push(Element::styled_text(props.label(crumb, &items), style));
         }
 
         // Return as Fragment for proper inline rendering
@@ -525,9 +640,9 @@ mod tests {
     fn test_breadcrumbs_truncation() {
         let props = BreadcrumbsProps::new(["a", "b", "c", "d", "e", "f"]).max_items(3);
         let result = props.render_string();
-        // Should show: a / ... / e / f
+        // Should show: a / … / e / f
         assert!(result.contains("a"));
-        assert!(result.contains("..."));
+        assert!(result.contains(&props.ellipsis));
         assert!(result.contains("f"));
     }
 
@@ -557,4 +672,82 @@ mod tests {
         let elem = Breadcrumbs::render(&props);
         assert!(elem.is_text());
     }
+
+    #[test]
+    fn test_breadcrumbs_collapse_middle() {
+        let props = BreadcrumbsProps::from_path("/home/user/projects/blaeck/src/lib.rs")
+            .ellipsis("…")
+            .max_width(32);
+        assert_eq!(props.render_string(), "home / … / blaeck / src / lib.rs");
+
+        let props = props.max_width(31);
+        assert_eq!(props.render_string(), "home / … / src / lib.rs");
+
+        let props = props.keep(2, 1).max_width(28);
+        assert_eq!(props.render_string(), "home / user / … / lib.rs");
+    }
+
+    #[test]
+    fn test_breadcrumbs_collapse_start() {
+        let props = BreadcrumbsProps::new(["a", "b", "c", "d", "e"])
+            .ellipsis("…")
+            .collapse(BreadcrumbCollapse::Start)
+            .max_width(13);
+        assert_eq!(props.render_string(), "… / c / d / e");
+    }
+
+    #[test]
+    fn test_breadcrumbs_collapse_fits_or_disabled() {
+        let props = BreadcrumbsProps::new(["a", "b", "c"]).max_width(20);
+        assert_eq!(props.render_string(), "a / b / c");
+
+        let props = props.collapse(BreadcrumbCollapse::None).max_width(3);
+        assert_eq!(props.render_string(), "a / b / c");
+    }
+
+    #[test]
+    fn test_breadcrumbs_collapse_single_or_kept_crumbs() {
+        let props = BreadcrumbsProps::new(["a-very-long-single-crumb"])
+            .ellipsis("…")
+            .max_width(10);
+        assert_eq!(props.render_string(), "a-very-lo…");
+
+        // keep_first covering every crumb still leaves the last one visible
+        let props = BreadcrumbsProps::new(["one", "two", "three"])
+            .ellipsis("…")
+            .keep(5, 1)
+            .max_width(13);
+        assert_eq!(props.render_string(), "one / … / th…");
+
+        // keep_last of zero still leaves the last one visible
+        let props = BreadcrumbsProps::new(["Home", "docs", "guide", "intro"])
+            .ellipsis("…")
+            .keep(1, 0)
+            .max_width(14);
+        assert_eq!(props.render_string(), "Home / … / in…");
+    }
+
+    #[test]
+    fn test_breadcrumbs_collapse_cuts_last_label() {
+        let props = BreadcrumbsProps::new(["root", "middle", "a-very-long-file-name.txt"])
+            .ellipsis("…")
+            .show_root(true)
+            .max_width(24);
+        let text = props.render_string();
+        assert_eq!(text, "~ / root / … / a-very-l…");
+        assert_eq!(text.width(), 24);
+
+        // The component renders the same text
+        let Element::Fragment(children) = Breadcrumbs::render(&props) else {
+            panic!("Expected Fragment element");
+        };
+        let rendered: String = children
+            .iter()
+            .map(|child| match child {
+                Element::Text { content, .. } => content.as_str(),
+                _ => "",
+            })
+            .collect();
+        assert_eq!(rendered, text);
+    }
 }
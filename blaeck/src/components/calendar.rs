@@ -0,0 +1,522 @@
+//! Calendar component - a month view grid.
+//!
+//! The Calendar shows one month as a title, a row of weekday names and a
+//! row per week. Dates can be highlighted in a color, marked as having
+//! events, and one can be shown as selected; today is underlined. It
+//! doesn't handle keys itself, so a date picker can drive it from its own
+//! state with [`Date`] arithmetic.
+//!
+//! ## When to use Calendar
+//!
+//! - Showing which days have activity (deploys, meetings, commits)
+//! - The grid of a date picker
+//!
+//! ## See also
+//!
+//! - [`TimePicker`](super::TimePicker) — Picking a time of day or duration
+//! - [`Table`](super::Table) — General rows and columns
+//!
+//! # Example
+//!
+//! ```ignore
+//! Element::node::<Calendar>(
+//!     CalendarProps::new(2026, 10)
+//!         .today(Date::today())
+//!         .highlight(31, Color::Yellow)
+//!         .events([3, 14, 15])
+//!         .selected(14),
+//!     vec![],
+//! )
+//! // Renders:
+//! //     October 2026
+//! // Mo Tu We Th Fr Sa Su
+//! //           1  2  3• 4
+//! //  5  6  7  8  9 10 11
+//! //  ...
+//! ```
+
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::style::{Color, Modifier, Style};
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
+pub enum Weekday {
+    #[default]
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// All days, Monday first.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Days from Monday, 0-6.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The day `n` days later.
+    pub fn plus(self, n: usize) -> Weekday {
+        Self::ALL[(self.index() + n) % 7]
+    }
+
+    /// Two-letter name, e.g. "Mo".
+    pub fn short_name(self) -> &'static str {
+        ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"][self.index()]
+    }
+
+    /// Whether this is Saturday or Sunday.
+    pub fn is_weekend(self) -> bool {
+        matches!(self, Weekday::Saturday | Weekday::Sunday)
+    }
+}
+
+/// A calendar date, without a time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
+pub struct Date {
+    /// Year, e.g. 2026.
+    pub year: i32,
+    /// Month, 1-12.
+    pub month: u8,
+    /// Day of the month, from 1.
+    pub day: u8,
+}
+
+impl Date {
+    /// Create a date, or `None` if the month or day is out of range.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// Today's date in UTC, from the system clock.
+    pub fn today() -> Self {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        Self::from_days((secs / 86_400) as i64)
+    }
+
+    /// The date `days` days after 1970-01-01.
+    pub fn from_days(days: i64) -> Self {
+        // Howard Hinnant's civil_from_days
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+
+    /// Days since 1970-01-01 (negative before).
+    pub fn days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let mp = (i64::from(self.month) + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// The date `days` days later (or earlier, if negative).
+    #[must_use]
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.days() + days)
+    }
+
+    /// The same day `months` months later (or earlier), clamped to the
+    /// end of shorter months.
+    #[must_use]
+    pub fn add_months(self, months: i32) -> Self {
+        let index = self.year * 12 + i32::from(self.month) - 1 + months;
+        let (year, month) = (index.div_euclid(12), index.rem_euclid(12) as u8 + 1);
+        let day = self.day.min(days_in_month(year, month));
+        Self { year, month, day }
+    }
+
+    /// Day of the week.
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::Thursday.plus(self.days().rem_euclid(7) as usize)
+    }
+}
+
+/// Whether `year` has a February 29th.
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Number of days in `month` (1-12) of `year`.
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+/// Properties for the Calendar component.
+#[derive(Debug, Clone)]
+pub struct CalendarProps {
+    /// Year shown.
+    pub year: i32,
+    /// Month shown, 1-12.
+    pub month: u8,
+    /// First day of each week row.
+    pub week_start: Weekday,
+    /// Day shown as selected (reverse video).
+    pub selected: Option<u8>,
+    /// Today, underlined when it falls in the month shown.
+    pub today: Option<Date>,
+    /// Days drawn in a color.
+    pub highlights: Vec<(u8, Color)>,
+    /// Days with a marker after the number.
+    pub events: Vec<u8>,
+    /// Marker override (`None` = `•`, or `*` with ASCII glyphs).
+    pub event_marker: Option<char>,
+    /// Color of the event marker.
+    pub event_color: Option<Color>,
+    /// Color of weekend days (`None` = same as weekdays).
+    pub weekend_color: Option<Color>,
+    /// Whether to show the "Month Year" title.
+    pub show_title: bool,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for CalendarProps {
+    fn default() -> Self {
+        Self {
+            year: 1970,
+            month: 1,
+            week_start: Weekday::Monday,
+            selected: None,
+            today: None,
+            highlights: Vec::new(),
+            events: Vec::new(),
+            event_marker: None,
+            event_color: Some(Color::Cyan),
+            weekend_color: Some(Color::DarkGray),
+            show_title: true,
+            glyphs: None,
+        }
+    }
+}
+
+impl CalendarProps {
+    /// Create props showing `month` (1-12) of `year`.
+    pub fn new(year: i32, month: u8) -> Self {
+        Self {
+            year,
+            month: month.clamp(1, 12),
+            ..Default::default()
+        }
+    }
+
+    /// Create props showing the month of `date`, with it selected.
+    pub fn for_date(date: Date) -> Self {
+        Self::new(date.year, date.month).selected(date.day)
+    }
+
+    /// Set the first day of each week row.
+    #[must_use]
+    pub fn week_start(mut self, day: Weekday) -> Self {
+        self.week_start = day;
+        self
+    }
+
+    /// Set the selected day.
+    #[must_use]
+    pub fn selected(mut self, day: u8) -> Self {
+        self.selected = Some(day);
+        self
+    }
+
+    /// Set today's date.
+    #[must_use]
+    pub fn today(mut self, date: Date) -> Self {
+        self.today = Some(date);
+        self
+    }
+
+    /// Draw `day` in `color`.
+    #[must_use]
+    pub fn highlight(mut self, day: u8, color: Color) -> Self {
+        self.highlights.push((day, color));
+        self
+    }
+
+    /// Mark days as having events.
+    #[must_use]
+    pub fn events(mut self, days: impl IntoIterator<Item = u8>) -> Self {
+        self.events.extend(days);
+        self
+    }
+
+    /// Set the event marker.
+    #[must_use]
+    pub fn event_marker(mut self, marker: char) -> Self {
+        self.event_marker = Some(marker);
+        self
+    }
+
+    /// Set the event marker color.
+    #[must_use]
+    pub fn event_color(mut self, color: Color) -> Self {
+        self.event_color = Some(color);
+        self
+    }
+
+    /// Set the weekend color.
+    #[must_use]
+    pub fn weekend_color(mut self, color: Color) -> Self {
+        self.weekend_color = Some(color);
+        self
+    }
+
+    /// Show or hide the title.
+    #[must_use]
+    pub fn show_title(mut self, show: bool) -> Self {
+        self.show_title = show;
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// Weekday names in column order.
+    pub fn weekdays(&self) -> [Weekday; 7] {
+        std::array::from_fn(|i| self.week_start.plus(i))
+    }
+
+    /// The month as week rows, with `None` for the days outside it.
+    pub fn weeks(&self) -> Vec<[Option<u8>; 7]> {
+        let first = Date {
+            year: self.year,
+            month: self.month,
+            day: 1,
+        };
+        let offset = (first.weekday().index() + 7 - self.week_start.index()) % 7;
+        let days = days_in_month(self.year, self.month) as usize;
+        (0..(offset + days).div_ceil(7))
+            .map(|week| {
+                std::array::from_fn(|col| {
+                    let n = week * 7 + col;
+                    (offset..offset + days)
+                        .contains(&n)
+                        .then(|| (n - offset + 1) as u8)
+                })
+            })
+            .collect()
+    }
+
+    /// The "Month Year" title.
+    pub fn title(&self) -> String {
+        format!("{} {}", MONTH_NAMES[self.month as usize - 1], self.year)
+    }
+
+    fn day_style(&self, day: u8, weekday: Weekday) -> Style {
+        let mut style = Style::new();
+        if let Some(color) = self.weekend_color.filter(|_| weekday.is_weekend()) {
+            style = style.fg(color);
+        }
+        if let Some(&(_, color)) = self.highlights.iter().rev().find(|(d, _)| *d == day) {
+            style = style.fg(color).add_modifier(Modifier::BOLD);
+        }
+        let is_today = self
+            .today
+            .is_some_and(|t| (t.year, t.month, t.day) == (self.year, self.month, day));
+        if is_today {
+            style = style.add_modifier(Modifier::UNDERLINED);
+        }
+        if self.selected == Some(day) {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+        style
+    }
+
+    /// The lines of the calendar, each a list of spans. Every cell is three
+    /// columns: the day, right-aligned, then the event marker or a space.
+    pub fn render_lines(&self) -> Vec<Vec<(String, Style)>> {
+        const WIDTH: usize = 7 * 3 - 1;
+        let marker = self
+            .event_marker
+            .unwrap_or(if GlyphSet::resolve(self.glyphs).is_ascii() {
+                '*'
+            } else {
+                '•'
+            });
+        let marker_style = self
+            .event_color
+            .map(|c| Style::new().fg(c))
+            .unwrap_or_default();
+
+        let mut lines = Vec::new();
+        if self.show_title {
+            lines.push(vec![(
+                format!("{:^WIDTH$}", self.title()).trim_end().to_string(),
+                Style::new().add_modifier(Modifier::BOLD),
+            )]);
+        }
+        let names: Vec<&str> = self.weekdays().iter().map(|d| d.short_name()).collect();
+        lines.push(vec![(
+            names.join(" "),
+            Style::new().add_modifier(Modifier::DIM),
+        )]);
+
+        let weekdays = self.weekdays();
+        for week in self.weeks() {
+            let mut spans = Vec::new();
+            for (col, day) in week.iter().enumerate() {
+                let Some(day) = *day else {
+                    spans.push(("   ".to_string(), Style::new()));
+                    continue;
+                };
+                spans.push((format!("{day:>2}"), self.day_style(day, weekdays[col])));
+                if self.events.contains(&day) {
+                    spans.push((marker.to_string(), marker_style));
+                } else {
+                    spans.push((" ".to_string(), Style::new()));
+                }
+            }
+            // Drop trailing blank cells and spacing
+            while spans.last().is_some_and(|(text, _)| text.trim().is_empty()) {
+                spans.pop();
+            }
+            lines.push(spans);
+        }
+        lines
+    }
+}
+
+/// A component that displays a month as a grid of days.
+pub struct Calendar;
+
+impl Component for Calendar {
+    type Props = CalendarProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let lines = props
+            .render_lines()
+            .into_iter()
+            .map(|spans| {
+                Element::Fragment(
+                    spans
+                        .into_iter()
+                        .map(|(text, style)| Element::styled_text(text, style))
+                        .collect(),
+                )
+            })
+            .collect();
+        Element::Fragment(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::test_util::line_texts;
+
+    #[test]
+    fn test_date_arithmetic() {
+        assert_eq!(Date::from_days(0), Date::new(1970, 1, 1).unwrap());
+        let date = Date::new(2024, 2, 29).unwrap();
+        assert_eq!(Date::from_days(date.days()), date);
+        assert_eq!(date.weekday(), Weekday::Thursday);
+        assert_eq!(date.add_days(1), Date::new(2024, 3, 1).unwrap());
+        assert_eq!(date.add_months(12), Date::new(2025, 2, 28).unwrap());
+        assert_eq!(date.add_months(-3), Date::new(2023, 11, 29).unwrap());
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(2023, 13, 1).is_none());
+    }
+
+    #[test]
+    fn test_calendar_grid_monday_start() {
+        let props = CalendarProps::new(2026, 10)
+            .events([3])
+            .glyphs(GlyphSet::Unicode);
+        assert_eq!(
+            line_texts(props.render_lines()),
+            vec![
+                "    October 2026",
+                "Mo Tu We Th Fr Sa Su",
+                "          1  2  3• 4",
+                " 5  6  7  8  9 10 11",
+                "12 13 14 15 16 17 18",
+                "19 20 21 22 23 24 25",
+                "26 27 28 29 30 31",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calendar_week_start_and_styles() {
+        let props = CalendarProps::new(2026, 2)
+            .week_start(Weekday::Sunday)
+            .selected(14)
+            .today(Date::new(2026, 2, 2).unwrap())
+            .highlight(1, Color::Yellow)
+            .show_title(false);
+        assert_eq!(props.weeks().len(), 4);
+        assert_eq!(props.weeks()[0][0], Some(1));
+        assert_eq!(line_texts(props.render_lines())[0], "Su Mo Tu We Th Fr Sa");
+
+        // Feb 14th 2026 is a Saturday, in the last column
+        assert_eq!(
+            props.render_lines()[2][12].1,
+            props.day_style(14, Weekday::Saturday)
+        );
+        assert!(props
+            .day_style(14, Weekday::Saturday)
+            .modifiers
+            .contains(Modifier::REVERSED));
+        assert!(props
+            .day_style(2, Weekday::Monday)
+            .modifiers
+            .contains(Modifier::UNDERLINED));
+        assert_eq!(props.day_style(1, Weekday::Sunday).fg, Color::Yellow);
+    }
+}
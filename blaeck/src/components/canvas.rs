@@ -0,0 +1,366 @@
+//! Canvas component - free-form drawing with a closure.
+//!
+//! The Canvas is a grid of cells the size you give it. Its `draw` closure
+//! receives a [`Painter`] that sets dots (2×4 braille dots per cell by
+//! default), draws lines and rectangles, and writes text into cells. Each
+//! cell takes the color of the last thing drawn in it, and text covers any
+//! dots underneath.
+//!
+//! ## When to use Canvas
+//!
+//! - Games and simulations
+//! - Custom visualizations the chart components don't cover
+//!
+//! ## See also
+//!
+//! - [`LineChart`](super::LineChart) — Plotting data, with axes and a legend
+//! - [`BigText`](super::BigText) — Large text banners
+//!
+//! # Example
+//!
+//! ```ignore
+//! let ball = state.ball;
+//! Element::node::<Canvas>(
+//!     CanvasProps::new(40, 10).draw(move |p| {
+//!         let (w, h) = p.size();
+//!         p.rect(0, 0, w as isize, h as isize, Color::DarkGray);
+//!         p.fill_rect(ball.x - 1, ball.y - 1, 3, 3, Color::Yellow);
+//!         p.text(2, 0, " PONG ", Style::new().fg(Color::White));
+//!     }),
+//!     vec![],
+//! )
+//! ```
+
+use crate::components::line_chart::LineMarker;
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::style::{Color, Style};
+use std::fmt;
+use std::rc::Rc;
+
+/// A canvas drawing closure.
+pub type DrawFn = Rc<dyn Fn(&mut Painter)>;
+
+/// Properties for the Canvas component.
+#[derive(Clone)]
+pub struct CanvasProps {
+    /// Width in cells.
+    pub width: usize,
+    /// Height in cells.
+    pub height: usize,
+    /// How dots are drawn, which sets the resolution.
+    pub marker: LineMarker,
+    /// Called with a blank painter on every render.
+    pub draw: Option<DrawFn>,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for CanvasProps {
+    fn default() -> Self {
+        Self {
+            width: 40,
+            height: 10,
+            marker: LineMarker::Braille,
+            draw: None,
+            glyphs: None,
+        }
+    }
+}
+
+impl fmt::Debug for CanvasProps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CanvasProps")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("marker", &self.marker)
+            .field("draw", &self.draw.as_ref().map(|_| "<fn>"))
+            .field("glyphs", &self.glyphs)
+            .finish()
+    }
+}
+
+impl CanvasProps {
+    /// Create a blank canvas `width` × `height` cells.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            ..Default::default()
+        }
+    }
+
+    /// Set how dots are drawn. Falls back to [`LineMarker::Dot`] with
+    /// ASCII glyphs.
+    #[must_use]
+    pub fn marker(mut self, marker: LineMarker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Set the drawing closure.
+    #[must_use]
+    pub fn draw<F>(mut self, draw: F) -> Self
+    where
+        F: Fn(&mut Painter) + 'static,
+    {
+        self.draw = Some(Rc::new(draw));
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// Run the drawing closure on a blank painter.
+    pub fn paint(&self) -> Painter {
+        let ascii = GlyphSet::resolve(self.glyphs).is_ascii();
+        let marker = if ascii { LineMarker::Dot } else { self.marker };
+        let mut painter = Painter::new(self.width, self.height, marker);
+        painter.ascii = ascii;
+        if let Some(draw) = &self.draw {
+            draw(&mut painter);
+        }
+        painter
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Cell {
+    bits: u8,
+    color: Option<Color>,
+    text: Option<(char, Style)>,
+}
+
+/// Drawing context for a [`Canvas`].
+///
+/// Dot coordinates start at the top left; with the default braille marker
+/// there are 2 dots across and 4 down per cell. Anything drawn outside the
+/// canvas is clipped.
+#[derive(Debug, Clone)]
+pub struct Painter {
+    width: usize,
+    height: usize,
+    marker: LineMarker,
+    ascii: bool,
+    cells: Vec<Cell>,
+}
+
+impl Painter {
+    /// Create a blank painter `width` × `height` cells.
+    pub fn new(width: usize, height: usize, marker: LineMarker) -> Self {
+        Self {
+            width,
+            height,
+            marker,
+            ascii: false,
+            cells: vec![Cell::default(); width * height],
+        }
+    }
+
+    /// Size in dots, across and down.
+    pub fn size(&self) -> (usize, usize) {
+        let (dx, dy) = self.marker.resolution();
+        (self.width * dx, self.height * dy)
+    }
+
+    /// Size in cells, across and down.
+    pub fn cell_size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Erase everything.
+    pub fn clear(&mut self) {
+        self.cells.fill(Cell::default());
+    }
+
+    /// Set the dot at (`x`, `y`).
+    pub fn point(&mut self, x: isize, y: isize, color: Color) {
+        let (dots_x, dots_y) = self.size();
+        if x < 0 || y < 0 || x as usize >= dots_x || y as usize >= dots_y {
+            return;
+        }
+        let (x, y) = (x as usize, y as usize);
+        let (dx, dy) = self.marker.resolution();
+        let bit = self.marker.bit(x % dx, y % dy);
+        let cell = &mut self.cells[(y / dy) * self.width + x / dx];
+        cell.bits |= bit;
+        cell.color = Some(color);
+    }
+
+    /// Draw a line between two dots, both included.
+    pub fn line(&mut self, from: (isize, isize), to: (isize, isize), color: Color) {
+        let (mut x, mut y) = from;
+        let (dx, dy) = ((to.0 - x).abs(), -(to.1 - y).abs());
+        let (sx, sy) = ((to.0 - x).signum(), (to.1 - y).signum());
+        let mut err = dx + dy;
+        loop {
+            self.point(x, y, color);
+            if (x, y) == to {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// Draw the outline of a rectangle `width` × `height` dots.
+    pub fn rect(&mut self, x: isize, y: isize, width: isize, height: isize, color: Color) {
+        if width <= 0 || height <= 0 {
+            return;
+        }
+        let (right, bottom) = (x + width - 1, y + height - 1);
+        self.line((x, y), (right, y), color);
+        self.line((x, bottom), (right, bottom), color);
+        self.line((x, y), (x, bottom), color);
+        self.line((right, y), (right, bottom), color);
+    }
+
+    /// Fill a rectangle `width` × `height` dots.
+    pub fn fill_rect(&mut self, x: isize, y: isize, width: isize, height: isize, color: Color) {
+        for row in y..y + height {
+            for col in x..x + width {
+                self.point(col, row, color);
+            }
+        }
+    }
+
+    /// Write `text` starting at cell (`col`, `row`), covering any dots.
+    pub fn text(&mut self, col: usize, row: usize, text: &str, style: Style) {
+        if row >= self.height {
+            return;
+        }
+        for (i, c) in text.chars().enumerate() {
+            if col + i >= self.width {
+                break;
+            }
+            self.cells[row * self.width + col + i].text = Some((c, style));
+        }
+    }
+
+    /// The drawn rows, top first: each cell's character and style.
+    pub fn rows(&self) -> Vec<Vec<(char, Style)>> {
+        self.cells
+            .chunks(self.width.max(1))
+            .take(self.height)
+            .map(|row| {
+                row.iter()
+                    .map(|cell| match (cell.text, cell.bits) {
+                        (Some(text), _) => text,
+                        (None, 0) => (' ', Style::new()),
+                        (None, bits) => (
+                            self.marker.cell(bits, self.ascii),
+                            cell.color.map(|c| Style::new().fg(c)).unwrap_or_default(),
+                        ),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// A component that shows whatever its `draw` closure paints.
+pub struct Canvas;
+
+impl Component for Canvas {
+    type Props = CanvasProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let lines = props
+            .paint()
+            .rows()
+            .into_iter()
+            .map(|row| {
+                // Runs of cells in the same style become one span
+                let mut spans = Vec::new();
+                let mut run = String::new();
+                let mut run_style = Style::new();
+                for (c, style) in row {
+                    if style != run_style && !run.is_empty() {
+                        spans.push(Element::styled_text(std::mem::take(&mut run), run_style));
+                    }
+                    run_style = style;
+                    run.push(c);
+                }
+                spans.push(Element::styled_text(run, run_style));
+                Element::Fragment(spans)
+            })
+            .collect();
+        Element::Fragment(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(painter: &Painter) -> Vec<String> {
+        painter
+            .rows()
+            .iter()
+            .map(|row| row.iter().map(|(c, _)| c).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_painter_points_and_clipping() {
+        let mut p = Painter::new(2, 1, LineMarker::Braille);
+        assert_eq!(p.size(), (4, 4));
+        p.point(0, 0, Color::Red);
+        p.point(3, 3, Color::Red);
+        p.point(-1, 0, Color::Red);
+        p.point(4, 0, Color::Red);
+        assert_eq!(chars(&p), ["⠁⢀"]);
+        p.clear();
+        assert_eq!(chars(&p), ["  "]);
+    }
+
+    #[test]
+    fn test_painter_lines_and_rects() {
+        let mut p = Painter::new(3, 2, LineMarker::Dot);
+        p.line((0, 0), (2, 1), Color::Green);
+        assert_eq!(chars(&p), ["•  ", " ••"]);
+
+        let mut p = Painter::new(4, 3, LineMarker::Dot);
+        p.rect(0, 0, 4, 3, Color::Blue);
+        p.fill_rect(1, 1, 1, 1, Color::Blue);
+        assert_eq!(chars(&p), ["••••", "•• •", "••••"]);
+    }
+
+    #[test]
+    fn test_painter_text_covers_dots() {
+        let mut p = Painter::new(4, 1, LineMarker::Dot);
+        p.fill_rect(0, 0, 4, 1, Color::Red);
+        p.text(1, 0, "hello", Style::new().fg(Color::White));
+        let row = &p.rows()[0];
+        assert_eq!(row.iter().map(|(c, _)| c).collect::<String>(), "•hel");
+        assert_eq!(row[0].1, Style::new().fg(Color::Red));
+        assert_eq!(row[1].1, Style::new().fg(Color::White));
+    }
+
+    #[test]
+    fn test_canvas_render_runs_closure() {
+        let props = CanvasProps::new(2, 1)
+            .glyphs(GlyphSet::Ascii)
+            .marker(LineMarker::Braille)
+            .draw(|p| {
+                assert_eq!(p.size(), (2, 1));
+                p.point(1, 0, Color::Red);
+            });
+        let Element::Fragment(lines) = Canvas::render(&props) else {
+            panic!("expected fragment");
+        };
+        assert_eq!(lines.len(), 1);
+        assert_eq!(chars(&props.paint()), [" *"]);
+    }
+}
@@ -0,0 +1,566 @@
+//! Chart primitives - scales, axes and legends shared by the chart components.
+//!
+//! [`BarChart`](super::BarChart) and [`Sparkline`](super::Sparkline) map their
+//! values through a [`Scale`], and BarChart draws its scale line with an
+//! [`Axis`] and its legend with a [`Legend`]. Custom charts can use the same
+//! pieces so their axes and legends look the same as the built-in ones.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use blaeck::prelude::*;
+//!
+//! let scale = Scale::log(1.0, 10_000.0);
+//! let axis = Axis::new(scale).title("requests");
+//! let legend = Legend::new()
+//!     .item("api", Color::Cyan)
+//!     .item("web", Color::Magenta);
+//!
+//! Element::column(vec![
+//!     my_plot(&scale, 40),
+//!     axis.render_horizontal(40),
+//!     legend.render(),
+//! ])
+//! ```
+
+use crate::components::calendar::Date;
+use crate::element::Element;
+use crate::style::{Color, Modifier, Style};
+
+/// How a [`Scale`] maps values onto its range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScaleKind {
+    /// Evenly spaced values.
+    #[default]
+    Linear,
+    /// Base-10 logarithmic. Values at or below zero map to the minimum.
+    Log,
+    /// Seconds (since the Unix epoch, or since any start time). Ticks land on
+    /// whole seconds, minutes, hours or days and are labeled as UTC times.
+    Time,
+}
+
+/// Maps data values in `min..=max` to positions from 0.0 to 1.0, and picks
+/// and formats tick values for an [`Axis`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scale {
+    /// How values are mapped.
+    pub kind: ScaleKind,
+    /// Lowest value of the domain.
+    pub min: f64,
+    /// Highest value of the domain.
+    pub max: f64,
+}
+
+impl Default for Scale {
+    fn default() -> Self {
+        Self::linear(0.0, 1.0)
+    }
+}
+
+impl Scale {
+    /// Create a linear scale.
+    pub fn linear(min: f64, max: f64) -> Self {
+        Self {
+            kind: ScaleKind::Linear,
+            min,
+            max,
+        }
+    }
+
+    /// Create a base-10 log scale. Both ends must be positive; a
+    /// non-positive `min` is raised to a tiny positive value.
+    pub fn log(min: f64, max: f64) -> Self {
+        Self {
+            kind: ScaleKind::Log,
+            min: min.max(f64::MIN_POSITIVE),
+            max,
+        }
+    }
+
+    /// Create a time scale over seconds.
+    pub fn time(min: f64, max: f64) -> Self {
+        Self {
+            kind: ScaleKind::Time,
+            min,
+            max,
+        }
+    }
+
+    /// Map `value` to 0.0 (at `min`) through 1.0 (at `max`), clamped.
+    ///
+    /// An empty domain (`min == max`) maps everything to 0.5.
+    pub fn normalize(&self, value: f64) -> f64 {
+        let (lo, hi, v) = match self.kind {
+            ScaleKind::Log => (
+                self.min.log10(),
+                self.max.max(self.min).log10(),
+                value.max(self.min).log10(),
+            ),
+            ScaleKind::Linear | ScaleKind::Time => (self.min, self.max, value),
+        };
+        if !(hi - lo).is_normal() {
+            return 0.5;
+        }
+        ((v - lo) / (hi - lo)).clamp(0.0, 1.0)
+    }
+
+    /// Cell index of `value` on an axis `len` cells long.
+    pub fn position(&self, value: f64, len: usize) -> usize {
+        (self.normalize(value) * len.saturating_sub(1) as f64).round() as usize
+    }
+
+    /// Round tick values inside the domain, about `count` of them.
+    pub fn ticks(&self, count: usize) -> Vec<f64> {
+        let count = count.max(1);
+        let span = self.max - self.min;
+        if !span.is_finite() || span <= 0.0 {
+            return vec![self.min];
+        }
+        match self.kind {
+            ScaleKind::Linear => stepped_ticks(self.min, self.max, nice_step(span / count as f64)),
+            ScaleKind::Time => stepped_ticks(self.min, self.max, time_step(span / count as f64)),
+            ScaleKind::Log => {
+                let first = self.min.log10().ceil() as i32;
+                let last = self.max.log10().floor() as i32;
+                if last <= first {
+                    // Less than a decade: powers of ten are too sparse
+                    return Self::linear(self.min, self.max).ticks(count);
+                }
+                let every = ((last - first) as usize).div_ceil(count).max(1);
+                (first..=last)
+                    .step_by(every)
+                    .map(|e| 10f64.powi(e))
+                    .collect()
+            }
+        }
+    }
+
+    /// Format a value as a short tick label.
+    ///
+    /// Numbers get at most two decimals and a k/M/G suffix from a thousand
+    /// up; times show `HH:MM:SS`, `HH:MM` or `MM-DD` depending on the span.
+    pub fn format(&self, value: f64) -> String {
+        match self.kind {
+            ScaleKind::Linear | ScaleKind::Log => format_number(value),
+            ScaleKind::Time => format_time(value, self.max - self.min),
+        }
+    }
+}
+
+/// A 1, 2 or 5 times a power of ten step no smaller than `raw`.
+fn nice_step(raw: f64) -> f64 {
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let norm = raw / magnitude;
+    let nice = if norm <= 1.0 {
+        1.0
+    } else if norm <= 2.0 {
+        2.0
+    } else if norm <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * magnitude
+}
+
+/// A step in seconds that lands on clock boundaries.
+fn time_step(raw: f64) -> f64 {
+    const STEPS: [f64; 17] = [
+        1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 900.0, 1800.0, 3600.0, 7200.0,
+        10800.0, 21600.0, 43200.0,
+    ];
+    const DAY: f64 = 86400.0;
+    STEPS
+        .iter()
+        .copied()
+        .find(|&step| step >= raw)
+        .unwrap_or_else(|| nice_step(raw / DAY).max(1.0) * DAY)
+}
+
+/// Multiples of `step` from `min` to `max`.
+fn stepped_ticks(min: f64, max: f64, step: f64) -> Vec<f64> {
+    let first = (min / step).ceil() as i64;
+    let last = (max / step + 1e-9).floor() as i64;
+    (first..=last)
+        .map(|i| {
+            let v = i as f64 * step;
+            // Avoid labels like "-0"
+            if v == 0.0 {
+                0.0
+            } else {
+                v
+            }
+        })
+        .collect()
+}
+
+fn format_number(value: f64) -> String {
+    let (scaled, suffix) = match value.abs() {
+        a if a >= 1e9 => (value / 1e9, "G"),
+        a if a >= 1e6 => (value / 1e6, "M"),
+        a if a >= 1e3 => (value / 1e3, "k"),
+        _ => (value, ""),
+    };
+    let mut s = format!("{:.2}", scaled);
+    if s.contains('.') {
+        s.truncate(s.trim_end_matches('0').trim_end_matches('.').len());
+    }
+    if s == "-0" {
+        s = "0".to_string();
+    }
+    s.push_str(suffix);
+    s
+}
+
+fn format_time(seconds: f64, span: f64) -> String {
+    let secs = seconds.floor() as i64;
+    let days = secs.div_euclid(86400);
+    let of_day = secs.rem_euclid(86400);
+    let (h, m, s) = (of_day / 3600, of_day % 3600 / 60, of_day % 60);
+    if span >= 2.0 * 86400.0 {
+        let date = Date::from_days(days);
+        format!("{:02}-{:02}", date.month, date.day)
+    } else if span >= 120.0 {
+        format!("{:02}:{:02}", h, m)
+    } else {
+        format!("{:02}:{:02}:{:02}", h, m, s)
+    }
+}
+
+/// Tick labels for a [`Scale`], laid out along a row or a column of cells.
+///
+/// The axis asks the scale for up to `ticks` values and drops to fewer when
+/// the labels would collide, so the same axis works at any size.
+#[derive(Debug, Clone)]
+pub struct Axis {
+    /// Scale the ticks come from.
+    pub scale: Scale,
+    /// Preferred number of ticks.
+    pub ticks: usize,
+    /// Optional axis title, drawn under a horizontal axis.
+    pub title: Option<String>,
+    /// Label color (`None` = dimmed).
+    pub color: Option<Color>,
+    /// Custom label formatter (`None` = [`Scale::format`]).
+    pub formatter: Option<fn(f64) -> String>,
+}
+
+impl Axis {
+    /// Create an axis for a scale with about five ticks.
+    pub fn new(scale: Scale) -> Self {
+        Self {
+            scale,
+            ticks: 5,
+            title: None,
+            color: None,
+            formatter: None,
+        }
+    }
+
+    /// Set the preferred number of ticks.
+    #[must_use]
+    pub fn ticks(mut self, count: usize) -> Self {
+        self.ticks = count.max(1);
+        self
+    }
+
+    /// Set the axis title.
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the label color.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Format labels with a custom function.
+    #[must_use]
+    pub fn formatter(mut self, f: fn(f64) -> String) -> Self {
+        self.formatter = Some(f);
+        self
+    }
+
+    /// Style used for labels.
+    pub fn style(&self) -> Style {
+        match self.color {
+            Some(color) => Style::new().fg(color),
+            None => Style::new().add_modifier(Modifier::DIM),
+        }
+    }
+
+    fn label(&self, value: f64) -> String {
+        match self.formatter {
+            Some(f) => f(value),
+            None => self.scale.format(value),
+        }
+    }
+
+    /// Labels and their start columns along a row `len` cells wide.
+    ///
+    /// Each label is centered on its tick, pushed inside the row at the
+    /// ends, and kept at least one cell from its neighbour.
+    pub fn horizontal_labels(&self, len: usize) -> Vec<(usize, String)> {
+        let mut fallback = Vec::new();
+        for count in (1..=self.ticks).rev() {
+            let mut placed: Vec<(usize, String)> = Vec::new();
+            let mut fits = true;
+            for value in self.scale.ticks(count) {
+                let label = self.label(value);
+                let width = label.chars().count();
+                let center = self.scale.position(value, len);
+                let start = center
+                    .saturating_sub(width / 2)
+                    .min(len.saturating_sub(width));
+                if let Some((prev, prev_label)) = placed.last() {
+                    if start <= prev + prev_label.chars().count() {
+                        fits = false;
+                        continue;
+                    }
+                }
+                placed.push((start, label));
+            }
+            if fits {
+                return placed;
+            }
+            fallback = placed;
+        }
+        fallback
+    }
+
+    /// The label row for a horizontal axis `len` cells wide.
+    pub fn horizontal_line(&self, len: usize) -> String {
+        let mut line = String::new();
+        for (start, label) in self.horizontal_labels(len) {
+            let col = line.chars().count();
+            line.push_str(&" ".repeat(start.saturating_sub(col)));
+            line.push_str(&label);
+        }
+        line
+    }
+
+    /// Labels for a vertical axis `len` rows tall, top row first.
+    ///
+    /// Rows without a tick are blank; all rows are right-aligned to the
+    /// widest label so they can sit to the left of a plot.
+    pub fn vertical_labels(&self, len: usize) -> Vec<String> {
+        let mut rows = vec![String::new(); len];
+        if len == 0 {
+            return rows;
+        }
+        let mut ticks = Vec::new();
+        for count in (1..=self.ticks).rev() {
+            ticks = self.scale.ticks(count);
+            let mut seen: Vec<usize> = ticks.iter().map(|&v| self.scale.position(v, len)).collect();
+            seen.dedup();
+            if seen.len() == ticks.len() {
+                break;
+            }
+        }
+        for value in ticks {
+            let row = len - 1 - self.scale.position(value, len);
+            if rows[row].is_empty() {
+                rows[row] = self.label(value);
+            }
+        }
+        let width = rows.iter().map(|r| r.chars().count()).max().unwrap_or(0);
+        rows.iter_mut()
+            .for_each(|r| *r = format!("{:>width$}", r, width = width));
+        rows
+    }
+
+    /// Render a horizontal axis `len` cells wide: the labels, then the
+    /// title centered under them if there is one.
+    pub fn render_horizontal(&self, len: usize) -> Element {
+        let mut lines = vec![Element::styled_text(
+            self.horizontal_line(len),
+            self.style(),
+        )];
+        if let Some(title) = &self.title {
+            lines.push(Element::styled_text(
+                format!("{:^len$}", title, len = len),
+                self.style(),
+            ));
+        }
+        Element::column(lines)
+    }
+
+    /// Render a vertical axis `len` rows tall.
+    pub fn render_vertical(&self, len: usize) -> Element {
+        Element::column(
+            self.vertical_labels(len)
+                .into_iter()
+                .map(|row| Element::styled_text(row, self.style()))
+                .collect(),
+        )
+    }
+}
+
+/// One entry in a [`Legend`].
+#[derive(Debug, Clone)]
+pub struct LegendItem {
+    /// Series name.
+    pub label: String,
+    /// Series color, used for the swatch.
+    pub color: Option<Color>,
+}
+
+/// A row of colored swatches naming each series of a chart.
+#[derive(Debug, Clone)]
+pub struct Legend {
+    /// Entries, in display order.
+    pub items: Vec<LegendItem>,
+    /// Swatch character.
+    pub symbol: char,
+    /// Spaces between entries.
+    pub gap: usize,
+    /// Label color (`None` = default).
+    pub label_color: Option<Color>,
+}
+
+impl Default for Legend {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            symbol: '■',
+            gap: 2,
+            label_color: None,
+        }
+    }
+}
+
+impl Legend {
+    /// Create an empty legend.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an entry.
+    #[must_use]
+    pub fn item(mut self, label: impl Into<String>, color: Color) -> Self {
+        self.items.push(LegendItem {
+            label: label.into(),
+            color: Some(color),
+        });
+        self
+    }
+
+    /// Set the swatch character.
+    #[must_use]
+    pub fn symbol(mut self, symbol: char) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Set the spacing between entries.
+    #[must_use]
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the label color.
+    #[must_use]
+    pub fn label_color(mut self, color: Color) -> Self {
+        self.label_color = Some(color);
+        self
+    }
+
+    /// Whether the legend has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The legend as plain text.
+    pub fn render_string(&self) -> String {
+        self.items
+            .iter()
+            .map(|item| format!("{} {}", self.symbol, item.label))
+            .collect::<Vec<_>>()
+            .join(&" ".repeat(self.gap))
+    }
+
+    /// Render the legend as one row of styled spans.
+    pub fn render(&self) -> Element {
+        let label_style = self
+            .label_color
+            .map(|c| Style::new().fg(c))
+            .unwrap_or_default();
+        let mut spans = Vec::new();
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                spans.push(Element::text(" ".repeat(self.gap)));
+            }
+            let swatch = item.color.map(|c| Style::new().fg(c)).unwrap_or_default();
+            spans.push(Element::styled_text(self.symbol.to_string(), swatch));
+            spans.push(Element::styled_text(
+                format!(" {}", item.label),
+                label_style,
+            ));
+        }
+        Element::Fragment(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_normalize_and_ticks() {
+        let linear = Scale::linear(0.0, 100.0);
+        assert_eq!(linear.normalize(25.0), 0.25);
+        assert_eq!(linear.normalize(150.0), 1.0);
+        assert_eq!(linear.ticks(5), vec![0.0, 20.0, 40.0, 60.0, 80.0, 100.0]);
+        assert_eq!(Scale::linear(3.0, 3.0).normalize(3.0), 0.5);
+
+        let log = Scale::log(1.0, 1000.0);
+        assert!((log.normalize(10.0) - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(log.ticks(5), vec![1.0, 10.0, 100.0, 1000.0]);
+
+        let time = Scale::time(0.0, 3600.0);
+        assert_eq!(time.ticks(4), vec![0.0, 900.0, 1800.0, 2700.0, 3600.0]);
+        assert_eq!(time.format(5400.0), "01:30");
+        assert_eq!(Scale::time(0.0, 60.0).format(45.0), "00:00:45");
+        assert_eq!(
+            Scale::time(0.0, 30.0 * 86400.0).format(86400.0 * 45.0),
+            "02-15"
+        );
+    }
+
+    #[test]
+    fn test_scale_format_number() {
+        let scale = Scale::default();
+        assert_eq!(scale.format(0.5), "0.5");
+        assert_eq!(scale.format(100.0), "100");
+        assert_eq!(scale.format(2500.0), "2.5k");
+        assert_eq!(scale.format(-3_000_000.0), "-3M");
+    }
+
+    #[test]
+    fn test_axis_horizontal_drops_crowded_ticks() {
+        let axis = Axis::new(Scale::linear(0.0, 100.0));
+        assert_eq!(axis.horizontal_line(20), "0  20  40 60  80 100");
+        assert_eq!(axis.horizontal_line(8), "0    100");
+    }
+
+    #[test]
+    fn test_axis_vertical_labels() {
+        let axis = Axis::new(Scale::linear(0.0, 10.0)).ticks(2);
+        assert_eq!(axis.vertical_labels(3), vec!["10", " 5", " 0"]);
+    }
+
+    #[test]
+    fn test_legend_render_string() {
+        let legend = Legend::new()
+            .item("api", Color::Cyan)
+            .item("web", Color::Red);
+        assert_eq!(legend.render_string(), "■ api  ■ web");
+        assert!(!legend.is_empty());
+    }
+}
@@ -15,6 +15,7 @@
 //! - [`Confirm`](super::Confirm) — Yes/no question (not a toggle)
 
 use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
 use crate::style::{Color, Modifier, Style};
 
 /// Style for checkbox indicators.
@@ -34,6 +35,16 @@ pub enum CheckboxStyle {
 }
 
 impl CheckboxStyle {
+    /// The style to draw with under `glyphs`: non-ASCII styles fall back
+    /// to [`CheckboxStyle::Bracket`] when only ASCII is available.
+    pub fn for_glyphs(self, glyphs: GlyphSet) -> Self {
+        if glyphs.is_ascii() {
+            CheckboxStyle::Bracket
+        } else {
+            self
+        }
+    }
+
     /// Get the characters for checked and unchecked states.
     pub fn chars(&self) -> (&'static str, &'static str) {
         match self {
@@ -67,6 +78,8 @@ pub struct CheckboxProps {
     pub disabled: bool,
     /// Focus indicator character.
     pub focus_indicator: Option<String>,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
 }
 
 impl Default for CheckboxProps {
@@ -81,6 +94,7 @@ impl Default for CheckboxProps {
             label_color: None,
             disabled: false,
             focus_indicator: Some("> ".to_string()),
+            glyphs: None,
         }
     }
 }
@@ -178,9 +192,19 @@ impl CheckboxProps {
         self
     }
 
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
     /// Build the display string.
     pub fn render_string(&self) -> String {
-        let (checked_str, unchecked_str) = self.style.chars();
+        let (checked_str, unchecked_str) = self
+            .style
+            .for_glyphs(GlyphSet::resolve(self.glyphs))
+            .chars();
         let indicator = if self.checked {
             checked_str
         } else {
@@ -0,0 +1,349 @@
+//! Collapsible component - a titled section whose children can be hidden.
+//!
+//! The Collapsible draws a header line with an expand indicator and, when
+//! expanded, its children indented below it. Stack several and drive them
+//! with `AccordionState` for an accordion where one section is open at a time.
+//!
+//! ## When to use Collapsible
+//!
+//! - Details that most users don't need to see ("Advanced options")
+//! - Grouping long output into sections that can be folded away
+//!
+//! ## See also
+//!
+//! - [`TreeView`](super::TreeView) — Expandable hierarchy of single-line nodes
+//! - [`Tabs`](super::Tabs) — Switch between sections instead of stacking them
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut sections = AccordionState::new(2);
+//!
+//! // In the input handler:
+//! sections.handle_key(&key);
+//!
+//! // In render:
+//! element! {
+//!     Box(flex_direction: FlexDirection::Column) {
+//!         Collapsible(title: "General", expanded: sections.is_open(0), focused: sections.focused == 0) {
+//!             Text(content: "Name: blaeck")
+//!         }
+//!         Collapsible(title: "Advanced", expanded: sections.is_open(1), focused: sections.focused == 1) {
+//!             Text(content: "Threads: 4")
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ## Animated reveal
+//!
+//! `reveal` is the fraction of children shown, so an expanding section can
+//! grow one child at a time:
+//!
+//! ```ignore
+//! CollapsibleProps::new("Log")
+//!     .expanded(true)
+//!     .reveal(timer.progress(300, Easing::EaseOut) as f32)
+//! ```
+
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::input::Key;
+use crate::layout::{FlexDirection, LayoutStyle};
+use crate::style::{Color, Modifier, Style};
+use crossterm::event::KeyCode;
+
+/// Properties for the Collapsible component.
+#[derive(Debug, Clone)]
+pub struct CollapsibleProps {
+    /// Header text.
+    pub title: String,
+    /// Whether the children are shown.
+    pub expanded: bool,
+    /// Whether the header is focused (drawn bold).
+    pub focused: bool,
+    /// Fraction of the children shown while expanded, from 0.0 to 1.0.
+    pub reveal: f32,
+    /// Columns the children are indented by.
+    pub indent: u16,
+    /// Header color.
+    pub title_color: Option<Color>,
+    /// Indicator color (defaults to the title color).
+    pub indicator_color: Option<Color>,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for CollapsibleProps {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            expanded: false,
+            focused: false,
+            reveal: 1.0,
+            indent: 2,
+            title_color: None,
+            indicator_color: None,
+            glyphs: None,
+        }
+    }
+}
+
+impl CollapsibleProps {
+    /// Create props for a collapsed section with the given title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set whether the children are shown.
+    #[must_use]
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Set the focused state.
+    #[must_use]
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Show only this fraction of the children, for animating the reveal.
+    #[must_use]
+    pub fn reveal(mut self, reveal: f32) -> Self {
+        self.reveal = reveal;
+        self
+    }
+
+    /// Set the indent of the children.
+    #[must_use]
+    pub fn indent(mut self, indent: u16) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Set the header color.
+    #[must_use]
+    pub fn title_color(mut self, color: Color) -> Self {
+        self.title_color = Some(color);
+        self
+    }
+
+    /// Set the indicator color.
+    #[must_use]
+    pub fn indicator_color(mut self, color: Color) -> Self {
+        self.indicator_color = Some(color);
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// The expand indicator for the current state.
+    pub fn indicator(&self) -> &'static str {
+        let ascii = GlyphSet::resolve(self.glyphs).is_ascii();
+        match (self.expanded, ascii) {
+            (true, false) => "▼",
+            (false, false) => "▶",
+            (true, true) => "v",
+            (false, true) => ">",
+        }
+    }
+
+    /// The header line, e.g. "▶ Advanced".
+    pub fn header_string(&self) -> String {
+        format!("{} {}", self.indicator(), self.title)
+    }
+
+    /// Header spans: the indicator and the title.
+    pub fn header_spans(&self) -> Vec<(String, Style)> {
+        let mut title_style = Style::new();
+        if let Some(color) = self.title_color {
+            title_style = title_style.fg(color);
+        }
+        if self.focused {
+            title_style = title_style.add_modifier(Modifier::BOLD);
+        }
+        let indicator_style = match self.indicator_color {
+            Some(color) => title_style.fg(color),
+            None => title_style,
+        };
+        vec![
+            (format!("{} ", self.indicator()), indicator_style),
+            (self.title.clone(), title_style),
+        ]
+    }
+
+    /// How many of `total` children are shown.
+    pub fn visible_children(&self, total: usize) -> usize {
+        if !self.expanded {
+            return 0;
+        }
+        let reveal = self.reveal.clamp(0.0, 1.0);
+        ((total as f32 * reveal).ceil() as usize).min(total)
+    }
+
+    /// Layout of the whole section: the header above the children.
+    pub fn to_layout_style(&self) -> LayoutStyle {
+        LayoutStyle {
+            flex_direction: FlexDirection::Column,
+            flex_shrink: 0.0,
+            ..Default::default()
+        }
+    }
+
+    /// Layout of the indented box holding the children.
+    pub fn content_layout_style(&self) -> LayoutStyle {
+        LayoutStyle {
+            flex_direction: FlexDirection::Column,
+            padding_left: Some(f32::from(self.indent)),
+            flex_shrink: 0.0,
+            ..Default::default()
+        }
+    }
+}
+
+/// A titled section whose children are hidden while collapsed.
+///
+/// The renderer lays out the header and the visible children and draws
+/// them directly.
+pub struct Collapsible;
+
+impl Component for Collapsible {
+    type Props = CollapsibleProps;
+
+    fn render(props: &Self::Props) -> Element {
+        // Like Box, the children are drawn by the renderer; this is the
+        // header on its own
+        Element::Fragment(
+            props
+                .header_spans()
+                .into_iter()
+                .map(|(text, style)| Element::styled_text(text, style))
+                .collect(),
+        )
+    }
+}
+
+/// Helper struct for a stack of collapsible sections where at most one is
+/// open at a time.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccordionState {
+    /// Section with keyboard focus.
+    pub focused: usize,
+    /// Section currently open, if any.
+    pub open: Option<usize>,
+    /// Number of sections.
+    pub count: usize,
+}
+
+impl AccordionState {
+    /// Create a state with every section closed and the first focused.
+    pub fn new(count: usize) -> Self {
+        Self {
+            focused: 0,
+            open: None,
+            count,
+        }
+    }
+
+    /// Whether section `index` is open.
+    pub fn is_open(&self, index: usize) -> bool {
+        self.open == Some(index)
+    }
+
+    /// Open section `index`, closing any other.
+    pub fn open(&mut self, index: usize) {
+        if index < self.count {
+            self.open = Some(index);
+        }
+    }
+
+    /// Close the open section.
+    pub fn close(&mut self) {
+        self.open = None;
+    }
+
+    /// Open the focused section, or close it if it's open.
+    pub fn toggle(&mut self) {
+        if self.is_open(self.focused) {
+            self.close();
+        } else {
+            self.open(self.focused);
+        }
+    }
+
+    /// Move focus to the previous section.
+    pub fn up(&mut self) {
+        self.focused = self.focused.saturating_sub(1);
+    }
+
+    /// Move focus to the next section.
+    pub fn down(&mut self) {
+        if self.focused < self.count.saturating_sub(1) {
+            self.focused += 1;
+        }
+    }
+
+    /// Handle Up/Down and Home/End to move focus, Enter/Space to toggle,
+    /// Right to open and Left to close.
+    ///
+    /// Returns true if the key was handled.
+    pub fn handle_key(&mut self, key: &Key) -> bool {
+        match key.code {
+            KeyCode::Up => self.up(),
+            KeyCode::Down => self.down(),
+            KeyCode::Home => self.focused = 0,
+            KeyCode::End => self.focused = self.count.saturating_sub(1),
+            KeyCode::Enter | KeyCode::Char(' ') => self.toggle(),
+            KeyCode::Right => self.open(self.focused),
+            KeyCode::Left => self.close(),
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collapsible_header_and_reveal() {
+        let props = CollapsibleProps::new("Advanced").glyphs(GlyphSet::Unicode);
+        assert_eq!(props.header_string(), "▶ Advanced");
+        assert_eq!(props.visible_children(4), 0);
+
+        let props = props.expanded(true);
+        assert_eq!(props.header_string(), "▼ Advanced");
+        assert_eq!(props.visible_children(4), 4);
+        assert_eq!(props.clone().reveal(0.3).visible_children(4), 2);
+        assert_eq!(props.clone().reveal(0.0).visible_children(4), 0);
+        assert_eq!(props.glyphs(GlyphSet::Ascii).header_string(), "v Advanced");
+    }
+
+    #[test]
+    fn test_accordion_state_keys() {
+        let mut state = AccordionState::new(3);
+        assert!(state.handle_key(&Key::new(KeyCode::Enter)));
+        assert!(state.is_open(0));
+        state.handle_key(&Key::new(KeyCode::Down));
+        state.handle_key(&Key::new(KeyCode::Char(' ')));
+        assert_eq!(state.open, Some(1));
+        state.handle_key(&Key::new(KeyCode::Enter));
+        assert_eq!(state.open, None);
+        state.handle_key(&Key::new(KeyCode::End));
+        state.handle_key(&Key::new(KeyCode::Right));
+        assert!(state.is_open(2));
+        assert!(!state.handle_key(&Key::new(KeyCode::Char('x'))));
+    }
+}
@@ -0,0 +1,616 @@
+//! ContextMenu component - popup menu with separators and submenus.
+//!
+//! A ContextMenu is a bordered list of actions that opens at a position and
+//! floats over the rest of the UI. Items can show a shortcut, be disabled,
+//! be grouped with separators, or open a submenu to the side.
+//! `ContextMenuState` tracks which items are highlighted at each open level.
+//!
+//! ## When to use ContextMenu
+//!
+//! - Actions on the item under the cursor in a file manager or list
+//! - "More actions" menus that don't fit in a toolbar
+//!
+//! ## See also
+//!
+//! - [`Select`](super::Select) — Choose a value from an inline list
+//! - [`Modal`](super::Modal) — Dialog that needs an answer
+//! - [`overlay`](crate::overlay) — The positioning used to place menus
+//!
+//! # Example
+//!
+//! ```ignore
+//! let items = vec![
+//!     MenuItem::new("Open").shortcut("enter"),
+//!     MenuItem::submenu("Open with", vec![MenuItem::new("Editor"), MenuItem::new("Viewer")]),
+//!     MenuItem::separator(),
+//!     MenuItem::new("Delete").shortcut("del"),
+//! ];
+//! let mut menu = ContextMenuState::new();
+//!
+//! // In the input handler:
+//! if key.is_char('m') {
+//!     menu.open(&items);
+//! } else if let ContextMenuAction::Selected(path) = menu.handle_key(&key, &items) {
+//!     // path is [1, 0] for "Open with > Editor"
+//! }
+//!
+//! // In render, inside a root Box starting at the origin of `bounds`:
+//! let anchor = Rect::new(cursor_x, cursor_y, 1, 1);
+//! menu.render(&items, anchor, bounds)
+//! ```
+
+use crate::components::box_component::BorderStyle;
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::input::Key;
+use crate::overlay::{float, Align, Positioner, Rect, Side};
+use crate::style::{Color, Modifier, Style};
+use crossterm::event::KeyCode;
+use unicode_width::UnicodeWidthStr;
+
+/// An entry in a context menu.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MenuItem {
+    /// Text shown for the item.
+    pub label: String,
+    /// Shortcut shown right-aligned (e.g. "ctrl+c").
+    pub shortcut: Option<String>,
+    /// Whether the item can't be chosen. Disabled items are skipped when
+    /// moving the highlight.
+    pub disabled: bool,
+    /// Whether this is a separator line rather than an item.
+    pub separator: bool,
+    /// Items of the submenu this item opens. Empty for plain actions.
+    pub children: Vec<MenuItem>,
+}
+
+impl MenuItem {
+    /// An action with a label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// A separator line between groups of items.
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..Default::default()
+        }
+    }
+
+    /// An item that opens a submenu.
+    pub fn submenu(label: impl Into<String>, children: Vec<MenuItem>) -> Self {
+        Self {
+            label: label.into(),
+            children,
+            ..Default::default()
+        }
+    }
+
+    /// Set the shortcut shown next to the label.
+    #[must_use]
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Set the item as disabled.
+    #[must_use]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Whether this item opens a submenu.
+    pub fn has_submenu(&self) -> bool {
+        !self.children.is_empty()
+    }
+
+    /// Whether the highlight can rest on this item.
+    pub fn is_selectable(&self) -> bool {
+        !self.separator && !self.disabled
+    }
+}
+
+/// Properties for one panel of a context menu.
+#[derive(Debug, Clone)]
+pub struct ContextMenuProps {
+    /// Items in this panel.
+    pub items: Vec<MenuItem>,
+    /// Index of the highlighted item.
+    pub selected: Option<usize>,
+    /// Border style.
+    pub border_style: BorderStyle,
+    /// Border color.
+    pub border_color: Option<Color>,
+    /// Color of the highlighted item.
+    pub selected_color: Option<Color>,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for ContextMenuProps {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            selected: None,
+            border_style: BorderStyle::Round,
+            border_color: Some(Color::DarkGray),
+            selected_color: Some(Color::Cyan),
+            glyphs: None,
+        }
+    }
+}
+
+impl ContextMenuProps {
+    /// Create props with the given items.
+    pub fn new(items: Vec<MenuItem>) -> Self {
+        Self {
+            items,
+            ..Default::default()
+        }
+    }
+
+    /// Set the highlighted item.
+    #[must_use]
+    pub fn selected(mut self, index: Option<usize>) -> Self {
+        self.selected = index;
+        self
+    }
+
+    /// Set the border style.
+    #[must_use]
+    pub fn border_style(mut self, style: BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Set the border color.
+    #[must_use]
+    pub fn border_color(mut self, color: Color) -> Self {
+        self.border_color = Some(color);
+        self
+    }
+
+    /// Set the color of the highlighted item.
+    #[must_use]
+    pub fn selected_color(mut self, color: Color) -> Self {
+        self.selected_color = Some(color);
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    fn submenu_arrow(&self) -> &'static str {
+        if GlyphSet::resolve(self.glyphs).is_ascii() {
+            ">"
+        } else {
+            "▸"
+        }
+    }
+
+    /// Columns between the borders.
+    fn inner_width(&self) -> usize {
+        let arrow = self.submenu_arrow().width();
+        self.items
+            .iter()
+            .filter(|item| !item.separator)
+            .map(|item| {
+                let right = match (&item.shortcut, item.has_submenu()) {
+                    (_, true) => arrow,
+                    (Some(shortcut), false) => shortcut.width(),
+                    (None, false) => 0,
+                };
+                // One space of padding each side, two between label and shortcut
+                item.label.width() + if right > 0 { right + 2 } else { 0 } + 2
+            })
+            .max()
+            .unwrap_or(2)
+    }
+
+    /// Size of the panel in cells, borders included.
+    pub fn size(&self) -> (u16, u16) {
+        let width = self.inner_width() + 2;
+        let height = self.items.len() + 2;
+        (width as u16, height as u16)
+    }
+
+    /// The panel's lines, borders included.
+    pub fn render_lines(&self) -> Vec<Element> {
+        let chars = self
+            .border_style
+            .for_glyphs(GlyphSet::resolve(self.glyphs))
+            .chars();
+        let border = self
+            .border_color
+            .map(|c| Style::new().fg(c))
+            .unwrap_or_default();
+        let inner = self.inner_width();
+        let horizontal = chars.horizontal.to_string().repeat(inner);
+        let arrow = self.submenu_arrow();
+
+        let mut lines = vec![Element::styled_text(
+            format!("{}{}{}", chars.top_left, horizontal, chars.top_right),
+            border,
+        )];
+        for (i, item) in self.items.iter().enumerate() {
+            if item.separator {
+                lines.push(Element::styled_text(
+                    format!("{}{}{}", chars.vertical, horizontal, chars.vertical),
+                    border,
+                ));
+                continue;
+            }
+            let right = if item.has_submenu() {
+                arrow
+            } else {
+                item.shortcut.as_deref().unwrap_or("")
+            };
+            let gap = inner.saturating_sub(item.label.width() + right.width() + 2);
+            let text = format!(" {}{}{} ", item.label, " ".repeat(gap), right);
+
+            let mut style = Style::new();
+            if item.disabled {
+                style = style.add_modifier(Modifier::DIM);
+            } else if self.selected == Some(i) {
+                if let Some(color) = self.selected_color {
+                    style = style.fg(color);
+                }
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            lines.push(Element::Fragment(vec![
+                Element::styled_text(chars.vertical.to_string(), border),
+                Element::styled_text(text, style),
+                Element::styled_text(chars.vertical.to_string(), border),
+            ]));
+        }
+        lines.push(Element::styled_text(
+            format!("{}{}{}", chars.bottom_left, horizontal, chars.bottom_right),
+            border,
+        ));
+        lines
+    }
+}
+
+/// A component that displays one panel of a context menu.
+///
+/// Use [`ContextMenuState::render`] to place the menu and its open
+/// submenus over the UI.
+pub struct ContextMenu;
+
+impl Component for ContextMenu {
+    type Props = ContextMenuProps;
+
+    fn render(props: &Self::Props) -> Element {
+        if props.items.is_empty() {
+            return Element::Empty;
+        }
+        Element::Fragment(props.render_lines())
+    }
+}
+
+/// What a key did to a context menu.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContextMenuAction {
+    /// The highlight moved or a submenu opened or closed.
+    Moved,
+    /// An item was chosen; the path of indices from the root menu. The
+    /// menu closes.
+    Selected(Vec<usize>),
+    /// The menu was closed without choosing.
+    Closed,
+    /// The key wasn't for the menu, or the menu is closed.
+    Ignored,
+}
+
+/// Open state and highlighted items of a context menu.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContextMenuState {
+    /// Highlighted index at each open level, root first. Empty when closed.
+    pub path: Vec<usize>,
+}
+
+/// The first selectable index at or after `from` moving by `step`.
+fn next_selectable(items: &[MenuItem], from: usize, forward: bool) -> Option<usize> {
+    let len = items.len();
+    (0..len)
+        .map(|i| {
+            if forward {
+                (from + i) % len
+            } else {
+                (from + len - i) % len
+            }
+        })
+        .find(|&i| items[i].is_selectable())
+}
+
+impl ContextMenuState {
+    /// A closed menu.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the menu with the first selectable item highlighted.
+    pub fn open(&mut self, items: &[MenuItem]) {
+        self.path = next_selectable(items, 0, true).into_iter().collect();
+    }
+
+    /// Close the menu and any submenus.
+    pub fn close(&mut self) {
+        self.path.clear();
+    }
+
+    /// Whether the menu is open.
+    pub fn is_open(&self) -> bool {
+        !self.path.is_empty()
+    }
+
+    /// Items of the deepest open level, or `None` when the path no longer
+    /// matches `items`.
+    fn level<'a>(&self, items: &'a [MenuItem]) -> Option<&'a [MenuItem]> {
+        let mut level = items;
+        for &i in &self.path[..self.path.len().saturating_sub(1)] {
+            level = &level.get(i)?.children;
+        }
+        Some(level)
+    }
+
+    /// Handle Up/Down (wrapping, skipping separators and disabled items),
+    /// Home/End, Right/Enter to open a submenu, Enter to choose, Left to
+    /// close a submenu and Esc to close the menu.
+    ///
+    /// If `items` changed and the open path no longer points at an item,
+    /// the menu closes.
+    pub fn handle_key(&mut self, key: &Key, items: &[MenuItem]) -> ContextMenuAction {
+        if !self.is_open() {
+            return ContextMenuAction::Ignored;
+        }
+        let level = self.level(items);
+        let Some(current) = self.path.last_mut() else {
+            return ContextMenuAction::Ignored;
+        };
+        let Some((level, item)) = level.and_then(|level| Some((level, level.get(*current)?)))
+        else {
+            self.close();
+            return ContextMenuAction::Closed;
+        };
+        match key.code {
+            KeyCode::Up => {
+                if let Some(i) = next_selectable(level, *current + level.len() - 1, false) {
+                    *current = i;
+                }
+            }
+            KeyCode::Down => {
+                if let Some(i) = next_selectable(level, *current + 1, true) {
+                    *current = i;
+                }
+            }
+            KeyCode::Home => {
+                if let Some(i) = next_selectable(level, 0, true) {
+                    *current = i;
+                }
+            }
+            KeyCode::End => {
+                if let Some(i) = next_selectable(level, level.len() - 1, false) {
+                    *current = i;
+                }
+            }
+            KeyCode::Right | KeyCode::Enter if item.has_submenu() => {
+                match next_selectable(&item.children, 0, true) {
+                    Some(i) => self.path.push(i),
+                    None => return ContextMenuAction::Ignored,
+                }
+            }
+            KeyCode::Enter => {
+                let path = std::mem::take(&mut self.path);
+                return ContextMenuAction::Selected(path);
+            }
+            KeyCode::Left if self.path.len() > 1 => {
+                self.path.pop();
+            }
+            KeyCode::Esc => {
+                self.close();
+                return ContextMenuAction::Closed;
+            }
+            _ => return ContextMenuAction::Ignored,
+        }
+        ContextMenuAction::Moved
+    }
+
+    /// The menu and its open submenus, floated next to `anchor` inside
+    /// `bounds`. Submenus open to the right of their item, or to the left
+    /// when there's no room. Returns [`Element::Empty`] when closed.
+    ///
+    /// The floats must be children of a Box that starts at the origin of
+    /// `bounds` (usually the root).
+    pub fn render(&self, items: &[MenuItem], anchor: Rect, bounds: Rect) -> Element {
+        if !self.is_open() {
+            return Element::Empty;
+        }
+        let mut panels = Vec::new();
+        let mut level = items;
+        let mut anchor = anchor;
+        let mut positioner = Positioner::new(Side::Bottom);
+        for &selected in &self.path {
+            // Stop at a stale path rather than index past `items`
+            if selected >= level.len() {
+                break;
+            }
+            let props = ContextMenuProps::new(level.to_vec()).selected(Some(selected));
+            let placement = positioner.place(anchor, props.size(), bounds);
+            let rect = placement.rect;
+            panels.push(float(
+                placement,
+                Element::node::<ContextMenu>(props, vec![]),
+            ));
+
+            // The next panel lines up with the highlighted item's row
+            anchor = Rect::new(rect.x, rect.y + 1 + selected as u16, rect.width, 1);
+            positioner = Positioner::new(Side::Right).align(Align::Start);
+            level = &level[selected].children;
+        }
+        Element::Fragment(panels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Vec<MenuItem> {
+        vec![
+            MenuItem::new("Open").shortcut("enter"),
+            MenuItem::submenu(
+                "Open with",
+                vec![MenuItem::new("Editor"), MenuItem::new("Viewer")],
+            ),
+            MenuItem::separator(),
+            MenuItem::new("Rename").disabled(true),
+            MenuItem::new("Delete"),
+        ]
+    }
+
+    #[test]
+    fn test_context_menu_render_lines() {
+        let props = ContextMenuProps::new(items())
+            .selected(Some(0))
+            .glyphs(GlyphSet::Unicode);
+        assert_eq!(props.size(), (16, 7));
+
+        let lines = props.render_lines();
+        assert_eq!(lines.len(), 7);
+        let text = |line: &Element| match line {
+            Element::Text { content, .. } => content.clone(),
+            Element::Fragment(spans) => spans
+                .iter()
+                .filter_map(|s| match s {
+                    Element::Text { content, .. } => Some(content.as_str()),
+                    _ => None,
+                })
+                .collect(),
+            _ => String::new(),
+        };
+        assert_eq!(text(&lines[0]), "╭──────────────╮");
+        assert_eq!(text(&lines[1]), "│ Open   enter │");
+        assert_eq!(text(&lines[2]), "│ Open with  ▸ │");
+        assert_eq!(text(&lines[3]), "│──────────────│");
+    }
+
+    #[test]
+    fn test_context_menu_state_navigation() {
+        let items = items();
+        let mut menu = ContextMenuState::new();
+        let key = |code| Key::new(code);
+        assert_eq!(
+            menu.handle_key(&key(KeyCode::Down), &items),
+            ContextMenuAction::Ignored
+        );
+
+        menu.open(&items);
+        assert_eq!(menu.path, [0]);
+        // Skips the separator and the disabled item
+        menu.handle_key(&key(KeyCode::Down), &items);
+        menu.handle_key(&key(KeyCode::Down), &items);
+        assert_eq!(menu.path, [4]);
+        // Wraps around
+        menu.handle_key(&key(KeyCode::Down), &items);
+        assert_eq!(menu.path, [0]);
+        menu.handle_key(&key(KeyCode::Up), &items);
+        assert_eq!(menu.path, [4]);
+
+        menu.handle_key(&key(KeyCode::Home), &items);
+        menu.handle_key(&key(KeyCode::Down), &items);
+        assert_eq!(
+            menu.handle_key(&key(KeyCode::Right), &items),
+            ContextMenuAction::Moved
+        );
+        assert_eq!(menu.path, [1, 0]);
+        menu.handle_key(&key(KeyCode::Left), &items);
+        assert_eq!(menu.path, [1]);
+        menu.handle_key(&key(KeyCode::Enter), &items);
+        menu.handle_key(&key(KeyCode::Down), &items);
+        assert_eq!(
+            menu.handle_key(&key(KeyCode::Enter), &items),
+            ContextMenuAction::Selected(vec![1, 1])
+        );
+        assert!(!menu.is_open());
+
+        menu.open(&items);
+        assert_eq!(
+            menu.handle_key(&key(KeyCode::Esc), &items),
+            ContextMenuAction::Closed
+        );
+    }
+
+    #[test]
+    fn test_context_menu_state_items_shrink() {
+        let three = vec![MenuItem::new("a"), MenuItem::new("b"), MenuItem::new("c")];
+        let mut menu = ContextMenuState::new();
+        let key = |code| Key::new(code);
+        menu.open(&three);
+        menu.handle_key(&key(KeyCode::Down), &three);
+        menu.handle_key(&key(KeyCode::Down), &three);
+        assert_eq!(menu.path, [2]);
+
+        let fewer = vec![MenuItem::new("a")];
+        let Element::Fragment(panels) =
+            menu.render(&fewer, Rect::new(0, 0, 1, 1), Rect::new(0, 0, 80, 24))
+        else {
+            panic!("Expected Fragment");
+        };
+        assert!(panels.is_empty());
+        assert_eq!(
+            menu.handle_key(&key(KeyCode::Down), &fewer),
+            ContextMenuAction::Closed
+        );
+        assert!(!menu.is_open());
+
+        // A stale submenu path closes too
+        let items = items();
+        menu.path = vec![1, 1];
+        assert_eq!(
+            menu.handle_key(&key(KeyCode::Up), &fewer),
+            ContextMenuAction::Closed
+        );
+        menu.path = vec![1, 1];
+        assert_eq!(
+            menu.handle_key(&key(KeyCode::Up), &items),
+            ContextMenuAction::Moved
+        );
+    }
+
+    #[test]
+    fn test_context_menu_render_places_submenu() {
+        let items = items();
+        let bounds = Rect::new(0, 0, 80, 24);
+        let anchor = Rect::new(10, 5, 1, 1);
+        let mut menu = ContextMenuState::new();
+        assert!(menu.render(&items, anchor, bounds).is_empty());
+
+        menu.path = vec![1, 0];
+        let Element::Fragment(panels) = menu.render(&items, anchor, bounds) else {
+            panic!("Expected Fragment");
+        };
+        assert_eq!(panels.len(), 2);
+        let inset = |panel: &Element| match panel {
+            Element::Node { props, .. } => {
+                let props = props.downcast_ref::<crate::components::BoxProps>().unwrap();
+                (props.inset_left.unwrap(), props.inset_top.unwrap())
+            }
+            _ => panic!("Expected Node"),
+        };
+        let (root_x, root_y) = inset(&panels[0]);
+        assert_eq!((root_x, root_y), (10.0, 6.0));
+        // Beside the "Open with" row of the root panel
+        assert_eq!(inset(&panels[1]).1, root_y + 2.0);
+        assert!(inset(&panels[1]).0 > root_x);
+    }
+}
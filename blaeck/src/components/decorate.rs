@@ -0,0 +1,127 @@
+//! Decoration combinators - wrap any element in a border, title, padding,
+//! background or centering box.
+//!
+//! Each function returns a new [`Element`] with the original wrapped in a
+//! [`Box`], so quick layouts don't need a `BoxProps` struct for every
+//! wrapper:
+//!
+//! ```ignore
+//! let panel = with_title(
+//!     with_padding(Element::text("All systems go"), 1.0),
+//!     "Status",
+//! );
+//! let dialog = centered(with_background(panel, Color::Rgb(30, 30, 40)));
+//! ```
+//!
+//! For anything beyond these basics, build the [`BoxProps`] directly.
+
+use crate::components::{BorderStyle, Box, BoxProps};
+use crate::element::Element;
+use crate::layout::{AlignItems, FlexDirection, JustifyContent};
+use crate::style::Color;
+use std::any::TypeId;
+
+/// Wrap `element` in a box with the given props.
+fn wrap(element: Element, props: BoxProps) -> Element {
+    Element::node::<Box>(props, vec![element])
+}
+
+/// Wrap an element in a border.
+pub fn with_border(element: Element, style: BorderStyle) -> Element {
+    wrap(element, BoxProps::new().with_border(style))
+}
+
+/// Wrap an element in a single-line border with a title in the top edge.
+///
+/// If `element` is already a bordered [`Box`], the title is added to that
+/// border instead of drawing a second one, so
+/// `with_title(with_border(el, BorderStyle::Round), "Logs")` gives one
+/// rounded box.
+pub fn with_title(mut element: Element, title: impl Into<String>) -> Element {
+    if let Element::Node { type_id, props, .. } = &mut element {
+        if *type_id == TypeId::of::<Box>() {
+            if let Some(props) = props.downcast_mut::<BoxProps>() {
+                if props.border_style.has_border() {
+                    props.title = Some(title.into());
+                    return element;
+                }
+            }
+        }
+    }
+    wrap(
+        element,
+        BoxProps::new()
+            .with_border(BorderStyle::Single)
+            .with_title(title),
+    )
+}
+
+/// Wrap an element with padding on all sides.
+///
+/// Vertical padding is in lines and horizontal padding in columns.
+pub fn with_padding(element: Element, padding: f32) -> Element {
+    wrap(element, BoxProps::new().with_padding(padding))
+}
+
+/// Fill the area behind an element with a background color.
+pub fn with_background(element: Element, color: Color) -> Element {
+    wrap(element, BoxProps::new().with_background(color))
+}
+
+/// Center an element horizontally and vertically in the space available
+/// to it.
+pub fn centered(element: Element) -> Element {
+    wrap(
+        element,
+        BoxProps {
+            flex_direction: FlexDirection::Row,
+            flex_grow: 1.0,
+            justify_content: Some(JustifyContent::Center),
+            align_items: Some(AlignItems::Center),
+            ..Default::default()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Blaeck;
+
+    fn render(element: Element, width: u16) -> Vec<String> {
+        let mut blaeck = Blaeck::with_size(Vec::new(), width, 10).unwrap();
+        blaeck.render(element).unwrap();
+        blaeck.frame_lines()
+    }
+
+    #[test]
+    fn test_with_title_reuses_border() {
+        let el = with_title(
+            with_border(Element::text("hello world"), BorderStyle::Round),
+            "Logs",
+        );
+        assert_eq!(
+            render(el, 20),
+            vec!["╭─ Logs ────╮", "│hello world│", "╰───────────╯"]
+        );
+
+        // Too long for the box: truncated
+        let el = with_title(Element::text("abcdefgh"), "A long title");
+        assert_eq!(render(el, 20)[0], "┌─ A l… ─┐");
+    }
+
+    #[test]
+    fn test_with_padding_and_centered() {
+        let lines = render(with_padding(Element::text("x"), 1.0), 10);
+        assert_eq!(lines, vec!["", " x", ""]);
+
+        let frame = BoxProps::new()
+            .with_border(BorderStyle::Single)
+            .with_width(11.0);
+        let lines = render(
+            Element::node::<Box>(frame, vec![centered(Element::text("mid"))]),
+            20,
+        );
+        assert_eq!(lines[1], "│   mid   │");
+    }
+}
@@ -3,6 +3,14 @@
 //! The Diff component displays file changes in unified diff format,
 //! with support for line numbers, colors, and chunk headers.
 //!
+//! [`DiffStyle::SideBySide`] shows the old and new text in two columns
+//! instead: context lines sit on both sides, runs of removed lines are
+//! paired with the added lines that follow them, and the words of a paired
+//! line that changed are highlighted.
+//!
+//! [`DiffProps::word_diff`] brings that word-level highlighting to the
+//! other styles, so a one-word edit in a long line stands out.
+//!
 //! ## When to use Diff
 //!
 //! - Showing code changes before committing
@@ -15,7 +23,11 @@
 //! - [`Markdown`](super::Markdown) — Formatted text display
 
 use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::palette::Palette;
 use crate::style::{Color, Modifier, Style};
+use crate::truncation::Truncation;
+use unicode_width::UnicodeWidthStr;
 
 /// Type of a diff line.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -117,6 +129,16 @@ pub enum DiffStyle {
     Minimal,
     /// With line numbers
     LineNumbers,
+    /// Old and new text in two columns, with line numbers
+    SideBySide,
+}
+
+/// A row of a side-by-side diff.
+enum SplitRow<'a> {
+    /// A chunk header across both columns.
+    Header(&'a DiffLine),
+    /// The old line on the left and the new line on the right.
+    Pair(Option<&'a DiffLine>, Option<&'a DiffLine>),
 }
 
 /// Properties for the Diff component.
@@ -144,6 +166,17 @@ pub struct DiffProps {
     pub dim_context: bool,
     /// Width for line number column (0 to auto-calculate).
     pub line_num_width: usize,
+    /// Width of each text column in side-by-side mode (0 = widest line).
+    pub side_width: usize,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+    /// Whether to highlight the changed words of paired removed/added
+    /// lines. Side-by-side mode always does.
+    pub word_diff: bool,
+    /// Background for changed words in added lines (`None` = reverse video).
+    pub added_word_bg: Option<Color>,
+    /// Background for changed words in removed lines (`None` = reverse video).
+    pub removed_word_bg: Option<Color>,
 }
 
 impl Default for DiffProps {
@@ -151,8 +184,8 @@ impl Default for DiffProps {
         Self {
             lines: Vec::new(),
             style: DiffStyle::Unified,
-            added_color: Color::Green,
-            removed_color: Color::Red,
+            added_color: Palette::detect().success,
+            removed_color: Palette::detect().error,
             context_color: Color::Reset,
             header_color: Color::Cyan,
             line_num_color: Color::DarkGray,
@@ -160,6 +193,11 @@ impl Default for DiffProps {
             show_prefix: true,
             dim_context: true,
             line_num_width: 0,
+            side_width: 0,
+            glyphs: None,
+            word_diff: false,
+            added_word_bg: None,
+            removed_word_bg: None,
         }
     }
 }
@@ -317,6 +355,41 @@ impl DiffProps {
         self
     }
 
+    /// Set the width of each column in side-by-side mode.
+    #[must_use]
+    pub fn side_width(mut self, width: usize) -> Self {
+        self.side_width = width;
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// Enable/disable highlighting changed words within changed lines.
+    #[must_use]
+    pub fn word_diff(mut self, enabled: bool) -> Self {
+        self.word_diff = enabled;
+        self
+    }
+
+    /// Set the background for changed words in added lines.
+    #[must_use]
+    pub fn added_word_bg(mut self, color: Color) -> Self {
+        self.added_word_bg = Some(color);
+        self
+    }
+
+    /// Set the background for changed words in removed lines.
+    #[must_use]
+    pub fn removed_word_bg(mut self, color: Color) -> Self {
+        self.removed_word_bg = Some(color);
+        self
+    }
+
     /// Calculate the width needed for line numbers.
     fn calc_line_num_width(&self) -> usize {
         if self.line_num_width > 0 {
@@ -339,16 +412,16 @@ impl DiffProps {
 
     /// Render a single line to a string.
     fn render_line(&self, line: &DiffLine, line_width: usize) -> String {
+        format!("{}{}", self.line_head(line, line_width), line.content)
+    }
+
+    /// What precedes a line's content: line numbers and prefix.
+    fn line_head(&self, line: &DiffLine, line_width: usize) -> String {
         let prefix = if self.show_prefix { line.prefix() } else { "" };
 
         match self.style {
-            DiffStyle::Minimal => {
-                format!("{}{}", prefix, line.content)
-            }
-            DiffStyle::Unified => {
-                format!("{}{}", prefix, line.content)
-            }
-            DiffStyle::LineNumbers => {
+            DiffStyle::Minimal | DiffStyle::Unified => prefix.to_string(),
+            DiffStyle::LineNumbers | DiffStyle::SideBySide => {
                 let old_num = line
                     .old_line
                     .map(|n| format!("{:>width$}", n, width = line_width))
@@ -359,16 +432,293 @@ impl DiffProps {
                     .unwrap_or_else(|| " ".repeat(line_width));
 
                 if line.line_type == DiffLineType::Header {
-                    line.content.clone()
+                    String::new()
                 } else {
-                    format!("{} {} {}{}", old_num, new_num, prefix, line.content)
+                    format!("{} {} {}", old_num, new_num, prefix)
+                }
+            }
+        }
+    }
+
+    /// The style of a line of the given type.
+    fn line_style(&self, line_type: DiffLineType) -> Style {
+        let mut style = Style::new();
+        match line_type {
+            DiffLineType::Added => {
+                style = style.fg(self.added_color);
+            }
+            DiffLineType::Removed => {
+                style = style.fg(self.removed_color);
+            }
+            DiffLineType::Context => {
+                style = style.fg(self.context_color);
+                if self.dim_context {
+                    style = style.add_modifier(Modifier::DIM);
                 }
             }
+            DiffLineType::Header => {
+                style = style.fg(self.header_color).add_modifier(Modifier::BOLD);
+            }
+        }
+
+        if let Some(bg) = self.bg_color {
+            style = style.bg(bg);
         }
+        style
+    }
+
+    /// The style of a changed word in a line of the given type.
+    fn word_style(&self, line_type: DiffLineType) -> Style {
+        let style = self.line_style(line_type);
+        let bg = match line_type {
+            DiffLineType::Added => self.added_word_bg,
+            DiffLineType::Removed => self.removed_word_bg,
+            _ => None,
+        };
+        match bg {
+            Some(bg) => style.bg(bg),
+            None => style.add_modifier(Modifier::REVERSED),
+        }
+    }
+
+    /// `text` split into spans, with the `changed` char ranges in the
+    /// word style. Ranges past the end of `text` are cut short.
+    fn word_spans(
+        &self,
+        text: &str,
+        changed: &[(usize, usize)],
+        line_type: DiffLineType,
+    ) -> Vec<(String, Style)> {
+        let style = self.line_style(line_type);
+        let chars: Vec<char> = text.chars().collect();
+        let part = |start: usize, end: usize| chars[start..end].iter().collect::<String>();
+        let mut spans = Vec::new();
+        let mut pos = 0;
+        for &(start, end) in changed {
+            let end = end.min(chars.len());
+            let start = start.min(end);
+            if start == end {
+                continue;
+            }
+            if pos < start {
+                spans.push((part(pos, start), style));
+            }
+            spans.push((part(start, end), self.word_style(line_type)));
+            pos = end;
+        }
+        if pos < chars.len() || spans.is_empty() {
+            spans.push((part(pos, chars.len()), style));
+        }
+        spans
+    }
+
+    /// Index pairs of removed lines and the added lines that replace them:
+    /// each run of removals is matched, in order, with the additions right
+    /// after it.
+    fn paired_lines(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        let mut removed = Vec::new();
+        let mut added = 0;
+        for (i, line) in self.lines.iter().enumerate() {
+            match line.line_type {
+                DiffLineType::Removed => {
+                    if added > 0 {
+                        removed.clear();
+                        added = 0;
+                    }
+                    removed.push(i);
+                }
+                DiffLineType::Added => {
+                    if let Some(&old) = removed.get(added) {
+                        pairs.push((old, i));
+                    }
+                    added += 1;
+                }
+                _ => {
+                    removed.clear();
+                    added = 0;
+                }
+            }
+        }
+        pairs
+    }
+
+    /// The changed char ranges of each line, for lines with a partner.
+    fn line_changes(&self) -> Vec<Option<CharRanges>> {
+        let mut changes = vec![None; self.lines.len()];
+        for (old, new) in self.paired_lines() {
+            if let Some((o, n)) = changed_words(&self.lines[old].content, &self.lines[new].content)
+            {
+                changes[old] = Some(o);
+                changes[new] = Some(n);
+            }
+        }
+        changes
+    }
+
+    /// Pair up the lines for side-by-side display. Removed lines are
+    /// matched with the added lines right after them, in order.
+    fn split_rows(&self) -> Vec<SplitRow<'_>> {
+        let mut rows = Vec::new();
+        let mut removed: Vec<&DiffLine> = Vec::new();
+        let mut added: Vec<&DiffLine> = Vec::new();
+
+        fn flush<'a>(
+            rows: &mut Vec<SplitRow<'a>>,
+            removed: &mut Vec<&'a DiffLine>,
+            added: &mut Vec<&'a DiffLine>,
+        ) {
+            for i in 0..removed.len().max(added.len()) {
+                rows.push(SplitRow::Pair(
+                    removed.get(i).copied(),
+                    added.get(i).copied(),
+                ));
+            }
+            removed.clear();
+            added.clear();
+        }
+
+        for line in &self.lines {
+            match line.line_type {
+                DiffLineType::Removed => {
+                    // A removal after additions starts a new run
+                    if !added.is_empty() {
+                        flush(&mut rows, &mut removed, &mut added);
+                    }
+                    removed.push(line);
+                }
+                DiffLineType::Added => added.push(line),
+                DiffLineType::Context => {
+                    flush(&mut rows, &mut removed, &mut added);
+                    rows.push(SplitRow::Pair(Some(line), Some(line)));
+                }
+                DiffLineType::Header => {
+                    flush(&mut rows, &mut removed, &mut added);
+                    rows.push(SplitRow::Header(line));
+                }
+            }
+        }
+        flush(&mut rows, &mut removed, &mut added);
+        rows
+    }
+
+    /// The side-by-side rows as styled spans.
+    fn split_spans(&self) -> Vec<Vec<(String, Style)>> {
+        let num_width = self.calc_line_num_width();
+        let side_width = if self.side_width > 0 {
+            self.side_width
+        } else {
+            self.lines
+                .iter()
+                .filter(|l| l.line_type != DiffLineType::Header)
+                .map(|l| l.content.width())
+                .max()
+                .unwrap_or(0)
+        };
+        let gutter = if GlyphSet::resolve(self.glyphs).is_ascii() {
+            " | "
+        } else {
+            " │ "
+        };
+        let gutter_style = Style::new().fg(self.line_num_color);
+
+        self.split_rows()
+            .into_iter()
+            .map(|row| match row {
+                SplitRow::Header(line) => {
+                    vec![(line.content.clone(), self.line_style(DiffLineType::Header))]
+                }
+                SplitRow::Pair(old, new) => {
+                    // Mark what changed when a removed line became an added one
+                    let (old_changed, new_changed) = match (old, new) {
+                        (Some(o), Some(n)) if o.line_type == DiffLineType::Removed => {
+                            changed_words(&o.content, &n.content).unwrap_or_default()
+                        }
+                        _ => Default::default(),
+                    };
+                    let mut spans = Vec::new();
+                    let old_num = old.and_then(|l| l.old_line);
+                    self.side_spans(
+                        old,
+                        old_num,
+                        &old_changed,
+                        num_width,
+                        side_width,
+                        &mut spans,
+                    );
+                    spans.push((gutter.to_string(), gutter_style));
+                    let new_num = new.and_then(|l| l.new_line);
+                    self.side_spans(
+                        new,
+                        new_num,
+                        &new_changed,
+                        num_width,
+                        side_width,
+                        &mut spans,
+                    );
+                    // Nothing follows the right column, so drop its padding
+                    while let Some((text, _)) = spans.last_mut() {
+                        let trimmed = text.trim_end().len();
+                        text.truncate(trimmed);
+                        if !text.is_empty() {
+                            break;
+                        }
+                        spans.pop();
+                    }
+                    spans
+                }
+            })
+            .collect()
+    }
+
+    /// One column of a side-by-side row: line number, prefix, then the
+    /// text padded or cut to `width`, with the `changed` char ranges
+    /// highlighted.
+    fn side_spans(
+        &self,
+        line: Option<&DiffLine>,
+        num: Option<usize>,
+        changed: &[(usize, usize)],
+        num_width: usize,
+        width: usize,
+        spans: &mut Vec<(String, Style)>,
+    ) {
+        let prefix_width = usize::from(self.show_prefix);
+        let num_column = if num_width > 0 { num_width + 1 } else { 0 };
+        let Some(line) = line else {
+            let blank = " ".repeat(num_column + prefix_width + width);
+            let style = self.bg_color.map_or(Style::new(), |bg| Style::new().bg(bg));
+            spans.push((blank, style));
+            return;
+        };
+
+        if num_column > 0 {
+            let num = num
+                .map(|n| format!("{:>num_width$} ", n))
+                .unwrap_or_else(|| " ".repeat(num_column));
+            spans.push((num, Style::new().fg(self.line_num_color)));
+        }
+
+        let style = self.line_style(line.line_type);
+        let prefix = if self.show_prefix { line.prefix() } else { "" };
+        let text = Truncation::resolve(self.glyphs).truncate(&line.content, width);
+        let padding = " ".repeat(width.saturating_sub(text.width()));
+        if !prefix.is_empty() {
+            spans.push((prefix.to_string(), style));
+        }
+        spans.extend(self.word_spans(&text, changed, line.line_type));
+        spans.push((padding, style));
     }
 
     /// Render the diff as lines (for plain text output).
     pub fn render_lines(&self) -> Vec<String> {
+        if self.style == DiffStyle::SideBySide {
+            return self
+                .split_spans()
+                .into_iter()
+                .map(|spans| spans.into_iter().map(|(text, _)| text).collect())
+                .collect();
+        }
         let line_width = self.calc_line_num_width();
         self.lines
             .iter()
@@ -382,6 +732,121 @@ impl DiffProps {
     }
 }
 
+/// Char ranges within a line, as (start, end).
+type CharRanges = Vec<(usize, usize)>;
+
+/// Most token pairs [`changed_words`] will compare before giving up.
+const MAX_WORD_DIFF_CELLS: usize = 250_000;
+
+/// Split `text` into words, runs of whitespace, and single punctuation
+/// marks, as char ranges.
+fn word_tokens(text: &str) -> Vec<(usize, usize)> {
+    fn class(c: char) -> u8 {
+        if c.is_whitespace() {
+            0
+        } else if c.is_alphanumeric() || c == '_' {
+            1
+        } else {
+            2
+        }
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    while start < chars.len() {
+        let kind = class(chars[start]);
+        let mut end = start + 1;
+        if kind != 2 {
+            while end < chars.len() && class(chars[end]) == kind {
+                end += 1;
+            }
+        }
+        tokens.push((start, end));
+        start = end;
+    }
+    tokens
+}
+
+/// The char ranges of the words that differ between `old` and `new`, as
+/// (old, new), found by matching up their longest common run of tokens.
+/// Changes split only by whitespace are merged into one range.
+///
+/// `None` when the lines share no words, since then the whole line changed,
+/// or when they are too long to compare.
+fn changed_words(old: &str, new: &str) -> Option<(CharRanges, CharRanges)> {
+    let old_chars: Vec<char> = old.chars().collect();
+    let new_chars: Vec<char> = new.chars().collect();
+    let old_tokens = word_tokens(old);
+    let new_tokens = word_tokens(new);
+    let (n, m) = (old_tokens.len(), new_tokens.len());
+    if n * m > MAX_WORD_DIFF_CELLS {
+        return None;
+    }
+
+    let token = |chars: &[char], (start, end): (usize, usize)| -> String {
+        chars[start..end].iter().collect()
+    };
+    let old_words: Vec<String> = old_tokens.iter().map(|&t| token(&old_chars, t)).collect();
+    let new_words: Vec<String> = new_tokens.iter().map(|&t| token(&new_chars, t)).collect();
+
+    // lcs[i][j] = longest common subsequence of old_words[i..] and new_words[j..]
+    let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old_words[i] == new_words[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut old_same = vec![false; n];
+    let mut new_same = vec![false; m];
+    let mut shares_word = false;
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old_words[i] == new_words[j] {
+            old_same[i] = true;
+            new_same[j] = true;
+            shares_word |= !old_words[i].trim().is_empty();
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    if !shares_word && n + m > 0 {
+        return None;
+    }
+
+    fn ranges(tokens: &[(usize, usize)], words: &[String], same: &[bool]) -> CharRanges {
+        let mut ranges = CharRanges::new();
+        // Whether only whitespace lies between the last range and here
+        let mut joinable = false;
+        for ((&(start, end), word), &same) in tokens.iter().zip(words).zip(same) {
+            if !same {
+                match ranges.last_mut() {
+                    Some(last) if joinable => last.1 = end,
+                    _ => ranges.push((start, end)),
+                }
+                joinable = true;
+            } else if !word.trim().is_empty() {
+                joinable = false;
+            }
+        }
+        ranges
+    }
+
+    Some((
+        ranges(&old_tokens, &old_words, &old_same),
+        ranges(&new_tokens, &new_words, &new_same),
+    ))
+}
+
 /// Parse chunk header to extract line numbers.
 /// Format: @@ -old_start,old_count +new_start,new_count @@
 fn parse_chunk_header(header: &str) -> Option<(usize, usize)> {
@@ -437,40 +902,53 @@ impl Component for Diff {
             return Element::text("");
         }
 
+        if props.style == DiffStyle::SideBySide {
+            let rows = props
+                .split_spans()
+                .into_iter()
+                .map(|spans| {
+                    Element::Fragment(
+                        spans
+                            .into_iter()
+                            .map(|(content, style)| Element::Text { content, style })
+                            .collect(),
+                    )
+                })
+                .collect();
+            return Element::Fragment(rows);
+        }
+
         // Build a Fragment with each line as a styled Text element
         let line_width = props.calc_line_num_width();
+        let changes = if props.word_diff {
+            props.line_changes()
+        } else {
+            vec![None; props.lines.len()]
+        };
         let mut elements = Vec::new();
 
-        for line in props.lines.iter() {
-            let rendered = props.render_line(line, line_width);
-
-            let mut style = Style::new();
-            match line.line_type {
-                DiffLineType::Added => {
-                    style = style.fg(props.added_color);
-                }
-                DiffLineType::Removed => {
-                    style = style.fg(props.removed_color);
-                }
-                DiffLineType::Context => {
-                    style = style.fg(props.context_color);
-                    if props.dim_context {
-                        style = style.add_modifier(Modifier::DIM);
-                    }
-                }
-                DiffLineType::Header => {
-                    style = style.fg(props.header_color).add_modifier(Modifier::BOLD);
+        for (line, changed) in props.lines.iter().zip(changes) {
+            let style = props.line_style(line.line_type);
+            match changed {
+                // Changed words split the line into an inline fragment
+                Some(changed) if !changed.is_empty() => {
+                    let mut spans = vec![Element::Text {
+                        content: props.line_head(line, line_width),
+                        style,
+                    }];
+                    spans.extend(
+                        props
+                            .word_spans(&line.content, &changed, line.line_type)
+                            .into_iter()
+                            .map(|(content, style)| Element::Text { content, style }),
+                    );
+                    elements.push(Element::Fragment(spans));
                 }
+                _ => elements.push(Element::Text {
+                    content: props.render_line(line, line_width),
+                    style,
+                }),
             }
-
-            if let Some(bg) = props.bg_color {
-                style = style.bg(bg);
-            }
-
-            elements.push(Element::Text {
-                content: rendered,
-                style,
-            });
         }
 
         Element::Fragment(elements)
@@ -662,4 +1140,110 @@ mod tests {
             panic!("Expected Fragment element");
         }
     }
+
+    #[test]
+    fn test_diff_side_by_side_pairs_lines() {
+        let props = DiffProps::from_unified(
+            "@@ -1,3 +1,3 @@\n keep\n-let x = 1;\n-gone\n+let x = 2;\n tail",
+        )
+        .style(DiffStyle::SideBySide)
+        .glyphs(GlyphSet::Ascii);
+        assert_eq!(
+            props.render_lines(),
+            [
+                "@@ -1,3 +1,3 @@",
+                "1  keep       | 1  keep",
+                "2 -let x = 1; | 2 +let x = 2;",
+                "3 -gone       |",
+                "4  tail       | 3  tail",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_side_by_side_marks_changes() {
+        let props = DiffProps::new()
+            .removed("let x = 1;")
+            .added("let x = 22;")
+            .style(DiffStyle::SideBySide)
+            .side_width(10)
+            .glyphs(GlyphSet::Unicode);
+        let rows = props.split_spans();
+        assert_eq!(rows.len(), 1);
+        let highlighted: Vec<&str> = rows[0]
+            .iter()
+            .filter(|(_, style)| style.modifiers.contains(Modifier::REVERSED))
+            .map(|(text, _)| text.as_str())
+            .collect();
+        // The new side is cut to 10 columns, so only part of its change shows
+        assert_eq!(highlighted, ["1", "2…"]);
+        assert!(Diff::render(&props).is_fragment());
+    }
+
+    #[test]
+    fn test_changed_words() {
+        assert_eq!(
+            changed_words("port = 8080", "port = 9090"),
+            Some((vec![(7, 11)], vec![(7, 11)]))
+        );
+        // Adjacent changes separated by a space become one range
+        assert_eq!(
+            changed_words("a quick brown fox", "a slow red fox"),
+            Some((vec![(2, 13)], vec![(2, 10)]))
+        );
+        assert_eq!(
+            changed_words("x = 1", "x = 1 # note"),
+            Some((vec![], vec![(5, 12)]))
+        );
+        assert_eq!(changed_words("abc", "xyz"), None);
+        assert_eq!(changed_words("same", "same"), Some((vec![], vec![])));
+    }
+
+    #[test]
+    fn test_diff_word_diff_highlights_changed_words() {
+        let props = DiffProps::new()
+            .context("[server]")
+            .removed("host = \"localhost\"")
+            .added("host = \"example.com\"")
+            .added("debug = true")
+            .word_diff(true)
+            .added_word_bg(Color::Rgb(0, 96, 0));
+        let Element::Fragment(lines) = Diff::render(&props) else {
+            panic!("Expected Fragment element");
+        };
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].is_text());
+        // The unpaired addition is colored as a whole
+        assert!(lines[3].is_text());
+
+        let Element::Fragment(spans) = &lines[2] else {
+            panic!("Expected inline Fragment");
+        };
+        let texts: Vec<(&str, Color)> = spans
+            .iter()
+            .map(|span| match span {
+                Element::Text { content, style } => (content.as_str(), style.bg),
+                _ => panic!("Expected Text element"),
+            })
+            .collect();
+        assert_eq!(
+            texts,
+            [
+                ("+", Color::Reset),
+                ("host = \"", Color::Reset),
+                ("example.com", Color::Rgb(0, 96, 0)),
+                ("\"", Color::Reset),
+            ]
+        );
+
+        // Without a background, the removed word is shown in reverse video
+        let Element::Fragment(spans) = &lines[1] else {
+            panic!("Expected inline Fragment");
+        };
+        assert!(matches!(
+            &spans[2],
+            Element::Text { content, style }
+                if content == "localhost" && style.modifiers.contains(Modifier::REVERSED)
+        ));
+    }
 }
@@ -12,9 +12,32 @@
 //!
 //! - [`Spacer`](super::Spacer) — Empty space (no visible line)
 //! - [`Box`](super::Box) — Containers with borders
+//!
+//! ## Section headers
+//!
+//! A label can sit left, center or right in the line, after an optional
+//! [`DividerStatus`] glyph, and the line can run along a gradient, e.g.
+//! fading out at the edges:
+//!
+//! ```ignore
+//! DividerProps::new()
+//!     .width(60)
+//!     .label("Tests")
+//!     .label_align(DividerAlign::Left)
+//!     .status(DividerStatus::Success)
+//!     .fade_edges(Color::DarkGray, Color::White)
+//! // ── ✓ Tests ─────────────────────────────────────────────────
+//! ```
 
+use crate::components::gradient::{ColorStop, GradientProps};
 use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::palette::Palette;
 use crate::style::{Color, Modifier, Style};
+use unicode_width::UnicodeWidthStr;
+
+/// Line cells kept before a left-aligned label or after a right-aligned one.
+const LABEL_INSET: usize = 2;
 
 /// Style for the divider line.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -48,6 +71,57 @@ impl DividerStyle {
     }
 }
 
+/// Where the label sits along the divider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DividerAlign {
+    /// A short stretch of line, then the label.
+    Left,
+    /// In the middle.
+    #[default]
+    Center,
+    /// The label, then a short stretch of line.
+    Right,
+}
+
+/// Status glyph shown before the label, colored from the palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DividerStatus {
+    /// Something passed: ✓
+    Success,
+    /// Something needs attention: ⚠
+    Warning,
+    /// Something failed: ✗
+    Error,
+    /// Neutral: ℹ
+    Info,
+}
+
+impl DividerStatus {
+    /// The glyph, or an ASCII stand-in.
+    pub fn icon(self, ascii: bool) -> &'static str {
+        match (self, ascii) {
+            (DividerStatus::Success, false) => "✓",
+            (DividerStatus::Warning, false) => "⚠",
+            (DividerStatus::Error, false) => "✗",
+            (DividerStatus::Info, false) => "ℹ",
+            (DividerStatus::Success, true) => "+",
+            (DividerStatus::Warning, true) => "!",
+            (DividerStatus::Error, true) => "x",
+            (DividerStatus::Info, true) => "i",
+        }
+    }
+
+    /// The color for this status from `palette`.
+    pub fn color(self, palette: &Palette) -> Color {
+        match self {
+            DividerStatus::Success => palette.success,
+            DividerStatus::Warning => palette.warning,
+            DividerStatus::Error => palette.error,
+            DividerStatus::Info => palette.info,
+        }
+    }
+}
+
 /// Properties for the Divider component.
 #[derive(Debug, Clone)]
 pub struct DividerProps {
@@ -63,6 +137,12 @@ pub struct DividerProps {
     pub label: Option<String>,
     /// Label color (defaults to line color).
     pub label_color: Option<Color>,
+    /// Where the label sits along the line.
+    pub label_align: DividerAlign,
+    /// Status glyph before the label.
+    pub status: Option<DividerStatus>,
+    /// Color the line along these stops, left to right.
+    pub gradient: Option<Vec<ColorStop>>,
 }
 
 impl Default for DividerProps {
@@ -74,6 +154,9 @@ impl Default for DividerProps {
             dim: false,
             label: None,
             label_color: None,
+            label_align: DividerAlign::Center,
+            status: None,
+            gradient: None,
         }
     }
 }
@@ -126,28 +209,142 @@ impl DividerProps {
         self
     }
 
+    /// Set where the label sits along the line.
+    #[must_use]
+    pub fn label_align(mut self, align: DividerAlign) -> Self {
+        self.label_align = align;
+        self
+    }
+
+    /// Show a status glyph before the label (or alone, without one).
+    #[must_use]
+    pub fn status(mut self, status: DividerStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Color the line from `start` on the left to `end` on the right.
+    #[must_use]
+    pub fn gradient(mut self, start: Color, end: Color) -> Self {
+        self.gradient = Some(vec![ColorStop::new(0.0, start), ColorStop::new(1.0, end)]);
+        self
+    }
+
+    /// Color the line along custom gradient stops.
+    #[must_use]
+    pub fn gradient_stops(mut self, stops: Vec<ColorStop>) -> Self {
+        self.gradient = Some(stops);
+        self
+    }
+
+    /// Fade the line from `middle` out to `edge` at both ends.
+    #[must_use]
+    pub fn fade_edges(self, edge: Color, middle: Color) -> Self {
+        self.gradient_stops(vec![
+            ColorStop::new(0.0, edge),
+            ColorStop::new(0.5, middle),
+            ColorStop::new(1.0, edge),
+        ])
+    }
+
+    /// Whether to use the ASCII status glyphs.
+    fn ascii(&self) -> bool {
+        self.style == DividerStyle::Ascii || GlyphSet::resolve(None).is_ascii()
+    }
+
+    /// Status glyph and label, without the surrounding spaces.
+    fn title(&self) -> Option<String> {
+        let icon = self.status.map(|status| status.icon(self.ascii()));
+        match (icon, &self.label) {
+            (Some(icon), Some(label)) => Some(format!("{} {}", icon, label)),
+            (Some(icon), None) => Some(icon.to_string()),
+            (None, Some(label)) => Some(label.clone()),
+            (None, None) => None,
+        }
+    }
+
+    /// Line cells before and after the title.
+    fn line_lengths(&self, title: Option<&str>) -> (usize, usize) {
+        let width = self.width.unwrap_or(20);
+        let Some(title) = title else {
+            return (width, 0);
+        };
+        let remaining = width.saturating_sub(title.width() + 2); // +2 for spaces
+        let left = match self.label_align {
+            DividerAlign::Left => remaining.min(LABEL_INSET),
+            DividerAlign::Center => remaining / 2,
+            DividerAlign::Right => remaining.saturating_sub(LABEL_INSET),
+        };
+        (left, remaining - left)
+    }
+
     /// Build the display string.
     pub fn render_string(&self) -> String {
-        let width = self.width.unwrap_or(20);
-        let ch = self.style.char();
-
-        if let Some(ref label) = self.label {
-            // Label in the middle: ── Label ──
-            let label_len = label.chars().count();
-            let remaining = width.saturating_sub(label_len + 2); // +2 for spaces
-            let left = remaining / 2;
-            let right = remaining - left;
-
-            format!(
-                "{} {} {}",
-                ch.to_string().repeat(left),
-                label,
-                ch.to_string().repeat(right)
-            )
-        } else {
-            ch.to_string().repeat(width)
+        let ch = self.style.char().to_string();
+        let title = self.title();
+        let (left, right) = self.line_lengths(title.as_deref());
+
+        match title {
+            // Label along the line: ── Label ──
+            Some(title) => format!("{} {} {}", ch.repeat(left), title, ch.repeat(right)),
+            None => ch.repeat(left),
         }
     }
+
+    /// Whether parts of the divider need styles of their own.
+    fn has_part_styles(&self) -> bool {
+        self.gradient.is_some() || self.status.is_some() || self.label_color.is_some()
+    }
+
+    /// The divider as separately styled spans: line cells, the status
+    /// glyph and the label.
+    fn render_parts(&self, base: Style) -> Vec<Element> {
+        let ch = self.style.char().to_string();
+        let title = self.title();
+        let (left, right) = self.line_lengths(title.as_deref());
+        let title_width = title.as_deref().map_or(0, |t| t.width() + 2);
+        let total = left + title_width + right;
+
+        let line = |from: usize, len: usize| -> Vec<Element> {
+            match &self.gradient {
+                Some(stops) => (from..from + len)
+                    .map(|i| {
+                        let position = if total > 1 {
+                            i as f32 / (total - 1) as f32
+                        } else {
+                            0.0
+                        };
+                        let color = GradientProps::interpolate_color(stops, position);
+                        Element::styled_text(&ch, base.fg(color))
+                    })
+                    .collect(),
+                None if len > 0 => vec![Element::styled_text(ch.repeat(len), base)],
+                None => Vec::new(),
+            }
+        };
+
+        let mut parts = line(0, left);
+        if title.is_some() {
+            parts.push(Element::styled_text(" ", base));
+            if let Some(status) = self.status {
+                let color = status.color(&Palette::detect());
+                parts.push(Element::styled_text(
+                    status.icon(self.ascii()),
+                    base.fg(color),
+                ));
+                if self.label.is_some() {
+                    parts.push(Element::styled_text(" ", base));
+                }
+            }
+            if let Some(label) = &self.label {
+                let style = self.label_color.map_or(base, |color| base.fg(color));
+                parts.push(Element::styled_text(label, style));
+            }
+            parts.push(Element::styled_text(" ", base));
+        }
+        parts.extend(line(left + title_width, right));
+        parts
+    }
 }
 
 /// A component that displays a horizontal divider line.
@@ -183,6 +380,9 @@ impl Component for Divider {
             style = style.add_modifier(Modifier::DIM);
         }
 
+        if props.has_part_styles() {
+            return Element::Fragment(props.render_parts(style));
+        }
         Element::styled_text(&content, style)
     }
 }
@@ -247,4 +447,43 @@ mod tests {
         let s = divider_with_label(20, "Hello");
         assert!(s.contains("Hello"));
     }
+
+    #[test]
+    fn test_divider_label_align() {
+        let props = DividerProps::new().width(12).label("ab");
+        assert_eq!(props.render_string(), "──── ab ────");
+        let props = props.label_align(DividerAlign::Left);
+        assert_eq!(props.render_string(), "── ab ──────");
+        let props = props.label_align(DividerAlign::Right);
+        assert_eq!(props.render_string(), "────── ab ──");
+    }
+
+    #[test]
+    fn test_divider_status_and_gradient() {
+        let props = DividerProps::new()
+            .width(12)
+            .label("ok")
+            .label_align(DividerAlign::Left)
+            .status(DividerStatus::Success)
+            .fade_edges(Color::Rgb(0, 0, 0), Color::Rgb(200, 200, 200));
+        let Element::Fragment(parts) = Divider::render(&props) else {
+            panic!("Expected Fragment");
+        };
+        let text: String = parts
+            .iter()
+            .map(|part| match part {
+                Element::Text { content, .. } => content.as_str(),
+                _ => "",
+            })
+            .collect();
+        assert_eq!(text, props.render_string());
+        assert_eq!(text.chars().count(), 12);
+        let fg = |i: usize| match &parts[i] {
+            Element::Text { style, .. } => style.fg,
+            _ => panic!("Expected Text"),
+        };
+        assert_eq!(fg(0), Color::Rgb(0, 0, 0));
+        assert_eq!(fg(parts.len() - 1), Color::Rgb(0, 0, 0));
+        assert_eq!(fg(3), Palette::detect().success);
+    }
 }
@@ -0,0 +1,273 @@
+//! Editing key presets shared by [`TextInput`](super::TextInput) and
+//! [`TextArea`](super::TextArea).
+//!
+//! [`EditKeys`] picks the keys the input states understand, on top of the
+//! standard arrows, Home/End and Backspace:
+//!
+//! - **Emacs** — Ctrl+A / Ctrl+E line start / end, Ctrl+B / Ctrl+F and
+//!   Alt+B / Alt+F move by character and word, Ctrl+P / Ctrl+N up / down,
+//!   Ctrl+D delete, Ctrl+H backspace, Ctrl+K / Ctrl+U kill to end / start of
+//!   line, Ctrl+W / Alt+D kill a word, Ctrl+Y yank, Ctrl+_ undo, Alt+< /
+//!   Alt+> start / end of text.
+//! - **Vim** — Insert mode types as usual; Esc enters Normal mode, where
+//!   `h j k l`, `w b`, `0 ^ $`, `gg G` move, `x X D dd dw db d$ d0` delete,
+//!   `C cc cw` change, `p P` put, `u` / Ctrl+R undo / redo, and
+//!   `i a I A o O` go back to Insert mode. Esc in Normal mode isn't handled,
+//!   so the app can use it to leave the field.
+//!
+//! Killed text (Ctrl+K, Ctrl+U, Ctrl+W, `D`, `dd`, `dw`, ...) goes to the
+//! state's `clipboard` for yank / put.
+//!
+//! ```ignore
+//! let mut name = TextInputState::new().edit_keys(EditKeys::Vim);
+//!
+//! // In render, show the mode
+//! let mode = match name.vim_mode() {
+//!     Some(VimMode::Normal) => "NORMAL",
+//!     _ => "",
+//! };
+//! ```
+
+use crate::input::Key;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Which editing keys an input state understands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EditKeys {
+    /// Arrows, Home/End, Ctrl+A select all and Ctrl+C/X/V clipboard keys.
+    #[default]
+    Standard,
+    /// Emacs / readline keys.
+    Emacs,
+    /// Vim Normal and Insert modes.
+    Vim,
+}
+
+/// The Vim mode of an input with [`EditKeys::Vim`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VimMode {
+    /// Keys move and edit; typing doesn't insert.
+    Normal,
+    /// Typing inserts text.
+    #[default]
+    Insert,
+}
+
+/// The preset, the Vim mode and the Vim operator waiting for its motion
+/// (`d`, `c`, `g`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct EditState {
+    pub(crate) keys: EditKeys,
+    mode: VimMode,
+    pending: Option<char>,
+}
+
+impl EditState {
+    /// A fresh state for `keys`, in Insert mode.
+    pub(crate) fn new(keys: EditKeys) -> Self {
+        Self {
+            keys,
+            ..Default::default()
+        }
+    }
+
+    /// The Vim mode, with [`EditKeys::Vim`].
+    pub(crate) fn vim_mode(&self) -> Option<VimMode> {
+        (self.keys == EditKeys::Vim).then_some(self.mode)
+    }
+}
+
+/// An edit the preset keys ask for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EditCommand {
+    Left,
+    Right,
+    Up,
+    Down,
+    WordLeft,
+    WordRight,
+    LineStart,
+    LineEnd,
+    Start,
+    End,
+    Delete,
+    Backspace,
+    DeleteWordLeft,
+    DeleteWordRight,
+    KillToEnd,
+    KillToStart,
+    KillLine,
+    /// Insert the clipboard at the cursor.
+    Yank,
+    /// Insert the clipboard after the cursor (Vim `p`).
+    YankAfter,
+    Undo,
+    Redo,
+    OpenBelow,
+    OpenAbove,
+}
+
+/// What a key does under a preset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Translated {
+    /// Run this edit.
+    Command(EditCommand),
+    /// The key was used up (a mode change, an operator, or a Normal-mode
+    /// key that does nothing).
+    Consumed,
+    /// Not a preset key; handle it the standard way.
+    Pass,
+}
+
+/// Work out what `key` does under the preset, updating the Vim state.
+pub(crate) fn translate(vim: &mut EditState, key: &Key) -> Translated {
+    match vim.keys {
+        EditKeys::Standard => Translated::Pass,
+        EditKeys::Emacs => emacs(key),
+        EditKeys::Vim => match vim.mode {
+            VimMode::Insert if key.code == KeyCode::Esc => {
+                vim.mode = VimMode::Normal;
+                Translated::Command(EditCommand::Left)
+            }
+            VimMode::Insert => Translated::Pass,
+            VimMode::Normal => vim_normal(vim, key),
+        },
+    }
+}
+
+fn emacs(key: &Key) -> Translated {
+    use EditCommand::*;
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let alt = key.modifiers.contains(KeyModifiers::ALT);
+    let KeyCode::Char(c) = key.code else {
+        return Translated::Pass;
+    };
+    let command = match (ctrl, alt, c.to_ascii_lowercase()) {
+        (true, false, 'a') => LineStart,
+        (true, false, 'e') => LineEnd,
+        (true, false, 'b') => Left,
+        (true, false, 'f') => Right,
+        (true, false, 'p') => Up,
+        (true, false, 'n') => Down,
+        (true, false, 'd') => Delete,
+        (true, false, 'h') => Backspace,
+        (true, false, 'k') => KillToEnd,
+        (true, false, 'u') => KillToStart,
+        (true, false, 'w') => DeleteWordLeft,
+        (true, false, 'y') => Yank,
+        (true, false, '_' | '/' | '7') => Undo,
+        (false, true, 'b') => WordLeft,
+        (false, true, 'f') => WordRight,
+        (false, true, 'd') => DeleteWordRight,
+        (false, true, '<') => Start,
+        (false, true, '>') => End,
+        _ => return Translated::Pass,
+    };
+    Translated::Command(command)
+}
+
+fn vim_normal(vim: &mut EditState, key: &Key) -> Translated {
+    use EditCommand::*;
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let pending = vim.pending.take();
+    let c = match key.code {
+        KeyCode::Char(c) if ctrl => {
+            return match c.to_ascii_lowercase() {
+                'r' => Translated::Command(Redo),
+                _ => Translated::Pass,
+            };
+        }
+        KeyCode::Char(c) => c,
+        KeyCode::Esc if pending.is_some() => return Translated::Consumed,
+        KeyCode::Backspace => return Translated::Command(Left),
+        KeyCode::Enter => return Translated::Command(Down),
+        _ => return Translated::Pass,
+    };
+
+    if let Some(op) = pending {
+        let command = match (op, c) {
+            ('g', 'g') => Start,
+            ('d' | 'c', 'd' | 'c') if c == op => KillLine,
+            ('d' | 'c', 'w' | 'e') => DeleteWordRight,
+            ('d' | 'c', 'b') => DeleteWordLeft,
+            ('d' | 'c', '$') => KillToEnd,
+            ('d' | 'c', '0' | '^') => KillToStart,
+            _ => return Translated::Consumed,
+        };
+        if op == 'c' {
+            vim.mode = VimMode::Insert;
+        }
+        return Translated::Command(command);
+    }
+
+    let (command, insert) = match c {
+        'h' => (Left, false),
+        'l' => (Right, false),
+        'j' => (Down, false),
+        'k' => (Up, false),
+        'w' | 'e' => (WordRight, false),
+        'b' => (WordLeft, false),
+        '0' | '^' => (LineStart, false),
+        '$' => (LineEnd, false),
+        'G' => (End, false),
+        'x' => (Delete, false),
+        'X' => (Backspace, false),
+        'D' => (KillToEnd, false),
+        'C' => (KillToEnd, true),
+        'p' => (YankAfter, false),
+        'P' => (Yank, false),
+        'u' => (Undo, false),
+        'a' => (Right, true),
+        'I' => (LineStart, true),
+        'A' => (LineEnd, true),
+        'o' => (OpenBelow, true),
+        'O' => (OpenAbove, true),
+        'i' => {
+            vim.mode = VimMode::Insert;
+            return Translated::Consumed;
+        }
+        'd' | 'c' | 'g' => {
+            vim.pending = Some(c);
+            return Translated::Consumed;
+        }
+        _ => return Translated::Consumed,
+    };
+    if insert {
+        vim.mode = VimMode::Insert;
+    }
+    Translated::Command(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vim_translate() {
+        let mut vim = EditState::new(EditKeys::Vim);
+        let key = |c| Key::new(KeyCode::Char(c));
+        assert_eq!(translate(&mut vim, &key('x')), Translated::Pass);
+        assert_eq!(
+            translate(&mut vim, &Key::new(KeyCode::Esc)),
+            Translated::Command(EditCommand::Left)
+        );
+        assert_eq!(vim.mode, VimMode::Normal);
+        assert_eq!(translate(&mut vim, &key('d')), Translated::Consumed);
+        assert_eq!(
+            translate(&mut vim, &key('d')),
+            Translated::Command(EditCommand::KillLine)
+        );
+        assert_eq!(translate(&mut vim, &key('c')), Translated::Consumed);
+        assert_eq!(
+            translate(&mut vim, &key('w')),
+            Translated::Command(EditCommand::DeleteWordRight)
+        );
+        assert_eq!(vim.mode, VimMode::Insert);
+        let mut vim = EditState::new(EditKeys::Emacs);
+        assert_eq!(
+            translate(&mut vim, &Key::with_ctrl(KeyCode::Char('k'))),
+            Translated::Command(EditCommand::KillToEnd)
+        );
+        assert_eq!(translate(&mut vim, &key('k')), Translated::Pass);
+    }
+}
@@ -0,0 +1,780 @@
+//! Form component - labeled fields with focus order and validation.
+//!
+//! A form is a list of [`Field`]s: text inputs, checkboxes and inline
+//! selects. [`FormState`] owns their values, moves focus between them and
+//! runs each field's validators, and [`Form`] draws them with the labels
+//! lined up and any errors under the field they belong to. Submitting a
+//! valid form gives a [`FormResult`] with every value by field name.
+//!
+//! Keys (in [`FormState::handle_key`]):
+//!
+//! - Tab / Shift+Tab, Down / Up — next / previous field
+//! - Enter — next field, or submit from the last one
+//! - Space — toggle a checkbox; Left / Right — change a select
+//! - Esc — cancel
+//!
+//! ## When to use Form
+//!
+//! - Setup wizards, login prompts, "new item" dialogs
+//! - Anything that collects several values and checks them together
+//!
+//! ## See also
+//!
+//! - [`TextInput`](super::TextInput), [`Checkbox`](super::Checkbox) —
+//!   Single inputs, when you handle keys yourself
+//! - [`Modal`](super::Modal) — Show a form in a dialog
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut form = FormState::new()
+//!     .field(Field::text("name", "Name").required())
+//!     .field(Field::text("email", "Email").validate(|v| {
+//!         if v.as_str().contains('@') { Ok(()) } else { Err("Not an email".into()) }
+//!     }))
+//!     .field(Field::select("plan", "Plan", ["Free", "Pro"]))
+//!     .field(Field::checkbox("terms", "Accept terms").required());
+//!
+//! // In the input handler:
+//! match form.handle_key(&key) {
+//!     FormAction::Submitted(result) => return Ok(result.text("name").unwrap().to_string()),
+//!     FormAction::Cancelled => return Err(..),
+//!     _ => {}
+//! }
+//!
+//! // In render:
+//! Element::node::<Form>(form.to_props(), vec![])
+//! ```
+
+use crate::components::text_input::TextInputState;
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::input::Key;
+use crate::palette::Palette;
+use crate::style::{Color, Modifier, Style};
+use crossterm::event::KeyCode;
+use std::fmt;
+use std::rc::Rc;
+
+/// Checks a field's value, returning the message to show if it's wrong.
+pub type Validator = Rc<dyn Fn(&FieldValue) -> Result<(), String>>;
+
+/// The value of a form field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldValue {
+    /// A text input's text.
+    Text(String),
+    /// Whether a checkbox is checked.
+    Bool(bool),
+    /// A select's chosen option.
+    Choice {
+        /// Index of the option.
+        index: usize,
+        /// The option's label.
+        label: String,
+    },
+}
+
+impl FieldValue {
+    /// The text, or the chosen option's label; empty for a checkbox.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FieldValue::Text(text) => text,
+            FieldValue::Choice { label, .. } => label,
+            FieldValue::Bool(_) => "",
+        }
+    }
+
+    /// Whether a checkbox is checked; false for other fields.
+    pub fn as_bool(&self) -> bool {
+        matches!(self, FieldValue::Bool(true))
+    }
+
+    /// The chosen option's index, for a select.
+    pub fn as_choice(&self) -> Option<usize> {
+        match self {
+            FieldValue::Choice { index, .. } => Some(*index),
+            _ => None,
+        }
+    }
+
+    /// Whether the value counts as filled in: non-blank text, a checked
+    /// checkbox, or any choice.
+    pub fn is_filled(&self) -> bool {
+        match self {
+            FieldValue::Text(text) => !text.trim().is_empty(),
+            FieldValue::Bool(checked) => *checked,
+            FieldValue::Choice { .. } => true,
+        }
+    }
+}
+
+/// The input of a form field.
+#[derive(Debug, Clone)]
+pub enum FieldInput {
+    /// A single-line text input.
+    Text {
+        /// Text and cursor.
+        state: Box<TextInputState>,
+        /// Shown while empty.
+        placeholder: Option<String>,
+        /// Whether to mask the text, for passwords.
+        mask: bool,
+    },
+    /// A checkbox.
+    Checkbox(bool),
+    /// A choice of options, changed in place with Left/Right.
+    Select {
+        /// The options.
+        options: Vec<String>,
+        /// Index of the chosen option.
+        selected: usize,
+    },
+}
+
+/// A labeled field in a [`FormState`].
+#[derive(Clone)]
+pub struct Field {
+    /// Key of the value in the [`FormResult`].
+    pub name: String,
+    /// Label shown before the input.
+    pub label: String,
+    /// The input and its value.
+    pub input: FieldInput,
+    /// The current validation error, shown under the field.
+    pub error: Option<String>,
+    validators: Vec<Validator>,
+    touched: bool,
+}
+
+impl fmt::Debug for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Field")
+            .field("name", &self.name)
+            .field("label", &self.label)
+            .field("input", &self.input)
+            .field("error", &self.error)
+            .field("validators", &self.validators.len())
+            .finish()
+    }
+}
+
+impl Field {
+    fn new(name: impl Into<String>, label: impl Into<String>, input: FieldInput) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into(),
+            input,
+            error: None,
+            validators: Vec::new(),
+            touched: false,
+        }
+    }
+
+    /// A text input field.
+    pub fn text(name: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(
+            name,
+            label,
+            FieldInput::Text {
+                state: Box::default(),
+                placeholder: None,
+                mask: false,
+            },
+        )
+    }
+
+    /// A checkbox field.
+    pub fn checkbox(name: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(name, label, FieldInput::Checkbox(false))
+    }
+
+    /// A select field, starting on the first option.
+    pub fn select<I, S>(name: impl Into<String>, label: impl Into<String>, options: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::new(
+            name,
+            label,
+            FieldInput::Select {
+                options: options.into_iter().map(Into::into).collect(),
+                selected: 0,
+            },
+        )
+    }
+
+    /// Set the starting text of a text field.
+    #[must_use]
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        if let FieldInput::Text { state, .. } = &mut self.input {
+            **state = TextInputState::with_value(value);
+        }
+        self
+    }
+
+    /// Set whether a checkbox starts checked.
+    #[must_use]
+    pub fn checked(mut self, checked: bool) -> Self {
+        if let FieldInput::Checkbox(value) = &mut self.input {
+            *value = checked;
+        }
+        self
+    }
+
+    /// Set the starting option of a select.
+    #[must_use]
+    pub fn selected(mut self, index: usize) -> Self {
+        if let FieldInput::Select { options, selected } = &mut self.input {
+            *selected = index.min(options.len().saturating_sub(1));
+        }
+        self
+    }
+
+    /// Set the placeholder of a text field.
+    #[must_use]
+    pub fn placeholder(mut self, text: impl Into<String>) -> Self {
+        if let FieldInput::Text { placeholder, .. } = &mut self.input {
+            *placeholder = Some(text.into());
+        }
+        self
+    }
+
+    /// Mask a text field, for passwords.
+    #[must_use]
+    pub fn mask(mut self) -> Self {
+        if let FieldInput::Text { mask, .. } = &mut self.input {
+            *mask = true;
+        }
+        self
+    }
+
+    /// Add a validator. Validators run in order; the first error is shown.
+    #[must_use]
+    pub fn validate<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&FieldValue) -> Result<(), String> + 'static,
+    {
+        self.validators.push(Rc::new(validator));
+        self
+    }
+
+    /// Require a value: non-blank text, or a checked checkbox.
+    #[must_use]
+    pub fn required(self) -> Self {
+        let message = format!("{} is required", self.label);
+        self.validate(move |value| {
+            if value.is_filled() {
+                Ok(())
+            } else {
+                Err(message.clone())
+            }
+        })
+    }
+
+    /// The field's current value.
+    pub fn get(&self) -> FieldValue {
+        match &self.input {
+            FieldInput::Text { state, .. } => FieldValue::Text(state.value.clone()),
+            FieldInput::Checkbox(checked) => FieldValue::Bool(*checked),
+            FieldInput::Select { options, selected } => FieldValue::Choice {
+                index: *selected,
+                label: options.get(*selected).cloned().unwrap_or_default(),
+            },
+        }
+    }
+
+    /// Run the validators, updating `error`. Returns true if valid.
+    pub fn check(&mut self) -> bool {
+        let value = self.get();
+        self.error = self.validators.iter().find_map(|v| v(&value).err());
+        self.error.is_none()
+    }
+
+    /// Handle an editing key. Returns true if the value changed.
+    fn edit(&mut self, key: &Key) -> bool {
+        match &mut self.input {
+            FieldInput::Text { state, .. } => match key.code {
+                KeyCode::Char(c) => {
+                    state.insert(c);
+                    true
+                }
+                KeyCode::Backspace => state.backspace(),
+                KeyCode::Delete => state.delete(),
+                KeyCode::Left => {
+                    state.move_left();
+                    false
+                }
+                KeyCode::Right => {
+                    state.move_right();
+                    false
+                }
+                KeyCode::Home => {
+                    state.move_home();
+                    false
+                }
+                KeyCode::End => {
+                    state.move_end();
+                    false
+                }
+                _ => false,
+            },
+            FieldInput::Checkbox(checked) => {
+                if key.is_char(' ') {
+                    *checked = !*checked;
+                    return true;
+                }
+                false
+            }
+            FieldInput::Select { options, selected } => {
+                let count = options.len();
+                if count == 0 {
+                    return false;
+                }
+                match key.code {
+                    KeyCode::Left => *selected = (*selected + count - 1) % count,
+                    KeyCode::Right | KeyCode::Char(' ') => *selected = (*selected + 1) % count,
+                    _ => return false,
+                }
+                true
+            }
+        }
+    }
+}
+
+/// The values of a submitted form, by field name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormResult {
+    /// Field names and values, in form order.
+    pub values: Vec<(String, FieldValue)>,
+}
+
+impl FormResult {
+    /// The value of the field called `name`.
+    pub fn get(&self, name: &str) -> Option<&FieldValue> {
+        self.values.iter().find(|(n, _)| n == name).map(|(_, v)| v)
+    }
+
+    /// The text of a text field, or the label of a select's choice.
+    pub fn text(&self, name: &str) -> Option<&str> {
+        self.get(name).map(FieldValue::as_str)
+    }
+
+    /// Whether a checkbox is checked.
+    pub fn checked(&self, name: &str) -> bool {
+        self.get(name).is_some_and(FieldValue::as_bool)
+    }
+
+    /// The index of a select's choice.
+    pub fn choice(&self, name: &str) -> Option<usize> {
+        self.get(name).and_then(FieldValue::as_choice)
+    }
+}
+
+/// What a key did to a form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormAction {
+    /// A field's value changed.
+    Changed,
+    /// Focus moved to another field.
+    Moved,
+    /// The form was submitted and every field is valid.
+    Submitted(FormResult),
+    /// Submitting failed validation; focus moved to the first bad field.
+    Invalid,
+    /// The form was cancelled with Esc.
+    Cancelled,
+    /// The key wasn't for the form.
+    Ignored,
+}
+
+/// Fields, focus and validation of a form.
+#[derive(Debug, Clone, Default)]
+pub struct FormState {
+    /// The fields, in focus order.
+    pub fields: Vec<Field>,
+    /// Index of the focused field.
+    pub focused: usize,
+}
+
+impl FormState {
+    /// Create an empty form.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a field.
+    #[must_use]
+    pub fn field(mut self, field: Field) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// The field called `name`.
+    pub fn get(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
+    /// The field called `name`, to change its value or error.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Field> {
+        self.fields.iter_mut().find(|f| f.name == name)
+    }
+
+    /// Move focus to field `index`. Leaving an edited field validates it.
+    pub fn focus(&mut self, index: usize) {
+        if index >= self.fields.len() || index == self.focused {
+            return;
+        }
+        if let Some(field) = self.fields.get_mut(self.focused) {
+            if field.touched {
+                field.check();
+            }
+        }
+        self.focused = index;
+    }
+
+    /// Focus the next field, wrapping around.
+    pub fn focus_next(&mut self) {
+        if !self.fields.is_empty() {
+            self.focus((self.focused + 1) % self.fields.len());
+        }
+    }
+
+    /// Focus the previous field, wrapping around.
+    pub fn focus_prev(&mut self) {
+        if !self.fields.is_empty() {
+            self.focus((self.focused + self.fields.len() - 1) % self.fields.len());
+        }
+    }
+
+    /// Validate every field. Returns true if all are valid.
+    pub fn validate(&mut self) -> bool {
+        // Check every field, so all errors show at once
+        let mut valid = true;
+        for field in &mut self.fields {
+            valid &= field.check();
+        }
+        valid
+    }
+
+    /// Whether every field is valid, without showing errors.
+    pub fn is_valid(&self) -> bool {
+        self.fields.iter().all(|field| {
+            let value = field.get();
+            field.validators.iter().all(|v| v(&value).is_ok())
+        })
+    }
+
+    /// The current values, valid or not.
+    pub fn values(&self) -> FormResult {
+        FormResult {
+            values: self
+                .fields
+                .iter()
+                .map(|f| (f.name.clone(), f.get()))
+                .collect(),
+        }
+    }
+
+    /// Validate and return the values, or `None` (with errors shown and
+    /// the first bad field focused) if any field is invalid.
+    pub fn submit(&mut self) -> Option<FormResult> {
+        if self.validate() {
+            return Some(self.values());
+        }
+        if let Some(index) = self.fields.iter().position(|f| f.error.is_some()) {
+            self.focused = index;
+        }
+        None
+    }
+
+    /// Handle focus, editing, submit and cancel keys (see the module docs).
+    pub fn handle_key(&mut self, key: &Key) -> FormAction {
+        if key.is_escape() {
+            return FormAction::Cancelled;
+        }
+        if self.fields.is_empty() {
+            return FormAction::Ignored;
+        }
+        if key.is_backtab() || key.code == KeyCode::Up {
+            self.focus_prev();
+            return FormAction::Moved;
+        }
+        if key.is_tab() || key.code == KeyCode::Down {
+            self.focus_next();
+            return FormAction::Moved;
+        }
+        if key.is_enter() {
+            if self.focused + 1 < self.fields.len() {
+                self.focus_next();
+                return FormAction::Moved;
+            }
+            return match self.submit() {
+                Some(result) => FormAction::Submitted(result),
+                None => FormAction::Invalid,
+            };
+        }
+        let field = &mut self.fields[self.focused];
+        if !field.edit(key) {
+            return FormAction::Ignored;
+        }
+        field.touched = true;
+        // Once a field shows an error, it updates as the value is fixed
+        if field.error.is_some() {
+            field.check();
+        }
+        FormAction::Changed
+    }
+
+    /// Props for rendering the form.
+    pub fn to_props(&self) -> FormProps {
+        FormProps {
+            fields: self.fields.clone(),
+            focused: Some(self.focused),
+            ..Default::default()
+        }
+    }
+}
+
+/// Properties for the Form component.
+#[derive(Debug, Clone)]
+pub struct FormProps {
+    /// The fields to show.
+    pub fields: Vec<Field>,
+    /// Index of the focused field (`None` = the form isn't focused).
+    pub focused: Option<usize>,
+    /// Color of the focused field's label.
+    pub focus_color: Option<Color>,
+    /// Color of error messages (`None` = the palette's error color).
+    pub error_color: Option<Color>,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for FormProps {
+    fn default() -> Self {
+        Self {
+            fields: Vec::new(),
+            focused: None,
+            focus_color: Some(Color::Cyan),
+            error_color: None,
+            glyphs: None,
+        }
+    }
+}
+
+impl FormProps {
+    /// Set the focused label color.
+    #[must_use]
+    pub fn focus_color(mut self, color: Color) -> Self {
+        self.focus_color = Some(color);
+        self
+    }
+
+    /// Set the error color.
+    #[must_use]
+    pub fn error_color(mut self, color: Color) -> Self {
+        self.error_color = Some(color);
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    fn input_string(&self, field: &Field, focused: bool, ascii: bool) -> String {
+        match &field.input {
+            FieldInput::Text {
+                state,
+                placeholder,
+                mask,
+            } => {
+                let mut props = state.to_props().focused(focused);
+                if let Some(placeholder) = placeholder {
+                    props = props.placeholder(placeholder.clone());
+                }
+                if *mask {
+                    props = props.mask();
+                }
+                props.render_string()
+            }
+            FieldInput::Checkbox(checked) => match (checked, ascii) {
+                (true, true) => "[x]".into(),
+                (false, true) => "[ ]".into(),
+                (true, false) => "☑".into(),
+                (false, false) => "☐".into(),
+            },
+            FieldInput::Select { options, selected } => {
+                let label = options.get(*selected).map_or("", String::as_str);
+                if ascii {
+                    format!("< {label} >")
+                } else {
+                    format!("‹ {label} ›")
+                }
+            }
+        }
+    }
+
+    /// The lines of the form, each a list of spans: a line per field with
+    /// the labels right-aligned, and a line under each field with an error.
+    pub fn render_lines(&self) -> Vec<Vec<(String, Style)>> {
+        let ascii = GlyphSet::resolve(self.glyphs).is_ascii();
+        let label_width = self
+            .fields
+            .iter()
+            .map(|f| unicode_width::UnicodeWidthStr::width(f.label.as_str()))
+            .max()
+            .unwrap_or(0);
+        let error_style =
+            Style::new().fg(self.error_color.unwrap_or_else(|| Palette::detect().error));
+        let indent = " ".repeat(label_width + 2);
+        let cross = if ascii { "x" } else { "✗" };
+
+        let mut lines = Vec::new();
+        for (i, field) in self.fields.iter().enumerate() {
+            let focused = self.focused == Some(i);
+            let mut label_style = Style::new();
+            if focused {
+                if let Some(color) = self.focus_color {
+                    label_style = label_style.fg(color);
+                }
+                label_style = label_style.add_modifier(Modifier::BOLD);
+            }
+            let pad = label_width - unicode_width::UnicodeWidthStr::width(field.label.as_str());
+            lines.push(vec![
+                (format!("{}{}: ", " ".repeat(pad), field.label), label_style),
+                (self.input_string(field, focused, ascii), Style::new()),
+            ]);
+            if let Some(error) = &field.error {
+                lines.push(vec![(format!("{indent}{cross} {error}"), error_style)]);
+            }
+        }
+        lines
+    }
+}
+
+/// A component that displays a form's fields and errors.
+pub struct Form;
+
+impl Component for Form {
+    type Props = FormProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let lines = props
+            .render_lines()
+            .into_iter()
+            .map(|spans| {
+                Element::Fragment(
+                    spans
+                        .into_iter()
+                        .map(|(text, style)| Element::styled_text(text, style))
+                        .collect(),
+                )
+            })
+            .collect();
+        Element::Fragment(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::test_util::line_texts;
+
+    fn form() -> FormState {
+        FormState::new()
+            .field(Field::text("name", "Name").required())
+            .field(Field::text("email", "Email").validate(|v| {
+                if v.as_str().contains('@') {
+                    Ok(())
+                } else {
+                    Err("Not an email".into())
+                }
+            }))
+            .field(Field::select("plan", "Plan", ["Free", "Pro"]))
+            .field(Field::checkbox("terms", "Terms"))
+    }
+
+    fn type_str(form: &mut FormState, text: &str) {
+        for c in text.chars() {
+            form.handle_key(&Key::new(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn test_form_submit_validates() {
+        let mut form = form();
+        let enter = Key::new(KeyCode::Enter);
+        form.focus(3);
+        assert_eq!(form.handle_key(&enter), FormAction::Invalid);
+        assert_eq!(form.focused, 0);
+        assert_eq!(form.fields[0].error.as_deref(), Some("Name is required"));
+        assert_eq!(form.fields[1].error.as_deref(), Some("Not an email"));
+
+        // Errors clear as the value is fixed
+        type_str(&mut form, "Ada");
+        assert!(form.fields[0].error.is_none());
+        form.handle_key(&enter);
+        type_str(&mut form, "ada@example.com");
+        form.handle_key(&enter);
+        form.handle_key(&Key::new(KeyCode::Right));
+        form.handle_key(&enter);
+        form.handle_key(&Key::new(KeyCode::Char(' ')));
+
+        let FormAction::Submitted(result) = form.handle_key(&enter) else {
+            panic!("expected submit");
+        };
+        assert_eq!(result.text("name"), Some("Ada"));
+        assert_eq!(result.text("plan"), Some("Pro"));
+        assert_eq!(result.choice("plan"), Some(1));
+        assert!(result.checked("terms"));
+    }
+
+    #[test]
+    fn test_form_focus_order_and_blur_validation() {
+        let mut form = form();
+        assert_eq!(
+            form.handle_key(&Key::new(KeyCode::BackTab)),
+            FormAction::Moved
+        );
+        assert_eq!(form.focused, 3);
+        form.handle_key(&Key::new(KeyCode::Tab));
+        assert_eq!(form.focused, 0);
+
+        // Untouched fields aren't validated on blur; edited ones are
+        form.handle_key(&Key::new(KeyCode::Tab));
+        assert!(form.fields[0].error.is_none());
+        type_str(&mut form, "x");
+        form.handle_key(&Key::new(KeyCode::Tab));
+        assert_eq!(form.fields[1].error.as_deref(), Some("Not an email"));
+        assert!(!form.is_valid());
+        assert_eq!(
+            form.handle_key(&Key::new(KeyCode::Esc)),
+            FormAction::Cancelled
+        );
+    }
+
+    #[test]
+    fn test_form_lines() {
+        let mut form = form();
+        form.submit();
+        let props = form.to_props().glyphs(GlyphSet::Ascii);
+        assert_eq!(
+            line_texts(props.render_lines()),
+            vec![
+                " Name: ▏",
+                "       x Name is required",
+                "Email:  ",
+                "       x Not an email",
+                " Plan: < Free >",
+                "Terms: [ ]",
+            ]
+        );
+        let bold = props.render_lines()[0][0].1.modifiers;
+        assert!(bold.contains(Modifier::BOLD));
+    }
+}
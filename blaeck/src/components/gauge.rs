@@ -0,0 +1,480 @@
+//! Gauge component - current utilization as a bar or an arc.
+//!
+//! The Gauge shows a single value from 0.0 to 1.0, colored by the threshold
+//! it has reached: green below 70%, yellow below 90% and red above by
+//! default, using the app's [`Palette`]. It is drawn as a thick bar with
+//! eighth-block precision, or as a half-circle arc of braille dots.
+//!
+//! ## When to use Gauge
+//!
+//! - CPU, memory and disk usage on a dashboard
+//! - Quotas and rate limits
+//!
+//! ## See also
+//!
+//! - [`Progress`](super::Progress) — Completion of a task, not a level
+//! - [`Sparkline`](super::Sparkline) — How the value changed over time
+//!
+//! # Example
+//!
+//! ```ignore
+//! Element::node::<Gauge>(
+//!     GaugeProps::new(cpu.usage())
+//!         .label("CPU")
+//!         .width(30)
+//!         .thickness(2),
+//!     vec![],
+//! )
+//! ```
+//!
+//! ## Animating changes
+//!
+//! [`animate_from`](GaugeProps::animate_from) shows the value part of the
+//! way from the previous one, so a gauge can sweep to each new reading:
+//!
+//! ```ignore
+//! GaugeProps::new(reading)
+//!     .shape(GaugeShape::Arc)
+//!     .animate_from(previous, timer.progress(400, Easing::EaseOut))
+//! ```
+//!
+//! [`Palette`]: crate::palette::Palette
+
+use crate::components::line_chart::LineMarker;
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::palette::Palette;
+use crate::style::{Color, Modifier, Style};
+use std::f32::consts::PI;
+
+/// How a gauge is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GaugeShape {
+    /// A horizontal bar, `thickness` rows tall.
+    #[default]
+    Bar,
+    /// A half circle filling from left to right. Falls back to
+    /// [`GaugeShape::Bar`] when only ASCII is available.
+    Arc,
+}
+
+/// Properties for the Gauge component.
+#[derive(Debug, Clone)]
+pub struct GaugeProps {
+    /// Value from 0.0 to 1.0.
+    pub value: f32,
+    /// How the gauge is drawn.
+    pub shape: GaugeShape,
+    /// Width in characters (of the bar, or of the arc).
+    pub width: usize,
+    /// Rows of the bar, or thickness of the arc's ring.
+    pub thickness: usize,
+    /// Optional label, before the bar or under the arc.
+    pub label: Option<String>,
+    /// Whether to show the value.
+    pub show_value: bool,
+    /// Text shown for the value (`None` = the percentage).
+    pub value_text: Option<String>,
+    /// Colors by the value they start at, in ascending order.
+    pub thresholds: Vec<(f32, Color)>,
+    /// Color of the unfilled part.
+    pub empty_color: Color,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for GaugeProps {
+    fn default() -> Self {
+        let palette = Palette::detect();
+        Self {
+            value: 0.0,
+            shape: GaugeShape::Bar,
+            width: 20,
+            thickness: 1,
+            label: None,
+            show_value: true,
+            value_text: None,
+            thresholds: vec![
+                (0.0, palette.success),
+                (0.7, palette.warning),
+                (0.9, palette.error),
+            ],
+            empty_color: Color::DarkGray,
+            glyphs: None,
+        }
+    }
+}
+
+impl GaugeProps {
+    /// Create props showing `value` (0.0 to 1.0).
+    pub fn new(value: f32) -> Self {
+        Self {
+            value: value.clamp(0.0, 1.0),
+            ..Default::default()
+        }
+    }
+
+    /// Set the value from a percentage (0 to 100).
+    #[must_use]
+    pub fn percent(mut self, percent: u32) -> Self {
+        self.value = (percent.min(100) as f32) / 100.0;
+        self
+    }
+
+    /// Set how the gauge is drawn.
+    #[must_use]
+    pub fn shape(mut self, shape: GaugeShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Set the width in characters.
+    #[must_use]
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    /// Set the rows of the bar, or the thickness of the arc's ring.
+    #[must_use]
+    pub fn thickness(mut self, thickness: usize) -> Self {
+        self.thickness = thickness.max(1);
+        self
+    }
+
+    /// Set the label.
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Show or hide the value.
+    #[must_use]
+    pub fn show_value(mut self, show: bool) -> Self {
+        self.show_value = show;
+        self
+    }
+
+    /// Show `text` instead of the percentage, e.g. "3.2 / 8 GB".
+    #[must_use]
+    pub fn value_text(mut self, text: impl Into<String>) -> Self {
+        self.value_text = Some(text.into());
+        self
+    }
+
+    /// Replace the thresholds: each color applies from its value up to the
+    /// next one's.
+    #[must_use]
+    pub fn thresholds<I: IntoIterator<Item = (f32, Color)>>(mut self, thresholds: I) -> Self {
+        self.thresholds = thresholds.into_iter().collect();
+        self.thresholds.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self
+    }
+
+    /// Use one color at every value.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.thresholds = vec![(0.0, color)];
+        self
+    }
+
+    /// Set the color of the unfilled part.
+    #[must_use]
+    pub fn empty_color(mut self, color: Color) -> Self {
+        self.empty_color = color;
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// Show the value `t` (0.0 to 1.0) of the way from `from` to the value
+    /// set, e.g. with [`AnimationTimer::progress`].
+    ///
+    /// [`AnimationTimer::progress`]: crate::animation::AnimationTimer::progress
+    #[must_use]
+    pub fn animate_from(mut self, from: f32, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0) as f32;
+        self.value = (from + (self.value - from) * t).clamp(0.0, 1.0);
+        self
+    }
+
+    /// The fill color for the current value.
+    pub fn color_for_value(&self) -> Color {
+        self.thresholds
+            .iter()
+            .rev()
+            .find(|(at, _)| self.value >= *at)
+            .or(self.thresholds.first())
+            .map_or(Color::Green, |(_, color)| *color)
+    }
+
+    /// The value as shown, e.g. " 73%".
+    pub fn value_label(&self) -> String {
+        match &self.value_text {
+            Some(text) => text.clone(),
+            None => format!("{:>3}%", (self.value * 100.0).round() as u32),
+        }
+    }
+
+    /// The shape to draw with, after the ASCII fallback.
+    fn effective_shape(&self) -> GaugeShape {
+        if GlyphSet::resolve(self.glyphs).is_ascii() {
+            GaugeShape::Bar
+        } else {
+            self.shape
+        }
+    }
+
+    /// One row of the bar: the filled part and the empty part.
+    pub fn bar_row(&self) -> (String, String) {
+        if GlyphSet::resolve(self.glyphs).is_ascii() {
+            let filled = ((self.value * self.width as f32).round() as usize).min(self.width);
+            return ("#".repeat(filled), "-".repeat(self.width - filled));
+        }
+        const PARTIAL: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+        let eighths = ((self.value * self.width as f32 * 8.0).round() as usize).min(self.width * 8);
+        let mut filled = "█".repeat(eighths / 8);
+        let mut used = eighths / 8;
+        let partial = eighths % 8;
+        if partial > 0 {
+            filled.push(PARTIAL[partial]);
+            used += 1;
+        }
+        (filled, "░".repeat(self.width - used))
+    }
+
+    /// The arc's rows: each cell's character and whether it is filled.
+    pub fn arc_cells(&self) -> Vec<Vec<(char, bool)>> {
+        let marker = LineMarker::Braille;
+        let dots_wide = self.width * 2;
+        let radius = (dots_wide as f32 - 1.0) / 2.0;
+        let ring = (self.thickness * 2) as f32;
+        let rows = ((radius + 0.5) / 4.0).ceil() as usize;
+        // Center at the bottom middle
+        let center_x = radius;
+        let center_y = (rows * 4 - 1) as f32;
+        let sweep = self.value * PI;
+
+        let mut cells = vec![vec![(0u8, 0u8); self.width]; rows];
+        for y in 0..rows * 4 {
+            for x in 0..dots_wide {
+                let dx = x as f32 - center_x;
+                let dy = center_y - y as f32;
+                let distance = (dx * dx + dy * dy).sqrt();
+                if distance > radius + 0.5 || distance <= radius + 0.5 - ring {
+                    continue;
+                }
+                let angle = dy.atan2(dx);
+                let bit = marker.bit(x % 2, y % 4);
+                let cell = &mut cells[y / 4][x / 2];
+                if self.value > 0.0 && PI - angle <= sweep {
+                    cell.0 |= bit;
+                } else {
+                    cell.1 |= bit;
+                }
+            }
+        }
+
+        // A cell has one color: filled dots win over empty ones
+        cells
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|(filled, empty)| match (filled, empty) {
+                        (0, 0) => (' ', false),
+                        (0, empty) => (marker.cell(empty, false), false),
+                        (filled, _) => (marker.cell(filled, false), true),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// A component that shows a level, colored by threshold.
+///
+/// # Examples
+///
+/// ```ignore
+/// let props = GaugeProps::new(0.82).label("MEM").width(20);
+/// // Renders (in yellow): MEM ████████████████▍░░░  82%
+/// ```
+pub struct Gauge;
+
+impl Component for Gauge {
+    type Props = GaugeProps;
+
+    fn render(props: &Self::Props) -> Element {
+        match props.effective_shape() {
+            GaugeShape::Bar => render_bar(props),
+            GaugeShape::Arc => render_arc(props),
+        }
+    }
+}
+
+fn render_bar(props: &GaugeProps) -> Element {
+    let fill = Style::new().fg(props.color_for_value());
+    let empty = Style::new().fg(props.empty_color);
+    let (filled, unfilled) = props.bar_row();
+    let label_width = props.label.as_ref().map_or(0, |l| l.chars().count() + 1);
+    let middle = (props.thickness - 1) / 2;
+
+    let lines = (0..props.thickness)
+        .map(|row| {
+            let mut spans = Vec::new();
+            if let Some(label) = &props.label {
+                let text = if row == middle {
+                    format!("{label} ")
+                } else {
+                    " ".repeat(label_width)
+                };
+                spans.push(Element::styled_text(
+                    text,
+                    Style::new().add_modifier(Modifier::BOLD),
+                ));
+            }
+            spans.push(Element::styled_text(filled.clone(), fill));
+            spans.push(Element::styled_text(unfilled.clone(), empty));
+            if props.show_value && row == middle {
+                spans.push(Element::styled_text(
+                    format!(" {}", props.value_label()),
+                    fill,
+                ));
+            }
+            Element::Fragment(spans)
+        })
+        .collect();
+    Element::Fragment(lines)
+}
+
+fn render_arc(props: &GaugeProps) -> Element {
+    let fill = Style::new().fg(props.color_for_value());
+    let empty = Style::new().fg(props.empty_color);
+
+    let mut lines: Vec<Element> = props
+        .arc_cells()
+        .into_iter()
+        .map(|row| {
+            // Runs of cells in the same state become one span
+            let mut spans = Vec::new();
+            let mut run = String::new();
+            let mut run_filled = false;
+            for (c, filled) in row {
+                if filled != run_filled && !run.is_empty() {
+                    let style = if run_filled { fill } else { empty };
+                    spans.push(Element::styled_text(std::mem::take(&mut run), style));
+                }
+                run_filled = filled;
+                run.push(c);
+            }
+            spans.push(Element::styled_text(
+                run,
+                if run_filled { fill } else { empty },
+            ));
+            Element::Fragment(spans)
+        })
+        .collect();
+
+    let centered = |text: &str| {
+        let pad = props.width.saturating_sub(text.chars().count()) / 2;
+        format!("{}{text}", " ".repeat(pad))
+    };
+    if props.show_value {
+        lines.push(Element::styled_text(
+            centered(props.value_label().trim_start()),
+            fill.add_modifier(Modifier::BOLD),
+        ));
+    }
+    if let Some(label) = &props.label {
+        lines.push(Element::text(centered(label)));
+    }
+    Element::Fragment(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gauge_thresholds() {
+        let props = GaugeProps::new(0.5).thresholds([
+            (0.8, Color::Red),
+            (0.0, Color::Green),
+            (0.6, Color::Yellow),
+        ]);
+        assert_eq!(props.color_for_value(), Color::Green);
+        assert_eq!(props.clone().percent(60).color_for_value(), Color::Yellow);
+        assert_eq!(props.percent(95).color_for_value(), Color::Red);
+    }
+
+    #[test]
+    fn test_gauge_bar_row() {
+        let props = GaugeProps::new(0.55).width(4).glyphs(GlyphSet::Unicode);
+        // 2.2 cells: two full blocks and a quarter
+        assert_eq!(props.bar_row(), ("██▎".to_string(), "░".to_string()));
+        assert_eq!(props.value_label(), " 55%");
+
+        let ascii = GaugeProps::new(0.5).width(4).glyphs(GlyphSet::Ascii);
+        assert_eq!(ascii.bar_row(), ("##".to_string(), "--".to_string()));
+        assert_eq!(
+            ascii.shape(GaugeShape::Arc).effective_shape(),
+            GaugeShape::Bar
+        );
+    }
+
+    #[test]
+    fn test_gauge_arc_fills_from_left() {
+        let cells = |value: f32| GaugeProps::new(value).width(8).arc_cells();
+        let empty = cells(0.0);
+        assert_eq!(empty.len(), 2);
+        assert!(empty.iter().flatten().all(|(_, filled)| !filled));
+        assert!(cells(1.0)
+            .iter()
+            .flatten()
+            .all(|&(c, filled)| filled || c == ' '));
+
+        // Half full: the left half is filled, the right half is not
+        let half = cells(0.5);
+        let bottom = &half[1];
+        assert!(bottom[0].1);
+        assert!(!bottom[7].1);
+    }
+
+    #[test]
+    fn test_gauge_animate_from() {
+        let props = GaugeProps::new(0.8).animate_from(0.2, 0.5);
+        assert!((props.value - 0.5).abs() < 1e-6);
+        assert_eq!(GaugeProps::new(0.8).animate_from(0.2, 1.0).value, 0.8);
+    }
+
+    #[test]
+    fn test_gauge_render_bar_rows() {
+        let props = GaugeProps::new(1.0)
+            .width(2)
+            .thickness(3)
+            .label("CPU")
+            .glyphs(GlyphSet::Unicode);
+        let Element::Fragment(lines) = Gauge::render(&props) else {
+            panic!("expected fragment");
+        };
+        assert_eq!(lines.len(), 3);
+        let text = |line: &Element| match line {
+            Element::Fragment(spans) => spans
+                .iter()
+                .map(|s| match s {
+                    Element::Text { content, .. } => content.clone(),
+                    _ => String::new(),
+                })
+                .collect::<String>(),
+            _ => String::new(),
+        };
+        assert_eq!(text(&lines[0]), "    ██");
+        assert_eq!(text(&lines[1]), "CPU ██ 100%");
+    }
+}
@@ -4,6 +4,11 @@
 //! Includes 10 preset gradients (Rainbow, Sunset, Ocean, Fire, etc.)
 //! or define custom color stops.
 //!
+//! Multi-line content is colored as one block, left to right by default or
+//! top to bottom or corner to corner with a [`GradientDirection`]. To color
+//! text that is already laid out, [`GradientProps::apply`] recolors the
+//! foreground of every text in an element tree instead.
+//!
 //! ## When to use Gradient
 //!
 //! - Eye-catching headers or titles
@@ -14,8 +19,10 @@
 //!
 //! - [`Text`](super::Text) — Plain styled text (single color)
 
+use crate::components::text::TextProps;
 use crate::element::{Component, Element};
 use crate::style::{Color, Modifier, Style};
+use std::any::TypeId;
 
 /// A color stop in a gradient.
 #[derive(Debug, Clone, Copy)]
@@ -122,6 +129,69 @@ impl GradientPreset {
     }
 }
 
+/// Which way a gradient runs across a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GradientDirection {
+    /// Left to right.
+    #[default]
+    Horizontal,
+    /// Top to bottom.
+    Vertical,
+    /// Top-left to bottom-right.
+    Diagonal,
+}
+
+impl GradientDirection {
+    /// Position (0.0 to 1.0) of the cell at `col`, `row` in a block
+    /// `width` cells wide and `height` rows tall.
+    pub fn position(&self, col: usize, row: usize, width: usize, height: usize) -> f32 {
+        let across = |i: usize, len: usize| {
+            if len > 1 {
+                i as f32 / (len - 1) as f32
+            } else {
+                0.0
+            }
+        };
+        match self {
+            GradientDirection::Horizontal => across(col, width),
+            GradientDirection::Vertical => across(row, height),
+            GradientDirection::Diagonal if height <= 1 => across(col, width),
+            GradientDirection::Diagonal if width <= 1 => across(row, height),
+            GradientDirection::Diagonal => (across(col, width) + across(row, height)) / 2.0,
+        }
+    }
+}
+
+/// Where the next text lands while walking an element tree, and how big
+/// the block walked so far is.
+#[derive(Debug, Default, Clone, Copy)]
+struct Cursor {
+    col: usize,
+    row: usize,
+    width: usize,
+    height: usize,
+}
+
+impl Cursor {
+    /// Advance over `content`, calling `cell` with each line's start.
+    fn text(&mut self, content: &str, mut cell: impl FnMut(&str, usize, usize)) {
+        for (i, line) in content.split('\n').enumerate() {
+            if i > 0 {
+                self.next_row();
+            }
+            cell(line, self.col, self.row);
+            self.col += line.chars().count();
+            self.width = self.width.max(self.col);
+            self.height = self.height.max(self.row + 1);
+        }
+    }
+
+    fn next_row(&mut self) {
+        self.row += 1;
+        self.col = 0;
+    }
+}
+
 /// Properties for the Gradient component.
 #[derive(Debug, Clone)]
 pub struct GradientProps {
@@ -137,6 +207,8 @@ pub struct GradientProps {
     pub italic: bool,
     /// Whether text should be underlined.
     pub underline: bool,
+    /// Which way the gradient runs across multi-line content.
+    pub direction: GradientDirection,
 }
 
 impl Default for GradientProps {
@@ -148,6 +220,7 @@ impl Default for GradientProps {
             bold: false,
             italic: false,
             underline: false,
+            direction: GradientDirection::Horizontal,
         }
     }
 }
@@ -226,6 +299,124 @@ impl GradientProps {
         self
     }
 
+    /// Set which way the gradient runs across multi-line content.
+    #[must_use]
+    pub fn direction(mut self, direction: GradientDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Recolor the foreground of every text in `element` along this
+    /// gradient, keeping the rest of each text's style.
+    ///
+    /// Texts are placed by the order they appear in: the children of a
+    /// node each start a new row, the parts of a fragment follow on from
+    /// each other. That matches columns of lines; row layouts and padding
+    /// aren't taken into account.
+    pub fn apply(&self, element: Element) -> Element {
+        let mut extent = Cursor::default();
+        Self::measure(&element, &mut extent);
+        let mut cursor = Cursor::default();
+        self.recolor(element, &mut cursor, &extent)
+    }
+
+    /// The text and style of a plain text or a [`Text`](super::Text) node.
+    fn text_of(element: &Element) -> Option<(&str, Style)> {
+        match element {
+            Element::Text { content, style } => Some((content, *style)),
+            Element::Node { type_id, props, .. } if *type_id == TypeId::of::<super::Text>() => {
+                let props = props.downcast_ref::<TextProps>()?;
+                Some((&props.content, props.to_style()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Walk `element` the way [`recolor`](Self::recolor) does, recording
+    /// the size of the block.
+    fn measure(element: &Element, cursor: &mut Cursor) {
+        if let Some((content, _)) = Self::text_of(element) {
+            cursor.text(content, |_, _, _| {});
+            return;
+        }
+        match element {
+            Element::Fragment(children) => {
+                for child in children {
+                    Self::measure(child, cursor);
+                }
+            }
+            Element::Node { children, .. } => {
+                for (i, child) in children.iter().enumerate() {
+                    if i > 0 {
+                        cursor.next_row();
+                    }
+                    Self::measure(child, cursor);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn recolor(&self, element: Element, cursor: &mut Cursor, extent: &Cursor) -> Element {
+        if let Some((content, style)) = Self::text_of(&element) {
+            let stops = self.effective_stops();
+            let mut lines = Vec::new();
+            cursor.text(content, |line, col, row| {
+                let spans: Vec<Element> = line
+                    .chars()
+                    .enumerate()
+                    .map(|(i, ch)| {
+                        let position =
+                            self.direction
+                                .position(col + i, row, extent.width, extent.height);
+                        let color = Self::interpolate_color(&stops, position);
+                        Element::styled_text(ch.to_string(), style.fg(color))
+                    })
+                    .collect();
+                lines.push(Element::Fragment(spans));
+            });
+            return if lines.len() == 1 {
+                lines.remove(0)
+            } else {
+                Element::column(lines)
+            };
+        }
+        match element {
+            Element::Fragment(children) => Element::Fragment(
+                children
+                    .into_iter()
+                    .map(|child| self.recolor(child, cursor, extent))
+                    .collect(),
+            ),
+            Element::Node {
+                type_id,
+                props,
+                layout_style,
+                children,
+                render_fn,
+            } => {
+                let children = children
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, child)| {
+                        if i > 0 {
+                            cursor.next_row();
+                        }
+                        self.recolor(child, cursor, extent)
+                    })
+                    .collect();
+                Element::Node {
+                    type_id,
+                    props,
+                    layout_style,
+                    children,
+                    render_fn,
+                }
+            }
+            other => other,
+        }
+    }
+
     /// Get the effective color stops (from preset or custom).
     fn effective_stops(&self) -> Vec<ColorStop> {
         if !self.stops.is_empty() {
@@ -241,7 +432,7 @@ impl GradientProps {
     }
 
     /// Interpolate color at a given position.
-    fn interpolate_color(stops: &[ColorStop], position: f32) -> Color {
+    pub(crate) fn interpolate_color(stops: &[ColorStop], position: f32) -> Color {
         if stops.is_empty() {
             return Color::White;
         }
@@ -287,7 +478,7 @@ impl GradientProps {
     }
 
     /// Convert any Color to RGB values.
-    fn color_to_rgb(color: Color) -> (u8, u8, u8) {
+    pub(crate) fn color_to_rgb(color: Color) -> (u8, u8, u8) {
         match color {
             Color::Rgb(r, g, b) => (r, g, b),
             Color::Red => (255, 0, 0),
@@ -393,31 +584,32 @@ impl Component for Gradient {
     type Props = GradientProps;
 
     fn render(props: &Self::Props) -> Element {
-        let chars: Vec<char> = props.content.chars().collect();
-        let len = chars.len();
-
-        if len == 0 {
+        if props.content.is_empty() {
             return Element::text("");
         }
 
         let stops = props.effective_stops();
         let base_modifiers = props.base_modifiers();
 
-        // Create a Fragment with individually styled characters
-        let children: Vec<Element> = chars
-            .into_iter()
-            .enumerate()
-            .map(|(i, ch)| {
-                let position = if len > 1 {
-                    i as f32 / (len - 1) as f32
-                } else {
-                    0.0
-                };
+        let mut extent = Cursor::default();
+        extent.text(&props.content, |_, _, _| {});
+
+        // Create a Fragment with individually styled characters, and a
+        // "\n" between lines
+        let mut children: Vec<Element> = Vec::new();
+        Cursor::default().text(&props.content, |line, col, row| {
+            if row > 0 {
+                children.push(Element::text("\n"));
+            }
+            for (i, ch) in line.chars().enumerate() {
+                let position = props
+                    .direction
+                    .position(col + i, row, extent.width, extent.height);
                 let color = GradientProps::interpolate_color(&stops, position);
                 let style = Style::new().fg(color).add_modifier(base_modifiers);
-                Element::styled_text(ch.to_string(), style)
-            })
-            .collect();
+                children.push(Element::styled_text(ch.to_string(), style));
+            }
+        });
 
         Element::Fragment(children)
     }
@@ -579,4 +771,72 @@ mod tests {
         assert_eq!(GradientProps::color_to_rgb(Color::White), (255, 255, 255));
         assert_eq!(GradientProps::color_to_rgb(Color::Black), (0, 0, 0));
     }
+
+    fn color_of(element: &Element) -> Color {
+        match element {
+            Element::Text { style, .. } => style.fg,
+            _ => panic!("Expected Text"),
+        }
+    }
+
+    #[test]
+    fn test_gradient_direction_position() {
+        let dir = GradientDirection::Diagonal;
+        assert_eq!(dir.position(0, 0, 5, 3), 0.0);
+        assert_eq!(dir.position(4, 2, 5, 3), 1.0);
+        assert_eq!(dir.position(4, 0, 5, 3), 0.5);
+        assert_eq!(GradientDirection::Vertical.position(3, 1, 5, 3), 0.5);
+        assert_eq!(GradientDirection::Diagonal.position(2, 0, 5, 1), 0.5);
+    }
+
+    #[test]
+    fn test_gradient_multiline_vertical() {
+        let props = GradientProps::new("ab\ncd")
+            .two_colors(Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255))
+            .direction(GradientDirection::Vertical);
+        let Element::Fragment(children) = Gradient::render(&props) else {
+            panic!("Expected Fragment");
+        };
+        assert_eq!(children.len(), 5);
+        assert!(matches!(&children[2], Element::Text { content, .. } if content == "\n"));
+        assert_eq!(color_of(&children[0]), color_of(&children[1]));
+        assert_eq!(color_of(&children[0]), Color::Rgb(0, 0, 0));
+        assert_eq!(color_of(&children[4]), Color::Rgb(255, 255, 255));
+    }
+
+    #[test]
+    fn test_gradient_multiline_renders_rows() {
+        let mut blaeck = crate::renderer::Blaeck::with_size(Vec::new(), 10, 5).unwrap();
+        let props = GradientProps::new("ab\ncde").direction(GradientDirection::Diagonal);
+        blaeck
+            .render(Element::node::<Gradient>(props, vec![]))
+            .unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["ab", "cde"]);
+    }
+
+    #[test]
+    fn test_gradient_apply_recolors_children() {
+        let tree = Element::column(vec![
+            Element::styled_text("ab", Style::new().add_modifier(Modifier::BOLD)),
+            Element::node::<crate::components::Text>(TextProps::new("cd"), vec![]),
+        ]);
+        let props = GradientProps::new("")
+            .two_colors(Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255))
+            .direction(GradientDirection::Vertical);
+        let Element::Node { children, .. } = props.apply(tree) else {
+            panic!("Expected Node");
+        };
+        let Element::Fragment(first) = &children[0] else {
+            panic!("Expected Fragment");
+        };
+        let Element::Text { style, .. } = &first[0] else {
+            panic!("Expected Text");
+        };
+        assert_eq!(style.fg, Color::Rgb(0, 0, 0));
+        assert!(style.modifiers.contains(Modifier::BOLD));
+        let Element::Fragment(second) = &children[1] else {
+            panic!("Expected Fragment");
+        };
+        assert_eq!(color_of(&second[1]), Color::Rgb(255, 255, 255));
+    }
 }
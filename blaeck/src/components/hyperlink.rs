@@ -0,0 +1,82 @@
+//! Hyperlink component - make any subtree a clickable OSC 8 region.
+//!
+//! [`Link`](super::Link) makes a single run of text clickable. `Hyperlink`
+//! wraps arbitrary children — a table row, a badge, a bordered box — so every
+//! cell they draw carries the same OSC 8 target. The target is tracked per
+//! cell in [`Output`](crate::output::Output), so it stays correct when the
+//! content wraps onto several lines or is clipped at the terminal edge.
+//!
+//! ## When to use Hyperlink
+//!
+//! - Whole rows or cards that should open a URL
+//! - Badges or status segments that link to a dashboard
+//! - Styled content that a plain `Link` can't express
+//!
+//! ## See also
+//!
+//! - [`Link`](super::Link) — Styled link text in one element
+//!
+//! ```ignore
+//! element! {
+//!     Hyperlink(url: "https://ci.example.com/build/42") {
+//!         Badge(text: "FAILED", color: Color::Red)
+//!         Text(content: " build #42")
+//!     }
+//! }
+//! ```
+//!
+//! Terminals without OSC 8 support ignore the sequences and show the content.
+
+use crate::element::{Component, Element};
+
+/// Properties for the Hyperlink component.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HyperlinkProps {
+    /// The URL the region links to. An empty URL disables the link.
+    pub url: String,
+}
+
+impl HyperlinkProps {
+    /// Create hyperlink props for a URL.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into() }
+    }
+
+    /// Returns the URL if the link is enabled.
+    pub fn target(&self) -> Option<&str> {
+        if self.url.is_empty() {
+            None
+        } else {
+            Some(&self.url)
+        }
+    }
+}
+
+/// A container whose children all link to one URL.
+///
+/// Children are laid out like a plain node (horizontal by default); wrap them
+/// in a [`Box`](super::Box) for other layouts.
+pub struct Hyperlink;
+
+impl Component for Hyperlink {
+    type Props = HyperlinkProps;
+
+    fn render(_props: &Self::Props) -> Element {
+        // Children are rendered by the renderer inside the link region
+        Element::Empty
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyperlink_props_target() {
+        assert_eq!(
+            HyperlinkProps::new("https://a.b").target(),
+            Some("https://a.b")
+        );
+        assert_eq!(HyperlinkProps::default().target(), None);
+    }
+}
@@ -0,0 +1,191 @@
+//! Image component - pictures inline, in whatever the terminal can show.
+//!
+//! The Image draws with the best [`GraphicsProtocol`] the terminal has:
+//! Kitty, iTerm2 or Sixel for real pixels, and colored half-blocks (two
+//! pixels per cell) everywhere else. It takes part in flex layout like any
+//! other leaf: it asks for its natural size, or the width/height you give
+//! it, and keeps its aspect ratio when its box shrinks.
+//!
+//! Images are decoded once, when the props are made. BMP is built in; PNG
+//! needs the `png` feature.
+//!
+//! ## When to use Image
+//!
+//! - Logos and splash screens
+//! - Thumbnails and previews next to file lists
+//!
+//! ## See also
+//!
+//! - [`Canvas`](super::Canvas) — Drawing with braille dots and text
+//! - [`BigText`](super::BigText) — Large text banners
+//!
+//! # Example
+//!
+//! ```ignore
+//! let logo = ImageProps::open("assets/logo.png")?.width(20);
+//!
+//! Element::row(vec![
+//!     Element::node::<Image>(logo.clone(), vec![]),
+//!     Element::text("  blaeck v0.4"),
+//! ])
+//! ```
+
+use crate::element::{Component, Element};
+use crate::graphics::{GraphicsProtocol, ImageData, CELL_PIXEL_HEIGHT, CELL_PIXEL_WIDTH};
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Properties for the Image component.
+#[derive(Debug, Clone, Default)]
+pub struct ImageProps {
+    /// The decoded image (`None` shows `alt`).
+    pub image: Option<Arc<ImageData>>,
+    /// Width in cells (`None` = from the height, or the natural size).
+    pub width: Option<u16>,
+    /// Height in cells (`None` = from the width, or the natural size).
+    pub height: Option<u16>,
+    /// Protocol override (`None` = detect from the terminal).
+    pub protocol: Option<GraphicsProtocol>,
+    /// Text shown when there is no image.
+    pub alt: String,
+}
+
+impl ImageProps {
+    /// Create props showing `image`.
+    pub fn new(image: ImageData) -> Self {
+        Self {
+            image: Some(Arc::new(image)),
+            ..Default::default()
+        }
+    }
+
+    /// Read and decode an image file.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        ImageData::open(path).map(Self::new)
+    }
+
+    /// Decode an image file held in memory, e.g. from `include_bytes!`.
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        ImageData::decode(bytes).map(Self::new)
+    }
+
+    /// Set the width in cells. Without a height, the height follows the
+    /// image's aspect ratio.
+    #[must_use]
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the height in cells. Without a width, the width follows the
+    /// image's aspect ratio.
+    #[must_use]
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Override the detected graphics protocol.
+    #[must_use]
+    pub fn protocol(mut self, protocol: GraphicsProtocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+
+    /// Set the text shown when there is no image.
+    #[must_use]
+    pub fn alt(mut self, alt: impl Into<String>) -> Self {
+        self.alt = alt.into();
+        self
+    }
+
+    /// The size asked for in cells, across and down.
+    ///
+    /// The natural size assumes cells of
+    /// [`CELL_PIXEL_WIDTH`] × [`CELL_PIXEL_HEIGHT`] pixels, so the layout is
+    /// the same whichever protocol draws the image.
+    pub fn cells(&self) -> (u16, u16) {
+        let Some(image) = &self.image else {
+            let alt = unicode_width::UnicodeWidthStr::width(self.alt.as_str());
+            return (alt as u16, u16::from(!self.alt.is_empty()));
+        };
+        // Width over height of the image, in cells
+        let aspect = (image.width().max(1) * CELL_PIXEL_HEIGHT) as f32
+            / (image.height().max(1) * CELL_PIXEL_WIDTH) as f32;
+        let natural = |pixels: u32, cell: u32| pixels.div_ceil(cell).max(1) as u16;
+        match (self.width, self.height) {
+            (Some(width), Some(height)) => (width, height),
+            (Some(width), None) => (width, ((f32::from(width) / aspect).round() as u16).max(1)),
+            (None, Some(height)) => (((f32::from(height) * aspect).round() as u16).max(1), height),
+            (None, None) => (
+                natural(image.width(), CELL_PIXEL_WIDTH),
+                natural(image.height(), CELL_PIXEL_HEIGHT),
+            ),
+        }
+    }
+
+    /// The size drawn in a box of `cols` × `rows` cells: the size asked
+    /// for, scaled down to fit with its shape kept.
+    pub fn fit(&self, cols: u16, rows: u16) -> (u16, u16) {
+        let (width, height) = self.cells();
+        if width <= cols && height <= rows {
+            return (width, height);
+        }
+        let scale = (f32::from(cols) / f32::from(width.max(1)))
+            .min(f32::from(rows) / f32::from(height.max(1)));
+        (
+            (f32::from(width) * scale).floor() as u16,
+            (f32::from(height) * scale).floor() as u16,
+        )
+    }
+}
+
+/// A component that shows an image.
+///
+/// The renderer sizes it in the layout and draws it with the terminal's
+/// graphics protocol.
+pub struct Image;
+
+impl Component for Image {
+    type Props = ImageProps;
+
+    fn render(_props: &Self::Props) -> Element {
+        // The renderer draws the image directly into the output grid
+        Element::empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(width: u32, height: u32) -> ImageProps {
+        ImageProps::new(ImageData::solid(width, height, [255, 0, 0, 255]))
+    }
+
+    #[test]
+    fn test_image_cells_keep_aspect() {
+        // 100×100 pixels is 10×5 cells of 10×20 pixels
+        assert_eq!(image(100, 100).cells(), (10, 5));
+        assert_eq!(image(100, 100).width(20).cells(), (20, 10));
+        assert_eq!(image(100, 100).height(2).cells(), (4, 2));
+        assert_eq!(image(100, 100).width(3).height(3).cells(), (3, 3));
+        assert_eq!(image(1, 1).cells(), (1, 1));
+    }
+
+    #[test]
+    fn test_image_fit_scales_down() {
+        let props = image(100, 100).width(20);
+        assert_eq!(props.fit(40, 40), (20, 10));
+        assert_eq!(props.fit(10, 40), (10, 5));
+        assert_eq!(props.fit(40, 2), (4, 2));
+    }
+
+    #[test]
+    fn test_image_alt_without_image() {
+        let props = ImageProps::default().alt("[logo]");
+        assert_eq!(props.cells(), (6, 1));
+        assert!(ImageProps::from_bytes(b"not an image").is_err());
+    }
+}
@@ -9,6 +9,17 @@
 //! - Context-sensitive help (changes based on mode)
 //! - Onboarding users to keyboard controls
 //!
+//! ## From registered bindings
+//!
+//! [`key_hints_from`] builds the hints from anything that implements
+//! [`KeyBindings`], such as [`Hotkeys`](crate::Hotkeys). Call it in the
+//! render function and the footer follows the bindings as they're
+//! registered, removed or switched:
+//!
+//! ```ignore
+//! Element::node::<KeyHints>(key_hints_from(app.hotkeys()), vec![])
+//! ```
+//!
 //! ## See also
 //!
 //! - [`StatusBar`](super::StatusBar) — Status indicators (often paired with KeyHints)
@@ -41,6 +52,39 @@ impl<K: Into<String>, A: Into<String>> From<(K, A)> for KeyHint {
     }
 }
 
+/// Something that knows its key bindings and can describe them.
+pub trait KeyBindings {
+    /// Hints for the bindings active right now, in display order.
+    fn key_hints(&self) -> Vec<KeyHint>;
+}
+
+impl KeyBindings for [KeyHint] {
+    fn key_hints(&self) -> Vec<KeyHint> {
+        self.to_vec()
+    }
+}
+
+/// Hints for the active bindings of `bindings`.
+///
+/// Bindings without a description are left out, and keys that do the same
+/// thing share a hint (`"↑/k"`), placed where the first of them was.
+pub fn key_hints_from<B: KeyBindings + ?Sized>(bindings: &B) -> KeyHintsProps {
+    let mut hints: Vec<KeyHint> = Vec::new();
+    for hint in bindings.key_hints() {
+        if hint.action.is_empty() {
+            continue;
+        }
+        match hints.iter_mut().find(|h| h.action == hint.action) {
+            Some(existing) => {
+                existing.key.push('/');
+                existing.key.push_str(&hint.key);
+            }
+            None => hints.push(hint),
+        }
+    }
+    KeyHintsProps::new(hints)
+}
+
 /// Style for the separator between hints.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum KeyHintSeparator {
@@ -368,4 +412,16 @@ mod tests {
         let elem = KeyHints::render(&props);
         assert!(elem.is_text());
     }
+
+    #[test]
+    fn test_key_hints_from_merges_actions() {
+        let bindings = [
+            KeyHint::new("up", "move"),
+            KeyHint::new("q", "quit"),
+            KeyHint::new("x", ""),
+            KeyHint::new("k", "move"),
+        ];
+        let props = key_hints_from(&bindings[..]);
+        assert_eq!(props.render_string(), "up/k move • q quit");
+    }
 }
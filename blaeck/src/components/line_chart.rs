@@ -0,0 +1,618 @@
+//! LineChart component - multi-series line charts.
+//!
+//! The LineChart plots one or more series as lines, drawn with braille dots
+//! (2×4 per cell) by default for smooth slopes, or with half blocks. It has
+//! a y axis with tick labels on the left, an x axis below, and a legend
+//! naming each series.
+//!
+//! Keep the [`Series`] in app state and [`push`](Series::push) new values
+//! as they arrive; with a [`window`](Series::window) the oldest values drop
+//! off and the chart scrolls.
+//!
+//! ## When to use LineChart
+//!
+//! - Metrics over time (latency, throughput, memory)
+//! - Comparing a few series on one axis
+//!
+//! ## See also
+//!
+//! - [`Sparkline`](super::Sparkline) — One-line trend with no axes
+//! - [`BarChart`](super::BarChart) — Comparing values across categories
+//! - [`charts`](super::charts) — The scale, axis and legend used here
+//!
+//! # Example
+//!
+//! ```ignore
+//! // In app state:
+//! let mut p50 = Series::new("p50", []).color(Color::Cyan).window(120);
+//! let mut p99 = Series::new("p99", []).color(Color::Magenta).window(120);
+//!
+//! // Every tick:
+//! p50.push(latency.p50());
+//! p99.push(latency.p99());
+//!
+//! // In render:
+//! Element::node::<LineChart>(
+//!     LineChartProps::new(vec![p50.clone(), p99.clone()])
+//!         .width(60)
+//!         .height(10)
+//!         .y_range(0.0, 500.0),
+//!     vec![],
+//! )
+//! ```
+
+use crate::components::charts::{Axis, Legend, LegendItem, Scale};
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::style::{Color, Style};
+
+/// One line on a [`LineChart`].
+#[derive(Debug, Clone, Default)]
+pub struct Series {
+    /// Name shown in the legend.
+    pub name: String,
+    /// Points as (x, y). A non-finite y leaves a gap in the line.
+    pub points: Vec<(f64, f64)>,
+    /// Line color.
+    pub color: Option<Color>,
+    /// Most points kept; older ones are dropped (`None` = keep all).
+    pub window: Option<usize>,
+}
+
+impl Series {
+    /// Create a series of values at x = 0, 1, 2, ...
+    pub fn new(name: impl Into<String>, values: impl IntoIterator<Item = impl Into<f64>>) -> Self {
+        Self::points(
+            name,
+            values
+                .into_iter()
+                .enumerate()
+                .map(|(i, y)| (i as f64, y.into())),
+        )
+    }
+
+    /// Create a series of (x, y) points.
+    pub fn points(name: impl Into<String>, points: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        Self {
+            name: name.into(),
+            points: points.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the line color.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Keep only the last `len` points.
+    #[must_use]
+    pub fn window(mut self, len: usize) -> Self {
+        self.window = Some(len);
+        self.trim();
+        self
+    }
+
+    /// Append a value at the x after the last point.
+    pub fn push(&mut self, y: f64) {
+        let x = self.points.last().map_or(0.0, |&(x, _)| x + 1.0);
+        self.push_point(x, y);
+    }
+
+    /// Append a point.
+    pub fn push_point(&mut self, x: f64, y: f64) {
+        self.points.push((x, y));
+        self.trim();
+    }
+
+    fn trim(&mut self) {
+        if let Some(len) = self.window {
+            let excess = self.points.len().saturating_sub(len);
+            self.points.drain(..excess);
+        }
+    }
+}
+
+/// How lines are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineMarker {
+    /// Braille dots, 2×4 per cell. Falls back to [`LineMarker::Dot`] with
+    /// ASCII glyphs.
+    #[default]
+    Braille,
+    /// Half blocks, 1×2 per cell. Falls back to [`LineMarker::Dot`] with
+    /// ASCII glyphs.
+    HalfBlock,
+    /// One mark per cell.
+    Dot,
+}
+
+impl LineMarker {
+    /// Dots per cell, across and down.
+    pub(crate) fn resolution(self) -> (usize, usize) {
+        match self {
+            LineMarker::Braille => (2, 4),
+            LineMarker::HalfBlock => (1, 2),
+            LineMarker::Dot => (1, 1),
+        }
+    }
+
+    /// The character for a cell with the given dots set.
+    pub(crate) fn cell(self, bits: u8, ascii: bool) -> char {
+        match self {
+            LineMarker::Braille => char::from_u32(0x2800 + u32::from(bits)).unwrap_or(' '),
+            LineMarker::HalfBlock => match bits {
+                1 => '▀',
+                2 => '▄',
+                _ => '█',
+            },
+            LineMarker::Dot if ascii => '*',
+            LineMarker::Dot => '•',
+        }
+    }
+
+    /// The bit for dot (`x`, `y`) within a cell.
+    pub(crate) fn bit(self, x: usize, y: usize) -> u8 {
+        match self {
+            // Braille numbers dots down the left column, then the right,
+            // with the bottom row last
+            LineMarker::Braille => match (x, y) {
+                (0, 3) => 0x40,
+                (1, 3) => 0x80,
+                (0, y) => 1 << y,
+                (_, y) => 1 << (y + 3),
+            },
+            LineMarker::HalfBlock => 1 << y,
+            LineMarker::Dot => 1,
+        }
+    }
+}
+
+/// Properties for the LineChart component.
+#[derive(Debug, Clone)]
+pub struct LineChartProps {
+    /// Series to plot; later series draw over earlier ones.
+    pub series: Vec<Series>,
+    /// Width of the plot area in cells.
+    pub width: usize,
+    /// Height of the plot area in rows.
+    pub height: usize,
+    /// Lowest y value shown (`None` = lowest in the data).
+    pub y_min: Option<f64>,
+    /// Highest y value shown (`None` = highest in the data).
+    pub y_max: Option<f64>,
+    /// Scale for y values, overriding `y_min` and `y_max`.
+    pub y_scale: Option<Scale>,
+    /// Scale for x values (`None` = linear over the data).
+    pub x_scale: Option<Scale>,
+    /// How lines are drawn.
+    pub marker: LineMarker,
+    /// Show the axes and their labels.
+    pub show_axes: bool,
+    /// Show a legend naming the series.
+    pub show_legend: bool,
+    /// Color of the axes and labels (`None` = dimmed).
+    pub axis_color: Option<Color>,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for LineChartProps {
+    fn default() -> Self {
+        Self {
+            series: Vec::new(),
+            width: 40,
+            height: 8,
+            y_min: None,
+            y_max: None,
+            y_scale: None,
+            x_scale: None,
+            marker: LineMarker::Braille,
+            show_axes: true,
+            show_legend: true,
+            axis_color: None,
+            glyphs: None,
+        }
+    }
+}
+
+impl LineChartProps {
+    /// Create props plotting the given series.
+    pub fn new(series: Vec<Series>) -> Self {
+        Self {
+            series,
+            ..Default::default()
+        }
+    }
+
+    /// Add a series.
+    #[must_use]
+    pub fn series(mut self, series: Series) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Set the width of the plot area.
+    #[must_use]
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    /// Set the height of the plot area.
+    #[must_use]
+    pub fn height(mut self, height: usize) -> Self {
+        self.height = height.max(1);
+        self
+    }
+
+    /// Fix the range of y values shown.
+    #[must_use]
+    pub fn y_range(mut self, min: f64, max: f64) -> Self {
+        self.y_min = Some(min);
+        self.y_max = Some(max);
+        self
+    }
+
+    /// Fix the lowest y value shown.
+    #[must_use]
+    pub fn y_min(mut self, min: f64) -> Self {
+        self.y_min = Some(min);
+        self
+    }
+
+    /// Fix the highest y value shown.
+    #[must_use]
+    pub fn y_max(mut self, max: f64) -> Self {
+        self.y_max = Some(max);
+        self
+    }
+
+    /// Set the scale for y values, e.g. [`Scale::log`].
+    #[must_use]
+    pub fn y_scale(mut self, scale: Scale) -> Self {
+        self.y_scale = Some(scale);
+        self
+    }
+
+    /// Set the scale for x values, e.g. [`Scale::time`].
+    #[must_use]
+    pub fn x_scale(mut self, scale: Scale) -> Self {
+        self.x_scale = Some(scale);
+        self
+    }
+
+    /// Set how lines are drawn.
+    #[must_use]
+    pub fn marker(mut self, marker: LineMarker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Show or hide the axes.
+    #[must_use]
+    pub fn show_axes(mut self, show: bool) -> Self {
+        self.show_axes = show;
+        self
+    }
+
+    /// Show or hide the legend.
+    #[must_use]
+    pub fn show_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+
+    /// Set the color of the axes and labels.
+    #[must_use]
+    pub fn axis_color(mut self, color: Color) -> Self {
+        self.axis_color = Some(color);
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    fn points(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.series
+            .iter()
+            .flat_map(|s| s.points.iter().copied())
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+    }
+
+    /// Get the effective y scale.
+    pub fn effective_y_scale(&self) -> Scale {
+        if let Some(scale) = self.y_scale {
+            return scale;
+        }
+        let (lo, hi) = extent(self.points().map(|(_, y)| y));
+        Scale::linear(self.y_min.unwrap_or(lo), self.y_max.unwrap_or(hi))
+    }
+
+    /// Get the effective x scale.
+    pub fn effective_x_scale(&self) -> Scale {
+        self.x_scale.unwrap_or_else(|| {
+            let (lo, hi) = extent(self.points().map(|(x, _)| x));
+            Scale::linear(lo, hi)
+        })
+    }
+
+    /// The marker actually used with the resolved glyph set.
+    fn resolved_marker(&self) -> LineMarker {
+        if GlyphSet::resolve(self.glyphs).is_ascii() {
+            LineMarker::Dot
+        } else {
+            self.marker
+        }
+    }
+
+    /// The plot area, top row first: each cell's character and the color of
+    /// the last series drawn through it (`None` for blank cells and series
+    /// without a color).
+    pub fn plot_cells(&self) -> Vec<Vec<(char, Option<Color>)>> {
+        let marker = self.resolved_marker();
+        let ascii = GlyphSet::resolve(self.glyphs).is_ascii();
+        let (dx, dy) = marker.resolution();
+        let (dots_x, dots_y) = (self.width * dx, self.height * dy);
+        let x_scale = self.effective_x_scale();
+        let y_scale = self.effective_y_scale();
+
+        let mut bits = vec![vec![0u8; self.width]; self.height];
+        let mut colors = vec![vec![None; self.width]; self.height];
+        for series in &self.series {
+            let mut set = |x: usize, y: usize| {
+                let (col, row) = (x / dx, y / dy);
+                bits[row][col] |= marker.bit(x % dx, y % dy);
+                colors[row][col] = series.color;
+            };
+            let mut prev: Option<(usize, usize)> = None;
+            for &(x, y) in &series.points {
+                if !x.is_finite() || !y.is_finite() {
+                    prev = None;
+                    continue;
+                }
+                let px = x_scale.position(x, dots_x);
+                let py = dots_y - 1 - y_scale.position(y, dots_y);
+                match prev {
+                    Some(from) => line(from, (px, py), &mut set),
+                    None => set(px, py),
+                }
+                prev = Some((px, py));
+            }
+        }
+
+        bits.into_iter()
+            .zip(colors)
+            .map(|(row, colors)| {
+                row.into_iter()
+                    .zip(colors)
+                    .map(|(b, color)| match b {
+                        0 => (' ', None),
+                        b => (marker.cell(b, ascii), color),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Legend naming each series, or `None` when hidden or nothing is named.
+    pub fn legend(&self) -> Option<Legend> {
+        if !self.show_legend || self.series.iter().all(|s| s.name.is_empty()) {
+            return None;
+        }
+        let mut legend = Legend::new();
+        if GlyphSet::resolve(self.glyphs).is_ascii() {
+            legend = legend.symbol('#');
+        }
+        legend.items = self
+            .series
+            .iter()
+            .map(|s| LegendItem {
+                label: s.name.clone(),
+                color: s.color,
+            })
+            .collect();
+        Some(legend)
+    }
+}
+
+/// Lowest and highest of `values`, or 0 to 1 when there are none.
+fn extent(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values
+        .fold(None, |acc: Option<(f64, f64)>, v| match acc {
+            Some((lo, hi)) => Some((lo.min(v), hi.max(v))),
+            None => Some((v, v)),
+        })
+        .unwrap_or((0.0, 1.0))
+}
+
+/// Calls `set` for every dot on the line from `from` to `to` (Bresenham).
+fn line(from: (usize, usize), to: (usize, usize), set: &mut impl FnMut(usize, usize)) {
+    let (mut x, mut y) = (from.0 as isize, from.1 as isize);
+    let (x1, y1) = (to.0 as isize, to.1 as isize);
+    let (dx, dy) = ((x1 - x).abs(), -(y1 - y).abs());
+    let (sx, sy) = ((x1 - x).signum(), (y1 - y).signum());
+    let mut err = dx + dy;
+    loop {
+        set(x as usize, y as usize);
+        if x == x1 && y == y1 {
+            break;
+        }
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+}
+
+/// A component that plots series as lines with axes and a legend.
+pub struct LineChart;
+
+impl Component for LineChart {
+    type Props = LineChartProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let ascii = GlyphSet::resolve(props.glyphs).is_ascii();
+        let axis_style = |axis: &Axis| match props.axis_color {
+            Some(color) => Style::new().fg(color),
+            None => axis.style(),
+        };
+        let y_axis = Axis::new(props.effective_y_scale());
+        let y_labels = if props.show_axes {
+            y_axis.vertical_labels(props.height)
+        } else {
+            vec![String::new(); props.height]
+        };
+        let label_width = y_labels.first().map_or(0, |l| l.chars().count());
+
+        let mut lines = Vec::new();
+        for (label, cells) in y_labels.into_iter().zip(props.plot_cells()) {
+            let mut spans = Vec::new();
+            if props.show_axes {
+                let tick = match (label.trim().is_empty(), ascii) {
+                    (true, false) => '│',
+                    (false, false) => '┤',
+                    (true, true) => '|',
+                    (false, true) => '+',
+                };
+                spans.push(Element::styled_text(
+                    format!("{label}{tick}"),
+                    axis_style(&y_axis),
+                ));
+            }
+            // Runs of cells in the same color become one span
+            let mut run = String::new();
+            let mut run_color = None;
+            for (c, color) in cells {
+                if color != run_color && !run.is_empty() {
+                    let style = run_color.map(|c| Style::new().fg(c)).unwrap_or_default();
+                    spans.push(Element::styled_text(std::mem::take(&mut run), style));
+                }
+                // Blanks join whatever run they're in
+                if c != ' ' || run.is_empty() {
+                    run_color = color;
+                }
+                run.push(c);
+            }
+            let style = run_color.map(|c| Style::new().fg(c)).unwrap_or_default();
+            spans.push(Element::styled_text(run, style));
+            lines.push(Element::Fragment(spans));
+        }
+
+        if props.show_axes {
+            let x_axis = Axis::new(props.effective_x_scale());
+            let (corner, rule) = if ascii { ('+', '-') } else { ('└', '─') };
+            let mut base = " ".repeat(label_width);
+            base.push(corner);
+            base.extend(std::iter::repeat_n(rule, props.width));
+            lines.push(Element::styled_text(base, axis_style(&x_axis)));
+            lines.push(Element::styled_text(
+                format!(
+                    "{}{}",
+                    " ".repeat(label_width + 1),
+                    x_axis.horizontal_line(props.width)
+                ),
+                axis_style(&x_axis),
+            ));
+        }
+
+        if let Some(legend) = props.legend() {
+            lines.push(legend.render());
+        }
+        Element::Fragment(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plot(props: &LineChartProps) -> Vec<String> {
+        props
+            .plot_cells()
+            .into_iter()
+            .map(|row| row.into_iter().map(|(c, _)| c).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_series_push_window() {
+        let mut series = Series::new("cpu", [1.0, 2.0, 3.0]).window(3);
+        series.push(4.0);
+        assert_eq!(series.points, vec![(1.0, 2.0), (2.0, 3.0), (3.0, 4.0)]);
+        series.push_point(10.0, 5.0);
+        assert_eq!(series.points.first(), Some(&(2.0, 3.0)));
+    }
+
+    #[test]
+    fn test_line_chart_plot_cells() {
+        let rising = Series::new("up", [0.0, 1.0, 2.0, 3.0]);
+        let props = LineChartProps::new(vec![rising])
+            .width(4)
+            .height(4)
+            .marker(LineMarker::Dot)
+            .glyphs(GlyphSet::Ascii);
+        assert_eq!(plot(&props), ["   *", "  * ", " *  ", "*   "]);
+
+        // Two dots across and four down per cell
+        let braille = LineChartProps::new(vec![Series::new("", [0.0, 0.0])])
+            .width(1)
+            .height(1)
+            .y_range(0.0, 1.0)
+            .glyphs(GlyphSet::Unicode);
+        assert_eq!(plot(&braille), ["⣀"]);
+
+        // A non-finite value breaks the line
+        let gap = Series::new("", [0.0, f64::NAN, 0.0]);
+        let props = LineChartProps::new(vec![gap])
+            .width(3)
+            .height(1)
+            .marker(LineMarker::Dot)
+            .glyphs(GlyphSet::Ascii);
+        assert_eq!(plot(&props), ["* *"]);
+    }
+
+    #[test]
+    fn test_line_chart_render_axes_and_legend() {
+        let props = LineChartProps::new(vec![
+            Series::new("a", [0.0, 10.0]).color(Color::Cyan),
+            Series::new("b", [10.0, 0.0]).color(Color::Red),
+        ])
+        .width(10)
+        .height(3)
+        .marker(LineMarker::Dot)
+        .glyphs(GlyphSet::Ascii);
+        assert_eq!(props.effective_y_scale(), Scale::linear(0.0, 10.0));
+
+        let Element::Fragment(lines) = LineChart::render(&props) else {
+            panic!("Expected Fragment");
+        };
+        // Three plot rows, the base line, x labels and the legend
+        assert_eq!(lines.len(), 6);
+        let text = |line: &Element| match line {
+            Element::Text { content, .. } => content.clone(),
+            Element::Fragment(spans) => spans
+                .iter()
+                .map(|s| match s {
+                    Element::Text { content, .. } => content.as_str(),
+                    _ => "",
+                })
+                .collect(),
+            _ => String::new(),
+        };
+        assert!(text(&lines[0]).starts_with("10+"));
+        assert_eq!(text(&lines[3]), "  +----------");
+        assert_eq!(text(&lines[5]), "# a  # b");
+    }
+}
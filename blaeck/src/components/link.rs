@@ -12,6 +12,7 @@
 //! ## See also
 //!
 //! - [`Text`](super::Text) — Plain text with underline style
+//! - [`Hyperlink`](super::Hyperlink) — Make a whole subtree clickable
 //! - [`Markdown`](super::Markdown) — Auto-converts [text](url) to links
 
 use crate::element::{Component, Element};
@@ -142,8 +143,8 @@ impl Component for Link {
     type Props = LinkProps;
 
     fn render(props: &Self::Props) -> Element {
-        // Note: We render just the text without OSC 8 sequences because
-        // the layout system's character grid doesn't handle escape sequences.
+        // Note: We render just the text without OSC 8 sequences; the renderer
+        // marks the cells with the URL so Output emits the link per cell.
         // Use link_url() helper for raw OSC 8 output outside the layout system.
         let content = props.text.clone();
 
@@ -0,0 +1,722 @@
+//! LogViewer component - a tailing log viewer with search and level filters.
+//!
+//! Needs the `log-viewer` feature, which brings in the regex engine.
+//!
+//! Where [`LogBox`](super::LogBox) shows a handful of recent lines, the
+//! LogViewer is a full pane for long-running logs. [`LogViewerState`]
+//! keeps lines in a bounded ring buffer, so memory stays flat however long
+//! the program runs, and only the rows on screen are ever styled.
+//!
+//! - **Follow mode**: the view sticks to the newest line until the user
+//!   scrolls up, and stays put while lines arrive; End follows again.
+//! - **Levels**: each line's level is detected from its first words
+//!   (`ERROR`, `[warn]`, `info:`), colored, and can be filtered by minimum.
+//!   Lines without a level are always shown.
+//! - **Search**: a regex highlights matches; `n` and `N` jump to older and
+//!   newer matching lines.
+//! - **Timestamps**: stamped on push, or given with the line.
+//!
+//! ## When to use LogViewer
+//!
+//! - Server and build output you watch and dig through
+//! - Any stream too long for a LogBox
+//!
+//! ## See also
+//!
+//! - [`LogBox`](super::LogBox) — The last few lines of a task
+//! - [`ScrollView`](super::ScrollView) — Scrolling any content
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut logs = LogViewerState::new(100_000).height(20);
+//!
+//! // As lines arrive:
+//! logs.push(LogEntry::stamped(line));
+//!
+//! // In the input handler:
+//! if key.is_char('/') {
+//!     logs.set_search(&query)?;
+//! } else {
+//!     logs.handle_key(&key);
+//! }
+//!
+//! // In render:
+//! Element::node::<LogViewer>(logs.to_props(), vec![])
+//! // Renders:
+//! // 12:00:01 INFO  server listening on :8080
+//! // 12:00:04 WARN  slow request: /api/users
+//! // ● following · warn+
+//! ```
+
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::input::Key;
+use crate::palette::Palette;
+use crate::style::{Color, Modifier, Style};
+use crossterm::event::KeyCode;
+use fancy_regex::Regex;
+use std::collections::VecDeque;
+
+/// How severe a log line is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// Fine-grained tracing.
+    Trace,
+    /// Debugging detail.
+    Debug,
+    /// Normal operation.
+    Info,
+    /// Something unexpected.
+    Warn,
+    /// A failure.
+    Error,
+}
+
+impl LogLevel {
+    /// Detect the level from the first few words of a line, ignoring case
+    /// and surrounding brackets or colons.
+    pub fn detect(text: &str) -> Option<Self> {
+        text.split_whitespace().take(4).find_map(|word| {
+            let word = word.trim_matches(|c: char| !c.is_ascii_alphabetic());
+            match word.to_ascii_lowercase().as_str() {
+                "trace" | "trc" => Some(LogLevel::Trace),
+                "debug" | "dbg" => Some(LogLevel::Debug),
+                "info" | "inf" => Some(LogLevel::Info),
+                "warn" | "warning" | "wrn" => Some(LogLevel::Warn),
+                "error" | "err" | "fatal" | "crit" => Some(LogLevel::Error),
+                _ => None,
+            }
+        })
+    }
+
+    /// The tag shown before a line, five columns wide.
+    pub fn tag(self) -> &'static str {
+        match self {
+            LogLevel::Trace => "TRACE",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO ",
+            LogLevel::Warn => "WARN ",
+            LogLevel::Error => "ERROR",
+        }
+    }
+
+    /// The style of a line at this level.
+    pub fn style(self) -> Style {
+        let palette = Palette::detect();
+        match self {
+            LogLevel::Trace | LogLevel::Debug => Style::new().add_modifier(Modifier::DIM),
+            LogLevel::Info => Style::new().fg(palette.info),
+            LogLevel::Warn => Style::new().fg(palette.warning),
+            LogLevel::Error => Style::new().fg(palette.error),
+        }
+    }
+}
+
+/// A line in a [`LogViewer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    /// The text of the line.
+    pub text: String,
+    /// Its level, if it has one.
+    pub level: Option<LogLevel>,
+    /// When it was logged, as shown.
+    pub timestamp: Option<String>,
+}
+
+impl LogEntry {
+    /// Create an entry, detecting its level from the text.
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        Self {
+            level: LogLevel::detect(&text),
+            text,
+            timestamp: None,
+        }
+    }
+
+    /// Create an entry stamped with the current UTC time of day.
+    pub fn stamped(text: impl Into<String>) -> Self {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let day = secs % 86_400;
+        Self::new(text).timestamp(format!(
+            "{:02}:{:02}:{:02}",
+            day / 3600,
+            day / 60 % 60,
+            day % 60
+        ))
+    }
+
+    /// Set the level.
+    #[must_use]
+    pub fn level(mut self, level: LogLevel) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Set the timestamp.
+    #[must_use]
+    pub fn timestamp(mut self, timestamp: impl Into<String>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+}
+
+impl<S: Into<String>> From<S> for LogEntry {
+    fn from(s: S) -> Self {
+        LogEntry::new(s)
+    }
+}
+
+/// Lines, filters and scroll position of a [`LogViewer`].
+///
+/// Scrolling counts lines that pass the filters, from the newest.
+#[derive(Debug, Clone)]
+pub struct LogViewerState {
+    lines: VecDeque<LogEntry>,
+    capacity: usize,
+    dropped: usize,
+    /// Rows shown at once, for paging and [`to_props`](Self::to_props).
+    pub height: usize,
+    /// Lines scrolled up from the newest.
+    pub scroll: usize,
+    /// Whether the view sticks to the newest line.
+    pub follow: bool,
+    /// Lowest level shown (`None` = all).
+    pub min_level: Option<LogLevel>,
+    /// The search pattern.
+    pub search: Option<Regex>,
+    /// Whether to show only lines matching the search.
+    pub only_matching: bool,
+}
+
+impl Default for LogViewerState {
+    fn default() -> Self {
+        Self::new(10_000)
+    }
+}
+
+impl LogViewerState {
+    /// Create a state keeping at most `capacity` lines; older lines are
+    /// dropped as new ones arrive.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lines: VecDeque::new(),
+            capacity: capacity.max(1),
+            dropped: 0,
+            height: 10,
+            scroll: 0,
+            follow: true,
+            min_level: None,
+            search: None,
+            only_matching: false,
+        }
+    }
+
+    /// Set the rows shown at once.
+    #[must_use]
+    pub fn height(mut self, rows: usize) -> Self {
+        self.height = rows.max(1);
+        self
+    }
+
+    /// Add a line. While following, the view moves to it; otherwise the
+    /// view stays where the user scrolled.
+    pub fn push(&mut self, entry: impl Into<LogEntry>) {
+        let entry = entry.into();
+        if self.lines.len() == self.capacity {
+            self.lines.pop_front();
+            self.dropped += 1;
+        }
+        if !self.follow && self.passes(&entry) {
+            self.scroll += 1;
+        }
+        self.lines.push_back(entry);
+    }
+
+    /// Add every line of `text`.
+    pub fn push_lines(&mut self, text: &str) {
+        for line in text.lines() {
+            self.push(line);
+        }
+    }
+
+    /// Number of lines held.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Whether no lines are held.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Number of lines dropped to stay within capacity.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Remove every line.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.scroll = 0;
+        self.follow = true;
+    }
+
+    /// Show only lines at `level` or above (`None` = all).
+    pub fn set_min_level(&mut self, level: Option<LogLevel>) {
+        self.min_level = level;
+        self.clamp_scroll();
+    }
+
+    /// Search for a regex, or stop searching with an empty pattern. A bad
+    /// pattern is returned as an error and keeps the previous search.
+    pub fn set_search(&mut self, pattern: &str) -> Result<(), String> {
+        self.search = if pattern.is_empty() {
+            None
+        } else {
+            Some(Regex::new(pattern).map_err(|e| e.to_string())?)
+        };
+        self.clamp_scroll();
+        Ok(())
+    }
+
+    /// Show only lines matching the search, or all lines.
+    pub fn set_only_matching(&mut self, only: bool) {
+        self.only_matching = only;
+        self.clamp_scroll();
+    }
+
+    fn matches(&self, entry: &LogEntry) -> bool {
+        self.search
+            .as_ref()
+            .is_some_and(|re| re.is_match(&entry.text).unwrap_or(false))
+    }
+
+    /// Whether a line passes the level filter (and search, with
+    /// `only_matching`).
+    fn passes(&self, entry: &LogEntry) -> bool {
+        let level_ok = match (self.min_level, entry.level) {
+            (Some(min), Some(level)) => level >= min,
+            _ => true,
+        };
+        level_ok && (!self.only_matching || self.search.is_none() || self.matches(entry))
+    }
+
+    /// Lines passing the filters, newest first.
+    fn filtered(&self) -> impl Iterator<Item = &LogEntry> {
+        self.lines.iter().rev().filter(|e| self.passes(e))
+    }
+
+    /// Keep the view full: no scrolling past the oldest page.
+    fn clamp_scroll(&mut self) {
+        let wanted = self.scroll + self.height;
+        let available = self.filtered().take(wanted).count();
+        if available < wanted {
+            self.scroll = available.saturating_sub(self.height);
+        }
+        self.follow = self.scroll == 0;
+    }
+
+    /// Scroll up (older) by `rows`, leaving follow mode.
+    pub fn scroll_up(&mut self, rows: usize) {
+        self.scroll += rows;
+        self.clamp_scroll();
+    }
+
+    /// Scroll down (newer) by `rows`; reaching the end follows again.
+    pub fn scroll_down(&mut self, rows: usize) {
+        self.scroll = self.scroll.saturating_sub(rows);
+        self.follow = self.scroll == 0;
+    }
+
+    /// Jump to the oldest line.
+    pub fn scroll_to_top(&mut self) {
+        self.scroll = usize::MAX / 2;
+        self.clamp_scroll();
+    }
+
+    /// Jump to the newest line and follow.
+    pub fn follow(&mut self) {
+        self.scroll = 0;
+        self.follow = true;
+    }
+
+    /// Scroll to the next matching line older than the view, putting it on
+    /// the top row. Returns false if there is none.
+    pub fn prev_match(&mut self) -> bool {
+        let found = self
+            .filtered()
+            .enumerate()
+            .skip(self.scroll + self.height)
+            .find(|(_, e)| self.matches(e))
+            .map(|(i, _)| i);
+        match found {
+            Some(i) => {
+                self.scroll = i + 1 - self.height;
+                self.follow = false;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Scroll to the next matching line newer than the view, putting it on
+    /// the bottom row. Returns false if there is none.
+    pub fn next_match(&mut self) -> bool {
+        let found = self
+            .filtered()
+            .take(self.scroll)
+            .enumerate()
+            .filter(|(_, e)| self.matches(e))
+            .last()
+            .map(|(i, _)| i);
+        match found {
+            Some(i) => {
+                self.scroll = i;
+                self.follow = i == 0;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Handle Up/Down, PageUp/PageDown, Home/End, `f` to toggle follow,
+    /// and `n`/`N` for older/newer matches.
+    ///
+    /// Returns true if the key was handled.
+    pub fn handle_key(&mut self, key: &Key) -> bool {
+        match key.code {
+            KeyCode::Up => self.scroll_up(1),
+            KeyCode::Down => self.scroll_down(1),
+            KeyCode::PageUp => self.scroll_up(self.height),
+            KeyCode::PageDown => self.scroll_down(self.height),
+            KeyCode::Home => self.scroll_to_top(),
+            KeyCode::End => self.follow(),
+            KeyCode::Char('f') if self.follow => {
+                self.follow = false;
+            }
+            KeyCode::Char('f') => self.follow(),
+            KeyCode::Char('n') => return self.prev_match(),
+            KeyCode::Char('N') => return self.next_match(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Props for rendering the rows in view.
+    pub fn to_props(&self) -> LogViewerProps {
+        let mut entries: Vec<LogEntry> = self
+            .filtered()
+            .skip(self.scroll)
+            .take(self.height)
+            .cloned()
+            .collect();
+        entries.reverse();
+        LogViewerProps {
+            entries,
+            search: self.search.clone(),
+            following: self.follow,
+            newer: self.scroll,
+            min_level: self.min_level,
+            ..Default::default()
+        }
+    }
+}
+
+/// Properties for the LogViewer component.
+#[derive(Debug, Clone)]
+pub struct LogViewerProps {
+    /// The lines in view, oldest first.
+    pub entries: Vec<LogEntry>,
+    /// Matches of this pattern are highlighted.
+    pub search: Option<Regex>,
+    /// Whether the view follows new lines.
+    pub following: bool,
+    /// Lines below the view.
+    pub newer: usize,
+    /// The level filter, shown in the status line.
+    pub min_level: Option<LogLevel>,
+    /// Whether to show timestamps.
+    pub show_timestamps: bool,
+    /// Whether to show level tags.
+    pub show_levels: bool,
+    /// Whether to show the status line.
+    pub show_status: bool,
+    /// Style of search matches.
+    pub match_style: Style,
+    /// Color of timestamps.
+    pub timestamp_color: Color,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for LogViewerProps {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            search: None,
+            following: true,
+            newer: 0,
+            min_level: None,
+            show_timestamps: true,
+            show_levels: true,
+            show_status: true,
+            match_style: Style::new().fg(Color::Black).bg(Color::Yellow),
+            timestamp_color: Color::DarkGray,
+            glyphs: None,
+        }
+    }
+}
+
+impl LogViewerProps {
+    /// Create props showing `entries`.
+    pub fn new<I, T>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<LogEntry>,
+    {
+        Self {
+            entries: entries.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Show or hide timestamps.
+    #[must_use]
+    pub fn show_timestamps(mut self, show: bool) -> Self {
+        self.show_timestamps = show;
+        self
+    }
+
+    /// Show or hide level tags.
+    #[must_use]
+    pub fn show_levels(mut self, show: bool) -> Self {
+        self.show_levels = show;
+        self
+    }
+
+    /// Show or hide the status line.
+    #[must_use]
+    pub fn show_status(mut self, show: bool) -> Self {
+        self.show_status = show;
+        self
+    }
+
+    /// Set the style of search matches.
+    #[must_use]
+    pub fn match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// The spans of one entry: timestamp, level tag, then the text with
+    /// search matches highlighted.
+    pub fn entry_spans(&self, entry: &LogEntry) -> Vec<(String, Style)> {
+        let mut spans = Vec::new();
+        if self.show_timestamps {
+            if let Some(timestamp) = &entry.timestamp {
+                spans.push((
+                    format!("{timestamp} "),
+                    Style::new().fg(self.timestamp_color),
+                ));
+            }
+        }
+        let style = entry.level.map(LogLevel::style).unwrap_or_default();
+        if self.show_levels {
+            if let Some(level) = entry.level {
+                spans.push((
+                    format!("{} ", level.tag()),
+                    style.add_modifier(Modifier::BOLD),
+                ));
+            }
+        }
+        let mut at = 0;
+        if let Some(search) = &self.search {
+            for found in search.find_iter(&entry.text).flatten() {
+                if found.start() == found.end() {
+                    continue;
+                }
+                if found.start() > at {
+                    spans.push((entry.text[at..found.start()].to_string(), style));
+                }
+                spans.push((found.as_str().to_string(), self.match_style));
+                at = found.end();
+            }
+        }
+        if at < entry.text.len() || spans.is_empty() {
+            spans.push((entry.text[at..].to_string(), style));
+        }
+        spans
+    }
+
+    /// The status line: follow mode, lines below, and active filters.
+    pub fn status(&self) -> String {
+        let ascii = GlyphSet::resolve(self.glyphs).is_ascii();
+        let mut parts = vec![if self.following {
+            format!("{} following", if ascii { "*" } else { "●" })
+        } else {
+            format!("{} {} newer", if ascii { "v" } else { "↓" }, self.newer)
+        }];
+        if let Some(level) = self.min_level {
+            parts.push(format!("{}+", level.tag().trim_end().to_lowercase()));
+        }
+        if let Some(search) = &self.search {
+            parts.push(format!("/{}/", search.as_str()));
+        }
+        parts.join(if ascii { " | " } else { " · " })
+    }
+
+    /// The lines of the viewer, each a list of spans.
+    pub fn render_lines(&self) -> Vec<Vec<(String, Style)>> {
+        let mut lines: Vec<_> = self.entries.iter().map(|e| self.entry_spans(e)).collect();
+        if self.show_status {
+            lines.push(vec![(
+                self.status(),
+                Style::new().add_modifier(Modifier::DIM),
+            )]);
+        }
+        lines
+    }
+}
+
+/// A component that displays a tailing, searchable log.
+pub struct LogViewer;
+
+impl Component for LogViewer {
+    type Props = LogViewerProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let lines = props
+            .render_lines()
+            .into_iter()
+            .map(|spans| {
+                Element::Fragment(
+                    spans
+                        .into_iter()
+                        .map(|(text, style)| Element::styled_text(text, style))
+                        .collect(),
+                )
+            })
+            .collect();
+        Element::Fragment(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(state: &LogViewerState) -> Vec<String> {
+        state
+            .to_props()
+            .entries
+            .into_iter()
+            .map(|e| e.text)
+            .collect()
+    }
+
+    fn state(lines: &[&str]) -> LogViewerState {
+        let mut state = LogViewerState::new(100).height(2);
+        for line in lines {
+            state.push(*line);
+        }
+        state
+    }
+
+    #[test]
+    fn test_log_level_detect() {
+        assert_eq!(LogLevel::detect("ERROR disk full"), Some(LogLevel::Error));
+        assert_eq!(
+            LogLevel::detect("12:00:01 [warn] slow"),
+            Some(LogLevel::Warn)
+        );
+        assert_eq!(LogLevel::detect("info: ready"), Some(LogLevel::Info));
+        assert_eq!(LogLevel::detect("hello world"), None);
+    }
+
+    #[test]
+    fn test_log_viewer_ring_buffer() {
+        let mut state = LogViewerState::new(3);
+        for i in 0..5 {
+            state.push(format!("line {i}"));
+        }
+        assert_eq!(state.len(), 3);
+        assert_eq!(state.dropped(), 2);
+        assert_eq!(texts(&state.height(5)), ["line 2", "line 3", "line 4"]);
+    }
+
+    #[test]
+    fn test_log_viewer_follow_and_scroll() {
+        let mut state = state(&["a", "b", "c", "d"]);
+        assert_eq!(texts(&state), ["c", "d"]);
+
+        state.handle_key(&Key::new(KeyCode::Up));
+        assert!(!state.follow);
+        assert_eq!(texts(&state), ["b", "c"]);
+        // New lines don't move a scrolled view
+        state.push("e");
+        assert_eq!(texts(&state), ["b", "c"]);
+        assert_eq!(state.to_props().newer, 2);
+
+        state.scroll_up(10);
+        assert_eq!(texts(&state), ["a", "b"]);
+        state.handle_key(&Key::new(KeyCode::End));
+        assert!(state.follow);
+        state.push("f");
+        assert_eq!(texts(&state), ["e", "f"]);
+    }
+
+    #[test]
+    fn test_log_viewer_level_filter() {
+        let mut state = state(&["ERROR one", "INFO two", "WARN three", "plain", "DEBUG four"]);
+        state.set_min_level(Some(LogLevel::Warn));
+        assert_eq!(
+            texts(&state.clone().height(5)),
+            ["ERROR one", "WARN three", "plain"]
+        );
+        assert_eq!(state.to_props().status(), "● following · warn+");
+    }
+
+    #[test]
+    fn test_log_viewer_search() {
+        let mut state = state(&["GET /a 200", "GET /b 500", "GET /c 200", "GET /d 200"]);
+        assert!(state.set_search("(").is_err());
+        state.set_search(r"5\d\d").unwrap();
+        assert!(state.handle_key(&Key::new(KeyCode::Char('n'))));
+        assert_eq!(texts(&state), ["GET /b 500", "GET /c 200"]);
+        assert!(!state.prev_match());
+        assert!(!state.next_match());
+
+        let props = state.to_props().show_timestamps(false);
+        let spans = props.entry_spans(&props.entries[0]);
+        let parts: Vec<&str> = spans.iter().map(|(t, _)| t.as_str()).collect();
+        assert_eq!(parts, ["GET /b ", "500"]);
+        assert_eq!(spans[1].1, props.match_style);
+
+        state.set_only_matching(true);
+        assert_eq!(texts(&state), ["GET /b 500"]);
+    }
+
+    #[test]
+    fn test_log_viewer_entry_spans() {
+        let props = LogViewerProps::new(Vec::<LogEntry>::new()).glyphs(GlyphSet::Ascii);
+        let entry = LogEntry::new("ERROR boom").timestamp("12:00:00");
+        let text: String = props
+            .entry_spans(&entry)
+            .into_iter()
+            .map(|(t, _)| t)
+            .collect();
+        assert_eq!(text, "12:00:00 ERROR ERROR boom");
+        assert_eq!(props.status(), "* following");
+    }
+}
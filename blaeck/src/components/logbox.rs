@@ -5,6 +5,14 @@
 //! - Expands as lines are added, up to max_lines
 //! - Scrolls to show most recent lines when exceeding max
 //!
+//! ## Wrapping
+//!
+//! Set [`LogBoxProps::wrap`] to soft-wrap long lines at a width. Wrapped
+//! rows hang under the start of the content, after the prefix or tree
+//! connector, with an optional [`continuation`](LogBoxProps::continuation)
+//! marker. `max_lines` and [`scroll`](LogBoxProps::scroll) then count
+//! visual rows rather than log lines.
+//!
 //! ## When to use LogBox
 //!
 //! - Command output or build logs
@@ -17,8 +25,11 @@
 //! - [`Table`](super::Table) — Structured data in columns
 //! - Optionally shows "+N more" indicator for hidden lines
 
+use crate::components::text_area::wrap_line;
 use crate::element::{Component, Element};
 use crate::style::{Color, Modifier, Style};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// A single line in the log box.
 #[derive(Debug, Clone)]
@@ -117,7 +128,7 @@ impl<S: Into<String>> From<S> for LogLine {
 pub struct LogBoxProps {
     /// The log lines to display.
     pub lines: Vec<LogLine>,
-    /// Maximum visible lines before scrolling (default: 5).
+    /// Maximum visible rows before scrolling (default: 5).
     pub max_lines: usize,
     /// Whether to show "+N more" when lines are hidden (default: true).
     pub show_overflow_count: bool,
@@ -131,6 +142,13 @@ pub struct LogBoxProps {
     pub indent: usize,
     /// Tree connector style for indented content.
     pub tree_style: TreeStyle,
+    /// Width in columns to soft-wrap lines at. None = no wrapping.
+    pub wrap_width: Option<usize>,
+    /// Marker drawn at the start of wrapped rows (e.g. "↪ ").
+    pub continuation: Option<String>,
+    /// Rows scrolled away from the newest end (or the oldest, with
+    /// `show_from_top`).
+    pub scroll: usize,
 }
 
 /// Style for tree connectors.
@@ -167,6 +185,9 @@ impl Default for LogBoxProps {
             show_from_bottom: true,
             indent: 0,
             tree_style: TreeStyle::None,
+            wrap_width: None,
+            continuation: None,
+            scroll: 0,
         }
     }
 }
@@ -256,23 +277,125 @@ impl LogBoxProps {
         self
     }
 
-    /// Get visible lines and overflow count.
-    fn visible_lines(&self) -> (Vec<&LogLine>, usize) {
-        let total = self.lines.len();
-        if total <= self.max_lines {
-            (self.lines.iter().collect(), 0)
-        } else if self.show_from_bottom {
-            // Show most recent (last N lines)
-            let skip = total - self.max_lines;
-            (self.lines.iter().skip(skip).collect(), skip)
+    /// Soft-wrap lines at `width` columns, including indent and prefix.
+    #[must_use]
+    pub fn wrap(mut self, width: usize) -> Self {
+        self.wrap_width = Some(width);
+        self
+    }
+
+    /// Set the marker drawn at the start of wrapped rows.
+    #[must_use]
+    pub fn continuation(mut self, marker: impl Into<String>) -> Self {
+        self.continuation = Some(marker.into());
+        self
+    }
+
+    /// Scroll by `rows` visual rows away from the newest end.
+    #[must_use]
+    pub fn scroll(mut self, rows: usize) -> Self {
+        self.scroll = rows;
+        self
+    }
+
+    /// Total number of visual rows after wrapping.
+    pub fn row_count(&self) -> usize {
+        self.rows().len()
+    }
+
+    /// The largest useful scroll offset.
+    pub fn max_scroll(&self) -> usize {
+        self.row_count().saturating_sub(self.max_lines)
+    }
+
+    /// Width of the prefix drawn before a line's content.
+    fn prefix_width(&self, line: &LogLine) -> usize {
+        let prefix = if self.tree_style != TreeStyle::None {
+            self.tree_style.chars().1
         } else {
-            // Show oldest (first N lines)
-            let overflow = total - self.max_lines;
-            (self.lines.iter().take(self.max_lines).collect(), overflow)
+            line.prefix.as_deref().unwrap_or("")
+        };
+        self.indent + prefix.width()
+    }
+
+    /// Split every line into visual rows.
+    fn rows(&self) -> Vec<LogRow<'_>> {
+        let marker_width = self
+            .continuation
+            .as_deref()
+            .map_or(0, UnicodeWidthStr::width);
+        let mut rows = Vec::new();
+        for (index, line) in self.lines.iter().enumerate() {
+            let Some(width) = self.wrap_width else {
+                rows.push(LogRow {
+                    index,
+                    line,
+                    content: line.content.clone(),
+                    first: true,
+                });
+                continue;
+            };
+            let prefix_width = self.prefix_width(line);
+            // At least one column per row, so narrow widths still progress.
+            let first_limit = width.saturating_sub(prefix_width).max(1);
+            let rest_limit = width.saturating_sub(prefix_width + marker_width).max(1);
+
+            let graphemes: Vec<&str> = line.content.graphemes(true).collect();
+            let mut start = 0;
+            let mut first = true;
+            loop {
+                let rest: String = graphemes[start..].concat();
+                let starts = wrap_line(&rest, if first { first_limit } else { rest_limit });
+                let end = starts.get(1).map_or(graphemes.len(), |&b| start + b);
+                rows.push(LogRow {
+                    index,
+                    line,
+                    content: graphemes[start..end].concat().trim_end().to_string(),
+                    first,
+                });
+                if end >= graphemes.len() {
+                    break;
+                }
+                start = end;
+                first = false;
+            }
+        }
+        rows
+    }
+
+    /// Get visible rows and the number of hidden rows.
+    fn visible_lines(&self) -> (Vec<LogRow<'_>>, usize) {
+        let mut rows = self.rows();
+        let total = rows.len();
+        if total <= self.max_lines {
+            return (rows, 0);
         }
+        let scroll = self.scroll.min(total - self.max_lines);
+        let start = if self.show_from_bottom {
+            // Show most recent (last N rows)
+            total - self.max_lines - scroll
+        } else {
+            // Show oldest (first N rows)
+            scroll
+        };
+        rows.truncate(start + self.max_lines);
+        rows.drain(..start);
+        (rows, total - self.max_lines)
     }
 }
 
+/// One visual row of a log line.
+#[derive(Debug, Clone)]
+struct LogRow<'a> {
+    /// Index of the line in `LogBoxProps::lines`.
+    index: usize,
+    line: &'a LogLine,
+    /// The part of the line's content on this row.
+    content: String,
+    /// Whether this is the line's first row.
+    first: bool,
+}
+
 /// A component that displays a scrolling log box.
 ///
 /// # Examples
@@ -305,28 +428,47 @@ impl Component for LogBox {
 
         let (visible, overflow) = props.visible_lines();
         let indent_str = " ".repeat(props.indent);
-        let (last_connector, mid_connector, _cont_connector) = props.tree_style.chars();
+        let (last_connector, mid_connector, cont_connector) = props.tree_style.chars();
+        let last_index = visible.last().map(|row| row.index);
+        let marker = props.continuation.as_deref().unwrap_or("");
 
         let mut elements: Vec<Element> = Vec::new();
 
-        for (i, line) in visible.iter().enumerate() {
-            let is_last = i == visible.len() - 1 && overflow == 0;
+        for row in &visible {
+            let is_last = Some(row.index) == last_index && overflow == 0;
 
-            // Build prefix
+            // Build prefix; wrapped rows hang under the content
             let prefix = if props.tree_style != TreeStyle::None {
-                if is_last {
-                    format!("{}{}", indent_str, last_connector)
+                match (row.first, is_last) {
+                    (true, true) => format!("{}{}", indent_str, last_connector),
+                    (true, false) => format!("{}{}", indent_str, mid_connector),
+                    (false, true) => format!(
+                        "{}{}{}",
+                        indent_str,
+                        " ".repeat(cont_connector.width()),
+                        marker
+                    ),
+                    (false, false) => format!("{}{}{}", indent_str, cont_connector, marker),
+                }
+            } else if let Some(ref custom_prefix) = row.line.prefix {
+                if row.first {
+                    format!("{}{}", indent_str, custom_prefix)
                 } else {
-                    format!("{}{}", indent_str, mid_connector)
+                    format!(
+                        "{}{}{}",
+                        indent_str,
+                        " ".repeat(custom_prefix.width()),
+                        marker
+                    )
                 }
-            } else if let Some(ref custom_prefix) = line.prefix {
-                format!("{}{}", indent_str, custom_prefix)
-            } else {
+            } else if row.first {
                 indent_str.clone()
+            } else {
+                format!("{}{}", indent_str, marker)
             };
 
-            let content = format!("{}{}", prefix, line.content);
-            let mut style = line.style;
+            let content = format!("{}{}", prefix, row.content);
+            let mut style = row.line.style;
             if let Some(bg) = props.bg_color {
                 style = style.bg(bg);
             }
@@ -500,6 +642,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_logbox_wrap_hangs_under_prefix() {
+        let props = LogBoxProps::new()
+            .line(LogLine::new("alpha beta gamma").prefix("> "))
+            .line("tail")
+            .tree_style(TreeStyle::Unicode)
+            .continuation("~")
+            .wrap(10)
+            .max_lines(10);
+        let (rows, overflow) = props.visible_lines();
+        assert_eq!(overflow, 0);
+        let contents: Vec<&str> = rows.iter().map(|r| r.content.as_str()).collect();
+        assert_eq!(contents, ["alpha", "beta", "gamma", "tail"]);
+        assert!(!rows[1].first && rows[3].first);
+
+        if let Element::Fragment(children) = LogBox::render(&props) {
+            let text: Vec<&str> = children
+                .iter()
+                .filter_map(|c| match c {
+                    Element::Text { content, .. } => Some(content.as_str()),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(text, ["├ alpha", "│ ~beta", "│ ~gamma", "└ tail"]);
+        } else {
+            panic!("Expected Fragment");
+        }
+    }
+
+    #[test]
+    fn test_logbox_scroll_counts_visual_rows() {
+        let props = LogBoxProps::with_lines(vec!["one two three", "four"])
+            .wrap(5)
+            .max_lines(2);
+        assert_eq!(props.row_count(), 4);
+        assert_eq!(props.max_scroll(), 2);
+
+        let scrolled = props.clone().scroll(1);
+        let (rows, overflow) = scrolled.visible_lines();
+        assert_eq!(overflow, 2);
+        assert_eq!(rows[0].content, "two");
+        assert_eq!(rows[1].content, "three");
+
+        // Scrolling past the top clamps to the oldest rows
+        let scrolled = props.scroll(10);
+        let (rows, _) = scrolled.visible_lines();
+        assert_eq!(rows[0].content, "one");
+    }
+
     #[test]
     fn test_tree_style_chars() {
         let (last, mid, cont) = TreeStyle::Unicode.chars();
@@ -1,8 +1,12 @@
 //! Markdown component - Render CommonMark-formatted text with terminal styling.
 //!
 //! Parses and renders markdown using pulldown-cmark. Supports:
-//! headers, bold, italic, code spans, code blocks, lists,
-//! blockquotes, links (OSC 8), and horizontal rules.
+//! headers, bold, italic, code spans, code blocks, lists, task lists,
+//! nested blockquotes, tables, footnotes, links (OSC 8), and horizontal
+//! rules.
+//!
+//! Tables are laid out with the [`Table`](super::Table) component, and
+//! footnotes are collected under the document, numbered in order of use.
 //!
 //! ## When to use Markdown
 //!
@@ -14,10 +18,15 @@
 //!
 //! - [`Text`](super::Text) — Plain text without parsing
 //! - [`SyntaxHighlight`](super::SyntaxHighlight) — Code blocks with highlighting
+//! - [`Toc`](super::Toc) — Jump list built from [`MarkdownProps::sections`]
 
+use crate::components::checkbox::CheckboxStyle;
+use crate::components::table::{CellAlign, Row, Table, TableProps};
+use crate::components::toc::Section;
 use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
 use crate::style::{Color, Modifier, Style};
-use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use pulldown_cmark::{Alignment, Event, Options, Parser, Tag, TagEnd};
 
 /// Properties for the Markdown component.
 #[derive(Debug, Clone)]
@@ -34,6 +43,8 @@ pub struct MarkdownProps {
     pub quote_color: Color,
     /// Whether to enable OSC 8 terminal hyperlinks.
     pub enable_hyperlinks: bool,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
 }
 
 impl Default for MarkdownProps {
@@ -45,6 +56,7 @@ impl Default for MarkdownProps {
             link_color: Color::Blue,
             quote_color: Color::DarkGray,
             enable_hyperlinks: true,
+            glyphs: None,
         }
     }
 }
@@ -92,6 +104,18 @@ impl MarkdownProps {
         self.enable_hyperlinks = enable;
         self
     }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// The document's headings, with the rendered line each one is on.
+    pub fn sections(&self) -> Vec<Section> {
+        render_lines(self).1
+    }
 }
 
 /// Style state for tracking nested formatting.
@@ -102,7 +126,7 @@ struct StyleState {
     strikethrough: bool,
     code: bool,
     heading_level: Option<u8>,
-    in_blockquote: bool,
+    quote_depth: usize,
     link_url: Option<String>,
 }
 
@@ -125,7 +149,7 @@ impl StyleState {
             style = style.fg(props.code_color);
         } else if self.heading_level.is_some() {
             style = style.fg(props.header_color);
-        } else if self.in_blockquote {
+        } else if self.quote_depth > 0 {
             style = style.fg(props.quote_color);
             modifiers |= Modifier::DIM;
         } else if self.link_url.is_some() {
@@ -144,6 +168,86 @@ enum ListKind {
     Ordered(u64),
 }
 
+/// A table being collected, cell by cell.
+#[derive(Debug, Default)]
+struct TableBuilder {
+    aligns: Vec<CellAlign>,
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+    row: Vec<String>,
+    cell: String,
+}
+
+impl TableBuilder {
+    /// The table's lines: header, divider, then rows.
+    fn lines(self, props: &MarkdownProps, prefix: &[(String, Style)]) -> Vec<Element> {
+        let table = TableProps::new(self.rows.into_iter().map(Row::new))
+            .header(Row::new(self.header))
+            .column_aligns(self.aligns)
+            .column_spacing(2)
+            .row_dividers();
+        let content = match Table::render(&table) {
+            Element::Text { content, .. } => content,
+            _ => return Vec::new(),
+        };
+        let header_style = Style::new()
+            .fg(props.header_color)
+            .add_modifier(Modifier::BOLD);
+        let divider_style = Style::new().fg(Color::DarkGray);
+        // Row dividers are only wanted under the header
+        content
+            .lines()
+            .enumerate()
+            .filter(|(i, line)| *i < 2 || !line.starts_with('─'))
+            .map(|(i, line)| {
+                let style = match i {
+                    0 => header_style,
+                    1 => divider_style,
+                    _ => Style::new(),
+                };
+                let mut segments = prefix.to_vec();
+                segments.push((line.trim_end().to_string(), style));
+                line_to_element(&segments)
+            })
+            .collect()
+    }
+}
+
+/// The bars in front of a line `depth` blockquotes deep.
+fn quote_prefix(depth: usize, props: &MarkdownProps) -> Vec<(String, Style)> {
+    if depth == 0 {
+        return Vec::new();
+    }
+    let bar = if GlyphSet::resolve(props.glyphs).is_ascii() {
+        "> "
+    } else {
+        "│ "
+    };
+    vec![(bar.repeat(depth), Style::new().fg(props.quote_color))]
+}
+
+/// Push a segment, starting the line with the blockquote bars if it's new.
+fn push_segment(
+    line: &mut Vec<(String, Style)>,
+    depth: usize,
+    props: &MarkdownProps,
+    text: String,
+    style: Style,
+) {
+    if line.is_empty() {
+        line.extend(quote_prefix(depth, props));
+    }
+    line.push((text, style));
+}
+
+/// Flush the current line, if there is one.
+fn flush(line: &mut Vec<(String, Style)>, lines: &mut Vec<Element>) {
+    if !line.is_empty() {
+        lines.push(line_to_element(line));
+        line.clear();
+    }
+}
+
 /// A component that renders markdown content.
 ///
 /// # Examples
@@ -169,210 +273,322 @@ impl Component for Markdown {
     type Props = MarkdownProps;
 
     fn render(props: &Self::Props) -> Element {
-        if props.content.is_empty() {
-            return Element::text("");
+        let (lines, _) = render_lines(props);
+        if lines.is_empty() {
+            Element::text("")
+        } else {
+            // Always return Fragment - the renderer handles vertical layout
+            // Even single lines must be wrapped so the renderer knows this is Markdown
+            Element::Fragment(lines)
         }
+    }
+}
 
-        let options = Options::ENABLE_STRIKETHROUGH;
-        let parser = Parser::new_ext(&props.content, options);
+/// Render the content to lines, collecting headings along the way.
+fn render_lines(props: &MarkdownProps) -> (Vec<Element>, Vec<Section>) {
+    let mut sections = Vec::new();
+    if props.content.is_empty() {
+        return (Vec::new(), sections);
+    }
 
-        let mut lines: Vec<Element> = Vec::new();
-        let mut current_line: Vec<(String, Style)> = Vec::new();
-        let mut style_state = StyleState::default();
-        let mut list_stack: Vec<ListKind> = Vec::new();
-        let mut in_code_block = false;
+    let options = Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TABLES
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES;
+    let parser = Parser::new_ext(&props.content, options);
+    let glyphs = GlyphSet::resolve(props.glyphs);
+
+    let mut lines: Vec<Element> = Vec::new();
+    let mut current_line: Vec<(String, Style)> = Vec::new();
+    let mut style_state = StyleState::default();
+    let mut list_stack: Vec<ListKind> = Vec::new();
+    let mut in_code_block = false;
+    let mut heading_title = String::new();
+    // Index of the current item's bullet in `current_line`, for task markers
+    let mut item_marker: Option<usize> = None;
+    let mut table: Option<TableBuilder> = None;
+    // Footnote labels in order of first use; their position is the number
+    let mut footnote_labels: Vec<String> = Vec::new();
+    // Rendered definitions with their numbers
+    let mut footnotes: Vec<(usize, Vec<Element>)> = Vec::new();
+    // Document lines, set aside while a footnote definition is rendered
+    let mut body_lines: Option<(usize, Vec<Element>)> = None;
+
+    let mut footnote_number = |label: &str| match footnote_labels.iter().position(|l| l == label) {
+        Some(i) => i + 1,
+        None => {
+            footnote_labels.push(label.to_string());
+            footnote_labels.len()
+        }
+    };
 
-        for event in parser {
+    for event in parser {
+        // Table cells collect plain text until the table is complete
+        if let Some(builder) = table.as_mut() {
             match event {
-                Event::Start(tag) => {
-                    match tag {
-                        Tag::Heading { level, .. } => {
-                            style_state.heading_level = Some(level as u8);
-                        }
-                        Tag::Strong => {
-                            style_state.bold = true;
-                        }
-                        Tag::Emphasis => {
-                            style_state.italic = true;
-                        }
-                        Tag::Strikethrough => {
-                            style_state.strikethrough = true;
-                        }
-                        Tag::CodeBlock(_) => {
-                            in_code_block = true;
-                            style_state.code = true;
-                        }
-                        Tag::BlockQuote(_) => {
-                            style_state.in_blockquote = true;
-                        }
-                        Tag::List(start) => {
-                            if let Some(n) = start {
-                                list_stack.push(ListKind::Ordered(n));
-                            } else {
-                                list_stack.push(ListKind::Bullet);
-                            }
-                        }
-                        Tag::Item => {
-                            // Add list marker
-                            let indent = "  ".repeat(list_stack.len().saturating_sub(1));
-                            let marker = match list_stack.last_mut() {
-                                Some(ListKind::Bullet) => format!("{}• ", indent),
-                                Some(ListKind::Ordered(n)) => {
-                                    let marker = format!("{}{}. ", indent, n);
-                                    *n += 1;
-                                    marker
-                                }
-                                None => String::new(),
-                            };
-                            if !marker.is_empty() {
-                                current_line.push((marker, Style::new()));
-                            }
-                        }
-                        Tag::Link { dest_url, .. } => {
-                            style_state.link_url = Some(dest_url.to_string());
-                        }
-                        _ => {}
-                    }
+                Event::Text(text) | Event::Code(text) => builder.cell.push_str(&text),
+                Event::End(TagEnd::TableCell) => {
+                    let cell = std::mem::take(&mut builder.cell);
+                    builder.row.push(cell.trim().to_string());
                 }
-                Event::End(tag_end) => {
-                    match tag_end {
-                        TagEnd::Heading(_) => {
-                            // Flush current line
-                            if !current_line.is_empty() {
-                                lines.push(line_to_element(&current_line));
-                                current_line.clear();
-                            }
-                            style_state.heading_level = None;
-                        }
-                        TagEnd::Strong => {
-                            style_state.bold = false;
-                        }
-                        TagEnd::Emphasis => {
-                            style_state.italic = false;
-                        }
-                        TagEnd::Strikethrough => {
-                            style_state.strikethrough = false;
-                        }
-                        TagEnd::CodeBlock => {
-                            in_code_block = false;
-                            style_state.code = false;
-                        }
-                        TagEnd::BlockQuote(_) => {
-                            style_state.in_blockquote = false;
-                        }
-                        TagEnd::List(_) => {
-                            list_stack.pop();
-                        }
-                        TagEnd::Item => {
-                            // Flush current line
-                            if !current_line.is_empty() {
-                                lines.push(line_to_element(&current_line));
-                                current_line.clear();
-                            }
-                        }
-                        TagEnd::Paragraph => {
-                            // Flush current line
-                            if !current_line.is_empty() {
-                                lines.push(line_to_element(&current_line));
-                                current_line.clear();
-                            }
-                        }
-                        TagEnd::Link => {
-                            style_state.link_url = None;
-                        }
-                        _ => {}
+                Event::End(TagEnd::TableHead) => builder.header = std::mem::take(&mut builder.row),
+                Event::End(TagEnd::TableRow) => {
+                    let row = std::mem::take(&mut builder.row);
+                    builder.rows.push(row);
+                }
+                Event::End(TagEnd::Table) => {
+                    if let Some(builder) = table.take() {
+                        let prefix = quote_prefix(style_state.quote_depth, props);
+                        lines.extend(builder.lines(props, &prefix));
                     }
                 }
-                Event::Text(text) => {
-                    let style = style_state.to_style(props);
-                    let text_str = text.to_string();
-
-                    // Handle OSC 8 hyperlinks for links
-                    if let Some(ref url) = style_state.link_url {
-                        if props.enable_hyperlinks {
-                            // OSC 8 format: \x1b]8;;URL\x1b\\TEXT\x1b]8;;\x1b\\
-                            let hyperlink =
-                                format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text_str);
-                            current_line.push((hyperlink, style));
+                _ => {}
+            }
+            continue;
+        }
+
+        match event {
+            Event::Start(tag) => {
+                match tag {
+                    Tag::Heading { level, .. } => {
+                        style_state.heading_level = Some(level as u8);
+                        heading_title.clear();
+                    }
+                    Tag::Strong => {
+                        style_state.bold = true;
+                    }
+                    Tag::Emphasis => {
+                        style_state.italic = true;
+                    }
+                    Tag::Strikethrough => {
+                        style_state.strikethrough = true;
+                    }
+                    Tag::CodeBlock(_) => {
+                        in_code_block = true;
+                        style_state.code = true;
+                    }
+                    Tag::BlockQuote(_) => {
+                        flush(&mut current_line, &mut lines);
+                        style_state.quote_depth += 1;
+                    }
+                    Tag::List(start) => {
+                        // A nested list starts below its parent item's text
+                        flush(&mut current_line, &mut lines);
+                        if let Some(n) = start {
+                            list_stack.push(ListKind::Ordered(n));
                         } else {
-                            // Fallback: show as [text](url)
-                            current_line.push((text_str, style));
-                        }
-                    } else if in_code_block {
-                        // Code blocks may have multiple lines
-                        for (i, line) in text_str.lines().enumerate() {
-                            if i > 0 {
-                                // Flush previous line
-                                if !current_line.is_empty() {
-                                    lines.push(line_to_element(&current_line));
-                                    current_line.clear();
-                                }
-                            }
-                            current_line.push((format!("  {}", line), style));
+                            list_stack.push(ListKind::Bullet);
                         }
-                    } else if style_state.in_blockquote {
-                        // Blockquote prefix
-                        for (i, line) in text_str.lines().enumerate() {
-                            if i > 0 && !current_line.is_empty() {
-                                lines.push(line_to_element(&current_line));
-                                current_line.clear();
+                    }
+                    Tag::Item => {
+                        // Add list marker
+                        let indent = "  ".repeat(list_stack.len().saturating_sub(1));
+                        let marker = match list_stack.last_mut() {
+                            Some(ListKind::Bullet) => format!("{}• ", indent),
+                            Some(ListKind::Ordered(n)) => {
+                                let marker = format!("{}{}. ", indent, n);
+                                *n += 1;
+                                marker
                             }
-                            current_line.push((format!("> {}", line), style));
+                            None => String::new(),
+                        };
+                        if !marker.is_empty() {
+                            push_segment(
+                                &mut current_line,
+                                style_state.quote_depth,
+                                props,
+                                marker,
+                                Style::new(),
+                            );
+                            item_marker = Some(current_line.len() - 1);
                         }
-                    } else {
-                        current_line.push((text_str, style));
                     }
+                    Tag::Link { dest_url, .. } => {
+                        style_state.link_url = Some(dest_url.to_string());
+                    }
+                    Tag::Table(aligns) => {
+                        flush(&mut current_line, &mut lines);
+                        let aligns = aligns
+                            .iter()
+                            .map(|align| match align {
+                                Alignment::Center => CellAlign::Center,
+                                Alignment::Right => CellAlign::Right,
+                                Alignment::Left | Alignment::None => CellAlign::Left,
+                            })
+                            .collect();
+                        table = Some(TableBuilder {
+                            aligns,
+                            ..Default::default()
+                        });
+                    }
+                    Tag::FootnoteDefinition(label) => {
+                        flush(&mut current_line, &mut lines);
+                        let number = footnote_number(&label);
+                        body_lines = Some((number, std::mem::take(&mut lines)));
+                        current_line
+                            .push((format!("[{number}] "), Style::new().fg(props.link_color)));
+                    }
+                    _ => {}
                 }
-                Event::Code(code) => {
-                    let style = Style::new().fg(props.code_color);
-                    current_line.push((format!("`{}`", code), style));
+            }
+            Event::End(tag_end) => match tag_end {
+                TagEnd::Heading(level) => {
+                    sections.push(Section::new(level as u8, heading_title.trim(), lines.len()));
+                    flush(&mut current_line, &mut lines);
+                    style_state.heading_level = None;
                 }
-                Event::SoftBreak => {
-                    if style_state.in_blockquote {
-                        // In blockquotes, soft breaks should create new lines
-                        if !current_line.is_empty() {
-                            lines.push(line_to_element(&current_line));
-                            current_line.clear();
-                        }
-                    } else {
-                        current_line.push((" ".to_string(), Style::new()));
-                    }
+                TagEnd::Strong => {
+                    style_state.bold = false;
                 }
-                Event::HardBreak => {
-                    if !current_line.is_empty() {
-                        lines.push(line_to_element(&current_line));
-                        current_line.clear();
-                    }
+                TagEnd::Emphasis => {
+                    style_state.italic = false;
+                }
+                TagEnd::Strikethrough => {
+                    style_state.strikethrough = false;
+                }
+                TagEnd::CodeBlock => {
+                    in_code_block = false;
+                    style_state.code = false;
                 }
-                Event::Rule => {
-                    // Horizontal rule
-                    if !current_line.is_empty() {
-                        lines.push(line_to_element(&current_line));
-                        current_line.clear();
+                TagEnd::BlockQuote(_) => {
+                    flush(&mut current_line, &mut lines);
+                    style_state.quote_depth = style_state.quote_depth.saturating_sub(1);
+                }
+                TagEnd::List(_) => {
+                    list_stack.pop();
+                }
+                TagEnd::Item | TagEnd::Paragraph => {
+                    flush(&mut current_line, &mut lines);
+                    item_marker = None;
+                }
+                TagEnd::Link => {
+                    style_state.link_url = None;
+                }
+                TagEnd::FootnoteDefinition => {
+                    flush(&mut current_line, &mut lines);
+                    if let Some((number, body)) = body_lines.take() {
+                        footnotes.push((number, std::mem::replace(&mut lines, body)));
                     }
-                    let hr_style = Style::new().fg(Color::DarkGray);
-                    lines.push(Element::styled_text(
-                        "────────────────────────────────",
-                        hr_style,
-                    ));
                 }
                 _ => {}
+            },
+            Event::Text(text) => {
+                let style = style_state.to_style(props);
+                let text_str = text.to_string();
+                if style_state.heading_level.is_some() {
+                    heading_title.push_str(&text_str);
+                }
+
+                // Handle OSC 8 hyperlinks for links
+                if let Some(ref url) = style_state.link_url {
+                    let text_str = if props.enable_hyperlinks {
+                        // OSC 8 format: \x1b]8;;URL\x1b\\TEXT\x1b]8;;\x1b\\
+                        format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text_str)
+                    } else {
+                        // Fallback: show as [text](url)
+                        text_str
+                    };
+                    push_segment(
+                        &mut current_line,
+                        style_state.quote_depth,
+                        props,
+                        text_str,
+                        style,
+                    );
+                } else if in_code_block {
+                    // Code blocks may have multiple lines
+                    for (i, line) in text_str.lines().enumerate() {
+                        if i > 0 {
+                            flush(&mut current_line, &mut lines);
+                        }
+                        push_segment(
+                            &mut current_line,
+                            style_state.quote_depth,
+                            props,
+                            format!("  {}", line),
+                            style,
+                        );
+                    }
+                } else {
+                    push_segment(
+                        &mut current_line,
+                        style_state.quote_depth,
+                        props,
+                        text_str,
+                        style,
+                    );
+                }
+            }
+            Event::Code(code) => {
+                if style_state.heading_level.is_some() {
+                    heading_title.push_str(&code);
+                }
+                let style = Style::new().fg(props.code_color);
+                push_segment(
+                    &mut current_line,
+                    style_state.quote_depth,
+                    props,
+                    format!("`{}`", code),
+                    style,
+                );
+            }
+            Event::TaskListMarker(checked) => {
+                // Swap the item's bullet for a checkbox
+                if let Some(segment) = item_marker.and_then(|i| current_line.get_mut(i)) {
+                    let (on, off) = CheckboxStyle::Unicode.for_glyphs(glyphs).chars();
+                    let indent = "  ".repeat(list_stack.len().saturating_sub(1));
+                    *segment = if checked {
+                        (format!("{indent}{on} "), Style::new().fg(Color::Green))
+                    } else {
+                        (format!("{indent}{off} "), Style::new())
+                    };
+                }
+            }
+            Event::FootnoteReference(label) => {
+                let number = footnote_number(&label);
+                push_segment(
+                    &mut current_line,
+                    style_state.quote_depth,
+                    props,
+                    format!("[{number}]"),
+                    Style::new().fg(props.link_color),
+                );
             }
+            Event::SoftBreak => {
+                if style_state.quote_depth > 0 {
+                    // In blockquotes, soft breaks should create new lines
+                    flush(&mut current_line, &mut lines);
+                } else {
+                    current_line.push((" ".to_string(), Style::new()));
+                }
+            }
+            Event::HardBreak => {
+                flush(&mut current_line, &mut lines);
+            }
+            Event::Rule => {
+                // Horizontal rule
+                flush(&mut current_line, &mut lines);
+                let hr_style = Style::new().fg(Color::DarkGray);
+                lines.push(Element::styled_text(
+                    "────────────────────────────────",
+                    hr_style,
+                ));
+            }
+            _ => {}
         }
+    }
 
-        // Flush any remaining content
-        if !current_line.is_empty() {
-            lines.push(line_to_element(&current_line));
-        }
+    // Flush any remaining content
+    flush(&mut current_line, &mut lines);
 
-        if lines.is_empty() {
-            Element::text("")
-        } else {
-            // Always return Fragment - the renderer handles vertical layout
-            // Even single lines must be wrapped so the renderer knows this is Markdown
-            Element::Fragment(lines)
-        }
+    // Footnotes go under the document, in order of use
+    if !footnotes.is_empty() {
+        footnotes.sort_by_key(|(number, _)| *number);
+        lines.push(Element::text(""));
+        lines.extend(footnotes.into_iter().flat_map(|(_, lines)| lines));
     }
+
+    (lines, sections)
 }
 
 /// Convert a line of styled segments to a single Element.
@@ -554,6 +770,21 @@ mod tests {
         assert!(elem.is_fragment());
     }
 
+    #[test]
+    fn test_markdown_sections() {
+        let props = MarkdownProps::new(
+            "# Intro\n\nHello\n\n## Install `blaeck`\n\n- one\n- two\n\n## Usage",
+        );
+        assert_eq!(
+            props.sections(),
+            vec![
+                Section::new(1, "Intro", 0),
+                Section::new(2, "Install blaeck", 2),
+                Section::new(2, "Usage", 5),
+            ]
+        );
+    }
+
     #[test]
     fn test_markdown_block_helper() {
         let props = markdown_block("test content");
@@ -584,4 +815,63 @@ mod tests {
         let style = state.to_style(&props);
         assert_eq!(style.fg, Color::Yellow);
     }
+
+    fn line_texts(content: &str) -> Vec<String> {
+        let props = MarkdownProps::new(content).glyphs(GlyphSet::Unicode);
+        let (lines, _) = render_lines(&props);
+        lines
+            .iter()
+            .map(|line| match line {
+                Element::Text { content, .. } => content.clone(),
+                Element::Fragment(spans) => spans
+                    .iter()
+                    .map(|span| match span {
+                        Element::Text { content, .. } => content.as_str(),
+                        _ => "",
+                    })
+                    .collect(),
+                _ => String::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        let lines = line_texts("| Name | Size |\n|------|-----:|\n| a | 1 |\n| bb | 22 |");
+        assert_eq!(
+            lines,
+            ["Name  Size", "──────────", "a        1", "bb      22"]
+        );
+    }
+
+    #[test]
+    fn test_markdown_task_list() {
+        let lines = line_texts("- [x] done\n- [ ] todo\n  - [ ] nested\n- plain");
+        assert_eq!(lines, ["☑ done", "☐ todo", "  ☐ nested", "• plain"]);
+
+        let props = MarkdownProps::new("- [x] done").glyphs(GlyphSet::Ascii);
+        let (lines, _) = render_lines(&props);
+        match &lines[0] {
+            Element::Fragment(spans) => match &spans[0] {
+                Element::Text { content, .. } => assert_eq!(content, "[x] "),
+                _ => panic!("expected text"),
+            },
+            _ => panic!("expected fragment"),
+        }
+    }
+
+    #[test]
+    fn test_markdown_nested_blockquote() {
+        let lines = line_texts("> outer **bold**\n>\n> > inner\n\nafter");
+        assert_eq!(lines, ["│ outer bold", "│ │ inner", "after"]);
+    }
+
+    #[test]
+    fn test_markdown_footnotes() {
+        let lines = line_texts("Fact[^src] and more[^b].\n\n[^b]: Second.\n[^src]: First.");
+        assert_eq!(
+            lines,
+            ["Fact[1] and more[2].", "", "[1] First.", "[2] Second."]
+        );
+    }
 }
@@ -6,96 +6,188 @@
 pub mod autocomplete;
 pub mod badge;
 pub mod barchart;
+pub mod big_text;
 pub mod blink;
 pub mod box_component;
 pub mod breadcrumbs;
+pub mod calendar;
+pub mod canvas;
+pub mod charts;
 pub mod checkbox;
+pub mod collapsible;
 pub mod confirm;
+pub mod context_menu;
+pub mod decorate;
 pub mod diff;
 pub mod divider;
+pub mod edit_keys;
+pub mod form;
+pub mod gauge;
 pub mod gradient;
+pub mod hyperlink;
+pub mod image;
 pub mod indent;
 pub mod keyhints;
+pub mod line_chart;
 pub mod link;
+#[cfg(feature = "log-viewer")]
+pub mod log_viewer;
 pub mod logbox;
 pub mod markdown;
 pub mod modal;
+pub mod multi_progress;
 pub mod multiselect;
 pub mod newline;
+pub mod pending_keys;
+pub mod placeholder;
 pub mod progress;
+pub mod radio_group;
+pub mod reveal;
+pub mod rule;
+pub mod scroll_view;
+pub mod scrollbar;
 pub mod select;
 pub mod spacer;
 pub mod sparkline;
 pub mod spinner;
+pub mod sprite;
 pub mod r#static;
 pub mod statusbar;
 pub mod syntax;
 pub mod table;
 pub mod tabs;
+pub mod task_list;
+#[cfg(test)]
+mod test_util;
 pub mod text;
+pub mod text_area;
 pub mod text_input;
+pub mod time_picker;
 pub mod timer;
+pub mod toc;
 pub mod transform;
 pub mod tree;
+pub mod truncate;
+pub mod value_tree;
+pub mod virtual_table;
+pub mod wizard;
 
 pub use autocomplete::{
     Autocomplete, AutocompleteItem, AutocompleteProps, AutocompleteState, FilterMode,
 };
-pub use badge::{badge, badge_bracket, Badge, BadgeProps, BadgeStyle};
+pub use badge::{badge, badge_bracket, badge_color, Badge, BadgeProps, BadgeStyle};
 pub use barchart::{
-    bar_chart, bar_chart_with_values, BarChart, BarChartProps, BarData, BarStyle, ValueFormat,
+    bar_chart, bar_chart_with_values, BarChart, BarChartProps, BarData, BarLayout, BarOrientation,
+    BarSeries, BarStyle, ValueFormat,
 };
+pub use big_text::{BigFont, BigText, BigTextProps};
 pub use blink::{
     animated_indicator, animated_indicator_colored, blink, blink_or, blink_pattern, blinking_dot,
     pulsing_dot,
 };
 pub use box_component::{BorderChars, BorderColors, BorderSides, BorderStyle, Box, BoxProps};
 pub use breadcrumbs::{
-    breadcrumbs, breadcrumbs_path, BreadcrumbSeparator, Breadcrumbs, BreadcrumbsProps, Crumb,
+    breadcrumbs, breadcrumbs_path, BreadcrumbCollapse, BreadcrumbSeparator, Breadcrumbs,
+    BreadcrumbsProps, Crumb,
 };
+pub use calendar::{days_in_month, is_leap_year, Calendar, CalendarProps, Date, Weekday};
+pub use canvas::{Canvas, CanvasProps, DrawFn, Painter};
+pub use charts::{Axis, Legend, LegendItem, Scale, ScaleKind};
 pub use checkbox::{checkbox, Checkbox, CheckboxProps, CheckboxStyle};
+pub use collapsible::{AccordionState, Collapsible, CollapsibleProps};
 pub use confirm::{confirm_prompt, Confirm, ConfirmProps, ConfirmStyle};
+pub use context_menu::{
+    ContextMenu, ContextMenuAction, ContextMenuProps, ContextMenuState, MenuItem,
+};
+pub use decorate::{centered, with_background, with_border, with_padding, with_title};
 pub use diff::{diff_lines, Diff, DiffLine, DiffLineType, DiffProps, DiffStyle};
-pub use divider::{divider, divider_with_label, Divider, DividerProps, DividerStyle};
-pub use gradient::{gradient, gradient_preset, ColorStop, Gradient, GradientPreset, GradientProps};
+pub use divider::{
+    divider, divider_with_label, Divider, DividerAlign, DividerProps, DividerStatus, DividerStyle,
+};
+pub use edit_keys::{EditKeys, VimMode};
+pub use form::{
+    Field, FieldInput, FieldValue, Form, FormAction, FormProps, FormResult, FormState, Validator,
+};
+pub use gauge::{Gauge, GaugeProps, GaugeShape};
+pub use gradient::{
+    gradient, gradient_preset, ColorStop, Gradient, GradientDirection, GradientPreset,
+    GradientProps,
+};
+pub use hyperlink::{Hyperlink, HyperlinkProps};
+pub use image::{Image, ImageProps};
 pub use indent::{Indent, IndentProps};
-pub use keyhints::{key_hints, KeyHint, KeyHintSeparator, KeyHintStyle, KeyHints, KeyHintsProps};
+pub use keyhints::{
+    key_hints, key_hints_from, KeyBindings, KeyHint, KeyHintSeparator, KeyHintStyle, KeyHints,
+    KeyHintsProps,
+};
+pub use line_chart::{LineChart, LineChartProps, LineMarker, Series};
 pub use link::{link, link_url, Link, LinkProps};
+#[cfg(feature = "log-viewer")]
+pub use log_viewer::{LogEntry, LogLevel, LogViewer, LogViewerProps, LogViewerState};
 pub use logbox::{log_box, LogBox, LogBoxProps, LogLine, TreeStyle};
 pub use markdown::{markdown_block, Markdown, MarkdownProps};
 pub use modal::{
-    alert, confirm_modal, error_modal, success_modal, Modal, ModalButton, ModalProps, ModalStyle,
+    alert, confirm_modal, error_modal, success_modal, Backdrop, BackdropProps, Modal, ModalAction,
+    ModalButton, ModalProps, ModalState, ModalStyle,
+};
+pub use multi_progress::{
+    BarId, BarStatus, MultiProgress, MultiProgressProps, MultiProgressState, ProgressBar,
 };
 pub use multiselect::{
     MultiSelect, MultiSelectItem, MultiSelectProps, MultiSelectState, MultiSelectStyle,
 };
 pub use newline::{Newline, NewlineProps};
+pub use pending_keys::{PendingKeys, PendingKeysProps};
+pub use placeholder::{EmptyState, ErrorState, LoadingState, PlaceholderKind, PlaceholderProps};
 pub use progress::{
-    progress_bar, progress_bar_bracketed, Progress, ProgressChars, ProgressProps, ProgressStyle,
+    format_bytes, progress_bar, progress_bar_bracketed, Progress, ProgressChars, ProgressProps,
+    ProgressRate, ProgressStyle,
 };
 pub use r#static::{Static, StaticItem, StaticProps};
+pub use radio_group::{RadioGroup, RadioGroupProps, RadioGroupState, RadioLayout, RadioStyle};
+pub use reveal::{Reveal, RevealProps, RevealState};
+pub use rule::{rule, Rule, RuleProps};
+pub use scroll_view::{ScrollState, ScrollView, ScrollViewProps};
+pub use scrollbar::{Scrollbar, ScrollbarOrientation, ScrollbarProps};
 pub use select::{Select, SelectIndicator, SelectItem, SelectProps, SelectState};
 pub use spacer::{flex_spacer, spacer, Spacer, SpacerProps};
 pub use sparkline::{sparkline, sparkline_labeled, Sparkline, SparklineProps, SparklineStyle};
-pub use spinner::{spinner_frame, spinner_frame_interval, Spinner, SpinnerProps, SpinnerStyle};
+pub use spinner::{
+    spinner_frame, spinner_frame_interval, Spinner, SpinnerFrames, SpinnerProps, SpinnerStyle,
+};
+pub use sprite::{Sprite, SpriteParseError, SpriteProps};
 pub use statusbar::{
     git_branch, icons, status_error, status_ok, status_warning, StatusBar, StatusBarProps,
     StatusSegment, StatusSeparator,
 };
 pub use syntax::{
-    syntax_highlight, syntax_highlight_with_lines, LineNumberStyle, SyntaxHighlight,
-    SyntaxHighlightProps, SyntaxTheme,
+    syntax_highlight, syntax_highlight_with_lines, HighlightedLine, LineNumberStyle, SyntaxBackend,
+    SyntaxHighlight, SyntaxHighlightProps, SyntaxTheme, SyntectBackend,
 };
 pub use table::{CellAlign, ColumnWidth, Row, RowStyle, Table, TableCell, TableProps, TableState};
 pub use tabs::{Tab, TabDivider, TabStyle, Tabs, TabsProps, TabsState};
+pub use task_list::{TaskItem, TaskItemStatus, TaskList, TaskListProps, TaskListState};
 pub use text::{Text, TextProps, TextWrap};
-pub use text_input::{TextInput, TextInputProps, TextInputState};
+pub use text_area::{CursorMove, TextArea, TextAreaProps, TextAreaState, TextPos};
+pub use text_input::{
+    PasteHandler, PasteMode, TextInput, TextInputProps, TextInputState, TextValidator,
+};
+pub use time_picker::{TimeField, TimePicker, TimePickerMode, TimePickerProps, TimePickerState};
 pub use timer::{
-    countdown, countdown_with_thresholds, stopwatch, timer_display, TimeFormat, Timer, TimerMode,
-    TimerProps,
+    countdown, countdown_with_thresholds, stopwatch, timer_display, Countdown, TimeFormat, Timer,
+    TimerMode, TimerProps, TimerState, TimerZone,
+};
+pub use toc::{
+    marked_sections, next_section, prev_section, section_at, Section, Toc, TocProps, TocState,
 };
 pub use transform::{transforms, Transform, TransformFn, TransformProps};
-pub use tree::{tree_view, TreeConnectors, TreeNode, TreeState, TreeView, TreeViewProps};
+pub use tree::{
+    tree_view, ChildLoader, TreeConnectors, TreeNode, TreeState, TreeView, TreeViewProps,
+};
+pub use truncate::{Truncate, TruncateProps, TruncateState};
+pub use value_tree::{DataValue, ValueTree, ValueTreeProps, ValueTreeState};
+pub use virtual_table::{RowFn, VirtualTable, VirtualTableProps, VirtualTableState};
+pub use wizard::{Wizard, WizardAction, WizardProps, WizardState};
 
 #[cfg(test)]
 mod tests {
@@ -225,6 +317,29 @@ mod tests {
         assert_eq!(chars.horizontal, 'E');
     }
 
+    #[test]
+    fn test_border_style_for_glyphs() {
+        use crate::glyphs::GlyphSet;
+
+        assert_eq!(
+            BorderStyle::Round.for_glyphs(GlyphSet::Ascii),
+            BorderStyle::Classic
+        );
+        assert_eq!(
+            BorderStyle::Round.for_glyphs(GlyphSet::Unicode),
+            BorderStyle::Round
+        );
+        let ascii = BorderStyle::Custom(BorderChars {
+            top_left: '*',
+            top_right: '*',
+            bottom_left: '*',
+            bottom_right: '*',
+            horizontal: '~',
+            vertical: '!',
+        });
+        assert_eq!(ascii.for_glyphs(GlyphSet::Ascii), ascii);
+    }
+
     #[test]
     fn test_border_style_none_has_no_chars() {
         // BorderStyle::None should not be used to get chars in practice
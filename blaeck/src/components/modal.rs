@@ -27,10 +27,39 @@
 //!     vec![],
 //! );
 //! ```
+//!
+//! ## As an overlay
+//!
+//! [`ModalProps::overlay`] centers the modal over the rest of the UI and
+//! dims what's underneath with a [`Backdrop`]. [`ModalState`] keeps the
+//! keyboard on the modal's buttons while it's open and gives focus back
+//! to whatever had it when it closes:
+//!
+//! ```ignore
+//! let mut modal = ModalState::new();
+//! modal.open(&props.buttons, &mut focus);
+//!
+//! // In the key handler, before anything else gets the key
+//! match modal.handle_key(&key, &props.buttons) {
+//!     ModalAction::Pressed(0) | ModalAction::Dismissed => modal.close(&mut focus),
+//!     ModalAction::Pressed(_) => { delete(); modal.close(&mut focus) }
+//!     ModalAction::Ignored => app_handle_key(&key),
+//!     _ => {}
+//! }
+//!
+//! // In the render function
+//! modal.render(&props, app_view(), (width, height))
+//! ```
 
-use crate::components::box_component::BorderStyle;
+use crate::components::box_component::{BorderStyle, Box, BoxProps};
 use crate::element::{Component, Element};
+use crate::focus::{FocusId, FocusManager};
+use crate::input::Key;
+use crate::layout::LayoutStyle;
+use crate::overlay::{center, float, Rect};
+use crate::palette::Palette;
 use crate::style::{Color, Modifier, Style};
+use crossterm::event::KeyCode;
 
 /// Modal visual style.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -53,10 +82,10 @@ impl ModalStyle {
     pub fn title_color(&self) -> Option<Color> {
         match self {
             ModalStyle::Default => None,
-            ModalStyle::Info => Some(Color::Cyan),
-            ModalStyle::Success => Some(Color::Green),
-            ModalStyle::Warning => Some(Color::Yellow),
-            ModalStyle::Error => Some(Color::Red),
+            ModalStyle::Info => Some(Palette::detect().info),
+            ModalStyle::Success => Some(Palette::detect().success),
+            ModalStyle::Warning => Some(Palette::detect().warning),
+            ModalStyle::Error => Some(Palette::detect().error),
         }
     }
 
@@ -64,10 +93,10 @@ impl ModalStyle {
     pub fn border_color(&self) -> Option<Color> {
         match self {
             ModalStyle::Default => Some(Color::White),
-            ModalStyle::Info => Some(Color::Cyan),
-            ModalStyle::Success => Some(Color::Green),
-            ModalStyle::Warning => Some(Color::Yellow),
-            ModalStyle::Error => Some(Color::Red),
+            ModalStyle::Info => Some(Palette::detect().info),
+            ModalStyle::Success => Some(Palette::detect().success),
+            ModalStyle::Warning => Some(Palette::detect().warning),
+            ModalStyle::Error => Some(Palette::detect().error),
         }
     }
 
@@ -179,6 +208,10 @@ pub struct ModalProps {
     pub center_title: bool,
     /// Dim the border.
     pub dim_border: bool,
+    /// Index of the button with keyboard focus, drawn reversed.
+    pub focused: Option<usize>,
+    /// How [`overlay`](Self::overlay) treats the content underneath.
+    pub backdrop: BackdropProps,
 }
 
 impl Default for ModalProps {
@@ -195,6 +228,8 @@ impl Default for ModalProps {
             show_icon: true,
             center_title: false,
             dim_border: false,
+            focused: None,
+            backdrop: BackdropProps::default(),
         }
     }
 }
@@ -284,6 +319,272 @@ impl ModalProps {
         self.dim_border = dim;
         self
     }
+
+    /// Set the button with keyboard focus.
+    #[must_use]
+    pub fn focused(mut self, index: Option<usize>) -> Self {
+        self.focused = index;
+        self
+    }
+
+    /// Set how [`overlay`](Self::overlay) treats the content underneath.
+    #[must_use]
+    pub fn backdrop(mut self, backdrop: BackdropProps) -> Self {
+        self.backdrop = backdrop;
+        self
+    }
+
+    /// Title with the style's icon, if shown.
+    fn full_title(&self) -> String {
+        match self.style.icon() {
+            Some(icon) if self.show_icon => format!("{} {}", icon, self.title),
+            _ => self.title.clone(),
+        }
+    }
+
+    /// Width of the button row: `[ label ]` per button, two spaces apart.
+    fn buttons_width(&self) -> usize {
+        self.buttons
+            .iter()
+            .map(|b| unicode_width::UnicodeWidthStr::width(b.label.as_str()) + 4)
+            .sum::<usize>()
+            + self.buttons.len().saturating_sub(1) * 2
+    }
+
+    /// Width inside the borders.
+    fn inner_width(&self) -> usize {
+        let title_width = unicode_width::UnicodeWidthStr::width(self.full_title().as_str());
+        let body_width = self.body.as_ref().map_or(0, |b| {
+            b.lines()
+                .map(unicode_width::UnicodeWidthStr::width)
+                .max()
+                .unwrap_or(0)
+        });
+        let content_width = title_width.max(body_width).max(self.buttons_width());
+        let inner_width = (content_width + self.padding * 2).max(self.min_width);
+        if self.max_width > 0 {
+            inner_width.min(self.max_width)
+        } else {
+            inner_width
+        }
+    }
+
+    /// Width and height of the rendered modal, borders included.
+    pub fn size(&self) -> (u16, u16) {
+        let body = self.body.as_ref().map_or(0, |body| {
+            body.lines().count() + if self.padding > 0 { 2 } else { 0 }
+        });
+        let buttons = if self.buttons.is_empty() { 0 } else { 2 };
+        let height = 4 + body + buttons;
+        let width = self.inner_width() + 2;
+        (
+            u16::try_from(width).unwrap_or(u16::MAX),
+            u16::try_from(height).unwrap_or(u16::MAX),
+        )
+    }
+
+    /// Show the modal centered over `content` in an area of `size`
+    /// (width, height), usually the terminal size, with the content
+    /// underneath dimmed by [`backdrop`](Self::backdrop).
+    ///
+    /// The result is a Box of exactly `size`, so it should be the root of
+    /// the tree, or sit at the top-left of the area it covers.
+    pub fn overlay(self, content: impl Into<Element>, size: (u16, u16)) -> Element {
+        let bounds = Rect::new(0, 0, size.0, size.1);
+        let backdrop = self.backdrop;
+        let placement = center(self.size(), bounds);
+        Element::node::<Box>(
+            BoxProps {
+                width: Some(f32::from(size.0)),
+                height: Some(f32::from(size.1)),
+                ..Default::default()
+            },
+            vec![
+                content.into(),
+                float(center(size, bounds), backdrop.into_element(size)),
+                float(placement, Element::node::<Modal>(self, vec![])),
+            ],
+        )
+    }
+}
+
+/// Properties for the [`Backdrop`] component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackdropProps {
+    /// Dim what's underneath.
+    pub dim: bool,
+    /// Repaint the text underneath in this color, e.g. grey.
+    pub color: Option<Color>,
+}
+
+impl Default for BackdropProps {
+    fn default() -> Self {
+        Self {
+            dim: true,
+            color: None,
+        }
+    }
+}
+
+impl BackdropProps {
+    /// A backdrop that dims what's underneath.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Dim what's underneath or not.
+    #[must_use]
+    pub fn dim(mut self, dim: bool) -> Self {
+        self.dim = dim;
+        self
+    }
+
+    /// Repaint the text underneath in `color`.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Apply the backdrop to the style of a cell underneath.
+    pub fn apply(&self, mut style: Style) -> Style {
+        if let Some(color) = self.color {
+            style = style.fg(color);
+        }
+        if self.dim {
+            style = style.dim();
+        }
+        style
+    }
+
+    /// A backdrop node of `size` (width, height).
+    fn into_element(self, size: (u16, u16)) -> Element {
+        Element::node_with_layout::<Backdrop>(
+            self,
+            LayoutStyle {
+                width: Some(f32::from(size.0)),
+                height: Some(f32::from(size.1)),
+                ..Default::default()
+            },
+            vec![],
+        )
+    }
+}
+
+/// Restyles whatever was drawn before it in its area, rather than drawing
+/// over it. Used by [`ModalProps::overlay`] to dim the UI behind a modal.
+pub struct Backdrop;
+
+impl Component for Backdrop {
+    type Props = BackdropProps;
+
+    fn render(_props: &Self::Props) -> Element {
+        // The renderer restyles the cells under the node's layout
+        Element::Empty
+    }
+}
+
+/// What a key did to an open modal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModalAction {
+    /// Focus moved to another button.
+    Moved,
+    /// The button at this index was pressed.
+    Pressed(usize),
+    /// Esc was pressed.
+    Dismissed,
+    /// The key did nothing, but was kept from the UI underneath.
+    Captured,
+    /// The modal is closed, so the key is for the UI underneath.
+    Ignored,
+}
+
+/// Open state and button focus of a modal.
+///
+/// While open, [`handle_key`](Self::handle_key) takes every key, so focus
+/// stays on the modal's buttons. Opening and closing go through the app's
+/// [`FocusManager`], which is blurred on open and refocused on close.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModalState {
+    /// Whether the modal is shown.
+    pub open: bool,
+    /// Index of the button with keyboard focus.
+    pub focused: usize,
+    /// What had focus before the modal opened.
+    restore: Option<FocusId>,
+}
+
+impl ModalState {
+    /// A closed modal.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the modal with the primary button (or the first) focused,
+    /// taking focus away from the rest of the UI.
+    pub fn open(&mut self, buttons: &[ModalButton], focus: &mut FocusManager) {
+        if !self.open {
+            self.restore = focus.focused();
+            focus.blur();
+        }
+        self.open = true;
+        self.focused = buttons.iter().position(|b| b.primary).unwrap_or(0);
+    }
+
+    /// Close the modal and give focus back to what had it before.
+    pub fn close(&mut self, focus: &mut FocusManager) {
+        if !self.open {
+            return;
+        }
+        self.open = false;
+        if let Some(id) = self.restore.take() {
+            focus.focus(id);
+        }
+    }
+
+    /// Whether the modal is open.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Handle Tab/Right and BackTab/Left (wrapping), Home/End, Enter or
+    /// Space to press the focused button and Esc to dismiss. Pressing and
+    /// dismissing leave the modal open; call [`close`](Self::close).
+    pub fn handle_key(&mut self, key: &Key, buttons: &[ModalButton]) -> ModalAction {
+        if !self.open {
+            return ModalAction::Ignored;
+        }
+        let len = buttons.len();
+        match key.code {
+            KeyCode::Tab | KeyCode::Right if len > 0 => {
+                self.focused = (self.focused + 1) % len;
+            }
+            KeyCode::BackTab | KeyCode::Left if len > 0 => {
+                self.focused = (self.focused + len - 1) % len;
+            }
+            KeyCode::Home if len > 0 => self.focused = 0,
+            KeyCode::End if len > 0 => self.focused = len - 1,
+            KeyCode::Enter | KeyCode::Char(' ') if self.focused < len => {
+                return ModalAction::Pressed(self.focused);
+            }
+            KeyCode::Esc => return ModalAction::Dismissed,
+            _ => return ModalAction::Captured,
+        }
+        ModalAction::Moved
+    }
+
+    /// `content` with the modal over it when open, as
+    /// [`ModalProps::overlay`] with the focused button highlighted, or
+    /// just `content` when closed.
+    pub fn render(&self, props: &ModalProps, content: Element, size: (u16, u16)) -> Element {
+        if !self.open {
+            return content;
+        }
+        props
+            .clone()
+            .focused(Some(self.focused))
+            .overlay(content, size)
+    }
 }
 
 /// A modal/dialog component.
@@ -298,40 +599,9 @@ impl Component for Modal {
         let title_color = props.style.title_color();
 
         // Build title with optional icon
-        let title = if props.show_icon {
-            if let Some(icon) = props.style.icon() {
-                format!("{} {}", icon, props.title)
-            } else {
-                props.title.clone()
-            }
-        } else {
-            props.title.clone()
-        };
-
-        // Calculate content width
+        let title = props.full_title();
         let title_width = unicode_width::UnicodeWidthStr::width(title.as_str());
-        let body_width = props.body.as_ref().map_or(0, |b| {
-            b.lines()
-                .map(unicode_width::UnicodeWidthStr::width)
-                .max()
-                .unwrap_or(0)
-        });
-        let buttons_width: usize = props
-            .buttons
-            .iter()
-            .map(|b| unicode_width::UnicodeWidthStr::width(b.label.as_str()) + 4) // [ label ]
-            .sum::<usize>()
-            + props.buttons.len().saturating_sub(1) * 2; // spacing between buttons
-
-        let content_width = title_width.max(body_width).max(buttons_width);
-        let inner_width = content_width + props.padding * 2;
-        let inner_width = inner_width.max(props.min_width);
-        let inner_width = if props.max_width > 0 {
-            inner_width.min(props.max_width)
-        } else {
-            inner_width
-        };
-        let _total_width = inner_width + 2; // +2 for borders
+        let inner_width = props.inner_width();
 
         let mut lines: Vec<Element> = Vec::new();
 
@@ -457,24 +727,21 @@ impl Component for Modal {
                     button_parts.push(Element::text("  "));
                 }
                 let btn_text = format!("[ {} ]", btn.label);
-                let btn_style = if btn.primary {
+                let mut btn_style = if btn.primary {
                     btn.color
                         .map(|c| Style::new().fg(c).add_modifier(Modifier::BOLD))
                         .unwrap_or_else(|| Style::new().add_modifier(Modifier::BOLD))
                 } else {
                     btn.color.map(|c| Style::new().fg(c)).unwrap_or_default()
                 };
+                if props.focused == Some(i) {
+                    btn_style = btn_style.add_modifier(Modifier::REVERSED);
+                }
                 button_parts.push(Element::styled_text(&btn_text, btn_style));
             }
 
             // Center buttons
-            let buttons_total_width: usize = props
-                .buttons
-                .iter()
-                .map(|b| unicode_width::UnicodeWidthStr::width(b.label.as_str()) + 4)
-                .sum::<usize>()
-                + props.buttons.len().saturating_sub(1) * 2;
-            let padding_total = inner_width.saturating_sub(buttons_total_width);
+            let padding_total = inner_width.saturating_sub(props.buttons_width());
             let left_pad = padding_total / 2;
             let right_pad = padding_total - left_pad;
 
@@ -642,4 +909,107 @@ mod tests {
         let elem = success_modal("Success", "Operation completed");
         assert!(elem.is_node());
     }
+
+    #[test]
+    fn test_modal_size_matches_render() {
+        let props = ModalProps::new("Delete")
+            .body("Really?\nThis can't be undone.")
+            .buttons(vec![ModalButton::cancel(), ModalButton::delete()]);
+        let Element::Fragment(lines) = Modal::render(&props) else {
+            panic!("Expected Fragment");
+        };
+        assert_eq!(props.size(), (32, lines.len() as u16));
+        assert_eq!(ModalProps::new("T").padding(0).size(), (32, 4));
+    }
+
+    #[test]
+    fn test_modal_state_traps_and_restores_focus() {
+        let buttons = vec![ModalButton::cancel(), ModalButton::ok()];
+        let mut focus = FocusManager::new();
+        focus.register(FocusId(1));
+        focus.register(FocusId(2));
+        focus.focus_next();
+
+        let mut modal = ModalState::new();
+        let key = |code| Key::new(code);
+        assert_eq!(
+            modal.handle_key(&key(KeyCode::Tab), &buttons),
+            ModalAction::Ignored
+        );
+
+        modal.open(&buttons, &mut focus);
+        assert!(modal.is_open());
+        assert_eq!(modal.focused, 1);
+        assert_eq!(focus.focused(), None);
+
+        assert_eq!(
+            modal.handle_key(&key(KeyCode::Tab), &buttons),
+            ModalAction::Moved
+        );
+        assert_eq!(modal.focused, 0);
+        modal.handle_key(&key(KeyCode::BackTab), &buttons);
+        assert_eq!(modal.focused, 1);
+        assert_eq!(
+            modal.handle_key(&key(KeyCode::Char('q')), &buttons),
+            ModalAction::Captured
+        );
+        assert_eq!(
+            modal.handle_key(&key(KeyCode::Enter), &buttons),
+            ModalAction::Pressed(1)
+        );
+        assert_eq!(
+            modal.handle_key(&key(KeyCode::Esc), &buttons),
+            ModalAction::Dismissed
+        );
+
+        modal.close(&mut focus);
+        assert!(!modal.is_open());
+        assert_eq!(focus.focused(), Some(FocusId(2)));
+    }
+
+    #[test]
+    fn test_modal_overlay_centers_and_dims() {
+        let mut blaeck = crate::renderer::Blaeck::with_size(Vec::new(), 20, 7).unwrap();
+        let props = ModalProps::new("Hi").min_width(6).padding(0);
+        assert_eq!(props.size(), (8, 4));
+        let content = Element::column((0..7).map(|_| Element::text("behind the modal")).collect());
+        blaeck.render(props.overlay(content, (20, 7))).unwrap();
+
+        let lines = blaeck.frame_lines();
+        assert_eq!(lines[0], "behind the modal");
+        assert_eq!(lines[1], "behind╭──────╮al");
+        assert_eq!(lines[2], "behind│Hi    │al");
+        assert_eq!(lines[4], "behind╰──────╯al");
+
+        let buffer = blaeck.frame_buffer().unwrap();
+        let dim = |x, y| buffer.get(x, y).modifiers.contains(Modifier::DIM);
+        assert!(dim(0, 0));
+        assert!(dim(0, 2));
+        assert!(!dim(8, 2));
+    }
+
+    #[test]
+    fn test_modal_focused_button_reversed() {
+        let props = ModalProps::new("T")
+            .buttons(vec![ModalButton::no(), ModalButton::yes()])
+            .focused(Some(0));
+        let Element::Fragment(lines) = Modal::render(&props) else {
+            panic!("Expected Fragment");
+        };
+        let Some(Element::Fragment(spans)) = lines.iter().rev().nth(1) else {
+            panic!("Expected button row");
+        };
+        let reversed: Vec<&str> = spans
+            .iter()
+            .filter_map(|span| match span {
+                Element::Text { content, style }
+                    if style.modifiers.contains(Modifier::REVERSED) =>
+                {
+                    Some(content.as_str())
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(reversed, vec!["[ No ]"]);
+    }
 }
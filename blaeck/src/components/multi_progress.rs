@@ -0,0 +1,508 @@
+//! MultiProgress component - a stack of progress bars for concurrent jobs.
+//!
+//! The MultiProgress shows one [`Progress`](super::Progress) bar per job,
+//! with labels lined up, a message after each bar, and an optional total
+//! bar under the stack. [`MultiProgressState`] adds and removes bars as
+//! jobs start and end; a bar can be updated by hand or bound to a
+//! [`TaskHandle`] so a worker thread drives it.
+//!
+//! ## When to use MultiProgress
+//!
+//! - Parallel downloads or uploads
+//! - Build steps or test shards running at once
+//!
+//! ## See also
+//!
+//! - [`Progress`](super::Progress) — A single bar
+//! - [`LogBox`](super::LogBox) — Output of the jobs as they run
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut jobs = MultiProgressState::new();
+//! for url in urls {
+//!     let (task, _) = TaskHandle::spawn(move |task| download(url, task));
+//!     jobs.add_task(file_name(url), task);
+//! }
+//! let extract = jobs.add("extract");
+//! jobs.set_message(extract, "waiting");
+//!
+//! // In render:
+//! Element::node::<MultiProgress>(jobs.to_props().show_total(true), vec![])
+//! // Renders:
+//! // linux.iso  ████████░░░░░░░░░░░░  40% 1.2 GB of 3 GB
+//! // extract    ░░░░░░░░░░░░░░░░░░░░   0% waiting
+//! // Total      ████░░░░░░░░░░░░░░░░  20% 0/2 done
+//! ```
+
+use crate::components::progress::{ProgressProps, ProgressStyle};
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::palette::Palette;
+use crate::style::{Color, Modifier, Style};
+use crate::task::{TaskHandle, TaskStatus};
+use unicode_width::UnicodeWidthStr;
+
+/// Identifies a bar in a [`MultiProgressState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BarId(u64);
+
+/// Where a bar's job is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BarStatus {
+    /// Still working.
+    #[default]
+    Running,
+    /// Completed.
+    Done,
+    /// Failed or cancelled.
+    Failed,
+}
+
+/// One bar of a [`MultiProgress`].
+#[derive(Debug, Clone)]
+pub struct ProgressBar {
+    /// The bar's id.
+    pub id: BarId,
+    /// Name shown before the bar.
+    pub label: String,
+    /// Progress from 0.0 to 1.0.
+    pub progress: f32,
+    /// Message shown after the bar.
+    pub message: String,
+    /// Where the job is.
+    pub status: BarStatus,
+    /// Task driving the bar, if bound.
+    pub task: Option<TaskHandle>,
+}
+
+impl ProgressBar {
+    /// The bar as it is now, with a bound task's progress, message and
+    /// status read in.
+    fn snapshot(&self) -> Self {
+        let Some(task) = &self.task else {
+            return self.clone();
+        };
+        let status = match task.status() {
+            TaskStatus::Running | TaskStatus::Cancelling => BarStatus::Running,
+            TaskStatus::Finished => BarStatus::Done,
+            TaskStatus::Cancelled => BarStatus::Failed,
+        };
+        let mut message = task.message();
+        if status == BarStatus::Failed && message.is_empty() {
+            message = "cancelled".to_string();
+        }
+        Self {
+            progress: task.progress(),
+            message,
+            status,
+            task: None,
+            ..self.clone()
+        }
+    }
+}
+
+/// The bars of a [`MultiProgress`], added and removed as jobs come and go.
+#[derive(Debug, Clone, Default)]
+pub struct MultiProgressState {
+    bars: Vec<ProgressBar>,
+    next_id: u64,
+}
+
+impl MultiProgressState {
+    /// Create a state with no bars.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a bar at 0% and return its id.
+    pub fn add(&mut self, label: impl Into<String>) -> BarId {
+        let id = BarId(self.next_id);
+        self.next_id += 1;
+        self.bars.push(ProgressBar {
+            id,
+            label: label.into(),
+            progress: 0.0,
+            message: String::new(),
+            status: BarStatus::Running,
+            task: None,
+        });
+        id
+    }
+
+    /// Add a bar that follows `task`'s progress, message and status.
+    pub fn add_task(&mut self, label: impl Into<String>, task: TaskHandle) -> BarId {
+        let id = self.add(label);
+        if let Some(bar) = self.get_mut(id) {
+            bar.task = Some(task);
+        }
+        id
+    }
+
+    /// Remove a bar. Returns false if there was none with `id`.
+    pub fn remove(&mut self, id: BarId) -> bool {
+        let before = self.bars.len();
+        self.bars.retain(|bar| bar.id != id);
+        self.bars.len() != before
+    }
+
+    /// Remove every finished bar (done or failed).
+    pub fn clear_finished(&mut self) {
+        self.bars
+            .retain(|bar| bar.snapshot().status == BarStatus::Running);
+    }
+
+    /// A bar by id.
+    pub fn get(&self, id: BarId) -> Option<&ProgressBar> {
+        self.bars.iter().find(|bar| bar.id == id)
+    }
+
+    fn get_mut(&mut self, id: BarId) -> Option<&mut ProgressBar> {
+        self.bars.iter_mut().find(|bar| bar.id == id)
+    }
+
+    /// Number of bars.
+    pub fn len(&self) -> usize {
+        self.bars.len()
+    }
+
+    /// Whether there are no bars.
+    pub fn is_empty(&self) -> bool {
+        self.bars.is_empty()
+    }
+
+    /// Set a bar's progress (0.0 to 1.0).
+    pub fn set_progress(&mut self, id: BarId, progress: f32) {
+        if let Some(bar) = self.get_mut(id) {
+            bar.progress = progress.clamp(0.0, 1.0);
+        }
+    }
+
+    /// Set a bar's message.
+    pub fn set_message(&mut self, id: BarId, message: impl Into<String>) {
+        if let Some(bar) = self.get_mut(id) {
+            bar.message = message.into();
+        }
+    }
+
+    /// Mark a bar done at 100%.
+    pub fn finish(&mut self, id: BarId) {
+        if let Some(bar) = self.get_mut(id) {
+            bar.progress = 1.0;
+            bar.status = BarStatus::Done;
+        }
+    }
+
+    /// Mark a bar failed, with the reason as its message.
+    pub fn fail(&mut self, id: BarId, message: impl Into<String>) {
+        if let Some(bar) = self.get_mut(id) {
+            bar.message = message.into();
+            bar.status = BarStatus::Failed;
+        }
+    }
+
+    /// Whether every bar is finished.
+    pub fn is_finished(&self) -> bool {
+        self.bars
+            .iter()
+            .all(|bar| bar.snapshot().status != BarStatus::Running)
+    }
+
+    /// Props for rendering the bars as they are now.
+    pub fn to_props(&self) -> MultiProgressProps {
+        MultiProgressProps::new(self.bars.iter().map(ProgressBar::snapshot))
+    }
+}
+
+/// Properties for the MultiProgress component.
+#[derive(Debug, Clone)]
+pub struct MultiProgressProps {
+    /// The bars, top to bottom.
+    pub bars: Vec<ProgressBar>,
+    /// Width of each bar in characters.
+    pub width: usize,
+    /// Bar style.
+    pub style: ProgressStyle,
+    /// Whether to show a total bar under the stack.
+    pub show_total: bool,
+    /// Label of the total bar.
+    pub total_label: String,
+    /// Whether to hide bars that are done.
+    pub hide_done: bool,
+    /// Most bars shown; the rest are counted in a "+N more" line.
+    pub max_visible: Option<usize>,
+    /// Color of running bars.
+    pub color: Option<Color>,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for MultiProgressProps {
+    fn default() -> Self {
+        Self {
+            bars: Vec::new(),
+            width: 20,
+            style: ProgressStyle::Block,
+            show_total: false,
+            total_label: "Total".to_string(),
+            hide_done: false,
+            max_visible: None,
+            color: Some(Color::Cyan),
+            glyphs: None,
+        }
+    }
+}
+
+impl MultiProgressProps {
+    /// Create props showing `bars`.
+    pub fn new(bars: impl IntoIterator<Item = ProgressBar>) -> Self {
+        Self {
+            bars: bars.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the width of each bar.
+    #[must_use]
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    /// Set the bar style.
+    #[must_use]
+    pub fn style(mut self, style: ProgressStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Show or hide the total bar.
+    #[must_use]
+    pub fn show_total(mut self, show: bool) -> Self {
+        self.show_total = show;
+        self
+    }
+
+    /// Set the label of the total bar.
+    #[must_use]
+    pub fn total_label(mut self, label: impl Into<String>) -> Self {
+        self.total_label = label.into();
+        self
+    }
+
+    /// Hide bars that are done.
+    #[must_use]
+    pub fn hide_done(mut self, hide: bool) -> Self {
+        self.hide_done = hide;
+        self
+    }
+
+    /// Show at most `count` bars.
+    #[must_use]
+    pub fn max_visible(mut self, count: usize) -> Self {
+        self.max_visible = Some(count);
+        self
+    }
+
+    /// Set the color of running bars.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// Mean progress of all bars, done and hidden ones included.
+    pub fn total(&self) -> f32 {
+        if self.bars.is_empty() {
+            return 0.0;
+        }
+        self.bars.iter().map(|bar| bar.progress).sum::<f32>() / self.bars.len() as f32
+    }
+
+    /// One row: label padded to `label_width`, bar, percentage, message.
+    fn row(
+        &self,
+        label: &str,
+        label_width: usize,
+        progress: f32,
+        message: &str,
+        bar_style: Style,
+    ) -> Vec<(String, Style)> {
+        let bar = ProgressProps::new(progress)
+            .width(self.width)
+            .style(self.style);
+        let bar = match self.glyphs {
+            Some(glyphs) => bar.glyphs(glyphs),
+            None => bar,
+        };
+        let padding = label_width.saturating_sub(label.width());
+        let mut spans = vec![
+            (format!("{label}{}  ", " ".repeat(padding)), Style::new()),
+            (bar.render_string(), bar_style),
+            (format!(" {:>3}%", bar.percentage()), Style::new()),
+        ];
+        if !message.is_empty() {
+            spans.push((
+                format!(" {message}"),
+                Style::new().add_modifier(Modifier::DIM),
+            ));
+        }
+        spans
+    }
+
+    /// The lines of the stack, each a list of spans.
+    pub fn render_lines(&self) -> Vec<Vec<(String, Style)>> {
+        let palette = Palette::detect();
+        let shown: Vec<&ProgressBar> = self
+            .bars
+            .iter()
+            .filter(|bar| !(self.hide_done && bar.status == BarStatus::Done))
+            .collect();
+        let visible = self.max_visible.unwrap_or(usize::MAX).min(shown.len());
+        let mut label_width = shown[..visible]
+            .iter()
+            .map(|bar| bar.label.width())
+            .max()
+            .unwrap_or(0);
+        if self.show_total {
+            label_width = label_width.max(self.total_label.width());
+        }
+
+        let mut lines: Vec<_> = shown[..visible]
+            .iter()
+            .map(|bar| {
+                let style = match bar.status {
+                    BarStatus::Running => {
+                        self.color.map(|c| Style::new().fg(c)).unwrap_or_default()
+                    }
+                    BarStatus::Done => Style::new().fg(palette.success),
+                    BarStatus::Failed => Style::new().fg(palette.error),
+                };
+                self.row(&bar.label, label_width, bar.progress, &bar.message, style)
+            })
+            .collect();
+        if visible < shown.len() {
+            lines.push(vec![(
+                format!("+{} more", shown.len() - visible),
+                Style::new().add_modifier(Modifier::DIM),
+            )]);
+        }
+        if self.show_total {
+            let done = self
+                .bars
+                .iter()
+                .filter(|bar| bar.status != BarStatus::Running)
+                .count();
+            lines.push(self.row(
+                &self.total_label,
+                label_width,
+                self.total(),
+                &format!("{done}/{} done", self.bars.len()),
+                Style::new().add_modifier(Modifier::BOLD),
+            ));
+        }
+        lines
+    }
+}
+
+/// A component that displays a stack of progress bars.
+pub struct MultiProgress;
+
+impl Component for MultiProgress {
+    type Props = MultiProgressProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let lines: Vec<Element> = props
+            .render_lines()
+            .into_iter()
+            .map(|spans| {
+                Element::Fragment(
+                    spans
+                        .into_iter()
+                        .map(|(text, style)| Element::styled_text(text, style))
+                        .collect(),
+                )
+            })
+            .collect();
+        if lines.is_empty() {
+            Element::Empty
+        } else {
+            Element::Fragment(lines)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::test_util::line_texts;
+
+    #[test]
+    fn test_multi_progress_add_remove() {
+        let mut state = MultiProgressState::new();
+        let a = state.add("a");
+        let b = state.add("b");
+        assert_ne!(a, b);
+        state.set_progress(a, 0.5);
+        state.finish(b);
+        assert_eq!(state.get(a).unwrap().progress, 0.5);
+        assert!(!state.is_finished());
+
+        state.clear_finished();
+        assert_eq!(state.len(), 1);
+        assert!(state.remove(a));
+        assert!(!state.remove(a));
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn test_multi_progress_lines() {
+        let mut state = MultiProgressState::new();
+        let iso = state.add("linux.iso");
+        let x = state.add("x");
+        state.set_progress(iso, 0.5);
+        state.set_message(iso, "1.5 GB of 3 GB");
+        state.fail(x, "404");
+
+        let props = state
+            .to_props()
+            .width(4)
+            .glyphs(GlyphSet::Ascii)
+            .show_total(true);
+        assert_eq!(
+            line_texts(props.render_lines()),
+            [
+                "linux.iso  =>--  50% 1.5 GB of 3 GB",
+                "x          ----   0% 404",
+                "Total      >---  25% 1/2 done",
+            ]
+        );
+
+        let props = props.show_total(false).max_visible(1);
+        assert_eq!(line_texts(props.render_lines())[1], "+1 more");
+    }
+
+    #[test]
+    fn test_multi_progress_task_binding() {
+        let mut state = MultiProgressState::new();
+        let task = TaskHandle::new();
+        let id = state.add_task("job", task.clone());
+        task.report(0.25, "step 1");
+        let bar = &state.to_props().bars[0];
+        assert_eq!((bar.progress, bar.message.as_str()), (0.25, "step 1"));
+        assert_eq!(bar.id, id);
+
+        task.cancel();
+        task.finish();
+        let bar = &state.to_props().bars[0];
+        assert_eq!(bar.status, BarStatus::Failed);
+        assert!(state.is_finished());
+    }
+}
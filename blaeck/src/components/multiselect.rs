@@ -4,6 +4,10 @@
 //! that users can toggle to select multiple items. Use `MultiSelectState`
 //! to track which items are selected.
 //!
+//! Like [`Select`](super::Select), it can lay items out in a grid with
+//! [`MultiSelectProps::columns`], navigated with
+//! [`MultiSelectState::left`] and [`MultiSelectState::right`].
+//!
 //! ## When to use MultiSelect
 //!
 //! - Multiple choices from a list (select all that apply)
@@ -117,6 +121,11 @@ pub struct MultiSelectProps {
     pub scroll_offset: usize,
     /// Cursor indicator character.
     pub cursor_indicator: &'static str,
+    /// Number of grid columns (1 = a plain list). In a grid, `max_visible`
+    /// and `scroll_offset` count rows rather than items.
+    pub columns: usize,
+    /// Spaces between grid columns.
+    pub column_gap: usize,
 }
 
 impl Default for MultiSelectProps {
@@ -133,6 +142,8 @@ impl Default for MultiSelectProps {
             max_visible: None,
             scroll_offset: 0,
             cursor_indicator: "❯",
+            columns: 1,
+            column_gap: 2,
         }
     }
 }
@@ -206,6 +217,20 @@ impl MultiSelectProps {
         self
     }
 
+    /// Lay items out in a grid with the given number of columns.
+    #[must_use]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Set the number of spaces between grid columns.
+    #[must_use]
+    pub fn column_gap(mut self, gap: usize) -> Self {
+        self.column_gap = gap;
+        self
+    }
+
     /// Check if an index is selected.
     pub fn is_selected(&self, index: usize) -> bool {
         self.selected.contains(&index)
@@ -230,19 +255,54 @@ impl MultiSelectProps {
     /// Get the visible items based on scroll offset and max_visible.
     fn visible_items(&self) -> Vec<(usize, &MultiSelectItem)> {
         let items: Vec<_> = self.items.iter().enumerate().collect();
+        let columns = self.columns.max(1);
+        let rows = items.len().div_ceil(columns);
 
         if let Some(max) = self.max_visible {
-            if items.len() > max {
-                let start = self.scroll_offset.min(items.len().saturating_sub(max));
-                return items.into_iter().skip(start).take(max).collect();
+            if rows > max {
+                let start = self.scroll_offset.min(rows - max);
+                return items
+                    .into_iter()
+                    .skip(start * columns)
+                    .take(max * columns)
+                    .collect();
             }
         }
 
         items
     }
 
-    /// Build the display strings for all visible items.
+    /// Build the display strings for all visible items, one per row. In a
+    /// grid, each row takes the style of the item under the cursor, or else
+    /// of its first item.
     pub fn render_lines(&self) -> Vec<(String, Style)> {
+        let cells = self.render_cells();
+        let columns = self.columns.max(1);
+        if columns == 1 {
+            return cells;
+        }
+
+        let gap = " ".repeat(self.column_gap);
+        cells
+            .chunks(columns)
+            .map(|row| {
+                let line = row
+                    .iter()
+                    .map(|(cell, _)| cell.as_str())
+                    .collect::<Vec<_>>()
+                    .join(&gap);
+                let style = row
+                    .iter()
+                    .find(|(_, s)| s.modifiers.contains(Modifier::BOLD))
+                    .unwrap_or(&row[0])
+                    .1;
+                (line, style)
+            })
+            .collect()
+    }
+
+    /// Build the display string for each visible item.
+    fn render_cells(&self) -> Vec<(String, Style)> {
         let (checked_char, unchecked_char) = self.style.chars();
         let visible_items = self.visible_items();
 
@@ -355,6 +415,7 @@ impl Component for MultiSelect {
 
 /// Helper struct for managing multiselect state.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
 pub struct MultiSelectState {
     /// Current cursor position.
     pub cursor: usize,
@@ -364,8 +425,10 @@ pub struct MultiSelectState {
     pub count: usize,
     /// Scroll offset for long lists.
     pub scroll_offset: usize,
-    /// Maximum visible items.
+    /// Maximum visible items (rows, in a grid).
     pub max_visible: Option<usize>,
+    /// Number of grid columns (1 = a plain list).
+    pub columns: usize,
 }
 
 impl MultiSelectState {
@@ -377,6 +440,7 @@ impl MultiSelectState {
             count,
             scroll_offset: 0,
             max_visible: None,
+            columns: 1,
         }
     }
 
@@ -387,6 +451,13 @@ impl MultiSelectState {
         self
     }
 
+    /// Navigate a grid with the given number of columns.
+    #[must_use]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
     /// Set initially selected indices.
     #[must_use]
     pub fn with_selected(mut self, selected: impl IntoIterator<Item = usize>) -> Self {
@@ -394,22 +465,41 @@ impl MultiSelectState {
         self
     }
 
-    /// Move cursor up.
+    /// Move cursor up (one row, in a grid).
     pub fn up(&mut self) {
-        if self.cursor > 0 {
-            self.cursor -= 1;
+        let columns = self.columns.max(1);
+        if self.cursor >= columns {
+            self.cursor -= columns;
             self.adjust_scroll();
         }
     }
 
-    /// Move cursor down.
+    /// Move cursor down (one row, in a grid). From a column the short last
+    /// row lacks, this lands on the last item.
     pub fn down(&mut self) {
-        if self.cursor < self.count.saturating_sub(1) {
-            self.cursor += 1;
+        let columns = self.columns.max(1);
+        let row = self.cursor / columns;
+        if row < self.count.saturating_sub(1) / columns {
+            self.cursor = (self.cursor + columns).min(self.count - 1);
             self.adjust_scroll();
         }
     }
 
+    /// Move cursor left within a grid row.
+    pub fn left(&mut self) {
+        if !self.cursor.is_multiple_of(self.columns.max(1)) {
+            self.cursor -= 1;
+        }
+    }
+
+    /// Move cursor right within a grid row.
+    pub fn right(&mut self) {
+        let columns = self.columns.max(1);
+        if self.cursor % columns < columns - 1 && self.cursor + 1 < self.count {
+            self.cursor += 1;
+        }
+    }
+
     /// Move to first item.
     pub fn first(&mut self) {
         self.cursor = 0;
@@ -484,13 +574,14 @@ impl MultiSelectState {
         self.selected.len() == self.count
     }
 
-    /// Adjust scroll offset to keep cursor visible.
+    /// Adjust scroll offset (in rows) to keep cursor visible.
     fn adjust_scroll(&mut self) {
         if let Some(max) = self.max_visible {
-            if self.cursor < self.scroll_offset {
-                self.scroll_offset = self.cursor;
-            } else if self.cursor >= self.scroll_offset + max {
-                self.scroll_offset = self.cursor - max + 1;
+            let row = self.cursor / self.columns.max(1);
+            if row < self.scroll_offset {
+                self.scroll_offset = row;
+            } else if row >= self.scroll_offset + max {
+                self.scroll_offset = row - max + 1;
             }
         }
     }
@@ -665,4 +756,33 @@ mod tests {
         let elem = MultiSelect::render(&props);
         assert!(elem.is_text());
     }
+
+    #[test]
+    fn test_multiselect_grid_render_lines() {
+        let props = MultiSelectProps::new(vec!["a", "bb", "c"])
+            .columns(2)
+            .cursor(1)
+            .selected(HashSet::from([2]));
+        let lines = props.render_lines();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].0, "  [ ] a   ❯ [ ] bb");
+        assert_eq!(lines[1].0, "  [x] c ");
+        assert!(lines[0].1.modifiers.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn test_multiselect_state_grid_navigation() {
+        let mut state = MultiSelectState::new(5).columns(2).max_visible(1);
+        state.right();
+        state.down();
+        assert_eq!(state.cursor, 3);
+        assert_eq!(state.scroll_offset, 1);
+        state.down();
+        assert_eq!(state.cursor, 4);
+        state.toggle();
+        assert!(state.is_selected(4));
+        state.up();
+        state.left();
+        assert_eq!(state.cursor, 2);
+    }
 }
@@ -0,0 +1,134 @@
+//! PendingKeys component - the keys typed so far in a multi-key binding.
+//!
+//! Shows the start of a sequence such as `g` or `space f` while the app
+//! waits for the rest, like Vim's `showcmd`. It renders nothing when no
+//! keys are pending, so it can stay in the layout.
+//!
+//! ## When to use PendingKeys
+//!
+//! - Status bar corner in apps with `g g` / leader-key bindings
+//!
+//! ## See also
+//!
+//! - [`KeyHints`](super::KeyHints) — The bindings that are available
+//! - [`ChordState`](crate::ChordState) — Tracks the pending keys
+//!
+//! # Example
+//!
+//! ```ignore
+//! Element::node::<PendingKeys>(chords.indicator().label("keys: "), vec![])
+//! ```
+
+use crate::element::{Component, Element};
+use crate::style::{Color, Modifier, Style};
+
+/// Properties for the PendingKeys component.
+#[derive(Debug, Clone)]
+pub struct PendingKeysProps {
+    /// The keys pressed so far, as display names.
+    pub keys: Vec<String>,
+    /// Text before the keys.
+    pub label: Option<String>,
+    /// Color of the keys.
+    pub color: Color,
+    /// Text between keys.
+    pub separator: String,
+}
+
+impl Default for PendingKeysProps {
+    fn default() -> Self {
+        Self {
+            keys: Vec::new(),
+            label: None,
+            color: Color::Yellow,
+            separator: " ".into(),
+        }
+    }
+}
+
+impl PendingKeysProps {
+    /// Create an indicator for the given keys.
+    pub fn new<I, S>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            keys: keys.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the text shown before the keys.
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the color of the keys.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the text between keys.
+    #[must_use]
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Build the display string (empty when nothing is pending).
+    pub fn render_string(&self) -> String {
+        if self.keys.is_empty() {
+            return String::new();
+        }
+        format!(
+            "{}{}",
+            self.label.as_deref().unwrap_or_default(),
+            self.keys.join(&self.separator)
+        )
+    }
+}
+
+/// A component that shows the keys typed so far in a sequence.
+pub struct PendingKeys;
+
+impl Component for PendingKeys {
+    type Props = PendingKeysProps;
+
+    fn render(props: &Self::Props) -> Element {
+        if props.keys.is_empty() {
+            return Element::Empty;
+        }
+        let keys = Element::styled_text(
+            props.keys.join(&props.separator),
+            Style::new().fg(props.color).add_modifier(Modifier::BOLD),
+        );
+        match &props.label {
+            Some(label) => Element::Fragment(vec![
+                Element::styled_text(label, Style::new().add_modifier(Modifier::DIM)),
+                keys,
+            ]),
+            None => keys,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_keys_render_string() {
+        assert_eq!(PendingKeysProps::default().render_string(), "");
+        let props = PendingKeysProps::new(["space", "f"]).label("keys: ");
+        assert_eq!(props.render_string(), "keys: space f");
+        assert!(matches!(
+            PendingKeys::render(&PendingKeysProps::default()),
+            Element::Empty
+        ));
+    }
+}
@@ -0,0 +1,313 @@
+//! Placeholder components - empty, error and loading states.
+//!
+//! [`EmptyState`], [`ErrorState`] and [`LoadingState`] stand in for a list,
+//! table or panel that has nothing to show yet. They share one layout (an
+//! icon and bold title, a description below, then a hint for what to do)
+//! and take their colors from the app's [`Palette`], so every placeholder
+//! in an app looks the same with minimal code.
+//!
+//! ## When to use them
+//!
+//! - A search with no results, an empty inbox
+//! - A request that failed, with a retry key
+//! - Data that hasn't arrived yet
+//!
+//! ## See also
+//!
+//! - [`Spinner`](super::Spinner) — A bare spinner, for inline loading
+//! - [`centered`](super::centered) — Center a placeholder in its panel
+//!
+//! # Example
+//!
+//! ```ignore
+//! if results.is_empty() {
+//!     Element::node::<EmptyState>(
+//!         PlaceholderProps::new("No matches")
+//!             .description(format!("Nothing matches \"{query}\"."))
+//!             .action("press / to search again"),
+//!         vec![],
+//!     )
+//! } else { /* ... */ }
+//!
+//! // Loading, animated by the caller:
+//! Element::node::<LoadingState>(
+//!     PlaceholderProps::new("Fetching issues").frame(spinner_frame(start, SpinnerStyle::Dots)),
+//!     vec![],
+//! )
+//! ```
+//!
+//! [`Palette`]: crate::palette::Palette
+
+use crate::components::spinner::SpinnerStyle;
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::palette::Palette;
+use crate::style::{Color, Modifier, Style};
+
+/// Which placeholder is shown, for its default icon and color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaceholderKind {
+    /// Nothing to show.
+    Empty,
+    /// Something went wrong.
+    Error,
+    /// Still waiting for data.
+    Loading,
+}
+
+impl PlaceholderKind {
+    /// The palette color for this kind.
+    pub fn color(self, palette: &Palette) -> Color {
+        match self {
+            PlaceholderKind::Empty => palette.muted,
+            PlaceholderKind::Error => palette.error,
+            PlaceholderKind::Loading => palette.info,
+        }
+    }
+}
+
+/// Properties shared by [`EmptyState`], [`ErrorState`] and [`LoadingState`].
+#[derive(Debug, Clone, Default)]
+pub struct PlaceholderProps {
+    /// Title, shown bold after the icon.
+    pub title: String,
+    /// Optional description below the title; may span several lines.
+    pub description: Option<String>,
+    /// Optional hint for what to do, e.g. "press r to retry".
+    pub action: Option<String>,
+    /// Icon override (`None` = the kind's icon, or the spinner).
+    pub icon: Option<String>,
+    /// Color override for the icon (`None` = from the palette).
+    pub color: Option<Color>,
+    /// Spinner style while loading.
+    pub spinner: SpinnerStyle,
+    /// Spinner frame while loading (caller manages animation).
+    pub frame: usize,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl PlaceholderProps {
+    /// Create props with a title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the description.
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the action hint.
+    #[must_use]
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    /// Replace the icon.
+    #[must_use]
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the icon color.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the spinner style while loading.
+    #[must_use]
+    pub fn spinner(mut self, style: SpinnerStyle) -> Self {
+        self.spinner = style;
+        self
+    }
+
+    /// Set the spinner frame while loading.
+    #[must_use]
+    pub fn frame(mut self, frame: usize) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// The icon shown for `kind`.
+    pub fn icon_for(&self, kind: PlaceholderKind) -> String {
+        if let Some(icon) = &self.icon {
+            return icon.clone();
+        }
+        let glyphs = GlyphSet::resolve(self.glyphs);
+        let ascii = glyphs.is_ascii();
+        match kind {
+            PlaceholderKind::Empty if ascii => "o".into(),
+            PlaceholderKind::Empty => "○".into(),
+            PlaceholderKind::Error if ascii => "x".into(),
+            PlaceholderKind::Error => "✗".into(),
+            PlaceholderKind::Loading => self
+                .spinner
+                .for_glyphs(glyphs)
+                .frame_at(self.frame)
+                .to_string(),
+        }
+    }
+
+    /// The lines of the placeholder for `kind`, each a list of spans.
+    pub fn render_lines(&self, kind: PlaceholderKind) -> Vec<Vec<(String, Style)>> {
+        let palette = Palette::detect();
+        let icon = self.icon_for(kind);
+        let icon_style = Style::new().fg(self.color.unwrap_or_else(|| kind.color(&palette)));
+        let indent = " ".repeat(unicode_width::UnicodeWidthStr::width(icon.as_str()) + 1);
+
+        let mut lines = vec![vec![
+            (format!("{icon} "), icon_style),
+            (
+                self.title.clone(),
+                Style::new().add_modifier(Modifier::BOLD),
+            ),
+        ]];
+        if let Some(description) = &self.description {
+            for line in description.lines() {
+                lines.push(vec![(format!("{indent}{line}"), Style::new())]);
+            }
+        }
+        if let Some(action) = &self.action {
+            lines.push(vec![(
+                format!("{indent}{action}"),
+                Style::new().fg(palette.muted),
+            )]);
+        }
+        lines
+    }
+}
+
+fn render(props: &PlaceholderProps, kind: PlaceholderKind) -> Element {
+    let lines = props
+        .render_lines(kind)
+        .into_iter()
+        .map(|spans| {
+            Element::Fragment(
+                spans
+                    .into_iter()
+                    .map(|(text, style)| Element::styled_text(text, style))
+                    .collect(),
+            )
+        })
+        .collect();
+    Element::Fragment(lines)
+}
+
+/// A placeholder for a list or panel with nothing to show.
+///
+/// # Examples
+///
+/// ```ignore
+/// let props = PlaceholderProps::new("No tasks").action("press n to add one");
+/// // Renders:
+/// // ○ No tasks
+/// //   press n to add one
+/// ```
+pub struct EmptyState;
+
+impl Component for EmptyState {
+    type Props = PlaceholderProps;
+
+    fn render(props: &Self::Props) -> Element {
+        render(props, PlaceholderKind::Empty)
+    }
+}
+
+/// A placeholder for content that failed to load.
+///
+/// # Examples
+///
+/// ```ignore
+/// let props = PlaceholderProps::new("Couldn't load issues")
+///     .description(err.to_string())
+///     .action("press r to retry");
+/// ```
+pub struct ErrorState;
+
+impl Component for ErrorState {
+    type Props = PlaceholderProps;
+
+    fn render(props: &Self::Props) -> Element {
+        render(props, PlaceholderKind::Error)
+    }
+}
+
+/// A placeholder for content that is still loading, with a spinner.
+pub struct LoadingState;
+
+impl Component for LoadingState {
+    type Props = PlaceholderProps;
+
+    fn render(props: &Self::Props) -> Element {
+        render(props, PlaceholderKind::Loading)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::test_util::line_texts;
+
+    #[test]
+    fn test_placeholder_layout() {
+        let props = PlaceholderProps::new("Couldn't load")
+            .description("timed out\nafter 30s")
+            .action("press r to retry")
+            .glyphs(GlyphSet::Unicode);
+        assert_eq!(
+            line_texts(props.render_lines(PlaceholderKind::Error)),
+            vec![
+                "✗ Couldn't load",
+                "  timed out",
+                "  after 30s",
+                "  press r to retry"
+            ]
+        );
+        let lines = props.render_lines(PlaceholderKind::Error);
+        assert_eq!(lines[0][0].1, Style::new().fg(Palette::detect().error));
+    }
+
+    #[test]
+    fn test_placeholder_icons() {
+        let props = PlaceholderProps::new("x").glyphs(GlyphSet::Ascii);
+        assert_eq!(props.icon_for(PlaceholderKind::Empty), "o");
+        assert_eq!(props.icon_for(PlaceholderKind::Loading), "|");
+        assert_eq!(
+            props.clone().frame(1).icon_for(PlaceholderKind::Loading),
+            "/"
+        );
+        assert_eq!(props.icon("?").icon_for(PlaceholderKind::Error), "?");
+    }
+
+    #[test]
+    fn test_placeholder_components_render_lines() {
+        let props = PlaceholderProps::new("Nothing here").action("press n");
+        for element in [
+            EmptyState::render(&props),
+            ErrorState::render(&props),
+            LoadingState::render(&props),
+        ] {
+            let Element::Fragment(lines) = element else {
+                panic!("expected fragment");
+            };
+            assert_eq!(lines.len(), 2);
+        }
+    }
+}
@@ -9,13 +9,28 @@
 //! - File downloads, uploads, processing tasks
 //! - Multi-step wizards showing completion
 //!
+//! ## Rate and ETA
+//!
+//! Feed a [`ProgressRate`] with `(elapsed, done)` samples as work advances;
+//! it estimates throughput over its last few samples, and
+//! [`ProgressProps::with_rate`] shows it after the bar:
+//!
+//! ```text
+//! ████████░░░░░░░░░░░░  42% • 12.4 MB/s • 00:13 left
+//! ```
+//!
 //! ## See also
 //!
 //! - [`Spinner`](super::Spinner) — Use for indeterminate loading (unknown duration)
 //! - [`Timer`](super::Timer) — Show elapsed/remaining time alongside progress
 
+use super::timer::TimeFormat;
 use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
 use crate::style::{Color, Modifier, Style};
+use crate::task::{TaskHandle, TaskStatus};
+use std::collections::VecDeque;
+use std::time::Duration;
 
 /// Built-in progress bar styles.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -36,6 +51,16 @@ pub enum ProgressStyle {
 }
 
 impl ProgressStyle {
+    /// The style to draw with under `glyphs`: non-ASCII styles fall back
+    /// to [`ProgressStyle::Ascii`] when only ASCII is available.
+    pub fn for_glyphs(self, glyphs: GlyphSet) -> Self {
+        if glyphs.is_ascii() {
+            ProgressStyle::Ascii
+        } else {
+            self
+        }
+    }
+
     /// Get the characters for filled and empty portions.
     pub fn chars(&self) -> ProgressChars {
         match self {
@@ -90,6 +115,97 @@ impl Default for ProgressChars {
     }
 }
 
+/// Throughput and ETA estimated from recent progress samples.
+///
+/// Only the last [`window`](ProgressRate::window) samples count, so the
+/// estimate follows changes in speed instead of averaging the whole run.
+#[derive(Debug, Clone)]
+pub struct ProgressRate {
+    /// Total amount of work (bytes, items, ...).
+    pub total: u64,
+    /// Number of samples the estimate is taken over.
+    pub window: usize,
+    samples: VecDeque<(Duration, u64)>,
+}
+
+impl ProgressRate {
+    /// Create an estimator for `total` units of work.
+    pub fn new(total: u64) -> Self {
+        Self {
+            total,
+            window: 10,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Set the number of samples to estimate over (at least 2).
+    #[must_use]
+    pub fn window(mut self, window: usize) -> Self {
+        self.window = window.max(2);
+        self
+    }
+
+    /// Record that `done` units were complete after `elapsed`.
+    ///
+    /// Samples that go back in time restart the window.
+    pub fn record(&mut self, elapsed: Duration, done: u64) {
+        if self.samples.back().is_some_and(|&(t, _)| elapsed < t) {
+            self.samples.clear();
+        }
+        self.samples.push_back((elapsed, done.min(self.total)));
+        while self.samples.len() > self.window {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Units complete as of the latest sample.
+    pub fn done(&self) -> u64 {
+        self.samples.back().map_or(0, |&(_, done)| done)
+    }
+
+    /// Fraction complete, 0.0 to 1.0.
+    pub fn progress(&self) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.done() as f32 / self.total as f32
+    }
+
+    /// Units per second over the sample window, once there are two samples.
+    pub fn rate(&self) -> Option<f64> {
+        let (&(t0, d0), &(t1, d1)) = (self.samples.front()?, self.samples.back()?);
+        let secs = (t1 - t0).as_secs_f64();
+        if secs <= 0.0 {
+            return None;
+        }
+        Some(d1.saturating_sub(d0) as f64 / secs)
+    }
+
+    /// Time left at the current rate (`None` while stalled, unknown or too
+    /// long to represent).
+    pub fn eta(&self) -> Option<Duration> {
+        let rate = self.rate().filter(|&r| r > 0.0)?;
+        let left = self.total.saturating_sub(self.done()) as f64;
+        // A near-stalled rate can put the ETA past what a Duration holds
+        Duration::try_from_secs_f64(left / rate).ok()
+    }
+}
+
+/// Format a byte count for humans, in decimal units: `"512 B"`, `"12.4 MB"`.
+pub fn format_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 6] = ["KB", "MB", "GB", "TB", "PB", "EB"];
+    if bytes < 1000.0 {
+        return format!("{} B", bytes.max(0.0).round());
+    }
+    let mut value = bytes / 1000.0;
+    let mut unit = 0;
+    while value >= 999.95 && unit + 1 < UNITS.len() {
+        value /= 1000.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
 /// Properties for the Progress component.
 #[derive(Debug, Clone)]
 pub struct ProgressProps {
@@ -119,6 +235,14 @@ pub struct ProgressProps {
     pub bold: bool,
     /// Whether the empty portion should be dimmed.
     pub dim_empty: bool,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+    /// Throughput in units per second, shown after the bar.
+    pub rate: Option<f64>,
+    /// Whether the rate counts bytes (`12.4 MB/s`) rather than items (`3.5/s`).
+    pub byte_rate: bool,
+    /// Estimated time left, shown after the bar.
+    pub eta: Option<Duration>,
 }
 
 impl Default for ProgressProps {
@@ -137,6 +261,10 @@ impl Default for ProgressProps {
             brackets: false,
             bold: false,
             dim_empty: true,
+            glyphs: None,
+            rate: None,
+            byte_rate: false,
+            eta: None,
         }
     }
 }
@@ -157,6 +285,24 @@ impl ProgressProps {
         self
     }
 
+    /// Bind to a background task: its progress, and its message as the label.
+    ///
+    /// A cancelled task's label says so, so the user sees the cancel key
+    /// took effect while the task winds down.
+    pub fn from_task(task: &TaskHandle) -> Self {
+        let mut label = task.message();
+        match task.status() {
+            TaskStatus::Cancelling => label.push_str(" (cancelling…)"),
+            TaskStatus::Cancelled => label.push_str(" (cancelled)"),
+            TaskStatus::Running | TaskStatus::Finished => {}
+        }
+        let props = Self::new(task.progress());
+        match label.trim() {
+            "" => props,
+            label => props.label(label),
+        }
+    }
+
     /// Set the progress from a percentage (0 to 100).
     #[must_use]
     pub fn percent(mut self, percent: u32) -> Self {
@@ -257,9 +403,69 @@ impl ProgressProps {
         self
     }
 
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// Show a throughput in units per second.
+    #[must_use]
+    pub fn rate(mut self, per_sec: f64) -> Self {
+        self.rate = Some(per_sec);
+        self
+    }
+
+    /// Format the rate as bytes per second.
+    #[must_use]
+    pub fn byte_rate(mut self) -> Self {
+        self.byte_rate = true;
+        self
+    }
+
+    /// Show an estimated time left.
+    #[must_use]
+    pub fn eta(mut self, eta: Duration) -> Self {
+        self.eta = Some(eta);
+        self
+    }
+
+    /// Take progress, rate and ETA from an estimator.
+    #[must_use]
+    pub fn with_rate(mut self, rate: &ProgressRate) -> Self {
+        self.progress = rate.progress().clamp(0.0, 1.0);
+        self.rate = rate.rate();
+        self.eta = rate.eta();
+        self
+    }
+
+    /// The text after the bar: percentage, rate and ETA, joined by bullets.
+    fn stats(&self) -> Vec<String> {
+        let mut stats = Vec::new();
+        if self.show_percentage {
+            stats.push(format!("{:>3}%", self.percentage()));
+        }
+        if let Some(rate) = self.rate {
+            stats.push(if self.byte_rate {
+                format!("{}/s", format_bytes(rate))
+            } else {
+                format!("{:.1}/s", rate)
+            });
+        }
+        if let Some(eta) = self.eta {
+            stats.push(format!("{} left", TimeFormat::MinSecPadded.format(eta)));
+        }
+        stats
+    }
+
     /// Get the characters to use for rendering.
     fn chars(&self) -> ProgressChars {
-        self.custom_chars.unwrap_or_else(|| self.style.chars())
+        self.custom_chars.unwrap_or_else(|| {
+            self.style
+                .for_glyphs(GlyphSet::resolve(self.glyphs))
+                .chars()
+        })
     }
 
     /// Get the percentage as an integer (0-100).
@@ -310,9 +516,16 @@ impl ProgressProps {
             result.push(']');
         }
 
-        // Percentage
-        if self.show_percentage {
-            result.push_str(&format!(" {:>3}%", self.percentage()));
+        // Percentage, rate and ETA
+        let stats = self.stats();
+        if !stats.is_empty() {
+            let bullet = if GlyphSet::resolve(self.glyphs).is_ascii() {
+                " - "
+            } else {
+                " • "
+            };
+            result.push(' ');
+            result.push_str(&stats.join(bullet));
         }
 
         result
@@ -390,6 +603,19 @@ pub fn progress_bar_bracketed(percent: u32, width: usize) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_progress_from_task() {
+        let task = TaskHandle::new();
+        task.report(0.4, "copying");
+        let props = ProgressProps::from_task(&task);
+        assert_eq!(props.progress, 0.4);
+        assert_eq!(props.label.as_deref(), Some("copying"));
+
+        task.cancel();
+        let props = ProgressProps::from_task(&task);
+        assert_eq!(props.label.as_deref(), Some("copying (cancelling…)"));
+    }
+
     #[test]
     fn test_progress_props_default() {
         let props = ProgressProps::default();
@@ -398,6 +624,14 @@ mod tests {
         assert_eq!(props.style, ProgressStyle::Block);
     }
 
+    #[test]
+    fn test_progress_glyphs_override() {
+        let bar = ProgressProps::new(0.5).width(4).glyphs(GlyphSet::Ascii);
+        assert!(bar.render_string().contains("=>--"));
+        let bar = ProgressProps::new(0.5).width(4).glyphs(GlyphSet::Unicode);
+        assert!(bar.render_string().contains("██░░"));
+    }
+
     #[test]
     fn test_progress_props_new() {
         let props = ProgressProps::new(0.5);
@@ -558,4 +792,61 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512.0), "512 B");
+        assert_eq!(format_bytes(1500.0), "1.5 KB");
+        assert_eq!(format_bytes(12_400_000.0), "12.4 MB");
+        assert_eq!(format_bytes(999_999.0), "1.0 MB");
+        assert_eq!(format_bytes(3.2e12), "3.2 TB");
+    }
+
+    #[test]
+    fn test_progress_rate_window() {
+        let mut rate = ProgressRate::new(1000).window(3);
+        assert_eq!(rate.rate(), None);
+        rate.record(Duration::from_secs(0), 0);
+        rate.record(Duration::from_secs(1), 100);
+        assert_eq!(rate.rate(), Some(100.0));
+        assert_eq!(rate.eta(), Some(Duration::from_secs(9)));
+
+        // Only the last three samples count
+        rate.record(Duration::from_secs(2), 300);
+        rate.record(Duration::from_secs(3), 500);
+        assert_eq!(rate.rate(), Some(200.0));
+        assert_eq!(rate.progress(), 0.5);
+
+        // Stalled progress has no ETA
+        let mut stalled = ProgressRate::new(10);
+        stalled.record(Duration::from_secs(0), 5);
+        stalled.record(Duration::from_secs(2), 5);
+        assert_eq!(stalled.eta(), None);
+
+        // A near-zero rate has no ETA rather than an overflowing one
+        let mut crawling = ProgressRate::new(1 << 40);
+        crawling.record(Duration::from_secs(0), 0);
+        crawling.record(Duration::from_secs(1 << 40), 1);
+        assert_eq!(crawling.eta(), None);
+    }
+
+    #[test]
+    fn test_progress_rate_and_eta_render() {
+        let bar = ProgressProps::new(0.42)
+            .width(4)
+            .glyphs(GlyphSet::Unicode)
+            .show_percentage()
+            .rate(12_400_000.0)
+            .byte_rate()
+            .eta(Duration::from_secs(13))
+            .render_string();
+        assert!(bar.ends_with(" 42% • 12.4 MB/s • 00:13 left"), "{}", bar);
+
+        let bar = ProgressProps::new(0.5)
+            .width(4)
+            .glyphs(GlyphSet::Ascii)
+            .rate(3.5)
+            .render_string();
+        assert!(bar.ends_with("-- 3.5/s"), "{}", bar);
+    }
 }
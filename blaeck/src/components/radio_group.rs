@@ -0,0 +1,411 @@
+//! RadioGroup component - single choice from a short list of options.
+//!
+//! The RadioGroup shows every option with a radio indicator, either stacked
+//! vertically or on one line. Use `RadioGroupState` to track the selection.
+//!
+//! ## When to use RadioGroup
+//!
+//! - A handful of mutually exclusive options that fit on screen at once
+//! - Inline choices in a form ("Size: ● S ○ M ○ L")
+//!
+//! ## See also
+//!
+//! - [`Select`](super::Select) — Scrolling list for many options
+//! - [`Checkbox`](super::Checkbox) — Single on/off toggle
+//! - [`Tabs`](super::Tabs) — Horizontal selection that switches views
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut size = RadioGroupState::new(3);
+//!
+//! // In the input handler:
+//! size.handle_key(&key);
+//!
+//! // In render:
+//! Element::node::<RadioGroup>(
+//!     RadioGroupProps::new(["Small", "Medium", "Large"])
+//!         .selected(size.selected)
+//!         .horizontal(),
+//!     vec![],
+//! )
+//! ```
+
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::input::Key;
+use crate::style::{Color, Modifier, Style};
+use crossterm::event::KeyCode;
+
+/// Style for radio indicators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RadioStyle {
+    /// Filled circles: ● ○
+    #[default]
+    Circle,
+    /// Parenthesized dot: (•) ( )
+    Paren,
+    /// ASCII: (*) ( )
+    Ascii,
+}
+
+impl RadioStyle {
+    /// The style to draw with under `glyphs`: non-ASCII styles fall back
+    /// to [`RadioStyle::Ascii`] when only ASCII is available.
+    pub fn for_glyphs(self, glyphs: GlyphSet) -> Self {
+        if glyphs.is_ascii() {
+            RadioStyle::Ascii
+        } else {
+            self
+        }
+    }
+
+    /// Get the characters for selected and unselected options.
+    pub fn chars(&self) -> (&'static str, &'static str) {
+        match self {
+            RadioStyle::Circle => ("●", "○"),
+            RadioStyle::Paren => ("(•)", "( )"),
+            RadioStyle::Ascii => ("(*)", "( )"),
+        }
+    }
+}
+
+/// How the options are arranged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RadioLayout {
+    /// One option per line.
+    #[default]
+    Vertical,
+    /// All options on one line.
+    Horizontal,
+}
+
+/// Properties for the RadioGroup component.
+#[derive(Debug, Clone)]
+pub struct RadioGroupProps {
+    /// Option labels.
+    pub options: Vec<String>,
+    /// Index of the selected option.
+    pub selected: usize,
+    /// Whether the group has focus. The selected option is bold while
+    /// focused.
+    pub focused: bool,
+    /// Indicator style.
+    pub style: RadioStyle,
+    /// Vertical or horizontal arrangement.
+    pub layout: RadioLayout,
+    /// Spaces between options in the horizontal layout.
+    pub gap: usize,
+    /// Color of the selected option.
+    pub selected_color: Option<Color>,
+    /// Color of the other options.
+    pub unselected_color: Option<Color>,
+    /// Whether the group is disabled.
+    pub disabled: bool,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for RadioGroupProps {
+    fn default() -> Self {
+        Self {
+            options: Vec::new(),
+            selected: 0,
+            focused: false,
+            style: RadioStyle::default(),
+            layout: RadioLayout::default(),
+            gap: 2,
+            selected_color: Some(Color::Cyan),
+            unselected_color: None,
+            disabled: false,
+            glyphs: None,
+        }
+    }
+}
+
+impl RadioGroupProps {
+    /// Create props with the given option labels.
+    pub fn new<I, S>(options: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the selected index.
+    #[must_use]
+    pub fn selected(mut self, index: usize) -> Self {
+        self.selected = index;
+        self
+    }
+
+    /// Set the focused state.
+    #[must_use]
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Set the indicator style.
+    #[must_use]
+    pub fn style(mut self, style: RadioStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the layout.
+    #[must_use]
+    pub fn layout(mut self, layout: RadioLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Put all options on one line.
+    #[must_use]
+    pub fn horizontal(self) -> Self {
+        self.layout(RadioLayout::Horizontal)
+    }
+
+    /// Set the spacing between options in the horizontal layout.
+    #[must_use]
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the color of the selected option.
+    #[must_use]
+    pub fn selected_color(mut self, color: Color) -> Self {
+        self.selected_color = Some(color);
+        self
+    }
+
+    /// Set the color of the other options.
+    #[must_use]
+    pub fn unselected_color(mut self, color: Color) -> Self {
+        self.unselected_color = Some(color);
+        self
+    }
+
+    /// Set the group as disabled.
+    #[must_use]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// Each option's text ("● Label") and style, in order.
+    pub fn render_options(&self) -> Vec<(String, Style)> {
+        let (on, off) = self
+            .style
+            .for_glyphs(GlyphSet::resolve(self.glyphs))
+            .chars();
+        self.options
+            .iter()
+            .enumerate()
+            .map(|(i, label)| {
+                let is_selected = i == self.selected;
+                let text = format!("{} {}", if is_selected { on } else { off }, label);
+                let mut style = Style::new();
+                if self.disabled {
+                    style = style.add_modifier(Modifier::DIM);
+                } else if is_selected {
+                    if let Some(color) = self.selected_color {
+                        style = style.fg(color);
+                    }
+                    if self.focused {
+                        style = style.add_modifier(Modifier::BOLD);
+                    }
+                } else if let Some(color) = self.unselected_color {
+                    style = style.fg(color);
+                }
+                (text, style)
+            })
+            .collect()
+    }
+
+    /// Build the display string, one line per option when vertical.
+    pub fn render_string(&self) -> String {
+        let options: Vec<String> = self.render_options().into_iter().map(|(t, _)| t).collect();
+        match self.layout {
+            RadioLayout::Vertical => options.join("\n"),
+            RadioLayout::Horizontal => options.join(&" ".repeat(self.gap)),
+        }
+    }
+}
+
+/// A component that displays a group of radio options.
+///
+/// Renders one line per option, or a single line when horizontal.
+pub struct RadioGroup;
+
+impl Component for RadioGroup {
+    type Props = RadioGroupProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let options = props.render_options();
+        if options.is_empty() {
+            return Element::Empty;
+        }
+        match props.layout {
+            RadioLayout::Vertical => Element::Fragment(
+                options
+                    .into_iter()
+                    .map(|(text, style)| Element::styled_text(&text, style))
+                    .collect(),
+            ),
+            RadioLayout::Horizontal => {
+                let gap = " ".repeat(props.gap);
+                let mut spans = Vec::with_capacity(options.len() * 2);
+                for (i, (text, style)) in options.into_iter().enumerate() {
+                    if i > 0 {
+                        spans.push(Element::text(&gap));
+                    }
+                    spans.push(Element::styled_text(&text, style));
+                }
+                // A single inline line inside the vertical fragment
+                Element::Fragment(vec![Element::Fragment(spans)])
+            }
+        }
+    }
+}
+
+/// Helper struct for managing radio selection.
+///
+/// Arrow keys move the selection directly, like radio buttons in a browser.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
+pub struct RadioGroupState {
+    /// Currently selected index.
+    pub selected: usize,
+    /// Number of options.
+    pub count: usize,
+}
+
+impl RadioGroupState {
+    /// Create a new radio group state with the first option selected.
+    pub fn new(count: usize) -> Self {
+        Self { selected: 0, count }
+    }
+
+    /// Start with `index` selected.
+    #[must_use]
+    pub fn with_selected(mut self, index: usize) -> Self {
+        self.jump_to(index);
+        self
+    }
+
+    /// Select the previous option.
+    pub fn up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Select the next option.
+    pub fn down(&mut self) {
+        if self.selected < self.count.saturating_sub(1) {
+            self.selected += 1;
+        }
+    }
+
+    /// Select the first option.
+    pub fn first(&mut self) {
+        self.selected = 0;
+    }
+
+    /// Select the last option.
+    pub fn last(&mut self) {
+        self.selected = self.count.saturating_sub(1);
+    }
+
+    /// Select a specific index.
+    pub fn jump_to(&mut self, index: usize) {
+        if index < self.count {
+            self.selected = index;
+        }
+    }
+
+    /// Handle Up/Left, Down/Right, Home/End and digit shortcuts (1-9).
+    ///
+    /// Returns true if the key changed the selection or was a navigation key.
+    pub fn handle_key(&mut self, key: &Key) -> bool {
+        match key.code {
+            KeyCode::Up | KeyCode::Left => self.up(),
+            KeyCode::Down | KeyCode::Right => self.down(),
+            KeyCode::Home => self.first(),
+            KeyCode::End => self.last(),
+            KeyCode::Char(c @ '1'..='9') => {
+                let index = c as usize - '1' as usize;
+                if index >= self.count {
+                    return false;
+                }
+                self.jump_to(index);
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_radio_group_render_string() {
+        let props = RadioGroupProps::new(["S", "M", "L"])
+            .selected(1)
+            .glyphs(GlyphSet::Unicode);
+        assert_eq!(props.render_string(), "○ S\n● M\n○ L");
+        assert_eq!(props.clone().horizontal().render_string(), "○ S  ● M  ○ L");
+        assert_eq!(
+            props
+                .glyphs(GlyphSet::Ascii)
+                .horizontal()
+                .gap(1)
+                .render_string(),
+            "( ) S (*) M ( ) L"
+        );
+    }
+
+    #[test]
+    fn test_radio_group_render_layout() {
+        let props = RadioGroupProps::new(["a", "b"]).glyphs(GlyphSet::Unicode);
+        match RadioGroup::render(&props) {
+            Element::Fragment(lines) => assert_eq!(lines.len(), 2),
+            _ => panic!("expected fragment"),
+        }
+        match RadioGroup::render(&props.horizontal()) {
+            Element::Fragment(lines) => {
+                assert_eq!(lines.len(), 1);
+                assert!(matches!(&lines[0], Element::Fragment(spans) if spans.len() == 3));
+            }
+            _ => panic!("expected fragment"),
+        }
+    }
+
+    #[test]
+    fn test_radio_group_state_keys() {
+        let mut state = RadioGroupState::new(3);
+        assert!(state.handle_key(&Key::new(KeyCode::Right)));
+        assert_eq!(state.selected, 1);
+        state.handle_key(&Key::new(KeyCode::End));
+        state.handle_key(&Key::new(KeyCode::Down));
+        assert_eq!(state.selected, 2);
+        state.handle_key(&Key::new(KeyCode::Char('1')));
+        assert_eq!(state.selected, 0);
+        assert!(!state.handle_key(&Key::new(KeyCode::Char('4'))));
+        assert!(!state.handle_key(&Key::new(KeyCode::Char('x'))));
+        assert_eq!(RadioGroupState::new(2).with_selected(5).selected, 0);
+    }
+}
@@ -0,0 +1,211 @@
+//! Reveal component - grows its children into view line by line.
+//!
+//! Reveal shows the top `progress` fraction of its children's rows: 0.0
+//! takes no space at all, 1.0 is their natural height. Animating `progress`
+//! makes a section slide open or shut instead of popping, and everything
+//! below it moves smoothly with it. [`RevealState`] runs the animation.
+//!
+//! ## When to use Reveal
+//!
+//! - Expanding details, settings panels and error output
+//! - Hiding a section without the layout jumping
+//!
+//! ## See also
+//!
+//! - [`Collapsible`](super::Collapsible) — A section with a header to toggle
+//! - [`Truncate`](super::Truncate) — Show the first lines until expanded
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut details = RevealState::new(false).duration(200);
+//!
+//! // In the input handler:
+//! if key.is_char('d') {
+//!     details.toggle();
+//! }
+//!
+//! // In render (keep rendering while details.is_animating()):
+//! Element::node::<Reveal>(
+//!     RevealProps::new(details.progress()),
+//!     vec![details_panel()],
+//! )
+//! ```
+
+use crate::animation::{AnimationTimer, Easing};
+use crate::components::scroll_view::ScrollViewProps;
+use crate::element::{Component, Element};
+
+/// Properties for the Reveal component.
+#[derive(Debug, Clone)]
+pub struct RevealProps {
+    /// Fraction of the rows shown, from 0.0 to 1.0.
+    pub progress: f32,
+}
+
+impl Default for RevealProps {
+    fn default() -> Self {
+        Self { progress: 1.0 }
+    }
+}
+
+impl RevealProps {
+    /// Create props showing `progress` (0.0 to 1.0) of the rows.
+    pub fn new(progress: f32) -> Self {
+        Self {
+            progress: progress.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Rows shown of content `content_height` rows tall.
+    pub fn visible_rows(&self, content_height: usize) -> usize {
+        let progress = self.progress.clamp(0.0, 1.0);
+        ((content_height as f32 * progress).ceil() as usize).min(content_height)
+    }
+
+    /// The view showing the top rows of content `content_height` rows tall.
+    pub fn view(&self, content_height: usize) -> ScrollViewProps {
+        ScrollViewProps::new(self.visible_rows(content_height) as u16).scrollbar(false)
+    }
+}
+
+/// A container that shows only the top part of its children.
+///
+/// The renderer measures the children and cuts them to the rows shown.
+pub struct Reveal;
+
+impl Component for Reveal {
+    type Props = RevealProps;
+
+    fn render(_props: &Self::Props) -> Element {
+        // Like ScrollView, the renderer draws the children directly
+        Element::empty()
+    }
+}
+
+/// Helper struct for animating a [`Reveal`] open and shut.
+///
+/// Changing direction mid-animation starts from where it is, so toggling
+/// quickly never jumps.
+#[derive(Debug, Clone)]
+pub struct RevealState {
+    shown: bool,
+    from: f32,
+    timer: AnimationTimer,
+    /// Length of a full open or close, in milliseconds.
+    pub duration_ms: u64,
+    /// Easing of the animation.
+    pub easing: Easing,
+}
+
+impl RevealState {
+    /// Create a state that starts fully shown or hidden, without animating.
+    pub fn new(shown: bool) -> Self {
+        Self {
+            shown,
+            from: if shown { 1.0 } else { 0.0 },
+            timer: AnimationTimer::new(),
+            duration_ms: 250,
+            easing: Easing::EaseOut,
+        }
+    }
+
+    /// Set the length of the animation in milliseconds.
+    #[must_use]
+    pub fn duration(mut self, duration_ms: u64) -> Self {
+        self.duration_ms = duration_ms;
+        self
+    }
+
+    /// Set the easing of the animation.
+    #[must_use]
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Whether the content is shown, or being revealed.
+    pub fn is_shown(&self) -> bool {
+        self.shown
+    }
+
+    /// Animate to shown or hidden.
+    pub fn set_shown(&mut self, shown: bool) {
+        if shown != self.shown {
+            self.from = self.progress();
+            self.shown = shown;
+            self.timer.reset();
+        }
+    }
+
+    /// Animate open.
+    pub fn show(&mut self) {
+        self.set_shown(true);
+    }
+
+    /// Animate shut.
+    pub fn hide(&mut self) {
+        self.set_shown(false);
+    }
+
+    /// Animate to the other state.
+    pub fn toggle(&mut self) {
+        self.set_shown(!self.shown);
+    }
+
+    /// Fraction shown now, for [`RevealProps::new`].
+    pub fn progress(&self) -> f32 {
+        let to = if self.shown { 1.0 } else { 0.0 };
+        // A partial run takes its share of the full duration
+        let distance = (to - self.from).abs();
+        if distance == 0.0 {
+            return to;
+        }
+        let duration = (self.duration_ms as f32 * distance).round() as u64;
+        if duration == 0 {
+            return to;
+        }
+        let t = self.timer.progress(duration, self.easing) as f32;
+        self.from + (to - self.from) * t
+    }
+
+    /// Whether the animation is still running, so the app keeps rendering.
+    pub fn is_animating(&self) -> bool {
+        let progress = self.progress();
+        progress != if self.shown { 1.0 } else { 0.0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_reveal_visible_rows() {
+        assert_eq!(RevealProps::new(0.0).visible_rows(10), 0);
+        assert_eq!(RevealProps::new(0.25).visible_rows(10), 3);
+        assert_eq!(RevealProps::new(1.0).visible_rows(10), 10);
+        assert_eq!(RevealProps::default().view(4).height, 4);
+        assert!(!RevealProps::new(0.5).view(4).scrollbar);
+    }
+
+    #[test]
+    fn test_reveal_state_animates() {
+        let mut state = RevealState::new(false).duration(100).easing(Easing::Linear);
+        assert_eq!(state.progress(), 0.0);
+        assert!(!state.is_animating());
+
+        state.show();
+        state.timer = AnimationTimer::with_start(Instant::now() - Duration::from_millis(50));
+        assert!((state.progress() - 0.5).abs() < 0.1);
+        assert!(state.is_animating());
+
+        // Reversing starts from where it was
+        state.hide();
+        assert!(state.progress() > 0.3);
+        state.timer = AnimationTimer::with_start(Instant::now() - Duration::from_millis(100));
+        assert_eq!(state.progress(), 0.0);
+        assert!(!state.is_animating());
+    }
+}
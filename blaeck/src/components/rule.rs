@@ -0,0 +1,287 @@
+//! Rule component - a full-width line with an inset title.
+//!
+//! Unlike [`Divider`](super::Divider), a Rule has no width of its own: it
+//! stretches across a column and grows into the free space of a row, so a
+//! section header spans the Box it's in without measuring anything. A
+//! parent without a width is only as wide as its other content; give the
+//! outermost Box the terminal width for a rule that spans the screen.
+//!
+//! ## When to use Rule
+//!
+//! - Section headers in long reports (`── Results ──────────`)
+//! - Separators that should follow the terminal width on resize
+//!
+//! ## See also
+//!
+//! - [`Divider`](super::Divider) — Fixed-width separator with status glyphs
+//!   and gradients
+//!
+//! # Example
+//!
+//! ```ignore
+//! use blaeck::prelude::*;
+//!
+//! let header = rule("Results", DividerAlign::Left);
+//!
+//! let footer = Element::node::<Rule>(
+//!     RuleProps::new("end")
+//!         .align(DividerAlign::Right)
+//!         .padding(2)
+//!         .color(Color::DarkGray),
+//!     vec![],
+//! );
+//! ```
+
+use crate::components::divider::{DividerAlign, DividerStyle};
+use crate::element::{Component, Element};
+use crate::layout::LayoutStyle;
+use crate::style::{Color, Modifier, Style};
+use crate::truncation::Truncation;
+use unicode_width::UnicodeWidthStr;
+
+/// Properties for the Rule component.
+#[derive(Debug, Clone)]
+pub struct RuleProps {
+    /// Title set into the line.
+    pub title: Option<String>,
+    /// Where the title sits along the line.
+    pub align: DividerAlign,
+    /// Style of the line.
+    pub style: DividerStyle,
+    /// Color of the line.
+    pub color: Option<Color>,
+    /// Color of the title (defaults to line color).
+    pub title_color: Option<Color>,
+    /// Whether to dim the line.
+    pub dim: bool,
+    /// Spaces on each side of the title.
+    pub padding: usize,
+    /// Line cells before a left-aligned title or after a right-aligned one.
+    pub inset: usize,
+    /// Fixed width. None = fill the parent.
+    pub width: Option<usize>,
+}
+
+impl Default for RuleProps {
+    fn default() -> Self {
+        Self {
+            title: None,
+            align: DividerAlign::Center,
+            style: DividerStyle::Single,
+            color: None,
+            title_color: None,
+            dim: false,
+            padding: 1,
+            inset: 2,
+            width: None,
+        }
+    }
+}
+
+impl RuleProps {
+    /// Create a rule with a title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: Some(title.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a plain rule without a title.
+    pub fn plain() -> Self {
+        Self::default()
+    }
+
+    /// Set where the title sits along the line.
+    #[must_use]
+    pub fn align(mut self, align: DividerAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set the line style.
+    #[must_use]
+    pub fn line_style(mut self, style: DividerStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the line color.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the title color.
+    #[must_use]
+    pub fn title_color(mut self, color: Color) -> Self {
+        self.title_color = Some(color);
+        self
+    }
+
+    /// Make the rule dimmed.
+    #[must_use]
+    pub fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    /// Set the spaces on each side of the title.
+    #[must_use]
+    pub fn padding(mut self, padding: usize) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the line cells before a left-aligned title or after a
+    /// right-aligned one.
+    #[must_use]
+    pub fn inset(mut self, inset: usize) -> Self {
+        self.inset = inset;
+        self
+    }
+
+    /// Use a fixed width instead of filling the parent.
+    #[must_use]
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Layout for the rule: one row, growing to fill the parent's width
+    /// unless the width is fixed.
+    pub fn layout_style(&self) -> LayoutStyle {
+        match self.width {
+            Some(width) => LayoutStyle {
+                width: Some(width as f32),
+                height: Some(1.0),
+                ..Default::default()
+            },
+            None => LayoutStyle {
+                height: Some(1.0),
+                max_height: Some(1.0),
+                flex_grow: 1.0,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Line cells before the title, the title as shown, and line cells
+    /// after it, for a rule `width` cells wide.
+    fn parts(&self, width: usize) -> (usize, Option<String>, usize) {
+        let Some(title) = self.title.as_deref().filter(|t| !t.is_empty()) else {
+            return (width, None, 0);
+        };
+        let room = width.saturating_sub(self.padding * 2);
+        let title = Truncation::detect().truncate(title, room).into_owned();
+        let remaining = room.saturating_sub(title.width());
+        let left = match self.align {
+            DividerAlign::Left => remaining.min(self.inset),
+            DividerAlign::Center => remaining / 2,
+            DividerAlign::Right => remaining.saturating_sub(self.inset),
+        };
+        let pad = " ".repeat(self.padding);
+        (left, Some(format!("{pad}{title}{pad}")), remaining - left)
+    }
+
+    /// Build the display string for a rule `width` cells wide.
+    pub fn render_string(&self, width: usize) -> String {
+        let ch = self.style.char().to_string();
+        let (left, title, right) = self.parts(width);
+        format!(
+            "{}{}{}",
+            ch.repeat(left),
+            title.unwrap_or_default(),
+            ch.repeat(right)
+        )
+    }
+}
+
+/// A full-width line with an inset title.
+///
+/// The renderer sizes it from the layout; rendered on its own (outside a
+/// layout) it's 20 cells wide unless [`RuleProps::width`] is set.
+pub struct Rule;
+
+impl Component for Rule {
+    type Props = RuleProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let width = props.width.unwrap_or(20);
+
+        let mut style = Style::new();
+        if let Some(color) = props.color {
+            style = style.fg(color);
+        }
+        if props.dim {
+            style = style.add_modifier(Modifier::DIM);
+        }
+
+        let Some(title_color) = props.title_color else {
+            return Element::styled_text(props.render_string(width), style);
+        };
+        let ch = props.style.char().to_string();
+        let (left, title, right) = props.parts(width);
+        Element::Fragment(vec![
+            Element::styled_text(ch.repeat(left), style),
+            Element::styled_text(title.unwrap_or_default(), style.fg(title_color)),
+            Element::styled_text(ch.repeat(right), style),
+        ])
+    }
+}
+
+/// Helper to create a full-width rule with a title.
+pub fn rule(title: impl Into<String>, align: DividerAlign) -> Element {
+    Element::node::<Rule>(RuleProps::new(title).align(align), vec![])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_render_string() {
+        let props = RuleProps::new("Results").align(DividerAlign::Left);
+        assert_eq!(props.render_string(20), "── Results ─────────");
+        let props = props.align(DividerAlign::Right).padding(2);
+        assert_eq!(props.render_string(20), "───────  Results  ──");
+        let props = props.align(DividerAlign::Center).padding(0);
+        assert_eq!(props.render_string(11), "──Results──");
+        assert_eq!(RuleProps::plain().render_string(3), "───");
+    }
+
+    #[test]
+    fn test_rule_fills_parent_width() {
+        use crate::components::{Box, BoxProps};
+        use crate::layout::FlexDirection;
+
+        let mut blaeck = crate::renderer::Blaeck::with_size(Vec::new(), 30, 4).unwrap();
+        let root = BoxProps {
+            width: Some(24.0),
+            flex_direction: FlexDirection::Column,
+            ..Default::default()
+        };
+        blaeck
+            .render(Element::node::<Box>(
+                root,
+                vec![
+                    Element::text("top"),
+                    rule("Results", DividerAlign::Left),
+                    Element::row(vec![
+                        Element::text("ab"),
+                        Element::node::<Rule>(RuleProps::plain(), vec![]),
+                    ]),
+                ],
+            ))
+            .unwrap();
+        assert_eq!(
+            blaeck.frame_lines(),
+            vec![
+                "top",
+                "── Results ─────────────",
+                "ab──────────────────────",
+            ]
+        );
+    }
+}
@@ -0,0 +1,375 @@
+//! ScrollView component - a fixed-height container that scrolls its children.
+//!
+//! The ScrollView lays out its children at their natural height, then shows
+//! only `height` rows of them starting at `offset`. Use `ScrollState` to
+//! track the offset and handle Up/Down/PageUp/PageDown/Home/End.
+//!
+//! ## When to use ScrollView
+//!
+//! - Long content in a fixed area (logs, help text, details panes)
+//! - Lists of arbitrary elements, not just strings
+//!
+//! ## See also
+//!
+//! - [`Select`](super::Select) — Scrolling single selection list
+//! - [`LogBox`](super::LogBox) — Tail of a log with a line limit
+//! - [`Scrollbar`](super::Scrollbar) — The scrollbar on its own, for custom containers
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut scroll = ScrollState::new(lines.len(), 10);
+//!
+//! // In the input handler:
+//! scroll.handle_key(&key);
+//!
+//! // In render:
+//! Element::node::<ScrollView>(
+//!     ScrollViewProps::new(10).offset(scroll.offset),
+//!     lines.iter().map(|l| Element::text(l)).collect(),
+//! )
+//! ```
+
+use crate::components::scrollbar::ScrollbarProps;
+use crate::element::{Component, Element};
+use crate::gestures::Wheel;
+use crate::glyphs::GlyphSet;
+use crate::input::Key;
+use crate::layout::{FlexDirection, LayoutStyle, Overflow};
+use crate::style::{Color, Style};
+use crossterm::event::KeyCode;
+
+/// Properties for the ScrollView component.
+#[derive(Debug, Clone)]
+pub struct ScrollViewProps {
+    /// Visible height in rows.
+    pub height: u16,
+    /// Fixed width (`None` = fill the parent).
+    pub width: Option<u16>,
+    /// First visible row of the content. Clamped so the view stays full.
+    pub offset: usize,
+    /// Show a scrollbar in the rightmost column when content overflows.
+    pub scrollbar: bool,
+    /// Color of the scrollbar track.
+    pub track_color: Option<Color>,
+    /// Color of the scrollbar thumb.
+    pub thumb_color: Option<Color>,
+    /// Glyph set override for the scrollbar (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+    /// Name that mouse wheel events over the view are routed by (see
+    /// [`Blaeck::scroll_target_at`](crate::Blaeck::scroll_target_at)).
+    pub scroll_id: Option<String>,
+}
+
+impl Default for ScrollViewProps {
+    fn default() -> Self {
+        Self {
+            height: 10,
+            width: None,
+            offset: 0,
+            scrollbar: true,
+            track_color: None,
+            thumb_color: None,
+            glyphs: None,
+            scroll_id: None,
+        }
+    }
+}
+
+impl ScrollViewProps {
+    /// Create props for a view `height` rows tall.
+    pub fn new(height: u16) -> Self {
+        Self {
+            height,
+            ..Default::default()
+        }
+    }
+
+    /// Set the first visible row.
+    #[must_use]
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set a fixed width.
+    #[must_use]
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Name the view so wheel events over it are routed to its handler.
+    #[must_use]
+    pub fn scroll_id(mut self, id: impl Into<String>) -> Self {
+        self.scroll_id = Some(id.into());
+        self
+    }
+
+    /// Show or hide the scrollbar.
+    #[must_use]
+    pub fn scrollbar(mut self, show: bool) -> Self {
+        self.scrollbar = show;
+        self
+    }
+
+    /// Set the scrollbar track color.
+    #[must_use]
+    pub fn track_color(mut self, color: Color) -> Self {
+        self.track_color = Some(color);
+        self
+    }
+
+    /// Set the scrollbar thumb color.
+    #[must_use]
+    pub fn thumb_color(mut self, color: Color) -> Self {
+        self.thumb_color = Some(color);
+        self
+    }
+
+    /// Override the detected glyph set used for the scrollbar.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// Layout of the viewport. The scrollbar column is reserved as padding
+    /// so content never runs under it.
+    pub fn to_layout_style(&self) -> LayoutStyle {
+        LayoutStyle {
+            width: self.width.map(f32::from),
+            height: Some(f32::from(self.height)),
+            flex_direction: FlexDirection::Column,
+            flex_shrink: 0.0,
+            overflow_y: Overflow::Hidden,
+            padding_right: Some(if self.scrollbar { 1.0 } else { 0.0 }),
+            ..Default::default()
+        }
+    }
+
+    /// The offset actually shown for content `content_height` rows tall.
+    pub fn clamped_offset(&self, content_height: usize) -> usize {
+        self.offset
+            .min(content_height.saturating_sub(self.height as usize))
+    }
+
+    /// Scrollbar cells from top to bottom for content `content_height` rows
+    /// tall, or `None` when everything fits.
+    pub fn scrollbar_cells(&self, content_height: usize) -> Option<Vec<(char, Style)>> {
+        if !self.scrollbar {
+            return None;
+        }
+        let mut bar = ScrollbarProps::new(content_height, self.height as usize, self.offset);
+        bar.track_color = self.track_color;
+        bar.thumb_color = self.thumb_color;
+        bar.glyphs = self.glyphs;
+        bar.cells()
+    }
+}
+
+/// A fixed-height container that shows a scrolled window of its children.
+///
+/// Children are laid out as a column. The renderer clips them to the view
+/// and draws the scrollbar.
+pub struct ScrollView;
+
+impl Component for ScrollView {
+    type Props = ScrollViewProps;
+
+    fn render(_props: &Self::Props) -> Element {
+        // Like Box, the renderer draws the children and scrollbar directly
+        Element::empty()
+    }
+}
+
+/// Rows scrolled per wheel notch.
+const WHEEL_ROWS: isize = 3;
+
+/// Helper struct for managing scroll position.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollState {
+    /// First visible row.
+    pub offset: usize,
+    /// Total content height in rows.
+    pub content_height: usize,
+    /// Visible height in rows.
+    pub viewport_height: usize,
+}
+
+impl ScrollState {
+    /// Create a scroll state for content and viewport heights in rows.
+    pub fn new(content_height: usize, viewport_height: usize) -> Self {
+        Self {
+            offset: 0,
+            content_height,
+            viewport_height,
+        }
+    }
+
+    /// Update the content height (e.g. when lines are added), keeping the
+    /// offset in range.
+    pub fn set_content_height(&mut self, height: usize) {
+        self.content_height = height;
+        self.offset = self.offset.min(self.max_offset());
+    }
+
+    /// Largest useful offset.
+    pub fn max_offset(&self) -> usize {
+        self.content_height.saturating_sub(self.viewport_height)
+    }
+
+    /// Whether the view shows the last row.
+    pub fn at_bottom(&self) -> bool {
+        self.offset >= self.max_offset()
+    }
+
+    /// Scroll by `delta` rows (negative scrolls up).
+    pub fn scroll_by(&mut self, delta: isize) {
+        self.offset = self
+            .offset
+            .saturating_add_signed(delta)
+            .min(self.max_offset());
+    }
+
+    /// Scroll up one row.
+    pub fn up(&mut self) {
+        self.scroll_by(-1);
+    }
+
+    /// Scroll down one row.
+    pub fn down(&mut self) {
+        self.scroll_by(1);
+    }
+
+    /// Scroll up one page.
+    pub fn page_up(&mut self) {
+        self.scroll_by(-(self.viewport_height.max(1) as isize));
+    }
+
+    /// Scroll down one page.
+    pub fn page_down(&mut self) {
+        self.scroll_by(self.viewport_height.max(1) as isize);
+    }
+
+    /// Scroll to the top.
+    pub fn top(&mut self) {
+        self.offset = 0;
+    }
+
+    /// Scroll to the bottom.
+    pub fn bottom(&mut self) {
+        self.offset = self.max_offset();
+    }
+
+    /// Make `row` visible, scrolling as little as possible.
+    pub fn scroll_to(&mut self, row: usize) {
+        if row < self.offset {
+            self.offset = row;
+        } else if row >= self.offset + self.viewport_height {
+            self.offset = (row + 1).saturating_sub(self.viewport_height);
+        }
+        self.offset = self.offset.min(self.max_offset());
+    }
+
+    /// Scroll so `row` is the first visible row, as far as the content
+    /// allows. Used to jump to a section.
+    pub fn jump_to(&mut self, row: usize) {
+        self.offset = row.min(self.max_offset());
+    }
+
+    /// Handle Up/Down, PageUp/PageDown and Home/End.
+    ///
+    /// Returns true if the key was a scroll key.
+    pub fn handle_key(&mut self, key: &Key) -> bool {
+        match key.code {
+            KeyCode::Up => self.up(),
+            KeyCode::Down => self.down(),
+            KeyCode::PageUp => self.page_up(),
+            KeyCode::PageDown => self.page_down(),
+            KeyCode::Home => self.top(),
+            KeyCode::End => self.bottom(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Scroll three rows per notch of a vertical wheel.
+    ///
+    /// Returns true if the wheel was vertical.
+    pub fn handle_wheel(&mut self, wheel: &Wheel) -> bool {
+        if wheel.is_horizontal() {
+            return false;
+        }
+        self.scroll_by(wheel.delta() * WHEEL_ROWS);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_state_keys() {
+        let mut state = ScrollState::new(25, 10);
+        assert!(state.handle_key(&Key::new(KeyCode::PageDown)));
+        assert_eq!(state.offset, 10);
+        state.handle_key(&Key::new(KeyCode::PageDown));
+        assert_eq!(state.offset, 15);
+        assert!(state.at_bottom());
+        state.handle_key(&Key::new(KeyCode::Up));
+        assert_eq!(state.offset, 14);
+        assert!(!state.handle_key(&Key::new(KeyCode::Char('x'))));
+
+        state.scroll_to(2);
+        assert_eq!(state.offset, 2);
+        state.jump_to(12);
+        assert_eq!(state.offset, 12);
+        state.jump_to(40);
+        assert_eq!(state.offset, 15);
+        state.set_content_height(5);
+        assert_eq!(state.offset, 0);
+    }
+
+    #[test]
+    fn test_scroll_state_wheel() {
+        use crate::gestures::ScrollDirection;
+        use crossterm::event::KeyModifiers;
+
+        let wheel = |direction| Wheel {
+            direction,
+            position: (0, 0),
+            modifiers: KeyModifiers::NONE,
+        };
+        let mut state = ScrollState::new(25, 10);
+        assert!(state.handle_wheel(&wheel(ScrollDirection::Down)));
+        assert_eq!(state.offset, 3);
+        state.handle_wheel(&wheel(ScrollDirection::Up));
+        state.handle_wheel(&wheel(ScrollDirection::Up));
+        assert_eq!(state.offset, 0);
+        assert!(!state.handle_wheel(&wheel(ScrollDirection::Right)));
+    }
+
+    #[test]
+    fn test_scrollbar_thumb() {
+        let props = ScrollViewProps::new(4).glyphs(GlyphSet::Unicode);
+        assert!(props.scrollbar_cells(4).is_none());
+
+        let bar = |offset| -> String {
+            props
+                .clone()
+                .offset(offset)
+                .scrollbar_cells(8)
+                .unwrap()
+                .into_iter()
+                .map(|(c, _)| c)
+                .collect()
+        };
+        assert_eq!(bar(0), "┃┃││");
+        assert_eq!(bar(1), "│┃┃│");
+        assert_eq!(bar(4), "││┃┃");
+        assert_eq!(bar(99), "││┃┃");
+    }
+}
@@ -0,0 +1,250 @@
+//! Scrollbar component - position indicator for scrolled content.
+//!
+//! A Scrollbar shows which part of `total` rows (or columns) a `viewport`
+//! starting at `offset` shows. It draws nothing when everything fits. Use it
+//! next to any custom scrolling container so it looks like the one
+//! [`ScrollView`](super::ScrollView) draws.
+//!
+//! ## When to use Scrollbar
+//!
+//! - Custom lists or canvases that scroll themselves
+//! - Horizontal scrolling of wide tables
+//!
+//! ## See also
+//!
+//! - [`ScrollView`](super::ScrollView) — Scrolling container with a built-in scrollbar
+//! - [`ScrollState`](super::ScrollState) — Tracks the offset
+//!
+//! # Example
+//!
+//! ```ignore
+//! element! {
+//!     Box(flex_direction: FlexDirection::Row) {
+//!         #(visible_rows)
+//!         Scrollbar(total: items.len(), viewport: 10, offset: scroll.offset)
+//!     }
+//! }
+//!
+//! // Horizontal, under a wide table
+//! Element::node::<Scrollbar>(
+//!     ScrollbarProps::new(200, 80, x_offset).horizontal(),
+//!     vec![],
+//! )
+//! ```
+
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::style::{Color, Style};
+
+/// Direction a scrollbar runs in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScrollbarOrientation {
+    /// A column of cells, for vertical scrolling.
+    #[default]
+    Vertical,
+    /// A row of cells, for horizontal scrolling.
+    Horizontal,
+}
+
+/// Properties for the Scrollbar component.
+#[derive(Debug, Clone, Default)]
+pub struct ScrollbarProps {
+    /// Size of the content in rows (or columns).
+    pub total: usize,
+    /// Visible size in rows (or columns).
+    pub viewport: usize,
+    /// First visible row (or column). Clamped to the content.
+    pub offset: usize,
+    /// Direction of the bar.
+    pub orientation: ScrollbarOrientation,
+    /// Length of the bar in cells (`None` = the viewport size).
+    pub length: Option<u16>,
+    /// Track and thumb characters (`None` = lines from the glyph set).
+    pub chars: Option<(char, char)>,
+    /// Color of the track.
+    pub track_color: Option<Color>,
+    /// Color of the thumb.
+    pub thumb_color: Option<Color>,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl ScrollbarProps {
+    /// Create props for a `viewport` at `offset` into `total` rows.
+    pub fn new(total: usize, viewport: usize, offset: usize) -> Self {
+        Self {
+            total,
+            viewport,
+            offset,
+            ..Default::default()
+        }
+    }
+
+    /// Draw the bar horizontally.
+    #[must_use]
+    pub fn horizontal(mut self) -> Self {
+        self.orientation = ScrollbarOrientation::Horizontal;
+        self
+    }
+
+    /// Set the direction of the bar.
+    #[must_use]
+    pub fn orientation(mut self, orientation: ScrollbarOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the length of the bar in cells.
+    #[must_use]
+    pub fn length(mut self, length: u16) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Use custom track and thumb characters.
+    #[must_use]
+    pub fn chars(mut self, track: char, thumb: char) -> Self {
+        self.chars = Some((track, thumb));
+        self
+    }
+
+    /// Set the track color.
+    #[must_use]
+    pub fn track_color(mut self, color: Color) -> Self {
+        self.track_color = Some(color);
+        self
+    }
+
+    /// Set the thumb color.
+    #[must_use]
+    pub fn thumb_color(mut self, color: Color) -> Self {
+        self.thumb_color = Some(color);
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// Track and thumb characters.
+    fn track_thumb(&self) -> (char, char) {
+        if let Some(chars) = self.chars {
+            return chars;
+        }
+        let ascii = GlyphSet::resolve(self.glyphs).is_ascii();
+        match (self.orientation, ascii) {
+            (ScrollbarOrientation::Vertical, true) => ('|', '#'),
+            (ScrollbarOrientation::Vertical, false) => ('│', '┃'),
+            (ScrollbarOrientation::Horizontal, true) => ('-', '#'),
+            (ScrollbarOrientation::Horizontal, false) => ('─', '━'),
+        }
+    }
+
+    /// Cells from top to bottom (or left to right), or `None` when the
+    /// content fits.
+    pub fn cells(&self) -> Option<Vec<(char, Style)>> {
+        let length = self.length.map_or(self.viewport, usize::from);
+        if length == 0 || self.total <= self.viewport {
+            return None;
+        }
+        let (track, thumb) = self.track_thumb();
+        let track_style = match self.track_color {
+            Some(color) => Style::new().fg(color),
+            None => Style::new().dim(),
+        };
+        let thumb_style = self
+            .thumb_color
+            .map(|c| Style::new().fg(c))
+            .unwrap_or_default();
+
+        // The thumb only touches an end of the track when the view does,
+        // so "at the top" and "at the bottom" are never ambiguous
+        let thumb_len = (length * self.viewport / self.total).clamp(1, length);
+        let max_offset = self.total - self.viewport;
+        let offset = self.offset.min(max_offset);
+        let thumb_start = (offset * (length - thumb_len)).div_ceil(max_offset);
+        Some(
+            (0..length)
+                .map(|i| {
+                    if i >= thumb_start && i < thumb_start + thumb_len {
+                        (thumb, thumb_style)
+                    } else {
+                        (track, track_style)
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+/// A component that shows the scroll position of some content.
+pub struct Scrollbar;
+
+impl Component for Scrollbar {
+    type Props = ScrollbarProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let Some(cells) = props.cells() else {
+            return Element::Empty;
+        };
+        let cells = cells
+            .into_iter()
+            .map(|(c, style)| Element::styled_text(c.to_string(), style));
+        match props.orientation {
+            // One line per cell
+            ScrollbarOrientation::Vertical => Element::Fragment(cells.collect()),
+            // A single line of cells
+            ScrollbarOrientation::Horizontal => {
+                Element::Fragment(vec![Element::Fragment(cells.collect())])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(props: &ScrollbarProps) -> String {
+        props.cells().unwrap().into_iter().map(|(c, _)| c).collect()
+    }
+
+    #[test]
+    fn test_scrollbar_cells() {
+        let props = ScrollbarProps::new(8, 4, 0).glyphs(GlyphSet::Unicode);
+        assert_eq!(bar(&props), "┃┃││");
+        assert_eq!(
+            bar(&ScrollbarProps {
+                offset: 4,
+                ..props.clone()
+            }),
+            "││┃┃"
+        );
+        assert!(ScrollbarProps::new(4, 4, 0).cells().is_none());
+
+        let wide = ScrollbarProps::new(100, 20, 40)
+            .horizontal()
+            .length(10)
+            .glyphs(GlyphSet::Ascii);
+        assert_eq!(bar(&wide), "----##----");
+        assert_eq!(bar(&wide.clone().chars('.', '=')), "....==....");
+    }
+
+    #[test]
+    fn test_scrollbar_render() {
+        let props = ScrollbarProps::new(8, 4, 0);
+        let Element::Fragment(lines) = Scrollbar::render(&props) else {
+            panic!("Expected Fragment");
+        };
+        assert_eq!(lines.len(), 4);
+
+        let Element::Fragment(lines) = Scrollbar::render(&props.horizontal()) else {
+            panic!("Expected Fragment");
+        };
+        assert!(matches!(&lines[..], [Element::Fragment(cells)] if cells.len() == 4));
+        assert!(Scrollbar::render(&ScrollbarProps::new(2, 4, 0)).is_empty());
+    }
+}
@@ -3,6 +3,11 @@
 //! The Select component displays a list of options that users can navigate
 //! with arrow keys and select with Enter. Use `SelectState` to track selection.
 //!
+//! With [`SelectProps::columns`] above 1, items are laid out in a grid that
+//! fills each row left to right, so a long list of short options fits on a
+//! few rows. [`SelectState::left`] and [`SelectState::right`] move within a
+//! row, and up/down move between rows.
+//!
 //! ## When to use Select
 //!
 //! - Single choice from a list of options
@@ -17,6 +22,7 @@
 //! - [`Tabs`](super::Tabs) — Horizontal selection (tab bar style)
 
 use crate::element::{Component, Element};
+use crate::gestures::{ScrollDirection, Wheel};
 use crate::style::{Color, Modifier, Style};
 
 /// A single item in a select list.
@@ -124,6 +130,14 @@ pub struct SelectProps {
     pub scroll_offset: usize,
     /// Whether to show the indicator for unselected items.
     pub show_unselected_indicator: bool,
+    /// Number of grid columns (1 = a plain list). In a grid, `max_visible`
+    /// and `scroll_offset` count rows rather than items.
+    pub columns: usize,
+    /// Spaces between grid columns.
+    pub column_gap: usize,
+    /// Name that mouse wheel events over the list are routed by (see
+    /// [`Blaeck::scroll_target_at`](crate::Blaeck::scroll_target_at)).
+    pub scroll_id: Option<String>,
 }
 
 impl Default for SelectProps {
@@ -138,6 +152,9 @@ impl Default for SelectProps {
             max_visible: None,
             scroll_offset: 0,
             show_unselected_indicator: true,
+            columns: 1,
+            column_gap: 2,
+            scroll_id: None,
         }
     }
 }
@@ -204,6 +221,27 @@ impl SelectProps {
         self
     }
 
+    /// Lay items out in a grid with the given number of columns.
+    #[must_use]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Set the number of spaces between grid columns.
+    #[must_use]
+    pub fn column_gap(mut self, gap: usize) -> Self {
+        self.column_gap = gap;
+        self
+    }
+
+    /// Name the list so wheel events over it are routed to its handler.
+    #[must_use]
+    pub fn scroll_id(mut self, id: impl Into<String>) -> Self {
+        self.scroll_id = Some(id.into());
+        self
+    }
+
     /// Get the currently selected item.
     pub fn selected_item(&self) -> Option<&SelectItem> {
         self.items.get(self.selected)
@@ -235,8 +273,37 @@ impl SelectProps {
         None
     }
 
-    /// Build the display strings for all visible items.
+    /// Build the display strings for all visible items, one per row. In a
+    /// grid, each row takes the style of its selected item, or else of its
+    /// first item.
     pub fn render_lines(&self) -> Vec<(String, Style)> {
+        let cells = self.render_cells();
+        let columns = self.columns.max(1);
+        if columns == 1 {
+            return cells;
+        }
+
+        let gap = " ".repeat(self.column_gap);
+        cells
+            .chunks(columns)
+            .map(|row| {
+                let line = row
+                    .iter()
+                    .map(|(cell, _)| cell.as_str())
+                    .collect::<Vec<_>>()
+                    .join(&gap);
+                let style = row
+                    .iter()
+                    .find(|(_, s)| s.modifiers.contains(Modifier::BOLD))
+                    .unwrap_or(&row[0])
+                    .1;
+                (line, style)
+            })
+            .collect()
+    }
+
+    /// Build the display string for each visible item.
+    fn render_cells(&self) -> Vec<(String, Style)> {
         let (selected_char, unselected_char) = self.indicator.chars();
         let visible_items = self.visible_items();
         let is_numbered = self.indicator.is_numbered();
@@ -309,11 +376,17 @@ impl SelectProps {
     /// Get the visible items based on scroll offset and max_visible.
     fn visible_items(&self) -> Vec<(usize, &SelectItem)> {
         let items: Vec<_> = self.items.iter().enumerate().collect();
+        let columns = self.columns.max(1);
+        let rows = items.len().div_ceil(columns);
 
         if let Some(max) = self.max_visible {
-            if items.len() > max {
-                let start = self.scroll_offset.min(items.len().saturating_sub(max));
-                return items.into_iter().skip(start).take(max).collect();
+            if rows > max {
+                let start = self.scroll_offset.min(rows - max);
+                return items
+                    .into_iter()
+                    .skip(start * columns)
+                    .take(max * columns)
+                    .collect();
             }
         }
 
@@ -338,6 +411,20 @@ impl SelectProps {
 ///     KeyCode::Enter => return Some(selected),
 ///     _ => {}
 /// }
+///
+/// // A grid of short options, four per row
+/// let mut state = SelectState::new(countries.len()).columns(4);
+/// let props = SelectProps::new(countries)
+///     .columns(4)
+///     .selected(state.selected);
+///
+/// match key.code {
+///     KeyCode::Left => state.left(),
+///     KeyCode::Right => state.right(),
+///     KeyCode::Up => state.up(),
+///     KeyCode::Down => state.down(),
+///     _ => {}
+/// }
 /// ```
 pub struct Select;
 
@@ -368,6 +455,7 @@ impl Component for Select {
 
 /// Helper struct for managing select state.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelectState {
     /// Currently selected index.
     pub selected: usize,
@@ -375,8 +463,10 @@ pub struct SelectState {
     pub count: usize,
     /// Scroll offset for long lists.
     pub scroll_offset: usize,
-    /// Maximum visible items.
+    /// Maximum visible items (rows, in a grid).
     pub max_visible: Option<usize>,
+    /// Number of grid columns (1 = a plain list).
+    pub columns: usize,
 }
 
 impl SelectState {
@@ -387,6 +477,7 @@ impl SelectState {
             count,
             scroll_offset: 0,
             max_visible: None,
+            columns: 1,
         }
     }
 
@@ -397,22 +488,48 @@ impl SelectState {
         self
     }
 
-    /// Move selection up.
+    /// Navigate a grid with the given number of columns.
+    #[must_use]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Move selection up (one row, in a grid).
     pub fn up(&mut self) {
-        if self.selected > 0 {
-            self.selected -= 1;
+        let columns = self.columns.max(1);
+        if self.selected >= columns {
+            self.selected -= columns;
             self.adjust_scroll();
         }
     }
 
-    /// Move selection down.
+    /// Move selection down (one row, in a grid). From a column the short
+    /// last row lacks, this lands on the last item.
     pub fn down(&mut self) {
-        if self.selected < self.count.saturating_sub(1) {
-            self.selected += 1;
+        let columns = self.columns.max(1);
+        let row = self.selected / columns;
+        if row < self.count.saturating_sub(1) / columns {
+            self.selected = (self.selected + columns).min(self.count - 1);
             self.adjust_scroll();
         }
     }
 
+    /// Move selection left within a grid row.
+    pub fn left(&mut self) {
+        if !self.selected.is_multiple_of(self.columns.max(1)) {
+            self.selected -= 1;
+        }
+    }
+
+    /// Move selection right within a grid row.
+    pub fn right(&mut self) {
+        let columns = self.columns.max(1);
+        if self.selected % columns < columns - 1 && self.selected + 1 < self.count {
+            self.selected += 1;
+        }
+    }
+
     /// Move to first item.
     pub fn first(&mut self) {
         self.selected = 0;
@@ -433,27 +550,43 @@ impl SelectState {
         }
     }
 
-    /// Page up (move by max_visible or 5 items).
+    /// Page up (move by max_visible or 5 items, or rows in a grid).
     pub fn page_up(&mut self) {
-        let page_size = self.max_visible.unwrap_or(5);
+        let page_size = self.max_visible.unwrap_or(5) * self.columns.max(1);
         self.selected = self.selected.saturating_sub(page_size);
         self.adjust_scroll();
     }
 
-    /// Page down (move by max_visible or 5 items).
+    /// Page down (move by max_visible or 5 items, or rows in a grid).
     pub fn page_down(&mut self) {
-        let page_size = self.max_visible.unwrap_or(5);
+        let page_size = self.max_visible.unwrap_or(5) * self.columns.max(1);
         self.selected = (self.selected + page_size).min(self.count.saturating_sub(1));
         self.adjust_scroll();
     }
 
-    /// Adjust scroll offset to keep selection visible.
+    /// Move the selection with the wheel: a row per vertical notch, and
+    /// across a grid row per horizontal one.
+    ///
+    /// Returns true if the wheel was handled.
+    pub fn handle_wheel(&mut self, wheel: &Wheel) -> bool {
+        match wheel.direction {
+            ScrollDirection::Up => self.up(),
+            ScrollDirection::Down => self.down(),
+            ScrollDirection::Left if self.columns > 1 => self.left(),
+            ScrollDirection::Right if self.columns > 1 => self.right(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Adjust scroll offset (in rows) to keep selection visible.
     fn adjust_scroll(&mut self) {
         if let Some(max) = self.max_visible {
-            if self.selected < self.scroll_offset {
-                self.scroll_offset = self.selected;
-            } else if self.selected >= self.scroll_offset + max {
-                self.scroll_offset = self.selected - max + 1;
+            let row = self.selected / self.columns.max(1);
+            if row < self.scroll_offset {
+                self.scroll_offset = row;
+            } else if row >= self.scroll_offset + max {
+                self.scroll_offset = row - max + 1;
             }
         }
     }
@@ -620,4 +753,62 @@ mod tests {
         assert_eq!(state.selected, 3);
         assert_eq!(state.scroll_offset, 1);
     }
+
+    #[test]
+    fn test_select_grid_render_lines() {
+        let props = SelectProps::new(vec!["a", "bb", "c", "d", "e"])
+            .columns(2)
+            .selected(3);
+        let lines = props.render_lines();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].0, "  a     bb");
+        assert_eq!(lines[1].0, "  c   ❯ d ");
+        assert_eq!(lines[2].0, "  e ");
+        assert!(lines[1].1.modifiers.contains(Modifier::BOLD));
+        assert!(!lines[2].1.modifiers.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn test_select_grid_scrolls_by_row() {
+        let props = SelectProps::new((1..=9).map(|n| n.to_string()))
+            .columns(3)
+            .max_visible(2)
+            .scroll_offset(1);
+        let lines = props.render_lines();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].0.contains('4'));
+        assert!(lines[1].0.contains('9'));
+    }
+
+    #[test]
+    fn test_select_state_grid_navigation() {
+        // 0 1 2
+        // 3 4 5
+        // 6
+        let mut state = SelectState::new(7).columns(3).max_visible(2);
+        state.right();
+        state.right();
+        state.right(); // Stays in the row
+        assert_eq!(state.selected, 2);
+
+        state.down();
+        assert_eq!(state.selected, 5);
+        state.down(); // Short last row
+        assert_eq!(state.selected, 6);
+        assert_eq!(state.scroll_offset, 1);
+        state.down();
+        assert_eq!(state.selected, 6);
+
+        state.right();
+        assert_eq!(state.selected, 6);
+        state.left();
+        assert_eq!(state.selected, 6);
+
+        state.up();
+        state.up();
+        assert_eq!(state.selected, 0);
+        assert_eq!(state.scroll_offset, 0);
+        state.left();
+        assert_eq!(state.selected, 0);
+    }
 }
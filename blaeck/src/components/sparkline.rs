@@ -9,13 +9,26 @@
 //! - Inline data summary in a dashboard
 //! - Small-scale data that doesn't need full chart
 //!
+//! ## Alerting
+//!
+//! Points can be colored individually: above a
+//! [`threshold`](SparklineProps::threshold) in the high color (red by
+//! default), or along a [`gradient`](SparklineProps::gradient) by value. A
+//! [`baseline`](SparklineProps::baseline) dims the points that fall below
+//! it, and [`minmax_labels`](SparklineProps::minmax_labels) puts the lowest
+//! and highest values at either end.
+//!
 //! ## See also
 //!
 //! - [`BarChart`](super::BarChart) — Full horizontal bar charts with labels
 //! - [`Progress`](super::Progress) — Single value (not a series)
+//! - [`charts`](super::charts) — The scale used to map values to heights
 
+use crate::components::charts::Scale;
+use crate::components::gradient::{ColorStop, GradientProps};
 use crate::element::{Component, Element};
-use crate::style::{Color, Style};
+use crate::palette::Palette;
+use crate::style::{Color, Modifier, Style};
 
 /// Block characters for sparkline from lowest to highest.
 const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
@@ -74,6 +87,15 @@ pub struct SparklineProps {
     pub label: Option<String>,
     /// Show min/max values.
     pub show_minmax: bool,
+    /// Scale mapping values to bar heights. If None, linear over the
+    /// effective min and max.
+    pub scale: Option<Scale>,
+    /// Reference value; points below it are dimmed.
+    pub baseline: Option<f64>,
+    /// Color points by value along these stops (0.0 = min, 1.0 = max).
+    pub gradient: Option<Vec<ColorStop>>,
+    /// Show the lowest value before the line and the highest after it.
+    pub minmax_labels: bool,
 }
 
 impl Default for SparklineProps {
@@ -90,6 +112,10 @@ impl Default for SparklineProps {
             threshold: None,
             label: None,
             show_minmax: false,
+            scale: None,
+            baseline: None,
+            gradient: None,
+            minmax_labels: false,
         }
     }
 }
@@ -160,7 +186,8 @@ impl SparklineProps {
         self
     }
 
-    /// Set threshold for high/low coloring.
+    /// Set threshold for high/low coloring: points above it take the high
+    /// color (red unless set), points at or below it the low color.
     #[must_use]
     pub fn threshold(mut self, threshold: f64) -> Self {
         self.threshold = Some(threshold);
@@ -181,6 +208,47 @@ impl SparklineProps {
         self
     }
 
+    /// Set the scale, e.g. [`Scale::log`] for spiky data.
+    #[must_use]
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Set a baseline; points below it are dimmed.
+    #[must_use]
+    pub fn baseline(mut self, baseline: f64) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /// Color points by value, from `low` at the minimum to `high` at the maximum.
+    #[must_use]
+    pub fn gradient(mut self, low: Color, high: Color) -> Self {
+        self.gradient = Some(vec![ColorStop::new(0.0, low), ColorStop::new(1.0, high)]);
+        self
+    }
+
+    /// Color points by value along custom gradient stops.
+    #[must_use]
+    pub fn gradient_stops(mut self, stops: Vec<ColorStop>) -> Self {
+        self.gradient = Some(stops);
+        self
+    }
+
+    /// Show the lowest value before the line and the highest after it.
+    #[must_use]
+    pub fn minmax_labels(mut self) -> Self {
+        self.minmax_labels = true;
+        self
+    }
+
+    /// Get the effective scale.
+    pub fn effective_scale(&self) -> Scale {
+        self.scale
+            .unwrap_or_else(|| Scale::linear(self.effective_min(), self.effective_max()))
+    }
+
     /// Get the effective min value.
     pub fn effective_min(&self) -> f64 {
         self.min
@@ -193,44 +261,91 @@ impl SparklineProps {
             .unwrap_or_else(|| self.data.iter().copied().fold(f64::NEG_INFINITY, f64::max))
     }
 
-    /// Render the sparkline string.
-    pub fn render_string(&self) -> String {
-        if self.data.is_empty() {
-            return String::new();
-        }
-
-        let min = self.effective_min();
-        let max = self.effective_max();
-        let range = max - min;
+    /// The character for each data point.
+    fn point_chars(&self) -> Vec<char> {
+        let scale = self.effective_scale();
         let chars = self.style.chars();
         let num_chars = chars.len();
 
-        let mut result = String::new();
+        self.data
+            .iter()
+            .map(|&value| {
+                // All values the same: the scale puts them at 0.5
+                let normalized = scale.normalize(value);
+
+                // Map to character index
+                let idx =
+                    ((normalized * (num_chars - 1) as f64).round() as usize).min(num_chars - 1);
+                chars[idx]
+            })
+            .collect()
+    }
+
+    /// Whether any point is styled on its own rather than with `color`.
+    fn has_point_styles(&self) -> bool {
+        self.threshold.is_some() || self.gradient.is_some() || self.baseline.is_some()
+    }
+
+    /// Style for a single point: threshold colors win over the gradient.
+    fn point_style(&self, value: f64, scale: &Scale, base: Style) -> Style {
+        let mut style = base;
+        let threshold_color = match self.threshold {
+            Some(limit) if value > limit => {
+                Some(self.high_color.unwrap_or_else(|| Palette::detect().error))
+            }
+            Some(_) => self.low_color,
+            None => None,
+        };
+        let gradient_color = self
+            .gradient
+            .as_ref()
+            .map(|stops| GradientProps::interpolate_color(stops, scale.normalize(value) as f32));
+        if let Some(color) = threshold_color.or(gradient_color) {
+            style = style.fg(color);
+        }
+        if self.baseline.is_some_and(|baseline| value < baseline) {
+            style = style.add_modifier(Modifier::DIM);
+        }
+        style
+    }
 
-        // Add label if present
+    /// Text before the points: the label, then the lowest value.
+    fn head(&self) -> String {
+        let mut head = String::new();
         if let Some(ref label) = self.label {
-            result.push_str(label);
-            result.push(' ');
+            head.push_str(label);
+            head.push(' ');
         }
-
-        // Generate sparkline
-        for &value in &self.data {
-            let normalized = if range == 0.0 {
-                0.5 // All values are the same
-            } else {
-                (value - min) / range
-            };
-
-            // Map to character index
-            let idx = ((normalized * (num_chars - 1) as f64).round() as usize).min(num_chars - 1);
-            result.push(chars[idx]);
+        if self.minmax_labels {
+            let low = self.data.iter().copied().fold(f64::INFINITY, f64::min);
+            head.push_str(&format!("{:.1} ", low));
         }
+        head
+    }
 
-        // Add min/max if requested
+    /// Text after the points: the highest value, then the scale range.
+    fn tail(&self) -> String {
+        let mut tail = String::new();
+        if self.minmax_labels {
+            let high = self.data.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            tail.push_str(&format!(" {:.1}", high));
+        }
         if self.show_minmax {
-            result.push_str(&format!(" ({:.1}-{:.1})", min, max));
+            let scale = self.effective_scale();
+            tail.push_str(&format!(" ({:.1}-{:.1})", scale.min, scale.max));
         }
+        tail
+    }
 
+    /// Render the sparkline string.
+    pub fn render_string(&self) -> String {
+        if self.data.is_empty() {
+            return String::new();
+        }
+
+        let mut result = self.head();
+        result.extend(self.point_chars());
+        result.push_str(&self.tail());
         result
     }
 }
@@ -262,8 +377,6 @@ impl Component for Sparkline {
     type Props = SparklineProps;
 
     fn render(props: &Self::Props) -> Element {
-        let content = props.render_string();
-
         let mut style = Style::new();
         if let Some(color) = props.color {
             style = style.fg(color);
@@ -272,7 +385,20 @@ impl Component for Sparkline {
             style = style.bg(bg);
         }
 
-        Element::styled_text(&content, style)
+        if props.data.is_empty() || !props.has_point_styles() {
+            return Element::styled_text(props.render_string(), style);
+        }
+
+        let scale = props.effective_scale();
+        let mut children = vec![Element::styled_text(props.head(), style)];
+        for (&value, ch) in props.data.iter().zip(props.point_chars()) {
+            children.push(Element::styled_text(
+                ch.to_string(),
+                props.point_style(value, &scale, style),
+            ));
+        }
+        children.push(Element::styled_text(props.tail(), style));
+        Element::Fragment(children)
     }
 }
 
@@ -396,6 +522,14 @@ mod tests {
         assert!(elem.is_text());
     }
 
+    #[test]
+    fn test_sparkline_log_scale() {
+        let props = SparklineProps::new(vec![1.0, 10.0, 100.0, 1000.0])
+            .scale(Scale::log(1.0, 1000.0))
+            .style(SparklineStyle::Ascii);
+        assert_eq!(props.render_string(), "_.=#");
+    }
+
     #[test]
     fn test_sparkline_range() {
         let props = SparklineProps::new(vec![50.0]).range(0.0, 100.0);
@@ -406,4 +540,52 @@ mod tests {
         // Should be roughly middle block character
         assert!(BLOCKS.contains(&ch));
     }
+
+    #[test]
+    fn test_sparkline_minmax_labels() {
+        let props = SparklineProps::new(vec![1.0, 8.0, 3.0])
+            .style(SparklineStyle::Ascii)
+            .minmax_labels();
+        assert_eq!(props.render_string(), "1.0 _#. 8.0");
+    }
+
+    #[test]
+    fn test_sparkline_threshold_and_baseline() {
+        let props = SparklineProps::new(vec![1.0, 5.0, 9.0])
+            .color(Color::Green)
+            .threshold(6.0)
+            .baseline(3.0);
+        let Element::Fragment(children) = Sparkline::render(&props) else {
+            panic!("Expected Fragment element");
+        };
+        let styles: Vec<Style> = children[1..4]
+            .iter()
+            .map(|child| match child {
+                Element::Text { style, .. } => *style,
+                _ => panic!("Expected Text element"),
+            })
+            .collect();
+        assert_eq!(styles[0].fg, Color::Green);
+        assert!(styles[0].modifiers.contains(Modifier::DIM));
+        assert_eq!(styles[1].fg, Color::Green);
+        assert!(!styles[1].modifiers.contains(Modifier::DIM));
+        assert_eq!(styles[2].fg, Palette::detect().error);
+    }
+
+    #[test]
+    fn test_sparkline_gradient() {
+        let props = SparklineProps::new(vec![0.0, 10.0]).gradient(Color::Black, Color::White);
+        assert_eq!(
+            props
+                .point_style(0.0, &props.effective_scale(), Style::new())
+                .fg,
+            Color::Rgb(0, 0, 0)
+        );
+        assert_eq!(
+            props
+                .point_style(10.0, &props.effective_scale(), Style::new())
+                .fg,
+            Color::Rgb(255, 255, 255)
+        );
+    }
 }
@@ -1,7 +1,10 @@
 //! Spinner component - animated loading indicator.
 //!
 //! The Spinner component displays an animated spinning indicator
-//! to show that an operation is in progress. Includes 15 built-in styles.
+//! to show that an operation is in progress. Includes 15 built-in styles,
+//! plus [`SpinnerFrames`] for arbitrary frame sets: your own frames and
+//! interval, optionally colored per frame, or one of the named presets from
+//! the cli-spinners collection (`SpinnerFrames::preset("dots12")`).
 //!
 //! ## When to use Spinner
 //!
@@ -15,7 +18,9 @@
 //! - [`Timer`](super::Timer) — Show elapsed/remaining time
 
 use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
 use crate::style::{Color, Modifier, Style};
+use unicode_width::UnicodeWidthStr;
 
 /// Built-in spinner animation styles.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -100,6 +105,19 @@ impl SpinnerStyle {
         }
     }
 
+    /// The style to draw with under `glyphs`: non-ASCII styles fall back
+    /// to [`SpinnerStyle::Line`] when only ASCII is available.
+    pub fn for_glyphs(self, glyphs: GlyphSet) -> Self {
+        match self {
+            SpinnerStyle::Line
+            | SpinnerStyle::GrowingDots
+            | SpinnerStyle::BouncingBar
+            | SpinnerStyle::Binary => self,
+            _ if glyphs.is_ascii() => SpinnerStyle::Line,
+            _ => self,
+        }
+    }
+
     /// Get the frame at the given index (wraps around).
     pub fn frame_at(&self, index: usize) -> &'static str {
         let frames = self.frames();
@@ -112,6 +130,83 @@ impl SpinnerStyle {
     }
 }
 
+/// An arbitrary set of spinner frames with its own interval.
+///
+/// Frames may be several cells wide; the spinner pads narrower frames to
+/// the widest so a following label doesn't jitter. Colors, if given, cycle
+/// alongside the frames.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpinnerFrames {
+    /// The animation frames.
+    pub frames: Vec<String>,
+    /// Milliseconds between frames.
+    pub interval_ms: u64,
+    /// Per-frame colors, cycled independently of the frame count.
+    pub colors: Vec<Color>,
+}
+
+impl SpinnerFrames {
+    /// Create a frame set from frames and an interval in milliseconds.
+    pub fn new<I, T>(frames: I, interval_ms: u64) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self {
+            frames: frames.into_iter().map(Into::into).collect(),
+            interval_ms: interval_ms.max(1),
+            colors: Vec::new(),
+        }
+    }
+
+    /// Look up a cli-spinners preset by name (e.g. `"dots2"`, `"bouncingBall"`).
+    pub fn preset(name: &str) -> Option<Self> {
+        PRESETS
+            .iter()
+            .find(|(n, _, _)| *n == name)
+            .map(|(_, interval, frames)| Self::new(frames.iter().copied(), *interval))
+    }
+
+    /// Names of all built-in presets, in registry order.
+    pub fn preset_names() -> impl Iterator<Item = &'static str> {
+        PRESETS.iter().map(|(name, _, _)| *name)
+    }
+
+    /// Set colors to cycle through, one per frame.
+    #[must_use]
+    pub fn colors<I: IntoIterator<Item = Color>>(mut self, colors: I) -> Self {
+        self.colors = colors.into_iter().collect();
+        self
+    }
+
+    /// Get the frame at the given index (wraps around).
+    pub fn frame_at(&self, index: usize) -> &str {
+        if self.frames.is_empty() {
+            return " ";
+        }
+        &self.frames[index % self.frames.len()]
+    }
+
+    /// Get the color for the frame at the given index, if any.
+    pub fn color_at(&self, index: usize) -> Option<Color> {
+        if self.colors.is_empty() {
+            return None;
+        }
+        Some(self.colors[index % self.colors.len()])
+    }
+
+    /// Display width of the widest frame.
+    pub fn width(&self) -> usize {
+        self.frames.iter().map(|f| f.width()).max().unwrap_or(1)
+    }
+}
+
+impl From<SpinnerStyle> for SpinnerFrames {
+    fn from(style: SpinnerStyle) -> Self {
+        Self::new(style.frames().iter().copied(), style.interval_ms())
+    }
+}
+
 /// Properties for the Spinner component.
 #[derive(Debug, Clone, Default)]
 pub struct SpinnerProps {
@@ -131,6 +226,11 @@ pub struct SpinnerProps {
     pub dim: bool,
     /// Custom frames (overrides style if set).
     pub custom_frames: Option<Vec<String>>,
+    /// Frame set with its own interval and colors (overrides
+    /// `custom_frames` and style if set).
+    pub frames: Option<SpinnerFrames>,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
 }
 
 impl SpinnerProps {
@@ -188,33 +288,79 @@ impl SpinnerProps {
         self
     }
 
-    /// Set custom animation frames.
+    /// Set custom animation frames, keeping the style's interval.
     #[must_use]
     pub fn custom_frames(mut self, frames: Vec<String>) -> Self {
         self.custom_frames = Some(frames);
         self
     }
 
+    /// Set a custom frame set with its own interval and colors.
+    #[must_use]
+    pub fn frames(mut self, frames: SpinnerFrames) -> Self {
+        self.frames = Some(frames);
+        self
+    }
+
+    /// Use a named cli-spinners preset; unknown names leave the style in place.
+    #[must_use]
+    pub fn preset(mut self, name: &str) -> Self {
+        if let Some(frames) = SpinnerFrames::preset(name) {
+            self.frames = Some(frames);
+        }
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
     /// Get the current frame string.
     pub fn current_frame(&self) -> &str {
-        if let Some(ref custom) = self.custom_frames {
+        if let Some(ref frames) = self.frames {
+            frames.frame_at(self.frame)
+        } else if let Some(ref custom) = self.custom_frames {
             if custom.is_empty() {
                 return " ";
             }
             &custom[self.frame % custom.len()]
         } else {
-            self.style.frame_at(self.frame)
+            self.style
+                .for_glyphs(GlyphSet::resolve(self.glyphs))
+                .frame_at(self.frame)
         }
     }
 
-    /// Get the recommended interval for the current style.
+    /// Get the recommended interval for the current frames.
     pub fn interval_ms(&self) -> u64 {
-        self.style.interval_ms()
+        match self.frames {
+            Some(ref frames) => frames.interval_ms,
+            None => self.style.interval_ms(),
+        }
+    }
+
+    /// The current frame, padded to the widest frame of a custom set.
+    fn padded_frame(&self) -> String {
+        let frame = self.current_frame();
+        let widest = match (&self.frames, &self.custom_frames) {
+            (Some(frames), _) => frames.width(),
+            (None, Some(custom)) => custom.iter().map(|f| f.width()).max().unwrap_or(1),
+            (None, None) => return frame.to_string(),
+        };
+        let pad = widest.saturating_sub(frame.width());
+        format!("{}{}", frame, " ".repeat(pad))
     }
 
     fn spinner_style(&self) -> Style {
         let mut style = Style::new();
-        if let Some(color) = self.color {
+        let frame_color = self
+            .frames
+            .as_ref()
+            .and_then(|frames| frames.color_at(self.frame));
+        if let Some(color) = frame_color.or(self.color) {
             style = style.fg(color);
         }
         if self.bold {
@@ -254,7 +400,7 @@ impl Component for Spinner {
     type Props = SpinnerProps;
 
     fn render(props: &Self::Props) -> Element {
-        let frame_str = props.current_frame();
+        let frame_str = props.padded_frame();
         let spinner_style = props.spinner_style();
 
         if let Some(ref label) = props.label {
@@ -265,7 +411,7 @@ impl Component for Spinner {
             // A more sophisticated version could use separate styles for spinner vs label
             Element::styled_text(&content, spinner_style)
         } else {
-            Element::styled_text(frame_str, spinner_style)
+            Element::styled_text(&frame_str, spinner_style)
         }
     }
 }
@@ -291,6 +437,343 @@ pub fn spinner_frame_interval(start: std::time::Instant, interval_ms: u64) -> us
     (elapsed_ms / interval_ms) as usize
 }
 
+/// Named frame sets from the cli-spinners collection: (name, interval, frames).
+const PRESETS: &[(&str, u64, &[&str])] = &[
+    (
+        "dots",
+        80,
+        &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+    ),
+    ("dots2", 80, &["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"]),
+    (
+        "dots3",
+        80,
+        &["⠋", "⠙", "⠚", "⠞", "⠖", "⠦", "⠴", "⠲", "⠳", "⠓"],
+    ),
+    (
+        "dots4",
+        80,
+        &[
+            "⠄", "⠆", "⠇", "⠋", "⠙", "⠸", "⠰", "⠠", "⠰", "⠸", "⠙", "⠋", "⠇", "⠆",
+        ],
+    ),
+    (
+        "dots5",
+        80,
+        &[
+            "⠋", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠦", "⠖", "⠒", "⠐", "⠐", "⠒", "⠓", "⠋",
+        ],
+    ),
+    (
+        "dots6",
+        80,
+        &[
+            "⠁", "⠉", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠤", "⠄", "⠄", "⠤", "⠴", "⠲", "⠒",
+            "⠂", "⠂", "⠒", "⠚", "⠙", "⠉", "⠁",
+        ],
+    ),
+    (
+        "dots7",
+        80,
+        &[
+            "⠈", "⠉", "⠋", "⠓", "⠒", "⠐", "⠐", "⠒", "⠖", "⠦", "⠤", "⠠", "⠠", "⠤", "⠦", "⠖", "⠒",
+            "⠐", "⠐", "⠒", "⠓", "⠋", "⠉", "⠈",
+        ],
+    ),
+    (
+        "dots8",
+        80,
+        &[
+            "⠁", "⠁", "⠉", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠤", "⠄", "⠄", "⠤", "⠠", "⠠",
+            "⠤", "⠦", "⠖", "⠒", "⠐", "⠐", "⠒", "⠓", "⠋", "⠉", "⠈", "⠈",
+        ],
+    ),
+    ("dots9", 80, &["⢹", "⢺", "⢼", "⣸", "⣇", "⡧", "⡗", "⡏"]),
+    ("dots10", 80, &["⢄", "⢂", "⢁", "⡁", "⡈", "⡐", "⡠"]),
+    ("dots11", 100, &["⠁", "⠂", "⠄", "⡀", "⢀", "⠠", "⠐", "⠈"]),
+    (
+        "dots12",
+        80,
+        &[
+            "⢀⠀", "⡀⠀", "⠄⠀", "⢂⠀", "⡂⠀", "⠅⠀", "⢃⠀", "⡃⠀", "⠍⠀", "⢋⠀", "⡋⠀", "⠍⠁", "⢋⠁", "⡋⠁",
+            "⠍⠉", "⠋⠉", "⠋⠉", "⠉⠙", "⠉⠙", "⠉⠩", "⠈⢙", "⠈⡙", "⢈⠩", "⡀⢙", "⠄⡙", "⢂⠩", "⡂⢘", "⠅⡘",
+            "⢃⠨", "⡃⢐", "⠍⡐", "⢋⠠", "⡋⢀", "⠍⡁", "⢋⠁", "⡋⠁", "⠍⠉", "⠋⠉", "⠋⠉", "⠉⠙", "⠉⠙", "⠉⠩",
+            "⠈⢙", "⠈⡙", "⠈⠩", "⠀⢙", "⠀⡙", "⠀⠩", "⠀⢘", "⠀⡘", "⠀⠨", "⠀⢐", "⠀⡐", "⠀⠠", "⠀⢀", "⠀⡀",
+        ],
+    ),
+    ("dots13", 80, &["⣼", "⣹", "⢻", "⠿", "⡟", "⣏", "⣧", "⣶"]),
+    ("line", 130, &["-", "\\", "|", "/"]),
+    ("line2", 100, &["⠂", "-", "–", "—", "–", "-"]),
+    ("pipe", 100, &["┤", "┘", "┴", "└", "├", "┌", "┬", "┐"]),
+    ("simpleDots", 400, &[".  ", ".. ", "...", "   "]),
+    (
+        "simpleDotsScrolling",
+        200,
+        &[".  ", ".. ", "...", " ..", "  .", "   "],
+    ),
+    ("star", 70, &["✶", "✸", "✹", "✺", "✹", "✷"]),
+    ("star2", 80, &["+", "x", "*"]),
+    (
+        "flip",
+        70,
+        &["_", "_", "_", "-", "`", "`", "'", "´", "-", "_", "_", "_"],
+    ),
+    ("hamburger", 100, &["☱", "☲", "☴"]),
+    (
+        "growVertical",
+        120,
+        &["▁", "▃", "▄", "▅", "▆", "▇", "▆", "▅", "▄", "▃"],
+    ),
+    (
+        "growHorizontal",
+        120,
+        &["▏", "▎", "▍", "▌", "▋", "▊", "▉", "▊", "▋", "▌", "▍", "▎"],
+    ),
+    ("balloon", 140, &[" ", ".", "o", "O", "@", "*", " "]),
+    ("balloon2", 120, &[".", "o", "O", "°", "O", "o", "."]),
+    ("noise", 100, &["▓", "▒", "░"]),
+    ("bounce", 120, &["⠁", "⠂", "⠄", "⠂"]),
+    ("boxBounce", 120, &["▖", "▘", "▝", "▗"]),
+    ("boxBounce2", 100, &["▌", "▀", "▐", "▄"]),
+    ("triangle", 50, &["◢", "◣", "◤", "◥"]),
+    (
+        "binary",
+        80,
+        &[
+            "010010", "001100", "100101", "111010", "111101", "010111", "101011", "111000",
+            "110011", "110101",
+        ],
+    ),
+    ("arc", 100, &["◜", "◠", "◝", "◞", "◡", "◟"]),
+    ("circle", 120, &["◡", "⊙", "◠"]),
+    ("squareCorners", 180, &["◰", "◳", "◲", "◱"]),
+    ("circleQuarters", 120, &["◴", "◷", "◶", "◵"]),
+    ("circleHalves", 50, &["◐", "◓", "◑", "◒"]),
+    ("squish", 100, &["╫", "╪"]),
+    ("toggle", 250, &["⊶", "⊷"]),
+    ("toggle2", 80, &["▫", "▪"]),
+    ("toggle3", 120, &["□", "■"]),
+    ("toggle4", 100, &["■", "□", "▪", "▫"]),
+    ("toggle5", 100, &["▮", "▯"]),
+    ("toggle6", 300, &["ဝ", "၀"]),
+    ("toggle7", 80, &["⦾", "⦿"]),
+    ("toggle8", 100, &["◍", "◌"]),
+    ("toggle9", 100, &["◉", "◎"]),
+    ("toggle10", 100, &["㊂", "㊀", "㊁"]),
+    ("toggle11", 50, &["⧇", "⧆"]),
+    ("toggle12", 120, &["☗", "☖"]),
+    ("toggle13", 80, &["=", "*", "-"]),
+    ("arrow", 100, &["←", "↖", "↑", "↗", "→", "↘", "↓", "↙"]),
+    (
+        "arrow2",
+        80,
+        &["⬆️ ", "↗️ ", "➡️ ", "↘️ ", "⬇️ ", "↙️ ", "⬅️ ", "↖️ "],
+    ),
+    (
+        "arrow3",
+        120,
+        &["▹▹▹▹▹", "▸▹▹▹▹", "▹▸▹▹▹", "▹▹▸▹▹", "▹▹▹▸▹", "▹▹▹▹▸"],
+    ),
+    (
+        "bouncingBar",
+        80,
+        &[
+            "[    ]", "[=   ]", "[==  ]", "[=== ]", "[====]", "[ ===]", "[  ==]", "[   =]",
+            "[    ]", "[   =]", "[  ==]", "[ ===]", "[====]", "[=== ]", "[==  ]", "[=   ]",
+        ],
+    ),
+    (
+        "bouncingBall",
+        80,
+        &[
+            "( ●    )",
+            "(  ●   )",
+            "(   ●  )",
+            "(    ● )",
+            "(     ●)",
+            "(    ● )",
+            "(   ●  )",
+            "(  ●   )",
+            "( ●    )",
+            "(●     )",
+        ],
+    ),
+    ("smiley", 200, &["😄 ", "😝 "]),
+    ("monkey", 300, &["🙈 ", "🙈 ", "🙉 ", "🙊 "]),
+    ("hearts", 100, &["💛 ", "💙 ", "💜 ", "💚 ", "❤️ "]),
+    (
+        "clock",
+        100,
+        &[
+            "🕛 ", "🕐 ", "🕑 ", "🕒 ", "🕓 ", "🕔 ", "🕕 ", "🕖 ", "🕗 ", "🕘 ", "🕙 ", "🕚 ",
+        ],
+    ),
+    ("earth", 180, &["🌍 ", "🌎 ", "🌏 "]),
+    (
+        "moon",
+        80,
+        &["🌑 ", "🌒 ", "🌓 ", "🌔 ", "🌕 ", "🌖 ", "🌗 ", "🌘 "],
+    ),
+    ("runner", 140, &["🚶 ", "🏃 "]),
+    (
+        "pong",
+        80,
+        &[
+            "▐⠂       ▌",
+            "▐⠈       ▌",
+            "▐ ⠂      ▌",
+            "▐ ⠠      ▌",
+            "▐  ⡀     ▌",
+            "▐  ⠠     ▌",
+            "▐   ⠂    ▌",
+            "▐   ⠈    ▌",
+            "▐    ⠂   ▌",
+            "▐    ⠠   ▌",
+            "▐     ⡀  ▌",
+            "▐     ⠠  ▌",
+            "▐      ⠂ ▌",
+            "▐      ⠈ ▌",
+            "▐       ⠂▌",
+            "▐       ⠠▌",
+            "▐       ⡀▌",
+            "▐      ⠠ ▌",
+            "▐      ⠂ ▌",
+            "▐     ⠈  ▌",
+            "▐     ⠂  ▌",
+            "▐    ⠠   ▌",
+            "▐    ⡀   ▌",
+            "▐   ⠠    ▌",
+            "▐   ⠂    ▌",
+            "▐  ⠈     ▌",
+            "▐  ⠂     ▌",
+            "▐ ⠠      ▌",
+            "▐ ⡀      ▌",
+            "▐⠠       ▌",
+        ],
+    ),
+    (
+        "shark",
+        120,
+        &[
+            "▐|\\____________▌",
+            "▐_|\\___________▌",
+            "▐__|\\__________▌",
+            "▐___|\\_________▌",
+            "▐____|\\________▌",
+            "▐_____|\\_______▌",
+            "▐______|\\______▌",
+            "▐_______|\\_____▌",
+            "▐________|\\____▌",
+            "▐_________|\\___▌",
+            "▐__________|\\__▌",
+            "▐___________|\\_▌",
+            "▐____________|\\▌",
+            "▐____________/|▌",
+            "▐___________/|_▌",
+            "▐__________/|__▌",
+            "▐_________/|___▌",
+            "▐________/|____▌",
+            "▐_______/|_____▌",
+            "▐______/|______▌",
+            "▐_____/|_______▌",
+            "▐____/|________▌",
+            "▐___/|_________▌",
+            "▐__/|__________▌",
+            "▐_/|___________▌",
+            "▐/|____________▌",
+        ],
+    ),
+    ("dqpb", 100, &["d", "q", "p", "b"]),
+    (
+        "weather",
+        100,
+        &[
+            "☀️ ", "☀️ ", "☀️ ", "🌤 ", "⛅️ ", "🌥 ", "☁️ ", "🌧 ", "🌨 ", "🌧 ", "🌨 ", "🌧 ", "🌨 ",
+            "⛈ ", "🌨 ", "🌧 ", "🌨 ", "☁️ ", "🌥 ", "⛅️ ", "🌤 ", "☀️ ", "☀️ ",
+        ],
+    ),
+    ("christmas", 400, &["🌲", "🎄"]),
+    (
+        "grenade",
+        80,
+        &[
+            "،  ", "′  ", " ´ ", " ‾ ", "  ⸌", "  ⸊", "  |", "  ⁎", "  ⁕", " ෴ ", "  ⁓", "   ",
+            "   ", "   ",
+        ],
+    ),
+    ("point", 125, &["∙∙∙", "●∙∙", "∙●∙", "∙∙●", "∙∙∙"]),
+    ("layer", 150, &["-", "=", "≡"]),
+    (
+        "betaWave",
+        80,
+        &[
+            "ρββββββ",
+            "βρβββββ",
+            "ββρββββ",
+            "βββρβββ",
+            "ββββρββ",
+            "βββββρβ",
+            "ββββββρ",
+        ],
+    ),
+    (
+        "fingerDance",
+        160,
+        &["🤘 ", "🤟 ", "🖖 ", "✋ ", "🤚 ", "👆 "],
+    ),
+    (
+        "mindblown",
+        160,
+        &[
+            "😐 ",
+            "😐 ",
+            "😮 ",
+            "😮 ",
+            "😦 ",
+            "😦 ",
+            "😧 ",
+            "😧 ",
+            "🤯 ",
+            "💥 ",
+            "✨ ",
+            "\u{3000} ",
+            "\u{3000} ",
+            "\u{3000} ",
+        ],
+    ),
+    ("speaker", 160, &["🔈 ", "🔉 ", "🔊 ", "🔉 "]),
+    ("orangePulse", 100, &["🔸 ", "🔶 ", "🟠 ", "🟠 ", "🔶 "]),
+    ("bluePulse", 100, &["🔹 ", "🔷 ", "🔵 ", "🔵 ", "🔷 "]),
+    (
+        "orangeBluePulse",
+        100,
+        &[
+            "🔸 ", "🔶 ", "🟠 ", "🟠 ", "🔶 ", "🔹 ", "🔷 ", "🔵 ", "🔵 ", "🔷 ",
+        ],
+    ),
+    (
+        "timeTravel",
+        100,
+        &[
+            "🕛 ", "🕚 ", "🕙 ", "🕘 ", "🕗 ", "🕖 ", "🕕 ", "🕔 ", "🕓 ", "🕒 ", "🕑 ", "🕐 ",
+        ],
+    ),
+    (
+        "aesthetic",
+        80,
+        &[
+            "▰▱▱▱▱▱▱",
+            "▰▰▱▱▱▱▱",
+            "▰▰▰▱▱▱▱",
+            "▰▰▰▰▱▱▱",
+            "▰▰▰▰▰▱▱",
+            "▰▰▰▰▰▰▱",
+            "▰▰▰▰▰▰▰",
+            "▰▱▱▱▱▱▱",
+        ],
+    ),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -363,6 +846,21 @@ mod tests {
         assert_eq!(props.current_frame(), "Y"); // 5 % 2 = 1
     }
 
+    #[test]
+    fn test_spinner_custom_frames_field_and_frame_set() {
+        let props = SpinnerProps {
+            custom_frames: Some(vec!["X".into(), "Y".into()]),
+            ..Default::default()
+        };
+        assert_eq!(props.current_frame(), "X");
+        assert_eq!(props.interval_ms(), SpinnerStyle::default().interval_ms());
+
+        // A frame set wins over plain custom frames
+        let props = props.frames(SpinnerFrames::new(["a", "b"], 250)).frame(1);
+        assert_eq!(props.current_frame(), "b");
+        assert_eq!(props.interval_ms(), 250);
+    }
+
     #[test]
     fn test_spinner_render_without_label() {
         let props = SpinnerProps::new().frame(0);
@@ -420,4 +918,46 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_spinner_frames_presets() {
+        let dots2 = SpinnerFrames::preset("dots2").unwrap();
+        assert_eq!(dots2.frame_at(0), "⣾");
+        assert_eq!(dots2.interval_ms, 80);
+        assert!(SpinnerFrames::preset("nope").is_none());
+
+        for name in SpinnerFrames::preset_names() {
+            let frames = SpinnerFrames::preset(name).unwrap();
+            assert!(
+                frames.frames.len() >= 2,
+                "Preset {} has too few frames",
+                name
+            );
+        }
+        assert!(SpinnerFrames::preset_names().count() >= 80);
+    }
+
+    #[test]
+    fn test_spinner_frames_interval_and_colors() {
+        let frames = SpinnerFrames::new(["a", "b"], 250).colors([Color::Red, Color::Blue]);
+        let props = SpinnerProps::new().frames(frames).frame(1);
+        assert_eq!(props.interval_ms(), 250);
+        assert_eq!(props.current_frame(), "b");
+        assert_eq!(props.spinner_style().fg, Color::Blue);
+
+        // A preset overrides the style's interval
+        let props = SpinnerProps::new().preset("star");
+        assert_eq!(props.interval_ms(), 70);
+    }
+
+    #[test]
+    fn test_spinner_pads_multi_char_frames() {
+        let props = SpinnerProps::new()
+            .frames(SpinnerFrames::new([".", "..", "..."], 100))
+            .label("wait");
+        match Spinner::render(&props) {
+            Element::Text { content, .. } => assert_eq!(content, ".   wait"),
+            _ => panic!("Expected Text element"),
+        }
+    }
 }
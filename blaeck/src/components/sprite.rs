@@ -0,0 +1,326 @@
+//! Sprite component - small pixel art drawn with half blocks.
+//!
+//! A Sprite is a grid of pixels, each a color or transparent. Every cell
+//! shows two pixels stacked with `▀`/`▄`, so a 16×16 sprite takes 16 columns
+//! and 8 rows. Nothing is sent to the terminal's graphics protocol, which
+//! makes it a good fit for logos and game sprites that must work anywhere.
+//!
+//! Pixels come from a 2D array of colors or from an XPM-like string: color
+//! definitions (`<char> <color>`), a blank line, then one row of characters
+//! per pixel row, with whitespace around each line ignored. Colors are
+//! `#rrggbb`, an ANSI name such as `red` or `darkgray`, or `none` for
+//! transparent.
+//!
+//! ## When to use Sprite
+//!
+//! - App logos in headers and splash screens
+//! - Characters and tiles in small games
+//!
+//! ## See also
+//!
+//! - [`Image`](super::Image) — Photos and large pictures via the terminal's
+//!   graphics protocol
+//! - [`Canvas`](super::Canvas) — Free-form drawing with braille dots
+//!
+//! # Example
+//!
+//! ```ignore
+//! let heart = SpriteProps::parse(
+//!     ". none\n\
+//!      r #e0245e\n\
+//!      \n\
+//!      .rr.rr.\n\
+//!      rrrrrrr\n\
+//!      .rrrrr.\n\
+//!      ..rrr..\n\
+//!      ...r...",
+//! )?;
+//!
+//! Element::node::<Sprite>(heart, vec![])
+//! ```
+
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::style::{Color, Style};
+use std::fmt;
+
+/// Error from parsing a sprite with [`SpriteProps::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpriteParseError(String);
+
+impl fmt::Display for SpriteParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for SpriteParseError {}
+
+/// Properties for the Sprite component.
+#[derive(Debug, Clone, Default)]
+pub struct SpriteProps {
+    /// Pixel rows, top first. `None` is transparent.
+    pub pixels: Vec<Vec<Option<Color>>>,
+    /// Color shown through transparent pixels (`None` = terminal background).
+    pub background: Option<Color>,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl SpriteProps {
+    /// Create a sprite from pixel rows, top first.
+    pub fn new(pixels: Vec<Vec<Option<Color>>>) -> Self {
+        Self {
+            pixels,
+            ..Default::default()
+        }
+    }
+
+    /// Create a sprite from rows of characters, coloring each with the
+    /// matching entry in `palette`. Characters not in the palette are
+    /// transparent.
+    pub fn from_art(art: &str, palette: &[(char, Color)]) -> Self {
+        let pixels = art
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| {
+                        palette
+                            .iter()
+                            .find(|(k, _)| *k == c)
+                            .map(|(_, color)| *color)
+                    })
+                    .collect()
+            })
+            .collect();
+        Self::new(pixels)
+    }
+
+    /// Parse an XPM-like sprite: `<char> <color>` definitions, a blank
+    /// line, then the pixel rows.
+    ///
+    /// Every character in the pixel rows must be defined. Whitespace around
+    /// each line is ignored, so the string can be indented.
+    pub fn parse(source: &str) -> Result<Self, SpriteParseError> {
+        let mut lines = source.lines().skip_while(|l| l.trim().is_empty());
+        let mut palette = Vec::new();
+        for line in lines.by_ref() {
+            let line = line.trim();
+            if line.is_empty() {
+                break;
+            }
+            let mut chars = line.chars();
+            let key = chars.next().unwrap_or(' ');
+            let name = chars.as_str().trim();
+            let color = parse_color(name)
+                .ok_or_else(|| SpriteParseError(format!("unknown color `{name}` for `{key}`")))?;
+            palette.push((key, color));
+        }
+
+        let mut rows: Vec<&str> = lines.map(str::trim).collect();
+        while rows.last().is_some_and(|l| l.is_empty()) {
+            rows.pop();
+        }
+        if rows.is_empty() {
+            return Err(SpriteParseError("sprite has no pixel rows".into()));
+        }
+
+        let pixels = rows
+            .iter()
+            .enumerate()
+            .map(|(y, row)| {
+                row.chars()
+                    .map(|c| {
+                        palette
+                            .iter()
+                            .find(|(k, _)| *k == c)
+                            .map(|(_, color)| *color)
+                            .ok_or_else(|| {
+                                SpriteParseError(format!("undefined pixel `{c}` in row {}", y + 1))
+                            })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self::new(pixels))
+    }
+
+    /// Show `color` through transparent pixels.
+    #[must_use]
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// Size in cells: (columns, rows).
+    pub fn size(&self) -> (usize, usize) {
+        let width = self.pixels.iter().map(Vec::len).max().unwrap_or(0);
+        (width, self.pixels.len().div_ceil(2))
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> Option<Color> {
+        self.pixels
+            .get(y)
+            .and_then(|row| row.get(x).copied().flatten())
+            .or(self.background)
+    }
+
+    /// The cells, top row first: each cell's character and style.
+    ///
+    /// ASCII glyph sets get `#` in the top pixel's color (or the bottom
+    /// one's, when the top is transparent) instead of half blocks.
+    pub fn rows(&self) -> Vec<Vec<(char, Style)>> {
+        let ascii = GlyphSet::resolve(self.glyphs).is_ascii();
+        let (width, height) = self.size();
+        (0..height)
+            .map(|row| {
+                (0..width)
+                    .map(|x| {
+                        let top = self.pixel(x, row * 2);
+                        let bottom = self.pixel(x, row * 2 + 1);
+                        match (top, bottom) {
+                            (None, None) => (' ', Style::new()),
+                            _ if ascii => ('#', Style::new().fg(top.or(bottom).unwrap())),
+                            (Some(t), Some(b)) if t == b => ('█', Style::new().fg(t)),
+                            (Some(t), Some(b)) => ('▀', Style::new().fg(t).bg(b)),
+                            (Some(t), None) => ('▀', Style::new().fg(t)),
+                            (None, Some(b)) => ('▄', Style::new().fg(b)),
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Parse `#rrggbb`, an ANSI color name, or `none`.
+fn parse_color(name: &str) -> Option<Option<Color>> {
+    if let Some(hex) = name.strip_prefix('#') {
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        return Some(Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?)));
+    }
+    let color = match name
+        .to_ascii_lowercase()
+        .replace(['-', '_', ' '], "")
+        .as_str()
+    {
+        "none" | "transparent" => return Some(None),
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "white" => Color::White,
+        "gray" | "grey" => Color::Gray,
+        "darkgray" | "darkgrey" => Color::DarkGray,
+        "lightred" => Color::LightRed,
+        "lightgreen" => Color::LightGreen,
+        "lightyellow" => Color::LightYellow,
+        "lightblue" => Color::LightBlue,
+        "lightmagenta" => Color::LightMagenta,
+        "lightcyan" => Color::LightCyan,
+        _ => return None,
+    };
+    Some(Some(color))
+}
+
+/// A component that shows pixel art with half-block characters.
+pub struct Sprite;
+
+impl Component for Sprite {
+    type Props = SpriteProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let lines = props
+            .rows()
+            .into_iter()
+            .map(|row| {
+                // Runs of cells in the same style become one span
+                let mut spans = Vec::new();
+                let mut run = String::new();
+                let mut run_style = Style::new();
+                for (c, style) in row {
+                    if style != run_style && !run.is_empty() {
+                        spans.push(Element::styled_text(std::mem::take(&mut run), run_style));
+                    }
+                    run_style = style;
+                    run.push(c);
+                }
+                spans.push(Element::styled_text(run, run_style));
+                Element::Fragment(spans)
+            })
+            .collect();
+        Element::Fragment(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(props: &SpriteProps) -> Vec<String> {
+        props
+            .rows()
+            .iter()
+            .map(|row| row.iter().map(|(c, _)| c).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_sprite_half_blocks() {
+        let props = SpriteProps::from_art("rr.\nrb.\n.b", &[('r', Color::Red), ('b', Color::Blue)])
+            .glyphs(GlyphSet::Unicode);
+        assert_eq!(props.size(), (3, 2));
+        assert_eq!(chars(&props), ["█▀ ", " ▀ "]);
+        let rows = props.rows();
+        assert_eq!(rows[0][1].1, Style::new().fg(Color::Red).bg(Color::Blue));
+        assert_eq!(rows[1][1].1, Style::new().fg(Color::Blue));
+
+        let ascii = props.clone().glyphs(GlyphSet::Ascii);
+        assert_eq!(chars(&ascii), ["## ", " # "]);
+        let filled = props.background(Color::Black);
+        assert_eq!(
+            filled.rows()[1][1],
+            ('▀', Style::new().fg(Color::Blue).bg(Color::Black))
+        );
+    }
+
+    #[test]
+    fn test_sprite_parse() {
+        let props = SpriteProps::parse(
+            "
+            . none
+            x #ff8000
+            o dark-gray
+
+            .x
+            oo
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            props.pixels,
+            vec![
+                vec![None, Some(Color::Rgb(255, 128, 0))],
+                vec![Some(Color::DarkGray), Some(Color::DarkGray)],
+            ]
+        );
+
+        let err = SpriteProps::parse("x red\n\nxy").unwrap_err();
+        assert_eq!(err.to_string(), "undefined pixel `y` in row 1");
+        assert!(SpriteProps::parse("x #12\n\nx").is_err());
+        assert!(SpriteProps::parse("x red\n").is_err());
+    }
+}
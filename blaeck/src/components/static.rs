@@ -5,6 +5,12 @@
 //! tasks, logs, or any output that shouldn't be re-rendered.
 //!
 //! Based on Ink's Static component pattern.
+//!
+//! Each item is printed once, the first time a frame contains its key, and
+//! is then left in the terminal's scrollback. Keep passing the full list (or
+//! only the new items) — items whose keys were already printed are skipped,
+//! so a long-running job that logs thousands of lines only writes each line
+//! once and redraws the live UI below it only when that changes.
 
 use crate::element::{Component, Element};
 use crate::layout::LayoutStyle;
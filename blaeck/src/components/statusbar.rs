@@ -3,6 +3,13 @@
 //! The StatusBar component displays multiple status segments inline,
 //! commonly used for showing git status, build status, or other indicators.
 //!
+//! Given a [`max_width`](StatusBarProps::max_width), a bar that doesn't fit
+//! gives up space one segment at a time, lowest
+//! [`priority`](StatusSegment::priority) first (the rightmost among equals):
+//! a segment with a [`min_width`](StatusSegment::min_width) is first cut
+//! down to it with an ellipsis, then dropped. The last segment left is cut
+//! to fit rather than dropped, so the bar always stays on one line.
+//!
 //! ## When to use StatusBar
 //!
 //! - Git branch and status display
@@ -15,7 +22,10 @@
 //! - [`KeyHints`](super::KeyHints) — Keyboard shortcuts (often at bottom)
 
 use crate::element::{Component, Element};
+use crate::palette::Palette;
 use crate::style::{Color, Modifier, Style};
+use crate::truncation::Truncation;
+use unicode_width::UnicodeWidthStr;
 
 /// A single segment in the status bar.
 #[derive(Debug, Clone)]
@@ -32,6 +42,11 @@ pub struct StatusSegment {
     pub bold: bool,
     /// Whether this segment should be dim.
     pub dim: bool,
+    /// How long the segment is kept when space runs out; lower goes first.
+    pub priority: u8,
+    /// Width the segment may be cut down to before it is dropped
+    /// (`None` = dropped whole).
+    pub min_width: Option<usize>,
 }
 
 impl StatusSegment {
@@ -44,6 +59,8 @@ impl StatusSegment {
             bg_color: None,
             bold: false,
             dim: false,
+            priority: 0,
+            min_width: None,
         }
     }
 
@@ -56,6 +73,8 @@ impl StatusSegment {
             bg_color: None,
             bold: false,
             dim: false,
+            priority: 0,
+            min_width: None,
         }
     }
 
@@ -94,6 +113,20 @@ impl StatusSegment {
         self
     }
 
+    /// Set the priority; lower-priority segments give way first.
+    #[must_use]
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Let the segment be cut down to `width` before it is dropped.
+    #[must_use]
+    pub fn min_width(mut self, width: usize) -> Self {
+        self.min_width = Some(width);
+        self
+    }
+
     /// Render to styled string.
     fn render_string(&self) -> String {
         match &self.icon {
@@ -199,6 +232,8 @@ pub struct StatusBarProps {
     pub suffix: Option<String>,
     /// Color for prefix/suffix.
     pub bracket_color: Option<Color>,
+    /// Width to fit the bar into (0 = no limit).
+    pub max_width: usize,
 }
 
 impl Default for StatusBarProps {
@@ -210,6 +245,7 @@ impl Default for StatusBarProps {
             prefix: None,
             suffix: None,
             bracket_color: Some(Color::DarkGray),
+            max_width: 0,
         }
     }
 }
@@ -295,6 +331,65 @@ impl StatusBarProps {
         self
     }
 
+    /// Set the width to fit the bar into (0 = no limit).
+    #[must_use]
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// The segments that fit in `max_width`, in order, as (index, text),
+    /// with text cut where a segment had to shrink.
+    fn fitted_segments(&self) -> Vec<(usize, String)> {
+        let mut shown: Vec<(usize, String)> = self
+            .segments
+            .iter()
+            .map(|s| s.render_string())
+            .enumerate()
+            .collect();
+        if self.max_width == 0 {
+            return shown;
+        }
+
+        let frame = self.prefix.as_deref().map_or(0, str::width)
+            + self.suffix.as_deref().map_or(0, str::width);
+        let sep = self.separator.as_str().width();
+        let truncation = Truncation::detect();
+        loop {
+            let width = frame
+                + shown.iter().map(|(_, text)| text.width()).sum::<usize>()
+                + sep * shown.len().saturating_sub(1);
+            if width <= self.max_width || shown.is_empty() {
+                return shown;
+            }
+            let overflow = width - self.max_width;
+
+            // Lowest priority gives way first, the rightmost among equals
+            let victim = (0..shown.len())
+                .rev()
+                .min_by_key(|&i| self.segments[shown[i].0].priority)
+                .unwrap_or(0);
+            let last = shown.len() == 1;
+            let (index, text) = &mut shown[victim];
+            let current = text.width();
+            let floor = if last {
+                Some(1)
+            } else {
+                self.segments[*index].min_width
+            };
+            match floor {
+                Some(floor) if current > floor => {
+                    let target = current.saturating_sub(overflow).max(floor);
+                    *text = truncation.truncate(text, target).into_owned();
+                }
+                _ if last => return shown,
+                _ => {
+                    shown.remove(victim);
+                }
+            }
+        }
+    }
+
     /// Render the status bar as a plain string (no ANSI codes).
     pub fn render_string(&self) -> String {
         if self.segments.is_empty() {
@@ -302,7 +397,11 @@ impl StatusBarProps {
         }
 
         let sep = self.separator.as_str();
-        let content: Vec<String> = self.segments.iter().map(|s| s.render_string()).collect();
+        let content: Vec<String> = self
+            .fitted_segments()
+            .into_iter()
+            .map(|(_, text)| text)
+            .collect();
         let joined = content.join(sep);
 
         match (&self.prefix, &self.suffix) {
@@ -359,7 +458,8 @@ impl Component for StatusBar {
         }
 
         // Segments
-        for (i, segment) in props.segments.iter().enumerate() {
+        for (i, (index, text)) in props.fitted_segments().into_iter().enumerate() {
+            let segment = &props.segments[index];
             // Separator
             if i > 0 && !sep.is_empty() {
                 let mut sep_style = Style::new();
@@ -384,7 +484,7 @@ impl Component for StatusBar {
                 style = style.add_modifier(Modifier::DIM);
             }
 
-            children.push(Element::styled_text(segment.render_string(), style));
+            children.push(Element::styled_text(text, style));
         }
 
         // Suffix
@@ -407,17 +507,17 @@ pub fn git_branch(branch: &str, color: Color) -> StatusSegment {
 
 /// Helper to create a status segment with a check mark.
 pub fn status_ok(text: &str) -> StatusSegment {
-    StatusSegment::with_icon(icons::CHECK, text).color(Color::Green)
+    StatusSegment::with_icon(icons::CHECK, text).color(Palette::detect().success)
 }
 
 /// Helper to create a status segment with a cross mark.
 pub fn status_error(text: &str) -> StatusSegment {
-    StatusSegment::with_icon(icons::CROSS, text).color(Color::Red)
+    StatusSegment::with_icon(icons::CROSS, text).color(Palette::detect().error)
 }
 
 /// Helper to create a status segment with a warning.
 pub fn status_warning(text: &str) -> StatusSegment {
-    StatusSegment::with_icon(icons::WARNING, text).color(Color::Yellow)
+    StatusSegment::with_icon(icons::WARNING, text).color(Palette::detect().warning)
 }
 
 #[cfg(test)]
@@ -570,4 +670,47 @@ mod tests {
         assert!(seg.render_string().contains("slow"));
         assert_eq!(seg.color, Some(Color::Yellow));
     }
+
+    #[test]
+    fn test_max_width_drops_lowest_priority() {
+        let props = StatusBarProps::new([
+            StatusSegment::new("main").priority(2),
+            StatusSegment::new("build").priority(0),
+            StatusSegment::new("tests").priority(1),
+        ])
+        .separator(StatusSeparator::Pipe);
+        assert_eq!(props.clone().max_width(18).render_string(), "main | tests");
+        assert_eq!(props.clone().max_width(11).render_string(), "main");
+        assert_eq!(props.max_width(100).render_string(), "main | build | tests");
+    }
+
+    #[test]
+    fn test_max_width_drops_rightmost_among_equals() {
+        let props = StatusBarProps::new(["aa", "bb", "cc"]).max_width(5);
+        assert_eq!(props.render_string(), "aa bb");
+    }
+
+    #[test]
+    fn test_max_width_truncates_to_min_width() {
+        let props = StatusBarProps::new([
+            StatusSegment::new("main").priority(1),
+            StatusSegment::new("feature/long-branch").min_width(6),
+        ]);
+        let bar = props.clone().max_width(15).render_string();
+        assert_eq!(bar.width(), 15);
+        assert!(bar.starts_with("main feature"));
+
+        // Below its min width the segment is dropped instead
+        assert_eq!(props.max_width(8).render_string(), "main");
+    }
+
+    #[test]
+    fn test_max_width_cuts_last_segment() {
+        let props = StatusBarProps::new(["a-very-long-segment"])
+            .square_brackets()
+            .max_width(10);
+        let bar = props.render_string();
+        assert_eq!(bar.width(), 10);
+        assert!(bar.starts_with("[a-ve") && bar.ends_with(']'));
+    }
 }
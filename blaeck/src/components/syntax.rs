@@ -31,14 +31,37 @@
 //!     vec![],
 //! )
 //! ```
+//!
+//! ## Other grammars and themes
+//!
+//! The highlighting itself comes from a [`SyntaxBackend`]. By default
+//! that's syntect with its bundled grammars and the theme picked by
+//! [`SyntaxTheme`]; a [`SyntectBackend`] can add `.sublime-syntax`
+//! grammars and load `.tmTheme` themes from disk. Other highlighters,
+//! such as tree-sitter, plug in by implementing the trait.
+//!
+//! ```ignore
+//! let backend = SyntectBackend::new(SyntaxTheme::OceanDark)
+//!     .load_syntaxes("assets/syntaxes")?
+//!     .load_theme("assets/themes/Nord.tmTheme")?;
+//!
+//! Element::node::<SyntaxHighlight>(
+//!     SyntaxHighlightProps::new(code).language("zig").backend(backend),
+//!     vec![],
+//! )
+//! ```
 
 use crate::element::{Component, Element};
 use crate::style::{Color, Modifier, Style};
-use std::sync::LazyLock;
+use std::fmt;
+use std::path::Path;
+use std::rc::Rc;
+use std::sync::{Arc, LazyLock};
 use syntect::easy::HighlightLines;
 use syntect::highlighting::{self, ThemeSet};
 use syntect::parsing::SyntaxSet;
 use syntect::util::LinesWithEndings;
+use syntect::LoadingError;
 
 // Cache syntax sets to avoid expensive reloading on every render
 static SYNTAX_SET: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
@@ -102,8 +125,118 @@ pub enum LineNumberStyle {
     WithSeparator,
 }
 
-/// Properties for the SyntaxHighlight component.
+/// A line of highlighted code, as styled spans.
+pub type HighlightedLine = Vec<(String, Style)>;
+
+/// Turns source code into styled lines.
+///
+/// Implement this to highlight with something other than syntect's
+/// bundled grammars, e.g. tree-sitter.
+pub trait SyntaxBackend {
+    /// Highlight `code`, returning one entry per line. Spans don't
+    /// include line endings. `language` is the name or file extension
+    /// given to [`SyntaxHighlightProps::language`].
+    fn highlight(&self, code: &str, language: Option<&str>) -> Vec<HighlightedLine>;
+}
+
+/// A syntect backend with its own grammars and theme.
 #[derive(Debug, Clone)]
+pub struct SyntectBackend {
+    /// Grammars; `None` = syntect's bundled set.
+    syntaxes: Option<Arc<SyntaxSet>>,
+    /// Theme to color with.
+    theme: Arc<highlighting::Theme>,
+}
+
+impl SyntectBackend {
+    /// A backend with the bundled grammars and one of the built-in themes.
+    pub fn new(theme: SyntaxTheme) -> Self {
+        Self {
+            syntaxes: None,
+            theme: Arc::new(theme.get_theme(&THEME_SET).clone()),
+        }
+    }
+
+    /// Use this theme, e.g. one parsed elsewhere.
+    #[must_use]
+    pub fn theme(mut self, theme: highlighting::Theme) -> Self {
+        self.theme = Arc::new(theme);
+        self
+    }
+
+    /// Use these grammars instead of the bundled set.
+    #[must_use]
+    pub fn syntaxes(mut self, syntaxes: SyntaxSet) -> Self {
+        self.syntaxes = Some(Arc::new(syntaxes));
+        self
+    }
+
+    /// Load a `.tmTheme` file.
+    pub fn load_theme(self, path: impl AsRef<Path>) -> Result<Self, LoadingError> {
+        Ok(self.theme(ThemeSet::get_theme(path)?))
+    }
+
+    /// Add the `.sublime-syntax` grammars found under `folder` to the
+    /// current ones.
+    pub fn load_syntaxes(self, folder: impl AsRef<Path>) -> Result<Self, LoadingError> {
+        let mut builder = self.syntax_set().clone().into_builder();
+        builder.add_from_folder(folder, true)?;
+        Ok(self.syntaxes(builder.build()))
+    }
+
+    /// The grammars in use.
+    pub fn syntax_set(&self) -> &SyntaxSet {
+        self.syntaxes.as_deref().unwrap_or(&SYNTAX_SET)
+    }
+}
+
+impl SyntaxBackend for SyntectBackend {
+    fn highlight(&self, code: &str, language: Option<&str>) -> Vec<HighlightedLine> {
+        highlight_with(self.syntax_set(), &self.theme, code, language)
+    }
+}
+
+/// Highlight `code` with syntect, falling back to plain text for lines it
+/// can't parse.
+fn highlight_with(
+    ps: &SyntaxSet,
+    theme: &highlighting::Theme,
+    code: &str,
+    language: Option<&str>,
+) -> Vec<HighlightedLine> {
+    // Find syntax for language
+    let syntax = if let Some(lang) = language {
+        ps.find_syntax_by_token(lang)
+            .or_else(|| ps.find_syntax_by_extension(lang))
+    } else {
+        // Try to detect from first line
+        ps.find_syntax_by_first_line(code)
+    }
+    .unwrap_or_else(|| ps.find_syntax_plain_text());
+
+    let mut highlighter = HighlightLines::new(syntax, theme);
+    LinesWithEndings::from(code)
+        .map(|line| match highlighter.highlight_line(line, ps) {
+            Ok(ranges) => ranges
+                .into_iter()
+                .map(|(style, text)| (strip_line_ending(text), syntect_to_blaeck_style(style)))
+                .filter(|(text, _)| !text.is_empty())
+                .collect(),
+            // Fallback to plain text
+            Err(_) => vec![(strip_line_ending(line), Style::new())],
+        })
+        .collect()
+}
+
+/// Remove a trailing newline/carriage return for display.
+fn strip_line_ending(text: &str) -> String {
+    text.trim_end_matches('\n')
+        .trim_end_matches('\r')
+        .to_string()
+}
+
+/// Properties for the SyntaxHighlight component.
+#[derive(Clone)]
 pub struct SyntaxHighlightProps {
     /// Source code to highlight.
     pub code: String,
@@ -123,6 +256,25 @@ pub struct SyntaxHighlightProps {
     pub trim_trailing: bool,
     /// Maximum width (for wrapping, 0 = no limit).
     pub max_width: usize,
+    /// Highlighter to use instead of syntect with [`theme`](Self::theme).
+    pub backend: Option<Rc<dyn SyntaxBackend>>,
+}
+
+impl fmt::Debug for SyntaxHighlightProps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyntaxHighlightProps")
+            .field("code", &self.code)
+            .field("language", &self.language)
+            .field("theme", &self.theme)
+            .field("line_numbers", &self.line_numbers)
+            .field("start_line", &self.start_line)
+            .field("line_number_color", &self.line_number_color)
+            .field("bg_color", &self.bg_color)
+            .field("trim_trailing", &self.trim_trailing)
+            .field("max_width", &self.max_width)
+            .field("backend", &self.backend.as_ref().map(|_| "<backend>"))
+            .finish()
+    }
 }
 
 impl Default for SyntaxHighlightProps {
@@ -137,6 +289,7 @@ impl Default for SyntaxHighlightProps {
             bg_color: None,
             trim_trailing: true,
             max_width: 0,
+            backend: None,
         }
     }
 }
@@ -205,6 +358,27 @@ impl SyntaxHighlightProps {
         self.bg_color = Some(color);
         self
     }
+
+    /// Highlight with `backend` instead of the built-in syntect setup.
+    #[must_use]
+    pub fn backend(mut self, backend: impl SyntaxBackend + 'static) -> Self {
+        self.backend = Some(Rc::new(backend));
+        self
+    }
+
+    /// The code as highlighted lines, from the backend if one is set.
+    pub fn highlighted_lines(&self) -> Vec<HighlightedLine> {
+        let language = self.language.as_deref();
+        match &self.backend {
+            Some(backend) => backend.highlight(&self.code, language),
+            None => highlight_with(
+                &SYNTAX_SET,
+                self.theme.get_theme(&THEME_SET),
+                &self.code,
+                language,
+            ),
+        }
+    }
 }
 
 /// Convert syntect color to blaeck color.
@@ -243,23 +417,6 @@ impl Component for SyntaxHighlight {
             return Element::Empty;
         }
 
-        let ps = &*SYNTAX_SET;
-        let ts = &*THEME_SET;
-
-        // Find syntax for language
-        let syntax = if let Some(ref lang) = props.language {
-            ps.find_syntax_by_token(lang)
-                .or_else(|| ps.find_syntax_by_extension(lang))
-        } else {
-            // Try to detect from first line
-            ps.find_syntax_by_first_line(&props.code)
-        }
-        .unwrap_or_else(|| ps.find_syntax_plain_text());
-
-        // Get theme
-        let theme = props.theme.get_theme(ts);
-
-        let mut highlighter = HighlightLines::new(syntax, theme);
         let mut lines: Vec<Element> = Vec::new();
 
         // Calculate line number width
@@ -267,7 +424,7 @@ impl Component for SyntaxHighlight {
         let end_line = props.start_line + total_lines;
         let line_num_width = end_line.to_string().len();
 
-        for (i, line) in LinesWithEndings::from(&props.code).enumerate() {
+        for (i, spans) in props.highlighted_lines().into_iter().enumerate() {
             let line_num = props.start_line + i;
             let mut line_segments: Vec<Element> = Vec::new();
 
@@ -311,42 +468,15 @@ impl Component for SyntaxHighlight {
                 }
             }
 
-            // Highlight the line
-            let highlighted = highlighter.highlight_line(line, ps);
-            match highlighted {
-                Ok(ranges) => {
-                    let ranges: Vec<_> = ranges.into_iter().collect();
-                    let range_count = ranges.len();
-                    for (idx, (style, text)) in ranges.into_iter().enumerate() {
-                        let mut text = text.to_string();
-                        // Remove trailing newline/carriage return for display
-                        if text.ends_with('\n') {
-                            text.pop();
-                        }
-                        if text.ends_with('\r') {
-                            text.pop();
-                        }
-                        // Only trim the last segment's trailing whitespace
-                        if props.trim_trailing && idx == range_count - 1 {
-                            text = text.trim_end().to_string();
-                        }
-                        // Keep whitespace segments - they're important for spacing
-                        if !text.is_empty() {
-                            let blaeck_style = with_bg(syntect_to_blaeck_style(style));
-                            line_segments.push(Element::styled_text(&text, blaeck_style));
-                        }
-                    }
+            let span_count = spans.len();
+            for (idx, (mut text, style)) in spans.into_iter().enumerate() {
+                // Only trim the last segment's trailing whitespace
+                if props.trim_trailing && idx == span_count - 1 {
+                    text = text.trim_end().to_string();
                 }
-                Err(_) => {
-                    // Fallback to plain text
-                    let mut text = line.to_string();
-                    if text.ends_with('\n') {
-                        text.pop();
-                    }
-                    if props.trim_trailing {
-                        text = text.trim_end().to_string();
-                    }
-                    line_segments.push(Element::styled_text(&text, with_bg(Style::new())));
+                // Keep whitespace segments - they're important for spacing
+                if !text.is_empty() {
+                    line_segments.push(Element::styled_text(&text, with_bg(style)));
                 }
             }
 
@@ -461,4 +591,59 @@ mod tests {
         let color = syntect_to_blaeck_color(c);
         assert_eq!(color, Color::Rgb(255, 128, 64));
     }
+
+    struct UpperBackend;
+
+    impl SyntaxBackend for UpperBackend {
+        fn highlight(&self, code: &str, _language: Option<&str>) -> Vec<HighlightedLine> {
+            code.lines()
+                .map(|line| vec![(line.to_uppercase(), Style::new().fg(Color::Red))])
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_syntax_custom_backend() {
+        let props = SyntaxHighlightProps::new("one  \ntwo")
+            .backend(UpperBackend)
+            .line_numbers(LineNumberStyle::Simple);
+        match SyntaxHighlight::render(&props) {
+            Element::Fragment(lines) => {
+                assert_eq!(lines.len(), 2);
+                match &lines[0] {
+                    Element::Fragment(spans) => match &spans[1] {
+                        Element::Text { content, style, .. } => {
+                            assert_eq!(content, "ONE");
+                            assert_eq!(style.fg, Color::Red);
+                        }
+                        _ => panic!("expected text"),
+                    },
+                    _ => panic!("expected line number and code"),
+                }
+            }
+            _ => panic!("expected lines"),
+        }
+    }
+
+    #[test]
+    fn test_syntect_backend_matches_builtin() {
+        let code = "fn main() {\n    let x = 1;\n}\n";
+        let props = SyntaxHighlightProps::new(code)
+            .language("rs")
+            .theme(SyntaxTheme::SolarizedDark);
+        let backend = SyntectBackend::new(SyntaxTheme::SolarizedDark);
+        assert_eq!(
+            backend.highlight(code, Some("rs")),
+            props.highlighted_lines()
+        );
+        assert!(backend
+            .highlight(code, Some("rs"))
+            .iter()
+            .flatten()
+            .all(|(text, _)| !text.ends_with('\n')));
+
+        assert!(SyntectBackend::new(SyntaxTheme::Monokai)
+            .load_theme("does/not/exist.tmTheme")
+            .is_err());
+    }
 }
@@ -16,11 +16,24 @@
 //! - [`Select`](super::Select) — If you just need a selectable list
 //! - [`BarChart`](super::BarChart) — Visual comparison of values
 
+use crate::components::checkbox::CheckboxStyle;
 use crate::element::{Component, Element};
+use crate::gestures::Wheel;
+use crate::glyphs::GlyphSet;
+use crate::input::Key;
+use crate::resize::ResizeState;
 use crate::style::{Color, Style};
+use crate::truncation::Truncation;
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::collections::BTreeSet;
+use unicode_width::UnicodeWidthStr;
 
 use super::BorderStyle;
 
+/// Narrowest an [`Auto`](ColumnWidth::Auto) column shrinks to when the
+/// table is too wide.
+pub const MIN_AUTO_WIDTH: usize = 3;
+
 /// Width specification for table columns.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum ColumnWidth {
@@ -28,9 +41,65 @@ pub enum ColumnWidth {
     Fixed(u16),
     /// Percentage of total table width (0.0 to 1.0).
     Percent(f32),
-    /// Auto-size based on content (uses flex-grow).
+    /// Width of the widest cell, header included. When the table has a
+    /// `width` and is still too wide once fit columns reach their `min`,
+    /// these columns shrink too, down to [`MIN_AUTO_WIDTH`], and longer
+    /// cells end in an ellipsis.
     #[default]
     Auto,
+    /// Width of the widest cell, clamped to `min..=max`; longer cells end
+    /// in an ellipsis. When the table has a `width`, leftover space is
+    /// shared between these columns by `weight`, and they shrink toward
+    /// `min` if the table is too wide.
+    Fit {
+        /// Narrowest the column gets.
+        min: u16,
+        /// Widest the column is measured to.
+        max: u16,
+        /// Share of the leftover width (0 = none).
+        weight: u16,
+    },
+}
+
+impl ColumnWidth {
+    /// Fit the widest cell, between `min` and `max`, with a weight of 1.
+    pub fn fit(min: u16, max: u16) -> Self {
+        ColumnWidth::Fit {
+            min,
+            max,
+            weight: 1,
+        }
+    }
+
+    /// Fit the widest cell, never narrower than `min`.
+    pub fn at_least(min: u16) -> Self {
+        ColumnWidth::Fit {
+            min,
+            max: u16::MAX,
+            weight: 1,
+        }
+    }
+
+    /// Fit the widest cell, never wider than `max`, without taking any
+    /// leftover width. Shrinks no narrower than [`MIN_AUTO_WIDTH`] (or
+    /// `max`, if smaller) when the table runs out of room.
+    pub fn at_most(max: u16) -> Self {
+        ColumnWidth::Fit {
+            min: max.min(MIN_AUTO_WIDTH as u16),
+            max,
+            weight: 0,
+        }
+    }
+
+    /// Set the share of leftover width for a [`Fit`](ColumnWidth::Fit)
+    /// column. Other widths are returned unchanged.
+    #[must_use]
+    pub fn weight(self, weight: u16) -> Self {
+        match self {
+            ColumnWidth::Fit { min, max, .. } => ColumnWidth::Fit { min, max, weight },
+            other => other,
+        }
+    }
 }
 
 /// Text alignment within a cell.
@@ -228,10 +297,20 @@ pub struct TableProps {
     pub selected_bg_color: Option<Color>,
     /// Show row dividers.
     pub row_dividers: bool,
-    /// Total table width (optional, for percentage calculations).
+    /// Total table width (optional, for percentages and fit columns).
     pub width: Option<u16>,
     /// Background color for all cells (lowest priority).
     pub bg_color: Option<Color>,
+    /// Column being resized, marked under the header.
+    pub resizing: Option<usize>,
+    /// Checkbox column style, shown before the first column (`None` = no
+    /// checkboxes).
+    pub checkboxes: Option<CheckboxStyle>,
+    /// Indices of checked rows.
+    pub checked: BTreeSet<usize>,
+    /// Name that mouse wheel events over the table are routed by (see
+    /// [`Blaeck::scroll_target_at`](crate::Blaeck::scroll_target_at)).
+    pub scroll_id: Option<String>,
 }
 
 impl Default for TableProps {
@@ -255,6 +334,10 @@ impl Default for TableProps {
             row_dividers: false,
             width: None,
             bg_color: None,
+            resizing: None,
+            checkboxes: None,
+            checked: BTreeSet::new(),
+            scroll_id: None,
         }
     }
 }
@@ -392,6 +475,83 @@ impl TableProps {
         self
     }
 
+    /// Mark a column as being resized (see [`ResizeState`](crate::resize::ResizeState)).
+    ///
+    /// The header divider is shown and drawn heavy under that column. `None`
+    /// leaves the table unchanged.
+    #[must_use]
+    pub fn resizing(mut self, column: Option<usize>) -> Self {
+        self.resizing = column;
+        self
+    }
+
+    /// Show a checkbox column in `style`, for multi-select.
+    #[must_use]
+    pub fn checkboxes(mut self, style: CheckboxStyle) -> Self {
+        self.checkboxes = Some(style);
+        self
+    }
+
+    /// Name the table so wheel events over it are routed to its handler.
+    #[must_use]
+    pub fn scroll_id(mut self, id: impl Into<String>) -> Self {
+        self.scroll_id = Some(id.into());
+        self
+    }
+
+    /// Set the checked rows.
+    #[must_use]
+    pub fn checked<I: IntoIterator<Item = usize>>(mut self, rows: I) -> Self {
+        self.checked = rows.into_iter().collect();
+        self
+    }
+
+    /// Take the selected row and checked rows from `state`.
+    #[must_use]
+    pub fn state(mut self, state: &TableState) -> Self {
+        self.selected = (state.row_count > 0).then_some(state.selected);
+        self.checked = state.checked.clone();
+        self
+    }
+
+    /// Width of the checkbox column and its spacing, or 0 without one.
+    fn checkbox_width(&self) -> usize {
+        self.checkboxes.map_or(0, |style| {
+            let (checked, _) = style.for_glyphs(GlyphSet::detect()).chars();
+            checked.width() + self.column_spacing as usize
+        })
+    }
+
+    /// The checkbox cell before a row: checked or not for data rows, blank
+    /// for the header (`None`), and empty without checkboxes.
+    fn checkbox_cell(&self, row: Option<usize>) -> String {
+        let Some(style) = self.checkboxes else {
+            return String::new();
+        };
+        let (checked, unchecked) = style.for_glyphs(GlyphSet::detect()).chars();
+        let spacing = " ".repeat(self.column_spacing as usize);
+        match row {
+            Some(i) if self.checked.contains(&i) => format!("{checked}{spacing}"),
+            Some(_) => format!("{unchecked}{spacing}"),
+            None => " ".repeat(self.checkbox_width()),
+        }
+    }
+
+    /// A [`ResizeState`] for these columns, starting from their current
+    /// widths.
+    pub fn resize_state(&self) -> ResizeState {
+        ResizeState::columns(self.column_widths().into_iter().map(|w| w as u16))
+    }
+
+    /// Use the column sizes from `resize` as fixed widths, and mark its
+    /// active column.
+    #[must_use]
+    pub fn resize(self, resize: &ResizeState) -> Self {
+        let active = resize.active();
+        self.fixed_widths(resize.sizes().iter().copied())
+            .resizing(active)
+    }
+
     /// Get the number of columns (from widths, header, or first row).
     fn num_columns(&self) -> usize {
         if !self.widths.is_empty() {
@@ -414,6 +574,96 @@ impl TableProps {
     fn get_width(&self, col: usize) -> ColumnWidth {
         self.widths.get(col).copied().unwrap_or_default()
     }
+
+    /// Width of the widest cell in a column, header included.
+    fn widest_cell(&self, col: usize) -> usize {
+        self.header
+            .iter()
+            .chain(&self.rows)
+            .filter_map(|row| row.cells.get(col))
+            .map(|cell| cell.content.width())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The width of each column in characters, with [`ColumnWidth::Fit`]
+    /// columns grown or shrunk to the table `width` when it is set.
+    pub fn column_widths(&self) -> Vec<usize> {
+        let mut widths: Vec<usize> = (0..self.num_columns())
+            .map(|col| match self.get_width(col) {
+                ColumnWidth::Fixed(w) => w as usize,
+                ColumnWidth::Percent(p) => match self.width {
+                    Some(table_width) => (table_width as f32 * p).floor() as usize,
+                    None => self.widest_cell(col),
+                },
+                ColumnWidth::Auto => self.widest_cell(col),
+                ColumnWidth::Fit { min, max, .. } => self
+                    .widest_cell(col)
+                    .clamp(min as usize, max.max(min) as usize),
+            })
+            .collect();
+        if let Some(table_width) = self.width {
+            self.fit_to_width(&mut widths, table_width as usize);
+        }
+        widths
+    }
+
+    /// Grow or shrink the fit columns so the table is `table_width` wide.
+    fn fit_to_width(&self, widths: &mut [usize], table_width: usize) {
+        let spacing = self.column_spacing as usize * widths.len().saturating_sub(1);
+        let used = widths.iter().sum::<usize>() + spacing + self.checkbox_width();
+
+        if used > table_width {
+            // Take from the widest fit column first, down to its min, then
+            // from the widest auto column
+            let mut excess = used - table_width;
+            for auto_pass in [false, true] {
+                let floor = |col: usize| match (self.get_width(col), auto_pass) {
+                    (ColumnWidth::Fit { min, .. }, false) => Some(min as usize),
+                    (ColumnWidth::Auto, true) => Some(MIN_AUTO_WIDTH),
+                    _ => None,
+                };
+                while excess > 0 {
+                    let shrinkable = (0..widths.len())
+                        .filter(|&col| floor(col).is_some_and(|min| widths[col] > min))
+                        .max_by_key(|&col| (widths[col], std::cmp::Reverse(col)));
+                    match shrinkable {
+                        Some(col) => widths[col] -= 1,
+                        None => break,
+                    }
+                    excess -= 1;
+                }
+            }
+            return;
+        }
+
+        let weights: Vec<usize> = (0..widths.len())
+            .map(|col| match self.get_width(col) {
+                ColumnWidth::Fit { weight, .. } => weight as usize,
+                _ => 0,
+            })
+            .collect();
+        let total: usize = weights.iter().sum();
+        if total == 0 {
+            return;
+        }
+        let leftover = table_width - used;
+        let mut given = 0;
+        for (width, &weight) in widths.iter_mut().zip(&weights) {
+            let share = leftover * weight / total;
+            *width += share;
+            given += share;
+        }
+        // Rounding leftovers go to the first weighted columns
+        for (width, _) in widths
+            .iter_mut()
+            .zip(&weights)
+            .filter(|(_, &weight)| weight > 0)
+            .take(leftover - given)
+        {
+            *width += 1;
+        }
+    }
 }
 
 /// A component that displays data in a table format.
@@ -460,25 +710,29 @@ impl Component for Table {
             return Element::text("");
         }
 
+        let widths = props.column_widths();
         let mut lines: Vec<String> = Vec::new();
 
         // Render header
         if let Some(ref header) = props.header {
-            lines.push(render_row_string(header, props));
+            lines.push(props.checkbox_cell(None) + &render_row_string(header, &widths, props));
 
             // Add divider after header
-            if props.row_dividers || props.border_style != BorderStyle::None {
-                lines.push(render_divider_string(props));
+            if props.row_dividers
+                || props.border_style != BorderStyle::None
+                || props.resizing.is_some()
+            {
+                lines.push(render_divider_string(&widths, props));
             }
         }
 
         // Render data rows
         for (i, row) in props.rows.iter().enumerate() {
-            lines.push(render_row_string(row, props));
+            lines.push(props.checkbox_cell(Some(i)) + &render_row_string(row, &widths, props));
 
             // Add row divider (except after last row)
             if props.row_dividers && i < props.rows.len() - 1 {
-                lines.push(render_divider_string(props));
+                lines.push(render_divider_string(&widths, props));
             }
         }
 
@@ -495,14 +749,13 @@ impl Component for Table {
 }
 
 /// Render a single row as a string.
-fn render_row_string(row: &Row, props: &TableProps) -> String {
-    let num_cols = props.num_columns();
+fn render_row_string(row: &Row, widths: &[usize], props: &TableProps) -> String {
     let spacing = " ".repeat(props.column_spacing as usize);
     let mut parts: Vec<String> = Vec::new();
 
-    for col in 0..num_cols {
+    for (col, &width) in widths.iter().enumerate() {
         let cell = row.cells.get(col);
-        let cell_text = render_cell_content(cell, col, props);
+        let cell_text = render_cell_content(cell, col, width, props);
         parts.push(cell_text);
     }
 
@@ -510,30 +763,35 @@ fn render_row_string(row: &Row, props: &TableProps) -> String {
 }
 
 /// Render cell content (just the padded text, no styling).
-fn render_cell_content(cell: Option<&TableCell>, col: usize, props: &TableProps) -> String {
+fn render_cell_content(
+    cell: Option<&TableCell>,
+    col: usize,
+    width: usize,
+    props: &TableProps,
+) -> String {
     let content = cell.map(|c| c.content.as_str()).unwrap_or("");
     let align = cell
         .and_then(|c| c.align)
         .unwrap_or_else(|| props.get_align(col));
 
-    // Get column width for padding
-    let width = match props.get_width(col) {
-        ColumnWidth::Fixed(w) => w as usize,
-        ColumnWidth::Percent(p) => {
-            if let Some(table_width) = props.width {
-                (table_width as f32 * p).floor() as usize
-            } else {
-                content.chars().count()
-            }
-        }
-        ColumnWidth::Auto => content.chars().count(),
-    };
-
-    // Pad content to width
-    let content_len = content.chars().count();
-    if content_len >= width {
-        // Truncate if too long
-        content.chars().take(width).collect::<String>()
+    // Pad content to width, in display columns
+    let content_len = content.width();
+    if content_len > width
+        && width > 0
+        && matches!(
+            props.get_width(col),
+            ColumnWidth::Fit { .. } | ColumnWidth::Auto
+        )
+    {
+        // Fit and auto columns mark the cut with an ellipsis
+        let cut = Truncation::detect().truncate(content, width);
+        let padding = width.saturating_sub(cut.width());
+        format!("{cut}{}", " ".repeat(padding))
+    } else if content_len >= width {
+        // Truncate if too long; a wide character that doesn't fit leaves a gap
+        let cut = Truncation::new("").truncate(content, width);
+        let padding = width.saturating_sub(cut.width());
+        format!("{cut}{}", " ".repeat(padding))
     } else {
         let padding = width - content_len;
         match align {
@@ -554,42 +812,49 @@ fn render_cell_content(cell: Option<&TableCell>, col: usize, props: &TableProps)
 }
 
 /// Render a divider line as a string.
-fn render_divider_string(props: &TableProps) -> String {
-    // Calculate total width from column widths and spacing
-    let num_cols = props.num_columns();
-    let total_width: usize = (0..num_cols)
-        .map(|col| match props.get_width(col) {
-            ColumnWidth::Fixed(w) => w as usize,
-            ColumnWidth::Percent(p) => {
-                if let Some(table_width) = props.width {
-                    (table_width as f32 * p).floor() as usize
-                } else {
-                    10 // Default width
-                }
-            }
-            ColumnWidth::Auto => 10, // Default width
-        })
-        .sum::<usize>()
-        + (num_cols.saturating_sub(1)) * props.column_spacing as usize;
-
-    "─".repeat(total_width)
+///
+/// The column being resized, if any, is drawn with a heavy line.
+fn render_divider_string(widths: &[usize], props: &TableProps) -> String {
+    let mut divider = "─".repeat(props.checkbox_width());
+    for (col, &width) in widths.iter().enumerate() {
+        if col > 0 {
+            divider.push_str(&"─".repeat(props.column_spacing as usize));
+        }
+        let line = if props.resizing == Some(col) {
+            "━"
+        } else {
+            "─"
+        };
+        divider.push_str(&line.repeat(width));
+    }
+    divider
 }
 
 /// State for table selection.
+///
+/// Besides the selected row (the cursor), rows can be checked for bulk
+/// actions: Space toggles the selected row, Shift+Up/Down checks a range
+/// from where it started, and Ctrl+A checks or unchecks every row.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableState {
     /// Currently selected row index.
     pub selected: usize,
     /// Total number of rows.
     pub row_count: usize,
+    /// Indices of checked rows.
+    pub checked: BTreeSet<usize>,
+    /// Where a Shift range started, and the rows checked before it.
+    #[cfg_attr(feature = "session", serde(skip))]
+    range: Option<(usize, BTreeSet<usize>)>,
 }
 
 impl TableState {
     /// Create new state for a table with the given row count.
     pub fn new(row_count: usize) -> Self {
         Self {
-            selected: 0,
             row_count,
+            ..Default::default()
         }
     }
 
@@ -616,6 +881,108 @@ impl TableState {
     pub fn last(&mut self) {
         self.selected = self.row_count.saturating_sub(1);
     }
+
+    /// Whether a row is checked.
+    pub fn is_checked(&self, index: usize) -> bool {
+        self.checked.contains(&index)
+    }
+
+    /// Indices of the checked rows, in order.
+    pub fn checked_rows(&self) -> Vec<usize> {
+        self.checked.iter().copied().collect()
+    }
+
+    /// Check or uncheck a row.
+    pub fn toggle(&mut self, index: usize) {
+        if index < self.row_count && !self.checked.remove(&index) {
+            self.checked.insert(index);
+        }
+    }
+
+    /// Check or uncheck the selected row.
+    pub fn toggle_selected(&mut self) {
+        self.toggle(self.selected);
+    }
+
+    /// Check every row.
+    pub fn check_all(&mut self) {
+        self.checked = (0..self.row_count).collect();
+    }
+
+    /// Uncheck every row.
+    pub fn clear_checked(&mut self) {
+        self.checked.clear();
+        self.range = None;
+    }
+
+    /// Check every row, or uncheck them all if they already are.
+    pub fn toggle_all(&mut self) {
+        if self.checked.len() == self.row_count {
+            self.clear_checked();
+        } else {
+            self.check_all();
+        }
+    }
+
+    /// Move the selection by one row, checking the range from where the
+    /// extension started to the new row. Moving back shrinks the range.
+    pub fn extend(&mut self, down: bool) {
+        let (anchor, base) = match &self.range {
+            Some(range) => range.clone(),
+            None => {
+                let range = (self.selected, self.checked.clone());
+                self.range = Some(range.clone());
+                range
+            }
+        };
+        if down {
+            self.down();
+        } else {
+            self.up();
+        }
+        let (from, to) = (anchor.min(self.selected), anchor.max(self.selected));
+        self.checked = base.into_iter().chain(from..=to).collect();
+    }
+
+    /// Handle Up/Down/Home/End to move, Space to toggle the selected row,
+    /// Shift+Up/Down to check a range, and Ctrl+A to toggle all.
+    ///
+    /// Returns true if the key was handled.
+    pub fn handle_key(&mut self, key: &Key) -> bool {
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        if !(shift && matches!(key.code, KeyCode::Up | KeyCode::Down)) {
+            self.range = None;
+        }
+        match key.code {
+            KeyCode::Up | KeyCode::Down if shift => self.extend(key.code == KeyCode::Down),
+            KeyCode::Up => self.up(),
+            KeyCode::Down => self.down(),
+            KeyCode::Home => self.first(),
+            KeyCode::End => self.last(),
+            KeyCode::Char(' ') => self.toggle_selected(),
+            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.toggle_all()
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Move the selection a row per notch of a vertical wheel.
+    ///
+    /// Returns true if the wheel was vertical.
+    pub fn handle_wheel(&mut self, wheel: &Wheel) -> bool {
+        if wheel.is_horizontal() {
+            return false;
+        }
+        self.range = None;
+        if wheel.delta() < 0 {
+            self.up();
+        } else {
+            self.down();
+        }
+        true
+    }
 }
 
 #[cfg(test)]
@@ -783,6 +1150,153 @@ mod tests {
         assert!(elem.is_text());
     }
 
+    #[test]
+    fn test_table_resizing_marks_column() {
+        let props = TableProps::new(vec![vec!["a", "b"]])
+            .header(vec!["A", "B"])
+            .fixed_widths([3, 2])
+            .column_spacing(1)
+            .resizing(Some(1));
+        match Table::render(&props) {
+            Element::Text { content, .. } => {
+                assert_eq!(content.lines().nth(1), Some("────━━"));
+            }
+            _ => panic!("expected text"),
+        }
+    }
+
+    fn render_lines(props: &TableProps) -> Vec<String> {
+        match Table::render(props) {
+            Element::Text { content, .. } => content.lines().map(String::from).collect(),
+            _ => panic!("expected text"),
+        }
+    }
+
+    #[test]
+    fn test_table_auto_widths_align_rows() {
+        let props = TableProps::new(vec![vec!["alice", "1"], vec!["bo", "22"]])
+            .header(vec!["Name", "N"])
+            .column_spacing(1)
+            .row_dividers();
+        assert_eq!(props.column_widths(), vec![5, 2]);
+        assert_eq!(
+            render_lines(&props),
+            vec!["Name  N ", "────────", "alice 1 ", "────────", "bo    22"]
+        );
+    }
+
+    #[test]
+    fn test_table_fit_clamps_and_ellipsizes() {
+        let props = TableProps::new(vec![vec!["a very long name", "x"]])
+            .widths([ColumnWidth::fit(2, 6), ColumnWidth::fit(3, 10)])
+            .column_spacing(1);
+        assert_eq!(props.column_widths(), vec![6, 3]);
+        assert_eq!(render_lines(&props), vec!["a ver… x  "]);
+    }
+
+    #[test]
+    fn test_table_widths_count_display_columns() {
+        let props = TableProps::new(vec![vec!["名前", "名前"], vec!["ab", "cd"]])
+            .widths([ColumnWidth::fit(1, 10), ColumnWidth::Fixed(3)])
+            .column_spacing(1);
+        assert_eq!(props.column_widths(), vec![4, 3]);
+        // The wide character that doesn't fit the fixed column leaves a gap
+        assert_eq!(render_lines(&props), vec!["名前 名 ", "ab   cd "]);
+    }
+
+    #[test]
+    fn test_table_fit_shares_leftover_by_weight() {
+        let props = TableProps::new(vec![vec!["ab", "cd", "ef"]])
+            .widths([
+                ColumnWidth::Fixed(4),
+                ColumnWidth::fit(0, 10),
+                ColumnWidth::fit(0, 10).weight(2),
+            ])
+            .column_spacing(1)
+            .width(20);
+        // 4 + 2 + 2 + 2 spacing leaves 10: 3 and 6, plus one left over
+        assert_eq!(props.column_widths(), vec![4, 6, 8]);
+    }
+
+    #[test]
+    fn test_table_fit_shrinks_to_width() {
+        let props = TableProps::new(vec![vec!["abcdefgh", "abcd", "xyz"]])
+            .widths([
+                ColumnWidth::fit(3, 20),
+                ColumnWidth::fit(3, 20),
+                ColumnWidth::Fixed(3),
+            ])
+            .column_spacing(1)
+            .width(12);
+        assert_eq!(props.column_widths(), vec![3, 4, 3]);
+        assert_eq!(render_lines(&props), vec!["ab… abcd xyz"]);
+    }
+
+    #[test]
+    fn test_table_auto_shrinks_after_fit() {
+        let props = TableProps::new(vec![vec!["identifier", "description", "ok"]])
+            .widths([
+                ColumnWidth::Auto,
+                ColumnWidth::at_least(6),
+                ColumnWidth::at_most(4),
+            ])
+            .column_spacing(1)
+            .width(18);
+        // Fit columns give way first, down to their floor, then the auto column
+        assert_eq!(props.column_widths(), vec![7, 6, 3]);
+        assert_eq!(render_lines(&props), vec!["identi… descr… ok "]);
+    }
+
+    #[test]
+    fn test_table_resize_state_round_trip() {
+        let props = TableProps::new(vec![vec!["abc", "de"]]).column_spacing(1);
+        let mut resize = props.resize_state();
+        assert_eq!(resize.sizes(), &[3, 2]);
+        resize.enter(1);
+        resize.adjust(2);
+        let props = props.resize(&resize);
+        assert_eq!(props.column_widths(), vec![3, 4]);
+        assert_eq!(props.resizing, Some(1));
+    }
+
+    #[test]
+    fn test_table_state_checks_rows() {
+        let mut state = TableState::new(5);
+        state.handle_key(&Key::new(KeyCode::Char(' ')));
+        state.handle_key(&Key::new(KeyCode::Down));
+        state.handle_key(&Key::new(KeyCode::Down));
+        let shift_down = Key::with_modifiers(KeyCode::Down, KeyModifiers::SHIFT);
+        state.handle_key(&shift_down);
+        state.handle_key(&shift_down);
+        assert_eq!(state.checked_rows(), vec![0, 2, 3, 4]);
+
+        // Moving back shrinks the range, keeping rows checked before it
+        state.handle_key(&Key::with_modifiers(KeyCode::Up, KeyModifiers::SHIFT));
+        assert_eq!(state.checked_rows(), vec![0, 2, 3]);
+
+        let ctrl_a = Key::with_modifiers(KeyCode::Char('a'), KeyModifiers::CONTROL);
+        state.handle_key(&ctrl_a);
+        assert_eq!(state.checked.len(), 5);
+        state.handle_key(&ctrl_a);
+        assert!(state.checked.is_empty());
+    }
+
+    #[test]
+    fn test_table_checkbox_column() {
+        let mut state = TableState::new(2);
+        state.toggle(1);
+        let props = TableProps::new(vec![vec!["a"], vec!["b"]])
+            .header(vec!["H"])
+            .column_spacing(1)
+            .checkboxes(CheckboxStyle::Bracket)
+            .row_dividers()
+            .state(&state);
+        assert_eq!(
+            render_lines(&props),
+            vec!["    H", "─────", "[ ] a", "─────", "[x] b"]
+        );
+    }
+
     #[test]
     fn test_column_width_default() {
         let width = ColumnWidth::default();
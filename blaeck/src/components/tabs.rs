@@ -16,6 +16,7 @@
 
 use crate::element::{Component, Element};
 use crate::style::{Color, Modifier, Style};
+use crate::truncation::Truncation;
 
 /// A single tab item.
 #[derive(Debug, Clone)]
@@ -127,6 +128,8 @@ pub struct TabsProps {
     pub selected_bold: bool,
     /// Whether to underline selected tab.
     pub selected_underline: bool,
+    /// Longest label in columns; longer labels are cut (`None` = no limit).
+    pub max_label_width: Option<usize>,
 }
 
 impl Default for TabsProps {
@@ -144,6 +147,7 @@ impl Default for TabsProps {
             padding: 1,
             selected_bold: true,
             selected_underline: false,
+            max_label_width: None,
         }
     }
 }
@@ -231,6 +235,21 @@ impl TabsProps {
         self
     }
 
+    /// Cut labels longer than `width` columns, with the app's ellipsis.
+    #[must_use]
+    pub fn max_label_width(mut self, width: usize) -> Self {
+        self.max_label_width = Some(width);
+        self
+    }
+
+    /// A tab's label as shown, cut to `max_label_width`.
+    pub fn label_for<'a>(&self, tab: &'a Tab) -> std::borrow::Cow<'a, str> {
+        match self.max_label_width {
+            Some(width) => Truncation::detect().truncate(&tab.label, width),
+            None => tab.label.as_str().into(),
+        }
+    }
+
     /// No divider between tabs.
     #[must_use]
     pub fn no_divider(mut self) -> Self {
@@ -284,10 +303,11 @@ impl Component for Tabs {
             }
 
             // Format tab - use brackets for selected, spaces for unselected (same width)
+            let label = props.label_for(tab);
             if is_selected {
-                parts.push(format!("[{}{}{}]", padding, tab.label, padding));
+                parts.push(format!("[{}{}{}]", padding, label, padding));
             } else {
-                parts.push(format!(" {}{}{} ", padding, tab.label, padding));
+                parts.push(format!(" {}{}{} ", padding, label, padding));
             }
         }
 
@@ -308,6 +328,7 @@ impl Component for Tabs {
 
 /// State for tab navigation.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
 pub struct TabsState {
     /// Currently selected tab index.
     pub selected: usize,
@@ -406,6 +427,16 @@ mod tests {
         assert_eq!(props.divider, TabDivider::Dot);
     }
 
+    #[test]
+    fn test_tabs_max_label_width() {
+        let props = TabsProps::new(vec!["Overview", "Logs"]).max_label_width(5);
+        assert_eq!(props.label_for(&props.tabs[1]), "Logs");
+        let Element::Text { content, .. } = Tabs::render(&props) else {
+            panic!("expected text");
+        };
+        assert_eq!(content, "[ Over… ] │   Logs  ");
+    }
+
     #[test]
     fn test_tabs_state_navigation() {
         let mut state = TabsState::new(4);
@@ -0,0 +1,555 @@
+//! TaskList component - a spinner checklist of tasks and subtasks.
+//!
+//! The TaskList is the familiar installer output: one line per task, with
+//! a spinner while it runs, a check when it's done and a cross when it
+//! fails, how long it took, and its subtasks indented beneath it.
+//! [`TaskListState`] keeps the tasks by id and stamps start and end times
+//! as they move through [`TaskItemStatus`].
+//!
+//! ## When to use TaskList
+//!
+//! - Install, setup and deploy scripts
+//! - Any sequence of steps where the user waits and watches
+//!
+//! ## See also
+//!
+//! - [`MultiProgress`](super::MultiProgress) — Jobs with measurable progress
+//! - [`Spinner`](super::Spinner) — A single spinner
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut tasks = TaskListState::new();
+//! tasks.add("deps", "Install dependencies");
+//! tasks.add_child("deps", "fetch", "Fetch packages");
+//! tasks.add_child("deps", "link", "Link binaries");
+//! tasks.add("build", "Build");
+//!
+//! tasks.start("deps");
+//! tasks.start("fetch");
+//! // ... later
+//! tasks.done("fetch");
+//!
+//! // In render (redraw every spinner interval while running):
+//! Element::node::<TaskList>(tasks.to_props(), vec![])
+//! // Renders:
+//! // ⠋ Install dependencies
+//! //   ✓ Fetch packages (2s)
+//! //   ○ Link binaries
+//! // ○ Build
+//! ```
+
+use crate::components::spinner::SpinnerStyle;
+use crate::components::timer::TimeFormat;
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::palette::Palette;
+use crate::style::{Color, Modifier, Style};
+use std::time::{Duration, Instant};
+
+/// Where a task is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskItemStatus {
+    /// Not started.
+    #[default]
+    Pending,
+    /// Working, shown with a spinner.
+    Running,
+    /// Completed.
+    Done,
+    /// Failed.
+    Failed,
+    /// Not needed, so not run.
+    Skipped,
+}
+
+impl TaskItemStatus {
+    /// Whether the task has ended, in any way.
+    pub fn is_finished(self) -> bool {
+        matches!(
+            self,
+            TaskItemStatus::Done | TaskItemStatus::Failed | TaskItemStatus::Skipped
+        )
+    }
+}
+
+/// A task in a [`TaskList`].
+#[derive(Debug, Clone)]
+pub struct TaskItem {
+    /// Unique id, used to update the task.
+    pub id: String,
+    /// Text shown for the task.
+    pub label: String,
+    /// Where the task is.
+    pub status: TaskItemStatus,
+    /// Detail shown after the label, e.g. an error or a skip reason.
+    pub message: Option<String>,
+    /// When the task started.
+    pub started: Option<Instant>,
+    /// How long the task ran, once it has started (fixed when it ends).
+    pub elapsed: Option<Duration>,
+    /// Subtasks, shown indented.
+    pub children: Vec<TaskItem>,
+}
+
+impl TaskItem {
+    /// Create a pending task.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            status: TaskItemStatus::Pending,
+            message: None,
+            started: None,
+            elapsed: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Set the status.
+    #[must_use]
+    pub fn status(mut self, status: TaskItemStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Set the message.
+    #[must_use]
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Set the elapsed time.
+    #[must_use]
+    pub fn elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed = Some(elapsed);
+        self
+    }
+
+    /// Add a subtask.
+    #[must_use]
+    pub fn child(mut self, child: TaskItem) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    fn find_mut(&mut self, id: &str) -> Option<&mut TaskItem> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.children
+            .iter_mut()
+            .find_map(|child| child.find_mut(id))
+    }
+
+    fn find(&self, id: &str) -> Option<&TaskItem> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(id))
+    }
+
+    /// The task with running times brought up to now.
+    fn snapshot(&self, now: Instant) -> Self {
+        let mut item = self.clone();
+        if item.status == TaskItemStatus::Running {
+            item.elapsed = item.started.map(|started| now - started);
+        }
+        item.children = self.children.iter().map(|c| c.snapshot(now)).collect();
+        item
+    }
+}
+
+/// The tasks of a [`TaskList`] and the times they ran.
+#[derive(Debug, Clone)]
+pub struct TaskListState {
+    /// Top-level tasks, in order.
+    pub items: Vec<TaskItem>,
+    /// When the list was created, for the spinner frame.
+    pub created: Instant,
+}
+
+impl Default for TaskListState {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            created: Instant::now(),
+        }
+    }
+}
+
+impl TaskListState {
+    /// Create an empty list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a pending top-level task.
+    pub fn add(&mut self, id: impl Into<String>, label: impl Into<String>) {
+        self.items.push(TaskItem::new(id, label));
+    }
+
+    /// Add a pending subtask under `parent`. Returns false if there is no
+    /// task with that id.
+    pub fn add_child(
+        &mut self,
+        parent: &str,
+        id: impl Into<String>,
+        label: impl Into<String>,
+    ) -> bool {
+        match self.get_mut(parent) {
+            Some(parent) => {
+                parent.children.push(TaskItem::new(id, label));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// A task by id, at any depth.
+    pub fn get(&self, id: &str) -> Option<&TaskItem> {
+        self.items.iter().find_map(|item| item.find(id))
+    }
+
+    /// A task by id, at any depth, to change.
+    pub fn get_mut(&mut self, id: &str) -> Option<&mut TaskItem> {
+        self.items.iter_mut().find_map(|item| item.find_mut(id))
+    }
+
+    /// Start a task, starting its clock.
+    pub fn start(&mut self, id: &str) {
+        if let Some(item) = self.get_mut(id) {
+            item.status = TaskItemStatus::Running;
+            item.started = Some(Instant::now());
+            item.elapsed = Some(Duration::ZERO);
+        }
+    }
+
+    fn end(&mut self, id: &str, status: TaskItemStatus, message: Option<String>) {
+        if let Some(item) = self.get_mut(id) {
+            item.status = status;
+            item.elapsed = item.started.map(|started| started.elapsed());
+            if message.is_some() {
+                item.message = message;
+            }
+        }
+    }
+
+    /// Mark a task done, stopping its clock.
+    pub fn done(&mut self, id: &str) {
+        self.end(id, TaskItemStatus::Done, None);
+    }
+
+    /// Mark a task failed, with the reason as its message.
+    pub fn fail(&mut self, id: &str, message: impl Into<String>) {
+        self.end(id, TaskItemStatus::Failed, Some(message.into()));
+    }
+
+    /// Mark a task skipped, with the reason as its message.
+    pub fn skip(&mut self, id: &str, reason: impl Into<String>) {
+        self.end(id, TaskItemStatus::Skipped, Some(reason.into()));
+    }
+
+    /// Set a task's message.
+    pub fn set_message(&mut self, id: &str, message: impl Into<String>) {
+        if let Some(item) = self.get_mut(id) {
+            item.message = Some(message.into());
+        }
+    }
+
+    /// Whether every top-level task has ended.
+    pub fn is_finished(&self) -> bool {
+        self.items.iter().all(|item| item.status.is_finished())
+    }
+
+    /// Whether any task, at any depth, failed.
+    pub fn has_failed(&self) -> bool {
+        fn failed(item: &TaskItem) -> bool {
+            item.status == TaskItemStatus::Failed || item.children.iter().any(failed)
+        }
+        self.items.iter().any(failed)
+    }
+
+    /// Props for rendering the list as it is now.
+    pub fn to_props(&self) -> TaskListProps {
+        let now = Instant::now();
+        let mut props = TaskListProps::new(self.items.iter().map(|item| item.snapshot(now)));
+        let interval = props.spinner.interval_ms().max(1) as u128;
+        props.frame = (self.created.elapsed().as_millis() / interval) as usize;
+        props
+    }
+}
+
+/// Properties for the TaskList component.
+#[derive(Debug, Clone)]
+pub struct TaskListProps {
+    /// Top-level tasks, in order.
+    pub items: Vec<TaskItem>,
+    /// Spinner for running tasks.
+    pub spinner: SpinnerStyle,
+    /// Spinner frame to draw.
+    pub frame: usize,
+    /// Whether to show how long tasks took.
+    pub show_elapsed: bool,
+    /// How elapsed times are written.
+    pub time_format: TimeFormat,
+    /// Whether to hide the subtasks of tasks that are done.
+    pub collapse_done: bool,
+    /// Spaces per level of nesting.
+    pub indent: usize,
+    /// Color of running tasks' spinners.
+    pub running_color: Option<Color>,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for TaskListProps {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            spinner: SpinnerStyle::Dots,
+            frame: 0,
+            show_elapsed: true,
+            time_format: TimeFormat::Human,
+            collapse_done: false,
+            indent: 2,
+            running_color: Some(Color::Cyan),
+            glyphs: None,
+        }
+    }
+}
+
+impl TaskListProps {
+    /// Create props showing `items`.
+    pub fn new(items: impl IntoIterator<Item = TaskItem>) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the spinner for running tasks.
+    #[must_use]
+    pub fn spinner(mut self, style: SpinnerStyle) -> Self {
+        self.spinner = style;
+        self
+    }
+
+    /// Set the spinner frame.
+    #[must_use]
+    pub fn frame(mut self, frame: usize) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    /// Show or hide elapsed times.
+    #[must_use]
+    pub fn show_elapsed(mut self, show: bool) -> Self {
+        self.show_elapsed = show;
+        self
+    }
+
+    /// Set how elapsed times are written.
+    #[must_use]
+    pub fn time_format(mut self, format: TimeFormat) -> Self {
+        self.time_format = format;
+        self
+    }
+
+    /// Hide the subtasks of tasks that are done.
+    #[must_use]
+    pub fn collapse_done(mut self, collapse: bool) -> Self {
+        self.collapse_done = collapse;
+        self
+    }
+
+    /// Set the spaces per level of nesting.
+    #[must_use]
+    pub fn indent(mut self, spaces: usize) -> Self {
+        self.indent = spaces;
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// The icon and its style for a status.
+    fn icon(&self, status: TaskItemStatus) -> (String, Style) {
+        let glyphs = GlyphSet::resolve(self.glyphs);
+        let ascii = glyphs.is_ascii();
+        let palette = Palette::detect();
+        let pick =
+            |unicode: &str, fallback: &str| if ascii { fallback } else { unicode }.to_string();
+        match status {
+            TaskItemStatus::Pending => (pick("○", "o"), Style::new().add_modifier(Modifier::DIM)),
+            TaskItemStatus::Running => (
+                self.spinner
+                    .for_glyphs(glyphs)
+                    .frame_at(self.frame)
+                    .to_string(),
+                self.running_color
+                    .map(|c| Style::new().fg(c))
+                    .unwrap_or_default(),
+            ),
+            TaskItemStatus::Done => (pick("✓", "+"), Style::new().fg(palette.success)),
+            TaskItemStatus::Failed => (pick("✗", "x"), Style::new().fg(palette.error)),
+            TaskItemStatus::Skipped => (pick("↓", "-"), Style::new().fg(palette.warning)),
+        }
+    }
+
+    fn push_lines(&self, item: &TaskItem, depth: usize, lines: &mut Vec<Vec<(String, Style)>>) {
+        let (icon, icon_style) = self.icon(item.status);
+        let label_style = match item.status {
+            TaskItemStatus::Pending | TaskItemStatus::Skipped => {
+                Style::new().add_modifier(Modifier::DIM)
+            }
+            _ => Style::new(),
+        };
+        let mut spans = vec![
+            (" ".repeat(depth * self.indent), Style::new()),
+            (icon, icon_style),
+            (format!(" {}", item.label), label_style),
+        ];
+        if let Some(message) = &item.message {
+            let style = if item.status == TaskItemStatus::Failed {
+                Style::new().fg(Palette::detect().error)
+            } else {
+                Style::new().add_modifier(Modifier::DIM)
+            };
+            spans.push((format!(" {message}"), style));
+        }
+        if self.show_elapsed && item.status != TaskItemStatus::Skipped {
+            if let Some(elapsed) = item.elapsed {
+                spans.push((
+                    format!(" ({})", self.time_format.format(elapsed)),
+                    Style::new().add_modifier(Modifier::DIM),
+                ));
+            }
+        }
+        lines.push(spans);
+
+        if !(self.collapse_done && item.status == TaskItemStatus::Done) {
+            for child in &item.children {
+                self.push_lines(child, depth + 1, lines);
+            }
+        }
+    }
+
+    /// The lines of the list, each a list of spans.
+    pub fn render_lines(&self) -> Vec<Vec<(String, Style)>> {
+        let mut lines = Vec::new();
+        for item in &self.items {
+            self.push_lines(item, 0, &mut lines);
+        }
+        lines
+    }
+}
+
+/// A component that displays a checklist of tasks.
+pub struct TaskList;
+
+impl Component for TaskList {
+    type Props = TaskListProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let lines: Vec<Element> = props
+            .render_lines()
+            .into_iter()
+            .map(|spans| {
+                Element::Fragment(
+                    spans
+                        .into_iter()
+                        .filter(|(text, _)| !text.is_empty())
+                        .map(|(text, style)| Element::styled_text(text, style))
+                        .collect(),
+                )
+            })
+            .collect();
+        if lines.is_empty() {
+            Element::Empty
+        } else {
+            Element::Fragment(lines)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::test_util::line_texts;
+
+    #[test]
+    fn test_task_list_state_transitions() {
+        let mut state = TaskListState::new();
+        state.add("deps", "Install");
+        assert!(state.add_child("deps", "fetch", "Fetch"));
+        assert!(!state.add_child("nope", "x", "X"));
+        state.add("build", "Build");
+
+        state.start("fetch");
+        assert_eq!(state.get("fetch").unwrap().status, TaskItemStatus::Running);
+        state.fail("fetch", "network down");
+        assert!(state.has_failed());
+        assert!(!state.is_finished());
+
+        state.done("deps");
+        state.skip("build", "up to date");
+        assert!(state.is_finished());
+        assert_eq!(
+            state.get("build").unwrap().message.as_deref(),
+            Some("up to date")
+        );
+    }
+
+    #[test]
+    fn test_task_list_lines() {
+        let items = vec![
+            TaskItem::new("deps", "Install")
+                .status(TaskItemStatus::Running)
+                .elapsed(Duration::from_secs(65))
+                .child(
+                    TaskItem::new("fetch", "Fetch")
+                        .status(TaskItemStatus::Done)
+                        .elapsed(Duration::from_secs(2)),
+                )
+                .child(TaskItem::new("link", "Link")),
+            TaskItem::new("test", "Test")
+                .status(TaskItemStatus::Failed)
+                .message("3 failed"),
+            TaskItem::new("docs", "Docs")
+                .status(TaskItemStatus::Skipped)
+                .message("no changes"),
+        ];
+        let props = TaskListProps::new(items).glyphs(GlyphSet::Ascii).frame(1);
+        assert_eq!(
+            line_texts(props.render_lines()),
+            [
+                "/ Install (1m 5s)",
+                "  + Fetch (2s)",
+                "  o Link",
+                "x Test 3 failed",
+                "- Docs no changes",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_task_list_collapse_done() {
+        let items = vec![TaskItem::new("a", "A")
+            .status(TaskItemStatus::Done)
+            .child(TaskItem::new("b", "B").status(TaskItemStatus::Done))];
+        let props = TaskListProps::new(items)
+            .glyphs(GlyphSet::Ascii)
+            .show_elapsed(false);
+        assert_eq!(line_texts(props.render_lines()).len(), 2);
+        assert_eq!(
+            line_texts(props.collapse_done(true).render_lines()),
+            ["+ A"]
+        );
+    }
+}
@@ -0,0 +1,13 @@
+//! Helpers shared by the component tests.
+
+use crate::style::Style;
+
+/// The text of one line of spans, styles dropped.
+pub(crate) fn span_text(spans: &[(String, Style)]) -> String {
+    spans.iter().map(|(text, _)| text.as_str()).collect()
+}
+
+/// The text of each line of spans, styles dropped.
+pub(crate) fn line_texts(lines: Vec<Vec<(String, Style)>>) -> Vec<String> {
+    lines.iter().map(|spans| span_text(spans)).collect()
+}
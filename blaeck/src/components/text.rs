@@ -15,8 +15,9 @@
 //! - [`Markdown`](super::Markdown) — Render markdown-formatted text
 //! - [`SyntaxHighlight`](super::SyntaxHighlight) — Code with syntax highlighting
 
-use crate::element::{Component, Element};
+use crate::element::{Component, Element, ElementBuilder};
 use crate::style::{Color, Modifier, Style};
+use crate::truncation::TruncateAt;
 
 /// How text should wrap when it exceeds the available width.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -34,6 +35,18 @@ pub enum TextWrap {
     TruncateMiddle,
 }
 
+impl TextWrap {
+    /// Where lines are cut for the truncating modes, `None` otherwise.
+    pub fn truncate_at(self) -> Option<TruncateAt> {
+        match self {
+            TextWrap::Truncate => Some(TruncateAt::End),
+            TextWrap::TruncateStart => Some(TruncateAt::Start),
+            TextWrap::TruncateMiddle => Some(TruncateAt::Middle),
+            TextWrap::Wrap | TextWrap::NoWrap => None,
+        }
+    }
+}
+
 /// Properties for the Text component.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct TextProps {
@@ -188,6 +201,74 @@ impl Component for Text {
     }
 }
 
+/// Shorthand setters for building Text without the macro.
+///
+/// ```ignore
+/// Text::builder().content("Done").color(Color::Green).bold().build()
+/// ```
+impl ElementBuilder<Text> {
+    /// Set the text content.
+    #[must_use]
+    pub fn content(self, content: impl Into<String>) -> Self {
+        let content = content.into();
+        self.set(|p| p.content = content)
+    }
+
+    /// Set the text color.
+    #[must_use]
+    pub fn color(self, color: Color) -> Self {
+        self.with(|p| p.color(color))
+    }
+
+    /// Set the background color.
+    #[must_use]
+    pub fn bg_color(self, color: Color) -> Self {
+        self.with(|p| p.bg_color(color))
+    }
+
+    /// Set bold styling.
+    #[must_use]
+    pub fn bold(self) -> Self {
+        self.with(TextProps::bold)
+    }
+
+    /// Set dim styling.
+    #[must_use]
+    pub fn dim(self) -> Self {
+        self.with(TextProps::dim)
+    }
+
+    /// Set italic styling.
+    #[must_use]
+    pub fn italic(self) -> Self {
+        self.with(TextProps::italic)
+    }
+
+    /// Set underline styling.
+    #[must_use]
+    pub fn underline(self) -> Self {
+        self.with(TextProps::underline)
+    }
+
+    /// Set strikethrough styling.
+    #[must_use]
+    pub fn strikethrough(self) -> Self {
+        self.with(TextProps::strikethrough)
+    }
+
+    /// Set inverse styling (swap fg/bg).
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        self.with(TextProps::inverse)
+    }
+
+    /// Set the text wrap mode.
+    #[must_use]
+    pub fn wrap(self, wrap: TextWrap) -> Self {
+        self.with(|p| p.wrap(wrap))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,1032 @@
+//! TextArea component - multi-line text editor.
+//!
+//! The TextArea shows a fixed-size, word-wrapped view of several lines of
+//! text with a cursor and an optional selection. Use `TextAreaState` to hold
+//! the text, move the cursor and apply edits from key events.
+//!
+//! ## When to use TextArea
+//!
+//! - Commit messages, notes and other free-form multi-line text
+//! - Editing small config snippets inside a TUI
+//!
+//! ## See also
+//!
+//! - [`TextInput`](super::TextInput) — Single-line text entry
+//! - [`ScrollView`](super::ScrollView) — Read-only scrolling content
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut notes = TextAreaState::with_value("first line\nsecond line");
+//! notes.set_size(40, 6);
+//!
+//! // In the input handler:
+//! notes.handle_key(&key);
+//!
+//! // In render:
+//! Element::node::<TextArea>(notes.to_props().placeholder("Notes…"), vec![])
+//! ```
+
+use crate::components::edit_keys::{
+    translate, EditCommand, EditKeys, EditState, Translated, VimMode,
+};
+use crate::components::text_input::{byte_offset, grapheme_count, word_end, word_start};
+use crate::element::{Component, Element};
+use crate::input::Key;
+use crate::style::{Color, Modifier, Style};
+use crossterm::event::{KeyCode, KeyModifiers};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// A cursor position: line index and grapheme index within the line.
+pub type TextPos = (usize, usize);
+
+/// One wrapped row of a logical line, as a grapheme range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct VisualRow {
+    line: usize,
+    start: usize,
+    end: usize,
+    /// Whether this is the last row of its line.
+    last: bool,
+}
+
+/// Grapheme index where each wrapped row of `line` starts.
+///
+/// Breaks after whitespace where possible and mid-word only when a word is
+/// longer than `limit`. Whitespace at a break hangs off the end of the row
+/// instead of starting the next one. A `limit` of 0 disables wrapping.
+pub(crate) fn wrap_line(line: &str, limit: usize) -> Vec<usize> {
+    let mut starts = vec![0];
+    if limit == 0 {
+        return starts;
+    }
+    let graphemes: Vec<&str> = line.graphemes(true).collect();
+    let mut row_width = 0;
+    let mut last_break = 0;
+    for (i, g) in graphemes.iter().enumerate() {
+        let w = g.width();
+        if g.chars().all(char::is_whitespace) {
+            row_width += w;
+            last_break = i + 1;
+            continue;
+        }
+        if row_width + w > limit && row_width > 0 {
+            let row_start = starts.last().copied().unwrap_or(0);
+            let start = if last_break > row_start {
+                last_break
+            } else {
+                i
+            };
+            starts.push(start);
+            row_width = graphemes[start..i].iter().map(|g| g.width()).sum();
+        }
+        row_width += w;
+    }
+    starts
+}
+
+/// Wrap every line to `width` columns.
+///
+/// Rows wrap one column short of the width so a cursor at the end of a row
+/// still has a cell to sit in.
+fn wrap_rows(lines: &[String], width: usize) -> Vec<VisualRow> {
+    let limit = width.saturating_sub(1);
+    let mut rows = Vec::new();
+    for (line_idx, line) in lines.iter().enumerate() {
+        let len = grapheme_count(line);
+        let starts = wrap_line(line, limit);
+        for (i, &start) in starts.iter().enumerate() {
+            let end = starts.get(i + 1).copied().unwrap_or(len);
+            rows.push(VisualRow {
+                line: line_idx,
+                start,
+                end,
+                last: i + 1 == starts.len(),
+            });
+        }
+    }
+    rows
+}
+
+/// Index of the row the cursor is drawn on.
+fn cursor_row(rows: &[VisualRow], (line, col): TextPos) -> usize {
+    rows.iter()
+        .position(|r| r.line == line && col >= r.start && (col < r.end || r.last))
+        .unwrap_or(0)
+}
+
+/// Properties for the TextArea component.
+#[derive(Debug, Clone)]
+pub struct TextAreaProps {
+    /// Lines of text (without newlines).
+    pub lines: Vec<String>,
+    /// Cursor position.
+    pub cursor: TextPos,
+    /// Selection anchor. None = no selection.
+    pub selection_anchor: Option<TextPos>,
+    /// First visible wrapped row.
+    pub scroll: usize,
+    /// Width in columns.
+    pub width: usize,
+    /// Height in rows.
+    pub height: usize,
+    /// Whether the editor is focused (shows cursor).
+    pub focused: bool,
+    /// Placeholder text shown when empty.
+    pub placeholder: Option<String>,
+    /// Text color.
+    pub color: Option<Color>,
+    /// Placeholder text color.
+    pub placeholder_color: Option<Color>,
+    /// Cursor color (`None` = reversed video).
+    pub cursor_color: Option<Color>,
+    /// Selection background color.
+    pub selection_color: Option<Color>,
+}
+
+impl Default for TextAreaProps {
+    fn default() -> Self {
+        Self {
+            lines: vec![String::new()],
+            cursor: (0, 0),
+            selection_anchor: None,
+            scroll: 0,
+            width: 40,
+            height: 5,
+            focused: true,
+            placeholder: None,
+            color: None,
+            placeholder_color: None,
+            cursor_color: None,
+            selection_color: None,
+        }
+    }
+}
+
+impl TextAreaProps {
+    /// Create new TextAreaProps with the given text, cursor at the end.
+    pub fn new(value: impl AsRef<str>) -> Self {
+        let state = TextAreaState::with_value(value);
+        Self {
+            lines: state.lines,
+            cursor: state.cursor,
+            ..Default::default()
+        }
+    }
+
+    /// Set the size in columns and rows.
+    #[must_use]
+    pub fn size(mut self, width: usize, height: usize) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Set whether the editor is focused.
+    #[must_use]
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Set the placeholder text.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set the text color.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the placeholder color.
+    #[must_use]
+    pub fn placeholder_color(mut self, color: Color) -> Self {
+        self.placeholder_color = Some(color);
+        self
+    }
+
+    /// Set the cursor color.
+    #[must_use]
+    pub fn cursor_color(mut self, color: Color) -> Self {
+        self.cursor_color = Some(color);
+        self
+    }
+
+    /// Set the selection background color.
+    #[must_use]
+    pub fn selection_color(mut self, color: Color) -> Self {
+        self.selection_color = Some(color);
+        self
+    }
+
+    /// Get selection range (start, end) where start <= end.
+    pub fn selection_range(&self) -> Option<(TextPos, TextPos)> {
+        selection_range(self.cursor, self.selection_anchor)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.lines.iter().all(String::is_empty)
+    }
+
+    fn text_style(&self) -> Style {
+        self.color.map(|c| Style::new().fg(c)).unwrap_or_default()
+    }
+
+    fn cursor_style(&self) -> Style {
+        match self.cursor_color {
+            Some(color) => Style::new().bg(color),
+            None => Style::new().add_modifier(Modifier::REVERSED),
+        }
+    }
+
+    /// Build one visible row, padded to the full width.
+    fn render_row(&self, row: &VisualRow) -> Element {
+        let line = &self.lines[row.line];
+        let selection = self.selection_range();
+        let selection_style = Style::new().bg(self.selection_color.unwrap_or(Color::Blue));
+        let text_style = self.text_style();
+
+        let mut spans: Vec<(String, Style)> = Vec::new();
+        let mut used = 0;
+        let mut push = |text: &str, style: Style, spans: &mut Vec<(String, Style)>| {
+            let w = text.width();
+            if used + w > self.width {
+                return;
+            }
+            used += w;
+            match spans.last_mut() {
+                Some((last, last_style)) if *last_style == style => last.push_str(text),
+                _ => spans.push((text.to_string(), style)),
+            }
+        };
+
+        for (i, g) in line
+            .graphemes(true)
+            .enumerate()
+            .skip(row.start)
+            .take(row.end - row.start)
+        {
+            let pos = (row.line, i);
+            let style = if self.focused && pos == self.cursor {
+                self.cursor_style()
+            } else if selection.is_some_and(|(s, e)| pos >= s && pos < e) {
+                selection_style
+            } else {
+                text_style
+            };
+            push(g, style, &mut spans);
+        }
+        if self.focused && row.last && self.cursor == (row.line, row.end) {
+            push(" ", self.cursor_style(), &mut spans);
+        }
+        let used: usize = spans.iter().map(|(text, _)| text.width()).sum();
+        if used < self.width {
+            spans.push((" ".repeat(self.width - used), Style::new()));
+        }
+
+        Element::Fragment(
+            spans
+                .into_iter()
+                .map(|(text, style)| Element::styled_text(text, style))
+                .collect(),
+        )
+    }
+
+    /// The placeholder row shown when there is no text.
+    fn render_placeholder(&self, placeholder: &str) -> Element {
+        let style = match self.placeholder_color {
+            Some(color) => Style::new().fg(color),
+            None => Style::new().add_modifier(Modifier::DIM),
+        };
+        let lead = if self.focused {
+            Element::styled_text(" ", self.cursor_style())
+        } else {
+            Element::text(" ")
+        };
+        let text: String = placeholder
+            .graphemes(true)
+            .scan(1, |used, g| {
+                *used += g.width();
+                (*used <= self.width).then_some(g)
+            })
+            .collect();
+        let pad = self.width.saturating_sub(1 + text.width());
+        Element::Fragment(vec![
+            lead,
+            Element::styled_text(text, style),
+            Element::text(" ".repeat(pad)),
+        ])
+    }
+}
+
+fn selection_range(cursor: TextPos, anchor: Option<TextPos>) -> Option<(TextPos, TextPos)> {
+    anchor
+        .filter(|&a| a != cursor)
+        .map(|a| (a.min(cursor), a.max(cursor)))
+}
+
+/// A component that displays a multi-line, word-wrapped text editor.
+///
+/// Always renders `height` rows of `width` columns. The caller handles key
+/// events, usually through [`TextAreaState::handle_key`].
+pub struct TextArea;
+
+impl Component for TextArea {
+    type Props = TextAreaProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let rows = wrap_rows(&props.lines, props.width);
+        let blank = || Element::text(" ".repeat(props.width));
+        let mut lines: Vec<Element> = match &props.placeholder {
+            Some(placeholder) if props.is_empty() => {
+                vec![props.render_placeholder(placeholder)]
+            }
+            _ => rows
+                .iter()
+                .skip(props.scroll)
+                .take(props.height)
+                .map(|row| props.render_row(row))
+                .collect(),
+        };
+        lines.truncate(props.height);
+        while lines.len() < props.height {
+            lines.push(blank());
+        }
+        Element::Fragment(lines)
+    }
+}
+
+/// Cursor motions for [`TextAreaState::move_cursor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorMove {
+    /// One grapheme left, onto the previous line at the start of a line.
+    Left,
+    /// One grapheme right, onto the next line at the end of a line.
+    Right,
+    /// One wrapped row up.
+    Up,
+    /// One wrapped row down.
+    Down,
+    /// Start of the current line.
+    LineStart,
+    /// End of the current line.
+    LineEnd,
+    /// One page of rows up.
+    PageUp,
+    /// One page of rows down.
+    PageDown,
+    /// Start of the text.
+    Start,
+    /// End of the text.
+    End,
+    /// Start of the previous word, onto the previous line at the start of
+    /// a line.
+    WordLeft,
+    /// End of the next word, onto the next line at the end of a line.
+    WordRight,
+}
+
+/// Helper struct for managing text area state.
+///
+/// Holds the lines, cursor, selection and scroll position, and the size of
+/// the view so that wrapping, Up/Down and scrolling match what's drawn.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextAreaState {
+    /// Lines of text (always at least one).
+    pub lines: Vec<String>,
+    /// Cursor position.
+    pub cursor: TextPos,
+    /// Selection anchor (where selection started). None = no selection.
+    pub selection_anchor: Option<TextPos>,
+    /// First visible wrapped row.
+    pub scroll: usize,
+    /// View width in columns.
+    pub width: usize,
+    /// View height in rows.
+    pub height: usize,
+    /// Text last killed, inserted by yank / put.
+    #[cfg_attr(feature = "session", serde(default))]
+    pub clipboard: String,
+    /// Display column Up/Down try to keep, so moving through a short row
+    /// doesn't lose the column.
+    goal_column: Option<usize>,
+    /// Editing key preset and Vim mode.
+    #[cfg_attr(feature = "session", serde(skip))]
+    edit: EditState,
+}
+
+impl Default for TextAreaState {
+    fn default() -> Self {
+        Self {
+            lines: vec![String::new()],
+            cursor: (0, 0),
+            selection_anchor: None,
+            scroll: 0,
+            width: 40,
+            height: 5,
+            clipboard: String::new(),
+            goal_column: None,
+            edit: EditState::default(),
+        }
+    }
+}
+
+impl TextAreaState {
+    /// Create a new empty state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new state with initial text, cursor at the end.
+    pub fn with_value(value: impl AsRef<str>) -> Self {
+        let mut state = Self::default();
+        state.set_value(value);
+        state
+    }
+
+    /// Use the Emacs or Vim editing keys (see [`EditKeys`]).
+    #[must_use]
+    pub fn edit_keys(mut self, keys: EditKeys) -> Self {
+        self.edit = EditState::new(keys);
+        self
+    }
+
+    /// The Vim mode, with [`EditKeys::Vim`].
+    pub fn vim_mode(&self) -> Option<VimMode> {
+        self.edit.vim_mode()
+    }
+
+    /// Set the view size. Use the same size the TextArea is rendered at.
+    pub fn set_size(&mut self, width: usize, height: usize) {
+        self.width = width;
+        self.height = height;
+        self.scroll_to_cursor();
+    }
+
+    /// Replace the text and move the cursor to the end.
+    pub fn set_value(&mut self, value: impl AsRef<str>) {
+        self.lines = value
+            .as_ref()
+            .split('\n')
+            .map(|l| l.trim_end_matches('\r').to_string())
+            .collect();
+        self.selection_anchor = None;
+        self.move_cursor(CursorMove::End, false);
+    }
+
+    /// The text, lines joined with `\n`.
+    pub fn value(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Check if there is no text.
+    pub fn is_empty(&self) -> bool {
+        self.lines.iter().all(String::is_empty)
+    }
+
+    /// Clear the text.
+    pub fn clear(&mut self) {
+        self.set_value("");
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        grapheme_count(&self.lines[line])
+    }
+
+    fn rows(&self) -> Vec<VisualRow> {
+        wrap_rows(&self.lines, self.width)
+    }
+
+    /// Display column of the cursor within its wrapped row.
+    fn cursor_column(&self, row: &VisualRow) -> usize {
+        let line = &self.lines[self.cursor.0];
+        let from = byte_offset(line, row.start);
+        let to = byte_offset(line, self.cursor.1);
+        line[from..to].width()
+    }
+
+    /// Grapheme index in `row` closest to display column `column`.
+    fn column_in_row(&self, row: &VisualRow, column: usize) -> usize {
+        let mut used = 0;
+        let mut col = row.start;
+        for g in self.lines[row.line]
+            .graphemes(true)
+            .skip(row.start)
+            .take(row.end - row.start)
+        {
+            used += g.width();
+            if used > column {
+                break;
+            }
+            col += 1;
+        }
+        // The end of a wrapped row is the start of the next one
+        if !row.last && col == row.end && row.end > row.start {
+            col -= 1;
+        }
+        col
+    }
+
+    /// Move the cursor by `rows` wrapped rows (negative moves up).
+    fn move_rows(&mut self, rows: isize) {
+        let all = self.rows();
+        let current = cursor_row(&all, self.cursor);
+        let column = self
+            .goal_column
+            .unwrap_or_else(|| self.cursor_column(&all[current]));
+        let target = current.saturating_add_signed(rows);
+        if rows < 0 && current == 0 {
+            self.cursor = (0, 0);
+        } else if target >= all.len() {
+            let last = self.lines.len() - 1;
+            self.cursor = (last, self.line_len(last));
+        } else {
+            let row = all[target];
+            self.cursor = (row.line, self.column_in_row(&row, column));
+        }
+        self.goal_column = Some(column);
+    }
+
+    /// Move the cursor. With `extend`, the selection grows from where the
+    /// cursor was; otherwise any selection is cleared.
+    pub fn move_cursor(&mut self, motion: CursorMove, extend: bool) {
+        if extend {
+            self.selection_anchor.get_or_insert(self.cursor);
+        } else {
+            self.selection_anchor = None;
+        }
+        let (line, col) = self.cursor;
+        let page = self.height.max(1) as isize;
+        match motion {
+            CursorMove::Up => return self.finish_vertical(-1),
+            CursorMove::Down => return self.finish_vertical(1),
+            CursorMove::PageUp => return self.finish_vertical(-page),
+            CursorMove::PageDown => return self.finish_vertical(page),
+            CursorMove::Left if col > 0 => self.cursor.1 -= 1,
+            CursorMove::Left if line > 0 => self.cursor = (line - 1, self.line_len(line - 1)),
+            CursorMove::Right if col < self.line_len(line) => self.cursor.1 += 1,
+            CursorMove::Right if line + 1 < self.lines.len() => self.cursor = (line + 1, 0),
+            CursorMove::Left | CursorMove::Right => {}
+            CursorMove::LineStart => self.cursor.1 = 0,
+            CursorMove::LineEnd => self.cursor.1 = self.line_len(line),
+            CursorMove::WordLeft if col > 0 => self.cursor.1 = word_start(&self.lines[line], col),
+            CursorMove::WordRight if col < self.line_len(line) => {
+                self.cursor.1 = word_end(&self.lines[line], col);
+            }
+            CursorMove::WordLeft if line > 0 => {
+                self.cursor = (line - 1, self.line_len(line - 1));
+            }
+            CursorMove::WordRight if line + 1 < self.lines.len() => self.cursor = (line + 1, 0),
+            CursorMove::WordLeft | CursorMove::WordRight => {}
+            CursorMove::Start => self.cursor = (0, 0),
+            CursorMove::End => {
+                let last = self.lines.len() - 1;
+                self.cursor = (last, self.line_len(last));
+            }
+        }
+        self.goal_column = None;
+        self.scroll_to_cursor();
+    }
+
+    fn finish_vertical(&mut self, rows: isize) {
+        self.move_rows(rows);
+        self.scroll_to_cursor();
+    }
+
+    /// Scroll as little as possible to keep the cursor in view.
+    pub fn scroll_to_cursor(&mut self) {
+        let rows = self.rows();
+        let row = cursor_row(&rows, self.cursor);
+        let height = self.height.max(1);
+        if row < self.scroll {
+            self.scroll = row;
+        } else if row >= self.scroll + height {
+            self.scroll = row + 1 - height;
+        }
+        self.scroll = self.scroll.min(rows.len().saturating_sub(height));
+    }
+
+    /// Insert text at the cursor, replacing any selection. `\n` splits lines.
+    pub fn insert_str(&mut self, text: &str) {
+        self.delete_selection();
+        let (line, col) = self.cursor;
+        let at = byte_offset(&self.lines[line], col);
+        let tail = self.lines[line].split_off(at);
+        let mut parts = text.split('\n').map(|p| p.trim_end_matches('\r'));
+        self.lines[line].push_str(parts.next().unwrap_or(""));
+        let mut last = line;
+        for part in parts {
+            last += 1;
+            self.lines.insert(last, part.to_string());
+        }
+        self.cursor = (last, grapheme_count(&self.lines[last]));
+        self.lines[last].push_str(&tail);
+        self.goal_column = None;
+        self.scroll_to_cursor();
+    }
+
+    /// Insert a character at the cursor, replacing any selection.
+    pub fn insert(&mut self, c: char) {
+        self.insert_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Split the line at the cursor.
+    pub fn newline(&mut self) {
+        self.insert_str("\n");
+    }
+
+    /// Delete the grapheme before the cursor, joining with the previous
+    /// line at the start of a line. Deletes the selection instead if there
+    /// is one.
+    pub fn backspace(&mut self) -> bool {
+        if self.delete_selection().is_some() {
+            return true;
+        }
+        if self.cursor == (0, 0) {
+            return false;
+        }
+        self.move_cursor(CursorMove::Left, true);
+        self.delete_selection();
+        true
+    }
+
+    /// Delete the grapheme at the cursor, joining with the next line at the
+    /// end of a line. Deletes the selection instead if there is one.
+    pub fn delete(&mut self) -> bool {
+        if self.delete_selection().is_some() {
+            return true;
+        }
+        let last = self.lines.len() - 1;
+        if self.cursor == (last, self.line_len(last)) {
+            return false;
+        }
+        self.move_cursor(CursorMove::Right, true);
+        self.delete_selection();
+        true
+    }
+
+    /// Check if there's an active selection.
+    pub fn has_selection(&self) -> bool {
+        self.selection_range().is_some()
+    }
+
+    /// Get selection range (start, end) where start <= end.
+    pub fn selection_range(&self) -> Option<(TextPos, TextPos)> {
+        selection_range(self.cursor, self.selection_anchor)
+    }
+
+    /// Get the selected text.
+    pub fn selected_text(&self) -> Option<String> {
+        let ((l0, c0), (l1, c1)) = self.selection_range()?;
+        let from = byte_offset(&self.lines[l0], c0);
+        let to = byte_offset(&self.lines[l1], c1);
+        if l0 == l1 {
+            return Some(self.lines[l0][from..to].to_string());
+        }
+        let mut text = self.lines[l0][from..].to_string();
+        for line in &self.lines[l0 + 1..l1] {
+            text.push('\n');
+            text.push_str(line);
+        }
+        text.push('\n');
+        text.push_str(&self.lines[l1][..to]);
+        Some(text)
+    }
+
+    /// Delete the selected text and return it.
+    pub fn delete_selection(&mut self) -> Option<String> {
+        let deleted = self.selected_text();
+        if let Some(((l0, c0), (l1, c1))) = self.selection_range() {
+            let from = byte_offset(&self.lines[l0], c0);
+            let to = byte_offset(&self.lines[l1], c1);
+            let tail = self.lines[l1][to..].to_string();
+            self.lines[l0].truncate(from);
+            self.lines[l0].push_str(&tail);
+            self.lines.drain(l0 + 1..=l1);
+            self.cursor = (l0, c0);
+            self.goal_column = None;
+            self.scroll_to_cursor();
+        }
+        self.selection_anchor = None;
+        deleted
+    }
+
+    /// Select all text.
+    pub fn select_all(&mut self) {
+        self.move_cursor(CursorMove::Start, false);
+        self.move_cursor(CursorMove::End, true);
+    }
+
+    /// Clear the selection without deleting text.
+    pub fn clear_selection(&mut self) {
+        self.selection_anchor = None;
+    }
+
+    /// Extend the selection by `motion` and cut it into
+    /// [`clipboard`](Self::clipboard). Returns false if nothing was cut.
+    fn kill(&mut self, motion: CursorMove) -> bool {
+        self.move_cursor(motion, true);
+        match self.delete_selection() {
+            Some(text) if !text.is_empty() => {
+                self.clipboard = text;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Cut the cursor's line, newline included, into
+    /// [`clipboard`](Self::clipboard).
+    fn kill_line(&mut self) {
+        let line = self.cursor.0;
+        self.clipboard = format!("{}\n", self.lines[line]);
+        if self.lines.len() == 1 {
+            self.lines[0].clear();
+        } else {
+            self.lines.remove(line);
+        }
+        self.cursor = (line.min(self.lines.len() - 1), 0);
+        self.goal_column = None;
+        self.scroll_to_cursor();
+    }
+
+    /// Insert [`clipboard`](Self::clipboard). Whole lines (from a line
+    /// kill) go above the cursor's line, or below it with `after`.
+    fn yank(&mut self, after: bool) -> bool {
+        if self.clipboard.is_empty() {
+            return false;
+        }
+        let text = self.clipboard.clone();
+        match text.strip_suffix('\n') {
+            Some(lines) if after => {
+                self.move_cursor(CursorMove::LineEnd, false);
+                self.insert_str(&format!("\n{lines}"));
+                self.move_cursor(CursorMove::LineStart, false);
+            }
+            Some(_) => {
+                let line = self.cursor.0;
+                self.move_cursor(CursorMove::LineStart, false);
+                self.insert_str(&text);
+                self.cursor = (line, 0);
+            }
+            None => {
+                if after {
+                    self.move_cursor(CursorMove::Right, false);
+                }
+                self.insert_str(&text);
+            }
+        }
+        true
+    }
+
+    fn apply(&mut self, command: EditCommand) -> bool {
+        use EditCommand::*;
+        let motion = match command {
+            Left => CursorMove::Left,
+            Right => CursorMove::Right,
+            Up => CursorMove::Up,
+            Down => CursorMove::Down,
+            WordLeft => CursorMove::WordLeft,
+            WordRight => CursorMove::WordRight,
+            LineStart => CursorMove::LineStart,
+            LineEnd => CursorMove::LineEnd,
+            Start => CursorMove::Start,
+            End => CursorMove::End,
+            Delete => return self.delete(),
+            Backspace => return self.backspace(),
+            DeleteWordLeft => return self.kill(CursorMove::WordLeft),
+            DeleteWordRight => return self.kill(CursorMove::WordRight),
+            // At the end of a line, Ctrl+K joins the next one
+            KillToEnd if self.cursor.1 == self.line_len(self.cursor.0) => return self.delete(),
+            KillToEnd => return self.kill(CursorMove::LineEnd),
+            KillToStart => return self.kill(CursorMove::LineStart),
+            KillLine => {
+                self.kill_line();
+                return true;
+            }
+            Yank => return self.yank(false),
+            YankAfter => return self.yank(true),
+            OpenBelow => {
+                self.move_cursor(CursorMove::LineEnd, false);
+                self.newline();
+                return true;
+            }
+            OpenAbove => {
+                self.move_cursor(CursorMove::LineStart, false);
+                self.newline();
+                self.move_cursor(CursorMove::Left, false);
+                return true;
+            }
+            Undo | Redo => return false,
+        };
+        self.move_cursor(motion, false);
+        true
+    }
+
+    /// Handle typing, Enter, Backspace/Delete, arrows, Home/End and
+    /// PageUp/PageDown. Shift extends the selection, Ctrl+Home/End jump to
+    /// the start/end of the text and Ctrl+A selects all. The
+    /// [`EditKeys`] preset adds Emacs or Vim keys; there is no undo.
+    ///
+    /// Returns true if the key was handled.
+    pub fn handle_key(&mut self, key: &Key) -> bool {
+        match translate(&mut self.edit, key) {
+            Translated::Command(command) => {
+                self.apply(command);
+                return true;
+            }
+            Translated::Consumed => return true,
+            Translated::Pass => {}
+        }
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        let motion = match key.code {
+            KeyCode::Left => CursorMove::Left,
+            KeyCode::Right => CursorMove::Right,
+            KeyCode::Up => CursorMove::Up,
+            KeyCode::Down => CursorMove::Down,
+            KeyCode::Home if ctrl => CursorMove::Start,
+            KeyCode::End if ctrl => CursorMove::End,
+            KeyCode::Home => CursorMove::LineStart,
+            KeyCode::End => CursorMove::LineEnd,
+            KeyCode::PageUp => CursorMove::PageUp,
+            KeyCode::PageDown => CursorMove::PageDown,
+            KeyCode::Char('a') if ctrl => {
+                self.select_all();
+                return true;
+            }
+            KeyCode::Char(c) if !ctrl && !alt => {
+                self.insert(c);
+                return true;
+            }
+            KeyCode::Enter => {
+                self.newline();
+                return true;
+            }
+            KeyCode::Backspace => {
+                self.backspace();
+                return true;
+            }
+            KeyCode::Delete => {
+                self.delete();
+                return true;
+            }
+            _ => return false,
+        };
+        self.move_cursor(motion, shift);
+        true
+    }
+
+    /// Convert to props for rendering.
+    pub fn to_props(&self) -> TextAreaProps {
+        TextAreaProps {
+            lines: self.lines.clone(),
+            cursor: self.cursor,
+            selection_anchor: self.selection_anchor,
+            scroll: self.scroll,
+            width: self.width,
+            height: self.height,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row_text(element: &Element) -> String {
+        match element {
+            Element::Fragment(spans) => spans.iter().map(row_text).collect(),
+            Element::Text { content, .. } => content.clone(),
+            _ => String::new(),
+        }
+    }
+
+    fn rendered(props: &TextAreaProps) -> Vec<String> {
+        match TextArea::render(props) {
+            Element::Fragment(rows) => rows.iter().map(row_text).collect(),
+            _ => panic!("expected rows"),
+        }
+    }
+
+    #[test]
+    fn test_wrap_line_breaks_at_words() {
+        assert_eq!(wrap_line("hello world", 5), vec![0, 6]);
+        assert_eq!(wrap_line("abcdefgh", 3), vec![0, 3, 6]);
+        assert_eq!(wrap_line("a bcdefg", 4), vec![0, 2, 6]);
+        assert_eq!(wrap_line("short", 0), vec![0]);
+    }
+
+    #[test]
+    fn test_text_area_state_editing() {
+        let mut state = TextAreaState::with_value("ab\ncd");
+        assert_eq!(state.cursor, (1, 2));
+
+        state.move_cursor(CursorMove::Start, false);
+        state.move_cursor(CursorMove::Right, false);
+        state.newline();
+        assert_eq!(state.lines, vec!["a", "b", "cd"]);
+        assert_eq!(state.cursor, (1, 0));
+
+        assert!(state.backspace());
+        assert_eq!(state.value(), "ab\ncd");
+        state.move_cursor(CursorMove::LineEnd, false);
+        assert!(state.delete());
+        assert_eq!(state.value(), "abcd");
+
+        state.insert_str("X\nY");
+        assert_eq!(state.value(), "abX\nYcd");
+        assert_eq!(state.cursor, (1, 1));
+    }
+
+    #[test]
+    fn test_text_area_state_selection() {
+        let mut state = TextAreaState::with_value("one\ntwo\nthree");
+        state.move_cursor(CursorMove::Start, false);
+        state.move_cursor(CursorMove::Right, false);
+        state.handle_key(&Key::with_modifiers(KeyCode::Down, KeyModifiers::SHIFT));
+        assert_eq!(state.selected_text().as_deref(), Some("ne\nt"));
+        state.insert('-');
+        assert_eq!(state.value(), "o-wo\nthree");
+
+        state.handle_key(&Key::with_ctrl(KeyCode::Char('a')));
+        assert_eq!(state.selected_text().unwrap(), state.value());
+    }
+
+    #[test]
+    fn test_text_area_wrapped_navigation_and_scroll() {
+        let mut state = TextAreaState::with_value("one two three four");
+        state.set_size(9, 2);
+        // Rows: "one two ", "three ", "four"
+        assert_eq!(state.scroll, 1);
+        state.handle_key(&Key::new(KeyCode::Up));
+        assert_eq!(state.cursor, (0, 12));
+        state.handle_key(&Key::new(KeyCode::Up));
+        assert_eq!(state.cursor, (0, 4));
+        assert_eq!(state.scroll, 0);
+        state.handle_key(&Key::new(KeyCode::Up));
+        assert_eq!(state.cursor, (0, 0));
+    }
+
+    #[test]
+    fn test_text_area_render() {
+        let mut state = TextAreaState::with_value("hello world");
+        state.set_size(8, 3);
+        assert_eq!(
+            rendered(&state.to_props()),
+            vec!["hello   ", "world   ", "        "]
+        );
+
+        let props = TextAreaProps::default()
+            .size(8, 1)
+            .placeholder("Write something");
+        assert_eq!(rendered(&props), vec![" Write s"]);
+    }
+
+    #[test]
+    fn test_text_area_renders_rows() {
+        let mut state = TextAreaState::with_value("ab\ncd");
+        state.set_size(4, 3);
+        let mut blaeck = crate::renderer::Blaeck::with_size(Vec::new(), 10, 5).unwrap();
+        blaeck
+            .render(Element::node::<TextArea>(state.to_props(), vec![]))
+            .unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["ab", "cd", ""]);
+    }
+
+    #[test]
+    fn test_text_area_vim_keys() {
+        let mut state = TextAreaState::with_value("alpha beta\ngamma").edit_keys(EditKeys::Vim);
+        let press = |state: &mut TextAreaState, keys: &str| {
+            for c in keys.chars() {
+                state.handle_key(&Key::new(KeyCode::Char(c)));
+            }
+        };
+        state.handle_key(&Key::new(KeyCode::Esc));
+        press(&mut state, "ggw");
+        assert_eq!(state.cursor, (0, 5));
+        press(&mut state, "ddp");
+        assert_eq!(state.value(), "gamma\nalpha beta");
+        assert_eq!(state.cursor, (1, 0));
+        press(&mut state, "kD");
+        assert_eq!(state.value(), "\nalpha beta");
+        press(&mut state, "onew");
+        assert_eq!(state.value(), "\nnew\nalpha beta");
+        assert_eq!(state.vim_mode(), Some(VimMode::Insert));
+
+        let mut state = TextAreaState::with_value("one two").edit_keys(EditKeys::Emacs);
+        state.handle_key(&Key::with_ctrl(KeyCode::Char('w')));
+        state.handle_key(&Key::with_ctrl(KeyCode::Char('a')));
+        state.handle_key(&Key::with_ctrl(KeyCode::Char('y')));
+        assert_eq!(state.value(), "twoone ");
+    }
+}
@@ -4,10 +4,52 @@
 //! placeholder text, and optional password masking. Use `TextInputState`
 //! to manage the text value and cursor position.
 //!
+//! `TextInputState` can also cap the length of the value and check it with
+//! a validator after every edit; its error shows after the input, which is
+//! drawn in the error color until the value is fixed.
+//!
+//! Keys (in [`TextInputState::handle_key`]):
+//!
+//! - Left / Right, Home / End — move; with Shift, extend the selection
+//! - Alt or Ctrl + Left / Right — move by word
+//! - Alt or Ctrl + Backspace, Ctrl+W — delete the word before the cursor
+//! - Alt or Ctrl + Delete, Alt+D — delete the word after the cursor
+//! - Ctrl+A — select all
+//! - Ctrl+C / Ctrl+X / Ctrl+V — copy / cut / paste
+//! - Ctrl+Z — undo; Ctrl+Y or Ctrl+Shift+Z — redo
+//!
+//! [`TextInputState::edit_keys`] adds Emacs or Vim keys on top; see
+//! [`EditKeys`].
+//!
+//! Terminals can't be asked for the system clipboard, so copy and cut keep
+//! the text in [`TextInputState::clipboard`] for paste; send it on with
+//! [`Blaeck::copy_to_clipboard`](crate::Blaeck::copy_to_clipboard), and
+//! insert pasted text from a paste event with
+//! [`TextInputState::handle_paste`].
+//!
+//! A paste with line breaks or control characters would break the
+//! single-line value, so [`PasteMode`] decides what happens to it: by
+//! default it's flattened onto one line, but it can be rejected or handed
+//! to an [`on_paste`](TextInputState::on_paste) handler instead (to open a
+//! multi-line editor, say). The same choice can be made on
+//! [`TextInputProps`] and applied with
+//! [`TextInputState::handle_paste_with`].
+//!
+//! Cursor positions count grapheme clusters, so an emoji, a flag or a
+//! letter with combining accents moves and deletes as one unit.
+//!
+//! Input methods for East-Asian text commit their result as ordinary typed
+//! characters. Hangul that arrives as separate conjoining jamo (ᄒ, ᅡ, ᆫ) is
+//! joined into syllables (한) as it's inserted, since most terminals can't
+//! draw the jamo as one character. An input method the app runs itself can
+//! show its text in progress at the cursor with
+//! [`TextInputState::compose`]; it's drawn underlined and joins the value
+//! on Enter (or any other editing key), and Esc drops it.
+//!
 //! ## When to use TextInput
 //!
 //! - Free-form text entry (names, paths, search queries)
-//! - Password fields (with `mask: true`)
+//! - Password fields (with `mask: Some('•')`)
 //! - Any user input that isn't a fixed set of choices
 //!
 //! ## See also
@@ -16,26 +58,159 @@
 //! - [`Select`](super::Select) — Fixed choices (no typing needed)
 //! - [`Confirm`](super::Confirm) — Yes/no questions
 
+use crate::components::edit_keys::{
+    translate, EditCommand, EditKeys, EditState, Translated, VimMode,
+};
 use crate::element::{Component, Element};
+use crate::input::Key;
+use crate::palette::Palette;
+use crate::renderer::strip_ansi_escapes;
 use crate::style::{Color, Modifier, Style};
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::fmt;
+use std::rc::Rc;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Checks a text input's value, returning the message to show if it's wrong.
+pub type TextValidator = Rc<dyn Fn(&str) -> Result<(), String>>;
+
+/// Receives pastes a text input won't take itself (see [`PasteMode::Forward`]).
+pub type PasteHandler = Rc<dyn Fn(&str)>;
+
+/// What a text input does with a paste containing line breaks or control
+/// characters. Pastes without them are always inserted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PasteMode {
+    /// Drop trailing line breaks, turn the others and tabs into spaces, and
+    /// leave out escape sequences and other control characters.
+    #[default]
+    Flatten,
+    /// Ignore the paste.
+    Reject,
+    /// Pass the paste, untouched, to the
+    /// [`on_paste`](TextInputState::on_paste) handler instead of inserting
+    /// it. Without a handler the paste is ignored.
+    Forward,
+}
+
+/// Whether pasting `text` needs the [`PasteMode`]'s say.
+fn is_unsafe_paste(text: &str) -> bool {
+    text.chars().any(char::is_control)
+}
+
+/// `text` on one line, as [`PasteMode::Flatten`] inserts it. Escape
+/// sequences are left out whole.
+pub(crate) fn flatten_paste(text: &str) -> String {
+    let text = strip_ansi_escapes(text);
+    let text = text.trim_end_matches(['\r', '\n']).replace("\r\n", "\n");
+    text.chars()
+        .filter_map(|c| match c {
+            '\n' | '\r' | '\t' => Some(' '),
+            c if c.is_control() => None,
+            c => Some(c),
+        })
+        .collect()
+}
+
+/// Most edits [`TextInputState`] keeps for undo.
+const UNDO_LIMIT: usize = 100;
+
+/// Whether a grapheme is part of a word, for word-wise movement.
+fn is_word(grapheme: &str) -> bool {
+    grapheme
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Grapheme index of the start of the word before grapheme `cursor`.
+pub(crate) fn word_start(s: &str, cursor: usize) -> usize {
+    let graphemes: Vec<&str> = s.graphemes(true).collect();
+    let mut i = cursor.min(graphemes.len());
+    while i > 0 && !is_word(graphemes[i - 1]) {
+        i -= 1;
+    }
+    while i > 0 && is_word(graphemes[i - 1]) {
+        i -= 1;
+    }
+    i
+}
+
+/// Grapheme index of the end of the word after grapheme `cursor`.
+pub(crate) fn word_end(s: &str, cursor: usize) -> usize {
+    let graphemes: Vec<&str> = s.graphemes(true).collect();
+    let mut i = cursor;
+    while i < graphemes.len() && !is_word(graphemes[i]) {
+        i += 1;
+    }
+    while i < graphemes.len() && is_word(graphemes[i]) {
+        i += 1;
+    }
+    i
+}
+
+/// Number of grapheme clusters in `s`.
+pub(crate) fn grapheme_count(s: &str) -> usize {
+    s.graphemes(true).count()
+}
+
+/// Byte offset of grapheme `idx` in `s` (or `s.len()` past the end).
+pub(crate) fn byte_offset(s: &str, idx: usize) -> usize {
+    s.grapheme_indices(true)
+        .nth(idx)
+        .map_or(s.len(), |(i, _)| i)
+}
+
+/// Join conjoining Hangul jamo in `s` into precomposed syllables: a
+/// leading consonant and a vowel make a syllable, which a trailing
+/// consonant can then close. Anything else is left as it is.
+pub(crate) fn compose_hangul(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut last: Option<char> = None;
+    for c in s.chars() {
+        if let Some(joined) = last.and_then(|prev| join_jamo(prev, c)) {
+            last = Some(joined);
+        } else if let Some(prev) = last.replace(c) {
+            out.push(prev);
+        }
+    }
+    out.extend(last);
+    out
+}
+
+/// The syllable `prev` and the jamo `c` make together, if any.
+fn join_jamo(prev: char, c: char) -> Option<char> {
+    const SYLLABLES: u32 = 0xAC00;
+    let (prev, c) = (prev as u32, c as u32);
+    let code = if (0x1100..=0x1112).contains(&prev) && (0x1161..=0x1175).contains(&c) {
+        SYLLABLES + ((prev - 0x1100) * 21 + (c - 0x1161)) * 28
+    } else if (SYLLABLES..=0xD7A3).contains(&prev)
+        && (prev - SYLLABLES).is_multiple_of(28)
+        && (0x11A8..=0x11C2).contains(&c)
+    {
+        prev + (c - 0x11A7)
+    } else {
+        return None;
+    };
+    char::from_u32(code)
+}
 
 /// Properties for the TextInput component.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct TextInputProps {
     /// The current text value.
     pub value: String,
     /// Placeholder text shown when value is empty.
     pub placeholder: Option<String>,
-    /// Cursor position (character index).
+    /// Cursor position (grapheme index).
     pub cursor: usize,
     /// Selection anchor (where selection started). None = no selection.
     pub selection_anchor: Option<usize>,
     /// Whether the input is focused (shows cursor).
     pub focused: bool,
-    /// Whether to mask the input (for passwords).
-    pub mask: bool,
-    /// Character to use for masking (default: '•').
-    pub mask_char: char,
+    /// Character shown in place of each character, for passwords.
+    pub mask: Option<char>,
     /// Text color.
     pub color: Option<Color>,
     /// Placeholder text color.
@@ -50,6 +225,45 @@ pub struct TextInputProps {
     pub dim: bool,
     /// Minimum width of the input field.
     pub min_width: Option<usize>,
+    /// Validation error, shown after the input.
+    pub error: Option<String>,
+    /// Color for an invalid input (defaults to the palette's error color).
+    pub error_color: Option<Color>,
+    /// Vim mode, for [`EditKeys::Vim`]; Normal mode draws a block cursor.
+    pub vim_mode: Option<VimMode>,
+    /// Input-method text in progress, drawn underlined at the cursor.
+    pub composition: Option<String>,
+    /// What a paste with line breaks or control characters does, for
+    /// [`TextInputState::handle_paste_with`].
+    pub paste_mode: PasteMode,
+    /// Receives pastes with [`PasteMode::Forward`].
+    pub on_paste: Option<PasteHandler>,
+}
+
+impl fmt::Debug for TextInputProps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextInputProps")
+            .field("value", &self.value)
+            .field("placeholder", &self.placeholder)
+            .field("cursor", &self.cursor)
+            .field("selection_anchor", &self.selection_anchor)
+            .field("focused", &self.focused)
+            .field("mask", &self.mask)
+            .field("color", &self.color)
+            .field("placeholder_color", &self.placeholder_color)
+            .field("cursor_color", &self.cursor_color)
+            .field("selection_color", &self.selection_color)
+            .field("bold", &self.bold)
+            .field("dim", &self.dim)
+            .field("min_width", &self.min_width)
+            .field("error", &self.error)
+            .field("error_color", &self.error_color)
+            .field("vim_mode", &self.vim_mode)
+            .field("composition", &self.composition)
+            .field("paste_mode", &self.paste_mode)
+            .field("on_paste", &self.on_paste.is_some())
+            .finish()
+    }
 }
 
 impl Default for TextInputProps {
@@ -60,8 +274,7 @@ impl Default for TextInputProps {
             cursor: 0,
             selection_anchor: None,
             focused: true,
-            mask: false,
-            mask_char: '•',
+            mask: None,
             color: None,
             placeholder_color: None,
             cursor_color: None,
@@ -69,6 +282,12 @@ impl Default for TextInputProps {
             bold: false,
             dim: false,
             min_width: None,
+            error: None,
+            error_color: None,
+            vim_mode: None,
+            composition: None,
+            paste_mode: PasteMode::default(),
+            on_paste: None,
         }
     }
 }
@@ -77,7 +296,7 @@ impl TextInputProps {
     /// Create new TextInputProps with the given value.
     pub fn new(value: impl Into<String>) -> Self {
         let value = value.into();
-        let cursor = value.len();
+        let cursor = grapheme_count(&value);
         Self {
             value,
             cursor,
@@ -89,7 +308,7 @@ impl TextInputProps {
     #[must_use]
     pub fn value(mut self, value: impl Into<String>) -> Self {
         self.value = value.into();
-        self.cursor = self.cursor.min(self.value.len());
+        self.cursor = self.cursor.min(grapheme_count(&self.value));
         self
     }
 
@@ -103,7 +322,7 @@ impl TextInputProps {
     /// Set the cursor position.
     #[must_use]
     pub fn cursor(mut self, cursor: usize) -> Self {
-        self.cursor = cursor.min(self.value.len());
+        self.cursor = cursor.min(grapheme_count(&self.value));
         self
     }
 
@@ -114,17 +333,16 @@ impl TextInputProps {
         self
     }
 
-    /// Enable password masking.
+    /// Enable password masking with '•'.
     #[must_use]
-    pub fn mask(mut self) -> Self {
-        self.mask = true;
-        self
+    pub fn mask(self) -> Self {
+        self.mask_char('•')
     }
 
-    /// Set the mask character.
+    /// Enable password masking with the given character.
     #[must_use]
     pub fn mask_char(mut self, c: char) -> Self {
-        self.mask_char = c;
+        self.mask = Some(c);
         self
     }
 
@@ -170,6 +388,20 @@ impl TextInputProps {
         self
     }
 
+    /// Show a validation error after the input.
+    #[must_use]
+    pub fn error(mut self, error: impl Into<String>) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
+    /// Set the color for an invalid input.
+    #[must_use]
+    pub fn error_color(mut self, color: Color) -> Self {
+        self.error_color = Some(color);
+        self
+    }
+
     /// Set selection anchor for text selection.
     #[must_use]
     pub fn selection(mut self, anchor: Option<usize>) -> Self {
@@ -190,60 +422,127 @@ impl TextInputProps {
         })
     }
 
+    /// The text as displayed: the value, or one mask character per
+    /// grapheme when masked.
+    fn display_value(&self) -> String {
+        match self.mask {
+            Some(c) => c.to_string().repeat(grapheme_count(&self.value)),
+            None => self.value.clone(),
+        }
+    }
+
+    /// Display column of the cursor, counting wide characters as two.
+    /// The cursor is drawn after any composition.
+    pub fn cursor_column(&self) -> usize {
+        if let Some((before, text, _)) = self.composition_parts() {
+            return before.width() + text.width();
+        }
+        let display = self.display_value();
+        display[..byte_offset(&display, self.cursor)].width()
+    }
+
+    /// Set the Vim mode shown by the cursor.
+    #[must_use]
+    pub fn vim_mode(mut self, mode: VimMode) -> Self {
+        self.vim_mode = Some(mode);
+        self
+    }
+
+    /// Show input-method text in progress at the cursor.
+    #[must_use]
+    pub fn composition(mut self, text: impl Into<String>) -> Self {
+        let text = text.into();
+        self.composition = (!text.is_empty()).then_some(text);
+        self
+    }
+
+    /// Choose what a paste with line breaks or control characters does
+    /// (see [`PasteMode`]).
+    #[must_use]
+    pub fn paste_mode(mut self, mode: PasteMode) -> Self {
+        self.paste_mode = mode;
+        self
+    }
+
+    /// Hand pastes with line breaks or control characters to `handler`
+    /// instead of inserting them. Sets [`PasteMode::Forward`].
+    #[must_use]
+    pub fn on_paste<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&str) + 'static,
+    {
+        self.paste_mode = PasteMode::Forward;
+        self.on_paste = Some(Rc::new(handler));
+        self
+    }
+
+    /// The display split around the composition: the text before the
+    /// cursor, the composition (masked like the value), then the cursor and
+    /// the rest. None when there's no composition or the input isn't
+    /// focused.
+    fn composition_parts(&self) -> Option<(String, String, String)> {
+        let text = self.composition.as_deref().filter(|_| self.focused)?;
+        let text = match self.mask {
+            Some(c) => c.to_string().repeat(grapheme_count(text)),
+            None => text.to_string(),
+        };
+        let display = self.display_value();
+        let (before, after) = display.split_at(byte_offset(&display, self.cursor));
+        let after = format!("{}{after}", self.cursor_glyph());
+        Some((before.to_string(), text, after))
+    }
+
+    fn cursor_glyph(&self) -> char {
+        match self.vim_mode {
+            Some(VimMode::Normal) => '█',
+            _ => '▏',
+        }
+    }
+
     /// Build the display string with cursor and selection.
     pub fn render_string(&self) -> String {
+        if let Some((before, text, after)) = self.composition_parts() {
+            return format!("{before}{text}{after}");
+        }
+        let cursor = self.cursor_glyph();
         if self.value.is_empty() {
             // Show placeholder or empty with cursor
             if let Some(ref placeholder) = self.placeholder {
                 if self.focused {
-                    format!("▏{}", placeholder)
+                    format!("{cursor}{placeholder}")
                 } else {
                     // Add space to maintain alignment with cursor
                     format!(" {}", placeholder)
                 }
             } else if self.focused {
-                "▏".to_string()
+                cursor.to_string()
             } else {
                 " ".to_string()
             }
         } else {
-            let display_value = if self.mask {
-                self.mask_char
-                    .to_string()
-                    .repeat(self.value.chars().count())
-            } else {
-                self.value.clone()
-            };
+            let display_value = self.display_value();
 
             if self.focused {
-                let char_count = display_value.chars().count();
-                let cursor_pos = self.cursor.min(char_count);
+                let cursor_pos = self.cursor;
+                let at = |idx: usize| byte_offset(&display_value, idx);
 
                 // Check for selection
                 if let Some((sel_start, sel_end)) = self.selection_range() {
-                    let sel_start = sel_start.min(char_count);
-                    let sel_end = sel_end.min(char_count);
-
                     // Build string with selection markers [selected]
-                    let before: String = display_value.chars().take(sel_start).collect();
-                    let selected: String = display_value
-                        .chars()
-                        .skip(sel_start)
-                        .take(sel_end - sel_start)
-                        .collect();
-                    let after: String = display_value.chars().skip(sel_end).collect();
+                    let before = &display_value[..at(sel_start)];
+                    let selected = &display_value[at(sel_start)..at(sel_end)];
+                    let after = &display_value[at(sel_end)..];
 
                     // Show cursor position within the selection context
                     if cursor_pos == sel_end {
-                        format!("{}[{}]▏{}", before, selected, after)
+                        format!("{before}[{selected}]{cursor}{after}")
                     } else {
-                        format!("{}▏[{}]{}", before, selected, after)
+                        format!("{before}{cursor}[{selected}]{after}")
                     }
                 } else {
                     // No selection, just cursor
-                    let before: String = display_value.chars().take(cursor_pos).collect();
-                    let after: String = display_value.chars().skip(cursor_pos).collect();
-                    format!("{}▏{}", before, after)
+                    let (before, after) = display_value.split_at(at(cursor_pos));
+                    format!("{before}{cursor}{after}")
                 }
             } else {
                 display_value
@@ -286,13 +585,20 @@ impl Component for TextInput {
     type Props = TextInputProps;
 
     fn render(props: &Self::Props) -> Element {
-        let content = props.render_string();
+        let mut content = props.render_string();
 
         let mut style = Style::new();
 
         // Choose color based on state
         // Only apply placeholder styling when NOT focused (so cursor stays visible when focused)
-        if props.value.is_empty() && props.placeholder.is_some() && !props.focused {
+        if let Some(error) = &props.error {
+            content = format!("{content}  {error}");
+            let color = props.error_color.unwrap_or_else(|| Palette::detect().error);
+            style = style.fg(color);
+            if props.bold {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+        } else if props.value.is_empty() && props.placeholder.is_some() && !props.focused {
             // Placeholder style (unfocused)
             if let Some(color) = props.placeholder_color {
                 style = style.fg(color);
@@ -312,6 +618,15 @@ impl Component for TextInput {
             }
         }
 
+        if props.error.is_none() {
+            if let Some((before, text, after)) = props.composition_parts() {
+                return Element::row(vec![
+                    Element::styled_text(before, style),
+                    Element::styled_text(text, style.add_modifier(Modifier::UNDERLINED)),
+                    Element::styled_text(after, style),
+                ]);
+            }
+        }
         Element::styled_text(&content, style)
     }
 }
@@ -320,14 +635,56 @@ impl Component for TextInput {
 ///
 /// This provides a convenient way to manage the value, cursor position,
 /// selection, and handle common editing operations.
-#[derive(Debug, Clone, Default)]
+#[derive(Clone, Default)]
 pub struct TextInputState {
     /// The current text value.
     pub value: String,
-    /// Cursor position (character index).
+    /// Cursor position (grapheme index).
     pub cursor: usize,
     /// Selection anchor (where selection started). None = no selection.
     pub selection_anchor: Option<usize>,
+    /// Most graphemes the value may hold. Longer input is cut off.
+    pub max_length: Option<usize>,
+    /// The validator's message for the current value.
+    pub error: Option<String>,
+    /// Text last copied or cut, inserted by [`paste`](Self::paste).
+    pub clipboard: String,
+    validator: Option<TextValidator>,
+    /// Value and cursor before each edit, newest last.
+    undo_stack: Vec<(String, usize)>,
+    /// Value and cursor of each undone edit, newest last.
+    redo_stack: Vec<(String, usize)>,
+    /// Cursor after the last typed character, while a word is being typed,
+    /// so typing a word is undone in one step.
+    typing_at: Option<usize>,
+    /// Editing key preset and Vim mode.
+    edit: EditState,
+    /// Input-method text in progress, not yet part of the value.
+    composition: String,
+    /// What to do with multi-line pastes.
+    paste_mode: PasteMode,
+    on_paste: Option<PasteHandler>,
+}
+
+impl fmt::Debug for TextInputState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextInputState")
+            .field("value", &self.value)
+            .field("cursor", &self.cursor)
+            .field("selection_anchor", &self.selection_anchor)
+            .field("max_length", &self.max_length)
+            .field("error", &self.error)
+            .field("clipboard", &self.clipboard)
+            .field("validator", &self.validator.is_some())
+            .field("undo_stack", &self.undo_stack.len())
+            .field("redo_stack", &self.redo_stack.len())
+            .field("edit_keys", &self.edit.keys)
+            .field("vim_mode", &self.vim_mode())
+            .field("composition", &self.composition)
+            .field("paste_mode", &self.paste_mode)
+            .field("on_paste", &self.on_paste.is_some())
+            .finish()
+    }
 }
 
 impl TextInputState {
@@ -339,28 +696,215 @@ impl TextInputState {
     /// Create a new state with initial value.
     pub fn with_value(value: impl Into<String>) -> Self {
         let value = value.into();
-        let cursor = value.len();
+        let cursor = grapheme_count(&value);
         Self {
             value,
             cursor,
-            selection_anchor: None,
+            ..Default::default()
         }
     }
 
+    /// Limit the value to `max` graphemes, cutting off any excess now.
+    #[must_use]
+    pub fn max_length(mut self, max: usize) -> Self {
+        self.max_length = Some(max);
+        self.value.truncate(byte_offset(&self.value, max));
+        self.cursor = self.cursor.min(self.len());
+        self.validate();
+        self
+    }
+
+    /// Check the value with `validator` now and after every edit.
+    #[must_use]
+    pub fn validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'static,
+    {
+        self.validator = Some(Rc::new(validator));
+        self.validate();
+        self
+    }
+
+    /// Choose what a paste with line breaks or control characters does
+    /// (see [`PasteMode`]).
+    #[must_use]
+    pub fn paste_mode(mut self, mode: PasteMode) -> Self {
+        self.paste_mode = mode;
+        self
+    }
+
+    /// Hand pastes with line breaks or control characters to `handler`
+    /// instead of inserting them. Sets [`PasteMode::Forward`].
+    #[must_use]
+    pub fn on_paste<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&str) + 'static,
+    {
+        self.paste_mode = PasteMode::Forward;
+        self.on_paste = Some(Rc::new(handler));
+        self
+    }
+
+    /// Use the Emacs or Vim editing keys (see [`EditKeys`]).
+    #[must_use]
+    pub fn edit_keys(mut self, keys: EditKeys) -> Self {
+        self.edit = EditState::new(keys);
+        self
+    }
+
+    /// The Vim mode, with [`EditKeys::Vim`].
+    pub fn vim_mode(&self) -> Option<VimMode> {
+        self.edit.vim_mode()
+    }
+
+    /// Run the validator, updating `error`. Returns true if valid.
+    pub fn validate(&mut self) -> bool {
+        self.error = self
+            .validator
+            .as_ref()
+            .and_then(|validate| validate(&self.value).err());
+        self.error.is_none()
+    }
+
+    /// Whether the value passed validation (always true without a validator).
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Number of graphemes in the value (the largest cursor position).
+    fn len(&self) -> usize {
+        grapheme_count(&self.value)
+    }
+
+    /// Byte offset of grapheme `idx` in the value.
+    fn byte_at(&self, idx: usize) -> usize {
+        byte_offset(&self.value, idx)
+    }
+
+    /// Save the value and cursor for undo, before an edit.
+    fn record(&mut self) {
+        if self.undo_stack.len() == UNDO_LIMIT {
+            self.undo_stack.remove(0);
+        }
+        self.undo_stack.push((self.value.clone(), self.cursor));
+        self.redo_stack.clear();
+        self.typing_at = None;
+    }
+
+    /// Swap the current value for a saved one, keeping the current one on
+    /// the other stack.
+    fn restore(&mut self, from_undo: bool) -> bool {
+        let (from, to) = if from_undo {
+            (&mut self.undo_stack, &mut self.redo_stack)
+        } else {
+            (&mut self.redo_stack, &mut self.undo_stack)
+        };
+        let Some((value, cursor)) = from.pop() else {
+            return false;
+        };
+        to.push((std::mem::replace(&mut self.value, value), self.cursor));
+        self.cursor = cursor;
+        self.selection_anchor = None;
+        self.typing_at = None;
+        self.validate();
+        true
+    }
+
+    /// Undo the last edit. Returns false if there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        self.restore(true)
+    }
+
+    /// Redo the last undone edit. Returns false if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        self.restore(false)
+    }
+
+    /// Whether there is an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Whether there is an undone edit to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
     /// Insert a character at the cursor position.
     /// If there's a selection, it's deleted first.
+    ///
+    /// Typing a word is undone in one step.
     pub fn insert(&mut self, c: char) {
-        self.delete_selection();
-        self.value.insert(self.cursor, c);
-        self.cursor += 1;
+        let typing = !c.is_whitespace() && !self.has_selection();
+        if !typing || self.typing_at != Some(self.cursor) {
+            self.record();
+        }
+        self.put_str(c.encode_utf8(&mut [0; 4]));
+        self.typing_at = typing.then_some(self.cursor);
     }
 
     /// Insert a string at the cursor position.
     /// If there's a selection, it's deleted first.
+    ///
+    /// A combining mark joins the grapheme before the cursor, so the cursor
+    /// lands after the combined character.
+    ///
+    /// With a max length, only as much of `s` as fits is inserted.
     pub fn insert_str(&mut self, s: &str) {
-        self.delete_selection();
-        self.value.insert_str(self.cursor, s);
-        self.cursor += s.len();
+        self.record();
+        self.put_str(s);
+    }
+
+    /// [`insert_str`](Self::insert_str) without saving for undo.
+    ///
+    /// Hangul jamo join the syllable before the cursor.
+    fn put_str(&mut self, s: &str) {
+        self.remove_selection();
+        let s = match self.max_length {
+            Some(max) => &s[..byte_offset(s, max.saturating_sub(self.len()))],
+            None => s,
+        };
+        let at = self.byte_at(self.cursor);
+        let start = self.value[..at]
+            .chars()
+            .next_back()
+            .map_or(at, |prev| at - prev.len_utf8());
+        let joined = compose_hangul(&format!("{}{s}", &self.value[start..at]));
+        self.value.replace_range(start..at, &joined);
+        self.cursor = grapheme_count(&self.value[..start + joined.len()]);
+        self.validate();
+    }
+
+    /// Show `text` at the cursor as input-method composition in progress,
+    /// replacing any earlier composition. It isn't part of the value until
+    /// [`commit_composition`](Self::commit_composition).
+    ///
+    /// This is for an input method the app runs itself (romaji to kana,
+    /// pinyin); the system's input method commits text as typed
+    /// characters.
+    pub fn compose(&mut self, text: impl Into<String>) {
+        self.composition = compose_hangul(&text.into());
+    }
+
+    /// The composition in progress, if any.
+    pub fn composition(&self) -> Option<&str> {
+        (!self.composition.is_empty()).then_some(self.composition.as_str())
+    }
+
+    /// Insert the composition at the cursor as one edit. Returns false if
+    /// there was none.
+    pub fn commit_composition(&mut self) -> bool {
+        if self.composition.is_empty() {
+            return false;
+        }
+        let text = std::mem::take(&mut self.composition);
+        self.insert_str(&text);
+        true
+    }
+
+    /// Drop the composition. Returns false if there was none.
+    pub fn cancel_composition(&mut self) -> bool {
+        !std::mem::take(&mut self.composition).is_empty()
     }
 
     /// Delete the character before the cursor (backspace).
@@ -371,8 +915,11 @@ impl TextInputState {
             return true;
         }
         if self.cursor > 0 {
+            self.record();
             self.cursor -= 1;
-            self.value.remove(self.cursor);
+            let range = self.byte_at(self.cursor)..self.byte_at(self.cursor + 1);
+            self.value.replace_range(range, "");
+            self.validate();
             true
         } else {
             false
@@ -386,8 +933,11 @@ impl TextInputState {
             self.delete_selection();
             return true;
         }
-        if self.cursor < self.value.len() {
-            self.value.remove(self.cursor);
+        if self.cursor < self.len() {
+            self.record();
+            let range = self.byte_at(self.cursor)..self.byte_at(self.cursor + 1);
+            self.value.replace_range(range, "");
+            self.validate();
             true
         } else {
             false
@@ -408,7 +958,7 @@ impl TextInputState {
     /// Move cursor right (clears selection).
     pub fn move_right(&mut self) -> bool {
         self.clear_selection();
-        if self.cursor < self.value.len() {
+        if self.cursor < self.len() {
             self.cursor += 1;
             true
         } else {
@@ -416,6 +966,54 @@ impl TextInputState {
         }
     }
 
+    /// Grapheme index of the start of the word before the cursor.
+    fn word_start(&self) -> usize {
+        word_start(&self.value, self.cursor)
+    }
+
+    /// Grapheme index of the end of the word after the cursor.
+    fn word_end(&self) -> usize {
+        word_end(&self.value, self.cursor)
+    }
+
+    /// Move cursor to the start of the previous word (clears selection).
+    pub fn move_word_left(&mut self) -> bool {
+        self.clear_selection();
+        let start = self.word_start();
+        let moved = start != self.cursor;
+        self.cursor = start;
+        moved
+    }
+
+    /// Move cursor to the end of the next word (clears selection).
+    pub fn move_word_right(&mut self) -> bool {
+        self.clear_selection();
+        let end = self.word_end();
+        let moved = end != self.cursor;
+        self.cursor = end;
+        moved
+    }
+
+    /// Delete from the start of the previous word to the cursor.
+    /// If there's a selection, deletes the selection instead.
+    pub fn delete_word_left(&mut self) -> bool {
+        if !self.has_selection() {
+            self.selection_anchor = Some(self.cursor);
+            self.cursor = self.word_start();
+        }
+        self.delete_selection().is_some()
+    }
+
+    /// Delete from the cursor to the end of the next word.
+    /// If there's a selection, deletes the selection instead.
+    pub fn delete_word_right(&mut self) -> bool {
+        if !self.has_selection() {
+            self.selection_anchor = Some(self.cursor);
+            self.cursor = self.word_end();
+        }
+        self.delete_selection().is_some()
+    }
+
     /// Move cursor to the start (clears selection).
     pub fn move_home(&mut self) {
         self.clear_selection();
@@ -425,19 +1023,26 @@ impl TextInputState {
     /// Move cursor to the end (clears selection).
     pub fn move_end(&mut self) {
         self.clear_selection();
-        self.cursor = self.value.len();
+        self.cursor = self.len();
     }
 
     /// Clear the input.
     pub fn clear(&mut self) {
+        self.record();
         self.value.clear();
         self.cursor = 0;
+        self.validate();
     }
 
     /// Set the value and move cursor to end.
     pub fn set_value(&mut self, value: impl Into<String>) {
+        self.record();
         self.value = value.into();
-        self.cursor = self.value.len();
+        if let Some(max) = self.max_length {
+            self.value.truncate(byte_offset(&self.value, max));
+        }
+        self.cursor = self.len();
+        self.validate();
     }
 
     /// Get the current value.
@@ -445,6 +1050,11 @@ impl TextInputState {
         &self.value
     }
 
+    /// Display column of the cursor, counting wide characters as two.
+    pub fn cursor_column(&self) -> usize {
+        self.value[..self.byte_at(self.cursor)].width()
+    }
+
     /// Check if the input is empty.
     pub fn is_empty(&self) -> bool {
         self.value.is_empty()
@@ -469,7 +1079,7 @@ impl TextInputState {
     /// Get the selected text.
     pub fn selected_text(&self) -> Option<&str> {
         self.selection_range()
-            .map(|(start, end)| &self.value[start..end])
+            .map(|(start, end)| &self.value[self.byte_at(start)..self.byte_at(end)])
     }
 
     /// Clear the selection without deleting text.
@@ -479,11 +1089,23 @@ impl TextInputState {
 
     /// Delete the selected text and return it.
     pub fn delete_selection(&mut self) -> Option<String> {
+        if self.has_selection() {
+            self.record();
+        }
+        self.remove_selection()
+    }
+
+    /// [`delete_selection`](Self::delete_selection) without saving for undo.
+    fn remove_selection(&mut self) -> Option<String> {
         if let Some((start, end)) = self.selection_range() {
             if start != end {
-                let deleted: String = self.value.drain(start..end).collect();
+                let deleted: String = self
+                    .value
+                    .drain(self.byte_at(start)..self.byte_at(end))
+                    .collect();
                 self.cursor = start;
                 self.selection_anchor = None;
+                self.validate();
                 return Some(deleted);
             }
         }
@@ -494,7 +1116,7 @@ impl TextInputState {
     /// Select all text.
     pub fn select_all(&mut self) {
         self.selection_anchor = Some(0);
-        self.cursor = self.value.len();
+        self.cursor = self.len();
     }
 
     /// Move cursor left with selection (shift+left).
@@ -515,7 +1137,7 @@ impl TextInputState {
         if self.selection_anchor.is_none() {
             self.selection_anchor = Some(self.cursor);
         }
-        if self.cursor < self.value.len() {
+        if self.cursor < self.len() {
             self.cursor += 1;
             true
         } else {
@@ -523,6 +1145,28 @@ impl TextInputState {
         }
     }
 
+    /// Extend the selection to the start of the previous word.
+    pub fn select_word_left(&mut self) -> bool {
+        if self.selection_anchor.is_none() {
+            self.selection_anchor = Some(self.cursor);
+        }
+        let start = self.word_start();
+        let moved = start != self.cursor;
+        self.cursor = start;
+        moved
+    }
+
+    /// Extend the selection to the end of the next word.
+    pub fn select_word_right(&mut self) -> bool {
+        if self.selection_anchor.is_none() {
+            self.selection_anchor = Some(self.cursor);
+        }
+        let end = self.word_end();
+        let moved = end != self.cursor;
+        self.cursor = end;
+        moved
+    }
+
     /// Select to beginning (shift+home).
     pub fn select_to_home(&mut self) {
         if self.selection_anchor.is_none() {
@@ -536,7 +1180,212 @@ impl TextInputState {
         if self.selection_anchor.is_none() {
             self.selection_anchor = Some(self.cursor);
         }
-        self.cursor = self.value.len();
+        self.cursor = self.len();
+    }
+
+    /// Copy the selection into [`clipboard`](Self::clipboard) and return it.
+    pub fn copy(&mut self) -> Option<String> {
+        let text = self.selected_text().filter(|t| !t.is_empty())?.to_string();
+        self.clipboard = text.clone();
+        Some(text)
+    }
+
+    /// Cut the selection into [`clipboard`](Self::clipboard) and return it.
+    pub fn cut(&mut self) -> Option<String> {
+        let text = self.copy()?;
+        self.delete_selection();
+        Some(text)
+    }
+
+    /// Insert [`clipboard`](Self::clipboard) at the cursor, replacing any
+    /// selection, as [`handle_paste`](Self::handle_paste) would. Returns
+    /// false if the clipboard is empty.
+    pub fn paste(&mut self) -> bool {
+        if self.clipboard.is_empty() {
+            return false;
+        }
+        let text = self.clipboard.clone();
+        self.handle_paste(&text)
+    }
+
+    /// Insert pasted text at the cursor, replacing any selection. Text with
+    /// line breaks or control characters goes by the
+    /// [`PasteMode`]: flattened onto one line, ignored, or passed to the
+    /// [`on_paste`](Self::on_paste) handler.
+    ///
+    /// Returns false if the paste was ignored.
+    pub fn handle_paste(&mut self, text: &str) -> bool {
+        let handler = self.on_paste.clone();
+        self.paste_by(text, self.paste_mode, handler.as_ref())
+    }
+
+    /// Like [`handle_paste`](Self::handle_paste), but going by the
+    /// [`paste_mode`](TextInputProps::paste_mode) and
+    /// [`on_paste`](TextInputProps::on_paste) handler of `props`.
+    pub fn handle_paste_with(&mut self, text: &str, props: &TextInputProps) -> bool {
+        self.paste_by(text, props.paste_mode, props.on_paste.as_ref())
+    }
+
+    fn paste_by(&mut self, text: &str, mode: PasteMode, handler: Option<&PasteHandler>) -> bool {
+        if !is_unsafe_paste(text) {
+            self.insert_str(text);
+            return true;
+        }
+        match mode {
+            PasteMode::Flatten => {
+                self.insert_str(&flatten_paste(text));
+                true
+            }
+            PasteMode::Reject => false,
+            PasteMode::Forward => match handler {
+                Some(handler) => {
+                    handler(text);
+                    true
+                }
+                None => false,
+            },
+        }
+    }
+
+    /// Select with `select` and cut the selection. Returns false (leaving
+    /// no selection) if there was nothing to cut.
+    fn kill(&mut self, select: impl FnOnce(&mut Self)) -> bool {
+        self.clear_selection();
+        select(self);
+        let killed = self.cut().is_some();
+        self.clear_selection();
+        killed
+    }
+
+    fn apply(&mut self, command: EditCommand) -> bool {
+        use EditCommand::*;
+        self.clear_selection();
+        match command {
+            Left => self.move_left(),
+            Right => self.move_right(),
+            WordLeft => self.move_word_left(),
+            WordRight => self.move_word_right(),
+            LineStart | Start | OpenAbove => {
+                self.move_home();
+                true
+            }
+            LineEnd | End | OpenBelow => {
+                self.move_end();
+                true
+            }
+            Delete => self.delete(),
+            Backspace => self.backspace(),
+            DeleteWordLeft => self.kill(|s| {
+                s.select_word_left();
+            }),
+            DeleteWordRight => self.kill(|s| {
+                s.select_word_right();
+            }),
+            KillToEnd => self.kill(Self::select_to_end),
+            KillToStart => self.kill(Self::select_to_home),
+            KillLine => self.kill(Self::select_all),
+            Yank => self.paste(),
+            YankAfter => {
+                self.move_right();
+                self.paste()
+            }
+            Undo => self.undo(),
+            Redo => self.redo(),
+            Up | Down => false,
+        }
+    }
+
+    /// Handle typing and the editing keys listed in the
+    /// [module docs](self), plus the [`EditKeys`] preset.
+    ///
+    /// During a [composition](Self::compose), Enter commits it, Esc drops
+    /// it and Backspace removes its last character; any other key commits
+    /// it before doing what it usually does.
+    ///
+    /// Returns true if the key was handled.
+    pub fn handle_key(&mut self, key: &Key) -> bool {
+        if !self.composition.is_empty() {
+            match key.code {
+                KeyCode::Enter => return self.commit_composition(),
+                KeyCode::Esc => return self.cancel_composition(),
+                KeyCode::Backspace => {
+                    let last = grapheme_count(&self.composition) - 1;
+                    self.composition
+                        .truncate(byte_offset(&self.composition, last));
+                    return true;
+                }
+                _ => {
+                    self.commit_composition();
+                }
+            }
+        }
+        match translate(&mut self.edit, key) {
+            Translated::Command(command) => {
+                self.apply(command);
+                return true;
+            }
+            Translated::Consumed => return true,
+            Translated::Pass => {}
+        }
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        let word = ctrl || alt;
+        match key.code {
+            KeyCode::Left => match (word, shift) {
+                (true, true) => self.select_word_left(),
+                (true, false) => self.move_word_left(),
+                (false, true) => self.select_left(),
+                (false, false) => self.move_left(),
+            },
+            KeyCode::Right => match (word, shift) {
+                (true, true) => self.select_word_right(),
+                (true, false) => self.move_word_right(),
+                (false, true) => self.select_right(),
+                (false, false) => self.move_right(),
+            },
+            KeyCode::Home if shift => {
+                self.select_to_home();
+                true
+            }
+            KeyCode::Home => {
+                self.move_home();
+                true
+            }
+            KeyCode::End if shift => {
+                self.select_to_end();
+                true
+            }
+            KeyCode::End => {
+                self.move_end();
+                true
+            }
+            KeyCode::Backspace if word => self.delete_word_left(),
+            KeyCode::Backspace => self.backspace(),
+            KeyCode::Delete if word => self.delete_word_right(),
+            KeyCode::Delete => self.delete(),
+            KeyCode::Char(c) if ctrl => match c.to_ascii_lowercase() {
+                'a' => {
+                    self.select_all();
+                    true
+                }
+                'c' => self.copy().is_some(),
+                'x' => self.cut().is_some(),
+                'v' => self.paste(),
+                'w' => self.delete_word_left(),
+                'z' if shift => self.redo(),
+                'z' => self.undo(),
+                'y' => self.redo(),
+                _ => return false,
+            },
+            KeyCode::Char('d') if alt => self.delete_word_right(),
+            KeyCode::Char(c) if !alt => {
+                self.insert(c);
+                true
+            }
+            _ => return false,
+        };
+        true
     }
 
     /// Convert to props for rendering.
@@ -545,6 +1394,11 @@ impl TextInputState {
             value: self.value.clone(),
             cursor: self.cursor,
             selection_anchor: self.selection_anchor,
+            error: self.error.clone(),
+            vim_mode: self.vim_mode(),
+            composition: self.composition().map(str::to_string),
+            paste_mode: self.paste_mode,
+            on_paste: self.on_paste.clone(),
             ..Default::default()
         }
     }
@@ -560,7 +1414,7 @@ mod tests {
         assert!(props.value.is_empty());
         assert_eq!(props.cursor, 0);
         assert!(props.focused);
-        assert!(!props.mask);
+        assert!(props.mask.is_none());
     }
 
     #[test]
@@ -582,7 +1436,7 @@ mod tests {
         assert_eq!(props.placeholder, Some("Enter text".to_string()));
         assert_eq!(props.cursor, 2);
         assert_eq!(props.color, Some(Color::Green));
-        assert!(props.mask);
+        assert_eq!(props.mask, Some('•'));
     }
 
     #[test]
@@ -642,6 +1496,13 @@ mod tests {
         assert_eq!(props.render_string(), "•••");
     }
 
+    #[test]
+    fn test_text_input_mask_char_enables_masking() {
+        let props = TextInputProps::new("abc").mask_char('*').focused(false);
+        assert_eq!(props.mask, Some('*'));
+        assert_eq!(props.render_string(), "***");
+    }
+
     #[test]
     fn test_text_input_state_new() {
         let state = TextInputState::new();
@@ -728,6 +1589,150 @@ mod tests {
         assert_eq!(state.cursor, 0);
     }
 
+    #[test]
+    fn test_text_input_state_graphemes() {
+        // "e" + combining acute, a family emoji (ZWJ sequence), a wide char
+        let mut state = TextInputState::with_value("e\u{301}👨\u{200d}👩\u{200d}👧漢");
+        assert_eq!(state.cursor, 3);
+        assert_eq!(state.cursor_column(), 5);
+
+        assert!(state.move_left());
+        assert_eq!(state.cursor_column(), 3);
+        assert!(state.backspace());
+        assert_eq!(state.value(), "e\u{301}漢");
+        assert_eq!(state.cursor, 1);
+
+        state.move_home();
+        assert!(state.delete());
+        assert_eq!(state.value(), "漢");
+
+        state.insert('a');
+        state.insert('\u{308}');
+        assert_eq!(state.value(), "a\u{308}漢");
+        assert_eq!(state.cursor, 1);
+
+        state.select_right();
+        assert_eq!(state.selected_text(), Some("漢"));
+        let props = state.to_props();
+        assert_eq!(props.render_string(), "a\u{308}[漢]▏");
+        assert_eq!(props.cursor_column(), 3);
+    }
+
+    #[test]
+    fn test_text_input_state_joins_hangul_jamo() {
+        // 한글 typed as conjoining jamo
+        let mut state = TextInputState::new();
+        for c in "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}".chars() {
+            state.insert(c);
+        }
+        assert_eq!(state.value(), "한글");
+        assert_eq!(state.cursor, 2);
+        assert_eq!(state.cursor_column(), 4);
+        assert!(state.undo());
+        assert_eq!(state.value(), "");
+
+        // Pasted decomposed text, and jamo that don't make a syllable
+        state.insert_str("a\u{1112}\u{1161}\u{1112}");
+        assert_eq!(state.value(), "a하\u{1112}");
+        assert_eq!(compose_hangul("\u{1161}\u{11AB}"), "\u{1161}\u{11AB}");
+    }
+
+    #[test]
+    fn test_text_input_composition() {
+        let mut state = TextInputState::with_value("ab");
+        state.move_left();
+        state.compose("にほ");
+        assert_eq!(state.composition(), Some("にほ"));
+        assert_eq!(state.value(), "ab");
+        let props = state.to_props();
+        assert_eq!(props.render_string(), "aにほ▏b");
+        assert_eq!(props.cursor_column(), 5);
+
+        // Backspace edits the composition, Esc drops it
+        assert!(state.handle_key(&Key::new(KeyCode::Backspace)));
+        assert_eq!(state.composition(), Some("に"));
+        assert!(state.handle_key(&Key::new(KeyCode::Esc)));
+        assert_eq!(state.composition(), None);
+        assert_eq!(state.value(), "ab");
+
+        // Enter commits it; another key commits it and then acts
+        state.compose("日本");
+        assert!(state.handle_key(&Key::new(KeyCode::Enter)));
+        assert_eq!(state.value(), "a日本b");
+        assert_eq!(state.cursor, 3);
+        state.compose("語");
+        assert!(state.handle_key(&Key::new(KeyCode::Char('!'))));
+        assert_eq!(state.value(), "a日本語!b");
+        assert!(state.undo());
+        assert!(state.undo());
+        assert_eq!(state.value(), "a日本b");
+
+        // Drawn underlined, and masked in password fields
+        let props = TextInputProps::new("x").composition("語");
+        let Element::Node { children, .. } = TextInput::render(&props) else {
+            panic!("expected the composition as its own text");
+        };
+        let Element::Text { content, style } = &children[1] else {
+            panic!("expected text");
+        };
+        assert_eq!(content, "語");
+        assert!(style.modifiers.contains(Modifier::UNDERLINED));
+        let props = props.mask().composition("語");
+        assert_eq!(props.render_string(), "••▏");
+    }
+
+    #[test]
+    fn test_text_input_paste_modes() {
+        use std::cell::RefCell;
+
+        let mut state = TextInputState::with_value("[]");
+        state.move_left();
+        assert!(state.handle_paste("a b"));
+        assert!(state.handle_paste("one\r\ntwo\tthree\u{1b}[31m\n\n"));
+        assert_eq!(state.value(), "[a bone two three]");
+        // One undo step per paste
+        assert!(state.undo());
+        assert_eq!(state.value(), "[a b]");
+
+        let mut state = TextInputState::new().paste_mode(PasteMode::Reject);
+        assert!(!state.handle_paste("rm -rf /\n"));
+        assert!(state.handle_paste("ok"));
+        assert_eq!(state.value(), "ok");
+        state.clipboard = "x\ny".to_string();
+        assert!(!state.paste());
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let log = seen.clone();
+        let mut state =
+            TextInputState::new().on_paste(move |text| log.borrow_mut().push(text.to_string()));
+        assert!(state.handle_paste("line 1\nline 2"));
+        assert!(state.handle_paste("plain"));
+        assert_eq!(state.value(), "plain");
+        assert_eq!(*seen.borrow(), vec!["line 1\nline 2".to_string()]);
+    }
+
+    #[test]
+    fn test_text_input_paste_mode_props() {
+        use std::cell::RefCell;
+
+        let props = TextInputProps::new("").paste_mode(PasteMode::Reject);
+        let mut state = TextInputState::new();
+        assert!(!state.handle_paste_with("a\nb", &props));
+        assert!(state.handle_paste("a\nb"));
+        assert_eq!(state.value(), "a b");
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let log = seen.clone();
+        let props = TextInputProps::new("").on_paste(move |text| log.borrow_mut().push(text.len()));
+        assert_eq!(props.paste_mode, PasteMode::Forward);
+        assert!(state.handle_paste_with("\u{1b}[1mx", &props));
+        assert_eq!(*seen.borrow(), vec![5]);
+
+        // The state's settings carry over to its props
+        let state = TextInputState::new().paste_mode(PasteMode::Reject);
+        assert_eq!(state.to_props().paste_mode, PasteMode::Reject);
+    }
+
     #[test]
     fn test_text_input_component_render() {
         let props = TextInputProps::new("Test").focused(true);
@@ -739,4 +1744,176 @@ mod tests {
             _ => panic!("Expected Text element"),
         }
     }
+
+    #[test]
+    fn test_text_input_state_max_length() {
+        let mut state = TextInputState::with_value("abcdef").max_length(4);
+        assert_eq!(state.value(), "abcd");
+        assert_eq!(state.cursor, 4);
+
+        state.insert('x');
+        assert_eq!(state.value(), "abcd");
+
+        state.move_home();
+        state.select_right();
+        state.insert_str("XYZ"); // Replaces one grapheme, so one fits
+        assert_eq!(state.value(), "Xbcd");
+        assert_eq!(state.cursor, 1);
+
+        state.set_value("e\u{301}e\u{301}e\u{301}e\u{301}e\u{301}");
+        assert_eq!(state.value(), "e\u{301}e\u{301}e\u{301}e\u{301}");
+    }
+
+    #[test]
+    fn test_text_input_state_validator() {
+        let mut state = TextInputState::new().validator(|v| {
+            if v.contains('@') {
+                Ok(())
+            } else {
+                Err("Not an email".into())
+            }
+        });
+        assert_eq!(state.error.as_deref(), Some("Not an email"));
+
+        state.insert_str("me@example.com");
+        assert!(state.is_valid());
+
+        state.move_home();
+        state.move_right();
+        state.move_right();
+        assert!(state.delete());
+        assert!(!state.is_valid());
+
+        let props = state.to_props().error_color(Color::Red);
+        match TextInput::render(&props) {
+            Element::Text { content, style } => {
+                assert_eq!(content, "me▏example.com  Not an email");
+                assert_eq!(style.fg, Color::Red);
+            }
+            _ => panic!("Expected Text element"),
+        }
+    }
+
+    #[test]
+    fn test_text_input_state_words() {
+        let mut state = TextInputState::with_value("let foo_bar = 42;");
+        assert!(state.move_word_left());
+        assert_eq!(state.cursor, 14);
+        state.move_word_left();
+        assert_eq!(state.cursor, 4);
+        assert!(state.move_word_right());
+        assert_eq!(state.cursor, 11);
+
+        assert!(state.select_word_right());
+        assert_eq!(state.selected_text(), Some(" = 42"));
+        state.clear_selection();
+
+        assert!(state.delete_word_left());
+        assert_eq!(state.value(), "let foo_bar = ;");
+        assert_eq!(state.cursor, 14);
+        state.move_home();
+        assert!(state.delete_word_right());
+        assert_eq!(state.value(), " foo_bar = ;");
+    }
+
+    #[test]
+    fn test_text_input_state_clipboard() {
+        let mut state = TextInputState::with_value("hello world");
+        assert_eq!(state.copy(), None);
+
+        state.select_word_left();
+        assert_eq!(state.cut(), Some("world".to_string()));
+        assert_eq!(state.value(), "hello ");
+
+        state.move_home();
+        assert!(state.paste());
+        assert_eq!(state.value(), "worldhello ");
+        assert_eq!(state.cursor, 5);
+    }
+
+    #[test]
+    fn test_text_input_state_undo_redo() {
+        let mut state = TextInputState::new();
+        for c in "hi there".chars() {
+            state.insert(c);
+        }
+        state.backspace();
+        assert_eq!(state.value(), "hi ther");
+
+        assert!(state.undo());
+        assert_eq!(state.value(), "hi there");
+        // The word typed after the space goes in one step
+        assert!(state.undo());
+        assert_eq!(state.value(), "hi ");
+        assert!(state.undo());
+        assert_eq!(state.value(), "hi");
+        assert!(state.undo());
+        assert_eq!(state.value(), "");
+        assert!(!state.undo());
+
+        assert!(state.redo());
+        assert_eq!(state.value(), "hi");
+        assert_eq!(state.cursor, 2);
+
+        state.insert('!');
+        assert!(!state.can_redo());
+    }
+
+    #[test]
+    fn test_text_input_state_handle_key() {
+        let mut state = TextInputState::new();
+        for c in "abc def".chars() {
+            assert!(state.handle_key(&Key::new(KeyCode::Char(c))));
+        }
+        state.handle_key(&Key::with_alt(KeyCode::Backspace));
+        assert_eq!(state.value(), "abc ");
+
+        state.handle_key(&Key::with_ctrl(KeyCode::Char('z')));
+        assert_eq!(state.value(), "abc def");
+        state.handle_key(&Key::with_ctrl(KeyCode::Char('y')));
+        assert_eq!(state.value(), "abc ");
+
+        state.handle_key(&Key::with_modifiers(KeyCode::Left, KeyModifiers::SHIFT));
+        state.handle_key(&Key::with_modifiers(
+            KeyCode::Left,
+            KeyModifiers::SHIFT | KeyModifiers::ALT,
+        ));
+        assert_eq!(state.selected_text(), Some("abc "));
+        state.handle_key(&Key::with_ctrl(KeyCode::Char('c')));
+        assert_eq!(state.clipboard, "abc ");
+
+        state.handle_key(&Key::new(KeyCode::End));
+        state.handle_key(&Key::with_ctrl(KeyCode::Char('v')));
+        assert_eq!(state.value(), "abc abc ");
+        assert!(!state.handle_key(&Key::new(KeyCode::Esc)));
+    }
+
+    #[test]
+    fn test_text_input_edit_keys() {
+        let mut state = TextInputState::with_value("hello big world").edit_keys(EditKeys::Emacs);
+        state.handle_key(&Key::with_ctrl(KeyCode::Char('a')));
+        assert_eq!(state.cursor, 0);
+        state.handle_key(&Key::with_alt(KeyCode::Char('f')));
+        state.handle_key(&Key::with_ctrl(KeyCode::Char('k')));
+        assert_eq!(state.value(), "hello");
+        state.handle_key(&Key::with_ctrl(KeyCode::Char('a')));
+        state.handle_key(&Key::with_ctrl(KeyCode::Char('y')));
+        assert_eq!(state.value(), " big worldhello");
+
+        let mut state = TextInputState::with_value("one two").edit_keys(EditKeys::Vim);
+        assert_eq!(state.vim_mode(), Some(VimMode::Insert));
+        assert!(state.handle_key(&Key::new(KeyCode::Esc)));
+        assert_eq!(state.to_props().render_string(), "one tw█o");
+        for c in "0dwx".chars() {
+            state.handle_key(&Key::new(KeyCode::Char(c)));
+        }
+        assert_eq!(state.value(), "two");
+        state.handle_key(&Key::new(KeyCode::Char('u')));
+        assert_eq!(state.value(), " two");
+        state.handle_key(&Key::new(KeyCode::Char('A')));
+        state.handle_key(&Key::new(KeyCode::Char('!')));
+        assert_eq!(state.value(), " two!");
+        assert!(state.handle_key(&Key::new(KeyCode::Esc)));
+        assert!(!state.handle_key(&Key::new(KeyCode::Esc)));
+    }
 }
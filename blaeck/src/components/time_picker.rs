@@ -0,0 +1,580 @@
+//! TimePicker component - segmented HH:MM:SS input for times and durations.
+//!
+//! The TimePicker shows hours, minutes and (optionally) seconds as separate
+//! fields. Use `TimePickerState` to move between fields, step values with the
+//! arrow keys and type digits.
+//!
+//! ## When to use TimePicker
+//!
+//! - Entering a duration for a [`Timer`](super::Timer) countdown
+//! - Picking a time of day (alarms, schedules)
+//!
+//! ## See also
+//!
+//! - [`Timer`](super::Timer) — Displays elapsed or remaining time
+//! - [`TextInput`](super::TextInput) — Free-form text entry
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut picker = TimePickerState::from_duration(Duration::from_secs(300))
+//!     .max(Duration::from_secs(3600));
+//!
+//! // In the input handler:
+//! picker.handle_key(&key);
+//!
+//! // In render:
+//! Element::node::<TimePicker>(
+//!     TimePickerProps::new(picker.value())
+//!         .field(picker.field)
+//!         .focused(true)
+//!         .invalid(!picker.is_valid()),
+//!     vec![],
+//! )
+//! ```
+
+use crate::element::{Component, Element};
+use crate::input::Key;
+use crate::palette::Palette;
+use crate::style::{Color, Modifier, Style};
+use crossterm::event::KeyCode;
+use std::time::Duration;
+
+/// One segment of a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeField {
+    #[default]
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+/// What the picker's value means.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimePickerMode {
+    /// A length of time; hours go up to 99.
+    #[default]
+    Duration,
+    /// A time of day; hours go up to 23.
+    Clock,
+}
+
+impl TimePickerMode {
+    /// Number of values the hours field can take.
+    fn hour_limit(self) -> u32 {
+        match self {
+            TimePickerMode::Duration => 100,
+            TimePickerMode::Clock => 24,
+        }
+    }
+}
+
+/// Properties for the TimePicker component.
+#[derive(Debug, Clone)]
+pub struct TimePickerProps {
+    /// Value to display.
+    pub value: Duration,
+    /// Field being edited.
+    pub field: TimeField,
+    /// Whether the picker has focus. The edited field is only highlighted
+    /// while focused.
+    pub focused: bool,
+    /// Whether to show the seconds field.
+    pub show_seconds: bool,
+    /// Separator between fields.
+    pub separator: char,
+    /// Color of the fields.
+    pub color: Option<Color>,
+    /// Color of the field being edited.
+    pub active_color: Option<Color>,
+    /// Whether the value failed validation (drawn in the error color).
+    pub invalid: bool,
+    /// Color for an invalid value (defaults to the palette's error color).
+    pub invalid_color: Option<Color>,
+}
+
+impl Default for TimePickerProps {
+    fn default() -> Self {
+        Self {
+            value: Duration::ZERO,
+            field: TimeField::Hours,
+            focused: false,
+            show_seconds: true,
+            separator: ':',
+            color: None,
+            active_color: Some(Color::Cyan),
+            invalid: false,
+            invalid_color: None,
+        }
+    }
+}
+
+impl TimePickerProps {
+    /// Create props showing `value`.
+    pub fn new(value: Duration) -> Self {
+        Self {
+            value,
+            ..Default::default()
+        }
+    }
+
+    /// Set the field being edited.
+    #[must_use]
+    pub fn field(mut self, field: TimeField) -> Self {
+        self.field = field;
+        self
+    }
+
+    /// Set the focused state.
+    #[must_use]
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Show or hide the seconds field.
+    #[must_use]
+    pub fn show_seconds(mut self, show: bool) -> Self {
+        self.show_seconds = show;
+        self
+    }
+
+    /// Set the separator between fields.
+    #[must_use]
+    pub fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Set the color of the fields.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the color of the field being edited.
+    #[must_use]
+    pub fn active_color(mut self, color: Color) -> Self {
+        self.active_color = Some(color);
+        self
+    }
+
+    /// Mark the value as invalid.
+    #[must_use]
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
+    /// Set the color for an invalid value.
+    #[must_use]
+    pub fn invalid_color(mut self, color: Color) -> Self {
+        self.invalid_color = Some(color);
+        self
+    }
+
+    /// The shown fields and their two-digit values.
+    pub fn fields(&self) -> Vec<(TimeField, u64)> {
+        let secs = self.value.as_secs();
+        let mut fields = vec![
+            (TimeField::Hours, secs / 3600),
+            (TimeField::Minutes, secs % 3600 / 60),
+        ];
+        if self.show_seconds {
+            fields.push((TimeField::Seconds, secs % 60));
+        }
+        fields
+    }
+
+    /// Spans for each field and separator, with the edited field
+    /// highlighted.
+    pub fn render_spans(&self) -> Vec<(String, Style)> {
+        let mut base = Style::new();
+        if self.invalid {
+            base = base.fg(self
+                .invalid_color
+                .unwrap_or_else(|| Palette::detect().error));
+        } else if let Some(color) = self.color {
+            base = base.fg(color);
+        }
+        let mut spans = Vec::new();
+        for (i, (field, value)) in self.fields().into_iter().enumerate() {
+            if i > 0 {
+                spans.push((self.separator.to_string(), base));
+            }
+            let mut style = base;
+            if self.focused && field == self.field {
+                if let (Some(color), false) = (self.active_color, self.invalid) {
+                    style = style.fg(color);
+                }
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            spans.push((format!("{:02}", value), style));
+        }
+        spans
+    }
+
+    /// Build the display string, e.g. "01:30:00".
+    pub fn render_string(&self) -> String {
+        self.render_spans().into_iter().map(|(t, _)| t).collect()
+    }
+}
+
+/// A component that displays a segmented time.
+///
+/// Renders a single line with the edited field in reverse video.
+pub struct TimePicker;
+
+impl Component for TimePicker {
+    type Props = TimePickerProps;
+
+    fn render(props: &Self::Props) -> Element {
+        Element::Fragment(
+            props
+                .render_spans()
+                .into_iter()
+                .map(|(text, style)| Element::styled_text(text, style))
+                .collect(),
+        )
+    }
+}
+
+/// Helper struct for editing a time field by field.
+///
+/// Up/Down step the edited field and wrap around without carrying into the
+/// next field, like the time inputs in most forms. Digits are typed two at a
+/// time; focus moves on once a field is complete.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimePickerState {
+    /// Hours, 0-99 (0-23 in clock mode).
+    pub hours: u32,
+    /// Minutes, 0-59.
+    pub minutes: u32,
+    /// Seconds, 0-59.
+    pub seconds: u32,
+    /// Field being edited.
+    pub field: TimeField,
+    /// Duration or time of day.
+    pub mode: TimePickerMode,
+    /// Whether the seconds field is shown and editable.
+    pub show_seconds: bool,
+    /// Smallest valid value.
+    pub min: Option<Duration>,
+    /// Largest valid value.
+    pub max: Option<Duration>,
+    /// First digit typed into the current field, waiting for a second.
+    typed: Option<u32>,
+}
+
+impl Default for TimePickerState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimePickerState {
+    /// Create a zero duration picker with the hours field active.
+    pub fn new() -> Self {
+        Self {
+            hours: 0,
+            minutes: 0,
+            seconds: 0,
+            field: TimeField::Hours,
+            mode: TimePickerMode::Duration,
+            show_seconds: true,
+            min: None,
+            max: None,
+            typed: None,
+        }
+    }
+
+    /// Create a duration picker starting at `value`.
+    pub fn from_duration(value: Duration) -> Self {
+        let mut state = Self::new();
+        state.set_value(value);
+        state
+    }
+
+    /// Create a time-of-day picker (HH:MM) at `hours:minutes`.
+    pub fn clock(hours: u32, minutes: u32) -> Self {
+        let mut state = Self::new();
+        state.mode = TimePickerMode::Clock;
+        state.show_seconds = false;
+        state.set_value(Duration::from_secs(u64::from(hours * 3600 + minutes * 60)));
+        state
+    }
+
+    /// Show or hide the seconds field.
+    #[must_use]
+    pub fn with_seconds(mut self, show: bool) -> Self {
+        self.show_seconds = show;
+        if !show {
+            self.seconds = 0;
+            if self.field == TimeField::Seconds {
+                self.field = TimeField::Minutes;
+            }
+        }
+        self
+    }
+
+    /// Set the smallest valid value.
+    #[must_use]
+    pub fn min(mut self, min: Duration) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the largest valid value.
+    #[must_use]
+    pub fn max(mut self, max: Duration) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// The current value.
+    pub fn value(&self) -> Duration {
+        Duration::from_secs(
+            u64::from(self.hours) * 3600 + u64::from(self.minutes) * 60 + u64::from(self.seconds),
+        )
+    }
+
+    /// Set the value. Hours past the mode's range wrap (clock) or saturate
+    /// (duration), and sub-second parts are dropped.
+    pub fn set_value(&mut self, value: Duration) {
+        let secs = value.as_secs();
+        let hours = secs / 3600;
+        let limit = u64::from(self.mode.hour_limit());
+        self.hours = match self.mode {
+            TimePickerMode::Clock => hours % limit,
+            TimePickerMode::Duration => hours.min(limit - 1),
+        } as u32;
+        self.minutes = (secs % 3600 / 60) as u32;
+        self.seconds = if self.show_seconds {
+            (secs % 60) as u32
+        } else {
+            0
+        };
+        self.typed = None;
+    }
+
+    /// Whether the value is within `min` and `max`.
+    pub fn is_valid(&self) -> bool {
+        let value = self.value();
+        self.min.is_none_or(|min| value >= min) && self.max.is_none_or(|max| value <= max)
+    }
+
+    /// Move the value into `min..=max`.
+    pub fn clamp(&mut self) {
+        let mut value = self.value();
+        if let Some(max) = self.max {
+            value = value.min(max);
+        }
+        if let Some(min) = self.min {
+            value = value.max(min);
+        }
+        self.set_value(value);
+    }
+
+    /// The editable fields, in order.
+    pub fn fields(&self) -> &'static [TimeField] {
+        if self.show_seconds {
+            &[TimeField::Hours, TimeField::Minutes, TimeField::Seconds]
+        } else {
+            &[TimeField::Hours, TimeField::Minutes]
+        }
+    }
+
+    fn limit(&self, field: TimeField) -> u32 {
+        match field {
+            TimeField::Hours => self.mode.hour_limit(),
+            TimeField::Minutes | TimeField::Seconds => 60,
+        }
+    }
+
+    fn slot(&mut self, field: TimeField) -> &mut u32 {
+        match field {
+            TimeField::Hours => &mut self.hours,
+            TimeField::Minutes => &mut self.minutes,
+            TimeField::Seconds => &mut self.seconds,
+        }
+    }
+
+    /// Add `delta` to the edited field, wrapping within its range.
+    pub fn step(&mut self, delta: i32) {
+        let field = self.field;
+        let limit = self.limit(field) as i32;
+        let slot = self.slot(field);
+        *slot = (*slot as i32 + delta).rem_euclid(limit) as u32;
+        self.typed = None;
+    }
+
+    /// Increase the edited field by one.
+    pub fn increment(&mut self) {
+        self.step(1);
+    }
+
+    /// Decrease the edited field by one.
+    pub fn decrement(&mut self) {
+        self.step(-1);
+    }
+
+    /// Move to the next field. Returns false at the last field.
+    pub fn next_field(&mut self) -> bool {
+        self.typed = None;
+        let fields = self.fields();
+        match fields.iter().position(|f| *f == self.field) {
+            Some(i) if i + 1 < fields.len() => {
+                self.field = fields[i + 1];
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Move to the previous field. Returns false at the first field.
+    pub fn prev_field(&mut self) -> bool {
+        self.typed = None;
+        let fields = self.fields();
+        match fields.iter().position(|f| *f == self.field) {
+            Some(i) if i > 0 => {
+                self.field = fields[i - 1];
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Type a digit into the edited field.
+    ///
+    /// The first digit replaces the field; the second completes it and moves
+    /// to the next field. A first digit that can't start a valid two-digit
+    /// value (e.g. 7 for minutes) completes the field on its own.
+    pub fn type_digit(&mut self, digit: u32) {
+        let field = self.field;
+        let limit = self.limit(field);
+        match self.typed.take() {
+            Some(first) => {
+                *self.slot(field) = (first * 10 + digit).min(limit - 1);
+                self.next_field();
+            }
+            None if digit * 10 >= limit => {
+                *self.slot(field) = digit.min(limit - 1);
+                self.next_field();
+            }
+            None => {
+                *self.slot(field) = digit;
+                self.typed = Some(digit);
+            }
+        }
+    }
+
+    /// Handle Left/Right to move between fields, Up/Down to step (PageUp and
+    /// PageDown by ten), Home/End for the first and last field, digits to
+    /// type and Backspace to zero the field.
+    ///
+    /// Returns true if the key was handled.
+    pub fn handle_key(&mut self, key: &Key) -> bool {
+        match key.code {
+            KeyCode::Left => {
+                self.prev_field();
+            }
+            KeyCode::Right => {
+                self.next_field();
+            }
+            KeyCode::Home => {
+                self.typed = None;
+                self.field = TimeField::Hours;
+            }
+            KeyCode::End => {
+                self.typed = None;
+                self.field = *self.fields().last().unwrap_or(&TimeField::Hours);
+            }
+            KeyCode::Up => self.increment(),
+            KeyCode::Down => self.decrement(),
+            KeyCode::PageUp => self.step(10),
+            KeyCode::PageDown => self.step(-10),
+            KeyCode::Backspace => {
+                self.typed = None;
+                *self.slot(self.field) = 0;
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                self.type_digit(c.to_digit(10).unwrap_or(0));
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_picker_render_string() {
+        let props = TimePickerProps::new(Duration::from_secs(3723));
+        assert_eq!(props.render_string(), "01:02:03");
+        assert_eq!(props.clone().show_seconds(false).render_string(), "01:02");
+        assert_eq!(props.clone().separator('.').render_string(), "01.02.03");
+
+        let spans = props.field(TimeField::Minutes).focused(true).render_spans();
+        assert_eq!(spans.len(), 5);
+        assert!(spans[2].1.modifiers.contains(Modifier::REVERSED));
+        assert!(!spans[0].1.modifiers.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_time_picker_step_wraps_per_field() {
+        let mut state = TimePickerState::from_duration(Duration::from_secs(59));
+        state.field = TimeField::Seconds;
+        state.increment();
+        assert_eq!(state.value(), Duration::ZERO);
+        state.decrement();
+        assert_eq!(state.seconds, 59);
+
+        let mut clock = TimePickerState::clock(23, 30);
+        assert_eq!(clock.fields().len(), 2);
+        clock.increment();
+        assert_eq!(clock.hours, 0);
+        clock.field = TimeField::Minutes;
+        clock.step(-40);
+        assert_eq!(clock.minutes, 50);
+    }
+
+    #[test]
+    fn test_time_picker_typing_and_keys() {
+        let mut state = TimePickerState::new();
+        for c in ['0', '1', '7', '4', '5'] {
+            assert!(state.handle_key(&Key::new(KeyCode::Char(c))));
+        }
+        // "01", then 7 completes minutes alone, then "45"
+        assert_eq!((state.hours, state.minutes, state.seconds), (1, 7, 45));
+
+        state.handle_key(&Key::new(KeyCode::Home));
+        assert_eq!(state.field, TimeField::Hours);
+        state.handle_key(&Key::new(KeyCode::Right));
+        state.handle_key(&Key::new(KeyCode::Backspace));
+        assert_eq!(state.minutes, 0);
+        state.handle_key(&Key::new(KeyCode::PageUp));
+        assert_eq!(state.minutes, 10);
+        assert!(!state.handle_key(&Key::new(KeyCode::Char('x'))));
+    }
+
+    #[test]
+    fn test_time_picker_validation() {
+        let mut state = TimePickerState::from_duration(Duration::from_secs(7200))
+            .min(Duration::from_secs(60))
+            .max(Duration::from_secs(3600));
+        assert!(!state.is_valid());
+        state.clamp();
+        assert_eq!(state.value(), Duration::from_secs(3600));
+        assert!(state.is_valid());
+
+        let state = TimePickerState::from_duration(Duration::from_secs(500 * 3600));
+        assert_eq!(state.hours, 99);
+    }
+}
@@ -33,10 +33,54 @@
 //!     vec![],
 //! );
 //! ```
+//!
+//! ## Stopwatch state
+//!
+//! [`TimerState`] is a clock that can be paused, resumed, reset and
+//! split into laps, so the app doesn't add up [`Instant`]s itself:
+//!
+//! ```ignore
+//! let mut clock = TimerState::started(Instant::now());
+//! clock.pause(Instant::now());   // on space
+//! clock.resume(Instant::now());  // on space again
+//! clock.lap(Instant::now());     // on l
+//!
+//! let now = Instant::now();
+//! let view = Element::column(vec![
+//!     Element::node::<Timer>(clock.props(now), vec![]),
+//!     clock.render_laps(TimeFormat::MinSecTenths),
+//! ]);
+//! ```
+//!
+//! ## Countdown callbacks
+//!
+//! [`TimerProps`] only shows a duration. A [`Countdown`] keeps the clock
+//! and runs callbacks as it passes thresholds and when it runs out; tick
+//! it from the event loop, or use
+//! [`use_countdown`](crate::reactive::use_countdown) and the reactive
+//! runtime ticks it and re-renders:
+//!
+//! ```ignore
+//! let countdown = Countdown::new(Duration::from_secs(60))
+//!     .on_threshold(Duration::from_secs(10), || beep())
+//!     .on_complete(|| submit());
+//! countdown.start(Instant::now());
+//!
+//! // Each frame
+//! countdown.tick(Instant::now());
+//! let timer = Element::node::<Timer>(
+//!     countdown.props(Instant::now()).expired_text("time's up"),
+//!     vec![],
+//! );
+//! ```
 
 use crate::element::{Component, Element};
+use crate::palette::Palette;
 use crate::style::{Color, Modifier, Style};
-use std::time::Duration;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 /// Time display format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -134,6 +178,20 @@ pub enum TimerMode {
     Countdown,
 }
 
+/// Which color zone a timer is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimerZone {
+    /// Above the thresholds, or a stopwatch.
+    #[default]
+    Normal,
+    /// At or below the warn threshold.
+    Warn,
+    /// At or below the danger threshold.
+    Danger,
+    /// A countdown that reached zero.
+    Expired,
+}
+
 /// Properties for the Timer component.
 #[derive(Debug, Clone)]
 pub struct TimerProps {
@@ -167,6 +225,8 @@ pub struct TimerProps {
     pub bold: bool,
     /// Dim text.
     pub dim: bool,
+    /// Text shown instead of the time once a countdown expires.
+    pub expired_text: Option<String>,
 }
 
 impl Default for TimerProps {
@@ -187,6 +247,7 @@ impl Default for TimerProps {
             suffix: None,
             bold: false,
             dim: false,
+            expired_text: None,
         }
     }
 }
@@ -301,6 +362,26 @@ impl TimerProps {
         self
     }
 
+    /// Set the text shown instead of the time once a countdown expires.
+    #[must_use]
+    pub fn expired_text(mut self, text: impl Into<String>) -> Self {
+        self.expired_text = Some(text.into());
+        self
+    }
+
+    /// Which color zone the timer is in.
+    pub fn zone(&self) -> TimerZone {
+        if self.is_complete() {
+            TimerZone::Expired
+        } else if self.is_danger() {
+            TimerZone::Danger
+        } else if self.is_warn() {
+            TimerZone::Warn
+        } else {
+            TimerZone::Normal
+        }
+    }
+
     /// Check if timer is in warn zone.
     pub fn is_warn(&self) -> bool {
         if self.mode != TimerMode::Countdown {
@@ -355,21 +436,22 @@ impl Component for Timer {
 
     fn render(props: &Self::Props) -> Element {
         // Determine color based on state
-        let color = if props.is_complete() {
-            props.complete_color
-        } else if props.is_danger() {
-            props.danger_color
-        } else if props.is_warn() {
-            props.warn_color
-        } else {
-            props.color
+        let zone = props.zone();
+        let color = match zone {
+            TimerZone::Expired => props.complete_color,
+            TimerZone::Danger => props.danger_color,
+            TimerZone::Warn => props.warn_color,
+            TimerZone::Normal => props.color,
         };
 
         // Handle blinking in danger zone
         let should_hide = props.blink_on_danger && props.is_danger() && !props.blink_visible;
 
-        // Format the time
-        let time_str = props.format.format(props.duration);
+        // Format the time, or the expired text
+        let time_str = match (&props.expired_text, zone) {
+            (Some(text), TimerZone::Expired) => text.clone(),
+            _ => props.format.format(props.duration),
+        };
 
         // Build full display string
         let display = if should_hide {
@@ -395,7 +477,7 @@ impl Component for Timer {
         if let Some(c) = color {
             style = style.fg(c);
         }
-        if props.bold {
+        if props.bold || zone == TimerZone::Expired {
             style = style.add_modifier(Modifier::BOLD);
         }
         if props.dim {
@@ -406,6 +488,337 @@ impl Component for Timer {
     }
 }
 
+/// A stopwatch clock with pause, resume, reset and laps.
+///
+/// Every method takes the current time, so it's easy to drive from tests.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimerState {
+    /// Time counted in earlier runs, before the last pause.
+    banked: Duration,
+    /// When the current run started, if running.
+    running_since: Option<Instant>,
+    /// Elapsed time at each lap, oldest first.
+    splits: Vec<Duration>,
+}
+
+impl TimerState {
+    /// A stopped clock at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A clock running from `now`.
+    pub fn started(now: Instant) -> Self {
+        let mut state = Self::new();
+        state.start(now);
+        state
+    }
+
+    /// Start or resume counting at `now`. Does nothing while running.
+    pub fn start(&mut self, now: Instant) {
+        if self.running_since.is_none() {
+            self.running_since = Some(now);
+        }
+    }
+
+    /// Resume counting at `now`; the same as [`start`](Self::start).
+    pub fn resume(&mut self, now: Instant) {
+        self.start(now);
+    }
+
+    /// Stop counting at `now`, keeping the time so far.
+    pub fn pause(&mut self, now: Instant) {
+        if let Some(since) = self.running_since.take() {
+            self.banked += now.saturating_duration_since(since);
+        }
+    }
+
+    /// Pause if running, resume if paused.
+    pub fn toggle(&mut self, now: Instant) {
+        if self.is_running() {
+            self.pause(now);
+        } else {
+            self.resume(now);
+        }
+    }
+
+    /// Stop and go back to zero, clearing the laps.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Whether the clock is counting.
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    /// Total time counted at `now`, pauses excluded.
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        self.banked
+            + self
+                .running_since
+                .map_or(Duration::ZERO, |since| now.saturating_duration_since(since))
+    }
+
+    /// Record a lap at `now`, returning its length.
+    pub fn lap(&mut self, now: Instant) -> Duration {
+        let elapsed = self.elapsed(now);
+        let lap = elapsed.saturating_sub(self.splits.last().copied().unwrap_or_default());
+        self.splits.push(elapsed);
+        lap
+    }
+
+    /// Elapsed time at each lap, oldest first.
+    pub fn splits(&self) -> &[Duration] {
+        &self.splits
+    }
+
+    /// Length of each lap, oldest first.
+    pub fn laps(&self) -> Vec<Duration> {
+        let mut previous = Duration::ZERO;
+        self.splits
+            .iter()
+            .map(|&split| {
+                let lap = split.saturating_sub(previous);
+                previous = split;
+                lap
+            })
+            .collect()
+    }
+
+    /// Stopwatch props showing the time counted at `now`.
+    pub fn props(&self, now: Instant) -> TimerProps {
+        TimerProps::stopwatch(self.elapsed(now))
+    }
+
+    /// The laps as a column, newest first: number, lap time and split.
+    /// With two or more laps of different lengths, the fastest is drawn in
+    /// the success color and the slowest in the error color.
+    pub fn render_laps(&self, format: TimeFormat) -> Element {
+        let laps = self.laps();
+        let fastest = laps.iter().min().copied();
+        let slowest = laps.iter().max().copied();
+        let marked = fastest != slowest;
+        let palette = Palette::detect();
+        let number_width = laps.len().to_string().len();
+        let lap_width = laps
+            .iter()
+            .map(|&lap| format.format(lap).len())
+            .max()
+            .unwrap_or(0);
+
+        let lines = laps
+            .iter()
+            .zip(&self.splits)
+            .enumerate()
+            .rev()
+            .map(|(i, (&lap, &split))| {
+                let line = format!(
+                    "Lap {:>number_width$}  {:>lap_width$}  {}",
+                    i + 1,
+                    format.format(lap),
+                    format.format(split),
+                );
+                let style = if marked && Some(lap) == fastest {
+                    Style::new().fg(palette.success)
+                } else if marked && Some(lap) == slowest {
+                    Style::new().fg(palette.error)
+                } else {
+                    Style::new()
+                };
+                Element::styled_text(line, style)
+            })
+            .collect();
+        Element::column(lines)
+    }
+}
+
+/// A callback run by a [`Countdown`].
+type CountdownCallback = Box<dyn FnMut()>;
+
+/// A threshold callback and whether it has run.
+struct Threshold {
+    at: Duration,
+    fired: bool,
+    callback: CountdownCallback,
+}
+
+struct CountdownInner {
+    total: Duration,
+    clock: TimerState,
+    started: bool,
+    thresholds: Vec<Threshold>,
+    on_complete: Option<CountdownCallback>,
+    completed: bool,
+    /// Whole seconds left at the last tick, to tell when the display changes.
+    shown: Option<u64>,
+}
+
+/// A running countdown that calls back as it passes thresholds and when it
+/// runs out. See the [module docs](self).
+///
+/// Cheap to clone; clones share the clock and callbacks.
+#[derive(Clone)]
+pub struct Countdown {
+    inner: Rc<RefCell<CountdownInner>>,
+}
+
+impl fmt::Debug for Countdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = self.inner.borrow();
+        f.debug_struct("Countdown")
+            .field("total", &inner.total)
+            .field("clock", &inner.clock)
+            .field("thresholds", &inner.thresholds.len())
+            .field("completed", &inner.completed)
+            .finish()
+    }
+}
+
+impl Countdown {
+    /// A countdown of `total`, not yet started.
+    pub fn new(total: Duration) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(CountdownInner {
+                total,
+                clock: TimerState::new(),
+                started: false,
+                thresholds: Vec::new(),
+                on_complete: None,
+                completed: false,
+                shown: None,
+            })),
+        }
+    }
+
+    /// Run `callback` once when the time left first drops to `at` or below.
+    #[must_use]
+    pub fn on_threshold(self, at: Duration, callback: impl FnMut() + 'static) -> Self {
+        self.inner.borrow_mut().thresholds.push(Threshold {
+            at,
+            fired: false,
+            callback: Box::new(callback),
+        });
+        self
+    }
+
+    /// Run `callback` once when the countdown reaches zero.
+    #[must_use]
+    pub fn on_complete(self, callback: impl FnMut() + 'static) -> Self {
+        self.inner.borrow_mut().on_complete = Some(Box::new(callback));
+        self
+    }
+
+    /// Start (or restart) counting down from the full duration at `now`.
+    /// Every callback can run again.
+    pub fn start(&self, now: Instant) {
+        let mut inner = self.inner.borrow_mut();
+        inner.clock = TimerState::started(now);
+        inner.started = true;
+        inner.completed = false;
+        inner.shown = None;
+        for threshold in &mut inner.thresholds {
+            threshold.fired = false;
+        }
+    }
+
+    /// Whether the countdown has been started.
+    pub fn is_started(&self) -> bool {
+        self.inner.borrow().started
+    }
+
+    /// Stop the clock at `now`; the time left holds until resumed.
+    pub fn pause(&self, now: Instant) {
+        self.inner.borrow_mut().clock.pause(now);
+    }
+
+    /// Carry on counting down from `now` after a pause.
+    pub fn resume(&self, now: Instant) {
+        let mut inner = self.inner.borrow_mut();
+        if inner.started {
+            inner.clock.resume(now);
+        }
+    }
+
+    /// Whether the countdown is started but paused.
+    pub fn is_paused(&self) -> bool {
+        let inner = self.inner.borrow();
+        inner.started && !inner.clock.is_running()
+    }
+
+    /// Whether the countdown has run out (as of the last tick).
+    pub fn is_complete(&self) -> bool {
+        self.inner.borrow().completed
+    }
+
+    /// Time left at `now`; the full duration until started.
+    pub fn remaining(&self, now: Instant) -> Duration {
+        let inner = self.inner.borrow();
+        inner.total.saturating_sub(inner.clock.elapsed(now))
+    }
+
+    /// Run the callbacks due at `now`: thresholds from the highest down,
+    /// then completion. Each runs once per start.
+    ///
+    /// Returns true if a callback ran or the whole seconds left changed,
+    /// i.e. the timer should be redrawn.
+    pub fn tick(&self, now: Instant) -> bool {
+        let remaining = self.remaining(now);
+        let mut due: Vec<(usize, CountdownCallback)> = Vec::new();
+        let mut complete = None;
+        let changed = {
+            let mut inner = self.inner.borrow_mut();
+            if !inner.started {
+                return false;
+            }
+            let shown = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+            let changed = inner.shown != Some(shown);
+            inner.shown = Some(shown);
+
+            inner
+                .thresholds
+                .sort_by_key(|threshold| std::cmp::Reverse(threshold.at));
+            for (i, threshold) in inner.thresholds.iter_mut().enumerate() {
+                if !threshold.fired && remaining <= threshold.at {
+                    threshold.fired = true;
+                    due.push((
+                        i,
+                        std::mem::replace(&mut threshold.callback, Box::new(|| {})),
+                    ));
+                }
+            }
+            if !inner.completed && remaining.is_zero() {
+                inner.completed = true;
+                complete = inner.on_complete.take();
+            }
+            changed
+        };
+        let fired = !due.is_empty() || complete.is_some();
+
+        // Run without the borrow held, so callbacks can use the countdown,
+        // then put them back for the next start
+        for (_, callback) in &mut due {
+            callback();
+        }
+        if let Some(callback) = complete.as_mut() {
+            callback();
+        }
+        let mut inner = self.inner.borrow_mut();
+        for (i, callback) in due {
+            inner.thresholds[i].callback = callback;
+        }
+        if inner.on_complete.is_none() {
+            inner.on_complete = complete;
+        }
+        changed || fired
+    }
+
+    /// Countdown props showing the time left at `now`.
+    pub fn props(&self, now: Instant) -> TimerProps {
+        TimerProps::countdown(self.remaining(now))
+    }
+}
+
 /// Helper to create a simple timer display.
 pub fn timer_display(duration: Duration, format: TimeFormat) -> Element {
     Timer::render(&TimerProps {
@@ -595,4 +1008,115 @@ mod tests {
             _ => panic!("Expected Text element"),
         }
     }
+
+    #[test]
+    fn test_timer_state_and_expired_text() {
+        let props = TimerProps::countdown(Duration::from_secs(3))
+            .warn_at(Duration::from_secs(10))
+            .danger_at(Duration::from_secs(5));
+        assert_eq!(props.zone(), TimerZone::Danger);
+        assert_eq!(
+            TimerProps::stopwatch(Duration::ZERO).zone(),
+            TimerZone::Normal
+        );
+
+        let props = TimerProps::countdown(Duration::ZERO).expired_text("time's up");
+        assert_eq!(props.zone(), TimerZone::Expired);
+        match Timer::render(&props) {
+            Element::Text { content, style } => {
+                assert_eq!(content, "time's up");
+                assert!(style.modifiers.contains(Modifier::BOLD));
+            }
+            _ => panic!("Expected Text element"),
+        }
+    }
+
+    #[test]
+    fn test_countdown_callbacks_fire_once() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (warn, danger, done) = (log.clone(), log.clone(), log.clone());
+        let countdown = Countdown::new(Duration::from_secs(10))
+            .on_threshold(Duration::from_secs(2), move || {
+                danger.borrow_mut().push("danger")
+            })
+            .on_threshold(Duration::from_secs(5), move || {
+                warn.borrow_mut().push("warn")
+            })
+            .on_complete(move || done.borrow_mut().push("done"));
+
+        let start = Instant::now();
+        assert!(!countdown.tick(start));
+        countdown.start(start);
+        assert!(countdown.tick(start));
+        assert!(!countdown.tick(start + Duration::from_millis(300)));
+        assert_eq!(
+            countdown.remaining(start + Duration::from_secs(4)),
+            Duration::from_secs(6)
+        );
+
+        // Skipping past both thresholds runs them in order
+        assert!(countdown.tick(start + Duration::from_secs(9)));
+        assert_eq!(*log.borrow(), vec!["warn", "danger"]);
+        countdown.tick(start + Duration::from_secs(12));
+        countdown.tick(start + Duration::from_secs(13));
+        assert_eq!(*log.borrow(), vec!["warn", "danger", "done"]);
+        assert!(countdown.is_complete());
+        assert!(countdown
+            .props(start + Duration::from_secs(13))
+            .is_complete());
+
+        // Restarting arms them again
+        countdown.start(start);
+        countdown.tick(start + Duration::from_secs(6));
+        assert_eq!(log.borrow().len(), 4);
+    }
+
+    #[test]
+    fn test_timer_state_pause_and_laps() {
+        let t0 = Instant::now();
+        let at = |secs: u64| t0 + Duration::from_secs(secs);
+        let mut clock = TimerState::new();
+        assert_eq!(clock.elapsed(at(5)), Duration::ZERO);
+
+        clock.start(at(0));
+        assert_eq!(clock.lap(at(3)), Duration::from_secs(3));
+        clock.pause(at(4));
+        assert!(!clock.is_running());
+        assert_eq!(clock.elapsed(at(100)), Duration::from_secs(4));
+        clock.toggle(at(100));
+        assert_eq!(clock.lap(at(105)), Duration::from_secs(6));
+        assert_eq!(clock.props(at(106)).duration, Duration::from_secs(10));
+        assert_eq!(
+            clock.laps(),
+            vec![Duration::from_secs(3), Duration::from_secs(6)]
+        );
+
+        let Element::Node { children, .. } = clock.render_laps(TimeFormat::MinSec) else {
+            panic!("Expected column");
+        };
+        let text: Vec<String> = children
+            .iter()
+            .map(|line| match line {
+                Element::Text { content, .. } => content.clone(),
+                _ => panic!("Expected Text element"),
+            })
+            .collect();
+        assert_eq!(text, vec!["Lap 2  0:06  0:09", "Lap 1  0:03  0:03"]);
+
+        clock.reset();
+        assert_eq!(clock, TimerState::new());
+    }
+
+    #[test]
+    fn test_countdown_pause() {
+        let t0 = Instant::now();
+        let at = |secs: u64| t0 + Duration::from_secs(secs);
+        let countdown = Countdown::new(Duration::from_secs(10));
+        countdown.start(at(0));
+        countdown.pause(at(3));
+        assert!(countdown.is_paused());
+        assert_eq!(countdown.remaining(at(50)), Duration::from_secs(7));
+        countdown.resume(at(50));
+        assert_eq!(countdown.remaining(at(52)), Duration::from_secs(5));
+    }
 }
@@ -0,0 +1,330 @@
+//! Toc component - table of contents for jumping between sections.
+//!
+//! A Toc lists the sections of a long document, indented by level, with the
+//! current one highlighted. Sections come from
+//! [`MarkdownProps::sections`](super::MarkdownProps::sections) or, for plain
+//! text, from lines starting with a marker ([`marked_sections`]). Each
+//! section records the rendered line it starts on, so jumping is just
+//! scrolling there.
+//!
+//! ## When to use Toc
+//!
+//! - Sidebar for long help pages or READMEs
+//! - Jump list for a manual rendered with Markdown
+//!
+//! ## See also
+//!
+//! - [`Markdown`](super::Markdown) — Renders the document
+//! - [`ScrollView`](super::ScrollView) — Scrolls the document to a section
+//!
+//! # Example
+//!
+//! ```ignore
+//! let doc = MarkdownProps::new(HELP);
+//! let sections = doc.sections();
+//!
+//! // In the input handler: arrows move through the list, Enter jumps,
+//! // `]` and `[` jump to the next and previous section directly.
+//! if let Some(line) = toc.handle_key(&key, &sections) {
+//!     scroll.jump_to(line);
+//! } else if key.is_char(']') {
+//!     if let Some(line) = next_section(&sections, scroll.offset) {
+//!         scroll.jump_to(line);
+//!     }
+//! }
+//!
+//! // In render:
+//! toc.follow(&sections, scroll.offset);
+//! element! {
+//!     Box(flex_direction: FlexDirection::Row) {
+//!         Toc(sections: sections.clone(), selected: Some(toc.selected))
+//!         ScrollView(height: 20, offset: scroll.offset) {
+//!             Markdown(content: HELP)
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::element::{Component, Element};
+use crate::input::Key;
+use crate::style::{Color, Modifier, Style};
+use crossterm::event::KeyCode;
+
+/// A section of a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// Heading level, 1 for top-level sections.
+    pub level: u8,
+    /// Section title.
+    pub title: String,
+    /// Rendered line the section starts on.
+    pub line: usize,
+}
+
+impl Section {
+    /// Create a section.
+    pub fn new(level: u8, title: impl Into<String>, line: usize) -> Self {
+        Self {
+            level,
+            title: title.into(),
+            line,
+        }
+    }
+}
+
+/// Sections of plain text: every line starting with `marker`, titled by the
+/// rest of the line.
+///
+/// Leading repeats of the marker's first character set the level, so with
+/// marker `"#"` the line `"## Usage"` is a level 2 section titled "Usage".
+/// ANSI escapes are ignored, so this also works on
+/// [`render_to_string`](crate::render_to_string) output.
+pub fn marked_sections(text: &str, marker: &str) -> Vec<Section> {
+    let Some(repeat) = marker.chars().next() else {
+        return Vec::new();
+    };
+    text.lines()
+        .enumerate()
+        .filter_map(|(line, raw)| {
+            let plain = crate::renderer::strip_ansi_escapes(raw);
+            let rest = plain.strip_prefix(marker)?;
+            let extra = rest.chars().take_while(|&c| c == repeat).count();
+            let title = rest[extra * repeat.len_utf8()..].trim();
+            (!title.is_empty()).then(|| Section::new(1 + extra as u8, title, line))
+        })
+        .collect()
+}
+
+/// Index of the section containing `line`: the last one starting at or
+/// before it.
+pub fn section_at(sections: &[Section], line: usize) -> Option<usize> {
+    sections.iter().rposition(|s| s.line <= line)
+}
+
+/// Start line of the first section after `line`.
+pub fn next_section(sections: &[Section], line: usize) -> Option<usize> {
+    sections.iter().map(|s| s.line).find(|&l| l > line)
+}
+
+/// Start line of the last section before `line`.
+pub fn prev_section(sections: &[Section], line: usize) -> Option<usize> {
+    sections.iter().rev().map(|s| s.line).find(|&l| l < line)
+}
+
+/// Properties for the Toc component.
+#[derive(Debug, Clone)]
+pub struct TocProps {
+    /// Sections to list.
+    pub sections: Vec<Section>,
+    /// Index of the highlighted section.
+    pub selected: Option<usize>,
+    /// Deepest level shown; deeper sections are left out.
+    pub max_level: u8,
+    /// Columns of indent per level.
+    pub indent: usize,
+    /// Color of the highlighted section.
+    pub selected_color: Option<Color>,
+    /// Color of the other sections.
+    pub color: Option<Color>,
+}
+
+impl Default for TocProps {
+    fn default() -> Self {
+        Self {
+            sections: Vec::new(),
+            selected: None,
+            max_level: 6,
+            indent: 2,
+            selected_color: Some(Color::Cyan),
+            color: None,
+        }
+    }
+}
+
+impl TocProps {
+    /// Create props with the given sections.
+    pub fn new(sections: Vec<Section>) -> Self {
+        Self {
+            sections,
+            ..Default::default()
+        }
+    }
+
+    /// Set the highlighted section.
+    #[must_use]
+    pub fn selected(mut self, index: Option<usize>) -> Self {
+        self.selected = index;
+        self
+    }
+
+    /// Show only sections up to this level.
+    #[must_use]
+    pub fn max_level(mut self, level: u8) -> Self {
+        self.max_level = level;
+        self
+    }
+
+    /// Set the indent per level.
+    #[must_use]
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Set the color of the highlighted section.
+    #[must_use]
+    pub fn selected_color(mut self, color: Color) -> Self {
+        self.selected_color = Some(color);
+        self
+    }
+
+    /// Set the color of the other sections.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// A component that lists a document's sections.
+pub struct Toc;
+
+impl Component for Toc {
+    type Props = TocProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let shown = props
+            .sections
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.level <= props.max_level);
+        let Some(min_level) = shown.clone().map(|(_, s)| s.level).min() else {
+            return Element::text("");
+        };
+
+        let lines = shown
+            .map(|(i, section)| {
+                let indent = " ".repeat((section.level - min_level) as usize * props.indent);
+                let mut style = Style::new();
+                let marker = if props.selected == Some(i) {
+                    if let Some(color) = props.selected_color {
+                        style = style.fg(color);
+                    }
+                    style = style.add_modifier(Modifier::BOLD);
+                    "› "
+                } else {
+                    if let Some(color) = props.color {
+                        style = style.fg(color);
+                    }
+                    "  "
+                };
+                Element::styled_text(format!("{}{}{}", marker, indent, section.title), style)
+            })
+            .collect();
+        Element::Fragment(lines)
+    }
+}
+
+/// Highlighted entry of a Toc.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TocState {
+    /// Index of the highlighted section.
+    pub selected: usize,
+}
+
+impl TocState {
+    /// Create a state with the first section highlighted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Highlight the section containing `line`, e.g. the document's scroll
+    /// offset, so the list follows scrolling.
+    pub fn follow(&mut self, sections: &[Section], line: usize) {
+        self.selected = section_at(sections, line).unwrap_or(0);
+    }
+
+    /// Handle Up/Down (also `k`/`j`), Home/End and Enter.
+    ///
+    /// Returns the line to jump to when Enter is pressed.
+    pub fn handle_key(&mut self, key: &Key, sections: &[Section]) -> Option<usize> {
+        let last = sections.len().checked_sub(1)?;
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1).min(last),
+            KeyCode::Home => self.selected = 0,
+            KeyCode::End => self.selected = last,
+            KeyCode::Enter => return sections.get(self.selected).map(|s| s.line),
+            _ => {}
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sections() -> Vec<Section> {
+        vec![
+            Section::new(1, "Intro", 0),
+            Section::new(2, "Install", 4),
+            Section::new(2, "Usage", 10),
+            Section::new(1, "Reference", 20),
+        ]
+    }
+
+    #[test]
+    fn test_marked_sections() {
+        let text = "\x1b[1m# Title\x1b[0m\nbody\n## Sub\n#\n#nospace";
+        assert_eq!(
+            marked_sections(text, "#"),
+            vec![
+                Section::new(1, "Title", 0),
+                Section::new(2, "Sub", 2),
+                Section::new(1, "nospace", 4),
+            ]
+        );
+        assert!(marked_sections(text, "").is_empty());
+    }
+
+    #[test]
+    fn test_section_navigation() {
+        let sections = sections();
+        assert_eq!(section_at(&sections, 12), Some(2));
+        assert_eq!(next_section(&sections, 4), Some(10));
+        assert_eq!(prev_section(&sections, 10), Some(4));
+        assert_eq!(next_section(&sections, 20), None);
+
+        let mut toc = TocState::new();
+        toc.follow(&sections, 5);
+        assert_eq!(toc.selected, 1);
+        assert_eq!(toc.handle_key(&Key::new(KeyCode::Down), &sections), None);
+        assert_eq!(
+            toc.handle_key(&Key::new(KeyCode::Enter), &sections),
+            Some(10)
+        );
+        toc.handle_key(&Key::new(KeyCode::End), &sections);
+        toc.handle_key(&Key::new(KeyCode::Char('j')), &sections);
+        assert_eq!(toc.selected, 3);
+    }
+
+    #[test]
+    fn test_toc_render() {
+        let props = TocProps::new(sections()).selected(Some(1)).max_level(2);
+        let Element::Fragment(lines) = Toc::render(&props) else {
+            panic!("Expected Fragment");
+        };
+        let texts: Vec<_> = lines
+            .iter()
+            .map(|l| match l {
+                Element::Text { content, .. } => content.as_str(),
+                _ => "",
+            })
+            .collect();
+        assert_eq!(
+            texts,
+            ["  Intro", "›   Install", "    Usage", "  Reference"]
+        );
+    }
+}
@@ -29,13 +29,60 @@
 //!     vec![],
 //! )
 //! ```
+//!
+//! ## Lazy children
+//!
+//! A node made with [`TreeNode::lazy`] gets its children from a loader the
+//! first time it's expanded with [`TreeState::expand_node`], so a file
+//! system or remote API can be browsed without building the whole tree.
+//! An expanded node whose children aren't there yet shows a spinner
+//! placeholder; for slow sources, mark the node [`loading`](TreeNode::loading)
+//! while fetching in the background and hand over the children with
+//! [`TreeNode::set_children`].
+//!
+//! ```ignore
+//! let mut root = TreeNode::lazy("/", "/", |path| list_dir(path));
+//!
+//! // In the input handler, on Enter:
+//! state.toggle_node(&mut root, &selected_id);
+//!
+//! // In render (redraw while a node is loading):
+//! TreeViewProps::new(root.clone()).state(state.clone()).frame(spinner_frame(start, SpinnerStyle::Dots))
+//! ```
+//!
+//! ## Search
+//!
+//! [`TreeState::search`] finds the nodes whose label contains a query,
+//! ignoring case, expands their ancestors and selects the first. The
+//! matched part of each label is highlighted, `n` and `N` move between
+//! matches, and [`TreeViewProps::filter`] hides the nodes that neither
+//! match nor lead to a match.
+//!
+//! ```ignore
+//! state.search(&root, "lib");
+//!
+//! // In the input handler:
+//! state.handle_search_key(&key, &root);
+//!
+//! // In render:
+//! TreeViewProps::new(root.clone()).state(state.clone()).filter(true)
+//! ```
 
+use crate::components::spinner::SpinnerStyle;
 use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::input::Key;
 use crate::style::{Color, Modifier, Style};
+use crossterm::event::KeyCode;
 use std::collections::HashSet;
+use std::fmt;
+use std::rc::Rc;
+
+/// A closure returning the children of the node with the given id.
+pub type ChildLoader = Rc<dyn Fn(&str) -> Vec<TreeNode>>;
 
 /// A node in the tree.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct TreeNode {
     /// Unique identifier for this node.
     pub id: String,
@@ -49,6 +96,25 @@ pub struct TreeNode {
     pub color: Option<Color>,
     /// Whether this node is disabled.
     pub disabled: bool,
+    /// Loads the children on first expansion; `None` once loaded.
+    pub loader: Option<ChildLoader>,
+    /// Whether the children are being fetched elsewhere.
+    pub loading: bool,
+}
+
+impl fmt::Debug for TreeNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TreeNode")
+            .field("id", &self.id)
+            .field("label", &self.label)
+            .field("icon", &self.icon)
+            .field("children", &self.children)
+            .field("color", &self.color)
+            .field("disabled", &self.disabled)
+            .field("loader", &self.loader.as_ref().map(|_| "<fn>"))
+            .field("loading", &self.loading)
+            .finish()
+    }
 }
 
 impl TreeNode {
@@ -62,6 +128,8 @@ impl TreeNode {
             children: Vec::new(),
             color: None,
             disabled: false,
+            loader: None,
+            loading: false,
         }
     }
 
@@ -74,6 +142,20 @@ impl TreeNode {
             children: Vec::new(),
             color: None,
             disabled: false,
+            loader: None,
+            loading: false,
+        }
+    }
+
+    /// Create a node whose children come from `loader`, called with the
+    /// node's id the first time it's expanded.
+    pub fn lazy<F>(id: impl Into<String>, label: impl Into<String>, loader: F) -> Self
+    where
+        F: Fn(&str) -> Vec<TreeNode> + 'static,
+    {
+        Self {
+            loader: Some(Rc::new(loader)),
+            ..Self::with_id(id, label)
         }
     }
 
@@ -117,24 +199,125 @@ impl TreeNode {
         self
     }
 
-    /// Check if this node has children.
+    /// Mark the children as being fetched, showing a placeholder while
+    /// the node is expanded.
+    #[must_use]
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Replace the children, e.g. when a background fetch finishes. The
+    /// node is no longer lazy or loading.
+    pub fn set_children(&mut self, children: Vec<TreeNode>) {
+        self.children = children;
+        self.loader = None;
+        self.loading = false;
+    }
+
+    /// Call the loader, if the children haven't been loaded yet.
+    ///
+    /// Returns true if the loader ran.
+    pub fn load_children(&mut self) -> bool {
+        match self.loader.take() {
+            Some(loader) => {
+                self.children = loader(&self.id);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether the children are still to come, from the loader or a fetch.
+    pub fn is_pending(&self) -> bool {
+        self.loader.is_some() || self.loading
+    }
+
+    /// The node with `id`, this one or any below it.
+    pub fn find_mut(&mut self, id: &str) -> Option<&mut TreeNode> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.children
+            .iter_mut()
+            .find_map(|child| child.find_mut(id))
+    }
+
+    /// Check if this node has children, or may have once loaded.
     pub fn has_children(&self) -> bool {
-        !self.children.is_empty()
+        !self.children.is_empty() || self.is_pending()
     }
 
     /// Check if this is a leaf node.
     pub fn is_leaf(&self) -> bool {
         self.children.is_empty()
     }
+
+    /// Ids of the nodes whose label contains `query`, ignoring case, top
+    /// to bottom. Children not loaded yet aren't searched.
+    pub fn search(&self, query: &str) -> Vec<String> {
+        fn walk(node: &TreeNode, query: &str, found: &mut Vec<String>) {
+            if find_ignore_case(&node.label, query).is_some() {
+                found.push(node.id.clone());
+            }
+            for child in &node.children {
+                walk(child, query, found);
+            }
+        }
+        let mut found = Vec::new();
+        if !query.is_empty() {
+            walk(self, query, &mut found);
+        }
+        found
+    }
+
+    /// Ids of the nodes above the node with `id`, from this one down, or
+    /// `None` if it isn't in the tree.
+    pub fn ancestors(&self, id: &str) -> Option<Vec<String>> {
+        if self.id == id {
+            return Some(Vec::new());
+        }
+        self.children.iter().find_map(|child| {
+            child.ancestors(id).map(|mut path| {
+                path.insert(0, self.id.clone());
+                path
+            })
+        })
+    }
+}
+
+/// Byte range of the first occurrence of `query` in `text`, ignoring case.
+fn find_ignore_case(text: &str, query: &str) -> Option<(usize, usize)> {
+    if query.is_empty() {
+        return None;
+    }
+    text.char_indices().find_map(|(start, _)| {
+        let mut rest = text[start..].char_indices();
+        let mut end = start;
+        for q in query.chars() {
+            match rest.next() {
+                Some((i, c)) if c.to_lowercase().eq(q.to_lowercase()) => {
+                    end = start + i + c.len_utf8();
+                }
+                _ => return None,
+            }
+        }
+        Some((start, end))
+    })
 }
 
 /// State for tree view (expanded nodes, selected node).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
 pub struct TreeState {
     /// Set of expanded node IDs.
     pub expanded: HashSet<String>,
     /// Currently selected/focused node ID.
     pub selected: Option<String>,
+    /// The search query; empty when not searching.
+    pub query: String,
+    /// Ids of the nodes matching the query, top to bottom.
+    pub matches: Vec<String>,
 }
 
 impl TreeState {
@@ -202,6 +385,102 @@ impl TreeState {
     pub fn collapse_all(&mut self) {
         self.expanded.clear();
     }
+
+    /// Expand the node with `id` in `root`, loading its children first if
+    /// it's lazy.
+    ///
+    /// Returns true if the node was found.
+    pub fn expand_node(&mut self, root: &mut TreeNode, id: &str) -> bool {
+        match root.find_mut(id) {
+            Some(node) => {
+                node.load_children();
+                self.expanded.insert(id.to_string());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Expand or collapse the node with `id` in `root`, loading its
+    /// children on first expansion.
+    pub fn toggle_node(&mut self, root: &mut TreeNode, id: &str) {
+        if self.is_expanded(id) {
+            self.expanded.remove(id);
+        } else {
+            self.expand_node(root, id);
+        }
+    }
+
+    /// Expand the ancestors of the node with `id` and select it.
+    pub fn reveal(&mut self, root: &TreeNode, id: &str) {
+        if let Some(ancestors) = root.ancestors(id) {
+            self.expanded.extend(ancestors);
+            self.selected = Some(id.to_string());
+        }
+    }
+
+    /// Search labels for `query`, expanding the ancestors of every match
+    /// and selecting the first. Returns the number of matches.
+    pub fn search(&mut self, root: &TreeNode, query: &str) -> usize {
+        self.query = query.to_string();
+        self.matches = root.search(query);
+        for id in &self.matches {
+            if let Some(ancestors) = root.ancestors(id) {
+                self.expanded.extend(ancestors);
+            }
+        }
+        if let Some(first) = self.matches.first() {
+            self.selected = Some(first.clone());
+        }
+        self.matches.len()
+    }
+
+    /// Stop searching. Expanded nodes stay expanded.
+    pub fn clear_search(&mut self) {
+        self.query.clear();
+        self.matches.clear();
+    }
+
+    /// Select the next match after the selection, wrapping around.
+    pub fn next_match(&mut self, root: &TreeNode) {
+        self.step_match(root, true);
+    }
+
+    /// Select the match before the selection, wrapping around.
+    pub fn prev_match(&mut self, root: &TreeNode) {
+        self.step_match(root, false);
+    }
+
+    fn step_match(&mut self, root: &TreeNode, forward: bool) {
+        let len = self.matches.len();
+        if len == 0 {
+            return;
+        }
+        let position = self
+            .matches
+            .iter()
+            .position(|id| self.selected.as_ref() == Some(id));
+        let index = match (position, forward) {
+            (Some(i), true) => (i + 1) % len,
+            (Some(i), false) => (i + len - 1) % len,
+            (None, true) => 0,
+            (None, false) => len - 1,
+        };
+        let id = self.matches[index].clone();
+        self.reveal(root, &id);
+    }
+
+    /// Handle `n` and `N` to move to the next and previous match.
+    ///
+    /// Returns true if the key was handled.
+    pub fn handle_search_key(&mut self, key: &Key, root: &TreeNode) -> bool {
+        match key.code {
+            KeyCode::Char('n') if !self.matches.is_empty() => self.next_match(root),
+            KeyCode::Char('N') if !self.matches.is_empty() => self.prev_match(root),
+            _ => return false,
+        }
+        true
+    }
 }
 
 /// Tree connector style.
@@ -219,6 +498,15 @@ pub enum TreeConnectors {
 }
 
 impl TreeConnectors {
+    /// The connectors to draw with under `glyphs`: Unicode connectors fall
+    /// back to [`TreeConnectors::Ascii`] when only ASCII is available.
+    pub fn for_glyphs(self, glyphs: GlyphSet) -> Self {
+        match self {
+            TreeConnectors::Unicode if glyphs.is_ascii() => TreeConnectors::Ascii,
+            _ => self,
+        }
+    }
+
     /// Get connector strings: (branch, last, vertical, space).
     pub fn chars(&self) -> (&'static str, &'static str, &'static str, &'static str) {
         match self {
@@ -230,6 +518,16 @@ impl TreeConnectors {
     }
 }
 
+/// Replaces the default Unicode expand/collapse indicators under ASCII.
+/// Custom indicators are kept as given.
+fn ascii_indicator(indicator: &str, glyphs: GlyphSet) -> &str {
+    match indicator {
+        "▶" if glyphs.is_ascii() => ">",
+        "▼" if glyphs.is_ascii() => "v",
+        _ => indicator,
+    }
+}
+
 /// Properties for the TreeView component.
 #[derive(Debug, Clone)]
 pub struct TreeViewProps {
@@ -256,6 +554,18 @@ pub struct TreeViewProps {
     pub show_indicators: bool,
     /// Indent size (in spaces, for Indent connector style).
     pub indent_size: usize,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+    /// Spinner shown under expanded nodes whose children are pending.
+    pub spinner: SpinnerStyle,
+    /// Spinner frame to draw.
+    pub frame: usize,
+    /// Text next to the spinner.
+    pub loading_label: String,
+    /// Hide nodes that neither match the search nor lead to a match.
+    pub filter: bool,
+    /// Style of the matched part of a label.
+    pub match_style: Style,
 }
 
 impl Default for TreeViewProps {
@@ -273,6 +583,12 @@ impl Default for TreeViewProps {
             collapse_indicator: "▼".to_string(),
             show_indicators: true,
             indent_size: 2,
+            glyphs: None,
+            spinner: SpinnerStyle::Dots,
+            frame: 0,
+            loading_label: "Loading…".to_string(),
+            filter: false,
+            match_style: Style::new().fg(Color::Black).bg(Color::Yellow),
         }
     }
 }
@@ -307,6 +623,13 @@ impl TreeViewProps {
         self
     }
 
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
     /// Set branch color.
     #[must_use]
     pub fn branch_color(mut self, color: Color) -> Self {
@@ -336,6 +659,41 @@ impl TreeViewProps {
         self
     }
 
+    /// Set the spinner shown while children load.
+    #[must_use]
+    pub fn spinner(mut self, spinner: SpinnerStyle) -> Self {
+        self.spinner = spinner;
+        self
+    }
+
+    /// Set the spinner frame for pending nodes.
+    #[must_use]
+    pub fn frame(mut self, frame: usize) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    /// Set the text shown while children load.
+    #[must_use]
+    pub fn loading_label(mut self, label: impl Into<String>) -> Self {
+        self.loading_label = label.into();
+        self
+    }
+
+    /// Show only the search matches and their ancestors.
+    #[must_use]
+    pub fn filter(mut self, filter: bool) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Set the style of the matched part of a label.
+    #[must_use]
+    pub fn match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
     /// Show or hide expand/collapse indicators.
     #[must_use]
     pub fn show_indicators(mut self, show: bool) -> Self {
@@ -355,9 +713,11 @@ impl TreeView {
         prefix: &str,
         is_last: bool,
         is_root: bool,
+        shown: Option<&HashSet<String>>,
         lines: &mut Vec<Element>,
     ) {
-        let (branch, last, vertical, space) = props.connectors.chars();
+        let glyphs = GlyphSet::resolve(props.glyphs);
+        let (branch, last, vertical, space) = props.connectors.for_glyphs(glyphs).chars();
 
         // Determine the connector for this node
         let connector = if is_root {
@@ -379,9 +739,9 @@ impl TreeView {
             // Add expand/collapse indicator
             if props.show_indicators && node.has_children() {
                 if props.state.is_expanded(&node.id) {
-                    line_content.push_str(&props.collapse_indicator);
+                    line_content.push_str(ascii_indicator(&props.collapse_indicator, glyphs));
                 } else {
-                    line_content.push_str(&props.expand_indicator);
+                    line_content.push_str(ascii_indicator(&props.expand_indicator, glyphs));
                 }
                 line_content.push(' ');
             }
@@ -392,9 +752,6 @@ impl TreeView {
                 line_content.push(' ');
             }
 
-            // Add label
-            line_content.push_str(&node.label);
-
             // Determine style
             let mut style = Style::new();
 
@@ -418,7 +775,20 @@ impl TreeView {
                 style = style.fg(color);
             }
 
-            lines.push(Element::styled_text(&line_content, style));
+            match find_ignore_case(&node.label, &props.state.query) {
+                Some((start, end)) => {
+                    line_content.push_str(&node.label[..start]);
+                    lines.push(Element::Fragment(vec![
+                        Element::styled_text(&line_content, style),
+                        Element::styled_text(&node.label[start..end], props.match_style),
+                        Element::styled_text(&node.label[end..], style),
+                    ]));
+                }
+                None => {
+                    line_content.push_str(&node.label);
+                    lines.push(Element::styled_text(&line_content, style));
+                }
+            }
         }
 
         // Render children if expanded (or if root and not showing root)
@@ -426,8 +796,13 @@ impl TreeView {
             (is_root && !props.show_root) || props.state.is_expanded(&node.id);
 
         if should_render_children {
-            let child_count = node.children.len();
-            for (i, child) in node.children.iter().enumerate() {
+            let children: Vec<&TreeNode> = node
+                .children
+                .iter()
+                .filter(|child| shown.is_none_or(|shown| shown.contains(&child.id)))
+                .collect();
+            let child_count = children.len();
+            for (i, child) in children.into_iter().enumerate() {
                 let is_last_child = i == child_count - 1;
 
                 // Calculate prefix for children
@@ -440,7 +815,29 @@ impl TreeView {
                     format!("{}{}", prefix, continuation)
                 };
 
-                Self::render_node(child, props, &child_prefix, is_last_child, false, lines);
+                Self::render_node(
+                    child,
+                    props,
+                    &child_prefix,
+                    is_last_child,
+                    false,
+                    shown,
+                    lines,
+                );
+            }
+
+            if node.children.is_empty() && node.is_pending() {
+                let child_prefix = if is_root {
+                    prefix.to_string()
+                } else {
+                    let continuation = if is_last { space } else { vertical };
+                    format!("{}{}", prefix, continuation)
+                };
+                let spinner = props.spinner.for_glyphs(glyphs).frame_at(props.frame);
+                lines.push(Element::styled_text(
+                    format!("{child_prefix}{last}{spinner} {}", props.loading_label),
+                    Style::new().add_modifier(Modifier::DIM),
+                ));
             }
         }
     }
@@ -451,7 +848,26 @@ impl Component for TreeView {
 
     fn render(props: &Self::Props) -> Element {
         let mut lines: Vec<Element> = Vec::new();
-        Self::render_node(&props.root, props, "", true, true, &mut lines);
+        // With filtering on, only matches and their ancestors are shown
+        let shown = (props.filter && !props.state.query.is_empty()).then(|| {
+            let mut shown = HashSet::new();
+            for id in &props.state.matches {
+                if let Some(ancestors) = props.root.ancestors(id) {
+                    shown.extend(ancestors);
+                    shown.insert(id.clone());
+                }
+            }
+            shown
+        });
+        Self::render_node(
+            &props.root,
+            props,
+            "",
+            true,
+            true,
+            shown.as_ref(),
+            &mut lines,
+        );
 
         if lines.is_empty() {
             Element::Empty
@@ -601,4 +1017,156 @@ mod tests {
         assert!(!state.is_expanded("a"));
         assert!(!state.is_expanded("b"));
     }
+
+    fn rendered(props: &TreeViewProps) -> Vec<String> {
+        match TreeView::render(props) {
+            Element::Fragment(lines) => lines
+                .into_iter()
+                .map(|line| match line {
+                    Element::Text { content, .. } => content,
+                    _ => panic!("expected text"),
+                })
+                .collect(),
+            Element::Text { content, .. } => vec![content],
+            _ => panic!("expected lines"),
+        }
+    }
+
+    #[test]
+    fn test_lazy_node_loads_once_on_expand() {
+        use std::cell::Cell;
+
+        let calls = Rc::new(Cell::new(0));
+        let counter = calls.clone();
+        let mut root = TreeNode::new("root").child(TreeNode::lazy("src", "src", move |id| {
+            counter.set(counter.get() + 1);
+            vec![TreeNode::leaf(format!("{id}/main.rs"))]
+        }));
+        assert!(root.children[0].has_children());
+        assert_eq!(calls.get(), 0);
+
+        let mut state = TreeState::new().expand("root");
+        state.toggle_node(&mut root, "src");
+        assert!(state.is_expanded("src"));
+        assert_eq!(root.children[0].children[0].label, "src/main.rs");
+
+        state.toggle_node(&mut root, "src");
+        state.toggle_node(&mut root, "src");
+        assert_eq!(calls.get(), 1);
+        assert!(!state.expand_node(&mut root, "missing"));
+    }
+
+    #[test]
+    fn test_pending_node_shows_placeholder() {
+        let root = TreeNode::new("root")
+            .child(TreeNode::new("remote").loading(true))
+            .child(TreeNode::leaf("local"));
+        let state = TreeState::new().expand("root").expand("remote");
+        let props = TreeViewProps::new(root.clone())
+            .state(state.clone())
+            .glyphs(GlyphSet::Ascii)
+            .spinner(SpinnerStyle::Line)
+            .frame(1)
+            .loading_label("fetching");
+        assert_eq!(
+            rendered(&props),
+            ["v root", "|-- v remote", "|   `-- / fetching", "`-- local"]
+        );
+
+        let mut root = root;
+        root.find_mut("remote")
+            .unwrap()
+            .set_children(vec![TreeNode::leaf("data")]);
+        let props = TreeViewProps::new(root)
+            .state(state)
+            .glyphs(GlyphSet::Ascii);
+        assert_eq!(
+            rendered(&props),
+            ["v root", "|-- v remote", "|   `-- data", "`-- local"]
+        );
+    }
+
+    fn search_tree() -> TreeNode {
+        TreeNode::new("root")
+            .child(
+                TreeNode::new("src")
+                    .child(TreeNode::leaf("main.rs"))
+                    .child(TreeNode::leaf("Lib.rs")),
+            )
+            .child(TreeNode::new("docs").child(TreeNode::leaf("guide.md")))
+            .child(TreeNode::leaf("lib-notes.txt"))
+    }
+
+    #[test]
+    fn test_search_expands_ancestors_and_cycles() {
+        let root = search_tree();
+        let mut state = TreeState::new();
+        assert_eq!(state.search(&root, "LIB"), 2);
+        assert_eq!(state.matches, ["Lib.rs", "lib-notes.txt"]);
+        assert!(state.is_expanded("root") && state.is_expanded("src"));
+        assert!(!state.is_expanded("docs"));
+        assert!(state.is_selected("Lib.rs"));
+
+        assert!(state.handle_search_key(&Key::new(KeyCode::Char('n')), &root));
+        assert!(state.is_selected("lib-notes.txt"));
+        state.next_match(&root);
+        assert!(state.is_selected("Lib.rs"));
+        state.handle_search_key(&Key::new(KeyCode::Char('N')), &root);
+        assert!(state.is_selected("lib-notes.txt"));
+
+        state.clear_search();
+        assert!(!state.handle_search_key(&Key::new(KeyCode::Char('n')), &root));
+    }
+
+    #[test]
+    fn test_filter_highlights_matches() {
+        let root = search_tree();
+        let mut state = TreeState::new();
+        state.search(&root, "lib");
+        let props = TreeViewProps::new(root)
+            .state(state)
+            .glyphs(GlyphSet::Ascii)
+            .show_indicators(false)
+            .filter(true);
+        let lines = match TreeView::render(&props) {
+            Element::Fragment(lines) => lines,
+            _ => panic!("expected lines"),
+        };
+        let text: Vec<String> = lines
+            .iter()
+            .map(|line| match line {
+                Element::Text { content, .. } => content.clone(),
+                Element::Fragment(spans) => spans
+                    .iter()
+                    .map(|span| match span {
+                        Element::Text { content, .. } => content.as_str(),
+                        _ => "",
+                    })
+                    .collect(),
+                _ => panic!("expected text"),
+            })
+            .collect();
+        assert_eq!(
+            text,
+            ["root", "|-- src", "|   `-- Lib.rs", "`-- lib-notes.txt"]
+        );
+
+        match &lines[2] {
+            Element::Fragment(spans) => match &spans[1] {
+                Element::Text { content, style, .. } => {
+                    assert_eq!(content, "Lib");
+                    assert_eq!(*style, props.match_style);
+                }
+                _ => panic!("expected text"),
+            },
+            _ => panic!("expected highlighted line"),
+        }
+    }
+
+    #[test]
+    fn test_find_ignore_case() {
+        assert_eq!(find_ignore_case("Straße", "SSE"), None);
+        assert_eq!(find_ignore_case("Über cool", "über"), Some((0, 5)));
+        assert_eq!(find_ignore_case("abc", ""), None);
+    }
 }
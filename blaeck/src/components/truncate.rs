@@ -0,0 +1,279 @@
+//! Truncate component - shows the first few lines of its children.
+//!
+//! Truncate cuts its children off after `lines` rows and adds a hint line
+//! like "… 12 more lines, press e to expand". Expanded, it shows everything,
+//! or scrolls the children in a [`ScrollView`](super::ScrollView) when
+//! `max_height` is set. Content that already fits is shown as is, with no
+//! hint.
+//!
+//! ## When to use Truncate
+//!
+//! - Stack traces and long diffs in a summary
+//! - Command output where the first lines are usually enough
+//!
+//! ## See also
+//!
+//! - [`Collapsible`](super::Collapsible) — Hide the content entirely behind a header
+//! - [`ScrollView`](super::ScrollView) — Always scroll, never expand
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut trace = TruncateState::new(20);
+//!
+//! // In the input handler ('e' toggles, arrows scroll while expanded):
+//! trace.handle_key(&key);
+//!
+//! // In render:
+//! Element::node::<Truncate>(
+//!     TruncateProps::new(5)
+//!         .expanded(trace.expanded)
+//!         .max_height(20)
+//!         .offset(trace.scroll.offset),
+//!     frames.iter().map(|f| Element::text(f)).collect(),
+//! )
+//! ```
+
+use crate::components::scroll_view::{ScrollState, ScrollViewProps};
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::input::Key;
+use crate::layout::{FlexDirection, LayoutStyle};
+use crate::style::{Color, Style};
+use crate::truncation::Truncation;
+use crossterm::event::KeyCode;
+
+/// Properties for the Truncate component.
+#[derive(Debug, Clone)]
+pub struct TruncateProps {
+    /// Rows shown while collapsed.
+    pub lines: u16,
+    /// Whether all of the content is shown.
+    pub expanded: bool,
+    /// Height of the scrolling view while expanded (`None` = no limit).
+    pub max_height: Option<u16>,
+    /// First visible row while expanded and scrolling.
+    pub offset: usize,
+    /// Key named in the hint.
+    pub key: char,
+    /// Color of the hint line.
+    pub hint_color: Color,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for TruncateProps {
+    fn default() -> Self {
+        Self {
+            lines: 5,
+            expanded: false,
+            max_height: None,
+            offset: 0,
+            key: 'e',
+            hint_color: Color::DarkGray,
+            glyphs: None,
+        }
+    }
+}
+
+impl TruncateProps {
+    /// Create props showing the first `lines` rows while collapsed.
+    pub fn new(lines: u16) -> Self {
+        Self {
+            lines,
+            ..Default::default()
+        }
+    }
+
+    /// Set whether all of the content is shown.
+    #[must_use]
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Scroll the expanded content in a view this many rows tall.
+    #[must_use]
+    pub fn max_height(mut self, height: u16) -> Self {
+        self.max_height = Some(height);
+        self
+    }
+
+    /// Set the first visible row while expanded.
+    #[must_use]
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the key named in the hint.
+    #[must_use]
+    pub fn key(mut self, key: char) -> Self {
+        self.key = key;
+        self
+    }
+
+    /// Set the hint color.
+    #[must_use]
+    pub fn hint_color(mut self, color: Color) -> Self {
+        self.hint_color = color;
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// The view showing content `content_height` rows tall: cut to `lines`
+    /// while collapsed, scrolling while expanded with a `max_height`.
+    pub fn view(&self, content_height: usize) -> ScrollViewProps {
+        let fits = |height: u16| content_height <= usize::from(height);
+        let mut view = match (self.expanded, self.max_height) {
+            (false, _) if !fits(self.lines) => ScrollViewProps::new(self.lines).scrollbar(false),
+            (true, Some(height)) if !fits(height) => {
+                ScrollViewProps::new(height).offset(self.offset)
+            }
+            _ => ScrollViewProps::new(content_height as u16).scrollbar(false),
+        };
+        view.glyphs = self.glyphs;
+        view
+    }
+
+    /// The hint below content `content_height` rows tall, or `None` when it
+    /// fits in `lines`.
+    pub fn hint(&self, content_height: usize) -> Option<String> {
+        let hidden = content_height.checked_sub(usize::from(self.lines))?;
+        if hidden == 0 {
+            return None;
+        }
+        if self.expanded {
+            return Some(format!("press {} to collapse", self.key));
+        }
+        let ellipsis = Truncation::resolve(self.glyphs).ellipsis;
+        let noun = if hidden == 1 { "line" } else { "lines" };
+        Some(format!(
+            "{ellipsis} {hidden} more {noun}, press {} to expand",
+            self.key
+        ))
+    }
+
+    /// Style of the hint line.
+    pub fn hint_style(&self) -> Style {
+        Style::new().fg(self.hint_color)
+    }
+
+    /// Layout of the whole component: the view above the hint.
+    pub fn to_layout_style(&self) -> LayoutStyle {
+        LayoutStyle {
+            flex_direction: FlexDirection::Column,
+            flex_shrink: 0.0,
+            ..Default::default()
+        }
+    }
+}
+
+/// A container that shows only the first rows of its children until
+/// expanded.
+///
+/// The renderer measures the children, cuts them to the view and draws the
+/// hint.
+pub struct Truncate;
+
+impl Component for Truncate {
+    type Props = TruncateProps;
+
+    fn render(_props: &Self::Props) -> Element {
+        // Like ScrollView, the renderer draws the children and hint directly
+        Element::empty()
+    }
+}
+
+/// Helper struct for toggling a Truncate and scrolling it while expanded.
+#[derive(Debug, Clone)]
+pub struct TruncateState {
+    /// Whether all of the content is shown.
+    pub expanded: bool,
+    /// Key that toggles `expanded`.
+    pub key: char,
+    /// Scroll position while expanded.
+    pub scroll: ScrollState,
+}
+
+impl TruncateState {
+    /// Create a collapsed state that scrolls in a view `max_height` rows
+    /// tall when expanded.
+    pub fn new(max_height: usize) -> Self {
+        Self {
+            expanded: false,
+            key: 'e',
+            scroll: ScrollState::new(0, max_height),
+        }
+    }
+
+    /// Set the content height, for scrolling.
+    pub fn set_content_height(&mut self, height: usize) {
+        self.scroll.set_content_height(height);
+    }
+
+    /// Expand, or collapse back to the top.
+    pub fn toggle(&mut self) {
+        self.expanded = !self.expanded;
+        if !self.expanded {
+            self.scroll.top();
+        }
+    }
+
+    /// Handle the toggle key, and scroll keys while expanded.
+    ///
+    /// Returns true if the key was handled.
+    pub fn handle_key(&mut self, key: &Key) -> bool {
+        if key.code == KeyCode::Char(self.key) {
+            self.toggle();
+            return true;
+        }
+        self.expanded && self.scroll.handle_key(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_view_and_hint() {
+        let props = TruncateProps::new(3).glyphs(GlyphSet::Unicode);
+        assert_eq!(props.view(10).height, 3);
+        assert_eq!(
+            props.hint(10).as_deref(),
+            Some("… 7 more lines, press e to expand")
+        );
+        assert_eq!(props.view(2).height, 2);
+        assert_eq!(props.hint(3), None);
+
+        let expanded = props.expanded(true);
+        assert_eq!(expanded.view(10).height, 10);
+        assert!(!expanded.view(10).scrollbar);
+        assert_eq!(expanded.hint(10).as_deref(), Some("press e to collapse"));
+
+        let scrolling = expanded.max_height(4).offset(2);
+        let view = scrolling.view(10);
+        assert_eq!((view.height, view.offset, view.scrollbar), (4, 2, true));
+    }
+
+    #[test]
+    fn test_truncate_state_keys() {
+        let mut state = TruncateState::new(4);
+        state.set_content_height(10);
+        assert!(!state.handle_key(&Key::new(KeyCode::Down)));
+        assert!(state.handle_key(&Key::new(KeyCode::Char('e'))));
+        assert!(state.expanded);
+        assert!(state.handle_key(&Key::new(KeyCode::Down)));
+        assert_eq!(state.scroll.offset, 1);
+        state.handle_key(&Key::new(KeyCode::Char('e')));
+        assert!(!state.expanded);
+        assert_eq!(state.scroll.offset, 0);
+    }
+}
@@ -0,0 +1,611 @@
+//! ValueTree component - a collapsible viewer for JSON and YAML data.
+//!
+//! The ValueTree shows a [`DataValue`] as a [`TreeView`]: objects and
+//! arrays are branches with their size, scalars are colored by type, and
+//! long strings are cut with the app's ellipsis. Every node's id is its
+//! path (`$.users[0].name`), which the viewer can show under the tree.
+//! [`ValueTreeState`] moves the selection, expands and collapses, and
+//! searches keys and values.
+//!
+//! `DataValue` converts from `serde_json::Value` with the `json` feature
+//! and from `serde_norway::Value` with the `yaml` feature.
+//!
+//! ## When to use ValueTree
+//!
+//! - Inspecting API responses, config files and logs
+//! - Any nested data whose shape isn't known ahead of time
+//!
+//! ## See also
+//!
+//! - [`TreeView`](super::TreeView) — Trees of your own nodes
+//! - [`SyntaxHighlight`](super::SyntaxHighlight) — The raw text, highlighted
+//!
+//! # Example
+//!
+//! ```ignore
+//! let value = DataValue::from(&serde_json::from_str::<serde_json::Value>(&body)?);
+//! let mut state = ValueTreeState::new().expand_to_depth(&value, 1);
+//!
+//! // In the input handler:
+//! state.handle_key(&key, &value);
+//!
+//! // In render:
+//! Element::node::<ValueTree>(ValueTreeProps::new(value.clone()).state(state.clone()), vec![])
+//! // Renders:
+//! // ▼ $ {2}
+//! // ├── ▶ users [3]
+//! // └── ok: true
+//! // $.users
+//! ```
+
+use crate::components::tree::{TreeNode, TreeState, TreeView, TreeViewProps};
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::input::Key;
+use crate::style::{Color, Modifier, Style};
+use crate::truncation::Truncation;
+use crossterm::event::KeyCode;
+
+/// A JSON/YAML-shaped value.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum DataValue {
+    /// `null`.
+    #[default]
+    Null,
+    /// `true` or `false`.
+    Bool(bool),
+    /// A number, as written.
+    Number(String),
+    /// A string.
+    String(String),
+    /// A list of values.
+    Array(Vec<DataValue>),
+    /// Keys and values, in order.
+    Object(Vec<(String, DataValue)>),
+}
+
+impl DataValue {
+    /// The items of an array or the members of an object, with the path
+    /// segment of each; empty for scalars.
+    pub fn children(&self) -> Vec<(String, &DataValue)> {
+        match self {
+            DataValue::Array(items) => items
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (format!("[{i}]"), v))
+                .collect(),
+            DataValue::Object(members) => {
+                members.iter().map(|(k, v)| (key_segment(k), v)).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Whether this is an array or object.
+    pub fn is_container(&self) -> bool {
+        matches!(self, DataValue::Array(_) | DataValue::Object(_))
+    }
+
+    /// The value at `path` (as in node ids, e.g. `$.users[0]`).
+    pub fn get(&self, path: &str) -> Option<&DataValue> {
+        if path == "$" {
+            return Some(self);
+        }
+        let mut value = self;
+        let mut at = "$".to_string();
+        'walk: while at != path {
+            for (segment, child) in value.children() {
+                let next = format!("{at}{segment}");
+                if path.starts_with(&next) {
+                    value = child;
+                    at = next;
+                    continue 'walk;
+                }
+            }
+            return None;
+        }
+        Some(value)
+    }
+
+    /// Paths of every key or scalar containing `query`, ignoring case, in
+    /// document order.
+    pub fn search(&self, query: &str) -> Vec<String> {
+        let query = query.to_lowercase();
+        let mut found = Vec::new();
+        if !query.is_empty() {
+            self.search_into("$", None, &query, &mut found);
+        }
+        found
+    }
+
+    fn search_into(&self, path: &str, key: Option<&str>, query: &str, found: &mut Vec<String>) {
+        let scalar = match self {
+            DataValue::String(s) | DataValue::Number(s) => Some(s.to_lowercase()),
+            DataValue::Bool(b) => Some(b.to_string()),
+            DataValue::Null => Some("null".to_string()),
+            _ => None,
+        };
+        let key_matches = key.is_some_and(|k| k.to_lowercase().contains(query));
+        if key_matches || scalar.is_some_and(|s| s.contains(query)) {
+            found.push(path.to_string());
+        }
+        let keys: Vec<Option<&str>> = match self {
+            DataValue::Object(members) => members.iter().map(|(k, _)| Some(k.as_str())).collect(),
+            _ => vec![None; self.children().len()],
+        };
+        for ((segment, child), key) in self.children().into_iter().zip(keys) {
+            child.search_into(&format!("{path}{segment}"), key, query, found);
+        }
+    }
+}
+
+/// The path segment for an object key: `.key`, or `["key"]` when it isn't
+/// a plain identifier.
+fn key_segment(key: &str) -> String {
+    let plain = !key.is_empty()
+        && !key.starts_with(|c: char| c.is_ascii_digit())
+        && key.chars().all(|c| c.is_alphanumeric() || c == '_');
+    if plain {
+        format!(".{key}")
+    } else {
+        format!("[{key:?}]")
+    }
+}
+
+/// The paths of `path`'s ancestors, from the root.
+fn ancestors(value: &DataValue, path: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut current = value;
+    let mut at = "$".to_string();
+    while at != path {
+        out.push(at.clone());
+        let Some((segment, child)) = current
+            .children()
+            .into_iter()
+            .find(|(segment, _)| path.starts_with(&format!("{at}{segment}")))
+        else {
+            break;
+        };
+        current = child;
+        at.push_str(&segment);
+    }
+    out
+}
+
+#[cfg(feature = "json")]
+impl From<&serde_json::Value> for DataValue {
+    fn from(value: &serde_json::Value) -> Self {
+        use serde_json::Value;
+        match value {
+            Value::Null => DataValue::Null,
+            Value::Bool(b) => DataValue::Bool(*b),
+            Value::Number(n) => DataValue::Number(n.to_string()),
+            Value::String(s) => DataValue::String(s.clone()),
+            Value::Array(items) => DataValue::Array(items.iter().map(Into::into).collect()),
+            Value::Object(members) => {
+                DataValue::Object(members.iter().map(|(k, v)| (k.clone(), v.into())).collect())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl From<&serde_norway::Value> for DataValue {
+    fn from(value: &serde_norway::Value) -> Self {
+        use serde_norway::Value;
+        match value {
+            Value::Null => DataValue::Null,
+            Value::Bool(b) => DataValue::Bool(*b),
+            Value::Number(n) => DataValue::Number(n.to_string()),
+            Value::String(s) => DataValue::String(s.clone()),
+            Value::Sequence(items) => DataValue::Array(items.iter().map(Into::into).collect()),
+            Value::Mapping(members) => DataValue::Object(
+                members
+                    .iter()
+                    .map(|(k, v)| {
+                        let key = match k {
+                            Value::String(s) => s.clone(),
+                            other => serde_norway::to_string(other)
+                                .unwrap_or_default()
+                                .trim_end()
+                                .to_string(),
+                        };
+                        (key, v.into())
+                    })
+                    .collect(),
+            ),
+            Value::Tagged(tagged) => (&tagged.value).into(),
+        }
+    }
+}
+
+/// Expanded nodes, selection and search of a [`ValueTree`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValueTreeState {
+    /// Expanded paths and the selected path.
+    pub tree: TreeState,
+    /// The last search query.
+    pub query: String,
+    /// Paths matching the query.
+    pub matches: Vec<String>,
+}
+
+impl Default for ValueTreeState {
+    fn default() -> Self {
+        Self {
+            tree: TreeState::new().expand("$").select("$"),
+            query: String::new(),
+            matches: Vec::new(),
+        }
+    }
+}
+
+impl ValueTreeState {
+    /// Create a state with the root expanded and selected.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expand every container down to `depth` levels below the root.
+    #[must_use]
+    pub fn expand_to_depth(mut self, value: &DataValue, depth: usize) -> Self {
+        fn walk(state: &mut TreeState, value: &DataValue, path: &str, depth: usize) {
+            if !value.is_container() {
+                return;
+            }
+            state.expanded.insert(path.to_string());
+            if depth > 0 {
+                for (segment, child) in value.children() {
+                    walk(state, child, &format!("{path}{segment}"), depth - 1);
+                }
+            }
+        }
+        walk(&mut self.tree, value, "$", depth);
+        self
+    }
+
+    /// The selected path.
+    pub fn selected(&self) -> &str {
+        self.tree.selected.as_deref().unwrap_or("$")
+    }
+
+    /// Select `path`, expanding its ancestors so it's shown.
+    pub fn reveal(&mut self, value: &DataValue, path: &str) {
+        for ancestor in ancestors(value, path) {
+            self.tree.expanded.insert(ancestor);
+        }
+        self.tree.selected = Some(path.to_string());
+    }
+
+    /// The paths shown, top to bottom.
+    pub fn visible(&self, value: &DataValue) -> Vec<String> {
+        fn walk(state: &TreeState, value: &DataValue, path: String, out: &mut Vec<String>) {
+            let expanded = state.is_expanded(&path);
+            out.push(path.clone());
+            if expanded {
+                for (segment, child) in value.children() {
+                    walk(state, child, format!("{path}{segment}"), out);
+                }
+            }
+        }
+        let mut out = Vec::new();
+        walk(&self.tree, value, "$".to_string(), &mut out);
+        out
+    }
+
+    /// Search keys and values for `query`, and select the first match.
+    /// Returns the number of matches.
+    pub fn search(&mut self, value: &DataValue, query: &str) -> usize {
+        self.query = query.to_string();
+        self.matches = value.search(query);
+        if let Some(first) = self.matches.first().cloned() {
+            self.reveal(value, &first);
+        }
+        self.matches.len()
+    }
+
+    /// Select the next match after the selection, wrapping around.
+    pub fn next_match(&mut self, value: &DataValue) {
+        let position = self.matches.iter().position(|m| m == self.selected());
+        let next = position.map_or(0, |i| (i + 1) % self.matches.len().max(1));
+        if let Some(path) = self.matches.get(next).cloned() {
+            self.reveal(value, &path);
+        }
+    }
+
+    /// Handle Up/Down to move, Right/Enter to expand, Left to collapse or
+    /// go to the parent, and `n` for the next search match.
+    ///
+    /// Returns true if the key was handled.
+    pub fn handle_key(&mut self, key: &Key, value: &DataValue) -> bool {
+        let visible = self.visible(value);
+        let selected = self.selected().to_string();
+        let index = visible.iter().position(|p| *p == selected).unwrap_or(0);
+        let is_container = value.get(&selected).is_some_and(DataValue::is_container);
+        match key.code {
+            KeyCode::Up if index > 0 => self.tree.selected = Some(visible[index - 1].clone()),
+            KeyCode::Down if index + 1 < visible.len() => {
+                self.tree.selected = Some(visible[index + 1].clone());
+            }
+            KeyCode::Right | KeyCode::Enter if is_container => {
+                if key.is_enter() {
+                    self.tree.toggle(&selected);
+                } else {
+                    self.tree.expanded.insert(selected);
+                }
+            }
+            KeyCode::Left if is_container && self.tree.is_expanded(&selected) => {
+                self.tree.expanded.remove(&selected);
+            }
+            KeyCode::Left => match ancestors(value, &selected).pop() {
+                Some(parent) => self.tree.selected = Some(parent),
+                None => return false,
+            },
+            KeyCode::Char('n') if !self.matches.is_empty() => self.next_match(value),
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Properties for the ValueTree component.
+#[derive(Debug, Clone)]
+pub struct ValueTreeProps {
+    /// The value shown.
+    pub value: DataValue,
+    /// Expanded nodes, selection and search.
+    pub state: ValueTreeState,
+    /// Longest string shown, in columns, before it's cut.
+    pub max_string_width: usize,
+    /// Whether to show the selected path under the tree.
+    pub show_path: bool,
+    /// Color of strings.
+    pub string_color: Color,
+    /// Color of numbers.
+    pub number_color: Color,
+    /// Color of `true` and `false`.
+    pub bool_color: Color,
+    /// Color of `null`.
+    pub null_color: Color,
+    /// Color of search matches.
+    pub match_color: Color,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for ValueTreeProps {
+    fn default() -> Self {
+        Self {
+            value: DataValue::Null,
+            state: ValueTreeState::new(),
+            max_string_width: 40,
+            show_path: true,
+            string_color: Color::Green,
+            number_color: Color::Cyan,
+            bool_color: Color::Yellow,
+            null_color: Color::DarkGray,
+            match_color: Color::Magenta,
+            glyphs: None,
+        }
+    }
+}
+
+impl ValueTreeProps {
+    /// Create props showing `value`.
+    pub fn new(value: DataValue) -> Self {
+        Self {
+            value,
+            ..Default::default()
+        }
+    }
+
+    /// Set the state.
+    #[must_use]
+    pub fn state(mut self, state: ValueTreeState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Set the longest string shown before it's cut.
+    #[must_use]
+    pub fn max_string_width(mut self, width: usize) -> Self {
+        self.max_string_width = width;
+        self
+    }
+
+    /// Show or hide the selected path.
+    #[must_use]
+    pub fn show_path(mut self, show: bool) -> Self {
+        self.show_path = show;
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// The label and type color of a value shown under `name`.
+    fn label(&self, name: &str, value: &DataValue) -> (String, Option<Color>) {
+        let (text, color) = match value {
+            DataValue::Null => ("null".to_string(), Some(self.null_color)),
+            DataValue::Bool(b) => (b.to_string(), Some(self.bool_color)),
+            DataValue::Number(n) => (n.clone(), Some(self.number_color)),
+            DataValue::String(s) => {
+                let quoted = format!("{s:?}");
+                let text =
+                    Truncation::resolve(self.glyphs).truncate(&quoted, self.max_string_width);
+                (text.into_owned(), Some(self.string_color))
+            }
+            DataValue::Array(items) => return (format!("{name} [{}]", items.len()), None),
+            DataValue::Object(members) => return (format!("{name} {{{}}}", members.len()), None),
+        };
+        (format!("{name}: {text}"), color)
+    }
+
+    fn node(&self, name: &str, path: String, value: &DataValue) -> TreeNode {
+        let (label, color) = self.label(name, value);
+        let mut node = TreeNode::with_id(path.clone(), label);
+        if self.state.matches.contains(&path) {
+            node = node.color(self.match_color);
+        } else if let Some(color) = color {
+            node = node.color(color);
+        }
+        let names: Vec<String> = match value {
+            DataValue::Object(members) => members.iter().map(|(k, _)| k.clone()).collect(),
+            _ => value
+                .children()
+                .into_iter()
+                .map(|(segment, _)| segment)
+                .collect(),
+        };
+        for ((segment, child), name) in value.children().into_iter().zip(names) {
+            node = node.child(self.node(&name, format!("{path}{segment}"), child));
+        }
+        node
+    }
+
+    /// The [`TreeView`] props for the value.
+    pub fn tree_props(&self) -> TreeViewProps {
+        let mut props = TreeViewProps::new(self.node("$", "$".to_string(), &self.value))
+            .state(self.state.tree.clone());
+        props.glyphs = self.glyphs;
+        props
+    }
+}
+
+/// A component that displays nested data as a collapsible tree.
+pub struct ValueTree;
+
+impl Component for ValueTree {
+    type Props = ValueTreeProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let mut lines = match TreeView::render(&props.tree_props()) {
+            Element::Fragment(lines) => lines,
+            Element::Empty => Vec::new(),
+            line => vec![line],
+        };
+        if props.show_path {
+            lines.push(Element::styled_text(
+                props.state.selected(),
+                Style::new().add_modifier(Modifier::DIM),
+            ));
+        }
+        Element::Fragment(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> DataValue {
+        DataValue::Object(vec![
+            (
+                "users".into(),
+                DataValue::Array(vec![DataValue::Object(vec![
+                    ("name".into(), DataValue::String("Ada Lovelace".into())),
+                    ("age".into(), DataValue::Number("36".into())),
+                ])]),
+            ),
+            ("ok".into(), DataValue::Bool(true)),
+            ("odd key".into(), DataValue::Null),
+        ])
+    }
+
+    fn lines(props: &ValueTreeProps) -> Vec<String> {
+        let Element::Fragment(lines) = ValueTree::render(props) else {
+            panic!("expected fragment");
+        };
+        lines
+            .iter()
+            .map(|line| match line {
+                Element::Text { content, .. } => content.clone(),
+                _ => String::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_value_paths_and_search() {
+        let value = sample();
+        assert_eq!(
+            value.get("$.users[0].name"),
+            Some(&DataValue::String("Ada Lovelace".into()))
+        );
+        assert_eq!(value.get("$[\"odd key\"]"), Some(&DataValue::Null));
+        assert_eq!(value.get("$.nope"), None);
+        assert_eq!(value.search("NAME"), vec!["$.users[0].name"]);
+        assert_eq!(value.search("36"), vec!["$.users[0].age"]);
+    }
+
+    #[test]
+    fn test_value_tree_render() {
+        let value = sample();
+        let props = ValueTreeProps::new(value.clone()).glyphs(GlyphSet::Ascii);
+        assert_eq!(
+            lines(&props),
+            vec![
+                "v $ {3}",
+                "|-- > users [1]",
+                "|-- ok: true",
+                "`-- odd key: null",
+                "$",
+            ]
+        );
+
+        let mut state = ValueTreeState::new();
+        assert_eq!(state.search(&value, "ada"), 1);
+        let lines = lines(&props.max_string_width(6).state(state));
+        assert_eq!(lines[3], "|       |-- name: \"Ad...");
+        assert_eq!(lines.last().unwrap(), "$.users[0].name");
+    }
+
+    #[test]
+    fn test_value_tree_state_keys() {
+        let value = sample();
+        let mut state = ValueTreeState::new();
+        state.handle_key(&Key::new(KeyCode::Down), &value);
+        assert_eq!(state.selected(), "$.users");
+        state.handle_key(&Key::new(KeyCode::Right), &value);
+        state.handle_key(&Key::new(KeyCode::Down), &value);
+        assert_eq!(state.selected(), "$.users[0]");
+        state.handle_key(&Key::new(KeyCode::Left), &value);
+        assert_eq!(state.selected(), "$.users");
+        state.handle_key(&Key::new(KeyCode::Left), &value);
+        assert_eq!(state.visible(&value).len(), 4);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_value_from_json() {
+        let json: serde_json::Value = serde_json::from_str(r#"{"a": [1, null]}"#).unwrap();
+        assert_eq!(
+            DataValue::from(&json),
+            DataValue::Object(vec![(
+                "a".into(),
+                DataValue::Array(vec![DataValue::Number("1".into()), DataValue::Null])
+            )])
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_value_from_yaml() {
+        let yaml: serde_norway::Value = serde_norway::from_str("a: [1, x]\n").unwrap();
+        assert_eq!(
+            DataValue::from(&yaml),
+            DataValue::Object(vec![(
+                "a".into(),
+                DataValue::Array(vec![
+                    DataValue::Number("1".into()),
+                    DataValue::String("x".into())
+                ])
+            )])
+        );
+    }
+}
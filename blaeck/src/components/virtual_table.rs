@@ -0,0 +1,415 @@
+//! VirtualTable component - a table that only builds the rows in view.
+//!
+//! A [`Table`](super::Table) renders every row it's given, which is too
+//! slow for tens of thousands of rows. The VirtualTable asks a closure for
+//! rows by index, and only for the window being shown, so its cost depends
+//! on the height of the view rather than the size of the data.
+//!
+//! Column widths stay put while scrolling: auto and fit columns are
+//! measured once, over the header and the first
+//! [`sample_rows`](VirtualTableProps::sample_rows) rows, rather than over
+//! the rows in view. [`VirtualTableState`] keeps the selection and scroll
+//! offset, moving the window to keep the selection in view.
+//!
+//! ## When to use VirtualTable
+//!
+//! - Query results, log records, file listings with many rows
+//! - Rows computed or loaded on demand
+//!
+//! ## See also
+//!
+//! - [`Table`](super::Table) — Small tables, all rows at once
+//! - `LogViewer` (with the `log-viewer` feature) — Scrolling through long text
+//!
+//! # Example
+//!
+//! ```ignore
+//! let records = Rc::new(load_records()); // 50_000 rows
+//! let mut state = VirtualTableState::new(records.len(), 20);
+//!
+//! // In the input handler:
+//! state.handle_key(&key);
+//!
+//! // In render:
+//! let rows = records.clone();
+//! Element::node::<VirtualTable>(
+//!     VirtualTableProps::new(rows.len(), move |i| Row::new([rows[i].name.clone(), rows[i].size.to_string()]))
+//!         .table(TableProps::default().header(["Name", "Size"]).selected_style(None, Some(Color::Blue)))
+//!         .state(&state),
+//!     vec![],
+//! )
+//! ```
+
+use crate::components::table::{ColumnWidth, Row, Table, TableProps};
+use crate::element::{Component, Element};
+use crate::input::Key;
+use crossterm::event::KeyCode;
+use std::fmt;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// A closure returning the row at an index.
+pub type RowFn = Rc<dyn Fn(usize) -> Row>;
+
+/// Properties for the VirtualTable component.
+#[derive(Clone)]
+pub struct VirtualTableProps {
+    /// Header, widths and styling; its `rows` are ignored.
+    pub table: TableProps,
+    /// Number of rows.
+    pub row_count: usize,
+    /// Returns the row at an index below `row_count`.
+    pub rows: Option<RowFn>,
+    /// Index of the first row in view.
+    pub offset: usize,
+    /// Rows in view.
+    pub height: usize,
+    /// Index of the selected row, highlighted when in view.
+    pub selected: Option<usize>,
+    /// Rows measured for auto and fit column widths.
+    pub sample_rows: usize,
+}
+
+impl Default for VirtualTableProps {
+    fn default() -> Self {
+        Self {
+            table: TableProps::default(),
+            row_count: 0,
+            rows: None,
+            offset: 0,
+            height: 20,
+            selected: None,
+            sample_rows: 100,
+        }
+    }
+}
+
+impl fmt::Debug for VirtualTableProps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VirtualTableProps")
+            .field("table", &self.table)
+            .field("row_count", &self.row_count)
+            .field("rows", &self.rows.as_ref().map(|_| "<fn>"))
+            .field("offset", &self.offset)
+            .field("height", &self.height)
+            .field("selected", &self.selected)
+            .field("sample_rows", &self.sample_rows)
+            .finish()
+    }
+}
+
+impl VirtualTableProps {
+    /// Create props for `row_count` rows, built by `rows`.
+    pub fn new<F>(row_count: usize, rows: F) -> Self
+    where
+        F: Fn(usize) -> Row + 'static,
+    {
+        Self {
+            row_count,
+            rows: Some(Rc::new(rows)),
+            ..Default::default()
+        }
+    }
+
+    /// Set the header, widths and styling.
+    #[must_use]
+    pub fn table(mut self, table: TableProps) -> Self {
+        self.table = table;
+        self
+    }
+
+    /// Set the first row in view.
+    #[must_use]
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the rows in view.
+    #[must_use]
+    pub fn height(mut self, height: usize) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Set the selected row.
+    #[must_use]
+    pub fn selected(mut self, index: usize) -> Self {
+        self.selected = Some(index);
+        self
+    }
+
+    /// Set the rows measured for column widths.
+    #[must_use]
+    pub fn sample_rows(mut self, count: usize) -> Self {
+        self.sample_rows = count;
+        self
+    }
+
+    /// Take the offset, height and selection from `state`.
+    #[must_use]
+    pub fn state(mut self, state: &VirtualTableState) -> Self {
+        self.offset = state.offset;
+        self.height = state.height;
+        self.selected = (state.row_count > 0).then_some(state.selected);
+        self
+    }
+
+    /// Indices of the rows in view.
+    pub fn window(&self) -> Range<usize> {
+        let start = self.offset.min(self.row_count);
+        start..(start + self.height).min(self.row_count)
+    }
+
+    fn build_rows(&self, range: Range<usize>) -> Vec<Row> {
+        match &self.rows {
+            Some(rows) => range.map(|i| rows(i)).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Column widths, measured over the header and the sample rows, so
+    /// they don't change as the window moves.
+    pub fn column_widths(&self) -> Vec<usize> {
+        let measured = self.table.widths.is_empty()
+            || self
+                .table
+                .widths
+                .iter()
+                .any(|w| matches!(w, ColumnWidth::Auto | ColumnWidth::Fit { .. }));
+        let sample = if measured {
+            self.build_rows(0..self.sample_rows.min(self.row_count))
+        } else {
+            Vec::new()
+        };
+        TableProps {
+            rows: sample,
+            ..self.table.clone()
+        }
+        .column_widths()
+    }
+
+    /// The [`Table`] props for the rows in view.
+    ///
+    /// Widths are pinned as exact fit columns, so cells wider than the
+    /// measured width end in an ellipsis.
+    pub fn window_props(&self) -> TableProps {
+        let window = self.window();
+        let widths = self.column_widths().into_iter().map(|w| {
+            let w = w as u16;
+            ColumnWidth::Fit {
+                min: w,
+                max: w,
+                weight: 0,
+            }
+        });
+        let selected = self
+            .selected
+            .filter(|i| window.contains(i))
+            .map(|i| i - window.start);
+        TableProps {
+            rows: self.build_rows(window),
+            selected,
+            width: None,
+            ..self.table.clone()
+        }
+        .widths(widths)
+    }
+
+    /// Where the window is, e.g. "21-40 of 50000".
+    pub fn position(&self) -> String {
+        let window = self.window();
+        if window.is_empty() {
+            return format!("0 of {}", self.row_count);
+        }
+        format!("{}-{} of {}", window.start + 1, window.end, self.row_count)
+    }
+}
+
+/// A component that displays a table, building only the rows in view.
+pub struct VirtualTable;
+
+impl Component for VirtualTable {
+    type Props = VirtualTableProps;
+
+    fn render(props: &Self::Props) -> Element {
+        Table::render(&props.window_props())
+    }
+}
+
+/// Selection and scroll offset of a [`VirtualTable`].
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
+pub struct VirtualTableState {
+    /// Index of the selected row.
+    pub selected: usize,
+    /// Index of the first row in view.
+    pub offset: usize,
+    /// Number of rows.
+    pub row_count: usize,
+    /// Rows in view.
+    pub height: usize,
+}
+
+impl VirtualTableState {
+    /// Create state for `row_count` rows, `height` in view at once.
+    pub fn new(row_count: usize, height: usize) -> Self {
+        Self {
+            row_count,
+            height: height.max(1),
+            ..Default::default()
+        }
+    }
+
+    /// Change the number of rows, e.g. as data loads, keeping the
+    /// selection in range.
+    pub fn set_row_count(&mut self, row_count: usize) {
+        self.row_count = row_count;
+        self.select(self.selected);
+    }
+
+    /// Change the rows in view, e.g. after the terminal was resized.
+    pub fn set_height(&mut self, height: usize) {
+        self.height = height.max(1);
+        self.select(self.selected);
+    }
+
+    /// Select a row, scrolling just enough to show it.
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.row_count.saturating_sub(1));
+        if self.selected < self.offset {
+            self.offset = self.selected;
+        } else if self.selected >= self.offset + self.height {
+            self.offset = self.selected + 1 - self.height;
+        }
+        self.offset = self.offset.min(self.row_count.saturating_sub(self.height));
+    }
+
+    /// Move the selection up.
+    pub fn up(&mut self) {
+        self.select(self.selected.saturating_sub(1));
+    }
+
+    /// Move the selection down.
+    pub fn down(&mut self) {
+        self.select(self.selected + 1);
+    }
+
+    /// Move the selection up a page.
+    pub fn page_up(&mut self) {
+        self.select(self.selected.saturating_sub(self.height));
+    }
+
+    /// Move the selection down a page.
+    pub fn page_down(&mut self) {
+        self.select(self.selected + self.height);
+    }
+
+    /// Select the first row.
+    pub fn first(&mut self) {
+        self.select(0);
+    }
+
+    /// Select the last row.
+    pub fn last(&mut self) {
+        self.select(self.row_count.saturating_sub(1));
+    }
+
+    /// Handle Up/Down, PageUp/PageDown and Home/End.
+    ///
+    /// Returns true if the key was handled.
+    pub fn handle_key(&mut self, key: &Key) -> bool {
+        match key.code {
+            KeyCode::Up => self.up(),
+            KeyCode::Down => self.down(),
+            KeyCode::PageUp => self.page_up(),
+            KeyCode::PageDown => self.page_down(),
+            KeyCode::Home => self.first(),
+            KeyCode::End => self.last(),
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn props(calls: &Rc<Cell<usize>>) -> VirtualTableProps {
+        let calls = calls.clone();
+        VirtualTableProps::new(50_000, move |i| {
+            calls.set(calls.get() + 1);
+            Row::new([format!("row {i}"), "x".repeat(i % 7)])
+        })
+        .table(
+            TableProps::default()
+                .header(["Name", "Data"])
+                .column_spacing(1),
+        )
+        .height(3)
+        .sample_rows(10)
+    }
+
+    fn lines(props: &VirtualTableProps) -> Vec<String> {
+        match VirtualTable::render(props) {
+            Element::Text { content, .. } => content.lines().map(String::from).collect(),
+            _ => panic!("expected text"),
+        }
+    }
+
+    #[test]
+    fn test_virtual_table_builds_only_window() {
+        let calls = Rc::new(Cell::new(0));
+        let props = props(&calls).offset(40_000);
+        assert_eq!(
+            lines(&props),
+            [
+                "Name  Data  ",
+                "row … xx    ",
+                "row … xxx   ",
+                "row … xxxx  "
+            ]
+        );
+        // 10 sampled for widths, 3 in view
+        assert_eq!(calls.get(), 13);
+        assert_eq!(props.position(), "40001-40003 of 50000");
+    }
+
+    #[test]
+    fn test_virtual_table_widths_stable_across_offsets() {
+        let calls = Rc::new(Cell::new(0));
+        let props = props(&calls);
+        assert_eq!(
+            props.column_widths(),
+            props.clone().offset(49_990).column_widths()
+        );
+
+        let fixed = props
+            .table(TableProps::default().fixed_widths([4, 2]))
+            .offset(5);
+        calls.set(0);
+        fixed.window_props();
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn test_virtual_table_state_keeps_selection_in_view() {
+        let mut state = VirtualTableState::new(100, 10);
+        state.page_down();
+        state.down();
+        assert_eq!((state.selected, state.offset), (11, 2));
+        state.last();
+        assert_eq!((state.selected, state.offset), (99, 90));
+        state.handle_key(&Key::new(KeyCode::Home));
+        assert_eq!((state.selected, state.offset), (0, 0));
+
+        state.set_row_count(5);
+        state.last();
+        assert_eq!((state.selected, state.offset), (4, 0));
+
+        let props = VirtualTableProps::new(5, |i| Row::new([i.to_string()])).state(&state);
+        assert_eq!(props.window_props().selected, Some(4));
+    }
+}
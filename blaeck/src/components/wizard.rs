@@ -0,0 +1,414 @@
+//! Wizard component - the step header of a multi-step flow.
+//!
+//! The Wizard shows where the user is in a flow of steps: a row of
+//! markers joined by lines, filled for finished steps and empty for the
+//! ones ahead, with each step's name. Under it is the current step's title
+//! and, when moving on was refused, why. [`WizardState`] keeps the current
+//! step and moves back and forward, asking a gate before leaving a step.
+//!
+//! Draw the current step's content below the Wizard yourself; a
+//! [`Form`](super::Form) per step works well.
+//!
+//! ## When to use Wizard
+//!
+//! - Installers and onboarding flows
+//! - Any task split into ordered steps that each need checking
+//!
+//! ## See also
+//!
+//! - [`Tabs`](super::Tabs) — Sections visited in any order
+//! - [`Breadcrumbs`](super::Breadcrumbs) — Where you are in a hierarchy
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut wizard = WizardState::new(3);
+//!
+//! // In the input handler:
+//! if key.is_enter() {
+//!     match wizard.next_if(|step| match step {
+//!         0 => account_form.submit().map(|_| ()).ok_or("Fix the errors above".into()),
+//!         _ => Ok(()),
+//!     }) {
+//!         WizardAction::Finished => install(),
+//!         _ => {}
+//!     }
+//! } else if key.is_escape() {
+//!     wizard.back();
+//! }
+//!
+//! // In render:
+//! Element::column(vec![
+//!     Element::node::<Wizard>(wizard.to_props(["Account", "Plan", "Confirm"]), vec![]),
+//!     step_content(wizard.current()),
+//! ])
+//! // Renders:
+//! // ● Account ─── ◉ Plan ─── ○ Confirm
+//! // Step 2 of 3: Plan
+//! ```
+
+use crate::element::{Component, Element};
+use crate::glyphs::GlyphSet;
+use crate::palette::Palette;
+use crate::style::{Color, Modifier, Style};
+
+/// Properties for the Wizard component.
+#[derive(Debug, Clone)]
+pub struct WizardProps {
+    /// Step names, in order.
+    pub steps: Vec<String>,
+    /// Index of the current step.
+    pub current: usize,
+    /// Whether to show step names next to the markers.
+    pub show_labels: bool,
+    /// Whether to show the "Step N of M" line.
+    pub show_title: bool,
+    /// Why the last move forward was refused, shown under the header.
+    pub error: Option<String>,
+    /// Cells of line between steps.
+    pub connector_width: usize,
+    /// Color of finished steps (`None` = the palette's success color).
+    pub done_color: Option<Color>,
+    /// Color of the current step.
+    pub current_color: Option<Color>,
+    /// Color of steps ahead.
+    pub pending_color: Option<Color>,
+    /// Glyph set override (`None` = detect from the terminal).
+    pub glyphs: Option<GlyphSet>,
+}
+
+impl Default for WizardProps {
+    fn default() -> Self {
+        Self {
+            steps: Vec::new(),
+            current: 0,
+            show_labels: true,
+            show_title: true,
+            error: None,
+            connector_width: 3,
+            done_color: None,
+            current_color: Some(Color::Cyan),
+            pending_color: Some(Color::DarkGray),
+            glyphs: None,
+        }
+    }
+}
+
+impl WizardProps {
+    /// Create props with step names.
+    pub fn new<I, S>(steps: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            steps: steps.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the current step.
+    #[must_use]
+    pub fn current(mut self, index: usize) -> Self {
+        self.current = index;
+        self
+    }
+
+    /// Show or hide the step names.
+    #[must_use]
+    pub fn show_labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// Show or hide the "Step N of M" line.
+    #[must_use]
+    pub fn show_title(mut self, show: bool) -> Self {
+        self.show_title = show;
+        self
+    }
+
+    /// Set the error shown under the header.
+    #[must_use]
+    pub fn error(mut self, error: impl Into<String>) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
+    /// Set the cells of line between steps.
+    #[must_use]
+    pub fn connector_width(mut self, width: usize) -> Self {
+        self.connector_width = width;
+        self
+    }
+
+    /// Set the color of finished steps.
+    #[must_use]
+    pub fn done_color(mut self, color: Color) -> Self {
+        self.done_color = Some(color);
+        self
+    }
+
+    /// Set the color of the current step.
+    #[must_use]
+    pub fn current_color(mut self, color: Color) -> Self {
+        self.current_color = Some(color);
+        self
+    }
+
+    /// Set the color of steps ahead.
+    #[must_use]
+    pub fn pending_color(mut self, color: Color) -> Self {
+        self.pending_color = Some(color);
+        self
+    }
+
+    /// Override the detected glyph set.
+    #[must_use]
+    pub fn glyphs(mut self, glyphs: GlyphSet) -> Self {
+        self.glyphs = Some(glyphs);
+        self
+    }
+
+    /// The header row as spans: a marker (and name) per step, joined by
+    /// lines. The line after a finished step takes its color.
+    pub fn header_spans(&self) -> Vec<(String, Style)> {
+        let ascii = GlyphSet::resolve(self.glyphs).is_ascii();
+        let (done, current, pending, line) = if ascii {
+            ("*", "@", "o", "-")
+        } else {
+            ("●", "◉", "○", "─")
+        };
+        let color_style =
+            |color: Option<Color>| color.map(|c| Style::new().fg(c)).unwrap_or_default();
+        let done_style =
+            Style::new().fg(self.done_color.unwrap_or_else(|| Palette::detect().success));
+        let current_style = color_style(self.current_color).add_modifier(Modifier::BOLD);
+        let pending_style = color_style(self.pending_color);
+
+        let mut spans = Vec::new();
+        for (i, step) in self.steps.iter().enumerate() {
+            if i > 0 {
+                let style = if i <= self.current {
+                    done_style
+                } else {
+                    pending_style
+                };
+                spans.push((format!(" {} ", line.repeat(self.connector_width)), style));
+            }
+            let (marker, style) = match i.cmp(&self.current) {
+                std::cmp::Ordering::Less => (done, done_style),
+                std::cmp::Ordering::Equal => (current, current_style),
+                std::cmp::Ordering::Greater => (pending, pending_style),
+            };
+            if self.show_labels {
+                spans.push((format!("{marker} {step}"), style));
+            } else {
+                spans.push((marker.to_string(), style));
+            }
+        }
+        spans
+    }
+
+    /// The lines of the wizard header, each a list of spans.
+    pub fn render_lines(&self) -> Vec<Vec<(String, Style)>> {
+        let mut lines = vec![self.header_spans()];
+        if self.show_title {
+            if let Some(step) = self.steps.get(self.current) {
+                lines.push(vec![(
+                    format!("Step {} of {}: {step}", self.current + 1, self.steps.len()),
+                    Style::new().add_modifier(Modifier::BOLD),
+                )]);
+            }
+        }
+        if let Some(error) = &self.error {
+            lines.push(vec![(
+                error.clone(),
+                Style::new().fg(Palette::detect().error),
+            )]);
+        }
+        lines
+    }
+}
+
+/// A component that displays the step header of a multi-step flow.
+pub struct Wizard;
+
+impl Component for Wizard {
+    type Props = WizardProps;
+
+    fn render(props: &Self::Props) -> Element {
+        let lines = props
+            .render_lines()
+            .into_iter()
+            .map(|spans| {
+                Element::Fragment(
+                    spans
+                        .into_iter()
+                        .map(|(text, style)| Element::styled_text(text, style))
+                        .collect(),
+                )
+            })
+            .collect();
+        Element::Fragment(lines)
+    }
+}
+
+/// What a move did to a wizard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WizardAction {
+    /// The current step changed.
+    Moved,
+    /// The gate refused to leave the step; the message is in `error`.
+    Blocked,
+    /// The last step was passed.
+    Finished,
+    /// Already at the first step, so there is nowhere to go back to.
+    Ignored,
+}
+
+/// Helper struct for moving through a wizard's steps.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "session", derive(serde::Serialize, serde::Deserialize))]
+pub struct WizardState {
+    /// Index of the current step.
+    pub current: usize,
+    /// Number of steps.
+    pub count: usize,
+    /// Why the last move forward was refused.
+    pub error: Option<String>,
+    /// Whether the last step has been passed.
+    pub finished: bool,
+}
+
+impl WizardState {
+    /// Create a state at the first of `count` steps.
+    pub fn new(count: usize) -> Self {
+        Self {
+            count,
+            ..Default::default()
+        }
+    }
+
+    /// Index of the current step.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Whether the current step is the first.
+    pub fn is_first(&self) -> bool {
+        self.current == 0
+    }
+
+    /// Whether the current step is the last.
+    pub fn is_last(&self) -> bool {
+        self.current + 1 >= self.count
+    }
+
+    /// Move on if `gate` accepts the current step, passing it the step's
+    /// index; an `Err` keeps the step and shows its message.
+    pub fn next_if<F>(&mut self, gate: F) -> WizardAction
+    where
+        F: FnOnce(usize) -> Result<(), String>,
+    {
+        if let Err(error) = gate(self.current) {
+            self.error = Some(error);
+            return WizardAction::Blocked;
+        }
+        self.error = None;
+        if self.is_last() {
+            self.finished = true;
+            return WizardAction::Finished;
+        }
+        self.current += 1;
+        WizardAction::Moved
+    }
+
+    /// Move on without a gate.
+    pub fn advance(&mut self) -> WizardAction {
+        self.next_if(|_| Ok(()))
+    }
+
+    /// Go back a step. Going back never needs the gate.
+    pub fn back(&mut self) -> WizardAction {
+        self.error = None;
+        self.finished = false;
+        if self.is_first() {
+            return WizardAction::Ignored;
+        }
+        self.current -= 1;
+        WizardAction::Moved
+    }
+
+    /// Props for rendering the header with these step names.
+    pub fn to_props<I, S>(&self, steps: I) -> WizardProps
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        WizardProps {
+            error: self.error.clone(),
+            ..WizardProps::new(steps).current(self.current)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::test_util::span_text;
+
+    #[test]
+    fn test_wizard_header() {
+        let props = WizardProps::new(["Account", "Plan", "Confirm"])
+            .current(1)
+            .glyphs(GlyphSet::Unicode);
+        assert_eq!(
+            span_text(&props.header_spans()),
+            "● Account ─── ◉ Plan ─── ○ Confirm"
+        );
+        assert_eq!(
+            span_text(
+                &props
+                    .clone()
+                    .show_labels(false)
+                    .connector_width(1)
+                    .header_spans()
+            ),
+            "● ─ ◉ ─ ○"
+        );
+        let lines = props
+            .glyphs(GlyphSet::Ascii)
+            .error("Pick a plan")
+            .render_lines();
+        assert_eq!(span_text(&lines[0]), "* Account --- @ Plan --- o Confirm");
+        assert_eq!(span_text(&lines[1]), "Step 2 of 3: Plan");
+        assert_eq!(span_text(&lines[2]), "Pick a plan");
+    }
+
+    #[test]
+    fn test_wizard_state_gates() {
+        let mut state = WizardState::new(2);
+        assert_eq!(state.back(), WizardAction::Ignored);
+        assert_eq!(
+            state.next_if(|_| Err("Name is required".into())),
+            WizardAction::Blocked
+        );
+        assert_eq!(state.current(), 0);
+        assert_eq!(
+            state.to_props(["a", "b"]).error.as_deref(),
+            Some("Name is required")
+        );
+
+        assert_eq!(
+            state.next_if(|step| if step == 0 { Ok(()) } else { Err("no".into()) }),
+            WizardAction::Moved
+        );
+        assert!(state.error.is_none());
+        assert!(state.is_last());
+        assert_eq!(state.advance(), WizardAction::Finished);
+        assert!(state.finished);
+        assert_eq!(state.back(), WizardAction::Moved);
+        assert!(!state.finished);
+    }
+}
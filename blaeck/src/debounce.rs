@@ -0,0 +1,134 @@
+//! Debouncing for terminal resize events.
+//!
+//! Dragging a terminal window's edge sends a resize event for every step.
+//! Clearing and relaying out the whole UI on each one floods the terminal
+//! with repaints, and the intermediate frames are thrown away anyway.
+//! [`ResizeDebouncer`] collects the events and reports the size once it has
+//! stopped changing for a short delay; until then the app loops draw
+//! [`resize_placeholder`], a single line that's cheap to redraw.
+//!
+//! ```ignore
+//! let mut debounce = ResizeDebouncer::new(Duration::from_millis(100));
+//!
+//! // On Event::Resize(w, h):
+//! if debounce.resize(w, h, Instant::now()) {
+//!     blaeck.handle_resize(w, h)?;
+//!     blaeck.render(resize_placeholder(w, h))?;
+//! }
+//!
+//! // Every loop iteration:
+//! if let Some((w, h)) = debounce.settled(Instant::now()) {
+//!     blaeck.handle_resize(w, h)?;
+//!     blaeck.render(ui())?;
+//! }
+//! ```
+//!
+//! [`App`](crate::App) and [`ReactiveApp`](crate::reactive::ReactiveApp) do
+//! this for you; set their `resize_debounce` to change the delay, or to zero
+//! to relayout on every event.
+
+use crate::element::Element;
+use crate::style::{Modifier, Style};
+use std::time::{Duration, Instant};
+
+/// Collects resize events until the size settles.
+#[derive(Debug, Clone)]
+pub struct ResizeDebouncer {
+    delay: Duration,
+    /// Latest size and when it was reported, while unsettled.
+    pending: Option<(u16, u16, Instant)>,
+}
+
+impl ResizeDebouncer {
+    /// Report the size once it has been stable for `delay`.
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            pending: None,
+        }
+    }
+
+    /// The settle delay.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Record a resize to `width` x `height`.
+    ///
+    /// Returns true if a placeholder should be drawn, i.e. the delay isn't
+    /// zero so the full relayout waits for [`settled`](Self::settled).
+    pub fn resize(&mut self, width: u16, height: u16, now: Instant) -> bool {
+        self.pending = Some((width, height, now));
+        !self.delay.is_zero()
+    }
+
+    /// The new size, once no resize has arrived for the delay. Returns it
+    /// only once per burst of events.
+    pub fn settled(&mut self, now: Instant) -> Option<(u16, u16)> {
+        match self.pending {
+            Some((width, height, at)) if now.saturating_duration_since(at) >= self.delay => {
+                self.pending = None;
+                Some((width, height))
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether a resize is waiting to settle.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// How long an event loop may wait for input: at most `max`, and no
+    /// longer than until a pending resize settles.
+    pub fn timeout(&self, now: Instant, max: Duration) -> Duration {
+        match self.pending {
+            Some((_, _, at)) => (at + self.delay).saturating_duration_since(now).min(max),
+            None => max,
+        }
+    }
+}
+
+impl Default for ResizeDebouncer {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(100))
+    }
+}
+
+/// A one-line stand-in drawn while the terminal is being resized.
+pub fn resize_placeholder(width: u16, height: u16) -> Element {
+    Element::styled_text(
+        format!("resizing to {}x{}…", width, height),
+        Style::new().add_modifier(Modifier::DIM),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resize_debouncer_settles_after_delay() {
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+        let mut debounce = ResizeDebouncer::new(ms(100));
+
+        assert!(debounce.resize(100, 30, start));
+        assert!(debounce.resize(110, 32, start + ms(40)));
+        assert_eq!(debounce.timeout(start + ms(40), ms(500)), ms(100));
+        assert_eq!(debounce.settled(start + ms(120)), None);
+
+        assert_eq!(debounce.settled(start + ms(140)), Some((110, 32)));
+        assert!(!debounce.is_pending());
+        assert_eq!(debounce.settled(start + ms(500)), None);
+        assert_eq!(debounce.timeout(start, ms(50)), ms(50));
+    }
+
+    #[test]
+    fn test_resize_debouncer_zero_delay_is_immediate() {
+        let now = Instant::now();
+        let mut debounce = ResizeDebouncer::new(Duration::ZERO);
+        assert!(!debounce.resize(80, 24, now));
+        assert_eq!(debounce.settled(now), Some((80, 24)));
+    }
+}
@@ -4,6 +4,7 @@
 //!
 //! - **`Element`** — A node in the UI tree. Can be `Text`, `Node` (component), `Fragment`, or `Empty`.
 //! - **`Component`** — A trait for defining UI pieces. Has a `Props` type and `render()` method.
+//! - **`SendElement`** — A `Send` mirror of `Element` for building trees off the UI thread.
 //!
 //! Components are type-erased via `TypeId` + `Box<dyn Any>` so the element tree can hold
 //! any component type. Props are downcast at render time.
@@ -24,6 +25,116 @@ pub trait Component: 'static {
 
     /// Render this component with the given props, returning an Element tree.
     fn render(props: &Self::Props) -> Element;
+
+    /// Start a fluent [`ElementBuilder`] for this component with default props.
+    ///
+    /// ```ignore
+    /// Text::builder().content("hi").color(Color::Green).build()
+    /// ```
+    fn builder() -> ElementBuilder<Self>
+    where
+        Self: Sized,
+    {
+        ElementBuilder::new()
+    }
+}
+
+/// Fluent construction of a component node, the non-macro counterpart of
+/// `element!`.
+///
+/// Start from [`Component::builder`], set props, add children and call
+/// [`build`](Self::build). `Text` and `Box` have shorthand setters for their
+/// common props; for everything else, [`with`](Self::with) applies the
+/// props' own builder methods and [`set`](Self::set) assigns fields.
+///
+/// ```ignore
+/// Box::builder()
+///     .column()
+///     .border(BorderStyle::Round)
+///     .child(Text::builder().content("Title").bold())
+///     .child(Spinner::builder().with(|p| p.label("Loading")))
+///     .children(items.iter().map(|i| Text::builder().content(i)))
+///     .build()
+/// ```
+pub struct ElementBuilder<C: Component> {
+    props: C::Props,
+    children: Vec<Element>,
+}
+
+impl<C: Component> Default for ElementBuilder<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Component> ElementBuilder<C> {
+    /// Create a builder with default props and no children.
+    pub fn new() -> Self {
+        Self {
+            props: C::Props::default(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Replace the props.
+    #[must_use]
+    pub fn props(mut self, props: C::Props) -> Self {
+        self.props = props;
+        self
+    }
+
+    /// Transform the props, typically with their own builder methods.
+    #[must_use]
+    pub fn with(mut self, f: impl FnOnce(C::Props) -> C::Props) -> Self {
+        self.props = f(self.props);
+        self
+    }
+
+    /// Modify the props in place, e.g. to set a field.
+    #[must_use]
+    pub fn set(mut self, f: impl FnOnce(&mut C::Props)) -> Self {
+        f(&mut self.props);
+        self
+    }
+
+    /// Append a child.
+    #[must_use]
+    pub fn child(mut self, child: impl Into<Element>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Append several children.
+    #[must_use]
+    pub fn children<I>(mut self, children: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Element>,
+    {
+        self.children.extend(children.into_iter().map(Into::into));
+        self
+    }
+
+    /// Append a child only when `condition` holds.
+    #[must_use]
+    pub fn child_if(self, condition: bool, child: impl FnOnce() -> Element) -> Self {
+        if condition {
+            self.child(child())
+        } else {
+            self
+        }
+    }
+
+    /// Build the element.
+    pub fn build(self) -> Element {
+        Element::node::<C>(self.props, self.children)
+    }
+}
+
+impl<C: Component> From<ElementBuilder<C>> for Element {
+    fn from(builder: ElementBuilder<C>) -> Self {
+        builder.build()
+    }
 }
 
 /// An element in the UI tree.
@@ -215,6 +326,28 @@ impl Element {
         )
     }
 
+    /// Give this element a weighted share of its parent's space, like CSS
+    /// `flex: N`.
+    ///
+    /// A `Box` gets [`BoxProps::flex`](crate::components::BoxProps::flex)
+    /// set; anything else is wrapped in a `Box` that has it.
+    ///
+    /// ```ignore
+    /// Element::row(vec![left_panel.flex(1.0), right_panel.flex(2.0)])
+    /// ```
+    pub fn flex(mut self, flex: f32) -> Self {
+        use crate::components::{Box, BoxProps};
+        if let Element::Node { type_id, props, .. } = &mut self {
+            if *type_id == TypeId::of::<Box>() {
+                if let Some(props) = props.downcast_mut::<BoxProps>() {
+                    props.flex = flex;
+                    return self;
+                }
+            }
+        }
+        Element::node::<Box>(BoxProps::new().with_flex(flex), vec![self])
+    }
+
     /// Get the children of this element (empty for non-nodes).
     pub fn children(&self) -> &[Element] {
         match self {
@@ -244,6 +377,170 @@ impl Element {
     }
 }
 
+/// A `Send` description of an element tree that can be materialized later.
+///
+/// [`Element`] stores props as `Box<dyn Any>`, which is `!Send`, so a tree
+/// built on a worker thread can't be handed back to the UI thread. `SendElement`
+/// mirrors the `Element` shape but requires `Send` props, so heavy tree
+/// construction (formatting large tables, parsing logs) can run on another
+/// thread or inside `tokio::task::spawn_blocking`. Call
+/// [`SendElement::into_element`] on the UI thread to turn it into a renderable
+/// `Element`.
+///
+/// # Example
+///
+/// ```ignore
+/// let handle = std::thread::spawn(|| {
+///     SendElement::node::<Box>(
+///         BoxProps::default(),
+///         rows.iter().map(|row| SendElement::node::<Text>(
+///             TextProps { content: row.clone(), ..Default::default() },
+///             vec![],
+///         )).collect(),
+///     )
+/// });
+/// blaeck.render(handle.join().unwrap().into_element())?;
+/// ```
+#[derive(Default)]
+pub enum SendElement {
+    /// An empty element (renders nothing)
+    #[default]
+    Empty,
+    /// A text element with optional styling
+    Text {
+        /// The text content
+        content: String,
+        /// The style to apply
+        style: Style,
+    },
+    /// A component node with `Send` props and children
+    Node {
+        /// The TypeId of the component
+        type_id: TypeId,
+        /// The props as a boxed `Any + Send`
+        props: Box<dyn Any + Send>,
+        /// The layout style for this node
+        layout_style: Box<LayoutStyle>,
+        /// Child elements
+        children: Vec<SendElement>,
+        /// Converts the props back into a typed `Element::Node`
+        materialize_fn: fn(Box<dyn Any + Send>, LayoutStyle, Vec<Element>) -> Element,
+    },
+    /// A fragment containing multiple elements (no wrapping container)
+    Fragment(Vec<SendElement>),
+}
+
+impl SendElement {
+    /// Create an empty element.
+    pub fn empty() -> Self {
+        SendElement::Empty
+    }
+
+    /// Create a text element with default style.
+    pub fn text(s: impl Into<String>) -> Self {
+        SendElement::Text {
+            content: s.into(),
+            style: Style::default(),
+        }
+    }
+
+    /// Create a text element with a specific style.
+    pub fn styled_text(s: impl Into<String>, style: Style) -> Self {
+        SendElement::Text {
+            content: s.into(),
+            style,
+        }
+    }
+
+    /// Create a component node. Requires the component's props to be `Send`.
+    pub fn node<C>(props: C::Props, children: Vec<SendElement>) -> Self
+    where
+        C: Component,
+        C::Props: Send,
+    {
+        Self::node_with_layout::<C>(props, LayoutStyle::default(), children)
+    }
+
+    /// Create a component node with layout style.
+    pub fn node_with_layout<C>(
+        props: C::Props,
+        layout_style: LayoutStyle,
+        children: Vec<SendElement>,
+    ) -> Self
+    where
+        C: Component,
+        C::Props: Send,
+    {
+        SendElement::Node {
+            type_id: TypeId::of::<C>(),
+            props: Box::new(props),
+            layout_style: Box::new(layout_style),
+            children,
+            materialize_fn: |props_any, layout_style, children| {
+                let props = props_any.downcast::<C::Props>().unwrap();
+                Element::node_with_layout::<C>(*props, layout_style, children)
+            },
+        }
+    }
+
+    /// Create a fragment from multiple elements.
+    pub fn fragment(elements: Vec<SendElement>) -> Self {
+        SendElement::Fragment(elements)
+    }
+
+    /// Get the type ID if this is a node element.
+    pub fn type_id(&self) -> Option<TypeId> {
+        match self {
+            SendElement::Node { type_id, .. } => Some(*type_id),
+            _ => None,
+        }
+    }
+
+    /// Get the children of this element (empty for non-nodes).
+    pub fn children(&self) -> &[SendElement] {
+        match self {
+            SendElement::Node { children, .. } => children,
+            _ => &[],
+        }
+    }
+
+    /// Convert into a renderable [`Element`].
+    ///
+    /// This is cheap (no components are rendered), but the result is `!Send`,
+    /// so call it on the thread that owns the renderer.
+    pub fn into_element(self) -> Element {
+        match self {
+            SendElement::Empty => Element::Empty,
+            SendElement::Text { content, style } => Element::Text { content, style },
+            SendElement::Node {
+                props,
+                layout_style,
+                children,
+                materialize_fn,
+                ..
+            } => {
+                let children = children
+                    .into_iter()
+                    .map(SendElement::into_element)
+                    .collect();
+                materialize_fn(props, *layout_style, children)
+            }
+            SendElement::Fragment(children) => Element::Fragment(
+                children
+                    .into_iter()
+                    .map(SendElement::into_element)
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<SendElement> for Element {
+    fn from(element: SendElement) -> Self {
+        element.into_element()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -281,6 +578,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_element_builder() {
+        let elem = ContainerComponent::builder()
+            .set(|p| p.label = "box".into())
+            .child(TestComponent::builder().with(|p| TestProps { value: p.value + 3 }))
+            .children((0..2).map(|_| Element::text("x")))
+            .child_if(false, Element::empty)
+            .build();
+        assert_eq!(elem.type_id(), Some(TypeId::of::<ContainerComponent>()));
+        assert_eq!(elem.children().len(), 3);
+        match elem.children()[0].render_component() {
+            Some(Element::Text { content, .. }) => assert_eq!(content, "Value: 3"),
+            _ => panic!("expected text"),
+        }
+        match elem.render_component() {
+            Some(Element::Text { content, .. }) => assert_eq!(content, "Container: box"),
+            _ => panic!("expected text"),
+        }
+    }
+
     #[test]
     fn test_element_empty() {
         let elem = Element::empty();
@@ -422,4 +739,70 @@ mod tests {
         let empty = Element::empty();
         assert!(empty.render_component().is_none());
     }
+
+    #[test]
+    fn test_send_element_is_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<SendElement>();
+    }
+
+    #[test]
+    fn test_send_element_built_on_thread() {
+        let built = std::thread::spawn(|| {
+            SendElement::node::<ContainerComponent>(
+                ContainerProps {
+                    label: "Worker".to_string(),
+                },
+                vec![
+                    SendElement::node::<TestComponent>(TestProps { value: 7 }, vec![]),
+                    SendElement::text("Plain"),
+                ],
+            )
+        })
+        .join()
+        .unwrap();
+
+        let elem = built.into_element();
+        assert_eq!(elem.type_id(), Some(TypeId::of::<ContainerComponent>()));
+        assert_eq!(elem.children().len(), 2);
+        assert!(elem.children()[1].is_text());
+
+        match elem.render_component().unwrap() {
+            Element::Text { content, .. } => assert_eq!(content, "Container: Worker"),
+            _ => panic!("Expected Text from render"),
+        }
+        match elem.children()[0].render_component().unwrap() {
+            Element::Text { content, .. } => assert_eq!(content, "Value: 7"),
+            _ => panic!("Expected Text from render"),
+        }
+    }
+
+    #[test]
+    fn test_send_element_preserves_layout() {
+        use crate::layout::FlexDirection;
+
+        let layout = LayoutStyle {
+            width: Some(20.0),
+            flex_direction: FlexDirection::Row,
+            ..Default::default()
+        };
+        let elem: Element =
+            SendElement::node_with_layout::<TestComponent>(TestProps::default(), layout, vec![])
+                .into();
+        assert_eq!(elem.layout_style().width, Some(20.0));
+        assert_eq!(elem.layout_style().flex_direction, FlexDirection::Row);
+    }
+
+    #[test]
+    fn test_send_element_fragment_and_empty() {
+        let elem = SendElement::fragment(vec![SendElement::empty(), SendElement::text("x")])
+            .into_element();
+        match elem {
+            Element::Fragment(children) => {
+                assert!(children[0].is_empty());
+                assert!(children[1].is_text());
+            }
+            _ => panic!("Expected Fragment"),
+        }
+    }
 }
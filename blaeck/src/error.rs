@@ -0,0 +1,150 @@
+//! Error type for rendering and running apps.
+//!
+//! Renderer and app APIs return [`BlaeckError`], which says what kind of
+//! thing failed so callers can react: retry a write, fall back when the
+//! terminal can't enter raw mode, or report a layout bug.
+//!
+//! ```ignore
+//! match blaeck.render(ui) {
+//!     Ok(()) => {}
+//!     Err(BlaeckError::Io(e)) if e.kind() == io::ErrorKind::BrokenPipe => return Ok(()),
+//!     Err(e) => return Err(e),
+//! }
+//! ```
+//!
+//! It converts to and from `std::io::Error`, so `?` keeps working in
+//! functions that return `io::Result`.
+
+use std::fmt;
+use std::io;
+
+/// Result type for renderer and app operations.
+pub type Result<T> = std::result::Result<T, BlaeckError>;
+
+/// An error from rendering or running an app.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum BlaeckError {
+    /// Computing the layout failed.
+    Layout(String),
+    /// A terminal operation (raw mode, cursor position, mouse, focus or
+    /// paste reporting) failed.
+    Terminal {
+        /// What was being done, e.g. "enabling raw mode".
+        context: &'static str,
+        /// The underlying error.
+        source: io::Error,
+    },
+    /// Writing output or reading input failed.
+    Io(io::Error),
+    /// The terminal doesn't support a feature that was required.
+    Unsupported(String),
+}
+
+impl BlaeckError {
+    /// A layout error with a description.
+    pub fn layout(message: impl Into<String>) -> Self {
+        BlaeckError::Layout(message.into())
+    }
+
+    /// A terminal error while doing `context`.
+    pub fn terminal(context: &'static str, source: io::Error) -> Self {
+        BlaeckError::Terminal { context, source }
+    }
+
+    /// An error for a missing terminal feature.
+    pub fn unsupported(feature: impl Into<String>) -> Self {
+        BlaeckError::Unsupported(feature.into())
+    }
+
+    /// The underlying I/O error, for `Io` and `Terminal` errors.
+    pub fn io_error(&self) -> Option<&io::Error> {
+        match self {
+            BlaeckError::Io(e) | BlaeckError::Terminal { source: e, .. } => Some(e),
+            BlaeckError::Layout(_) | BlaeckError::Unsupported(_) => None,
+        }
+    }
+
+    /// The closest `io::ErrorKind`, for code that only looks at kinds.
+    pub fn kind(&self) -> io::ErrorKind {
+        match self {
+            BlaeckError::Io(e) | BlaeckError::Terminal { source: e, .. } => e.kind(),
+            BlaeckError::Layout(_) => io::ErrorKind::Other,
+            BlaeckError::Unsupported(_) => io::ErrorKind::Unsupported,
+        }
+    }
+}
+
+impl fmt::Display for BlaeckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlaeckError::Layout(message) => write!(f, "layout error: {message}"),
+            BlaeckError::Terminal { context, source } => write!(f, "{context}: {source}"),
+            BlaeckError::Io(e) => e.fmt(f),
+            BlaeckError::Unsupported(feature) => {
+                write!(f, "not supported by this terminal: {feature}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BlaeckError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BlaeckError::Io(e) | BlaeckError::Terminal { source: e, .. } => Some(e),
+            BlaeckError::Layout(_) | BlaeckError::Unsupported(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for BlaeckError {
+    fn from(e: io::Error) -> Self {
+        BlaeckError::Io(e)
+    }
+}
+
+impl From<taffy::TaffyError> for BlaeckError {
+    fn from(e: taffy::TaffyError) -> Self {
+        BlaeckError::Layout(e.to_string())
+    }
+}
+
+impl From<BlaeckError> for io::Error {
+    fn from(e: BlaeckError) -> Self {
+        match e {
+            BlaeckError::Io(e) => e,
+            e => io::Error::new(e.kind(), e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_display_and_kind() {
+        let e = BlaeckError::terminal(
+            "enabling raw mode",
+            io::Error::new(io::ErrorKind::NotConnected, "not a tty"),
+        );
+        assert_eq!(e.to_string(), "enabling raw mode: not a tty");
+        assert_eq!(e.kind(), io::ErrorKind::NotConnected);
+        assert!(std::error::Error::source(&e).is_some());
+
+        let e = BlaeckError::unsupported("OSC 52");
+        assert_eq!(e.kind(), io::ErrorKind::Unsupported);
+        assert!(e.io_error().is_none());
+    }
+
+    #[test]
+    fn test_error_io_round_trip() {
+        let e: BlaeckError = io::Error::new(io::ErrorKind::BrokenPipe, "closed").into();
+        assert!(matches!(e, BlaeckError::Io(_)));
+        let back: io::Error = e.into();
+        assert_eq!(back.kind(), io::ErrorKind::BrokenPipe);
+
+        let back: io::Error = BlaeckError::layout("bad node").into();
+        assert_eq!(back.to_string(), "layout error: bad node");
+    }
+}
@@ -0,0 +1,113 @@
+//! Frame filters: post-process the output grid before it is written.
+//!
+//! A [`FrameFilter`] sees every frame after layout and painting, just before
+//! the grid is turned into ANSI text. Use it for whole-screen effects — dim
+//! everything behind a modal, draw a watermark, add scanlines — or to make
+//! sure secrets never reach the terminal.
+//!
+//! ```ignore
+//! // Any FnMut(&mut Output) is a filter
+//! blaeck.add_filter(|out: &mut Output| out.map_styles(|_, _, s| s.dim()));
+//!
+//! // Mask tokens wherever they appear on screen
+//! blaeck.add_filter(Redact::new([api_key.as_str()]));
+//! ```
+//!
+//! Filters run in the order they were added, on both live frames and
+//! [`Static`](crate::components::Static) output.
+
+use crate::output::Output;
+use unicode_width::UnicodeWidthStr;
+
+/// Post-processes a frame before it is serialized.
+pub trait FrameFilter {
+    /// Modify the frame in place.
+    fn apply(&mut self, output: &mut Output);
+}
+
+impl<F: FnMut(&mut Output)> FrameFilter for F {
+    fn apply(&mut self, output: &mut Output) {
+        self(output)
+    }
+}
+
+/// Masks every on-screen occurrence of the given strings.
+///
+/// Matching is per row, so a secret split across two lines by wrapping is
+/// not caught; keep secrets out of wrapping text where possible.
+#[derive(Debug, Clone)]
+pub struct Redact {
+    /// Strings to hide.
+    secrets: Vec<String>,
+    /// Replacement character.
+    mask: char,
+}
+
+impl Redact {
+    /// Create a filter that masks each of `secrets` with `*`.
+    pub fn new<I, S>(secrets: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            secrets: secrets
+                .into_iter()
+                .map(Into::into)
+                .filter(|s: &String| !s.is_empty())
+                .collect(),
+            mask: '*',
+        }
+    }
+
+    /// Set the replacement character.
+    #[must_use]
+    pub fn mask(mut self, mask: char) -> Self {
+        self.mask = mask;
+        self
+    }
+}
+
+impl FrameFilter for Redact {
+    fn apply(&mut self, output: &mut Output) {
+        for y in 0..output.height {
+            let Some(line) = output.line_text(y) else {
+                break;
+            };
+            for secret in &self.secrets {
+                for (start, _) in line.match_indices(secret.as_str()) {
+                    let x = line[..start].width() as u16;
+                    let width = secret.width();
+                    let style = output.style_at(x, y).unwrap_or_default();
+                    let masked: String = std::iter::repeat_n(self.mask, width).collect();
+                    output.write(x, y, &masked, style);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Style;
+
+    #[test]
+    fn test_redact_masks_secret() {
+        let mut out = Output::new(30, 2);
+        out.write(0, 0, "token=hunter2 ok", Style::default());
+        out.write(2, 1, "日 hunter2", Style::default());
+        Redact::new(["hunter2"]).apply(&mut out);
+        assert_eq!(out.line_text(0).unwrap().trim_end(), "token=******* ok");
+        assert_eq!(out.line_text(1).unwrap().trim_end(), "  日 *******");
+    }
+
+    #[test]
+    fn test_closure_filter() {
+        let mut out = Output::new(5, 1);
+        out.write(0, 0, "hi", Style::default());
+        let mut filter = |o: &mut Output| o.write(4, 0, "!", Style::default());
+        filter.apply(&mut out);
+        assert_eq!(out.line_text(0).as_deref(), Some("hi  !"));
+    }
+}
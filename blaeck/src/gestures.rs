@@ -0,0 +1,426 @@
+//! Clicks, double-clicks and drags built from raw mouse events.
+//!
+//! Terminals report button presses, releases and drags one cell at a time.
+//! A [`GestureDetector`] turns those into [`Gesture`]s:
+//!
+//! - A press and release without moving is a [`Click`](Gesture::Click).
+//! - A second click on the same cell with the same button within the
+//!   double-click interval (400ms by default) is a
+//!   [`DoubleClick`](Gesture::DoubleClick) instead.
+//! - Moving with a button held is a [`DragStart`](Gesture::DragStart), then
+//!   a [`DragMove`](Gesture::DragMove) per cell and a
+//!   [`DragEnd`](Gesture::DragEnd) on release. Each carries the cell where
+//!   the button went down, so a split pane can resize from it or a list can
+//!   select the range.
+//!
+//! ```ignore
+//! app.on_gesture(GestureDetector::new(), |app, gesture| match gesture {
+//!     Gesture::DoubleClick { position, .. } => app_state.open_at(position),
+//!     Gesture::DragMove { origin, position, .. } => app_state.resize_split(origin, position),
+//!     _ => {}
+//! });
+//! ```
+//!
+//! Scroll-wheel notches aren't gestures; [`Wheel::from_mouse`] reads them,
+//! and the apps deliver them to the scrollable component under the pointer
+//! (see `App::on_scroll` and `use_scroll`).
+//!
+//! Positions are terminal cells as (column, row). Setting a gesture handler
+//! turns on mouse capture for the run, which stops the terminal's own text
+//! selection; see [`SelectMode`](crate::SelectMode) for a keyboard
+//! alternative.
+
+use crate::input::Mouse;
+use crossterm::event::{KeyModifiers, MouseButton, MouseEventKind};
+use std::time::{Duration, Instant};
+
+/// Default time allowed between the clicks of a double-click.
+pub const DEFAULT_DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// A gesture made with the mouse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gesture {
+    /// A button was pressed and released on the same cell.
+    Click {
+        /// The button.
+        button: MouseButton,
+        /// The cell clicked.
+        position: (u16, u16),
+    },
+    /// A second click on the same cell, soon after the first.
+    DoubleClick {
+        /// The button.
+        button: MouseButton,
+        /// The cell clicked.
+        position: (u16, u16),
+    },
+    /// The mouse moved with a button held.
+    DragStart {
+        /// The button held.
+        button: MouseButton,
+        /// Where the button went down.
+        origin: (u16, u16),
+        /// Where the mouse is now.
+        position: (u16, u16),
+    },
+    /// The mouse moved further during a drag.
+    DragMove {
+        /// The button held.
+        button: MouseButton,
+        /// Where the button went down.
+        origin: (u16, u16),
+        /// Where the mouse is now.
+        position: (u16, u16),
+    },
+    /// The button was released, ending a drag.
+    DragEnd {
+        /// The button that was held.
+        button: MouseButton,
+        /// Where the button went down.
+        origin: (u16, u16),
+        /// Where it was released.
+        position: (u16, u16),
+    },
+}
+
+impl Gesture {
+    /// The button making the gesture.
+    pub fn button(&self) -> MouseButton {
+        match *self {
+            Gesture::Click { button, .. }
+            | Gesture::DoubleClick { button, .. }
+            | Gesture::DragStart { button, .. }
+            | Gesture::DragMove { button, .. }
+            | Gesture::DragEnd { button, .. } => button,
+        }
+    }
+
+    /// Where the mouse is: the cell clicked, or the current drag position.
+    pub fn position(&self) -> (u16, u16) {
+        match *self {
+            Gesture::Click { position, .. }
+            | Gesture::DoubleClick { position, .. }
+            | Gesture::DragStart { position, .. }
+            | Gesture::DragMove { position, .. }
+            | Gesture::DragEnd { position, .. } => position,
+        }
+    }
+
+    /// Where a drag started, for drag gestures.
+    pub fn origin(&self) -> Option<(u16, u16)> {
+        match *self {
+            Gesture::DragStart { origin, .. }
+            | Gesture::DragMove { origin, .. }
+            | Gesture::DragEnd { origin, .. } => Some(origin),
+            _ => None,
+        }
+    }
+
+    /// Whether this is part of a drag.
+    pub fn is_drag(&self) -> bool {
+        self.origin().is_some()
+    }
+}
+
+/// A button that is down: which one, where it went down, and whether it
+/// has moved since.
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    button: MouseButton,
+    origin: (u16, u16),
+    dragging: bool,
+}
+
+/// Turns raw mouse events into [`Gesture`]s.
+#[derive(Debug, Clone)]
+pub struct GestureDetector {
+    double_click: Duration,
+    press: Option<Press>,
+    last_click: Option<(Instant, MouseButton, (u16, u16))>,
+}
+
+impl Default for GestureDetector {
+    fn default() -> Self {
+        Self {
+            double_click: DEFAULT_DOUBLE_CLICK,
+            press: None,
+            last_click: None,
+        }
+    }
+}
+
+impl GestureDetector {
+    /// Create a detector with the default double-click interval.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the time allowed between the clicks of a double-click.
+    #[must_use]
+    pub fn double_click(mut self, interval: Duration) -> Self {
+        self.double_click = interval;
+        self
+    }
+
+    /// Whether a drag is in progress.
+    pub fn is_dragging(&self) -> bool {
+        self.press.is_some_and(|press| press.dragging)
+    }
+
+    /// Forget any press in progress and the last click.
+    pub fn reset(&mut self) {
+        self.press = None;
+        self.last_click = None;
+    }
+
+    /// Feed a mouse event that happened at `now`, returning the gesture it
+    /// completes or continues, if any.
+    ///
+    /// Moves without a button and scrolling aren't gestures.
+    pub fn feed(&mut self, mouse: &Mouse, now: Instant) -> Option<Gesture> {
+        let position = mouse.position();
+        match mouse.kind {
+            MouseEventKind::Down(button) => {
+                self.press = Some(Press {
+                    button,
+                    origin: position,
+                    dragging: false,
+                });
+                None
+            }
+            MouseEventKind::Drag(button) => {
+                // A drag with no press seen starts where it's first reported
+                let press = self.press.get_or_insert(Press {
+                    button,
+                    origin: position,
+                    dragging: false,
+                });
+                if press.button != button {
+                    return None;
+                }
+                let origin = press.origin;
+                if press.dragging {
+                    return Some(Gesture::DragMove {
+                        button,
+                        origin,
+                        position,
+                    });
+                }
+                if position == origin {
+                    return None;
+                }
+                press.dragging = true;
+                self.last_click = None;
+                Some(Gesture::DragStart {
+                    button,
+                    origin,
+                    position,
+                })
+            }
+            MouseEventKind::Up(button) => {
+                let press = self.press.take().filter(|press| press.button == button)?;
+                if press.dragging {
+                    return Some(Gesture::DragEnd {
+                        button,
+                        origin: press.origin,
+                        position,
+                    });
+                }
+                if position != press.origin {
+                    self.last_click = None;
+                    return None;
+                }
+                let double = self.last_click.is_some_and(|(at, last_button, last)| {
+                    last_button == button
+                        && last == position
+                        && now.saturating_duration_since(at) <= self.double_click
+                });
+                if double {
+                    // A third click starts a new pair
+                    self.last_click = None;
+                    Some(Gesture::DoubleClick { button, position })
+                } else {
+                    self.last_click = Some((now, button, position));
+                    Some(Gesture::Click { button, position })
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Which way the wheel turned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollDirection {
+    /// Towards the start: content moves down.
+    Up,
+    /// Towards the end: content moves up.
+    Down,
+    /// Horizontally towards the start.
+    Left,
+    /// Horizontally towards the end.
+    Right,
+}
+
+/// One notch of the scroll wheel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wheel {
+    /// Which way it turned.
+    pub direction: ScrollDirection,
+    /// The cell under the pointer, as (column, row).
+    pub position: (u16, u16),
+    /// Modifier keys held.
+    pub modifiers: KeyModifiers,
+}
+
+impl Wheel {
+    /// The wheel notch in a mouse event, if it is one.
+    pub fn from_mouse(mouse: &Mouse) -> Option<Self> {
+        let direction = match mouse.kind {
+            MouseEventKind::ScrollUp => ScrollDirection::Up,
+            MouseEventKind::ScrollDown => ScrollDirection::Down,
+            MouseEventKind::ScrollLeft => ScrollDirection::Left,
+            MouseEventKind::ScrollRight => ScrollDirection::Right,
+            _ => return None,
+        };
+        Some(Self {
+            direction,
+            position: mouse.position(),
+            modifiers: mouse.modifiers,
+        })
+    }
+
+    /// -1 towards the start (up or left), 1 towards the end.
+    pub fn delta(&self) -> isize {
+        match self.direction {
+            ScrollDirection::Up | ScrollDirection::Left => -1,
+            ScrollDirection::Down | ScrollDirection::Right => 1,
+        }
+    }
+
+    /// Whether the wheel turned horizontally.
+    pub fn is_horizontal(&self) -> bool {
+        matches!(
+            self.direction,
+            ScrollDirection::Left | ScrollDirection::Right
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEFT: MouseButton = MouseButton::Left;
+
+    fn at(kind: MouseEventKind, column: u16, row: u16) -> Mouse {
+        Mouse::new(kind, column, row)
+    }
+
+    fn click(
+        detector: &mut GestureDetector,
+        position: (u16, u16),
+        now: Instant,
+    ) -> Option<Gesture> {
+        let (column, row) = position;
+        assert_eq!(
+            detector.feed(&at(MouseEventKind::Down(LEFT), column, row), now),
+            None
+        );
+        detector.feed(&at(MouseEventKind::Up(LEFT), column, row), now)
+    }
+
+    #[test]
+    fn test_click_and_double_click() {
+        let mut detector = GestureDetector::new();
+        let t0 = Instant::now();
+        let position = (4, 2);
+        assert_eq!(
+            click(&mut detector, position, t0),
+            Some(Gesture::Click {
+                button: LEFT,
+                position
+            })
+        );
+        assert_eq!(
+            click(&mut detector, position, t0 + Duration::from_millis(200)),
+            Some(Gesture::DoubleClick {
+                button: LEFT,
+                position
+            })
+        );
+        // A third click is a single click again
+        let third = click(&mut detector, position, t0 + Duration::from_millis(300));
+        assert!(matches!(third, Some(Gesture::Click { .. })));
+
+        // Too slow, or on another cell
+        let late = t0 + Duration::from_secs(1);
+        assert!(matches!(
+            click(&mut detector, position, late),
+            Some(Gesture::Click { .. })
+        ));
+        assert!(matches!(
+            click(&mut detector, (5, 2), late),
+            Some(Gesture::Click { .. })
+        ));
+    }
+
+    #[test]
+    fn test_double_click_interval() {
+        let mut detector = GestureDetector::new().double_click(Duration::from_millis(100));
+        let t0 = Instant::now();
+        click(&mut detector, (0, 0), t0);
+        let second = click(&mut detector, (0, 0), t0 + Duration::from_millis(150));
+        assert!(matches!(second, Some(Gesture::Click { .. })));
+    }
+
+    #[test]
+    fn test_drag() {
+        let mut detector = GestureDetector::new();
+        let now = Instant::now();
+        detector.feed(&at(MouseEventKind::Down(LEFT), 10, 3), now);
+        let start = detector.feed(&at(MouseEventKind::Drag(LEFT), 11, 3), now);
+        assert_eq!(
+            start,
+            Some(Gesture::DragStart {
+                button: LEFT,
+                origin: (10, 3),
+                position: (11, 3)
+            })
+        );
+        assert!(detector.is_dragging());
+        let moved = detector
+            .feed(&at(MouseEventKind::Drag(LEFT), 14, 5), now)
+            .unwrap();
+        assert!(matches!(moved, Gesture::DragMove { .. }));
+        assert_eq!(moved.origin(), Some((10, 3)));
+        assert_eq!(moved.position(), (14, 5));
+        let end = detector
+            .feed(&at(MouseEventKind::Up(LEFT), 14, 5), now)
+            .unwrap();
+        assert!(matches!(end, Gesture::DragEnd { .. }));
+        assert!(!detector.is_dragging());
+
+        // Scrolls and plain moves aren't gestures
+        assert_eq!(detector.feed(&at(MouseEventKind::Moved, 1, 1), now), None);
+        assert_eq!(
+            detector.feed(&at(MouseEventKind::ScrollDown, 1, 1), now),
+            None
+        );
+    }
+
+    #[test]
+    fn test_wheel_from_mouse() {
+        let wheel = Wheel::from_mouse(&at(MouseEventKind::ScrollUp, 3, 7)).unwrap();
+        assert_eq!(wheel.direction, ScrollDirection::Up);
+        assert_eq!(wheel.position, (3, 7));
+        assert_eq!(wheel.delta(), -1);
+        let right = Wheel::from_mouse(&at(MouseEventKind::ScrollRight, 0, 0)).unwrap();
+        assert!(right.is_horizontal());
+        assert_eq!(right.delta(), 1);
+        assert_eq!(
+            Wheel::from_mouse(&at(MouseEventKind::Down(LEFT), 0, 0)),
+            None
+        );
+    }
+}
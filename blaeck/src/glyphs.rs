@@ -0,0 +1,121 @@
+//! Glyph sets: Unicode or ASCII-only drawing characters.
+//!
+//! Spinners, tree connectors, borders, checkboxes and progress bars draw
+//! with Unicode by default. On terminals that can't show it — a non-UTF-8
+//! locale, the Linux console, a serial line — they fall back to ASCII
+//! equivalents chosen by [`GlyphSet::detect`].
+//!
+//! Every affected component has a `glyphs` prop to override detection:
+//!
+//! ```ignore
+//! // Always ASCII, whatever the terminal says
+//! ProgressProps::new(0.4).glyphs(GlyphSet::Ascii)
+//! ```
+//!
+//! Set `BLAECK_GLYPHS=ascii` or `BLAECK_GLYPHS=unicode` to override
+//! detection for the whole app.
+
+use std::sync::OnceLock;
+
+/// Cached result of glyph set detection.
+static GLYPH_SET: OnceLock<GlyphSet> = OnceLock::new();
+
+/// Which characters components may draw with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GlyphSet {
+    /// Box drawing, block elements, braille and other Unicode symbols.
+    #[default]
+    Unicode,
+    /// Printable ASCII only.
+    Ascii,
+}
+
+impl GlyphSet {
+    /// Detect the glyph set the terminal can display.
+    ///
+    /// Checks `BLAECK_GLYPHS`, the locale (`LC_ALL`, `LC_CTYPE`, `LANG`) and
+    /// `TERM`. The result is cached after the first call.
+    pub fn detect() -> Self {
+        *GLYPH_SET.get_or_init(|| {
+            let var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+            Self::from_env(
+                var("BLAECK_GLYPHS").as_deref(),
+                var("LC_ALL")
+                    .or_else(|| var("LC_CTYPE"))
+                    .or_else(|| var("LANG"))
+                    .as_deref(),
+                var("TERM").as_deref(),
+            )
+        })
+    }
+
+    /// Pick a glyph set from environment values. Used by [`detect`](Self::detect).
+    ///
+    /// `locale` is the first non-empty of `LC_ALL`, `LC_CTYPE` and `LANG`.
+    /// An unset locale is treated as UTF-8, since most terminals that don't
+    /// set one still render Unicode.
+    pub fn from_env(
+        override_value: Option<&str>,
+        locale: Option<&str>,
+        term: Option<&str>,
+    ) -> Self {
+        match override_value.map(str::to_ascii_lowercase).as_deref() {
+            Some("ascii") => return GlyphSet::Ascii,
+            Some("unicode") | Some("utf8") | Some("utf-8") => return GlyphSet::Unicode,
+            _ => {}
+        }
+
+        if matches!(term, Some("linux" | "vt100" | "vt102" | "vt220" | "dumb")) {
+            return GlyphSet::Ascii;
+        }
+
+        match locale.filter(|l| !l.is_empty()) {
+            Some(locale) => {
+                let locale = locale.to_ascii_lowercase();
+                if locale.contains("utf-8") || locale.contains("utf8") {
+                    GlyphSet::Unicode
+                } else {
+                    GlyphSet::Ascii
+                }
+            }
+            None => GlyphSet::Unicode,
+        }
+    }
+
+    /// Resolve a component's override against the detected glyph set.
+    pub fn resolve(override_value: Option<GlyphSet>) -> Self {
+        override_value.unwrap_or_else(Self::detect)
+    }
+
+    /// Returns true for [`GlyphSet::Ascii`].
+    pub fn is_ascii(self) -> bool {
+        self == GlyphSet::Ascii
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glyph_set_from_env() {
+        assert_eq!(
+            GlyphSet::from_env(None, Some("en_US.UTF-8"), Some("xterm")),
+            GlyphSet::Unicode
+        );
+        assert_eq!(GlyphSet::from_env(None, None, None), GlyphSet::Unicode);
+        assert_eq!(GlyphSet::from_env(None, Some("C"), None), GlyphSet::Ascii);
+        assert_eq!(
+            GlyphSet::from_env(None, Some("en_US.UTF-8"), Some("linux")),
+            GlyphSet::Ascii
+        );
+        assert_eq!(
+            GlyphSet::from_env(Some("unicode"), Some("POSIX"), Some("dumb")),
+            GlyphSet::Unicode
+        );
+        assert_eq!(
+            GlyphSet::from_env(Some("ASCII"), None, None),
+            GlyphSet::Ascii
+        );
+    }
+}
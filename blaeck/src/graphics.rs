@@ -0,0 +1,701 @@
+//! Inline terminal graphics.
+//!
+//! Terminals that support a graphics protocol can show real pixels inline;
+//! everything else gets unicode half-block art (`▀` with the top pixel as the
+//! foreground and the bottom pixel as the background, two pixels per cell).
+//!
+//! | Protocol | Terminals | Encoding |
+//! |----------|-----------|----------|
+//! | [`Kitty`](GraphicsProtocol::Kitty) | kitty, WezTerm, Ghostty | Raw RGBA, base64, chunked APC |
+//! | [`Iterm2`](GraphicsProtocol::Iterm2) | iTerm2 | BMP file, base64, OSC 1337 |
+//! | [`Sixel`](GraphicsProtocol::Sixel) | foot, mlterm, xterm `-ti vt340` | 216-color palette, DCS sixel |
+//! | [`HalfBlocks`](GraphicsProtocol::HalfBlocks) | everything else | `▀` cells with fg/bg colors |
+//!
+//! Images are placed into the [`Output`](crate::output::Output) grid with
+//! [`Output::write_image`](crate::output::Output::write_image). The grid
+//! reserves the covered cells and emits the protocol escape when serialized.
+//!
+//! Detection is environment-based (no terminal queries), cached like
+//! [`supports_truecolor`](crate::style::supports_truecolor).
+
+use crate::style::{Color, Style};
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Assumed pixel width of one terminal cell, used to size sixel output.
+pub const CELL_PIXEL_WIDTH: u32 = 10;
+/// Assumed pixel height of one terminal cell, used to size sixel output.
+pub const CELL_PIXEL_HEIGHT: u32 = 20;
+
+/// Cached result of graphics protocol detection.
+static GRAPHICS_PROTOCOL: OnceLock<GraphicsProtocol> = OnceLock::new();
+
+/// An RGBA image held in memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageData {
+    width: u32,
+    height: u32,
+    pixels: Vec<[u8; 4]>,
+}
+
+impl ImageData {
+    /// Create an image from tightly packed RGBA bytes.
+    ///
+    /// Returns `None` if the buffer length doesn't match `width * height * 4`.
+    pub fn from_rgba(width: u32, height: u32, rgba: &[u8]) -> Option<Self> {
+        if rgba.len() != (width as usize) * (height as usize) * 4 {
+            return None;
+        }
+        let pixels = rgba
+            .chunks_exact(4)
+            .map(|p| [p[0], p[1], p[2], p[3]])
+            .collect();
+        Some(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Create an opaque image from tightly packed RGB bytes.
+    ///
+    /// Returns `None` if the buffer length doesn't match `width * height * 3`.
+    pub fn from_rgb(width: u32, height: u32, rgb: &[u8]) -> Option<Self> {
+        if rgb.len() != (width as usize) * (height as usize) * 3 {
+            return None;
+        }
+        let pixels = rgb
+            .chunks_exact(3)
+            .map(|p| [p[0], p[1], p[2], 255])
+            .collect();
+        Some(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Create an image filled with a single color.
+    pub fn solid(width: u32, height: u32, rgba: [u8; 4]) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![rgba; (width as usize) * (height as usize)],
+        }
+    }
+
+    /// Image width in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Image height in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Get the RGBA value at (x, y).
+    ///
+    /// # Panics
+    /// Panics if coordinates are out of bounds.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        self.pixels[(y as usize) * (self.width as usize) + (x as usize)]
+    }
+
+    /// Set the RGBA value at (x, y).
+    ///
+    /// # Panics
+    /// Panics if coordinates are out of bounds.
+    pub fn set_pixel(&mut self, x: u32, y: u32, rgba: [u8; 4]) {
+        let idx = (y as usize) * (self.width as usize) + (x as usize);
+        self.pixels[idx] = rgba;
+    }
+
+    /// Resample to a new size with nearest-neighbor sampling.
+    pub fn resize(&self, width: u32, height: u32) -> ImageData {
+        let width = width.max(1);
+        let height = height.max(1);
+        if self.width == 0 || self.height == 0 {
+            return ImageData::solid(width, height, [0, 0, 0, 0]);
+        }
+        let mut pixels = Vec::with_capacity((width as usize) * (height as usize));
+        for y in 0..height {
+            let src_y = (y as u64 * self.height as u64 / height as u64) as u32;
+            for x in 0..width {
+                let src_x = (x as u64 * self.width as u64 / width as u64) as u32;
+                pixels.push(self.pixel(src_x, src_y));
+            }
+        }
+        ImageData {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Raw RGBA bytes, row-major.
+    pub fn to_rgba_bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+
+    /// Decode an image file held in memory.
+    ///
+    /// Uncompressed 24- and 32-bit BMP is always supported; PNG needs the
+    /// `png` feature. Other formats are an [`io::ErrorKind::InvalidData`] error.
+    pub fn decode(bytes: &[u8]) -> io::Result<Self> {
+        if bytes.starts_with(b"BM") {
+            return decode_bmp(bytes);
+        }
+        if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+            return decode_png(bytes);
+        }
+        Err(invalid_image("unrecognized image format"))
+    }
+
+    /// Read and decode an image file. See [`decode`](Self::decode).
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::decode(&std::fs::read(path)?)
+    }
+}
+
+fn invalid_image(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Decode an uncompressed 24- or 32-bit BMP, bottom-up or top-down.
+fn decode_bmp(bytes: &[u8]) -> io::Result<ImageData> {
+    let u16_at = |i: usize| {
+        bytes
+            .get(i..i + 2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+    };
+    let u32_at = |i: usize| {
+        bytes
+            .get(i..i + 4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    };
+    let truncated = || invalid_image("truncated BMP");
+    let offset = u32_at(10).ok_or_else(truncated)? as usize;
+    let width = u32_at(18).ok_or_else(truncated)? as i32;
+    let height = u32_at(22).ok_or_else(truncated)? as i32;
+    let bits = u16_at(28).ok_or_else(truncated)?;
+    let compression = u32_at(30).ok_or_else(truncated)?;
+    // BI_BITFIELDS with the standard masks is how many tools write 32-bit
+    if width <= 0 || !(bits == 24 || bits == 32) || !(compression == 0 || compression == 3) {
+        return Err(invalid_image(
+            "unsupported BMP (only uncompressed 24/32-bit)",
+        ));
+    }
+
+    let (width, top_down) = (width as u32, height < 0);
+    let height = height.unsigned_abs();
+    let pixel_size = usize::from(bits / 8);
+    // Rows are padded to a multiple of 4 bytes
+    let stride = (width as usize * pixel_size).div_ceil(4) * 4;
+    let mut image = ImageData::solid(width, height, [0, 0, 0, 255]);
+    for row in 0..height {
+        let start = offset + row as usize * stride;
+        let line = bytes
+            .get(start..start + width as usize * pixel_size)
+            .ok_or_else(truncated)?;
+        let y = if top_down { row } else { height - 1 - row };
+        for (x, p) in line.chunks_exact(pixel_size).enumerate() {
+            let alpha = if bits == 32 { p[3] } else { 255 };
+            image.set_pixel(x as u32, y, [p[2], p[1], p[0], alpha]);
+        }
+    }
+    Ok(image)
+}
+
+#[cfg(feature = "png")]
+fn decode_png(bytes: &[u8]) -> io::Result<ImageData> {
+    let mut decoder = png::Decoder::new(bytes);
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder
+        .read_info()
+        .map_err(|e| invalid_image(&e.to_string()))?;
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut buf)
+        .map_err(|e| invalid_image(&e.to_string()))?;
+    let buf = &buf[..info.buffer_size()];
+    let rgba: Vec<u8> = match info.color_type {
+        png::ColorType::Rgba => buf.to_vec(),
+        png::ColorType::Rgb => buf
+            .chunks_exact(3)
+            .flat_map(|p| [p[0], p[1], p[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buf
+            .chunks_exact(2)
+            .flat_map(|p| [p[0], p[0], p[0], p[1]])
+            .collect(),
+        png::ColorType::Grayscale => buf.iter().flat_map(|&g| [g, g, g, 255]).collect(),
+        png::ColorType::Indexed => return Err(invalid_image("unexpanded indexed PNG")),
+    };
+    ImageData::from_rgba(info.width, info.height, &rgba)
+        .ok_or_else(|| invalid_image("bad PNG size"))
+}
+
+#[cfg(not(feature = "png"))]
+fn decode_png(_bytes: &[u8]) -> io::Result<ImageData> {
+    Err(invalid_image("PNG images need blaeck's `png` feature"))
+}
+
+/// A terminal graphics protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GraphicsProtocol {
+    /// Kitty graphics protocol (APC `_G`).
+    Kitty,
+    /// iTerm2 inline images (OSC 1337).
+    Iterm2,
+    /// DEC sixel graphics.
+    Sixel,
+    /// Unicode half-block fallback; works everywhere colors work.
+    HalfBlocks,
+}
+
+impl GraphicsProtocol {
+    /// Detects the best protocol for the current terminal.
+    ///
+    /// Checks `BLAECK_GRAPHICS` (override: `kitty`, `iterm2`, `sixel`, `halfblocks`),
+    /// then `TERM`, `TERM_PROGRAM`, and `KITTY_WINDOW_ID`.
+    /// The result is cached after the first call.
+    pub fn detect() -> Self {
+        *GRAPHICS_PROTOCOL.get_or_init(|| {
+            let var = |name: &str| std::env::var(name).ok();
+            Self::from_env(
+                var("BLAECK_GRAPHICS").as_deref(),
+                var("TERM").as_deref(),
+                var("TERM_PROGRAM").as_deref(),
+                var("KITTY_WINDOW_ID").is_some(),
+            )
+        })
+    }
+
+    /// Pick a protocol from environment values. Used by [`detect`](Self::detect).
+    pub fn from_env(
+        override_value: Option<&str>,
+        term: Option<&str>,
+        term_program: Option<&str>,
+        in_kitty: bool,
+    ) -> Self {
+        match override_value.map(str::to_ascii_lowercase).as_deref() {
+            Some("kitty") => return GraphicsProtocol::Kitty,
+            Some("iterm2") | Some("iterm") => return GraphicsProtocol::Iterm2,
+            Some("sixel") => return GraphicsProtocol::Sixel,
+            Some("halfblocks") | Some("none") => return GraphicsProtocol::HalfBlocks,
+            _ => {}
+        }
+
+        let term = term.unwrap_or("");
+        if in_kitty || term == "xterm-kitty" || term == "xterm-ghostty" {
+            return GraphicsProtocol::Kitty;
+        }
+        match term_program {
+            Some("iTerm.app") => return GraphicsProtocol::Iterm2,
+            Some("WezTerm") | Some("ghostty") => return GraphicsProtocol::Kitty,
+            _ => {}
+        }
+        if term.contains("sixel") || term.starts_with("foot") || term.starts_with("mlterm") {
+            return GraphicsProtocol::Sixel;
+        }
+        GraphicsProtocol::HalfBlocks
+    }
+
+    /// Whether this protocol draws real pixels (as opposed to text cells).
+    pub fn is_pixel(&self) -> bool {
+        !matches!(self, GraphicsProtocol::HalfBlocks)
+    }
+
+    /// This protocol if it draws real pixels, or
+    /// [`BlaeckError::Unsupported`](crate::BlaeckError::Unsupported) for
+    /// the half-block fallback. For output that needs pixel graphics:
+    ///
+    /// ```ignore
+    /// let protocol = GraphicsProtocol::detect().require_pixel()?;
+    /// ```
+    pub fn require_pixel(self) -> crate::error::Result<Self> {
+        if self.is_pixel() {
+            Ok(self)
+        } else {
+            Err(crate::error::BlaeckError::unsupported(
+                "pixel graphics (Kitty, iTerm2 or Sixel)",
+            ))
+        }
+    }
+
+    /// Encode an image to fill `cols` x `rows` cells at the cursor position.
+    ///
+    /// `id` identifies the placement so a re-render replaces the previous
+    /// image instead of stacking a new one (Kitty only).
+    /// Returns `None` for [`HalfBlocks`](GraphicsProtocol::HalfBlocks); use
+    /// [`half_blocks`] for that.
+    pub fn encode(&self, image: &ImageData, cols: u16, rows: u16, id: u32) -> Option<String> {
+        match self {
+            GraphicsProtocol::Kitty => Some(encode_kitty(image, cols, rows, id)),
+            GraphicsProtocol::Iterm2 => Some(encode_iterm2(image, cols, rows)),
+            GraphicsProtocol::Sixel => Some(encode_sixel(&image.resize(
+                cols as u32 * CELL_PIXEL_WIDTH,
+                rows as u32 * CELL_PIXEL_HEIGHT,
+            ))),
+            GraphicsProtocol::HalfBlocks => None,
+        }
+    }
+}
+
+/// Render an image as `rows` lines of `cols` half-block cells.
+///
+/// Each cell shows two vertically stacked pixels: the upper one as the `▀`
+/// foreground, the lower one as the background. Fully transparent pixels
+/// fall back to the terminal's default color.
+pub fn half_blocks(image: &ImageData, cols: u16, rows: u16) -> Vec<Vec<(char, Style)>> {
+    let scaled = image.resize(cols as u32, rows as u32 * 2);
+    let to_color = |p: [u8; 4]| {
+        if p[3] == 0 {
+            Color::Reset
+        } else {
+            Color::Rgb(p[0], p[1], p[2])
+        }
+    };
+    (0..rows as u32)
+        .map(|row| {
+            (0..cols as u32)
+                .map(|col| {
+                    let top = to_color(scaled.pixel(col, row * 2));
+                    let bottom = to_color(scaled.pixel(col, row * 2 + 1));
+                    match (top, bottom) {
+                        (Color::Reset, Color::Reset) => (' ', Style::new()),
+                        (Color::Reset, bottom) => ('▄', Style::new().fg(bottom)),
+                        (top, bottom) => ('▀', Style::new().fg(top).bg(bottom)),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Kitty: transmit-and-display raw RGBA, scaled to the cell box.
+///
+/// `q=2` suppresses terminal responses (which would otherwise arrive as
+/// keyboard input) and `C=1` keeps the cursor in place.
+fn encode_kitty(image: &ImageData, cols: u16, rows: u16, id: u32) -> String {
+    let payload = base64_encode(&image.to_rgba_bytes());
+    let mut out = format!("\x1b_Ga=d,d=i,i={},q=2\x1b\\", id);
+    let chunks: Vec<&[u8]> = payload.as_bytes().chunks(4096).collect();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = if i + 1 < chunks.len() { 1 } else { 0 };
+        let data = std::str::from_utf8(chunk).unwrap_or_default();
+        if i == 0 {
+            out.push_str(&format!(
+                "\x1b_Ga=T,f=32,s={},v={},c={},r={},i={},C=1,q=2,m={};{}\x1b\\",
+                image.width(),
+                image.height(),
+                cols,
+                rows,
+                id,
+                more,
+                data
+            ));
+        } else {
+            out.push_str(&format!("\x1b_Gm={};{}\x1b\\", more, data));
+        }
+    }
+    out
+}
+
+/// iTerm2: send a BMP file inline, sized in cells.
+fn encode_iterm2(image: &ImageData, cols: u16, rows: u16) -> String {
+    let bmp = encode_bmp(image);
+    format!(
+        "\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=0:{}\x07",
+        bmp.len(),
+        cols,
+        rows,
+        base64_encode(&bmp)
+    )
+}
+
+/// Sixel: quantize to the 6x6x6 color cube and emit run-length-encoded bands.
+fn encode_sixel(image: &ImageData) -> String {
+    let quantize = |p: [u8; 4]| -> Option<usize> {
+        if p[3] < 128 {
+            return None;
+        }
+        let q = |v: u8| (v as usize * 5 + 127) / 255;
+        Some(q(p[0]) * 36 + q(p[1]) * 6 + q(p[2]))
+    };
+
+    // P2=1: transparent pixels keep the background
+    let mut out = String::from("\x1bP0;1;0q");
+    out.push_str(&format!("\"1;1;{};{}", image.width(), image.height()));
+    for idx in 0..216 {
+        let (r, g, b) = (idx / 36, (idx / 6) % 6, idx % 6);
+        out.push_str(&format!("#{};2;{};{};{}", idx, r * 20, g * 20, b * 20));
+    }
+
+    let mut band_y = 0;
+    while band_y < image.height() {
+        let band_h = (image.height() - band_y).min(6);
+        // Collect the sixel bits for every color used in this band
+        let mut colors: Vec<(usize, Vec<u8>)> = Vec::new();
+        for x in 0..image.width() {
+            for dy in 0..band_h {
+                if let Some(c) = quantize(image.pixel(x, band_y + dy)) {
+                    let pos = match colors.iter().position(|(color, _)| *color == c) {
+                        Some(pos) => pos,
+                        None => {
+                            colors.push((c, vec![0; image.width() as usize]));
+                            colors.len() - 1
+                        }
+                    };
+                    colors[pos].1[x as usize] |= 1 << dy;
+                }
+            }
+        }
+        for (i, (color, bits)) in colors.iter().enumerate() {
+            out.push_str(&format!("#{}", color));
+            push_sixel_runs(&mut out, bits);
+            if i + 1 < colors.len() {
+                out.push('$');
+            }
+        }
+        out.push('-');
+        band_y += 6;
+    }
+    out.push_str("\x1b\\");
+    out
+}
+
+/// Append sixel characters with `!n` run-length compression.
+fn push_sixel_runs(out: &mut String, bits: &[u8]) {
+    let mut i = 0;
+    while i < bits.len() {
+        let mut run = 1;
+        while i + run < bits.len() && bits[i + run] == bits[i] {
+            run += 1;
+        }
+        let ch = (b'?' + bits[i]) as char;
+        if run > 3 {
+            out.push_str(&format!("!{}{}", run, ch));
+        } else {
+            for _ in 0..run {
+                out.push(ch);
+            }
+        }
+        i += run;
+    }
+}
+
+/// Encode as an uncompressed 32-bit BMP (bottom-up BGRA rows).
+fn encode_bmp(image: &ImageData) -> Vec<u8> {
+    let pixel_bytes = image.width() as usize * image.height() as usize * 4;
+    let file_size = 54 + pixel_bytes;
+    let mut bmp = Vec::with_capacity(file_size);
+    bmp.extend_from_slice(b"BM");
+    bmp.extend_from_slice(&(file_size as u32).to_le_bytes());
+    bmp.extend_from_slice(&0u32.to_le_bytes());
+    bmp.extend_from_slice(&54u32.to_le_bytes());
+    bmp.extend_from_slice(&40u32.to_le_bytes());
+    bmp.extend_from_slice(&(image.width() as i32).to_le_bytes());
+    bmp.extend_from_slice(&(image.height() as i32).to_le_bytes());
+    bmp.extend_from_slice(&1u16.to_le_bytes());
+    bmp.extend_from_slice(&32u16.to_le_bytes());
+    bmp.extend_from_slice(&0u32.to_le_bytes());
+    bmp.extend_from_slice(&(pixel_bytes as u32).to_le_bytes());
+    bmp.extend_from_slice(&2835u32.to_le_bytes());
+    bmp.extend_from_slice(&2835u32.to_le_bytes());
+    bmp.extend_from_slice(&0u32.to_le_bytes());
+    bmp.extend_from_slice(&0u32.to_le_bytes());
+    for y in (0..image.height()).rev() {
+        for x in 0..image.width() {
+            let [r, g, b, a] = image.pixel(x, y);
+            bmp.extend_from_slice(&[b, g, r, a]);
+        }
+    }
+    bmp
+}
+
+/// Standard base64 with padding.
+pub(crate) fn base64_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+        out.push(ALPHABET[(n >> 18) as usize & 63] as char);
+        out.push(ALPHABET[(n >> 12) as usize & 63] as char);
+        out.push(if chunk.len() > 1 {
+            ALPHABET[(n >> 6) as usize & 63] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            ALPHABET[n as usize & 63] as char
+        } else {
+            '='
+        });
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checker() -> ImageData {
+        let mut img = ImageData::solid(2, 2, [255, 0, 0, 255]);
+        img.set_pixel(1, 0, [0, 0, 255, 255]);
+        img.set_pixel(0, 1, [0, 0, 255, 255]);
+        img
+    }
+
+    #[test]
+    fn test_image_from_rgba_validates_length() {
+        assert!(ImageData::from_rgba(2, 2, &[0; 16]).is_some());
+        assert!(ImageData::from_rgba(2, 2, &[0; 15]).is_none());
+        assert!(ImageData::from_rgb(1, 1, &[1, 2, 3]).is_some());
+    }
+
+    #[test]
+    fn test_image_resize_nearest() {
+        let img = checker().resize(4, 4);
+        assert_eq!(img.width(), 4);
+        assert_eq!(img.pixel(0, 0), [255, 0, 0, 255]);
+        assert_eq!(img.pixel(3, 0), [0, 0, 255, 255]);
+        assert_eq!(img.pixel(0, 3), [0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn test_decode_bmp_round_trip() {
+        let img = checker();
+        assert_eq!(ImageData::decode(&encode_bmp(&img)).unwrap(), img);
+        assert!(ImageData::decode(&encode_bmp(&img)[..60]).is_err());
+        assert!(ImageData::decode(b"GIF89a").is_err());
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn test_decode_png() {
+        let img = checker();
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(&mut bytes, 2, 2);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder
+            .write_header()
+            .unwrap()
+            .write_image_data(&img.to_rgba_bytes())
+            .unwrap();
+        assert_eq!(ImageData::decode(&bytes).unwrap(), img);
+    }
+
+    #[test]
+    fn test_detect_from_env() {
+        use GraphicsProtocol::*;
+        assert_eq!(
+            GraphicsProtocol::from_env(None, Some("xterm-kitty"), None, false),
+            Kitty
+        );
+        assert_eq!(
+            GraphicsProtocol::from_env(None, Some("xterm"), None, true),
+            Kitty
+        );
+        assert_eq!(
+            GraphicsProtocol::from_env(None, Some("xterm-256color"), Some("iTerm.app"), false),
+            Iterm2
+        );
+        assert_eq!(
+            GraphicsProtocol::from_env(None, Some("foot"), None, false),
+            Sixel
+        );
+        assert_eq!(
+            GraphicsProtocol::from_env(None, Some("xterm"), None, false),
+            HalfBlocks
+        );
+        assert_eq!(
+            GraphicsProtocol::from_env(Some("HalfBlocks"), Some("xterm-kitty"), None, true),
+            HalfBlocks
+        );
+    }
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_half_blocks() {
+        let lines = half_blocks(&checker(), 2, 1);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].len(), 2);
+        let (ch, style) = lines[0][0];
+        assert_eq!(ch, '▀');
+        assert_eq!(style.fg, Color::Rgb(255, 0, 0));
+        assert_eq!(style.bg, Color::Rgb(0, 0, 255));
+    }
+
+    #[test]
+    fn test_half_blocks_transparent() {
+        let img = ImageData::solid(1, 2, [0, 0, 0, 0]);
+        let lines = half_blocks(&img, 1, 1);
+        assert_eq!(lines[0][0].0, ' ');
+    }
+
+    #[test]
+    fn test_encode_kitty() {
+        let out = GraphicsProtocol::Kitty.encode(&checker(), 4, 2, 7).unwrap();
+        assert!(out.starts_with("\x1b_Ga=d,d=i,i=7"));
+        assert!(out.contains("a=T,f=32,s=2,v=2,c=4,r=2,i=7"));
+        assert!(out.ends_with("\x1b\\"));
+    }
+
+    #[test]
+    fn test_encode_iterm2() {
+        let out = GraphicsProtocol::Iterm2
+            .encode(&checker(), 4, 2, 0)
+            .unwrap();
+        assert!(out.starts_with("\x1b]1337;File=inline=1;"));
+        assert!(out.contains("width=4;height=2"));
+        assert!(out.ends_with('\x07'));
+    }
+
+    #[test]
+    fn test_encode_sixel() {
+        let out = GraphicsProtocol::Sixel.encode(&checker(), 1, 1, 0).unwrap();
+        assert!(out.starts_with("\x1bP0;1;0q"));
+        assert!(out.contains("\"1;1;10;20"));
+        assert!(out.ends_with("\x1b\\"));
+    }
+
+    #[test]
+    fn test_encode_halfblocks_is_none() {
+        assert!(GraphicsProtocol::HalfBlocks
+            .encode(&checker(), 1, 1, 0)
+            .is_none());
+        assert!(!GraphicsProtocol::HalfBlocks.is_pixel());
+    }
+
+    #[test]
+    fn test_require_pixel() {
+        assert_eq!(
+            GraphicsProtocol::Kitty.require_pixel().unwrap(),
+            GraphicsProtocol::Kitty
+        );
+        let err = GraphicsProtocol::HalfBlocks.require_pixel().unwrap_err();
+        assert!(matches!(err, crate::error::BlaeckError::Unsupported(_)));
+    }
+
+    #[test]
+    fn test_sixel_runs() {
+        let mut out = String::new();
+        push_sixel_runs(&mut out, &[1, 1, 1, 1, 1, 0]);
+        assert_eq!(out, "!5@?");
+    }
+}
@@ -0,0 +1,234 @@
+//! App-wide hotkeys that run before focused-component input.
+//!
+//! A [`Hotkeys`] registry maps [`KeyPattern`]s to actions. [`App`] checks it
+//! for every key press before calling the input handler, so a global command
+//! like `ctrl+p` works no matter which widget has focus:
+//!
+//! ```ignore
+//! let mut app = App::new()?;
+//! app.register_hotkey("ctrl+p", "command palette", |app| app.exit())?;
+//!
+//! // Footer listing every registered hotkey
+//! let hints = key_hints_from(app.hotkeys());
+//! ```
+//!
+//! Registering a binding that is already taken (including the same key
+//! spelled differently, like `"A"` and `"shift+a"`) replaces the old one and
+//! returns a [`HotkeyConflict`] describing what was replaced. Conflicts are
+//! also kept in [`Hotkeys::conflicts`] so they can be shown in a debug view.
+//!
+//! [`App`]: crate::App
+
+use crate::components::{KeyBindings, KeyHint};
+use crate::input::{Key, KeyPattern, KeyPatternError};
+use std::fmt;
+
+/// A registered hotkey: its binding and what it does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hotkey {
+    /// The key binding.
+    pub pattern: KeyPattern,
+    /// Short description for help text (e.g. "command palette").
+    pub description: String,
+}
+
+/// A registration that replaced an existing hotkey.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HotkeyConflict {
+    /// The binding both hotkeys use.
+    pub pattern: KeyPattern,
+    /// Description of the hotkey that was replaced.
+    pub replaced: String,
+    /// Description of the hotkey that now owns the binding.
+    pub description: String,
+}
+
+impl fmt::Display for HotkeyConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "hotkey {} ({}) replaces {}",
+            self.pattern, self.description, self.replaced
+        )
+    }
+}
+
+/// A registry of hotkeys with actions of type `A`.
+pub struct Hotkeys<A> {
+    entries: Vec<(Hotkey, A)>,
+    conflicts: Vec<HotkeyConflict>,
+}
+
+impl<A> Default for Hotkeys<A> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            conflicts: Vec::new(),
+        }
+    }
+}
+
+impl<A> fmt::Debug for Hotkeys<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hotkeys")
+            .field("hotkeys", &self.hotkeys().collect::<Vec<_>>())
+            .field("conflicts", &self.conflicts)
+            .finish()
+    }
+}
+
+impl<A> Hotkeys<A> {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `action` for the binding `pattern` (e.g. `"ctrl+p"`).
+    ///
+    /// Returns the conflict if the binding was already registered; the new
+    /// action replaces the old one.
+    pub fn register(
+        &mut self,
+        pattern: &str,
+        description: impl Into<String>,
+        action: A,
+    ) -> Result<Option<HotkeyConflict>, KeyPatternError> {
+        let pattern: KeyPattern = pattern.parse()?;
+        Ok(self.insert(
+            Hotkey {
+                pattern,
+                description: description.into(),
+            },
+            action,
+        ))
+    }
+
+    fn insert(&mut self, hotkey: Hotkey, action: A) -> Option<HotkeyConflict> {
+        let existing = self
+            .entries
+            .iter()
+            .position(|(h, _)| h.pattern.same_binding(&hotkey.pattern));
+        let conflict = existing.map(|i| {
+            let (old, _) = self.entries.remove(i);
+            HotkeyConflict {
+                pattern: hotkey.pattern,
+                replaced: old.description,
+                description: hotkey.description.clone(),
+            }
+        });
+        if let Some(conflict) = &conflict {
+            self.conflicts.push(conflict.clone());
+        }
+        self.entries.push((hotkey, action));
+        conflict
+    }
+
+    /// Remove the hotkey bound to `pattern`. Returns whether one was removed.
+    pub fn unregister(&mut self, pattern: &str) -> Result<bool, KeyPatternError> {
+        let pattern: KeyPattern = pattern.parse()?;
+        let before = self.entries.len();
+        self.entries
+            .retain(|(h, _)| !h.pattern.same_binding(&pattern));
+        Ok(self.entries.len() != before)
+    }
+
+    /// The action for `key`, if a hotkey matches it.
+    ///
+    /// A keypad-only binding wins over a plain one for keypad keys.
+    pub fn action_mut(&mut self, key: &Key) -> Option<&mut A> {
+        self.entries
+            .iter_mut()
+            .filter(|(h, _)| h.pattern.matches(key))
+            .max_by_key(|(h, _)| h.pattern.keypad)
+            .map(|(_, action)| action)
+    }
+
+    /// Registered hotkeys, in registration order.
+    pub fn hotkeys(&self) -> impl Iterator<Item = &Hotkey> {
+        self.entries.iter().map(|(h, _)| h)
+    }
+
+    /// Key hints for every hotkey, for a help footer or overlay.
+    pub fn hints(&self) -> Vec<KeyHint> {
+        self.hotkeys()
+            .map(|h| KeyHint::new(h.pattern.to_string(), h.description.clone()))
+            .collect()
+    }
+
+    /// Every conflict seen so far, oldest first.
+    pub fn conflicts(&self) -> &[HotkeyConflict] {
+        &self.conflicts
+    }
+
+    /// Number of registered hotkeys.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no hotkeys are registered.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Move every hotkey from `other` into this registry, as if registered
+    /// after the existing ones.
+    pub(crate) fn append(&mut self, other: Hotkeys<A>) {
+        self.conflicts.extend(other.conflicts);
+        for (hotkey, action) in other.entries {
+            self.insert(hotkey, action);
+        }
+    }
+}
+
+impl<A> KeyBindings for Hotkeys<A> {
+    fn key_hints(&self) -> Vec<KeyHint> {
+        self.hints()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_hotkeys_conflict_replaces_binding() {
+        let mut hotkeys = Hotkeys::new();
+        assert_eq!(hotkeys.register("ctrl+p", "palette", 1).unwrap(), None);
+        assert_eq!(hotkeys.register("A", "add", 2).unwrap(), None);
+
+        let conflict = hotkeys.register("shift+a", "archive", 3).unwrap().unwrap();
+        assert_eq!(conflict.replaced, "add");
+        assert_eq!(
+            conflict.to_string(),
+            "hotkey shift+a (archive) replaces add"
+        );
+        assert_eq!(hotkeys.conflicts().len(), 1);
+        assert_eq!(hotkeys.len(), 2);
+
+        let key = Key::with_modifiers(KeyCode::Char('A'), KeyModifiers::SHIFT);
+        assert_eq!(hotkeys.action_mut(&key), Some(&mut 3));
+        assert!(hotkeys.register("ctrl+nope", "bad", 4).is_err());
+    }
+
+    #[test]
+    fn test_hotkeys_hints_and_unregister() {
+        let mut hotkeys = Hotkeys::new();
+        hotkeys.register("ctrl+p", "palette", ()).unwrap();
+        hotkeys.register("f1", "help", ()).unwrap();
+
+        let hints = hotkeys.hints();
+        assert_eq!(hints[0].key, "ctrl+p");
+        assert_eq!(hints[1].action, "help");
+
+        assert!(hotkeys.unregister("F1").unwrap());
+        assert!(!hotkeys.unregister("f1").unwrap());
+        assert_eq!(
+            crate::components::key_hints_from(&hotkeys).render_string(),
+            "ctrl+p palette"
+        );
+        let key = Key::with_ctrl(KeyCode::Char('p'));
+        assert!(hotkeys.action_mut(&key).is_some());
+        assert!(hotkeys.action_mut(&Key::new(KeyCode::F(1))).is_none());
+    }
+}
@@ -0,0 +1,129 @@
+//! User-idle detection.
+//!
+//! An [`IdleTracker`] notices when no input has arrived for a timeout, so a
+//! dashboard can dim itself, pause animations, or lock, and wake up on the
+//! next key press, mouse event or paste.
+//!
+//! [`App::on_idle`](crate::App::on_idle) and the
+//! [`use_idle`](crate::reactive::use_idle) hook drive a tracker from the app
+//! loop. The input that wakes an idle app only wakes it; it isn't passed to
+//! hotkeys, input, gesture or paste handlers, so a stray press on a locked
+//! screen does nothing else.
+//!
+//! ```ignore
+//! let mut idle = IdleTracker::new(Duration::from_secs(300));
+//!
+//! // Every loop iteration:
+//! if idle.check(Instant::now()) {
+//!     // Just went idle: dim, pause, lock...
+//! }
+//!
+//! // On a key press, mouse event or paste:
+//! if idle.activity(Instant::now()) {
+//!     // Woke up; don't act on this key
+//! }
+//! ```
+
+use std::time::{Duration, Instant};
+
+/// A change in idle state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleEvent {
+    /// No input arrived for the timeout.
+    Idle,
+    /// Input arrived while idle.
+    Active,
+}
+
+/// Tracks time since the last input.
+#[derive(Debug, Clone)]
+pub struct IdleTracker {
+    timeout: Duration,
+    last_activity: Instant,
+    idle: bool,
+}
+
+impl IdleTracker {
+    /// Go idle after `timeout` without input, counting from now.
+    pub fn new(timeout: Duration) -> Self {
+        Self::starting_at(timeout, Instant::now())
+    }
+
+    /// Go idle after `timeout` without input, counting from `now`.
+    pub fn starting_at(timeout: Duration, now: Instant) -> Self {
+        Self {
+            timeout,
+            last_activity: now,
+            idle: false,
+        }
+    }
+
+    /// The idle timeout.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Whether the user is idle.
+    pub fn is_idle(&self) -> bool {
+        self.idle
+    }
+
+    /// How long since the last input.
+    pub fn idle_for(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.last_activity)
+    }
+
+    /// Record input at `now`. Returns true if this woke the tracker from
+    /// idle.
+    pub fn activity(&mut self, now: Instant) -> bool {
+        self.last_activity = now;
+        std::mem::replace(&mut self.idle, false)
+    }
+
+    /// Update the idle state. Returns true if the tracker just went idle.
+    pub fn check(&mut self, now: Instant) -> bool {
+        if self.idle || self.idle_for(now) < self.timeout {
+            return false;
+        }
+        self.idle = true;
+        true
+    }
+
+    /// Record input and report the resulting change, if any.
+    pub(crate) fn activity_event(&mut self, now: Instant) -> Option<IdleEvent> {
+        self.activity(now).then_some(IdleEvent::Active)
+    }
+
+    /// Check the timeout and report the resulting change, if any.
+    pub(crate) fn check_event(&mut self, now: Instant) -> Option<IdleEvent> {
+        self.check(now).then_some(IdleEvent::Idle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idle_tracker_goes_idle_and_wakes() {
+        let start = Instant::now();
+        let secs = Duration::from_secs;
+        let mut idle = IdleTracker::starting_at(secs(60), start);
+
+        assert!(!idle.check(start + secs(59)));
+        assert!(idle.check(start + secs(60)));
+        assert!(idle.is_idle());
+        // Reported once
+        assert!(!idle.check(start + secs(90)));
+        assert_eq!(idle.idle_for(start + secs(90)), secs(90));
+
+        assert_eq!(
+            idle.activity_event(start + secs(100)),
+            Some(IdleEvent::Active)
+        );
+        assert!(!idle.is_idle());
+        assert!(!idle.activity(start + secs(101)));
+        assert_eq!(idle.check_event(start + secs(150)), None);
+        assert_eq!(idle.check_event(start + secs(161)), Some(IdleEvent::Idle));
+    }
+}
@@ -1,36 +1,98 @@
 //! Input handling for interactive terminal applications.
 //!
 //! Provides key event types and an input reader that wraps crossterm.
-
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
-use std::time::Duration;
-
-/// A key press event.
+//!
+//! [`Key`] keeps everything crossterm reports: function keys up to F35,
+//! media keys, the Super/Hyper/Meta modifiers, keypad keys and, on
+//! terminals with the kitty keyboard protocol, repeats and releases.
+//! [`App`](crate::App) and [`ReactiveApp`](crate::reactive::ReactiveApp)
+//! turn the protocol on where the terminal supports it; repeats and
+//! releases are only reported with the config's `key_releases` set.
+//! [`KeyPattern`] parses bindings such as `"ctrl+s"`, `"super+f13"`,
+//! `"keypad+enter"` or `"media-playpause"`.
+
+use crate::chords::{ChordMatch, ChordState, KeySequence};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MediaKeyCode,
+    MouseButton, MouseEvent, MouseEventKind,
+};
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// A key event.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Key {
     pub code: KeyCode,
     pub modifiers: KeyModifiers,
+    /// Press, repeat or release. Terminals without the kitty keyboard
+    /// protocol only report presses.
+    pub kind: KeyEventKind,
+    /// Extra state such as [`KeyEventState::KEYPAD`] for keypad keys.
+    pub state: KeyEventState,
 }
 
 impl Key {
     pub fn new(code: KeyCode) -> Self {
-        Self {
-            code,
-            modifiers: KeyModifiers::NONE,
-        }
+        Self::with_modifiers(code, KeyModifiers::NONE)
     }
 
     pub fn with_ctrl(code: KeyCode) -> Self {
-        Self {
-            code,
-            modifiers: KeyModifiers::CONTROL,
-        }
+        Self::with_modifiers(code, KeyModifiers::CONTROL)
     }
 
     pub fn with_alt(code: KeyCode) -> Self {
+        Self::with_modifiers(code, KeyModifiers::ALT)
+    }
+
+    /// A key press with the given modifiers.
+    pub fn with_modifiers(code: KeyCode, modifiers: KeyModifiers) -> Self {
         Self {
             code,
-            modifiers: KeyModifiers::ALT,
+            modifiers,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }
+    }
+
+    /// Check if this is a press (not a repeat or release)
+    pub fn is_press(&self) -> bool {
+        self.kind == KeyEventKind::Press
+    }
+
+    /// Check if this is an auto-repeat of a held key
+    pub fn is_repeat(&self) -> bool {
+        self.kind == KeyEventKind::Repeat
+    }
+
+    /// Check if this is a key release
+    pub fn is_release(&self) -> bool {
+        self.kind == KeyEventKind::Release
+    }
+
+    /// Check if the key is on the numeric keypad
+    pub fn is_keypad(&self) -> bool {
+        self.state.contains(KeyEventState::KEYPAD)
+    }
+
+    /// Check if the Super (Cmd/Windows) modifier is held
+    pub fn has_super(&self) -> bool {
+        self.modifiers.contains(KeyModifiers::SUPER)
+    }
+
+    /// Get the number if this is a function key (F1 to F35)
+    pub fn function_key(&self) -> Option<u8> {
+        match self.code {
+            KeyCode::F(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Get the media key if this is one (play, volume, ...)
+    pub fn media_key(&self) -> Option<MediaKeyCode> {
+        match self.code {
+            KeyCode::Media(media) => Some(media),
+            _ => None,
         }
     }
 
@@ -87,6 +149,251 @@ impl From<KeyEvent> for Key {
         Self {
             code: event.code,
             modifiers: event.modifiers,
+            kind: event.kind,
+            state: event.state,
+        }
+    }
+}
+
+/// Error from parsing a [`KeyPattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyPatternError(pub(crate) String);
+
+impl fmt::Display for KeyPatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for KeyPatternError {}
+
+/// A key binding such as `"ctrl+s"`, parsed from text.
+///
+/// The syntax is modifiers joined with `+`, then the key:
+///
+/// - Modifiers: `ctrl`, `alt`, `shift`, `super` (`cmd`, `win`), `hyper`,
+///   `meta`, and `keypad` for keys on the numeric keypad.
+/// - Keys: a single character (`a`, `?`, `+`), `enter`, `esc`, `tab`,
+///   `backtab`, `backspace`, `delete`, `insert`, `home`, `end`, `pageup`,
+///   `pagedown`, `up`, `down`, `left`, `right`, `space`, `f1` to `f35`,
+///   `capslock`, `scrolllock`, `numlock`, `printscreen`, `pause`, `menu`,
+///   and media keys `media-play`, `media-pause`, `media-playpause`,
+///   `media-stop`, `media-next`, `media-prev`, `media-record`,
+///   `volume-up`, `volume-down` and `mute`.
+///
+/// Names are case-insensitive, except that an uppercase letter implies
+/// Shift: `"A"` and `"shift+a"` are the same binding. Patterns match
+/// presses and repeats, never releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyPattern {
+    /// The key.
+    pub code: KeyCode,
+    /// Modifiers that must be held (and no others).
+    pub modifiers: KeyModifiers,
+    /// Whether the key must come from the keypad.
+    pub keypad: bool,
+}
+
+impl KeyPattern {
+    /// A pattern for `code` with `modifiers`.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            code,
+            modifiers,
+            keypad: false,
+        }
+    }
+
+    /// Whether `key` is this binding.
+    pub fn matches(&self, key: &Key) -> bool {
+        if key.is_release() || (self.keypad && !key.is_keypad()) {
+            return false;
+        }
+        normalize(self.code, self.modifiers) == normalize(key.code, key.modifiers)
+    }
+
+    /// Whether `other` is the same binding spelled differently, such as
+    /// `"A"` and `"shift+a"`.
+    pub fn same_binding(&self, other: &KeyPattern) -> bool {
+        self.keypad == other.keypad
+            && normalize(self.code, self.modifiers) == normalize(other.code, other.modifiers)
+    }
+}
+
+/// Canonical form for comparing bindings: Shift is folded into the case of
+/// letters, dropped from other characters (it's already in the symbol) and
+/// Shift+Tab becomes BackTab.
+fn normalize(code: KeyCode, modifiers: KeyModifiers) -> (KeyCode, KeyModifiers) {
+    match code {
+        KeyCode::Char(c) if c.is_alphabetic() => {
+            let shifted = modifiers.contains(KeyModifiers::SHIFT) || c.is_uppercase();
+            let modifiers = if shifted {
+                modifiers | KeyModifiers::SHIFT
+            } else {
+                modifiers
+            };
+            (
+                KeyCode::Char(c.to_lowercase().next().unwrap_or(c)),
+                modifiers,
+            )
+        }
+        KeyCode::Char(c) => (KeyCode::Char(c), modifiers - KeyModifiers::SHIFT),
+        KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => {
+            (KeyCode::BackTab, modifiers - KeyModifiers::SHIFT)
+        }
+        KeyCode::BackTab => (KeyCode::BackTab, modifiers - KeyModifiers::SHIFT),
+        _ => (code, modifiers),
+    }
+}
+
+/// Named keys and their canonical spelling, used both ways.
+const KEY_NAMES: &[(&str, KeyCode)] = &[
+    ("enter", KeyCode::Enter),
+    ("esc", KeyCode::Esc),
+    ("tab", KeyCode::Tab),
+    ("backtab", KeyCode::BackTab),
+    ("backspace", KeyCode::Backspace),
+    ("delete", KeyCode::Delete),
+    ("insert", KeyCode::Insert),
+    ("home", KeyCode::Home),
+    ("end", KeyCode::End),
+    ("pageup", KeyCode::PageUp),
+    ("pagedown", KeyCode::PageDown),
+    ("up", KeyCode::Up),
+    ("down", KeyCode::Down),
+    ("left", KeyCode::Left),
+    ("right", KeyCode::Right),
+    ("space", KeyCode::Char(' ')),
+    ("capslock", KeyCode::CapsLock),
+    ("scrolllock", KeyCode::ScrollLock),
+    ("numlock", KeyCode::NumLock),
+    ("printscreen", KeyCode::PrintScreen),
+    ("pause", KeyCode::Pause),
+    ("menu", KeyCode::Menu),
+    ("media-play", KeyCode::Media(MediaKeyCode::Play)),
+    ("media-pause", KeyCode::Media(MediaKeyCode::Pause)),
+    ("media-playpause", KeyCode::Media(MediaKeyCode::PlayPause)),
+    ("media-stop", KeyCode::Media(MediaKeyCode::Stop)),
+    ("media-next", KeyCode::Media(MediaKeyCode::TrackNext)),
+    ("media-prev", KeyCode::Media(MediaKeyCode::TrackPrevious)),
+    ("media-record", KeyCode::Media(MediaKeyCode::Record)),
+    ("media-forward", KeyCode::Media(MediaKeyCode::FastForward)),
+    ("media-rewind", KeyCode::Media(MediaKeyCode::Rewind)),
+    ("volume-up", KeyCode::Media(MediaKeyCode::RaiseVolume)),
+    ("volume-down", KeyCode::Media(MediaKeyCode::LowerVolume)),
+    ("mute", KeyCode::Media(MediaKeyCode::MuteVolume)),
+];
+
+/// Other accepted spellings.
+const KEY_ALIASES: &[(&str, KeyCode)] = &[
+    ("return", KeyCode::Enter),
+    ("escape", KeyCode::Esc),
+    ("bs", KeyCode::Backspace),
+    ("del", KeyCode::Delete),
+    ("ins", KeyCode::Insert),
+    ("pgup", KeyCode::PageUp),
+    ("pgdn", KeyCode::PageDown),
+    ("pgdown", KeyCode::PageDown),
+];
+
+/// Modifier names in display order.
+const MODIFIER_NAMES: &[(&str, KeyModifiers)] = &[
+    ("ctrl", KeyModifiers::CONTROL),
+    ("alt", KeyModifiers::ALT),
+    ("shift", KeyModifiers::SHIFT),
+    ("super", KeyModifiers::SUPER),
+    ("hyper", KeyModifiers::HYPER),
+    ("meta", KeyModifiers::META),
+];
+
+fn parse_key_name(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+    let lower = name.to_ascii_lowercase();
+    if let Some(n) = lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+        return (1..=35).contains(&n).then_some(KeyCode::F(n));
+    }
+    KEY_NAMES
+        .iter()
+        .chain(KEY_ALIASES)
+        .find(|(n, _)| *n == lower)
+        .map(|(_, code)| *code)
+}
+
+impl FromStr for KeyPattern {
+    type Err = KeyPatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        // A trailing "+" after a separator is the plus key itself
+        let (mods, key) = match s.strip_suffix("++") {
+            Some(mods) => (mods, "+"),
+            None if s == "+" => ("", "+"),
+            None => match s.rsplit_once('+') {
+                Some((mods, key)) => (mods, key),
+                None => ("", s),
+            },
+        };
+        if key.is_empty() {
+            return Err(KeyPatternError(format!("missing key in {s:?}")));
+        }
+
+        let mut pattern = KeyPattern::new(
+            parse_key_name(key)
+                .ok_or_else(|| KeyPatternError(format!("unknown key {key:?} in {s:?}")))?,
+            KeyModifiers::NONE,
+        );
+        for name in mods.split('+').filter(|m| !m.is_empty()) {
+            match name.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => pattern.modifiers |= KeyModifiers::CONTROL,
+                "alt" | "opt" | "option" => pattern.modifiers |= KeyModifiers::ALT,
+                "shift" => pattern.modifiers |= KeyModifiers::SHIFT,
+                "super" | "cmd" | "win" => pattern.modifiers |= KeyModifiers::SUPER,
+                "hyper" => pattern.modifiers |= KeyModifiers::HYPER,
+                "meta" => pattern.modifiers |= KeyModifiers::META,
+                "keypad" | "kp" => pattern.keypad = true,
+                _ => {
+                    return Err(KeyPatternError(format!(
+                        "unknown modifier {name:?} in {s:?}"
+                    )))
+                }
+            }
+        }
+        Ok(pattern)
+    }
+}
+
+impl fmt::Display for KeyPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (code, modifiers) = normalize(self.code, self.modifiers);
+        for (name, modifier) in MODIFIER_NAMES {
+            if modifiers.contains(*modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+        if self.keypad {
+            f.write_str("keypad+")?;
+        }
+        match code {
+            KeyCode::Char(' ') => f.write_str("space"),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::F(n) => write!(f, "f{n}"),
+            code => match KEY_NAMES.iter().find(|(_, c)| *c == code) {
+                Some((name, _)) => f.write_str(name),
+                None => write!(f, "{code:?}"),
+            },
+        }
+    }
+}
+
+impl From<&Key> for KeyPattern {
+    fn from(key: &Key) -> Self {
+        Self {
+            code: key.code,
+            modifiers: key.modifiers,
+            keypad: key.is_keypad(),
         }
     }
 }
@@ -102,6 +409,91 @@ pub fn poll_key(timeout: Duration) -> std::io::Result<Option<Key>> {
     Ok(None)
 }
 
+/// A mouse event. Terminals only report these while mouse capture is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mouse {
+    /// Press, release, drag, move or scroll.
+    pub kind: MouseEventKind,
+    /// The cell column, from 0 at the left of the terminal.
+    pub column: u16,
+    /// The cell row, from 0 at the top of the terminal.
+    pub row: u16,
+    /// Modifiers held during the event.
+    pub modifiers: KeyModifiers,
+}
+
+impl Mouse {
+    /// A mouse event at (`column`, `row`) with no modifiers.
+    pub fn new(kind: MouseEventKind, column: u16, row: u16) -> Self {
+        Self {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    /// The cell as (column, row).
+    pub fn position(&self) -> (u16, u16) {
+        (self.column, self.row)
+    }
+
+    /// The button pressed, if this is a press.
+    pub fn pressed(&self) -> Option<MouseButton> {
+        match self.kind {
+            MouseEventKind::Down(button) => Some(button),
+            _ => None,
+        }
+    }
+}
+
+impl From<MouseEvent> for Mouse {
+    fn from(event: MouseEvent) -> Self {
+        Self {
+            kind: event.kind,
+            column: event.column,
+            row: event.row,
+            modifiers: event.modifiers,
+        }
+    }
+}
+
+/// A key press, mouse event, paste, or change of terminal size or focus,
+/// as seen by the app loops.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputEvent {
+    /// A key event.
+    Key(Key),
+    /// A mouse event.
+    Mouse(Mouse),
+    /// The terminal was resized to (columns, rows).
+    Resize(u16, u16),
+    /// The terminal window gained (true) or lost (false) focus. Only
+    /// reported while focus reporting is on (see
+    /// [`Blaeck::set_focus_reporting`](crate::Blaeck::set_focus_reporting)).
+    Focus(bool),
+    /// Text was pasted. Only reported while bracketed paste is on (see
+    /// [`Blaeck::set_bracketed_paste`](crate::Blaeck::set_bracketed_paste));
+    /// otherwise the terminal types the text as keys.
+    Paste(String),
+}
+
+/// Polls for a key, mouse, resize, focus or paste event with a timeout.
+/// Returns None on timeout.
+pub fn poll_event(timeout: Duration) -> std::io::Result<Option<InputEvent>> {
+    if event::poll(timeout)? {
+        match event::read()? {
+            Event::Key(key_event) => return Ok(Some(InputEvent::Key(Key::from(key_event)))),
+            Event::Mouse(mouse) => return Ok(Some(InputEvent::Mouse(Mouse::from(mouse)))),
+            Event::Resize(width, height) => return Ok(Some(InputEvent::Resize(width, height))),
+            Event::FocusGained => return Ok(Some(InputEvent::Focus(true))),
+            Event::FocusLost => return Ok(Some(InputEvent::Focus(false))),
+            Event::Paste(text) => return Ok(Some(InputEvent::Paste(text))),
+        }
+    }
+    Ok(None)
+}
+
 /// Blocks until a key is pressed.
 pub fn read_key() -> std::io::Result<Key> {
     loop {
@@ -125,6 +517,19 @@ pub enum Arrow {
     Right,
 }
 
+impl Arrow {
+    /// The direction of an arrow key code, including keypad arrows.
+    pub fn from_code(code: KeyCode) -> Option<Self> {
+        match code {
+            KeyCode::Up => Some(Arrow::Up),
+            KeyCode::Down => Some(Arrow::Down),
+            KeyCode::Left => Some(Arrow::Left),
+            KeyCode::Right => Some(Arrow::Right),
+            _ => None,
+        }
+    }
+}
+
 /// Builder for matching keys and executing handlers.
 pub struct KeyMatcher<'a, T> {
     key: &'a Key,
@@ -207,14 +612,7 @@ impl<'a, T> KeyMatcher<'a, T> {
         F: FnOnce(&mut T, Arrow),
     {
         if !self.handled {
-            let arrow = match self.key.code {
-                KeyCode::Up => Some(Arrow::Up),
-                KeyCode::Down => Some(Arrow::Down),
-                KeyCode::Left => Some(Arrow::Left),
-                KeyCode::Right => Some(Arrow::Right),
-                _ => None,
-            };
-            if let Some(dir) = arrow {
+            if let Some(dir) = Arrow::from_code(self.key.code) {
                 f(self.state, dir);
                 self.handled = true;
             }
@@ -222,6 +620,81 @@ impl<'a, T> KeyMatcher<'a, T> {
         self
     }
 
+    /// Execute handler if the key matches a [`KeyPattern`] such as
+    /// `"ctrl+s"`. Panics if the pattern doesn't parse.
+    pub fn on<F>(mut self, pattern: &str, f: F) -> Self
+    where
+        F: FnOnce(&mut T),
+    {
+        let pattern: KeyPattern = pattern
+            .parse()
+            .unwrap_or_else(|e| panic!("invalid key pattern: {e}"));
+        if !self.handled && pattern.matches(self.key) {
+            f(self.state);
+            self.handled = true;
+        }
+        self
+    }
+
+    /// Execute handler when the key completes one of `sequences` (such as
+    /// `"g g"` or `"leader f"`), with the index of the sequence.
+    ///
+    /// Keys that start or continue a sequence are consumed; see
+    /// [`crate::chords`]. Panics if a sequence doesn't parse.
+    pub fn on_sequences<F>(mut self, chords: &mut ChordState, sequences: &[&str], f: F) -> Self
+    where
+        F: FnOnce(&mut T, usize),
+    {
+        if self.handled {
+            return self;
+        }
+        let sequences: Vec<KeySequence> = sequences
+            .iter()
+            .map(|s| {
+                chords
+                    .sequence(s)
+                    .unwrap_or_else(|e| panic!("invalid key sequence: {e}"))
+            })
+            .collect();
+        match chords.feed(self.key, Instant::now(), &sequences) {
+            ChordMatch::Matched(i) => {
+                f(self.state, i);
+                self.handled = true;
+            }
+            ChordMatch::Pending => self.handled = true,
+            ChordMatch::NoMatch => {}
+        }
+        self
+    }
+
+    /// Execute handler if the key is a function key, with its number.
+    pub fn on_function<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut T, u8),
+    {
+        if !self.handled {
+            if let Some(n) = self.key.function_key() {
+                f(self.state, n);
+                self.handled = true;
+            }
+        }
+        self
+    }
+
+    /// Execute handler if the key is a media key.
+    pub fn on_media<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut T, MediaKeyCode),
+    {
+        if !self.handled {
+            if let Some(media) = self.key.media_key() {
+                f(self.state, media);
+                self.handled = true;
+            }
+        }
+        self
+    }
+
     /// Execute handler for any unhandled key.
     pub fn otherwise<F>(mut self, f: F) -> Self
     where
@@ -278,10 +751,7 @@ mod tests {
 
     #[test]
     fn test_key_is_backtab() {
-        let key = Key {
-            code: KeyCode::BackTab,
-            modifiers: KeyModifiers::SHIFT,
-        };
+        let key = Key::with_modifiers(KeyCode::BackTab, KeyModifiers::SHIFT);
         assert!(key.is_backtab());
     }
 
@@ -299,6 +769,99 @@ mod tests {
         assert!(key.is_enter());
     }
 
+    #[test]
+    fn test_key_from_extended_event() {
+        let mut event = crossterm::event::KeyEvent::new(KeyCode::F(13), KeyModifiers::SUPER);
+        event.kind = KeyEventKind::Release;
+        event.state = KeyEventState::KEYPAD;
+        let key = Key::from(event);
+        assert_eq!(key.function_key(), Some(13));
+        assert!(key.has_super());
+        assert!(key.is_release());
+        assert!(key.is_keypad());
+    }
+
+    #[test]
+    fn test_key_pattern_parse_and_match() {
+        let parse = |s: &str| s.parse::<KeyPattern>().unwrap();
+        assert!(parse("ctrl+s").matches(&Key::with_ctrl(KeyCode::Char('s'))));
+        assert!(!parse("ctrl+s").matches(&Key::new(KeyCode::Char('s'))));
+        assert!(parse("A").matches(&Key::with_modifiers(
+            KeyCode::Char('A'),
+            KeyModifiers::SHIFT
+        )));
+        assert!(parse("shift+a").matches(&Key::new(KeyCode::Char('A'))));
+        assert!(parse("?").matches(&Key::with_modifiers(
+            KeyCode::Char('?'),
+            KeyModifiers::SHIFT
+        )));
+        assert!(
+            parse("shift+tab").matches(&Key::with_modifiers(KeyCode::BackTab, KeyModifiers::SHIFT))
+        );
+        assert!(
+            parse("Super+F13").matches(&Key::with_modifiers(KeyCode::F(13), KeyModifiers::SUPER))
+        );
+        assert!(
+            parse("media-playpause").matches(&Key::new(KeyCode::Media(MediaKeyCode::PlayPause)))
+        );
+        assert_eq!(parse("ctrl++").code, KeyCode::Char('+'));
+
+        let mut enter = Key::new(KeyCode::Enter);
+        assert!(!parse("keypad+enter").matches(&enter));
+        enter.state = KeyEventState::KEYPAD;
+        assert!(parse("keypad+enter").matches(&enter));
+        assert!(parse("enter").matches(&enter));
+        enter.kind = KeyEventKind::Release;
+        assert!(!parse("enter").matches(&enter));
+
+        assert!("ctrl+nope".parse::<KeyPattern>().is_err());
+        assert!("hold+a".parse::<KeyPattern>().is_err());
+        assert!("f36".parse::<KeyPattern>().is_err());
+    }
+
+    #[test]
+    fn test_key_pattern_display_round_trips() {
+        for s in [
+            "ctrl+alt+delete",
+            "shift+a",
+            "super+f13",
+            "keypad+enter",
+            "space",
+            "volume-up",
+        ] {
+            let pattern: KeyPattern = s.parse().unwrap();
+            assert_eq!(pattern.to_string(), s);
+        }
+        assert_eq!("Return".parse::<KeyPattern>().unwrap().to_string(), "enter");
+    }
+
+    #[test]
+    fn test_key_matcher_extended() {
+        let mut hit = None;
+        match_key(&Key::new(KeyCode::F(20)), &mut hit)
+            .on("ctrl+f20", |h| *h = Some(0))
+            .on_function(|h, n| *h = Some(n));
+        assert_eq!(hit, Some(20));
+
+        let mut hit = false;
+        match_key(&Key::with_ctrl(KeyCode::Char('s')), &mut hit).on("ctrl+s", |h| *h = true);
+        assert!(hit);
+    }
+
+    #[test]
+    fn test_key_matcher_on_sequences() {
+        let mut chords = ChordState::new().leader("space").unwrap();
+        let mut hits = Vec::new();
+        for c in ['g', 'g', 'j', ' ', 'f'] {
+            match_key(&Key::new(KeyCode::Char(c)), &mut hits)
+                .on_sequences(&mut chords, &["g g", "leader f"], |h, i| h.push(i))
+                .on_char('j', |h| h.push(9))
+                .on_char('g', |h| h.push(99));
+        }
+        assert_eq!(hits, [0, 9, 1]);
+        assert!(!chords.is_pending());
+    }
+
     #[test]
     fn test_key_matcher_on_char() {
         let key = Key::new(KeyCode::Char('q'));
@@ -386,10 +949,7 @@ mod tests {
 
     #[test]
     fn test_key_matcher_on_backtab() {
-        let key = Key {
-            code: KeyCode::BackTab,
-            modifiers: KeyModifiers::SHIFT,
-        };
+        let key = Key::with_modifiers(KeyCode::BackTab, KeyModifiers::SHIFT);
         let mut called = false;
         match_key(&key, &mut called).on_backtab(|c| *c = true);
         assert!(called);
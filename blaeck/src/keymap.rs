@@ -0,0 +1,618 @@
+//! Named actions bound to keys, per context, loadable from a config file.
+//!
+//! A [`Keymap`] maps key sequences to action names such as `"save"` or
+//! `"goto-top"`. Each binding belongs to a [`KeyContext`]: the global map,
+//! the map of a focused widget, or the map of a modal. Instead of a
+//! `match key` block per screen, the app asks the keymap which action a key
+//! means in the active contexts and matches on the name:
+//!
+//! ```ignore
+//! let mut keymap = Keymap::new();
+//! keymap.bind(KeyContext::Global, "ctrl+q", "quit")?;
+//! keymap.bind(KeyContext::widget("list"), "g g", "goto-top")?;
+//! keymap.bind(KeyContext::modal("confirm"), "y", "confirm")?;
+//! keymap.describe("goto-top", "first item");
+//!
+//! // Let users rebind keys
+//! keymap.load_file(config_dir.join("keys.conf"))?;
+//!
+//! match keymap.dispatch(&key, &[KeyContext::widget("list")]) {
+//!     KeymapMatch::Action(action) => match action.as_str() {
+//!         "quit" => app.exit(),
+//!         "goto-top" => list.select(0),
+//!         _ => {}
+//!     },
+//!     KeymapMatch::Pending | KeymapMatch::Unbound => {}
+//! }
+//! ```
+//!
+//! Contexts passed to [`Keymap::dispatch`] are tried in order, then the
+//! global map. A modal context hides everything after it, global included,
+//! so an open dialog gets every key. Multi-key sequences work as described
+//! in [`crate::chords`].
+//!
+//! ## Config files
+//!
+//! A config has a `[section]` per context (`[global]`, `[widget.list]`,
+//! `[modal.confirm]`) followed by `keys = action` lines. Lines starting
+//! with `#` are comments:
+//!
+//! ```text
+//! [global]
+//! ctrl+q = quit
+//!
+//! [widget.list]
+//! # vim-style
+//! g g = goto-top
+//! shift+g = goto-bottom
+//! d d = none
+//! ```
+//!
+//! The first line for an action in a section replaces that action's
+//! default keys there; further lines add more keys. Binding keys to `none`
+//! unbinds them. The whole file is checked before anything changes.
+
+use crate::chords::{ChordMatch, ChordState, KeySequence};
+use crate::components::{KeyBindings, KeyHint, PendingKeysProps};
+use crate::input::{Key, KeyPatternError};
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Where a binding applies.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum KeyContext {
+    /// Everywhere, after more specific contexts.
+    Global,
+    /// While the named widget has focus.
+    Widget(String),
+    /// While the named modal is open; hides the contexts after it.
+    Modal(String),
+}
+
+impl KeyContext {
+    /// The context of a focused widget.
+    pub fn widget(name: impl Into<String>) -> Self {
+        KeyContext::Widget(name.into())
+    }
+
+    /// The context of an open modal.
+    pub fn modal(name: impl Into<String>) -> Self {
+        KeyContext::Modal(name.into())
+    }
+
+    /// Parse a config section name: `global`, `widget.<name>` or
+    /// `modal.<name>`.
+    pub fn parse(section: &str) -> Option<Self> {
+        match section.trim().split_once('.') {
+            None if section.trim() == "global" => Some(KeyContext::Global),
+            Some(("widget", name)) if !name.is_empty() => Some(KeyContext::widget(name)),
+            Some(("modal", name)) if !name.is_empty() => Some(KeyContext::modal(name)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for KeyContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyContext::Global => f.write_str("global"),
+            KeyContext::Widget(name) => write!(f, "widget.{name}"),
+            KeyContext::Modal(name) => write!(f, "modal.{name}"),
+        }
+    }
+}
+
+/// A key sequence bound to an action in a context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBinding {
+    /// Where the binding applies.
+    pub context: KeyContext,
+    /// The keys.
+    pub sequence: KeySequence,
+    /// The action name.
+    pub action: String,
+}
+
+/// What a key means in the active contexts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeymapMatch {
+    /// The key completed a binding for this action.
+    Action(String),
+    /// The key started or continued a sequence; wait for more keys.
+    Pending,
+    /// The key isn't bound.
+    Unbound,
+}
+
+/// Two bindings that get in each other's way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeymapConflict {
+    /// A binding replaced another for the same keys.
+    Replaced {
+        /// Context of both bindings.
+        context: KeyContext,
+        /// The keys.
+        sequence: KeySequence,
+        /// Action that lost the keys.
+        replaced: String,
+        /// Action that now owns them.
+        action: String,
+    },
+    /// A sequence is the start of a longer one, so the longer one can never
+    /// complete.
+    Shadowed {
+        /// Context of both bindings.
+        context: KeyContext,
+        /// The shorter sequence.
+        prefix: KeySequence,
+        /// Action of the shorter sequence.
+        prefix_action: String,
+        /// The sequence that can't complete.
+        sequence: KeySequence,
+        /// Action of that sequence.
+        action: String,
+    },
+}
+
+impl fmt::Display for KeymapConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeymapConflict::Replaced {
+                context,
+                sequence,
+                replaced,
+                action,
+            } => write!(f, "[{context}] {sequence} ({action}) replaces {replaced}"),
+            KeymapConflict::Shadowed {
+                context,
+                prefix,
+                prefix_action,
+                sequence,
+                action,
+            } => write!(
+                f,
+                "[{context}] {prefix} ({prefix_action}) shadows {sequence} ({action})"
+            ),
+        }
+    }
+}
+
+/// Why a keymap config couldn't be loaded.
+#[derive(Debug)]
+pub enum KeymapError {
+    /// Reading the file failed.
+    Io(io::Error),
+    /// A line isn't valid.
+    Parse {
+        /// 1-based line number.
+        line: usize,
+        /// What's wrong with it.
+        message: String,
+    },
+}
+
+impl fmt::Display for KeymapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeymapError::Io(e) => write!(f, "reading keymap: {e}"),
+            KeymapError::Parse { line, message } => write!(f, "line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for KeymapError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            KeymapError::Io(e) => Some(e),
+            KeymapError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for KeymapError {
+    fn from(e: io::Error) -> Self {
+        KeymapError::Io(e)
+    }
+}
+
+/// Key bindings for named actions, per context.
+#[derive(Debug, Clone, Default)]
+pub struct Keymap {
+    bindings: Vec<KeyBinding>,
+    descriptions: HashMap<String, String>,
+    chords: ChordState,
+    replaced: Vec<KeymapConflict>,
+}
+
+impl Keymap {
+    /// Create an empty keymap.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the key that `leader` stands for in sequences (e.g. `"space"`).
+    pub fn leader(mut self, pattern: &str) -> Result<Self, KeyPatternError> {
+        self.chords = self.chords.leader(pattern)?;
+        Ok(self)
+    }
+
+    /// Set the time allowed between the keys of a sequence.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.chords = self.chords.timeout(timeout);
+        self
+    }
+
+    /// Bind `keys` (e.g. `"ctrl+s"` or `"g g"`) to `action` in `context`.
+    ///
+    /// Returns the conflict if the keys were bound to another action there;
+    /// the new binding replaces the old one.
+    pub fn bind(
+        &mut self,
+        context: KeyContext,
+        keys: &str,
+        action: impl Into<String>,
+    ) -> Result<Option<KeymapConflict>, KeyPatternError> {
+        let sequence = self.chords.sequence(keys)?;
+        Ok(self.insert(KeyBinding {
+            context,
+            sequence,
+            action: action.into(),
+        }))
+    }
+
+    fn insert(&mut self, binding: KeyBinding) -> Option<KeymapConflict> {
+        let existing = self.bindings.iter().position(|b| {
+            b.context == binding.context && b.sequence.same_binding(&binding.sequence)
+        });
+        let old = existing.map(|i| self.bindings.remove(i));
+        self.bindings.push(binding.clone());
+        let old = old.filter(|old| old.action != binding.action)?;
+        let conflict = KeymapConflict::Replaced {
+            context: binding.context,
+            sequence: binding.sequence,
+            replaced: old.action,
+            action: binding.action,
+        };
+        self.replaced.push(conflict.clone());
+        Some(conflict)
+    }
+
+    /// Remove the binding for `keys` in `context`. Returns whether there
+    /// was one.
+    pub fn unbind(&mut self, context: &KeyContext, keys: &str) -> Result<bool, KeyPatternError> {
+        let sequence = self.chords.sequence(keys)?;
+        let before = self.bindings.len();
+        self.bindings
+            .retain(|b| !(b.context == *context && b.sequence.same_binding(&sequence)));
+        Ok(self.bindings.len() != before)
+    }
+
+    /// Remove every binding for `action` in `context`. Returns how many
+    /// were removed.
+    pub fn unbind_action(&mut self, context: &KeyContext, action: &str) -> usize {
+        let before = self.bindings.len();
+        self.bindings
+            .retain(|b| !(b.context == *context && b.action == action));
+        before - self.bindings.len()
+    }
+
+    /// Set the help text for `action` (defaults to the action name).
+    pub fn describe(&mut self, action: impl Into<String>, description: impl Into<String>) {
+        self.descriptions.insert(action.into(), description.into());
+    }
+
+    /// The help text for `action`.
+    pub fn description<'a>(&'a self, action: &'a str) -> &'a str {
+        self.descriptions
+            .get(action)
+            .map(String::as_str)
+            .unwrap_or(action)
+    }
+
+    /// Every binding, in the order it was made.
+    pub fn bindings(&self) -> &[KeyBinding] {
+        &self.bindings
+    }
+
+    /// The key sequences bound to `action` in `context`.
+    pub fn keys_for<'a>(
+        &'a self,
+        context: &'a KeyContext,
+        action: &'a str,
+    ) -> impl Iterator<Item = &'a KeySequence> + 'a {
+        self.bindings
+            .iter()
+            .filter(move |b| b.context == *context && b.action == action)
+            .map(|b| &b.sequence)
+    }
+
+    /// Replacements made so far, then every pair of bindings in the same
+    /// context where one sequence shadows a longer one.
+    pub fn conflicts(&self) -> Vec<KeymapConflict> {
+        let mut conflicts = self.replaced.clone();
+        for a in &self.bindings {
+            for b in &self.bindings {
+                if a.context == b.context
+                    && a.sequence.keys().len() < b.sequence.keys().len()
+                    && a.sequence.is_prefix_of(&b.sequence)
+                {
+                    conflicts.push(KeymapConflict::Shadowed {
+                        context: a.context.clone(),
+                        prefix: a.sequence.clone(),
+                        prefix_action: a.action.clone(),
+                        sequence: b.sequence.clone(),
+                        action: b.action.clone(),
+                    });
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// The contexts searched for `contexts`: those given, up to and
+    /// including the first modal, then global unless a modal was given.
+    fn search_order(contexts: &[KeyContext]) -> Vec<&KeyContext> {
+        let mut order = Vec::new();
+        for context in contexts {
+            order.push(context);
+            if matches!(context, KeyContext::Modal(_)) {
+                return order;
+            }
+        }
+        order.push(&KeyContext::Global);
+        order
+    }
+
+    /// Active bindings for `contexts`, most specific first.
+    fn active(&self, contexts: &[KeyContext]) -> Vec<&KeyBinding> {
+        Self::search_order(contexts)
+            .into_iter()
+            .flat_map(|context| self.bindings.iter().filter(move |b| b.context == *context))
+            .collect()
+    }
+
+    /// What `key` means with `contexts` active, most specific first.
+    pub fn dispatch(&mut self, key: &Key, contexts: &[KeyContext]) -> KeymapMatch {
+        self.dispatch_at(key, Instant::now(), contexts)
+    }
+
+    /// [`dispatch`](Self::dispatch) for a key pressed at `now`.
+    pub fn dispatch_at(&mut self, key: &Key, now: Instant, contexts: &[KeyContext]) -> KeymapMatch {
+        let active: Vec<KeyBinding> = self.active(contexts).into_iter().cloned().collect();
+        match self
+            .chords
+            .feed(key, now, active.iter().map(|b| &b.sequence))
+        {
+            ChordMatch::Matched(i) => KeymapMatch::Action(active[i].action.clone()),
+            ChordMatch::Pending => KeymapMatch::Pending,
+            ChordMatch::NoMatch => KeymapMatch::Unbound,
+        }
+    }
+
+    /// Drop a pending sequence once its timeout has passed. Returns whether
+    /// it was dropped, so the indicator can be hidden.
+    pub fn poll(&mut self, now: Instant) -> bool {
+        self.chords.poll(now)
+    }
+
+    /// Props for a [`PendingKeys`](crate::components::PendingKeys)
+    /// indicator showing the keys of a pending sequence.
+    pub fn pending_keys(&self) -> PendingKeysProps {
+        self.chords.indicator()
+    }
+
+    /// Key hints for the bindings active with `contexts`, most specific
+    /// first; keys shadowed by a more specific context are left out.
+    pub fn hints(&self, contexts: &[KeyContext]) -> Vec<KeyHint> {
+        let mut seen: Vec<&KeySequence> = Vec::new();
+        let mut hints = Vec::new();
+        for binding in self.active(contexts) {
+            if seen.iter().any(|s| s.same_binding(&binding.sequence)) {
+                continue;
+            }
+            seen.push(&binding.sequence);
+            hints.push(KeyHint::new(
+                binding.sequence.to_string(),
+                self.description(&binding.action),
+            ));
+        }
+        hints
+    }
+
+    /// Apply a config (see the [module docs](self)), returning the
+    /// conflicts it caused. Nothing changes if any line is invalid.
+    pub fn apply_config(&mut self, source: &str) -> Result<Vec<KeymapConflict>, KeymapError> {
+        let mut context = KeyContext::Global;
+        let mut lines = Vec::new();
+        for (i, line) in source.lines().enumerate() {
+            let parse_error = |message: String| KeymapError::Parse {
+                line: i + 1,
+                message,
+            };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                context = KeyContext::parse(section)
+                    .ok_or_else(|| parse_error(format!("unknown section [{section}]")))?;
+                continue;
+            }
+            let (keys, action) = line
+                .rsplit_once('=')
+                .ok_or_else(|| parse_error(format!("expected `keys = action`, got {line:?}")))?;
+            let (keys, action) = (keys.trim(), action.trim());
+            let sequence = self
+                .chords
+                .sequence(keys)
+                .map_err(|e| parse_error(e.to_string()))?;
+            if action.is_empty() {
+                return Err(parse_error(format!("missing action for {keys:?}")));
+            }
+            lines.push((context.clone(), sequence, action.to_string()));
+        }
+
+        let before = self.replaced.len();
+        let mut rebound: Vec<(KeyContext, String)> = Vec::new();
+        for (context, sequence, action) in lines {
+            if action == "none" {
+                self.bindings
+                    .retain(|b| !(b.context == context && b.sequence.same_binding(&sequence)));
+                continue;
+            }
+            let key = (context.clone(), action.clone());
+            if !rebound.contains(&key) {
+                self.unbind_action(&context, &action);
+                rebound.push(key);
+            }
+            self.insert(KeyBinding {
+                context,
+                sequence,
+                action,
+            });
+        }
+        Ok(self.replaced[before..].to_vec())
+    }
+
+    /// Read a config file and [apply](Self::apply_config) it.
+    pub fn load_file(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<Vec<KeymapConflict>, KeymapError> {
+        let source = std::fs::read_to_string(path)?;
+        self.apply_config(&source)
+    }
+}
+
+impl KeyBindings for Keymap {
+    fn key_hints(&self) -> Vec<KeyHint> {
+        self.hints(&[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyCode;
+
+    fn key(c: char) -> Key {
+        Key::new(KeyCode::Char(c))
+    }
+
+    fn keymap() -> Keymap {
+        let mut keymap = Keymap::new();
+        keymap.bind(KeyContext::Global, "q", "quit").unwrap();
+        keymap.bind(KeyContext::Global, "g g", "top").unwrap();
+        keymap
+            .bind(KeyContext::widget("list"), "d d", "delete")
+            .unwrap();
+        keymap
+            .bind(KeyContext::widget("list"), "q", "close")
+            .unwrap();
+        keymap
+            .bind(KeyContext::modal("confirm"), "y", "yes")
+            .unwrap();
+        keymap
+    }
+
+    #[test]
+    fn test_keymap_dispatch_by_context() {
+        let mut keymap = keymap();
+        let action = |a: &str| KeymapMatch::Action(a.into());
+        let list = [KeyContext::widget("list")];
+        let modal = [KeyContext::modal("confirm"), KeyContext::widget("list")];
+
+        assert_eq!(keymap.dispatch(&key('q'), &[]), action("quit"));
+        assert_eq!(keymap.dispatch(&key('q'), &list), action("close"));
+        assert_eq!(keymap.dispatch(&key('d'), &list), KeymapMatch::Pending);
+        assert_eq!(keymap.pending_keys().keys, ["d"]);
+        assert_eq!(keymap.dispatch(&key('d'), &list), action("delete"));
+        assert_eq!(keymap.dispatch(&key('g'), &list), KeymapMatch::Pending);
+        assert_eq!(keymap.dispatch(&key('g'), &list), action("top"));
+
+        // A modal hides the widget and global maps
+        assert_eq!(keymap.dispatch(&key('q'), &modal), KeymapMatch::Unbound);
+        assert_eq!(keymap.dispatch(&key('y'), &modal), action("yes"));
+
+        keymap.describe("close", "close list");
+        let hints = keymap.hints(&list);
+        let keys: Vec<_> = hints
+            .iter()
+            .map(|h| (h.key.as_str(), h.action.as_str()))
+            .collect();
+        assert_eq!(
+            keys,
+            [("d d", "delete"), ("q", "close list"), ("g g", "top")]
+        );
+    }
+
+    #[test]
+    fn test_keymap_conflicts() {
+        let mut keymap = keymap();
+        let conflict = keymap.bind(KeyContext::Global, "Q", "quit").unwrap();
+        assert_eq!(conflict, None);
+        let conflict = keymap
+            .bind(KeyContext::Global, "shift+q", "query")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            conflict.to_string(),
+            "[global] shift+q (query) replaces quit"
+        );
+
+        keymap.bind(KeyContext::Global, "g", "go").unwrap();
+        let conflicts = keymap.conflicts();
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(
+            conflicts[1].to_string(),
+            "[global] g (go) shadows g g (top)"
+        );
+    }
+
+    #[test]
+    fn test_keymap_apply_config() {
+        let mut keymap = keymap();
+        let conflicts = keymap
+            .apply_config(
+                "# user keys\n\
+                 [global]\n\
+                 ctrl+q = quit\n\
+                 x = quit\n\
+                 \n\
+                 [widget.list]\n\
+                 d d = none\n\
+                 x = close\n",
+            )
+            .unwrap();
+        assert!(conflicts.is_empty());
+
+        let list = [KeyContext::widget("list")];
+        let global = KeyContext::Global;
+        let quit: Vec<_> = keymap
+            .keys_for(&global, "quit")
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(quit, ["ctrl+q", "x"]);
+        assert_eq!(keymap.dispatch(&key('q'), &[]), KeymapMatch::Unbound);
+        assert_eq!(keymap.dispatch(&key('d'), &list), KeymapMatch::Unbound);
+        assert_eq!(
+            keymap.dispatch(&key('x'), &list),
+            KeymapMatch::Action("close".into())
+        );
+
+        let err = keymap
+            .apply_config("[global]\nq = quit\nhold+q = x")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"line 3: unknown modifier "hold" in "hold+q""#
+        );
+        assert!(keymap.apply_config("[panel]\n").is_err());
+        let quit: Vec<_> = keymap.keys_for(&global, "quit").collect();
+        assert_eq!(quit.len(), 2);
+    }
+}
@@ -443,6 +443,8 @@ pub struct LayoutResult {
     pub width: f32,
     /// Computed height
     pub height: f32,
+    /// Height including children that overflow the node (at least `height`)
+    pub content_height: f32,
 }
 
 impl LayoutTree {
@@ -490,6 +492,7 @@ impl LayoutTree {
             y: layout.location.y,
             width: layout.size.width,
             height: layout.size.height,
+            content_height: layout.size.height.max(layout.content_size.height),
         }
     }
 
@@ -130,81 +130,177 @@
 //!
 //! This provides [`async_runtime::AsyncApp`] for apps that need to integrate
 //! with async operations like D-Bus, HTTP requests, or other I/O.
+//!
+//! # Session Persistence
+//!
+//! Enable the `session` feature to persist UI state between launches:
+//!
+//! ```toml
+//! blaeck = { version = "0.1", features = ["session"] }
+//! ```
+//!
+//! This provides [`session::SessionStore`] and
+//! [`reactive::use_session_state`].
 
 pub mod animation;
 pub mod app;
 pub mod buffer;
+pub mod chords;
+pub mod clipboard;
 pub mod components;
+pub mod debounce;
 pub mod element;
+pub mod error;
+pub mod filter;
 pub mod focus;
+pub mod gestures;
+pub mod glyphs;
+pub mod graphics;
+pub mod hotkeys;
+pub mod idle;
 pub mod input;
+pub mod keymap;
 pub mod layout;
 pub mod log_update;
+pub mod metrics;
 pub mod output;
+pub mod overlay;
+pub mod pager;
+pub mod palette;
+pub mod patch;
+pub mod pipeline;
 pub mod reactive;
+pub mod regions;
 pub mod renderer;
+pub mod replay;
+pub mod resize;
+pub mod scaffold;
+pub mod selection;
+pub mod stream;
 pub mod style;
+pub mod task;
+pub mod tea;
 pub mod timeline;
+pub mod toast;
 
 #[cfg(feature = "async")]
 pub mod async_runtime;
 
+#[cfg(feature = "session")]
+pub mod session;
+pub mod truncation;
+
 pub use animation::{lerp_rgb, lerp_u8, AnimationTimer, BlinkPattern, Easing, IndicatorStyle};
-pub use app::{App, AppConfig, AppResult, ExitReason};
+pub use app::{
+    App, AppConfig, AppResult, ExitReason, GestureCallback, HotkeyAction, IdleCallback,
+    PasteCallback, ScrollCallback, TerminalFocusCallback,
+};
 pub use buffer::{Buffer, Cell};
+pub use chords::{ChordMatch, ChordState, KeySequence};
 pub use components::{
-    alert, animated_indicator, animated_indicator_colored, badge, badge_bracket, bar_chart,
-    bar_chart_with_values, blink, blink_or, blink_pattern, blinking_dot, breadcrumbs,
-    breadcrumbs_path, checkbox, confirm_modal, confirm_prompt, countdown,
+    alert, animated_indicator, animated_indicator_colored, badge, badge_bracket, badge_color,
+    bar_chart, bar_chart_with_values, blink, blink_or, blink_pattern, blinking_dot, breadcrumbs,
+    breadcrumbs_path, centered, checkbox, confirm_modal, confirm_prompt, countdown,
     countdown_with_thresholds, diff_lines, divider, divider_with_label, error_modal, flex_spacer,
-    git_branch, gradient, gradient_preset, icons, key_hints, link, link_url, log_box,
-    markdown_block, progress_bar, progress_bar_bracketed, pulsing_dot, spacer, sparkline,
-    sparkline_labeled, spinner_frame, spinner_frame_interval, status_error, status_ok,
-    status_warning, stopwatch, success_modal, syntax_highlight, syntax_highlight_with_lines,
-    timer_display, transforms, tree_view, Autocomplete, AutocompleteItem, AutocompleteProps,
-    AutocompleteState, Badge, BadgeProps, BadgeStyle, BarChart, BarChartProps, BarData, BarStyle,
-    BorderChars, BorderColors, BorderSides, BorderStyle, Box, BoxProps, BreadcrumbSeparator,
-    Breadcrumbs, BreadcrumbsProps, CellAlign, Checkbox, CheckboxProps, CheckboxStyle, ColorStop,
-    ColumnWidth, Confirm, ConfirmProps, ConfirmStyle, Crumb, Diff, DiffLine, DiffLineType,
-    DiffProps, DiffStyle, Divider, DividerProps, DividerStyle, FilterMode, Gradient,
-    GradientPreset, GradientProps, Indent, IndentProps, KeyHint, KeyHintSeparator, KeyHintStyle,
-    KeyHints, KeyHintsProps, LineNumberStyle, Link, LinkProps, LogBox, LogBoxProps, LogLine,
-    Markdown, MarkdownProps, Modal, ModalButton, ModalProps, ModalStyle, MultiSelect,
-    MultiSelectItem, MultiSelectProps, MultiSelectState, MultiSelectStyle, Newline, NewlineProps,
-    Progress, ProgressChars, ProgressProps, ProgressStyle, Row, RowStyle, Select, SelectIndicator,
-    SelectItem, SelectProps, SelectState, Spacer, SpacerProps, Sparkline, SparklineProps,
-    SparklineStyle, Spinner, SpinnerProps, SpinnerStyle, Static, StaticItem, StaticProps,
-    StatusBar, StatusBarProps, StatusSegment, StatusSeparator, SyntaxHighlight,
-    SyntaxHighlightProps, SyntaxTheme, Tab, TabDivider, TabStyle, Table, TableCell, TableProps,
-    TableState, Tabs, TabsProps, TabsState, Text, TextInput, TextInputProps, TextInputState,
-    TextProps, TextWrap, TimeFormat, Timer, TimerMode, TimerProps, Transform, TransformFn,
-    TransformProps, TreeConnectors, TreeNode, TreeState, TreeStyle, TreeView, TreeViewProps,
-    ValueFormat,
+    format_bytes, git_branch, gradient, gradient_preset, icons, key_hints, key_hints_from, link,
+    link_url, log_box, markdown_block, marked_sections, next_section, prev_section, progress_bar,
+    progress_bar_bracketed, pulsing_dot, rule, section_at, spacer, sparkline, sparkline_labeled,
+    spinner_frame, spinner_frame_interval, status_error, status_ok, status_warning, stopwatch,
+    success_modal, syntax_highlight, syntax_highlight_with_lines, timer_display, transforms,
+    tree_view, with_background, with_border, with_padding, with_title, AccordionState,
+    Autocomplete, AutocompleteItem, AutocompleteProps, AutocompleteState, Axis, Backdrop,
+    BackdropProps, Badge, BadgeProps, BadgeStyle, BarChart, BarChartProps, BarData, BarId,
+    BarLayout, BarOrientation, BarSeries, BarStatus, BarStyle, BigFont, BigText, BigTextProps,
+    BorderChars, BorderColors, BorderSides, BorderStyle, Box, BoxProps, BreadcrumbCollapse,
+    BreadcrumbSeparator, Breadcrumbs, BreadcrumbsProps, Calendar, CalendarProps, Canvas,
+    CanvasProps, CellAlign, Checkbox, CheckboxProps, CheckboxStyle, Collapsible, CollapsibleProps,
+    ColorStop, ColumnWidth, Confirm, ConfirmProps, ConfirmStyle, ContextMenu, ContextMenuAction,
+    ContextMenuProps, ContextMenuState, Countdown, Crumb, CursorMove, DataValue, Date, Diff,
+    DiffLine, DiffLineType, DiffProps, DiffStyle, Divider, DividerAlign, DividerProps,
+    DividerStatus, DividerStyle, EditKeys, EmptyState, ErrorState, Field, FieldValue, FilterMode,
+    Form, FormAction, FormProps, FormResult, FormState, Gauge, GaugeProps, GaugeShape, Gradient,
+    GradientDirection, GradientPreset, GradientProps, Hyperlink, HyperlinkProps, Image, ImageProps,
+    Indent, IndentProps, KeyBindings, KeyHint, KeyHintSeparator, KeyHintStyle, KeyHints,
+    KeyHintsProps, Legend, LegendItem, LineChart, LineChartProps, LineMarker, LineNumberStyle,
+    Link, LinkProps, LoadingState, LogBox, LogBoxProps, LogLine, Markdown, MarkdownProps, MenuItem,
+    Modal, ModalAction, ModalButton, ModalProps, ModalState, ModalStyle, MultiProgress,
+    MultiProgressProps, MultiProgressState, MultiSelect, MultiSelectItem, MultiSelectProps,
+    MultiSelectState, MultiSelectStyle, Newline, NewlineProps, Painter, PasteMode, PendingKeys,
+    PendingKeysProps, PlaceholderKind, PlaceholderProps, Progress, ProgressBar, ProgressChars,
+    ProgressProps, ProgressRate, ProgressStyle, RadioGroup, RadioGroupProps, RadioGroupState,
+    RadioLayout, RadioStyle, Reveal, RevealProps, RevealState, Row, RowStyle, Rule, RuleProps,
+    Scale, ScaleKind, ScrollState, ScrollView, ScrollViewProps, Scrollbar, ScrollbarOrientation,
+    ScrollbarProps, Section, Select, SelectIndicator, SelectItem, SelectProps, SelectState, Series,
+    Spacer, SpacerProps, Sparkline, SparklineProps, SparklineStyle, Spinner, SpinnerFrames,
+    SpinnerProps, SpinnerStyle, Sprite, SpriteParseError, SpriteProps, Static, StaticItem,
+    StaticProps, StatusBar, StatusBarProps, StatusSegment, StatusSeparator, SyntaxBackend,
+    SyntaxHighlight, SyntaxHighlightProps, SyntaxTheme, SyntectBackend, Tab, TabDivider, TabStyle,
+    Table, TableCell, TableProps, TableState, Tabs, TabsProps, TabsState, TaskItem, TaskItemStatus,
+    TaskList, TaskListProps, TaskListState, Text, TextArea, TextAreaProps, TextAreaState,
+    TextInput, TextInputProps, TextInputState, TextProps, TextWrap, TimeField, TimeFormat,
+    TimePicker, TimePickerMode, TimePickerProps, TimePickerState, Timer, TimerMode, TimerProps,
+    TimerState, TimerZone, Toc, TocProps, TocState, Transform, TransformFn, TransformProps,
+    TreeConnectors, TreeNode, TreeState, TreeStyle, TreeView, TreeViewProps, Truncate,
+    TruncateProps, TruncateState, ValueFormat, ValueTree, ValueTreeProps, ValueTreeState, VimMode,
+    VirtualTable, VirtualTableProps, VirtualTableState, Weekday, Wizard, WizardAction, WizardProps,
+    WizardState,
 };
-pub use element::{Component, Element};
+pub use debounce::{resize_placeholder, ResizeDebouncer};
+pub use element::{Component, Element, ElementBuilder, SendElement};
+pub use error::BlaeckError;
+pub use filter::{FrameFilter, Redact};
 pub use focus::{FocusCallback, FocusEvent, FocusId, FocusManager, FocusState};
-pub use input::{match_key, poll_key, read_key, Arrow, InputHandler, Key, KeyMatcher};
+pub use gestures::{Gesture, GestureDetector, ScrollDirection, Wheel};
+pub use glyphs::GlyphSet;
+pub use graphics::{GraphicsProtocol, ImageData};
+pub use hotkeys::{Hotkey, HotkeyConflict, Hotkeys};
+pub use idle::{IdleEvent, IdleTracker};
+pub use input::{
+    match_key, poll_event, poll_key, read_key, Arrow, InputEvent, InputHandler, Key, KeyMatcher,
+    KeyPattern, KeyPatternError, Mouse,
+};
+pub use keymap::{KeyBinding, KeyContext, Keymap, KeymapConflict, KeymapError, KeymapMatch};
 pub use layout::{
     AlignContent, AlignItems, AlignSelf, Display, FlexDirection, FlexWrap, GridAutoFlow,
     GridPlacement, JustifyContent, LayoutResult, LayoutStyle, LayoutTree, Overflow, Position,
     TrackSize,
 };
 pub use log_update::LogUpdate;
+pub use metrics::{Metrics, MetricsCallback};
 pub use output::{Output, OutputResult};
+pub use overlay::{Align, Placement, Positioner, Rect, Side};
+pub use pager::{print_paged, render_to_string, PagerConfig, PagerMode};
+pub use palette::{ColorVision, Palette, SimulateColorVision};
+pub use patch::{CellRun, FrameEncoder, FramePatch, PatchError};
+pub use pipeline::{LayoutPipeline, PaintedFrame};
+pub use regions::{RegionId, Regions};
 pub use renderer::Blaeck;
+pub use replay::{InputRecorder, InputRecording, InputReplay, RecordedEvent, RecordingError};
+pub use resize::{ResizeAction, ResizeModel, ResizeState};
+pub use selection::{SelectAction, SelectMode};
+pub use stream::{print_streamed, render_streamed};
 pub use style::{rgb_to_256, supports_truecolor, Color, Modifier, Style};
+pub use task::{TaskHandle, TaskStatus};
 pub use timeline::{
     Act, Animatable, LoopBehavior, PlayingTimeline, Spring, SpringTrack, StaggerConfig,
     StaggerOrder, StaggerTrack, Timeline, TimelineDebugInfo, TimelineState, Track,
 };
+pub use toast::{Toast, ToastCorner, ToastId, ToastKind, ToastManager};
 
 #[cfg(feature = "async")]
 pub use async_runtime::{
     channel, poll_key_async, read_key_async, AppEvent, AsyncApp, AsyncAppConfig, Receiver, Sender,
 };
 
+#[cfg(feature = "session")]
+pub use session::SessionStore;
+
+#[cfg(feature = "log-viewer")]
+pub use components::{LogEntry, LogLevel, LogViewer, LogViewerProps, LogViewerState};
+
 /// Re-export the element! macro from blaeck-macros.
 pub use blaeck_macros::element;
+pub use truncation::{TruncateAt, Truncation};
 
 /// Print an element to stdout and return.
 ///
@@ -216,7 +312,7 @@ pub use blaeck_macros::element;
 /// ```ignore
 /// use blaeck::prelude::*;
 ///
-/// fn main() -> std::io::Result<()> {
+/// fn main() -> blaeck::error::Result<()> {
 ///     blaeck::print(element! {
 ///         Text(content: "Hello!", color: Color::Green, bold: true)
 ///     })
@@ -224,7 +320,9 @@ pub use blaeck_macros::element;
 /// ```
 ///
 /// For interactive apps that respond to keyboard input, use [`reactive::ReactiveApp`].
-pub fn print(element: Element) -> std::io::Result<()> {
+/// For output taller than the terminal, use [`print_paged`] to send it through `$PAGER`,
+/// or [`print_streamed`] to render very tall reports row by row.
+pub fn print(element: Element) -> error::Result<()> {
     let mut blaeck = Blaeck::new(std::io::stdout())?;
     blaeck.render(element)?;
     blaeck.unmount()
@@ -234,49 +332,78 @@ pub fn print(element: Element) -> std::io::Result<()> {
 pub mod prelude {
     pub use crate::animation::{AnimationTimer, BlinkPattern, Easing, IndicatorStyle};
     pub use crate::components::{
-        alert, animated_indicator, animated_indicator_colored, badge, badge_bracket, bar_chart,
-        bar_chart_with_values, blink, blink_or, blink_pattern, blinking_dot, breadcrumbs,
-        breadcrumbs_path, checkbox, confirm_modal, confirm_prompt, countdown,
-        countdown_with_thresholds, diff_lines, divider, divider_with_label, error_modal,
-        flex_spacer, git_branch, gradient, gradient_preset, icons, key_hints, link, link_url,
-        log_box, markdown_block, progress_bar, progress_bar_bracketed, pulsing_dot, spacer,
+        alert, animated_indicator, animated_indicator_colored, badge, badge_bracket, badge_color,
+        bar_chart, bar_chart_with_values, blink, blink_or, blink_pattern, blinking_dot,
+        breadcrumbs, breadcrumbs_path, centered, checkbox, confirm_modal, confirm_prompt,
+        countdown, countdown_with_thresholds, diff_lines, divider, divider_with_label, error_modal,
+        flex_spacer, format_bytes, git_branch, gradient, gradient_preset, icons, key_hints,
+        key_hints_from, link, link_url, log_box, markdown_block, marked_sections, next_section,
+        prev_section, progress_bar, progress_bar_bracketed, pulsing_dot, rule, section_at, spacer,
         sparkline, sparkline_labeled, spinner_frame, spinner_frame_interval, status_error,
         status_ok, status_warning, stopwatch, success_modal, syntax_highlight,
-        syntax_highlight_with_lines, timer_display, transforms, tree_view, Autocomplete,
-        AutocompleteItem, AutocompleteProps, AutocompleteState, Badge, BadgeProps, BadgeStyle,
-        BarChart, BarChartProps, BarData, BarStyle, BorderChars, BorderColors, BorderSides,
-        BorderStyle, Box, BoxProps, BreadcrumbSeparator, Breadcrumbs, BreadcrumbsProps, CellAlign,
-        Checkbox, CheckboxProps, CheckboxStyle, ColorStop, ColumnWidth, Confirm, ConfirmProps,
-        ConfirmStyle, Crumb, Diff, DiffLine, DiffLineType, DiffProps, DiffStyle, Divider,
-        DividerProps, DividerStyle, FilterMode, Gradient, GradientPreset, GradientProps, Indent,
-        IndentProps, KeyHint, KeyHintSeparator, KeyHintStyle, KeyHints, KeyHintsProps,
-        LineNumberStyle, Link, LinkProps, LogBox, LogBoxProps, LogLine, Markdown, MarkdownProps,
-        Modal, ModalButton, ModalProps, ModalStyle, MultiSelect, MultiSelectItem, MultiSelectProps,
-        MultiSelectState, MultiSelectStyle, Newline, NewlineProps, Progress, ProgressChars,
-        ProgressProps, ProgressStyle, Row, RowStyle, Select, SelectIndicator, SelectItem,
-        SelectProps, SelectState, Spacer, SpacerProps, Sparkline, SparklineProps, SparklineStyle,
-        Spinner, SpinnerProps, SpinnerStyle, Static, StaticItem, StaticProps, StatusBar,
-        StatusBarProps, StatusSegment, StatusSeparator, SyntaxHighlight, SyntaxHighlightProps,
-        SyntaxTheme, Tab, TabDivider, TabStyle, Table, TableCell, TableProps, TableState, Tabs,
-        TabsProps, TabsState, Text, TextInput, TextInputProps, TextInputState, TextProps, TextWrap,
-        TimeFormat, Timer, TimerMode, TimerProps, Transform, TransformFn, TransformProps,
-        TreeConnectors, TreeNode, TreeState, TreeStyle, TreeView, TreeViewProps, ValueFormat,
+        syntax_highlight_with_lines, timer_display, transforms, tree_view, with_background,
+        with_border, with_padding, with_title, AccordionState, Autocomplete, AutocompleteItem,
+        AutocompleteProps, AutocompleteState, Axis, Backdrop, BackdropProps, Badge, BadgeProps,
+        BadgeStyle, BarChart, BarChartProps, BarData, BarId, BarLayout, BarOrientation, BarSeries,
+        BarStatus, BarStyle, BigFont, BigText, BigTextProps, BorderChars, BorderColors,
+        BorderSides, BorderStyle, Box, BoxProps, BreadcrumbCollapse, BreadcrumbSeparator,
+        Breadcrumbs, BreadcrumbsProps, Calendar, CalendarProps, Canvas, CanvasProps, CellAlign,
+        Checkbox, CheckboxProps, CheckboxStyle, Collapsible, CollapsibleProps, ColorStop,
+        ColumnWidth, Confirm, ConfirmProps, ConfirmStyle, ContextMenu, ContextMenuAction,
+        ContextMenuProps, ContextMenuState, Countdown, Crumb, CursorMove, DataValue, Date, Diff,
+        DiffLine, DiffLineType, DiffProps, DiffStyle, Divider, DividerAlign, DividerProps,
+        DividerStatus, DividerStyle, EditKeys, EmptyState, ErrorState, Field, FieldValue,
+        FilterMode, Form, FormAction, FormProps, FormResult, FormState, Gauge, GaugeProps,
+        GaugeShape, Gradient, GradientDirection, GradientPreset, GradientProps, Hyperlink,
+        HyperlinkProps, Image, ImageProps, Indent, IndentProps, KeyBindings, KeyHint,
+        KeyHintSeparator, KeyHintStyle, KeyHints, KeyHintsProps, Legend, LegendItem, LineChart,
+        LineChartProps, LineMarker, LineNumberStyle, Link, LinkProps, LoadingState, LogBox,
+        LogBoxProps, LogLine, Markdown, MarkdownProps, MenuItem, Modal, ModalAction, ModalButton,
+        ModalProps, ModalState, ModalStyle, MultiProgress, MultiProgressProps, MultiProgressState,
+        MultiSelect, MultiSelectItem, MultiSelectProps, MultiSelectState, MultiSelectStyle,
+        Newline, NewlineProps, Painter, PasteMode, PendingKeys, PendingKeysProps, PlaceholderKind,
+        PlaceholderProps, Progress, ProgressBar, ProgressChars, ProgressProps, ProgressRate,
+        ProgressStyle, RadioGroup, RadioGroupProps, RadioGroupState, RadioLayout, RadioStyle,
+        Reveal, RevealProps, RevealState, Row, RowStyle, Rule, RuleProps, Scale, ScaleKind,
+        ScrollState, ScrollView, ScrollViewProps, Scrollbar, ScrollbarOrientation, ScrollbarProps,
+        Section, Select, SelectIndicator, SelectItem, SelectProps, SelectState, Series, Spacer,
+        SpacerProps, Sparkline, SparklineProps, SparklineStyle, Spinner, SpinnerFrames,
+        SpinnerProps, SpinnerStyle, Sprite, SpriteParseError, SpriteProps, Static, StaticItem,
+        StaticProps, StatusBar, StatusBarProps, StatusSegment, StatusSeparator, SyntaxBackend,
+        SyntaxHighlight, SyntaxHighlightProps, SyntaxTheme, SyntectBackend, Tab, TabDivider,
+        TabStyle, Table, TableCell, TableProps, TableState, Tabs, TabsProps, TabsState, TaskItem,
+        TaskItemStatus, TaskList, TaskListProps, TaskListState, Text, TextArea, TextAreaProps,
+        TextAreaState, TextInput, TextInputProps, TextInputState, TextProps, TextWrap, TimeField,
+        TimeFormat, TimePicker, TimePickerMode, TimePickerProps, TimePickerState, Timer, TimerMode,
+        TimerProps, TimerState, TimerZone, Toc, TocProps, TocState, Transform, TransformFn,
+        TransformProps, TreeConnectors, TreeNode, TreeState, TreeStyle, TreeView, TreeViewProps,
+        Truncate, TruncateProps, TruncateState, ValueFormat, ValueTree, ValueTreeProps,
+        ValueTreeState, VimMode, VirtualTable, VirtualTableProps, VirtualTableState, Weekday,
+        Wizard, WizardAction, WizardProps, WizardState,
     };
-    pub use crate::element::{Component, Element};
+    pub use crate::element::{Component, Element, ElementBuilder, SendElement};
+    pub use crate::error::BlaeckError;
+    pub use crate::glyphs::GlyphSet;
     pub use crate::layout::{
         AlignContent, AlignItems, AlignSelf, Display, FlexDirection, FlexWrap, GridAutoFlow,
         GridPlacement, JustifyContent, LayoutResult, LayoutStyle, Overflow, Position, TrackSize,
     };
+    pub use crate::palette::Palette;
     pub use crate::renderer::Blaeck;
     pub use crate::style::{rgb_to_256, supports_truecolor, Color, Modifier, Style};
+    pub use crate::task::TaskHandle;
     pub use crate::timeline::{
         Act, Animatable, LoopBehavior, PlayingTimeline, Spring, SpringTrack, StaggerConfig,
         StaggerOrder, StaggerTrack, Timeline, TimelineDebugInfo, Track,
     };
+    pub use crate::toast::{ToastCorner, ToastKind, ToastManager};
     pub use blaeck_macros::element;
 
     #[cfg(feature = "async")]
     pub use crate::async_runtime::{channel, AppEvent, AsyncApp, AsyncAppConfig, Receiver, Sender};
+
+    #[cfg(feature = "log-viewer")]
+    pub use crate::components::{LogEntry, LogLevel, LogViewer, LogViewerProps, LogViewerState};
 }
 
 #[cfg(test)]
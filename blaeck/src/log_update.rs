@@ -128,6 +128,72 @@ impl<W: Write> LogUpdate<W> {
         Ok(())
     }
 
+    /// Updates the previous output in place using a precomputed cell patch.
+    ///
+    /// `patch` must transform the previously rendered content into `content`
+    /// (see [`Buffer::diff_ansi`](crate::buffer::Buffer::diff_ansi)). Falls back
+    /// to a full [`render`](Self::render) when nothing has been rendered yet or
+    /// the line count changed, since the patch can't add or remove lines.
+    pub fn patch(&mut self, content: &str, patch: &str) -> Result<()> {
+        let output = format!("{}\r\n", content);
+        if output == self.previous_output {
+            return Ok(());
+        }
+        let line_count = output.matches('\n').count().max(1);
+        if self.previous_output.is_empty() || line_count != self.previous_line_count {
+            return self.render(content);
+        }
+
+        let mut buffer = String::with_capacity(patch.len() + 32);
+        buffer.push_str("\x1b[?2026h");
+        buffer.push_str("\x1b[?25l");
+        buffer.push_str(patch);
+        if self.cursor_visible {
+            buffer.push_str("\x1b[?25h");
+        }
+        buffer.push_str("\x1b[?2026l");
+
+        write!(self.writer, "{}", buffer)?;
+        self.writer.flush()?;
+
+        self.previous_output = output;
+        Ok(())
+    }
+
+    /// Writes `text` above the current output and redraws the output below
+    /// it, in one write.
+    ///
+    /// The text scrolls up with the terminal and isn't tracked, so later
+    /// renders only replace the output under it. `text` should use `\r\n`
+    /// line endings and no trailing newline.
+    pub fn print_above(&mut self, text: &str) -> Result<()> {
+        let mut buffer = String::with_capacity(text.len() + self.previous_output.len() + 32);
+        buffer.push_str("\x1b[?2026h");
+        buffer.push_str("\x1b[?25l");
+        if self.previous_line_count > 0 {
+            // Back to the top of the output and clear everything below it
+            buffer.push_str(&format!("\x1b[{}A", self.previous_line_count));
+            buffer.push_str("\x1b[0G\x1b[J");
+        }
+        buffer.push_str(text);
+        buffer.push_str("\r\n");
+        buffer.push_str(&self.previous_output);
+        if self.cursor_visible {
+            buffer.push_str("\x1b[?25h");
+        }
+        buffer.push_str("\x1b[?2026l");
+
+        write!(self.writer, "{}", buffer)?;
+        self.writer.flush()
+    }
+
+    /// Writes an escape sequence that doesn't move the cursor (such as a
+    /// clipboard or title update) without disturbing the tracked output.
+    pub fn write_raw(&mut self, data: &str) -> Result<()> {
+        write!(self.writer, "{}", data)?;
+        self.writer.flush()
+    }
+
     /// Clears the current output without rendering new content.
     ///
     /// After calling clear(), the next render() will write from scratch.
@@ -421,4 +487,39 @@ mod tests {
         assert!(output.contains("Red"));
         assert!(output.contains("Green"));
     }
+
+    #[test]
+    fn test_log_update_print_above() {
+        let mut buf = Vec::new();
+        {
+            let mut lu = LogUpdate::new(&mut buf);
+            lu.render("footer").unwrap();
+            lu.print_above("one\r\ntwo").unwrap();
+            // The footer is still tracked, so rendering it again is a no-op
+            lu.render("footer").unwrap();
+            assert_eq!(lu.previous_line_count(), 1);
+        }
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains("\x1b[1A\x1b[0G\x1b[Jone\r\ntwo\r\nfooter\r\n"));
+        assert_eq!(output.matches("footer").count(), 2);
+    }
+
+    #[test]
+    fn test_log_update_patch() {
+        let mut buf = Vec::new();
+        {
+            let mut lu = LogUpdate::new(&mut buf);
+            // Nothing rendered yet: falls back to a full render
+            lu.patch("ab\r\ncd", "PATCH1").unwrap();
+            lu.patch("ab\r\nce", "PATCH2").unwrap();
+            // Line count changed: full render again
+            lu.patch("ab", "PATCH3").unwrap();
+            assert_eq!(lu.previous_line_count(), 1);
+        }
+        let output = String::from_utf8(buf).unwrap();
+        assert!(!output.contains("PATCH1"));
+        assert!(output.contains("PATCH2"));
+        assert!(!output.contains("ce"));
+        assert!(!output.contains("PATCH3"));
+    }
 }
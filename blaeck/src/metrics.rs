@@ -0,0 +1,205 @@
+//! Render and runtime metrics.
+//!
+//! Services that embed a status TUI usually want to know what it costs.
+//! [`Blaeck`](crate::Blaeck) keeps a running [`Metrics`] snapshot — frames
+//! rendered, skipped, discarded and unchanged, frame times — and
+//! [`ReactiveApp`](crate::reactive::ReactiveApp) adds runtime counters
+//! (signal updates, dispatched and dropped input events).
+//!
+//! Read it on demand, get a callback after every frame, or export it in the
+//! Prometheus text format:
+//!
+//! ```ignore
+//! blaeck.set_metrics_callback(|m| {
+//!     if m.last_frame_time > Duration::from_millis(16) {
+//!         log::warn!("slow frame: {:?}", m.last_frame_time);
+//!     }
+//! });
+//!
+//! // In your /metrics handler:
+//! body.push_str(&blaeck.metrics().to_prometheus("myapp_tui"));
+//! ```
+
+use std::fmt::Write as _;
+use std::time::Duration;
+
+/// Callback invoked with the current metrics after each rendered frame.
+pub type MetricsCallback = Box<dyn FnMut(&Metrics)>;
+
+/// Counters and timings for a renderer and its runtime.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Metrics {
+    /// Frames laid out and written to the terminal.
+    pub frames_rendered: u64,
+    /// Render calls skipped by throttling.
+    pub frames_skipped: u64,
+    /// Frames not written because the UI was frozen for selection or they
+    /// were laid out for an old terminal width.
+    pub frames_discarded: u64,
+    /// Frames not written because they matched the frame on screen.
+    pub frames_unchanged: u64,
+    /// Total time spent rendering frames.
+    pub total_frame_time: Duration,
+    /// Time spent on the most recent frame.
+    pub last_frame_time: Duration,
+    /// Slowest frame so far.
+    pub max_frame_time: Duration,
+    /// Signal writes that marked the UI dirty (reactive runtime only).
+    pub signal_updates: u64,
+    /// Input events delivered to at least one handler (reactive runtime only).
+    pub events_dispatched: u64,
+    /// Input events received with no handler to deliver them to.
+    pub events_dropped: u64,
+}
+
+impl Metrics {
+    /// Average time per rendered frame, or zero before the first frame.
+    pub fn avg_frame_time(&self) -> Duration {
+        if self.frames_rendered == 0 {
+            Duration::ZERO
+        } else {
+            // Divide in f64: the frame count can outgrow a u32 divisor
+            Duration::from_secs_f64(
+                self.total_frame_time.as_secs_f64() / self.frames_rendered as f64,
+            )
+        }
+    }
+
+    /// Record a rendered frame that took `elapsed`.
+    pub fn record_frame(&mut self, elapsed: Duration) {
+        self.frames_rendered += 1;
+        self.total_frame_time += elapsed;
+        self.last_frame_time = elapsed;
+        self.max_frame_time = self.max_frame_time.max(elapsed);
+    }
+
+    /// Record a render call skipped by throttling.
+    pub fn record_skipped(&mut self) {
+        self.frames_skipped += 1;
+    }
+
+    /// Record a frame discarded while frozen or laid out at a stale width.
+    pub fn record_discarded(&mut self) {
+        self.frames_discarded += 1;
+    }
+
+    /// Record a frame that matched the one on screen and wasn't written.
+    pub fn record_unchanged(&mut self) {
+        self.frames_unchanged += 1;
+    }
+
+    /// Format as Prometheus text exposition, with every metric name
+    /// prefixed by `prefix` (e.g. `"myapp_tui"` → `myapp_tui_frames_rendered_total`).
+    pub fn to_prometheus(&self, prefix: &str) -> String {
+        let mut out = String::new();
+        let mut metric = |name: &str, kind: &str, help: &str, value: String| {
+            let _ = writeln!(out, "# HELP {prefix}_{name} {help}");
+            let _ = writeln!(out, "# TYPE {prefix}_{name} {kind}");
+            let _ = writeln!(out, "{prefix}_{name} {value}");
+        };
+        metric(
+            "frames_rendered_total",
+            "counter",
+            "Frames written to the terminal.",
+            self.frames_rendered.to_string(),
+        );
+        metric(
+            "frames_skipped_total",
+            "counter",
+            "Render calls skipped by throttling.",
+            self.frames_skipped.to_string(),
+        );
+        metric(
+            "frames_discarded_total",
+            "counter",
+            "Frames not written because the UI was frozen or the width was stale.",
+            self.frames_discarded.to_string(),
+        );
+        metric(
+            "frames_unchanged_total",
+            "counter",
+            "Frames not written because nothing changed.",
+            self.frames_unchanged.to_string(),
+        );
+        metric(
+            "frame_time_seconds_total",
+            "counter",
+            "Total time spent rendering frames.",
+            self.total_frame_time.as_secs_f64().to_string(),
+        );
+        metric(
+            "frame_time_seconds_avg",
+            "gauge",
+            "Average time per rendered frame.",
+            self.avg_frame_time().as_secs_f64().to_string(),
+        );
+        metric(
+            "frame_time_seconds_max",
+            "gauge",
+            "Slowest rendered frame.",
+            self.max_frame_time.as_secs_f64().to_string(),
+        );
+        metric(
+            "signal_updates_total",
+            "counter",
+            "Signal writes that marked the UI dirty.",
+            self.signal_updates.to_string(),
+        );
+        metric(
+            "events_dispatched_total",
+            "counter",
+            "Input events delivered to handlers.",
+            self.events_dispatched.to_string(),
+        );
+        metric(
+            "events_dropped_total",
+            "counter",
+            "Input events received with no handler.",
+            self.events_dropped.to_string(),
+        );
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics_record_frames() {
+        let mut m = Metrics::default();
+        assert_eq!(m.avg_frame_time(), Duration::ZERO);
+        m.record_frame(Duration::from_millis(2));
+        m.record_frame(Duration::from_millis(4));
+        m.record_skipped();
+        m.record_discarded();
+        assert_eq!(m.frames_rendered, 2);
+        assert_eq!(m.frames_skipped, 1);
+        assert_eq!(m.frames_discarded, 1);
+        assert_eq!(m.avg_frame_time(), Duration::from_millis(3));
+        assert_eq!(m.last_frame_time, Duration::from_millis(4));
+        assert_eq!(m.max_frame_time, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn test_metrics_avg_frame_time_many_frames() {
+        let m = Metrics {
+            frames_rendered: u64::from(u32::MAX) + 1,
+            total_frame_time: Duration::from_secs(1 << 32),
+            ..Metrics::default()
+        };
+        assert_eq!(m.avg_frame_time(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_metrics_prometheus() {
+        let mut m = Metrics::default();
+        m.record_frame(Duration::from_millis(500));
+        m.events_dropped = 3;
+        let text = m.to_prometheus("tui");
+        assert!(text
+            .contains("# TYPE tui_frames_rendered_total counter\ntui_frames_rendered_total 1\n"));
+        assert!(text.contains("tui_frame_time_seconds_avg 0.5\n"));
+        assert!(text.contains("tui_events_dropped_total 3\n"));
+    }
+}
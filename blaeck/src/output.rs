@@ -4,10 +4,22 @@
 //! where text can be written at x,y positions with styles. The grid is then
 //! converted to a string with ANSI escape codes for terminal rendering.
 //!
+//! Images can be placed with [`Output::write_image`]. With a pixel graphics
+//! protocol the covered cells are reserved and the protocol escape is emitted
+//! at the image origin; otherwise the image is drawn as half-block cells.
+//!
 //! Based on Ink's output.ts pattern.
 
+use crate::buffer::Buffer;
+use crate::graphics::{self, GraphicsProtocol, ImageData};
+use crate::renderer::strip_ansi_escapes;
 use crate::style::Style;
-use unicode_width::UnicodeWidthChar;
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Default distance between tab stops.
+pub const DEFAULT_TAB_WIDTH: u16 = 4;
 
 /// Result of getting the rendered output from the Output grid.
 #[derive(Debug, Clone)]
@@ -23,27 +35,72 @@ pub struct OutputResult {
 struct StyledChar {
     /// The character value (may be empty for wide char continuation).
     char: char,
+    /// The full grapheme cluster when it is more than one `char` (emoji ZWJ
+    /// sequences, flags, combining marks). `char` holds its first codepoint.
+    cluster: Option<Box<str>>,
     /// The style applied to this character.
     style: Style,
     /// Whether this is a placeholder for a wide character.
     is_wide_continuation: bool,
+    /// Hyperlink target, as an index into `Output::links`.
+    link: Option<usize>,
+    /// Innermost named scrollable component covering the cell, as an index
+    /// into `Output::scroll_targets`.
+    scroll: Option<usize>,
 }
 
 impl Default for StyledChar {
     fn default() -> Self {
         Self {
             char: ' ',
+            cluster: None,
             style: Style::default(),
             is_wide_continuation: false,
+            link: None,
+            scroll: None,
         }
     }
 }
 
+impl StyledChar {
+    /// The cell's text: the whole grapheme cluster, or its single `char`.
+    fn push_to(&self, out: &mut String) {
+        match &self.cluster {
+            Some(cluster) => out.push_str(cluster),
+            None => out.push(self.char),
+        }
+    }
+
+    /// Replaces the cell's content with a space, keeping its style and link.
+    fn blank(&mut self) {
+        self.char = ' ';
+        self.cluster = None;
+        self.is_wide_continuation = false;
+    }
+}
+
+/// A pixel image reserved over a block of cells.
+#[derive(Debug, Clone)]
+struct ImagePlacement {
+    x: u16,
+    y: u16,
+    cols: u16,
+    rows: u16,
+    /// Protocol escape that draws the image at the cursor.
+    escape: String,
+}
+
+impl ImagePlacement {
+    fn covers(&self, x: u16, y: u16) -> bool {
+        x >= self.x && x < self.x + self.cols && y >= self.y && y < self.y + self.rows
+    }
+}
+
 /// Virtual output grid for building terminal UI.
 ///
 /// Write text at arbitrary x,y positions with styles, then call `get()` to
 /// render the entire grid to a string with ANSI escape codes.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Output {
     /// Width of the output grid in columns.
     pub width: u16,
@@ -51,6 +108,18 @@ pub struct Output {
     pub height: u16,
     /// The 2D grid of styled characters.
     grid: Vec<Vec<StyledChar>>,
+    /// Pixel images drawn on top of the grid.
+    images: Vec<ImagePlacement>,
+    /// Interned hyperlink URLs referenced by cells.
+    links: Vec<String>,
+    /// Hyperlinks currently open; writes are tagged with the innermost one.
+    link_stack: Vec<usize>,
+    /// Interned ids of scrollable components referenced by cells.
+    scroll_targets: Vec<String>,
+    /// Columns between tab stops when expanding `\t`.
+    tab_width: u16,
+    /// Content row held by the first grid row (0 except for windows).
+    origin_y: u16,
 }
 
 impl Output {
@@ -66,105 +135,446 @@ impl Output {
             width,
             height,
             grid,
+            images: Vec::new(),
+            links: Vec::new(),
+            link_stack: Vec::new(),
+            scroll_targets: Vec::new(),
+            tab_width: DEFAULT_TAB_WIDTH,
+            origin_y: 0,
         }
     }
 
+    /// Creates a grid holding only content rows `origin_y..origin_y + height`.
+    ///
+    /// Writes use content coordinates: rows outside the window are dropped,
+    /// so a tall scrolled area can be painted without a grid for all of it.
+    /// [`line_text`](Self::line_text), [`blit`](Self::blit) and the other
+    /// readers index the window from 0.
+    pub fn window(width: u16, height: u16, origin_y: u16) -> Self {
+        Self {
+            origin_y,
+            ..Self::new(width, height)
+        }
+    }
+
+    /// Content rows this grid holds. Anything drawn outside them is dropped,
+    /// so the renderer skips subtrees that lie entirely outside.
+    pub fn visible_rows(&self) -> std::ops::Range<u16> {
+        self.origin_y..self.origin_y.saturating_add(self.height)
+    }
+
+    /// Grid row for content row `y`, if it's in the window.
+    fn grid_row(&self, y: usize) -> Option<usize> {
+        let row = y.checked_sub(self.origin_y as usize)?;
+        (row < self.height as usize).then_some(row)
+    }
+
+    /// Sets the distance between tab stops (default [`DEFAULT_TAB_WIDTH`]).
+    ///
+    /// Tab stops are measured from the column each write starts at.
+    /// A width of 0 drops tabs.
+    pub fn set_tab_width(&mut self, width: u16) {
+        self.tab_width = width;
+    }
+
+    /// Starts a hyperlinked region.
+    ///
+    /// Every cell written until the matching [`pop_link`](Self::pop_link)
+    /// carries an OSC 8 target, whatever row or column it lands on. Regions
+    /// nest; the innermost URL wins.
+    pub fn push_link(&mut self, url: &str) {
+        let index = self.intern_link(url);
+        self.link_stack.push(index);
+    }
+
+    /// Index of `url` in the link table, adding it if needed.
+    fn intern_link(&mut self, url: &str) -> usize {
+        match self.links.iter().position(|l| l == url) {
+            Some(index) => index,
+            None => {
+                self.links.push(url.to_string());
+                self.links.len() - 1
+            }
+        }
+    }
+
+    /// Claims the cells of a `width` x `height` area for the scrollable
+    /// component `id`, so wheel events over them can be routed to it.
+    ///
+    /// Writes keep the claim; an area claimed later (a component nested
+    /// inside) takes precedence over an earlier one.
+    pub fn mark_scroll_target(&mut self, x: u16, y: u16, width: u16, height: u16, id: &str) {
+        let index = match self.scroll_targets.iter().position(|t| t == id) {
+            Some(index) => index,
+            None => {
+                self.scroll_targets.push(id.to_string());
+                self.scroll_targets.len() - 1
+            }
+        };
+        for content_y in y as usize..y as usize + height as usize {
+            let Some(row) = self.grid_row(content_y) else {
+                continue;
+            };
+            let end = (x as usize + width as usize).min(self.width as usize);
+            for cell in self.grid[row].iter_mut().take(end).skip(x as usize) {
+                cell.scroll = Some(index);
+            }
+        }
+    }
+
+    /// The innermost scrollable component claiming the cell at (x, y), if
+    /// any (see [`mark_scroll_target`](Self::mark_scroll_target)).
+    pub fn scroll_target_at(&self, x: u16, y: u16) -> Option<&str> {
+        let row = self.grid.get(self.grid_row(y as usize)?)?;
+        let index = row.get(x as usize)?.scroll?;
+        Some(&self.scroll_targets[index])
+    }
+
+    /// Ends the innermost hyperlinked region.
+    pub fn pop_link(&mut self) {
+        self.link_stack.pop();
+    }
+
     /// Writes text at the specified position with the given style.
     ///
     /// Multi-line text (containing '\n') is split and written line by line.
     /// Text that extends beyond the grid boundaries is clipped.
-    /// Wide characters (like CJK) are handled properly.
+    /// Text advances by grapheme cluster and display width, so CJK, emoji
+    /// (including ZWJ sequences and flags) and combining marks stay aligned.
+    /// Tabs are expanded to the next tab stop (see [`set_tab_width`](Self::set_tab_width)).
     /// Embedded ANSI escape codes are stripped (use the style parameter instead).
     pub fn write(&mut self, x: u16, y: u16, text: &str, style: Style) {
         if text.is_empty() {
             return;
         }
+        let link = self.link_stack.last().copied();
 
         for (line_offset, line) in text.split('\n').enumerate() {
-            let current_y = y as usize + line_offset;
-            if current_y >= self.height as usize {
-                break;
+            let content_y = y as usize + line_offset;
+            if content_y < self.origin_y as usize {
+                continue;
             }
+            let Some(current_y) = self.grid_row(content_y) else {
+                break;
+            };
 
             let mut current_x = x as usize;
-            let mut chars = line.chars().peekable();
+            let line = strip_ansi_escapes(line);
+            let line = expand_tabs(&line, self.tab_width);
 
-            while let Some(ch) = chars.next() {
+            // Advance by grapheme cluster, not char: a flag, a ZWJ family or
+            // "e" + combining accent is several chars but one on-screen glyph.
+            for grapheme in line.graphemes(true) {
                 if current_x >= self.width as usize {
                     break;
                 }
 
-                // Skip ANSI escape sequences
-                if ch == '\x1b' {
-                    match chars.peek() {
-                        Some('[') => {
-                            // Standard ANSI escape: \x1b[...m
-                            chars.next(); // consume '['
-                            while let Some(&c) = chars.peek() {
-                                chars.next();
-                                if c == 'm' {
-                                    break;
-                                }
-                            }
-                            continue;
-                        }
-                        Some(']') => {
-                            // OSC escape: \x1b]...\x07 or \x1b]...\x1b\\
-                            chars.next(); // consume ']'
-                            while let Some(c) = chars.next() {
-                                if c == '\x07' {
-                                    break;
-                                }
-                                if c == '\x1b' && chars.peek() == Some(&'\\') {
-                                    chars.next();
-                                    break;
-                                }
-                            }
-                            continue;
-                        }
-                        _ => {
-                            // Unknown escape, skip just the ESC
-                            continue;
-                        }
+                let width = grapheme.width();
+
+                // Zero-width clusters (a stray combining mark, a lone ZWJ)
+                // attach to the previous cell instead of taking a column
+                if width == 0 {
+                    if current_x > x as usize {
+                        self.append_to_previous(current_y, current_x, grapheme);
                     }
+                    continue;
                 }
 
-                // Get the display width of the character
-                let char_width = ch.width().unwrap_or(1);
-
-                // Write the character
-                self.grid[current_y][current_x] = StyledChar {
-                    char: ch,
-                    style,
-                    is_wide_continuation: false,
-                };
-
-                current_x += 1;
-
                 // ## Why wide character handling?
                 //
-                // CJK characters (Chinese, Japanese, Korean) and some emoji are
-                // "full-width" — they occupy 2 terminal columns but are 1 char.
+                // CJK characters (Chinese, Japanese, Korean) and most emoji are
+                // "full-width" — they occupy 2 terminal columns but are 1 grapheme.
                 // If we don't account for this, text after wide chars is misaligned.
                 //
                 // Solution: mark the "extra" column as a continuation cell.
                 // When rendering, we skip continuation cells (they're just placeholders).
                 // This keeps our grid coordinates aligned with terminal columns.
-                for _ in 1..char_width {
-                    if current_x >= self.width as usize {
-                        break;
+                let width = width.min(2);
+                if current_x + width > self.width as usize {
+                    // A wide char that doesn't fit would wrap in the terminal;
+                    // pad the last column instead
+                    self.put(current_y, current_x, 1, " ", style, link);
+                    break;
+                }
+                self.put(current_y, current_x, width, grapheme, style, link);
+                current_x += width;
+            }
+        }
+    }
+
+    /// Writes one grapheme occupying `width` columns at (x, y).
+    ///
+    /// Any wide character partially covered by the write is blanked, so the
+    /// grid never holds a head without its continuation (or the reverse).
+    fn put(
+        &mut self,
+        y: usize,
+        x: usize,
+        width: usize,
+        grapheme: &str,
+        style: Style,
+        link: Option<usize>,
+    ) {
+        let row = &mut self.grid[y];
+
+        // Overwriting the right half of a wide char: blank its head
+        if row[x].is_wide_continuation {
+            let mut head = x;
+            while head > 0 && row[head].is_wide_continuation {
+                row[head].blank();
+                head -= 1;
+            }
+            row[head].blank();
+        }
+        // Overwriting the left half of a wide char: blank its orphaned tail
+        let mut tail = x + width;
+        while tail < row.len() && row[tail].is_wide_continuation {
+            row[tail].blank();
+            tail += 1;
+        }
+
+        let mut chars = grapheme.chars();
+        let first = chars.next().unwrap_or(' ');
+        row[x] = StyledChar {
+            char: first,
+            cluster: chars.next().map(|_| grapheme.into()),
+            style,
+            is_wide_continuation: false,
+            link,
+            scroll: row[x].scroll,
+        };
+        for cell in &mut row[x + 1..x + width] {
+            *cell = StyledChar {
+                char: '\0',
+                cluster: None,
+                style,
+                is_wide_continuation: true,
+                link,
+                scroll: cell.scroll,
+            };
+        }
+    }
+
+    /// Appends a zero-width cluster to the cell left of `x`.
+    fn append_to_previous(&mut self, y: usize, x: usize, grapheme: &str) {
+        let row = &mut self.grid[y];
+        let mut head = x - 1;
+        while head > 0 && row[head].is_wide_continuation {
+            head -= 1;
+        }
+        let cell = &mut row[head];
+        let mut text = String::new();
+        cell.push_to(&mut text);
+        text.push_str(grapheme);
+        cell.cluster = Some(text.into());
+    }
+
+    /// Draws an image scaled to fill `cols` x `rows` cells at the given position.
+    ///
+    /// With a pixel protocol (Kitty, iTerm2, Sixel) the covered cells are
+    /// reserved and [`get()`](Self::get) emits the protocol escape there; with
+    /// [`GraphicsProtocol::HalfBlocks`] the image is written as colored cells.
+    /// The image is clipped to the grid.
+    pub fn write_image(
+        &mut self,
+        x: u16,
+        y: u16,
+        cols: u16,
+        rows: u16,
+        image: &ImageData,
+        protocol: GraphicsProtocol,
+    ) {
+        // Images starting above a window are dropped, like in blit
+        let Some(y) = self.grid_row(y as usize).map(|row| row as u16) else {
+            return;
+        };
+        if x >= self.width || cols == 0 || rows == 0 {
+            return;
+        }
+        let cols = cols.min(self.width - x);
+        let rows = rows.min(self.height - y);
+
+        // Clear the covered cells so nothing underneath leaks through
+        for row in &mut self.grid[y as usize..(y + rows) as usize] {
+            for cell in &mut row[x as usize..(x + cols) as usize] {
+                *cell = StyledChar::default();
+            }
+        }
+        self.images.retain(|p| {
+            !(p.x < x + cols && x < p.x + p.cols && p.y < y + rows && y < p.y + p.rows)
+        });
+
+        // Position-derived id so the same slot replaces its previous image
+        let id = y as u32 * self.width as u32 + x as u32 + 1;
+        match protocol.encode(image, cols, rows, id) {
+            Some(escape) => self.images.push(ImagePlacement {
+                x,
+                y,
+                cols,
+                rows,
+                escape,
+            }),
+            None => {
+                let link = self.link_stack.last().copied();
+                for (dy, line) in graphics::half_blocks(image, cols, rows)
+                    .into_iter()
+                    .enumerate()
+                {
+                    for (dx, (ch, style)) in line.into_iter().enumerate() {
+                        let cell = &mut self.grid[y as usize + dy][x as usize + dx];
+                        *cell = StyledChar {
+                            char: ch,
+                            cluster: None,
+                            style,
+                            is_wide_continuation: false,
+                            link,
+                            scroll: cell.scroll,
+                        };
                     }
-                    self.grid[current_y][current_x] = StyledChar {
-                        char: '\0',
-                        style,
-                        is_wide_continuation: true,
-                    };
-                    current_x += 1;
                 }
             }
         }
     }
 
+    /// Copies `rows` rows of `src`, starting at row `src_y`, to (x, y).
+    ///
+    /// Used to show a window of content painted on its own grid, such as
+    /// the visible part of a scroll view. Cells, styles and hyperlinks are
+    /// copied and clipped to this grid; pixel images are copied when they
+    /// fit entirely inside the window.
+    pub fn blit(&mut self, src: &Output, src_y: u16, x: u16, y: u16, rows: u16) {
+        let mut text = String::new();
+        for dy in 0..rows {
+            let Some(row) = src.grid.get((src_y + dy) as usize) else {
+                break;
+            };
+            let content_y = (y + dy) as usize;
+            if content_y < self.origin_y as usize {
+                continue;
+            }
+            let Some(dst_y) = self.grid_row(content_y) else {
+                break;
+            };
+            for (sx, cell) in row.iter().enumerate() {
+                let dst_x = x as usize + sx;
+                if cell.is_wide_continuation {
+                    continue;
+                }
+                let width = 1 + row[sx + 1..]
+                    .iter()
+                    .take_while(|c| c.is_wide_continuation)
+                    .count();
+                if dst_x + width > self.width as usize {
+                    break;
+                }
+                text.clear();
+                cell.push_to(&mut text);
+                let link = cell.link.map(|i| self.intern_link(&src.links[i]));
+                self.put(dst_y, dst_x, width, &text, cell.style, link);
+                if let Some(i) = cell.scroll {
+                    let id = &src.scroll_targets[i];
+                    self.mark_scroll_target(x + sx as u16, content_y as u16, width as u16, 1, id);
+                }
+            }
+        }
+
+        let window = src_y..src_y + rows;
+        for image in &src.images {
+            if window.contains(&image.y) && image.y + image.rows <= window.end {
+                let Some(dst_y) = self.grid_row((image.y - src_y + y) as usize) else {
+                    continue;
+                };
+                self.images.push(ImagePlacement {
+                    x: image.x + x,
+                    y: dst_y as u16,
+                    ..image.clone()
+                });
+            }
+        }
+    }
+
+    /// Returns the plain text of row `y`, one grapheme per cell.
+    ///
+    /// Wide character continuations are skipped, so byte offsets in the
+    /// result don't map to columns when the row has wide characters; use
+    /// [`UnicodeWidthStr`] on the prefix to find the column.
+    pub fn line_text(&self, y: u16) -> Option<String> {
+        let row = self.grid.get(y as usize)?;
+        let mut text = String::with_capacity(row.len());
+        for cell in row.iter().filter(|c| !c.is_wide_continuation) {
+            cell.push_to(&mut text);
+        }
+        Some(text)
+    }
+
+    /// Returns the style of the cell at (x, y).
+    pub fn style_at(&self, x: u16, y: u16) -> Option<Style> {
+        self.grid
+            .get(y as usize)
+            .and_then(|row| row.get(x as usize))
+            .map(|cell| cell.style)
+    }
+
+    /// Replaces the style of every cell with `f(x, y, style)`.
+    pub fn map_styles(&mut self, mut f: impl FnMut(u16, u16, Style) -> Style) {
+        for (y, row) in self.grid.iter_mut().enumerate() {
+            for (x, cell) in row.iter_mut().enumerate() {
+                cell.style = f(x as u16, y as u16, cell.style);
+            }
+        }
+    }
+
+    /// Replaces the style of the cells in the `width` x `height` area at
+    /// (x, y), in content coordinates, with `f(style)`.
+    pub fn map_area_styles(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        mut f: impl FnMut(Style) -> Style,
+    ) {
+        for row in y..y.saturating_add(height) {
+            let Some(grid_y) = self.grid_row(row as usize) else {
+                continue;
+            };
+            let cells = &mut self.grid[grid_y];
+            let end = (x.saturating_add(width) as usize).min(cells.len());
+            for cell in cells.iter_mut().take(end).skip(x as usize) {
+                cell.style = f(cell.style);
+            }
+        }
+    }
+
+    /// Converts the grid to a [`Buffer`] for frame-to-frame diffing.
+    ///
+    /// Wide character continuations become cells with an empty symbol.
+    /// Images and hyperlinks aren't represented; check
+    /// [`has_overlays`](Self::has_overlays) before relying on the buffer alone.
+    pub fn to_buffer(&self) -> Buffer {
+        let mut buffer = Buffer::new(self.width, self.height);
+        for (y, row) in self.grid.iter().enumerate() {
+            for (x, styled_char) in row.iter().enumerate() {
+                let cell = buffer.get_mut(x as u16, y as u16);
+                if styled_char.is_wide_continuation {
+                    cell.set_symbol("");
+                } else {
+                    let mut symbol = String::new();
+                    styled_char.push_to(&mut symbol);
+                    cell.set_symbol(&symbol);
+                }
+                cell.set_style(styled_char.style);
+            }
+        }
+        buffer
+    }
+
+    /// Whether the grid has content beyond plain styled cells (pixel images
+    /// or hyperlinks), which a cell diff can't reproduce.
+    pub fn has_overlays(&self) -> bool {
+        !self.images.is_empty() || self.grid.iter().flatten().any(|c| c.link.is_some())
+    }
+
     /// Renders the grid to a string with ANSI escape codes.
     ///
     /// Each line has trailing whitespace trimmed (like Ink does).
@@ -185,16 +595,43 @@ impl Output {
     pub fn get(&self) -> OutputResult {
         let mut lines: Vec<String> = Vec::with_capacity(self.height as usize);
 
-        for row in &self.grid {
+        for (y, row) in self.grid.iter().enumerate() {
             let mut line = String::new();
             let mut current_style: Option<Style> = None;
+            let mut current_link: Option<usize> = None;
+            let mut col = 0;
+
+            while col < row.len() {
+                // Pixel images: draw at the origin without moving the cursor,
+                // then step over the reserved cells
+                if let Some(image) = self.images.iter().find(|p| p.covers(col as u16, y as u16)) {
+                    self.switch_link(&mut line, &mut current_link, None);
+                    if let Some(style) = current_style.take() {
+                        if !style.to_ansi_string().is_empty() {
+                            line.push_str(&Style::reset_ansi());
+                        }
+                    }
+                    if image.x as usize == col && image.y as usize == y {
+                        line.push_str("\x1b7");
+                        line.push_str(&image.escape);
+                        line.push_str("\x1b8");
+                    }
+                    let skip = (image.x + image.cols) as usize - col;
+                    line.push_str(&format!("\x1b[{}C", skip));
+                    col += skip;
+                    continue;
+                }
+
+                let styled_char = &row[col];
+                col += 1;
 
-            for styled_char in row {
                 // Skip wide character continuations (see write() for why these exist)
                 if styled_char.is_wide_continuation {
                     continue;
                 }
 
+                self.switch_link(&mut line, &mut current_link, styled_char.link);
+
                 // Only emit ANSI codes when style changes (optimization)
                 if Some(styled_char.style) != current_style {
                     // Reset previous style if any
@@ -209,7 +646,7 @@ impl Output {
                     current_style = Some(styled_char.style);
                 }
 
-                line.push(styled_char.char);
+                styled_char.push_to(&mut line);
             }
 
             // Reset at end of line if we have an active style
@@ -219,6 +656,10 @@ impl Output {
                 }
             }
 
+            // Links are closed per row so clipped or wrapped regions never
+            // leak a target into the next line
+            self.switch_link(&mut line, &mut current_link, None);
+
             // Trim trailing whitespace (but preserve ANSI sequences)
             let line = Self::trim_trailing_whitespace(&line);
             lines.push(line);
@@ -232,6 +673,26 @@ impl Output {
         }
     }
 
+    /// Emits OSC 8 sequences when the hyperlink target changes.
+    ///
+    /// Rows of the same region share an `id`, so terminals highlight the
+    /// whole region on hover even though each row is opened separately.
+    fn switch_link(&self, line: &mut String, current: &mut Option<usize>, next: Option<usize>) {
+        if *current == next {
+            return;
+        }
+        if current.is_some() {
+            line.push_str("\x1b]8;;\x07");
+        }
+        if let Some(index) = next {
+            line.push_str(&format!(
+                "\x1b]8;id=blaeck-{};{}\x07",
+                index, self.links[index]
+            ));
+        }
+        *current = next;
+    }
+
     /// Trims trailing whitespace from a line while preserving ANSI escape sequences.
     fn trim_trailing_whitespace(line: &str) -> String {
         // Simple approach: trim trailing spaces, ANSI reset sequences will be preserved
@@ -240,10 +701,37 @@ impl Output {
     }
 }
 
+/// Replaces each `\t` with spaces up to the next multiple of `tab_width`.
+///
+/// Columns are counted in display width from the start of `line`, which
+/// must not contain escape sequences. Returns the input unchanged, without
+/// allocating, when it has no tabs.
+pub fn expand_tabs(line: &str, tab_width: u16) -> Cow<'_, str> {
+    if !line.contains('\t') {
+        return Cow::Borrowed(line);
+    }
+    let tab_width = tab_width as usize;
+    let mut out = String::with_capacity(line.len() + tab_width);
+    let mut column = 0;
+    for grapheme in line.graphemes(true) {
+        if grapheme == "\t" {
+            if tab_width > 0 {
+                let spaces = tab_width - column % tab_width;
+                out.extend(std::iter::repeat_n(' ', spaces));
+                column += spaces;
+            }
+        } else {
+            out.push_str(grapheme);
+            column += grapheme.width();
+        }
+    }
+    Cow::Owned(out)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::style::Color;
+    use crate::style::{Color, Modifier};
 
     #[test]
     fn test_output_new() {
@@ -318,6 +806,55 @@ mod tests {
         assert!(result.output.contains("日本語"));
     }
 
+    #[test]
+    fn test_output_grapheme_clusters() {
+        let mut out = Output::new(20, 1);
+        // ZWJ family, flag, combining accent: one cell group each
+        out.write(0, 0, "👨‍👩‍👧|🇸🇪|e\u{301}|", Style::default());
+        let result = out.get();
+        assert_eq!(result.output, "👨‍👩‍👧|🇸🇪|e\u{301}|");
+
+        // Columns stay aligned: the 3rd '|' is at column 2 + 1 + 2 + 1 + 1
+        let buffer = out.to_buffer();
+        assert_eq!(buffer.get(2, 0).symbol, "|");
+        assert_eq!(buffer.get(5, 0).symbol, "|");
+        assert_eq!(buffer.get(6, 0).symbol, "e\u{301}");
+        assert_eq!(buffer.get(7, 0).symbol, "|");
+    }
+
+    #[test]
+    fn test_output_expands_tabs() {
+        assert_eq!(expand_tabs("a\tbc\td", 4), "a   bc  d");
+        assert_eq!(expand_tabs("日\tx", 4), "日  x");
+        assert_eq!(expand_tabs("a\tb", 0), "ab");
+
+        let mut out = Output::new(20, 1);
+        out.set_tab_width(8);
+        out.write(2, 0, "x\ty", Style::default());
+        // Tab stops count from the write origin, not the grid edge
+        assert_eq!(out.get().output, "  x       y");
+    }
+
+    #[test]
+    fn test_output_wide_char_shadow() {
+        let mut out = Output::new(10, 1);
+        out.write(0, 0, "日本", Style::default());
+        // Overwriting the right half of 日 blanks its left half
+        out.write(1, 0, "x", Style::default());
+        assert_eq!(out.get().output, " x本");
+
+        let mut out = Output::new(10, 1);
+        out.write(0, 0, "日本", Style::default());
+        // Overwriting the left half of 本 blanks its right half
+        out.write(2, 0, "y", Style::default());
+        assert_eq!(out.get().output, "日y");
+
+        // A wide char with one column left doesn't spill past the edge
+        let mut out = Output::new(3, 1);
+        out.write(0, 0, "ab日", Style::default());
+        assert_eq!(out.get().output, "ab");
+    }
+
     #[test]
     fn test_output_trims_trailing_whitespace() {
         let mut out = Output::new(80, 3);
@@ -348,6 +885,27 @@ mod tests {
         assert_eq!(result.output.trim(), "Hello");
     }
 
+    #[test]
+    fn test_output_write_skips_non_sgr_csi() {
+        let mut out = Output::new(20, 1);
+        out.write(0, 0, "ab\x1b[3Ccd\x1b[Kef", Style::default());
+        let result = out.get();
+        assert_eq!(result.output, "abcdef");
+    }
+
+    #[test]
+    fn test_output_write_skips_graphics_strings() {
+        let mut out = Output::new(20, 1);
+        out.write(
+            0,
+            0,
+            "a\x1b_Gf=100;AAAA\x1b\\b\x1b7c\x1b8",
+            Style::default(),
+        );
+        let result = out.get();
+        assert_eq!(result.output, "abc");
+    }
+
     #[test]
     fn test_output_clips_beyond_height() {
         let mut out = Output::new(80, 2);
@@ -371,6 +929,167 @@ mod tests {
         assert!(result.output.contains("34")); // Blue fg
     }
 
+    #[test]
+    fn test_output_write_image_halfblocks() {
+        let mut out = Output::new(10, 2);
+        let image = ImageData::solid(4, 4, [255, 0, 0, 255]);
+        out.write_image(1, 0, 2, 2, &image, GraphicsProtocol::HalfBlocks);
+        let result = out.get();
+        let lines: Vec<&str> = result.output.split("\r\n").collect();
+        assert!(lines[0].contains("▀▀"));
+        assert!(lines[1].contains("▀▀"));
+    }
+
+    #[test]
+    fn test_output_write_image_reserves_cells() {
+        let mut out = Output::new(10, 2);
+        out.write(0, 0, "abcdefghij", Style::default());
+        let image = ImageData::solid(2, 2, [0, 255, 0, 255]);
+        out.write_image(2, 0, 3, 2, &image, GraphicsProtocol::Kitty);
+        out.write(0, 1, "XY", Style::default());
+        let result = out.get();
+        let lines: Vec<&str> = result.output.split("\r\n").collect();
+        // Escape at the origin, wrapped in save/restore, then step over the cells
+        assert!(lines[0].starts_with("ab\x1b7\x1b_G"));
+        assert!(lines[0].ends_with("\x1b8\x1b[3Cfghij"));
+        assert_eq!(lines[1], "XY\x1b[3C");
+    }
+
+    #[test]
+    fn test_output_write_image_clipped() {
+        let mut out = Output::new(4, 1);
+        let image = ImageData::solid(2, 2, [0, 0, 255, 255]);
+        out.write_image(3, 0, 5, 5, &image, GraphicsProtocol::HalfBlocks);
+        out.write_image(9, 9, 1, 1, &image, GraphicsProtocol::HalfBlocks);
+        let result = out.get();
+        assert_eq!(result.height, 1);
+        assert!(result.output.contains('▀'));
+    }
+
+    #[test]
+    fn test_output_link_region() {
+        let mut out = Output::new(10, 2);
+        out.write(0, 0, "a", Style::default());
+        out.push_link("https://example.com");
+        out.write(1, 0, "bc\nde", Style::default());
+        out.pop_link();
+        out.write(3, 0, "f", Style::default());
+        let result = out.get();
+        let lines: Vec<&str> = result.output.split("\r\n").collect();
+        let open = "\x1b]8;id=blaeck-0;https://example.com\x07";
+        let close = "\x1b]8;;\x07";
+        assert_eq!(lines[0], format!("a{open}bc{close}f"));
+        // Second row reopens the same link and closes it at end of line
+        assert_eq!(lines[1], format!(" {open}de{close}"));
+    }
+
+    #[test]
+    fn test_output_link_clipped() {
+        let mut out = Output::new(3, 1);
+        out.push_link("https://example.com");
+        out.write(1, 0, "hello", Style::default());
+        out.pop_link();
+        let result = out.get();
+        assert!(result.output.ends_with("he\x1b]8;;\x07"));
+    }
+
+    #[test]
+    fn test_output_nested_links() {
+        let mut out = Output::new(10, 1);
+        out.push_link("outer");
+        out.write(0, 0, "ab", Style::default());
+        out.push_link("inner");
+        out.write(2, 0, "cd", Style::default());
+        out.pop_link();
+        out.write(4, 0, "ef", Style::default());
+        out.pop_link();
+        let result = out.get();
+        assert_eq!(
+            result.output,
+            "\x1b]8;id=blaeck-0;outer\x07ab\x1b]8;;\x07\x1b]8;id=blaeck-1;inner\x07cd\x1b]8;;\x07\x1b]8;id=blaeck-0;outer\x07ef\x1b]8;;\x07"
+        );
+    }
+
+    #[test]
+    fn test_output_cell_access() {
+        let mut out = Output::new(10, 2);
+        out.write(0, 1, "日x", Style::new().fg(Color::Red));
+        assert_eq!(out.line_text(1).as_deref(), Some("日x       "));
+        assert_eq!(out.line_text(2), None);
+
+        out.map_styles(|_, y, style| if y == 1 { style.dim() } else { style });
+        assert!(out
+            .style_at(2, 1)
+            .unwrap()
+            .modifiers
+            .contains(Modifier::DIM));
+        assert_eq!(out.style_at(0, 0), Some(Style::default()));
+    }
+
+    #[test]
+    fn test_output_map_area_styles() {
+        let mut out = Output::window(4, 2, 5);
+        out.map_area_styles(1, 4, 2, 2, |style| style.dim());
+        let dim = |x, y| {
+            out.style_at(x, y)
+                .unwrap()
+                .modifiers
+                .contains(Modifier::DIM)
+        };
+        assert!(dim(1, 0) && dim(2, 0));
+        assert!(!dim(0, 0) && !dim(3, 0) && !dim(1, 1));
+    }
+
+    #[test]
+    fn test_output_to_buffer() {
+        let mut out = Output::new(5, 2);
+        out.write(0, 0, "日a", Style::new().fg(Color::Red));
+        assert!(!out.has_overlays());
+        let buffer = out.to_buffer();
+        assert_eq!(buffer.get(0, 0).symbol, "日");
+        assert_eq!(buffer.get(1, 0).symbol, "");
+        assert_eq!(buffer.get(2, 0).symbol, "a");
+        assert_eq!(buffer.get(2, 0).fg, Color::Red);
+        assert_eq!(buffer.get(0, 1).symbol, " ");
+
+        out.push_link("https://example.com");
+        out.write(0, 1, "x", Style::default());
+        out.pop_link();
+        assert!(out.has_overlays());
+    }
+
+    #[test]
+    fn test_output_window() {
+        let mut output = Output::window(10, 2, 3);
+        assert_eq!(output.visible_rows(), 3..5);
+        output.write(0, 2, "above\nfirst\nsecond\nbelow", Style::default());
+        output.write(2, 5, "past", Style::default());
+        assert_eq!(output.line_text(0).unwrap().trim_end(), "first");
+        assert_eq!(output.line_text(1).unwrap().trim_end(), "second");
+        assert_eq!(output.line_text(2), None);
+    }
+
+    #[test]
+    fn test_output_scroll_targets() {
+        let mut out = Output::new(10, 4);
+        out.mark_scroll_target(0, 0, 10, 4, "outer");
+        out.mark_scroll_target(2, 1, 3, 2, "inner");
+        out.write(2, 1, "text", Style::default());
+        assert_eq!(out.scroll_target_at(0, 0), Some("outer"));
+        assert_eq!(out.scroll_target_at(3, 2), Some("inner"));
+        assert_eq!(out.scroll_target_at(5, 1), Some("outer"));
+        assert_eq!(out.scroll_target_at(10, 0), None);
+
+        // Claims survive being blitted through a scrolled window
+        let mut canvas = Output::window(4, 2, 1);
+        canvas.mark_scroll_target(0, 0, 4, 3, "list");
+        let mut screen = Output::new(10, 2);
+        screen.blit(&canvas, 0, 5, 0, 2);
+        assert_eq!(screen.scroll_target_at(5, 0), Some("list"));
+        assert_eq!(screen.scroll_target_at(8, 1), Some("list"));
+        assert_eq!(screen.scroll_target_at(4, 0), None);
+    }
+
     #[test]
     fn test_output_empty_write() {
         let mut out = Output::new(80, 5);
@@ -0,0 +1,414 @@
+//! Positioning for floating UI: tooltips, popovers, menus, suggestion lists.
+//!
+//! A floating element sits next to an anchor (the input a suggestion list
+//! belongs to, the button a menu opens from). [`Positioner`] picks where it
+//! goes: on the preferred side of the anchor, flipped to the opposite side
+//! when there isn't room, slid along the anchor to stay inside the terminal,
+//! and clipped as a last resort. It never overlaps the anchor.
+//!
+//! ```text
+//!            ┌────────┐
+//!            │ popover│   Side::Top, flipped from Bottom because the
+//!            └────────┘   anchor is near the bottom edge
+//!   [ anchor ]
+//! ```
+//!
+//! The result is a plain [`Rect`], and [`float`] wraps an element in an
+//! absolutely positioned Box at that rect:
+//!
+//! ```ignore
+//! let button = Rect::new(2, 20, 10, 1);
+//! let placement = Positioner::new(Side::Bottom)
+//!     .place(button, popover_size, Rect::new(0, 0, width, height));
+//!
+//! element! {
+//!     Box(width: width as f32, height: height as f32) {
+//!         #(main_ui)
+//!         #(float(placement, popover))
+//!     }
+//! }
+//! ```
+//!
+//! Components with floating parts do this for you, e.g.
+//! [`AutocompleteProps::render_suggestions`](crate::components::AutocompleteProps::render_suggestions)
+//! and [`ContextMenuState::render`](crate::components::ContextMenuState::render).
+
+use crate::components::{Box, BoxProps};
+use crate::element::Element;
+use crate::layout::Position;
+
+/// A rectangle of terminal cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct Rect {
+    /// Left column.
+    pub x: u16,
+    /// Top row.
+    pub y: u16,
+    /// Width in columns.
+    pub width: u16,
+    /// Height in rows.
+    pub height: u16,
+}
+
+impl Rect {
+    /// Create a rectangle.
+    pub const fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Column just past the right edge.
+    pub fn right(&self) -> u16 {
+        self.x.saturating_add(self.width)
+    }
+
+    /// Row just past the bottom edge.
+    pub fn bottom(&self) -> u16 {
+        self.y.saturating_add(self.height)
+    }
+
+    /// Whether the rectangle covers no cells.
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Whether the cell at `(x, y)` is inside.
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+
+    /// The overlap of two rectangles (empty if they don't overlap).
+    pub fn intersection(&self, other: Rect) -> Rect {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+        Rect::new(x, y, right.saturating_sub(x), bottom.saturating_sub(y))
+    }
+
+    /// Whether the rectangles share any cell.
+    pub fn intersects(&self, other: Rect) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The rectangle with `margin` cells removed on every side.
+    pub fn shrink(&self, margin: u16) -> Rect {
+        Rect::new(
+            self.x.saturating_add(margin),
+            self.y.saturating_add(margin),
+            self.width.saturating_sub(margin.saturating_mul(2)),
+            self.height.saturating_sub(margin.saturating_mul(2)),
+        )
+    }
+}
+
+/// Side of the anchor a floating element is placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub enum Side {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Side {
+    /// The side across the anchor.
+    pub fn opposite(self) -> Side {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+
+    /// Whether the element is above or below the anchor.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Side::Top | Side::Bottom)
+    }
+}
+
+/// Alignment with the anchor along the side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub enum Align {
+    /// Left edges (or top edges) line up.
+    #[default]
+    Start,
+    /// Centered on the anchor.
+    Center,
+    /// Right edges (or bottom edges) line up.
+    End,
+}
+
+/// Where a floating element ended up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    /// Cells the element occupies.
+    pub rect: Rect,
+    /// Side it was placed on, after any flip.
+    pub side: Side,
+    /// Whether it had to be made smaller than requested to fit.
+    pub clipped: bool,
+}
+
+impl Placement {
+    /// Position a Box absolutely at this placement.
+    ///
+    /// The Box's parent must start at the origin of the bounds passed to
+    /// [`Positioner::place`] (usually the root).
+    pub fn apply(&self, props: BoxProps) -> BoxProps {
+        BoxProps {
+            position: Position::Absolute,
+            inset_left: Some(f32::from(self.rect.x)),
+            inset_top: Some(f32::from(self.rect.y)),
+            width: Some(f32::from(self.rect.width)),
+            height: Some(f32::from(self.rect.height)),
+            ..props
+        }
+    }
+}
+
+/// Wrap `child` in an absolutely positioned Box at `placement`.
+pub fn float(placement: Placement, child: impl Into<Element>) -> Element {
+    Element::node::<Box>(placement.apply(BoxProps::default()), vec![child.into()])
+}
+
+/// Center an element of `size` (width, height) in `bounds`, shrinking it
+/// to fit when it's larger.
+pub fn center(size: (u16, u16), bounds: Rect) -> Placement {
+    let width = size.0.min(bounds.width);
+    let height = size.1.min(bounds.height);
+    Placement {
+        rect: Rect::new(
+            bounds.x + (bounds.width - width) / 2,
+            bounds.y + (bounds.height - height) / 2,
+            width,
+            height,
+        ),
+        side: Side::Bottom,
+        clipped: (width, height) != size,
+    }
+}
+
+/// Computes where to put a floating element next to an anchor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Positioner {
+    /// Preferred side.
+    pub side: Side,
+    /// Alignment along the side.
+    pub align: Align,
+    /// Cells between the anchor and the element.
+    pub offset: u16,
+    /// Move to the opposite side when it has more room.
+    pub flip: bool,
+    /// Slide along the side to stay in bounds.
+    pub shift: bool,
+    /// Cells to keep clear at the edges of the bounds.
+    pub margin: u16,
+}
+
+impl Default for Positioner {
+    fn default() -> Self {
+        Self::new(Side::Bottom)
+    }
+}
+
+impl Positioner {
+    /// Prefer `side`, start-aligned, flipping and shifting as needed.
+    pub fn new(side: Side) -> Self {
+        Self {
+            side,
+            align: Align::Start,
+            offset: 0,
+            flip: true,
+            shift: true,
+            margin: 0,
+        }
+    }
+
+    /// Set the alignment along the side.
+    #[must_use]
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set the gap between the anchor and the element.
+    #[must_use]
+    pub fn offset(mut self, offset: u16) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Enable or disable flipping to the opposite side.
+    #[must_use]
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    /// Enable or disable shifting along the side. Without it, the part
+    /// that doesn't fit is clipped.
+    #[must_use]
+    pub fn shift(mut self, shift: bool) -> Self {
+        self.shift = shift;
+        self
+    }
+
+    /// Keep `margin` cells clear at the edges of the bounds.
+    #[must_use]
+    pub fn margin(mut self, margin: u16) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Cells free on `side` of the anchor.
+    fn space(&self, side: Side, anchor: Rect, bounds: Rect) -> i32 {
+        let offset = i32::from(self.offset);
+        match side {
+            Side::Top => i32::from(anchor.y) - offset - i32::from(bounds.y),
+            Side::Bottom => i32::from(bounds.bottom()) - i32::from(anchor.bottom()) - offset,
+            Side::Left => i32::from(anchor.x) - offset - i32::from(bounds.x),
+            Side::Right => i32::from(bounds.right()) - i32::from(anchor.right()) - offset,
+        }
+    }
+
+    /// Place an element of `size` (width, height) next to `anchor`, inside
+    /// `bounds`.
+    pub fn place(&self, anchor: Rect, size: (u16, u16), bounds: Rect) -> Placement {
+        let bounds = bounds.shrink(self.margin);
+        let (width, height) = (i32::from(size.0), i32::from(size.1));
+
+        let need = |side: Side| if side.is_vertical() { height } else { width };
+        let mut side = self.side;
+        if self.flip {
+            let here = self.space(side, anchor, bounds);
+            let there = self.space(side.opposite(), anchor, bounds);
+            if here < need(side) && there > here {
+                side = side.opposite();
+            }
+        }
+
+        // Main axis: shrink to the free space so the anchor stays uncovered
+        let main = need(side).min(self.space(side, anchor, bounds).max(0));
+        let offset = i32::from(self.offset);
+        let main_pos = match side {
+            Side::Top => i32::from(anchor.y) - offset - main,
+            Side::Bottom => i32::from(anchor.bottom()) + offset,
+            Side::Left => i32::from(anchor.x) - offset - main,
+            Side::Right => i32::from(anchor.right()) + offset,
+        };
+
+        // Cross axis: align with the anchor, then slide into bounds
+        let (anchor_start, anchor_len, lo, hi, cross) = if side.is_vertical() {
+            (anchor.x, anchor.width, bounds.x, bounds.right(), width)
+        } else {
+            (anchor.y, anchor.height, bounds.y, bounds.bottom(), height)
+        };
+        let (anchor_start, anchor_len) = (i32::from(anchor_start), i32::from(anchor_len));
+        let (lo, hi) = (i32::from(lo), i32::from(hi));
+        let mut cross_pos = match self.align {
+            Align::Start => anchor_start,
+            Align::Center => anchor_start + (anchor_len - cross) / 2,
+            Align::End => anchor_start + anchor_len - cross,
+        };
+        if self.shift {
+            cross_pos = cross_pos.min(hi - cross).max(lo);
+        }
+        let cross_end = (cross_pos + cross).min(hi);
+        let cross_pos = cross_pos.max(lo);
+        let cross = (cross_end - cross_pos).max(0);
+
+        let clamp = |v: i32| v.clamp(0, i32::from(u16::MAX)) as u16;
+        let rect = if side.is_vertical() {
+            Rect::new(clamp(cross_pos), clamp(main_pos), clamp(cross), clamp(main))
+        } else {
+            Rect::new(clamp(main_pos), clamp(cross_pos), clamp(main), clamp(cross))
+        };
+        Placement {
+            rect,
+            side,
+            clipped: (rect.width, rect.height) != size,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCREEN: Rect = Rect::new(0, 0, 40, 10);
+
+    #[test]
+    fn test_center() {
+        let p = center((10, 4), SCREEN);
+        assert_eq!(p.rect, Rect::new(15, 3, 10, 4));
+        assert!(!p.clipped);
+
+        let p = center((50, 4), Rect::new(2, 0, 40, 10));
+        assert_eq!(p.rect, Rect::new(2, 3, 40, 4));
+        assert!(p.clipped);
+    }
+
+    #[test]
+    fn test_place_preferred_side() {
+        let anchor = Rect::new(5, 2, 10, 1);
+        let p = Positioner::new(Side::Bottom).place(anchor, (8, 3), SCREEN);
+        assert_eq!(p.rect, Rect::new(5, 3, 8, 3));
+        assert_eq!(p.side, Side::Bottom);
+        assert!(!p.clipped);
+
+        let p = Positioner::new(Side::Right)
+            .offset(1)
+            .align(Align::Center)
+            .place(anchor, (4, 3), SCREEN);
+        assert_eq!(p.rect, Rect::new(16, 1, 4, 3));
+
+        let p = Positioner::new(Side::Bottom)
+            .align(Align::End)
+            .place(anchor, (4, 1), SCREEN);
+        assert_eq!(p.rect.x, 11);
+    }
+
+    #[test]
+    fn test_place_flips_and_shifts() {
+        // Near the bottom edge: flips above
+        let anchor = Rect::new(35, 8, 5, 1);
+        let p = Positioner::new(Side::Bottom).place(anchor, (10, 4), SCREEN);
+        assert_eq!(p.side, Side::Top);
+        // Shifted left to stay on screen
+        assert_eq!(p.rect, Rect::new(30, 4, 10, 4));
+        assert!(!p.rect.intersects(anchor));
+
+        // Without shift the overflow is clipped instead
+        let p = Positioner::new(Side::Top)
+            .shift(false)
+            .place(anchor, (10, 4), SCREEN);
+        assert_eq!(p.rect, Rect::new(35, 4, 5, 4));
+        assert!(p.clipped);
+    }
+
+    #[test]
+    fn test_place_clips_to_available_space() {
+        // Taller than either side: takes the larger side, shrunk to fit
+        let anchor = Rect::new(0, 3, 10, 1);
+        let p = Positioner::new(Side::Top).place(anchor, (10, 8), SCREEN);
+        assert_eq!(p.side, Side::Bottom);
+        assert_eq!(p.rect, Rect::new(0, 4, 10, 6));
+        assert!(p.clipped);
+
+        let p = Positioner::new(Side::Bottom)
+            .margin(1)
+            .place(anchor, (50, 2), SCREEN);
+        assert_eq!(p.rect, Rect::new(1, 4, 38, 2));
+
+        let props = p.apply(BoxProps::default());
+        assert_eq!(props.position, Position::Absolute);
+        assert_eq!(props.inset_top, Some(4.0));
+    }
+}
@@ -0,0 +1,307 @@
+//! Paged output for one-shot rendering.
+//!
+//! [`crate::print`] writes everything straight to stdout, which is fine for a
+//! status line but not for a 400-line report. [`print_paged`] renders the
+//! element the same way and, when the result is taller than the terminal,
+//! pipes it into the user's pager instead — the same behavior as `git log`.
+//!
+//! The pager is taken from `$PAGER`, falling back to `less`; if it isn't
+//! installed, the output is printed directly. Like git, `LESS` defaults to
+//! `FRX` when unset so colors pass through (`R`), short output exits
+//! immediately (`F`), and the screen isn't cleared on exit (`X`).
+//!
+//! ```ignore
+//! use blaeck::prelude::*;
+//! use blaeck::{print_paged, PagerConfig};
+//!
+//! print_paged(report, PagerConfig::default())?;
+//!
+//! // Plain text (no ANSI) for pagers that don't understand escapes
+//! print_paged(report, PagerConfig::default().plain())?;
+//!
+//! // Start at the first "§" line; `n`/`N` in less jump between sections
+//! print_paged(manual, PagerConfig::default().section_marker("§"))?;
+//! ```
+//!
+//! [`marked_sections`](crate::components::marked_sections) extracts the same
+//! sections for an in-app [`Toc`](crate::components::Toc).
+
+use crate::element::Element;
+use crate::renderer::{strip_ansi_escapes, Blaeck, Result};
+use std::io::{self, IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+/// When to send output through the pager.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PagerMode {
+    /// Page only when stdout is a terminal and the output is taller than it.
+    #[default]
+    Auto,
+    /// Always page when stdout is a terminal.
+    Always,
+    /// Never page; behaves like [`crate::print`].
+    Never,
+}
+
+/// Configuration for [`print_paged`].
+#[derive(Debug, Clone)]
+pub struct PagerConfig {
+    /// When to use the pager.
+    pub mode: PagerMode,
+    /// Whether to keep ANSI styling in the paged output.
+    pub styled: bool,
+    /// Pager command override. Uses `$PAGER`, then `less`, when `None`.
+    pub command: Option<String>,
+    /// Prefix of section heading lines. When the pager is `less`, it opens
+    /// at the first section and `n`/`N` jump to the next/previous one.
+    pub section_marker: Option<String>,
+}
+
+impl Default for PagerConfig {
+    fn default() -> Self {
+        Self {
+            mode: PagerMode::Auto,
+            styled: true,
+            command: None,
+            section_marker: None,
+        }
+    }
+}
+
+impl PagerConfig {
+    /// Set the paging mode.
+    #[must_use]
+    pub fn mode(mut self, mode: PagerMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Strip ANSI styling from the paged output.
+    #[must_use]
+    pub fn plain(mut self) -> Self {
+        self.styled = false;
+        self
+    }
+
+    /// Use a specific pager command instead of `$PAGER`.
+    #[must_use]
+    pub fn command(mut self, command: impl Into<String>) -> Self {
+        self.command = Some(command.into());
+        self
+    }
+
+    /// Mark lines starting with `marker` as section headings.
+    #[must_use]
+    pub fn section_marker(mut self, marker: impl Into<String>) -> Self {
+        self.section_marker = Some(marker.into());
+        self
+    }
+
+    /// Decide whether output of `line_count` lines should be paged.
+    pub fn should_page(&self, line_count: usize, terminal_height: u16, is_tty: bool) -> bool {
+        if !is_tty {
+            return false;
+        }
+        match self.mode {
+            PagerMode::Auto => line_count > terminal_height as usize,
+            PagerMode::Always => true,
+            PagerMode::Never => false,
+        }
+    }
+
+    /// Resolve the pager command into a program and its arguments.
+    ///
+    /// Returns `None` if the configured pager is empty or `cat`, which git
+    /// also treats as "don't page".
+    pub fn resolve_command(&self) -> Option<(String, Vec<String>)> {
+        let raw = self
+            .command
+            .clone()
+            .or_else(|| std::env::var("PAGER").ok())
+            .unwrap_or_else(|| "less".to_string());
+        let mut parts = raw.split_whitespace().map(String::from);
+        let program = parts.next()?;
+        if program == "cat" {
+            return None;
+        }
+        let mut args: Vec<String> = parts.collect();
+        if let Some(marker) = self.section_marker.as_deref().filter(|m| !m.is_empty()) {
+            // less searches the text with escapes removed under -R
+            if program == "less" || program.ends_with("/less") {
+                args.push(format!("--pattern=^{}", escape_pattern(marker)));
+            }
+        }
+        Some((program, args))
+    }
+}
+
+/// Escape regex metacharacters for a less search pattern.
+fn escape_pattern(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\.^$*+?()[]{}|".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Render an element to a string at the given width, without a terminal.
+///
+/// Lines are separated by `\n` (not the `\r\n` used for raw-mode rendering).
+pub fn render_to_string(element: &Element, width: u16) -> Result<String> {
+    let mut engine = Blaeck::with_size(io::sink(), width, u16::MAX)?;
+    Ok(engine.render_element(element)?.replace("\r\n", "\n"))
+}
+
+/// Print an element to stdout, paging it if it's taller than the terminal.
+///
+/// See the [module docs](self) for pager selection. If the pager isn't
+/// installed, the output is printed as is.
+pub fn print_paged(element: Element, config: PagerConfig) -> Result<()> {
+    let (width, height) = crossterm::terminal::size().unwrap_or((80, 24));
+    let rendered = render_to_string(&element, width)?;
+    let mut stdout = io::stdout();
+    let is_tty = stdout.is_terminal();
+    write_paged(&mut stdout, &rendered, &config, height, is_tty)
+}
+
+/// Page `rendered` or, when it shouldn't be paged or the pager can't be
+/// started, write it to `out`.
+fn write_paged<W: Write>(
+    out: &mut W,
+    rendered: &str,
+    config: &PagerConfig,
+    height: u16,
+    is_tty: bool,
+) -> Result<()> {
+    let line_count = rendered.lines().count();
+    if config.should_page(line_count, height, is_tty) {
+        if let Some((program, args)) = config.resolve_command() {
+            let content = if config.styled {
+                rendered.into()
+            } else {
+                strip_ansi_escapes(rendered)
+            };
+            if pipe_to_pager(&program, &args, &content)? {
+                return Ok(());
+            }
+        }
+    }
+
+    writeln!(out, "{}", rendered)?;
+    out.flush()?;
+    Ok(())
+}
+
+/// Spawn the pager and feed it the content, waiting for it to exit.
+///
+/// Returns false, without writing anything, if the pager isn't installed.
+fn pipe_to_pager(program: &str, args: &[String], content: &str) -> Result<bool> {
+    let mut command = Command::new(program);
+    command.args(args).stdin(Stdio::piped());
+    if std::env::var_os("LESS").is_none() {
+        command.env("LESS", "FRX");
+    }
+
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e.into()),
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // The user may quit the pager before reading everything
+        match writeln!(stdin, "{}", content) {
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => {}
+            other => other?,
+        }
+    }
+    child.wait()?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{Text, TextProps};
+
+    #[test]
+    fn test_should_page_auto() {
+        let config = PagerConfig::default();
+        assert!(config.should_page(100, 24, true));
+        assert!(!config.should_page(10, 24, true));
+        assert!(!config.should_page(100, 24, false));
+    }
+
+    #[test]
+    fn test_should_page_modes() {
+        let always = PagerConfig::default().mode(PagerMode::Always);
+        assert!(always.should_page(1, 24, true));
+        assert!(!always.should_page(1, 24, false));
+
+        let never = PagerConfig::default().mode(PagerMode::Never);
+        assert!(!never.should_page(1000, 24, true));
+    }
+
+    #[test]
+    fn test_resolve_command_override() {
+        let config = PagerConfig::default().command("less -S -R");
+        let (program, args) = config.resolve_command().unwrap();
+        assert_eq!(program, "less");
+        assert_eq!(args, vec!["-S", "-R"]);
+    }
+
+    #[test]
+    fn test_resolve_command_section_marker() {
+        let config = PagerConfig::default()
+            .command("less -S")
+            .section_marker("## ");
+        let (_, args) = config.resolve_command().unwrap();
+        assert_eq!(args, vec!["-S", "--pattern=^## "]);
+
+        let (_, args) = config.section_marker("1.").resolve_command().unwrap();
+        assert_eq!(args, vec!["-S", "--pattern=^1\\."]);
+
+        let config = PagerConfig::default().command("most").section_marker("#");
+        assert!(config.resolve_command().unwrap().1.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_command_cat_disables() {
+        assert!(PagerConfig::default()
+            .command("cat")
+            .resolve_command()
+            .is_none());
+        assert!(PagerConfig::default()
+            .command("  ")
+            .resolve_command()
+            .is_none());
+    }
+
+    #[test]
+    fn test_missing_pager_prints_directly() {
+        let config = PagerConfig::default()
+            .mode(PagerMode::Always)
+            .command("blaeck-no-such-pager");
+        let mut out = Vec::new();
+        write_paged(&mut out, "report", &config, 24, true).unwrap();
+        assert_eq!(out, b"report\n");
+    }
+
+    #[test]
+    fn test_render_to_string_tall_content() {
+        let content: Vec<String> = (0..200).map(|i| format!("line {}", i)).collect();
+        let elem = Element::node::<Text>(
+            TextProps {
+                content: content.join("\n"),
+                ..Default::default()
+            },
+            vec![],
+        );
+        let rendered = render_to_string(&elem, 40).unwrap();
+        assert_eq!(rendered.lines().count(), 200);
+        assert!(!rendered.contains('\r'));
+        assert!(rendered.ends_with("line 199"));
+    }
+}
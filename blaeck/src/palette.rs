@@ -0,0 +1,342 @@
+//! Semantic status colors, color-blind safe palettes and a simulation filter.
+//!
+//! Components that signal success, warning or error (status bar helpers,
+//! modals, diffs) take their colors from the [`Palette`] returned by
+//! [`Palette::detect`]. The standard palette is the usual green, yellow and
+//! red; the alternatives keep those roles distinguishable for the common
+//! color-vision deficiencies:
+//!
+//! ```ignore
+//! // Pick a palette for the whole app
+//! BLAECK_PALETTE=deuteranopia my-app
+//!
+//! // Or use one directly
+//! let palette = Palette::for_vision(ColorVision::Tritanopia);
+//! Element::styled_text("failed", Style::new().fg(palette.error))
+//! ```
+//!
+//! To check a UI, add [`SimulateColorVision`] as a frame filter. It redraws
+//! every cell the way someone with the deficiency would see it:
+//!
+//! ```ignore
+//! blaeck.add_filter(SimulateColorVision::new(ColorVision::Protanopia));
+//!
+//! // Or from the environment: BLAECK_SIMULATE=protanopia
+//! if let Some(filter) = SimulateColorVision::from_env() {
+//!     blaeck.add_filter(filter);
+//! }
+//! ```
+
+use crate::components::GradientProps;
+use crate::filter::FrameFilter;
+use crate::output::Output;
+use crate::style::Color;
+use std::sync::OnceLock;
+
+/// Cached result of palette detection.
+static PALETTE: OnceLock<Palette> = OnceLock::new();
+
+/// A type of color vision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorVision {
+    /// Typical color vision.
+    #[default]
+    Normal,
+    /// No green cones; red and green are confused (the most common).
+    Deuteranopia,
+    /// No red cones; red and green are confused and red looks dark.
+    Protanopia,
+    /// No blue cones; blue and green, and yellow and pink are confused.
+    Tritanopia,
+    /// No color vision; only lightness is seen.
+    Achromatopsia,
+}
+
+impl ColorVision {
+    /// Parse a name such as `"deuteranopia"` or `"deutan"`, case-insensitively.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "normal" | "none" | "standard" => Some(ColorVision::Normal),
+            "deuteranopia" | "deutan" => Some(ColorVision::Deuteranopia),
+            "protanopia" | "protan" => Some(ColorVision::Protanopia),
+            "tritanopia" | "tritan" => Some(ColorVision::Tritanopia),
+            "achromatopsia" | "achroma" | "grayscale" | "greyscale" => {
+                Some(ColorVision::Achromatopsia)
+            }
+            _ => None,
+        }
+    }
+
+    /// Linear-RGB transform for this deficiency (Machado et al. 2009,
+    /// full severity), or `None` for normal vision.
+    fn matrix(self) -> Option<[[f32; 3]; 3]> {
+        match self {
+            ColorVision::Normal => None,
+            ColorVision::Deuteranopia => Some([
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ]),
+            ColorVision::Protanopia => Some([
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ]),
+            ColorVision::Tritanopia => Some([
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ]),
+            ColorVision::Achromatopsia => Some([[0.212_6, 0.715_2, 0.072_2]; 3]),
+        }
+    }
+
+    /// How `color` looks with this type of vision.
+    ///
+    /// [`Color::Reset`] is left alone; everything else becomes [`Color::Rgb`]
+    /// (except under normal vision, which returns `color` unchanged).
+    pub fn simulate(self, color: Color) -> Color {
+        let Some(m) = self.matrix() else {
+            return color;
+        };
+        if color == Color::Reset {
+            return color;
+        }
+        let (r, g, b) = GradientProps::color_to_rgb(color);
+        let rgb = [to_linear(r), to_linear(g), to_linear(b)];
+        let out = m.map(|row| row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]);
+        Color::Rgb(to_srgb(out[0]), to_srgb(out[1]), to_srgb(out[2]))
+    }
+}
+
+/// sRGB channel to linear light.
+fn to_linear(v: u8) -> f32 {
+    let v = f32::from(v) / 255.0;
+    if v <= 0.040_45 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Linear light to an sRGB channel.
+fn to_srgb(v: f32) -> u8 {
+    let v = v.clamp(0.0, 1.0);
+    let v = if v <= 0.003_130_8 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    };
+    (v * 255.0).round() as u8
+}
+
+/// Colors for the roles components use to signal state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    /// Success, added lines, "OK".
+    pub success: Color,
+    /// Warnings and pending states.
+    pub warning: Color,
+    /// Errors, removed lines, failures.
+    pub error: Color,
+    /// Neutral information.
+    pub info: Color,
+    /// Secondary text and hints.
+    pub muted: Color,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl Palette {
+    /// Green, yellow and red: the default.
+    pub const fn standard() -> Self {
+        Self {
+            success: Color::Green,
+            warning: Color::Yellow,
+            error: Color::Red,
+            info: Color::Cyan,
+            muted: Color::DarkGray,
+        }
+    }
+
+    /// Blue for success and vermilion for errors, from the Okabe-Ito set.
+    pub const fn deuteranopia() -> Self {
+        Self {
+            success: Color::Rgb(0, 114, 178),
+            warning: Color::Rgb(240, 228, 66),
+            error: Color::Rgb(213, 94, 0),
+            info: Color::Rgb(86, 180, 233),
+            muted: Color::DarkGray,
+        }
+    }
+
+    /// Like [`deuteranopia`](Self::deuteranopia), with a lighter orange for
+    /// errors since reds look dark without red cones.
+    pub const fn protanopia() -> Self {
+        Self {
+            success: Color::Rgb(0, 114, 178),
+            warning: Color::Rgb(240, 228, 66),
+            error: Color::Rgb(230, 159, 0),
+            info: Color::Rgb(86, 180, 233),
+            muted: Color::DarkGray,
+        }
+    }
+
+    /// Teal for success and red for errors, avoiding the blue/green and
+    /// yellow/pink pairs.
+    pub const fn tritanopia() -> Self {
+        Self {
+            success: Color::Rgb(0, 158, 155),
+            warning: Color::Rgb(204, 121, 167),
+            error: Color::Rgb(213, 40, 30),
+            info: Color::Rgb(86, 180, 233),
+            muted: Color::DarkGray,
+        }
+    }
+
+    /// Roles told apart by lightness alone.
+    pub const fn achromatopsia() -> Self {
+        Self {
+            success: Color::Rgb(140, 140, 140),
+            warning: Color::Rgb(200, 200, 200),
+            error: Color::Rgb(255, 255, 255),
+            info: Color::Rgb(170, 170, 170),
+            muted: Color::DarkGray,
+        }
+    }
+
+    /// The palette suited to a type of color vision.
+    pub const fn for_vision(vision: ColorVision) -> Self {
+        match vision {
+            ColorVision::Normal => Self::standard(),
+            ColorVision::Deuteranopia => Self::deuteranopia(),
+            ColorVision::Protanopia => Self::protanopia(),
+            ColorVision::Tritanopia => Self::tritanopia(),
+            ColorVision::Achromatopsia => Self::achromatopsia(),
+        }
+    }
+
+    /// Use `palette` for the whole app, instead of the one chosen with
+    /// `BLAECK_PALETTE`. Must be called before anything calls
+    /// [`detect`](Self::detect); returns false (and changes nothing) after.
+    pub fn install(palette: Palette) -> bool {
+        PALETTE.set(palette).is_ok()
+    }
+
+    /// The palette for the app, chosen with `BLAECK_PALETTE` (e.g.
+    /// `BLAECK_PALETTE=deuteranopia`). Defaults to [`standard`](Self::standard).
+    /// The result is cached after the first call.
+    pub fn detect() -> Self {
+        *PALETTE.get_or_init(|| {
+            std::env::var("BLAECK_PALETTE")
+                .ok()
+                .and_then(|v| ColorVision::parse(&v))
+                .map(Self::for_vision)
+                .unwrap_or_default()
+        })
+    }
+}
+
+/// Frame filter that shows the output as seen with a color-vision
+/// deficiency. For checking a UI, not for shipping.
+#[derive(Debug, Clone, Copy)]
+pub struct SimulateColorVision {
+    vision: ColorVision,
+}
+
+impl SimulateColorVision {
+    /// Simulate `vision`.
+    pub fn new(vision: ColorVision) -> Self {
+        Self { vision }
+    }
+
+    /// A filter for the vision named in `BLAECK_SIMULATE`, if set.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("BLAECK_SIMULATE")
+            .ok()
+            .and_then(|v| ColorVision::parse(&v))
+            .filter(|v| *v != ColorVision::Normal)
+            .map(Self::new)
+    }
+}
+
+impl FrameFilter for SimulateColorVision {
+    fn apply(&mut self, output: &mut Output) {
+        let vision = self.vision;
+        output.map_styles(|_, _, mut style| {
+            style.fg = vision.simulate(style.fg);
+            style.bg = vision.simulate(style.bg);
+            style
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Style;
+
+    fn distance(a: Color, b: Color) -> f32 {
+        let (a, b) = (
+            GradientProps::color_to_rgb(a),
+            GradientProps::color_to_rgb(b),
+        );
+        let d = |x: u8, y: u8| (f32::from(x) - f32::from(y)).powi(2);
+        (d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)).sqrt()
+    }
+
+    #[test]
+    fn test_palettes_stay_distinguishable() {
+        for vision in [
+            ColorVision::Deuteranopia,
+            ColorVision::Protanopia,
+            ColorVision::Tritanopia,
+            ColorVision::Achromatopsia,
+        ] {
+            let p = Palette::for_vision(vision);
+            let seen = |c| vision.simulate(c);
+            assert!(
+                distance(seen(p.success), seen(p.error)) > 100.0,
+                "{vision:?}"
+            );
+            assert!(
+                distance(seen(p.warning), seen(p.error)) > 60.0,
+                "{vision:?}"
+            );
+        }
+        // The standard palette is what the simulation exists to catch
+        let p = Palette::standard();
+        let deutan = ColorVision::Deuteranopia;
+        assert!(
+            distance(deutan.simulate(p.success), deutan.simulate(p.error))
+                < distance(p.success, p.error) / 2.0
+        );
+    }
+
+    #[test]
+    fn test_simulate_filter() {
+        assert_eq!(
+            ColorVision::parse("Deutan"),
+            Some(ColorVision::Deuteranopia)
+        );
+        assert_eq!(ColorVision::parse("nope"), None);
+        assert_eq!(ColorVision::Normal.simulate(Color::Red), Color::Red);
+        assert_eq!(
+            ColorVision::Achromatopsia.simulate(Color::Rgb(255, 255, 255)),
+            Color::Rgb(255, 255, 255)
+        );
+
+        let mut out = Output::new(2, 1);
+        out.write(0, 0, "x", Style::new().fg(Color::Red));
+        SimulateColorVision::new(ColorVision::Achromatopsia).apply(&mut out);
+        let Color::Rgb(r, g, b) = out.style_at(0, 0).unwrap().fg else {
+            panic!("expected rgb");
+        };
+        assert!(r == g && g == b);
+        assert_eq!(out.style_at(1, 0).unwrap().fg, Color::Reset);
+    }
+}
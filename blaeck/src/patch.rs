@@ -0,0 +1,454 @@
+//! Frame patches for rendering in one process and displaying in another.
+//!
+//! A [`FramePatch`] lists the runs of cells that changed between two
+//! [`Buffer`]s. It encodes to a compact byte format, so a UI can be laid out
+//! and painted in one process (a server, a daemon, a multiplexer) and shown
+//! by a thin client in another that only keeps a buffer and applies patches.
+//!
+//! ```ignore
+//! // Producer: one encoder per client
+//! let mut encoder = FrameEncoder::new();
+//! blaeck.render(ui())?;
+//! if let Some(frame) = blaeck.frame_buffer() {
+//!     socket.write_all(&encoder.encode(&frame).to_bytes())?;
+//! }
+//!
+//! // Client
+//! let mut screen = Buffer::new(0, 0);
+//! let patch = FramePatch::from_bytes(&message)?;
+//! let previous = screen.clone();
+//! patch.apply(&mut screen)?;
+//! print!("{}", Buffer::diff_ansi(&previous, &screen));
+//! ```
+//!
+//! The first patch from an encoder, and any patch after the frame size
+//! changes, is a full frame that replaces the client's buffer. Other patches
+//! only apply on top of the frame they were made from; if a client misses
+//! one, [`FrameEncoder::reset`] makes the next patch a full frame again.
+//!
+//! # Format
+//!
+//! All integers are little-endian.
+//!
+//! ```text
+//! patch := "BKP1" width:u16 height:u16 full:u8 run_count:u32 run*
+//! run   := x:u16 y:u16 cell_count:u16 cell*
+//! cell  := fg:color bg:color modifiers:u16 symbol_len:u8 symbol:utf8
+//! color := 0..=16 (named, in `Color` declaration order)
+//!        | 17 r:u8 g:u8 b:u8 | 18 index:u8
+//! ```
+
+use crate::buffer::{Buffer, Cell};
+use crate::style::{Color, Modifier};
+use std::fmt;
+
+const MAGIC: &[u8; 4] = b"BKP1";
+
+/// Named colors in the order they're encoded.
+const NAMED_COLORS: [Color; 17] = [
+    Color::Reset,
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+];
+const RGB_TAG: u8 = 17;
+const INDEXED_TAG: u8 = 18;
+
+/// Consecutive changed cells on one row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellRun {
+    /// Column of the first cell.
+    pub x: u16,
+    /// Row of the run.
+    pub y: u16,
+    /// The new cells, left to right.
+    pub cells: Vec<Cell>,
+}
+
+/// The changes from one frame to the next.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FramePatch {
+    /// Width of the new frame.
+    pub width: u16,
+    /// Height of the new frame.
+    pub height: u16,
+    /// Whether this is a whole frame: the target is reset to blank cells of
+    /// the new size before the runs are written.
+    pub full: bool,
+    /// Changed cells.
+    pub runs: Vec<CellRun>,
+}
+
+/// Why a patch couldn't be decoded or applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatchError {
+    /// The bytes don't start with the patch header.
+    BadMagic,
+    /// The bytes ended in the middle of the patch.
+    Truncated,
+    /// A color tag that isn't part of the format.
+    BadColor(u8),
+    /// A cell symbol that isn't valid UTF-8.
+    BadSymbol,
+    /// A run that doesn't fit in the frame.
+    OutOfBounds {
+        /// Column of the run.
+        x: u16,
+        /// Row of the run.
+        y: u16,
+    },
+    /// An incremental patch for a frame of a different size; the client
+    /// needs a full frame.
+    SizeMismatch {
+        /// Size the patch was made for.
+        expected: (u16, u16),
+        /// Size of the buffer it was applied to.
+        actual: (u16, u16),
+    },
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatchError::BadMagic => write!(f, "not a frame patch"),
+            PatchError::Truncated => write!(f, "frame patch is truncated"),
+            PatchError::BadColor(tag) => write!(f, "unknown color tag {}", tag),
+            PatchError::BadSymbol => write!(f, "cell symbol is not valid UTF-8"),
+            PatchError::OutOfBounds { x, y } => {
+                write!(f, "cell run at {},{} is outside the frame", x, y)
+            }
+            PatchError::SizeMismatch { expected, actual } => write!(
+                f,
+                "patch is for a {}x{} frame but the buffer is {}x{}",
+                expected.0, expected.1, actual.0, actual.1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PatchError {}
+
+impl FramePatch {
+    /// The patch that turns `old` into `new`.
+    ///
+    /// Without an old frame, or when the size changed, this is a full frame
+    /// holding every cell that isn't blank.
+    pub fn between(old: Option<&Buffer>, new: &Buffer) -> Self {
+        let old = old.filter(|old| old.width() == new.width() && old.height() == new.height());
+        let blank = Cell::default();
+        let mut runs: Vec<CellRun> = Vec::new();
+        for y in 0..new.height() {
+            for x in 0..new.width() {
+                let cell = new.get(x, y);
+                let changed = match old {
+                    Some(old) => old.get(x, y) != cell,
+                    None => *cell != blank,
+                };
+                if !changed {
+                    continue;
+                }
+                match runs.last_mut() {
+                    Some(run) if run.y == y && run.x + run.cells.len() as u16 == x => {
+                        run.cells.push(cell.clone());
+                    }
+                    _ => runs.push(CellRun {
+                        x,
+                        y,
+                        cells: vec![cell.clone()],
+                    }),
+                }
+            }
+        }
+        Self {
+            width: new.width(),
+            height: new.height(),
+            full: old.is_none(),
+            runs,
+        }
+    }
+
+    /// Whether applying the patch changes nothing.
+    pub fn is_empty(&self) -> bool {
+        !self.full && self.runs.is_empty()
+    }
+
+    /// Number of changed cells.
+    pub fn cell_count(&self) -> usize {
+        self.runs.iter().map(|run| run.cells.len()).sum()
+    }
+
+    /// Apply the patch to `buffer`.
+    ///
+    /// A full patch replaces the buffer. An incremental patch needs a buffer
+    /// of the size it was made for, and leaves the buffer untouched if it
+    /// doesn't fit.
+    pub fn apply(&self, buffer: &mut Buffer) -> Result<(), PatchError> {
+        if !self.full && (buffer.width(), buffer.height()) != (self.width, self.height) {
+            return Err(PatchError::SizeMismatch {
+                expected: (self.width, self.height),
+                actual: (buffer.width(), buffer.height()),
+            });
+        }
+        for run in &self.runs {
+            if run.y >= self.height || run.x as usize + run.cells.len() > self.width as usize {
+                return Err(PatchError::OutOfBounds { x: run.x, y: run.y });
+            }
+        }
+
+        if self.full {
+            *buffer = Buffer::new(self.width, self.height);
+        }
+        for run in &self.runs {
+            for (x, cell) in (run.x..).zip(&run.cells) {
+                buffer.set(x, run.y, cell.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Encode the patch in the byte format described in the
+    /// [module docs](self).
+    ///
+    /// Symbols longer than 255 bytes are truncated to the last full
+    /// character that fits.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(13 + self.cell_count() * 8);
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&self.width.to_le_bytes());
+        out.extend_from_slice(&self.height.to_le_bytes());
+        out.push(self.full as u8);
+        out.extend_from_slice(&(self.runs.len() as u32).to_le_bytes());
+        for run in &self.runs {
+            out.extend_from_slice(&run.x.to_le_bytes());
+            out.extend_from_slice(&run.y.to_le_bytes());
+            out.extend_from_slice(&(run.cells.len() as u16).to_le_bytes());
+            for cell in &run.cells {
+                encode_color(&mut out, cell.fg);
+                encode_color(&mut out, cell.bg);
+                out.extend_from_slice(&cell.modifiers.bits().to_le_bytes());
+                let mut end = cell.symbol.len().min(u8::MAX as usize);
+                while !cell.symbol.is_char_boundary(end) {
+                    end -= 1;
+                }
+                out.push(end as u8);
+                out.extend_from_slice(&cell.symbol.as_bytes()[..end]);
+            }
+        }
+        out
+    }
+
+    /// Decode a patch made by [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PatchError> {
+        let mut reader = Reader { bytes };
+        if reader.take(4)? != MAGIC {
+            return Err(PatchError::BadMagic);
+        }
+        let width = reader.u16()?;
+        let height = reader.u16()?;
+        let full = reader.u8()? != 0;
+        let run_count = reader.u32()?;
+
+        let mut runs = Vec::new();
+        for _ in 0..run_count {
+            let x = reader.u16()?;
+            let y = reader.u16()?;
+            let len = reader.u16()?;
+            let mut cells = Vec::with_capacity(len as usize);
+            for _ in 0..len {
+                let fg = reader.color()?;
+                let bg = reader.color()?;
+                let modifiers = Modifier::from_bits_truncate(reader.u16()?);
+                let symbol_len = reader.u8()? as usize;
+                let symbol = std::str::from_utf8(reader.take(symbol_len)?)
+                    .map_err(|_| PatchError::BadSymbol)?;
+                cells.push(Cell {
+                    symbol: symbol.to_string(),
+                    fg,
+                    bg,
+                    modifiers,
+                });
+            }
+            runs.push(CellRun { x, y, cells });
+        }
+        Ok(Self {
+            width,
+            height,
+            full,
+            runs,
+        })
+    }
+}
+
+fn encode_color(out: &mut Vec<u8>, color: Color) {
+    match color {
+        Color::Rgb(r, g, b) => out.extend_from_slice(&[RGB_TAG, r, g, b]),
+        Color::Indexed(n) => out.extend_from_slice(&[INDEXED_TAG, n]),
+        named => {
+            let tag = NAMED_COLORS.iter().position(|&c| c == named).unwrap_or(0);
+            out.push(tag as u8);
+        }
+    }
+}
+
+/// Cursor over the bytes being decoded.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], PatchError> {
+        if self.bytes.len() < len {
+            return Err(PatchError::Truncated);
+        }
+        let (head, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8, PatchError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, PatchError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, PatchError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn color(&mut self) -> Result<Color, PatchError> {
+        match self.u8()? {
+            RGB_TAG => {
+                let rgb = self.take(3)?;
+                Ok(Color::Rgb(rgb[0], rgb[1], rgb[2]))
+            }
+            INDEXED_TAG => Ok(Color::Indexed(self.u8()?)),
+            tag => NAMED_COLORS
+                .get(tag as usize)
+                .copied()
+                .ok_or(PatchError::BadColor(tag)),
+        }
+    }
+}
+
+/// Produces patches for one client, remembering the last frame it sent.
+#[derive(Debug, Clone, Default)]
+pub struct FrameEncoder {
+    last: Option<Buffer>,
+}
+
+impl FrameEncoder {
+    /// An encoder whose first patch is a full frame.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The patch from the last encoded frame to `frame`.
+    pub fn encode(&mut self, frame: &Buffer) -> FramePatch {
+        let patch = FramePatch::between(self.last.as_ref(), frame);
+        self.last = Some(frame.clone());
+        patch
+    }
+
+    /// Forget the last frame so the next patch is a full frame, e.g. when a
+    /// client reconnects or reports a [`PatchError::SizeMismatch`].
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Style;
+
+    #[test]
+    fn test_frame_patch_runs_and_apply() {
+        let old = Buffer::new(10, 3);
+        let mut new = old.clone();
+        new.set_string(2, 1, "abc", Style::new().fg(Color::Rgb(1, 2, 3)));
+        new.set(9, 2, Cell::new("界").bg(Color::Indexed(200)));
+
+        let patch = FramePatch::between(Some(&old), &new);
+        assert!(!patch.full);
+        assert_eq!(patch.runs.len(), 2);
+        assert_eq!((patch.runs[0].x, patch.runs[0].y), (2, 1));
+        assert_eq!(patch.cell_count(), 4);
+
+        let mut client = old.clone();
+        patch.apply(&mut client).unwrap();
+        assert_eq!(client, new);
+
+        assert!(FramePatch::between(Some(&new), &new).is_empty());
+        let mut small = Buffer::new(5, 3);
+        assert_eq!(
+            patch.apply(&mut small),
+            Err(PatchError::SizeMismatch {
+                expected: (10, 3),
+                actual: (5, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_frame_patch_bytes_round_trip() {
+        let mut frame = Buffer::new(6, 2);
+        frame.set_string(0, 0, "hi", Style::new().add_modifier(Modifier::BOLD));
+        frame.set(3, 1, Cell::new("é").fg(Color::LightCyan));
+
+        let mut encoder = FrameEncoder::new();
+        let patch = encoder.encode(&frame);
+        assert!(patch.full);
+        let bytes = patch.to_bytes();
+        let decoded = FramePatch::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, patch);
+
+        let mut client = Buffer::new(0, 0);
+        decoded.apply(&mut client).unwrap();
+        assert_eq!(client, frame);
+
+        frame.set(5, 1, Cell::new("x"));
+        let next = encoder.encode(&frame);
+        assert!(!next.full);
+        assert_eq!(next.cell_count(), 1);
+
+        assert_eq!(
+            FramePatch::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(PatchError::Truncated)
+        );
+        assert_eq!(FramePatch::from_bytes(b"nope"), Err(PatchError::BadMagic));
+    }
+
+    #[test]
+    fn test_frame_patch_from_renderer() {
+        let mut blaeck = crate::Blaeck::with_size(Vec::new(), 20, 5).unwrap();
+        assert!(blaeck.frame_buffer().is_none());
+        blaeck.render(crate::Element::text("hello")).unwrap();
+        let frame = blaeck.frame_buffer().unwrap();
+
+        let mut client = Buffer::new(0, 0);
+        let bytes = FrameEncoder::new().encode(&frame).to_bytes();
+        FramePatch::from_bytes(&bytes)
+            .unwrap()
+            .apply(&mut client)
+            .unwrap();
+        assert_eq!(client.get(0, 0).symbol, "h");
+        assert_eq!(client, frame);
+    }
+}
@@ -0,0 +1,393 @@
+//! Worker-thread layout for very large trees.
+//!
+//! A [`LayoutPipeline`] lays out and paints element trees on a worker
+//! thread while the UI thread presents the previous frame and keeps
+//! handling input. Trees are built as [`SendElement`]s (since [`Element`]
+//! props aren't `Send`), submitted to the pipeline, and the painted frames
+//! are handed to [`Blaeck::present`](crate::Blaeck::present).
+//!
+//! This mode is opt-in; [`Blaeck::render`](crate::Blaeck::render) still lays
+//! out on the calling thread. It pays off when layout takes longer than a
+//! frame, for tables or logs with tens of thousands of nodes.
+//!
+//! ```ignore
+//! let mut pipeline = blaeck.layout_pipeline();
+//! loop {
+//!     if dirty {
+//!         pipeline.submit(build_ui(&state)); // returns at once
+//!     }
+//!     if let Some(frame) = pipeline.poll()? {
+//!         blaeck.present(frame)?;
+//!     }
+//!     if let Some(key) = poll_key(Duration::from_millis(8))? {
+//!         handle(&mut state, key); // never waits for layout
+//!     }
+//! }
+//! ```
+//!
+//! ## Guarantees
+//!
+//! - **Order.** Frames come out in the order their trees were submitted.
+//! - **Latest wins.** There is one tree being laid out and at most one
+//!   waiting. Submitting while one is waiting replaces it, so the worker
+//!   never falls behind the UI and a burst of updates costs one layout.
+//!   [`poll`](LayoutPipeline::poll) likewise returns only the newest
+//!   finished frame.
+//! - **Static output.** Every [`Static`](crate::components::Static) item in
+//!   a tree that was laid out is printed exactly once, even when its frame
+//!   is superseded. Items only in a replaced tree are printed with the next
+//!   tree that has them, as [`Blaeck::render`](crate::Blaeck::render) would.
+//! - **Size.** Frames laid out before a [`resize`](LayoutPipeline::resize)
+//!   are never presented.
+//! - **Renderer settings.** Frame filters, damage tracking and metrics are
+//!   applied by the [`Blaeck`](crate::Blaeck) that presents the frame.
+//!   Cached boxes are cached on the worker, so
+//!   [`Blaeck::invalidate_cache`](crate::Blaeck::invalidate_cache) doesn't
+//!   reach them; change the box's cache key to repaint it.
+
+use crate::element::{Element, SendElement};
+use crate::error::{BlaeckError, Result};
+use crate::output::{Output, DEFAULT_TAB_WIDTH};
+use crate::renderer::Blaeck;
+use std::io;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// A frame laid out and painted by a [`LayoutPipeline`], ready for
+/// [`Blaeck::present`](crate::Blaeck::present).
+#[derive(Debug)]
+pub struct PaintedFrame {
+    /// Static items printed above the live region, if any are new.
+    above: Option<String>,
+    /// The painted live region.
+    output: Output,
+    /// When the tree was submitted.
+    submitted: Instant,
+    /// Resize generation the frame was laid out in.
+    generation: u64,
+}
+
+impl PaintedFrame {
+    /// The painted live region.
+    pub fn output(&self) -> &Output {
+        &self.output
+    }
+
+    /// Time from submitting the tree until now.
+    pub fn latency(&self) -> Duration {
+        self.submitted.elapsed()
+    }
+
+    pub(crate) fn into_parts(self) -> (Option<String>, Output, Instant) {
+        (self.above, self.output, self.submitted)
+    }
+
+    /// Takes over the Static output of an older frame that won't be
+    /// presented.
+    fn absorb(&mut self, older: PaintedFrame) {
+        if let Some(mut above) = older.above {
+            if let Some(mine) = self.above.take() {
+                above.push_str(&mine);
+            }
+            self.above = Some(above);
+        }
+    }
+}
+
+/// A tree waiting for the worker.
+struct Job {
+    tree: SendElement,
+    width: u16,
+    height: u16,
+    tab_width: u16,
+    generation: u64,
+    submitted: Instant,
+}
+
+/// The job slot shared with the worker.
+#[derive(Default)]
+struct Slot {
+    job: Option<Job>,
+    shutdown: bool,
+}
+
+type Shared = Arc<(Mutex<Slot>, Condvar)>;
+
+/// Lays out and paints element trees on a worker thread. See the
+/// [module docs](self).
+pub struct LayoutPipeline {
+    shared: Shared,
+    frames: Receiver<Result<PaintedFrame>>,
+    worker: Option<JoinHandle<()>>,
+    width: u16,
+    height: u16,
+    tab_width: u16,
+    /// Bumped on resize, so frames laid out before it are dropped
+    generation: u64,
+    /// Trees submitted whose frames haven't been received
+    outstanding: usize,
+    /// Trees and frames replaced by newer ones
+    dropped: u64,
+}
+
+impl LayoutPipeline {
+    /// Start a worker that lays out frames `width` columns wide, with
+    /// `height` rows available.
+    pub fn new(width: u16, height: u16) -> Self {
+        let shared: Shared = Arc::default();
+        let (tx, frames) = mpsc::channel();
+        let worker = {
+            let shared = Arc::clone(&shared);
+            std::thread::spawn(move || run_worker(&shared, &tx))
+        };
+        Self {
+            shared,
+            frames,
+            worker: Some(worker),
+            width,
+            height,
+            tab_width: DEFAULT_TAB_WIDTH,
+            generation: 0,
+            outstanding: 0,
+            dropped: 0,
+        }
+    }
+
+    /// Set the distance between tab stops for trees submitted from now on.
+    pub fn set_tab_width(&mut self, width: u16) {
+        self.tab_width = width;
+    }
+
+    /// Lay out trees submitted from now on at a new size. Frames of the old
+    /// size still being laid out are dropped.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.width = width;
+        self.height = height;
+        self.generation += 1;
+    }
+
+    /// Queue `tree` for layout and return at once.
+    ///
+    /// Returns true if it replaced a tree the worker hadn't started on.
+    pub fn submit(&mut self, tree: SendElement) -> bool {
+        let job = Job {
+            tree,
+            width: self.width,
+            height: self.height,
+            tab_width: self.tab_width,
+            generation: self.generation,
+            submitted: Instant::now(),
+        };
+        let (slot, ready) = &*self.shared;
+        let replaced = lock(slot).job.replace(job).is_some();
+        ready.notify_one();
+        if replaced {
+            self.dropped += 1;
+        } else {
+            self.outstanding += 1;
+        }
+        replaced
+    }
+
+    /// The newest finished frame, without waiting, or `None` if no frame
+    /// finished since the last call.
+    pub fn poll(&mut self) -> Result<Option<PaintedFrame>> {
+        let mut newest = None;
+        while let Ok(frame) = self.frames.try_recv() {
+            self.accept(frame, &mut newest)?;
+        }
+        Ok(newest)
+    }
+
+    /// Like [`poll`](Self::poll), but waits up to `timeout` for a frame if
+    /// none has finished.
+    pub fn wait(&mut self, timeout: Duration) -> Result<Option<PaintedFrame>> {
+        let mut newest = None;
+        let deadline = Instant::now() + timeout;
+        while newest.is_none() && self.outstanding > 0 {
+            let left = deadline.saturating_duration_since(Instant::now());
+            match self.frames.recv_timeout(left) {
+                Ok(frame) => self.accept(frame, &mut newest)?,
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => return Err(worker_gone()),
+            }
+        }
+        if let Some(more) = self.poll()? {
+            merge(&mut newest, more, &mut self.dropped);
+        }
+        Ok(newest)
+    }
+
+    /// Wait for every submitted tree and return the newest frame, e.g.
+    /// before exiting.
+    pub fn finish(&mut self) -> Result<Option<PaintedFrame>> {
+        let mut newest = None;
+        while self.outstanding > 0 {
+            let frame = self.frames.recv().map_err(|_| worker_gone())?;
+            self.accept(frame, &mut newest)?;
+        }
+        Ok(newest)
+    }
+
+    /// Whether every submitted tree has come back as a frame.
+    pub fn is_idle(&self) -> bool {
+        self.outstanding == 0
+    }
+
+    /// Trees and frames skipped because a newer one replaced them.
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    fn accept(
+        &mut self,
+        frame: Result<PaintedFrame>,
+        newest: &mut Option<PaintedFrame>,
+    ) -> Result<()> {
+        self.outstanding = self.outstanding.saturating_sub(1);
+        let mut frame = frame?;
+        if frame.generation != self.generation {
+            // Laid out at an old size: keep only its Static output. An
+            // empty grid never matches the renderer's width, so
+            // `Blaeck::present` won't draw it
+            frame.output = Output::new(0, 0);
+        }
+        merge(newest, frame, &mut self.dropped);
+        Ok(())
+    }
+}
+
+/// Makes `frame` the newest, carrying over the Static output of the frame
+/// it replaces.
+fn merge(newest: &mut Option<PaintedFrame>, mut frame: PaintedFrame, dropped: &mut u64) {
+    if let Some(older) = newest.take() {
+        *dropped += 1;
+        frame.absorb(older);
+    }
+    *newest = Some(frame);
+}
+
+impl Drop for LayoutPipeline {
+    fn drop(&mut self) {
+        let (slot, ready) = &*self.shared;
+        lock(slot).shutdown = true;
+        ready.notify_one();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn lock(slot: &Mutex<Slot>) -> std::sync::MutexGuard<'_, Slot> {
+    // A panic while holding the lock leaves the slot usable
+    slot.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn worker_gone() -> BlaeckError {
+    BlaeckError::layout("layout worker stopped")
+}
+
+/// The worker: takes the waiting job, lays it out and sends the frame,
+/// until the pipeline is dropped.
+fn run_worker(shared: &Shared, frames: &Sender<Result<PaintedFrame>>) {
+    let (slot, ready) = &**shared;
+    let mut renderer: Option<Blaeck<io::Sink>> = None;
+    loop {
+        let job = {
+            let mut slot = lock(slot);
+            loop {
+                if slot.shutdown {
+                    return;
+                }
+                if let Some(job) = slot.job.take() {
+                    break job;
+                }
+                slot = ready.wait(slot).unwrap_or_else(|e| e.into_inner());
+            }
+        };
+        let frame = paint(&mut renderer, job);
+        if frames.send(frame).is_err() {
+            return;
+        }
+    }
+}
+
+/// Lays out one job, keeping the renderer (and so the Static items already
+/// printed and the cached boxes) between jobs.
+fn paint(renderer: &mut Option<Blaeck<io::Sink>>, job: Job) -> Result<PaintedFrame> {
+    let blaeck = match renderer {
+        Some(blaeck) => {
+            if (blaeck.width(), blaeck.height()) != (job.width, job.height) {
+                blaeck.handle_resize(job.width, job.height)?;
+            }
+            blaeck
+        }
+        None => renderer.insert(Blaeck::with_size(io::sink(), job.width, job.height)?),
+    };
+    blaeck.set_tab_width(job.tab_width);
+    let element: Element = job.tree.into_element();
+    let (above, output) = blaeck.paint_frame(&element)?;
+    Ok(PaintedFrame {
+        above,
+        output,
+        submitted: job.submitted,
+        generation: job.generation,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{Text, TextProps};
+
+    fn text(content: &str) -> SendElement {
+        SendElement::node::<Text>(TextProps::new(content), vec![])
+    }
+
+    fn lines(frame: &PaintedFrame) -> Vec<String> {
+        let output = frame.output();
+        (0..output.height)
+            .filter_map(|y| output.line_text(y))
+            .map(|l| l.trim_end().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_pipeline_lays_out_latest_tree() {
+        let mut pipeline = LayoutPipeline::new(20, 5);
+        pipeline.submit(text("one"));
+        pipeline.submit(text("two"));
+        pipeline.submit(text("three"));
+        let frame = pipeline.finish().unwrap().expect("a frame");
+        assert_eq!(lines(&frame), ["three"]);
+        assert!(pipeline.is_idle());
+        assert_eq!(pipeline.poll().unwrap().map(|f| lines(&f)), None);
+    }
+
+    #[test]
+    fn test_pipeline_present_matches_render() {
+        let mut direct = Blaeck::with_size(Vec::new(), 20, 5).unwrap();
+        direct.render(text("hello").into_element()).unwrap();
+
+        let mut blaeck = Blaeck::with_size(Vec::new(), 20, 5).unwrap();
+        let mut pipeline = blaeck.layout_pipeline();
+        pipeline.submit(text("hello"));
+        let frame = pipeline.wait(Duration::from_secs(5)).unwrap().unwrap();
+        blaeck.present(frame).unwrap();
+        assert_eq!(blaeck.frame_lines(), direct.frame_lines());
+    }
+
+    #[test]
+    fn test_pipeline_drops_frames_from_before_resize() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 20, 5).unwrap();
+        let mut pipeline = blaeck.layout_pipeline();
+        pipeline.submit(text("old"));
+        pipeline.resize(30, 5);
+        blaeck.handle_resize(30, 5).unwrap();
+        let frame = pipeline.finish().unwrap().unwrap();
+        blaeck.present(frame).unwrap();
+        assert!(blaeck.frame_lines().is_empty());
+    }
+}
@@ -4,12 +4,24 @@
 //! for reactive components.
 
 use super::runtime::RuntimeHandle;
+use super::scheduler::{FrameScheduler, Immediate};
 use super::scope::Scope;
+use crate::debounce::{resize_placeholder, ResizeDebouncer};
 use crate::element::Element;
-use crate::input::poll_key;
-use crate::renderer::Blaeck;
-use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use crate::error::{BlaeckError, Result};
+use crate::gestures::{GestureDetector, Wheel, DEFAULT_DOUBLE_CLICK};
+use crate::hotkeys::{HotkeyConflict, Hotkeys};
+use crate::input::{InputEvent, Key, KeyPatternError};
+use crate::metrics::Metrics;
+use crate::renderer::{Blaeck, TerminalModes};
+use crate::replay::{InputRecording, InputReplay, InputSource};
+use crate::selection::{SelectAction, SelectMode};
+#[cfg(feature = "session")]
+use crate::session::SessionStore;
+use crossterm::terminal::enable_raw_mode;
+use std::cell::RefCell;
 use std::io::{self, Write};
+use std::rc::Rc;
 use std::time::Duration;
 
 /// Configuration for ReactiveApp.
@@ -20,6 +32,25 @@ pub struct ReactiveAppConfig {
 
     /// Whether to exit on Ctrl+C (default: true).
     pub exit_on_ctrl_c: bool,
+
+    /// When pending frames are rendered (default: [`Immediate`]).
+    ///
+    /// Shared between clones of the config.
+    pub scheduler: Rc<RefCell<dyn FrameScheduler>>,
+
+    /// How long the terminal size must be stable before relayout; a
+    /// placeholder is shown meanwhile. Zero relayouts on every resize
+    /// event (default: 100ms).
+    pub resize_debounce: Duration,
+
+    /// Time allowed between the clicks of a double-click, for
+    /// [`use_gesture`](super::use_gesture) (default: 400ms).
+    pub double_click: Duration,
+
+    /// Ask terminals with the kitty keyboard protocol to report key repeats
+    /// and releases too. Input handlers then see releases, so they should
+    /// check [`Key::is_press`] (default: false).
+    pub key_releases: bool,
 }
 
 impl Default for ReactiveAppConfig {
@@ -27,14 +58,30 @@ impl Default for ReactiveAppConfig {
         Self {
             poll_interval: Duration::from_millis(50),
             exit_on_ctrl_c: true,
+            scheduler: Rc::new(RefCell::new(Immediate)),
+            resize_debounce: Duration::from_millis(100),
+            double_click: DEFAULT_DOUBLE_CLICK,
+            key_releases: false,
         }
     }
 }
 
+impl ReactiveAppConfig {
+    /// Set the frame scheduling policy.
+    #[must_use]
+    pub fn with_scheduler(mut self, scheduler: impl FrameScheduler + 'static) -> Self {
+        self.scheduler = Rc::new(RefCell::new(scheduler));
+        self
+    }
+}
+
 /// Result returned when the reactive app exits.
 pub struct ReactiveAppResult {
     /// How the app exited.
     pub exit_reason: ReactiveExitReason,
+    /// The input, if the app was run
+    /// [with recording](ReactiveApp::with_recording).
+    pub recording: Option<InputRecording>,
 }
 
 /// Why the reactive app exited.
@@ -47,6 +94,9 @@ pub enum ReactiveExitReason {
     Completed,
 }
 
+/// A hotkey action run with the reactive app.
+pub type ReactiveHotkeyAction<W> = Box<dyn FnMut(&mut ReactiveApp<W>)>;
+
 /// A reactive application runtime.
 ///
 /// ReactiveApp provides the main entry point for reactive/signals-based UIs.
@@ -80,7 +130,7 @@ pub enum ReactiveExitReason {
 ///     }
 /// }
 ///
-/// fn main() -> std::io::Result<()> {
+/// fn main() -> blaeck::error::Result<()> {
 ///     ReactiveApp::run(counter)
 /// }
 /// ```
@@ -99,6 +149,15 @@ pub struct ReactiveApp<W: Write> {
 
     /// Why the app is exiting.
     exit_reason: ReactiveExitReason,
+
+    /// Key that enters select mode, if enabled.
+    select_key: Option<char>,
+
+    /// App-wide hotkeys, checked before `use_input` handlers.
+    hotkeys: Hotkeys<ReactiveHotkeyAction<W>>,
+
+    /// The terminal or a replay, optionally recorded.
+    input: InputSource,
 }
 
 impl ReactiveApp<io::Stdout> {
@@ -111,7 +170,7 @@ impl ReactiveApp<io::Stdout> {
     /// ```ignore
     /// ReactiveApp::run(my_component)?;
     /// ```
-    pub fn run<F>(component: F) -> io::Result<ReactiveAppResult>
+    pub fn run<F>(component: F) -> Result<ReactiveAppResult>
     where
         F: Fn(Scope) -> Element,
     {
@@ -119,19 +178,33 @@ impl ReactiveApp<io::Stdout> {
     }
 
     /// Run a reactive component with custom configuration.
-    pub fn run_with_config<F>(
+    pub fn run_with_config<F>(component: F, config: ReactiveAppConfig) -> Result<ReactiveAppResult>
+    where
+        F: Fn(Scope) -> Element,
+    {
+        let app = Self::new(config)?;
+        app.run_component(component)
+    }
+
+    /// Run a reactive component with a session store attached.
+    ///
+    /// State created with [`use_session_state`](super::use_session_state) is
+    /// restored from `session` and saved back to it when the app exits.
+    #[cfg(feature = "session")]
+    pub fn run_with_session<F>(
         component: F,
         config: ReactiveAppConfig,
-    ) -> io::Result<ReactiveAppResult>
+        session: SessionStore,
+    ) -> Result<ReactiveAppResult>
     where
         F: Fn(Scope) -> Element,
     {
-        let app = Self::new(config)?;
+        let app = Self::new(config)?.with_session(session);
         app.run_component(component)
     }
 
     /// Create a new ReactiveApp with stdout.
-    pub fn new(config: ReactiveAppConfig) -> io::Result<Self> {
+    pub fn new(config: ReactiveAppConfig) -> Result<Self> {
         Self::with_writer(io::stdout(), config)
     }
 }
@@ -140,7 +213,7 @@ impl<W: Write> ReactiveApp<W> {
     /// Create a ReactiveApp with a custom writer.
     ///
     /// Useful for testing or writing to a buffer.
-    pub fn with_writer(writer: W, config: ReactiveAppConfig) -> io::Result<Self> {
+    pub fn with_writer(writer: W, config: ReactiveAppConfig) -> Result<Self> {
         let runtime = RuntimeHandle::new();
         let blaeck = Blaeck::new(writer)?;
 
@@ -150,20 +223,115 @@ impl<W: Write> ReactiveApp<W> {
             config,
             should_exit: false,
             exit_reason: ReactiveExitReason::Completed,
+            select_key: None,
+            hotkeys: Hotkeys::new(),
+            input: InputSource::default(),
         })
     }
 
+    /// Attach a session store to the runtime.
+    ///
+    /// The store is saved when the app exits.
+    #[cfg(feature = "session")]
+    #[must_use]
+    pub fn with_session(self, session: SessionStore) -> Self {
+        self.runtime.set_session(session);
+        self
+    }
+
+    /// Enable select mode, entered by pressing `key`.
+    ///
+    /// Select mode freezes the UI so text can be selected with the keyboard
+    /// and copied to the clipboard (see [`SelectMode`]). While it is on,
+    /// keys go to the selection instead of `use_input` handlers; updates
+    /// resume when it ends.
+    #[must_use]
+    pub fn with_select_key(mut self, key: char) -> Self {
+        self.select_key = Some(key);
+        self
+    }
+
+    /// Record the input; the recording is returned in
+    /// [`ReactiveAppResult`].
+    #[must_use]
+    pub fn with_recording(mut self) -> Self {
+        self.input.start_recording();
+        self
+    }
+
+    /// Take input from `replay` instead of the terminal.
+    ///
+    /// The app exits once the replay ends and the last change has been
+    /// rendered, unless the replay was made with
+    /// [`InputReplay::then_live`]. See [`crate::replay`].
+    #[must_use]
+    pub fn with_replay(mut self, replay: InputReplay) -> Self {
+        self.input.set_replay(replay);
+        self
+    }
+
     /// Request the app to exit.
     pub fn exit(&mut self) {
         self.should_exit = true;
         self.exit_reason = ReactiveExitReason::UserExit;
     }
 
+    /// Register a global hotkey such as `"ctrl+p"`.
+    ///
+    /// Hotkeys run before `use_input` handlers, and a key that triggers one
+    /// is not passed on. Returns the conflict if the binding was taken; the
+    /// new action replaces the old one. See [`crate::hotkeys`].
+    pub fn register_hotkey<F>(
+        &mut self,
+        pattern: &str,
+        description: impl Into<String>,
+        action: F,
+    ) -> std::result::Result<Option<HotkeyConflict>, KeyPatternError>
+    where
+        F: FnMut(&mut Self) + 'static,
+    {
+        self.hotkeys
+            .register(pattern, description, Box::new(action))
+    }
+
+    /// The registered hotkeys, e.g. for [`Hotkeys::hints`].
+    pub fn hotkeys(&self) -> &Hotkeys<ReactiveHotkeyAction<W>> {
+        &self.hotkeys
+    }
+
+    /// Run the hotkey for `key`, if any. Returns whether one ran.
+    pub fn dispatch_hotkey(&mut self, key: &Key) -> bool {
+        // Take the registry so the action can borrow the app; hotkeys it
+        // registers land in the empty one and are merged back after.
+        let mut hotkeys = std::mem::take(&mut self.hotkeys);
+        let handled = match hotkeys.action_mut(key) {
+            Some(action) => {
+                action(self);
+                true
+            }
+            None => false,
+        };
+        let added = std::mem::replace(&mut self.hotkeys, hotkeys);
+        self.hotkeys.append(added);
+        // The action may have changed state the UI shows
+        if handled {
+            self.runtime.mark_dirty();
+        }
+        handled
+    }
+
     /// Get a reference to the runtime.
     pub fn runtime(&self) -> &RuntimeHandle {
         &self.runtime
     }
 
+    /// Get render and runtime metrics collected so far.
+    pub fn metrics(&self) -> Metrics {
+        let mut metrics = *self.blaeck.metrics();
+        self.runtime.record_metrics(&mut metrics);
+        metrics
+    }
+
     /// Get a reference to the underlying renderer.
     pub fn blaeck(&self) -> &Blaeck<W> {
         &self.blaeck
@@ -174,16 +342,51 @@ impl<W: Write> ReactiveApp<W> {
         &mut self.blaeck
     }
 
+    /// Run `component` as the root of this app until it exits.
+    ///
+    /// Like [`run`](ReactiveApp::run), for an app set up with hotkeys or a
+    /// session first.
+    pub fn start<F>(self, component: F) -> Result<ReactiveAppResult>
+    where
+        F: Fn(Scope) -> Element,
+    {
+        self.run_component(component)
+    }
+
     /// Run the component render loop.
-    fn run_component<F>(mut self, component: F) -> io::Result<ReactiveAppResult>
+    fn run_component<F>(mut self, component: F) -> Result<ReactiveAppResult>
+    where
+        F: Fn(Scope) -> Element,
+    {
+        // Whatever the loop turns on is turned off again, even if it fails
+        let mut modes = TerminalModes::default();
+        let looped = self.event_loop(&component, &mut modes);
+        let restored = modes.restore(&mut self.blaeck);
+        looped.and(restored)?;
+        self.blaeck.unmount()?;
+        #[cfg(feature = "session")]
+        self.runtime.save_session()?;
+
+        Ok(ReactiveAppResult {
+            exit_reason: self.exit_reason,
+            recording: self.input.take_recording(),
+        })
+    }
+
+    /// Set up the terminal, render and handle input until the app exits,
+    /// recording in `modes` what was turned on.
+    fn event_loop<F>(&mut self, component: &F, modes: &mut TerminalModes) -> Result<()>
     where
         F: Fn(Scope) -> Element,
     {
         // Create root component instance
         let root_id = self.runtime.create_instance();
 
-        // Enable raw mode for keyboard input
-        enable_raw_mode()?;
+        // Enable raw mode for keyboard input, unless it's all replayed
+        modes.raw_mode = self.input.reads_terminal();
+        if modes.raw_mode {
+            enable_raw_mode().map_err(|e| BlaeckError::terminal("enabling raw mode", e))?;
+        }
 
         // Initial render
         let scope = Scope::new(self.runtime.clone(), root_id);
@@ -194,10 +397,113 @@ impl<W: Write> ReactiveApp<W> {
         self.blaeck.render(element)?;
         self.runtime.clear_dirty();
 
+        // Mouse capture, if the first render registered gesture or scroll
+        // handlers
+        modes.mouse_capture =
+            self.runtime.has_gesture_handlers() || self.runtime.has_scroll_handlers();
+        if modes.mouse_capture {
+            self.blaeck.set_mouse_capture(true)?;
+            if modes.raw_mode {
+                // Mouse rows are relative to the screen, not the frame. If the
+                // terminal doesn't say where the cursor is, assume the top row.
+                let _ = self.blaeck.locate_frame();
+            }
+        }
+        // Focus reporting, if the first render watches terminal focus
+        modes.focus_reporting = self.runtime.has_focus_watchers();
+        if modes.focus_reporting {
+            self.blaeck.set_focus_reporting(true)?;
+        }
+        // Bracketed paste, if it registered paste handlers
+        modes.bracketed_paste = self.runtime.has_paste_handlers();
+        if modes.bracketed_paste {
+            self.blaeck.set_bracketed_paste(true)?;
+        }
+        modes.enable_keyboard_enhancement(&mut self.blaeck, self.config.key_releases)?;
+        let mut gestures = GestureDetector::new().double_click(self.config.double_click);
+        let now = self.config.scheduler.borrow().now();
+        self.config.scheduler.borrow_mut().frame_rendered(now);
+
+        // Active keyboard selection, if any (see with_select_key)
+        let mut select: Option<SelectMode> = None;
+        let mut resize = ResizeDebouncer::new(self.config.resize_debounce);
+
         // Main event loop
         while !self.should_exit {
-            // Poll for input
-            if let Some(key) = poll_key(self.config.poll_interval)? {
+            // A finished replay exits once its effects are on screen
+            if self.input.is_finished() && !self.runtime.needs_render() {
+                break;
+            }
+
+            // Poll for input, for as long as the scheduler allows
+            let now = self.config.scheduler.borrow().now();
+            let timeout = self.config.scheduler.borrow_mut().wait_timeout(
+                now,
+                self.config.poll_interval,
+                self.runtime.needs_render(),
+            );
+            let key = match self.input.poll(resize.timeout(now, timeout))? {
+                Some(InputEvent::Key(key)) => Some(key),
+                Some(InputEvent::Mouse(mouse)) => {
+                    let now = self.config.scheduler.borrow().now();
+                    let gesture = gestures.feed(&mouse, now);
+                    if self.runtime.record_activity(now) {
+                        // Mouse input that wakes the app from idle does
+                        // nothing else, like a key
+                        self.config.scheduler.borrow_mut().input_received(now);
+                    } else if select.is_none() {
+                        // The mouse is ignored while the UI is frozen for
+                        // selection
+                        if let Some(gesture) = gesture {
+                            self.config.scheduler.borrow_mut().input_received(now);
+                            self.runtime.dispatch_gesture(&gesture);
+                        }
+                        if let Some(wheel) = Wheel::from_mouse(&mouse) {
+                            let (column, row) = wheel.position;
+                            if let Some(target) = self.blaeck.scroll_target_at(column, row) {
+                                self.config.scheduler.borrow_mut().input_received(now);
+                                self.runtime.dispatch_scroll(target, &wheel);
+                            }
+                        }
+                    }
+                    None
+                }
+                Some(InputEvent::Resize(width, height)) => {
+                    if resize.resize(width, height, self.config.scheduler.borrow().now()) {
+                        self.blaeck.handle_resize(width, height)?;
+                        self.blaeck.render(resize_placeholder(width, height))?;
+                    }
+                    None
+                }
+                Some(InputEvent::Focus(focused)) => {
+                    if self.runtime.set_terminal_focus(focused) {
+                        let now = self.config.scheduler.borrow().now();
+                        self.config.scheduler.borrow_mut().input_received(now);
+                    }
+                    None
+                }
+                Some(InputEvent::Paste(text)) => {
+                    let now = self.config.scheduler.borrow().now();
+                    // A paste that wakes the app from idle is dropped, like
+                    // a key; pastes are ignored while the UI is frozen for
+                    // selection
+                    let woke = self.runtime.record_activity(now);
+                    if woke || select.is_none() {
+                        self.config.scheduler.borrow_mut().input_received(now);
+                    }
+                    if !woke && select.is_none() {
+                        self.runtime.dispatch_paste(&text);
+                    }
+                    None
+                }
+                None => None,
+            };
+            if let Some(key) = key {
+                let now = self.config.scheduler.borrow().now();
+                self.config.scheduler.borrow_mut().input_received(now);
+                // A key that wakes the app from idle does nothing else
+                let woke = self.runtime.record_activity(now);
+
                 // Handle Ctrl+C
                 if self.config.exit_on_ctrl_c && key.is_ctrl_c() {
                     self.should_exit = true;
@@ -205,29 +511,75 @@ impl<W: Write> ReactiveApp<W> {
                     break;
                 }
 
-                // Dispatch to registered input handlers
-                self.runtime.dispatch_input(&key);
+                if woke {
+                    // Already marked dirty by the idle signal
+                } else if let Some(mode) = select.as_mut() {
+                    // Select mode: the UI is frozen and keys drive the selection
+                    let done = match mode.handle_key(&key) {
+                        SelectAction::Copied(text) => {
+                            match self.blaeck.copy_to_clipboard(&text) {
+                                // No clipboard to copy to; leave select mode
+                                Ok(()) | Err(BlaeckError::Unsupported(_)) => {}
+                                Err(e) => return Err(e),
+                            }
+                            true
+                        }
+                        SelectAction::Exited => true,
+                        SelectAction::Moved => {
+                            self.blaeck.render_selection(mode)?;
+                            false
+                        }
+                        SelectAction::Ignored => false,
+                    };
+                    if done {
+                        select = None;
+                        self.blaeck.set_frozen(false);
+                        self.runtime.mark_dirty();
+                    }
+                } else if self.select_key.is_some_and(|c| key.is_char(c)) {
+                    self.blaeck.set_frozen(true);
+                    let mode = SelectMode::new(self.blaeck.frame_lines());
+                    self.blaeck.render_selection(&mode)?;
+                    select = Some(mode);
+                } else if !self.dispatch_hotkey(&key) {
+                    // Dispatch to registered input handlers
+                    self.runtime.dispatch_input(&key);
+                }
             }
 
-            // Re-render if state changed
-            if self.runtime.needs_render() {
+            // Background tasks reporting progress count as state changes
+            self.runtime.poll_tasks();
+            self.runtime
+                .poll_toasts(self.config.scheduler.borrow().now());
+            self.runtime.poll_idle(self.config.scheduler.borrow().now());
+            self.runtime
+                .poll_countdowns(self.config.scheduler.borrow().now());
+
+            // One full relayout once the size stops changing
+            let now = self.config.scheduler.borrow().now();
+            if let Some((width, height)) = resize.settled(now) {
+                self.blaeck.handle_resize(width, height)?;
+                self.runtime.mark_dirty();
+            }
+
+            // Re-render if state changed and the scheduler agrees
+            if self.runtime.needs_render()
+                && !resize.is_pending()
+                && self.config.scheduler.borrow_mut().should_render(now)
+            {
                 let scope = Scope::new(self.runtime.clone(), root_id);
                 self.runtime.set_current_instance(Some(root_id));
                 self.runtime.reset_hook_cursor(root_id);
                 let element = component(scope);
                 self.runtime.set_current_instance(None);
+                self.runtime.record_metrics(self.blaeck.metrics_mut());
                 self.blaeck.render(element)?;
                 self.runtime.clear_dirty();
+                self.config.scheduler.borrow_mut().frame_rendered(now);
             }
         }
 
-        // Cleanup
-        disable_raw_mode()?;
-        self.blaeck.unmount()?;
-
-        Ok(ReactiveAppResult {
-            exit_reason: self.exit_reason,
-        })
+        Ok(())
     }
 }
 
@@ -247,11 +599,24 @@ mod tests {
         let config = ReactiveAppConfig {
             poll_interval: Duration::from_millis(100),
             exit_on_ctrl_c: false,
+            ..Default::default()
         };
         assert_eq!(config.poll_interval, Duration::from_millis(100));
         assert!(!config.exit_on_ctrl_c);
     }
 
+    #[test]
+    fn test_config_with_scheduler() {
+        use crate::reactive::Coalesced;
+        use std::time::Instant;
+
+        let config = ReactiveAppConfig::default().with_scheduler(Coalesced::new(10));
+        let now = Instant::now();
+        let mut scheduler = config.scheduler.borrow_mut();
+        scheduler.frame_rendered(now);
+        assert!(!scheduler.should_render(now));
+    }
+
     #[test]
     fn test_exit_reason_eq() {
         assert_eq!(ReactiveExitReason::UserExit, ReactiveExitReason::UserExit);
@@ -293,6 +658,191 @@ mod tests {
         let _width = app.blaeck().width();
     }
 
+    #[test]
+    fn test_metrics_include_runtime_counters() {
+        let app = ReactiveApp::with_writer(Vec::new(), ReactiveAppConfig::default()).unwrap();
+        let id = app.runtime().create_signal(0i32);
+        app.runtime().set_signal(id, 1);
+
+        let metrics = app.metrics();
+        assert_eq!(metrics.signal_updates, 1);
+        assert_eq!(metrics.frames_rendered, 0);
+    }
+
+    #[test]
+    fn test_hotkeys_run_before_input() {
+        use crossterm::event::KeyCode;
+
+        let mut app = ReactiveApp::with_writer(Vec::new(), ReactiveAppConfig::default()).unwrap();
+        app.register_hotkey("q", "quit", |app| app.exit()).unwrap();
+        assert!(!app.dispatch_hotkey(&Key::new(KeyCode::Char('x'))));
+        assert!(!app.should_exit);
+        assert!(app.dispatch_hotkey(&Key::new(KeyCode::Char('q'))));
+        assert!(app.should_exit);
+        assert_eq!(app.exit_reason, ReactiveExitReason::UserExit);
+        assert_eq!(app.hotkeys().len(), 1);
+    }
+
+    #[test]
+    fn test_replay_drives_component() {
+        use crate::reactive::{use_input, use_state};
+        use crate::replay::{InputRecording, InputReplay};
+        use std::time::Duration;
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let log = seen.clone();
+        let script = InputRecording::new()
+            .key(Duration::ZERO, "a")
+            .key(Duration::from_millis(5), "b");
+        let result = ReactiveApp::with_writer(Vec::new(), ReactiveAppConfig::default())
+            .unwrap()
+            .with_replay(InputReplay::new(script).instant())
+            .start(move |cx| {
+                let count = use_state(cx.clone(), || 0);
+                log.borrow_mut().push(count.get());
+                let handle = count.clone();
+                use_input(cx, move |_key| handle.set(handle.get() + 1));
+                Element::text(format!("{}", count.get()))
+            })
+            .unwrap();
+        assert_eq!(result.exit_reason, ReactiveExitReason::Completed);
+        assert!(result.recording.is_none());
+        assert_eq!(seen.borrow().last(), Some(&2));
+    }
+
+    #[test]
+    fn test_scheduler_usable_during_frame() {
+        use crate::reactive::{use_input, use_state};
+        use crate::replay::{InputRecording, InputReplay};
+        use std::time::Duration;
+
+        let config = ReactiveAppConfig::default();
+        let scheduler = config.scheduler.clone();
+        let script = InputRecording::new().key(Duration::ZERO, "a");
+        let result = ReactiveApp::with_writer(Vec::new(), config)
+            .unwrap()
+            .with_replay(InputReplay::new(script).instant())
+            .start(move |cx| {
+                // Components and handlers may reach the shared scheduler
+                let now = scheduler.borrow().now();
+                let count = use_state(cx.clone(), || 0);
+                let handle = count.clone();
+                let scheduler = scheduler.clone();
+                use_input(cx, move |_key| {
+                    scheduler.borrow_mut().input_received(now);
+                    handle.set(handle.get() + 1);
+                });
+                Element::text(format!("{}", count.get()))
+            })
+            .unwrap();
+        assert_eq!(result.exit_reason, ReactiveExitReason::Completed);
+    }
+
+    #[test]
+    fn test_scroll_goes_to_innermost_component() {
+        use crate::components::{ScrollState, ScrollView, ScrollViewProps};
+        use crate::components::{Select, SelectProps, SelectState};
+        use crate::reactive::{use_scroll, use_state};
+        use crate::replay::{InputRecording, InputReplay};
+
+        // A list inside a view three rows tall. Content rows: x, y, the
+        // list's a, b, c, then z
+        let script = InputRecording::parse(
+            "0 mouse scroll-down 1,0\n10 mouse scroll-down 1,0\n20 mouse scroll-down 1,2",
+        )
+        .unwrap();
+        let seen = Rc::new(RefCell::new((0, 0)));
+        let log = seen.clone();
+        ReactiveApp::with_writer(Vec::new(), ReactiveAppConfig::default())
+            .unwrap()
+            .with_replay(InputReplay::new(script).instant())
+            .start(move |cx| {
+                let outer = use_state(cx.clone(), || ScrollState::new(6, 3));
+                let inner = use_state(cx.clone(), || SelectState::new(3));
+                let state = outer.clone();
+                use_scroll(cx.clone(), "outer", move |wheel| {
+                    state.update(|mut s| {
+                        s.handle_wheel(wheel);
+                        s
+                    })
+                });
+                let state = inner.clone();
+                use_scroll(cx, "inner", move |wheel| {
+                    state.update(|mut s| {
+                        s.handle_wheel(wheel);
+                        s
+                    })
+                });
+                let (offset, selected) = (outer.get().offset, inner.get().selected);
+                *log.borrow_mut() = (offset, selected);
+                let list = SelectProps::new(vec!["a", "b", "c"])
+                    .selected(selected)
+                    .scroll_id("inner");
+                Element::node::<ScrollView>(
+                    ScrollViewProps::new(3)
+                        .width(10)
+                        .offset(offset)
+                        .scroll_id("outer"),
+                    vec![
+                        Element::text("x"),
+                        Element::text("y"),
+                        Element::node::<Select>(list, vec![]),
+                        Element::text("z"),
+                    ],
+                )
+            })
+            .unwrap();
+        // The view scrolled to the end, then the list moved once under the
+        // pointer; the last notch hit the view again, already at the end
+        assert_eq!(*seen.borrow(), (3, 1));
+    }
+
+    #[test]
+    fn test_terminal_focus_signal() {
+        use crate::reactive::use_terminal_focus;
+        use crate::replay::{InputRecording, InputReplay};
+
+        let script = InputRecording::parse("0 focus out\n10 focus in\n20 focus in").unwrap();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let log = seen.clone();
+        let app = ReactiveApp::with_writer(Vec::new(), ReactiveAppConfig::default())
+            .unwrap()
+            .with_replay(InputReplay::new(script).instant());
+        let runtime = app.runtime().clone();
+        app.start(move |cx| {
+            let focused = use_terminal_focus(cx);
+            log.borrow_mut().push(focused.get());
+            Element::text(if focused.get() { "active" } else { "dimmed" })
+        })
+        .unwrap();
+        // One render per change; the repeated focus in isn't one
+        assert_eq!(*seen.borrow(), vec![true, false, true]);
+        assert!(runtime.has_focus_watchers());
+        assert!(runtime.terminal_focused());
+    }
+
+    #[test]
+    fn test_paste_handler() {
+        use crate::reactive::{use_paste, use_state};
+        use crate::replay::{InputRecording, InputReplay};
+
+        let script = InputRecording::parse("0 paste \"one\\ntwo\"").unwrap();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let log = seen.clone();
+        ReactiveApp::with_writer(Vec::new(), ReactiveAppConfig::default())
+            .unwrap()
+            .with_replay(InputReplay::new(script).instant())
+            .start(move |cx| {
+                let pasted = use_state(cx.clone(), String::new);
+                log.borrow_mut().push(pasted.get());
+                let setter = pasted.clone();
+                use_paste(cx, move |text| setter.set(text.to_string()));
+                Element::text(pasted.get())
+            })
+            .unwrap();
+        assert_eq!(seen.borrow().last().unwrap(), "one\ntwo");
+    }
+
     #[test]
     fn test_config_clone() {
         let config = ReactiveAppConfig::default();
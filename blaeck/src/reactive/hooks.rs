@@ -14,14 +14,31 @@
 //!
 //! - [`use_state`] - Create reactive state
 //! - [`use_input`] - Register an input handler
+//! - [`use_gesture`] - Register a mouse gesture handler
+//! - [`use_scroll`] - Register a scroll-wheel handler for a component
+//! - [`use_paste`] - Register a handler for pasted text
+//! - [`use_task`] - Track a background task's progress
+//! - [`use_toasts`] - Show toast notifications that expire on their own
+//! - [`use_idle`] - Know when the user hasn't pressed a key for a while
+//! - [`use_terminal_focus`] - Know when the terminal window loses focus
+//! - [`use_countdown`] - A countdown that runs callbacks and re-renders as it ticks
+//! - `use_session_state` - Reactive state persisted across launches (`session` feature)
 
 use super::instance::HookSlot;
-use super::runtime::{InputHandlerId, RuntimeHandle, TimelineId};
+use super::runtime::{
+    GestureHandlerId, InputHandlerId, PasteHandlerId, RuntimeHandle, ScrollHandlerId, TimelineId,
+};
 use super::scope::Scope;
 use super::signal::Signal;
+use crate::components::Countdown;
+use crate::gestures::{Gesture, Wheel};
+use crate::idle::IdleTracker;
 use crate::input::Key;
+use crate::task::TaskHandle;
 use crate::timeline::{Animatable, Timeline, TimelineDebugInfo};
+use crate::toast::ToastManager;
 use std::marker::PhantomData;
+use std::time::{Duration, Instant};
 
 /// Create a reactive state signal.
 ///
@@ -191,6 +208,238 @@ where
     }
 }
 
+/// Register a handler for mouse clicks, double-clicks and drags.
+///
+/// Like [`use_input`], the handler is registered on the first render and
+/// persists. If any component registers one on the app's first render,
+/// [`ReactiveApp`](super::ReactiveApp) turns on mouse capture. See
+/// [`crate::gestures`].
+///
+/// # Example
+///
+/// ```ignore
+/// fn list(cx: Scope) -> Element {
+///     let opened = use_state(cx.clone(), || None);
+///     let setter = opened.clone();
+///
+///     use_gesture(cx, move |gesture| {
+///         if let Gesture::DoubleClick { position: (_, row), .. } = gesture {
+///             setter.set(Some(*row));
+///         }
+///     });
+///     // ...
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if:
+/// - Called outside of a reactive component render
+/// - Hook order changes between renders
+pub fn use_gesture<F>(cx: Scope, handler: F)
+where
+    F: Fn(&Gesture) + 'static,
+{
+    let rt = cx.rt.clone();
+    let component_id = cx.component_id;
+
+    let cursor = rt
+        .with_instance_mut(component_id, |instance| instance.advance_cursor())
+        .expect("Component instance not found");
+
+    let existing = rt.with_instance(component_id, |instance| instance.get_hook(cursor).cloned());
+
+    match existing {
+        Some(Some(HookSlot::Gesture(id))) => {
+            if !rt.has_gesture_handler(id) {
+                panic!("Gesture handler was unexpectedly removed");
+            }
+        }
+        Some(Some(other)) => {
+            panic!(
+                "Hook order changed: expected Gesture hook at position {}, found {:?}. \
+                 Hooks must be called unconditionally and in the same order every render.",
+                cursor, other
+            );
+        }
+        Some(None) | None => {
+            let handler_id: GestureHandlerId = rt.register_gesture_handler(handler);
+            rt.with_instance_mut(component_id, |instance| {
+                instance.push_hook(HookSlot::Gesture(handler_id));
+            });
+        }
+    }
+}
+
+/// Register a handler for the scroll wheel over the component named `id` —
+/// a [`ScrollView`](crate::ScrollView), [`Select`](crate::Select) or
+/// [`Table`](crate::Table) given that `scroll_id`.
+///
+/// Wheel notches go to the innermost named component under the pointer
+/// only. Like [`use_gesture`], the handler is registered on the first
+/// render and persists, and registering one turns on mouse capture.
+///
+/// # Example
+///
+/// ```ignore
+/// fn log_view(cx: Scope) -> Element {
+///     let scroll = use_state(cx.clone(), || ScrollState::new(200, 10));
+///     let setter = scroll.clone();
+///
+///     use_scroll(cx, "log", move |wheel| {
+///         setter.update(|mut state| {
+///             state.handle_wheel(wheel);
+///             state
+///         });
+///     });
+///     // ... ScrollViewProps::default().scroll_id("log") ...
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if:
+/// - Called outside of a reactive component render
+/// - Hook order changes between renders
+pub fn use_scroll<F>(cx: Scope, id: impl Into<String>, handler: F)
+where
+    F: Fn(&Wheel) + 'static,
+{
+    let rt = cx.rt.clone();
+    let component_id = cx.component_id;
+
+    let cursor = rt
+        .with_instance_mut(component_id, |instance| instance.advance_cursor())
+        .expect("Component instance not found");
+
+    let existing = rt.with_instance(component_id, |instance| instance.get_hook(cursor).cloned());
+
+    match existing {
+        Some(Some(HookSlot::Scroll(id))) => {
+            if !rt.has_scroll_handler(id) {
+                panic!("Scroll handler was unexpectedly removed");
+            }
+        }
+        Some(Some(other)) => {
+            panic!(
+                "Hook order changed: expected Scroll hook at position {}, found {:?}. \
+                 Hooks must be called unconditionally and in the same order every render.",
+                cursor, other
+            );
+        }
+        Some(None) | None => {
+            let handler_id: ScrollHandlerId = rt.register_scroll_handler(id.into(), handler);
+            rt.with_instance_mut(component_id, |instance| {
+                instance.push_hook(HookSlot::Scroll(handler_id));
+            });
+        }
+    }
+}
+
+/// Register a handler for pasted text, which arrives in one piece.
+///
+/// Like [`use_input`], the handler is registered on the first render and
+/// persists. If any component registers one on the app's first render,
+/// [`ReactiveApp`](super::ReactiveApp) turns on bracketed paste, so a
+/// paste's line breaks don't arrive as Enter presses.
+///
+/// # Example
+///
+/// ```ignore
+/// fn search(cx: Scope) -> Element {
+///     let query = use_state(cx.clone(), TextInputState::new);
+///     let setter = query.clone();
+///
+///     use_paste(cx, move |text| {
+///         setter.update(|mut state| {
+///             state.handle_paste(text);
+///             state
+///         });
+///     });
+///     // ...
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if:
+/// - Called outside of a reactive component render
+/// - Hook order changes between renders
+pub fn use_paste<F>(cx: Scope, handler: F)
+where
+    F: Fn(&str) + 'static,
+{
+    let rt = cx.rt.clone();
+    let component_id = cx.component_id;
+
+    let cursor = rt
+        .with_instance_mut(component_id, |instance| instance.advance_cursor())
+        .expect("Component instance not found");
+
+    let existing = rt.with_instance(component_id, |instance| instance.get_hook(cursor).cloned());
+
+    match existing {
+        Some(Some(HookSlot::Paste(id))) => {
+            if !rt.has_paste_handler(id) {
+                panic!("Paste handler was unexpectedly removed");
+            }
+        }
+        Some(Some(other)) => {
+            panic!(
+                "Hook order changed: expected Paste hook at position {}, found {:?}. \
+                 Hooks must be called unconditionally and in the same order every render.",
+                cursor, other
+            );
+        }
+        Some(None) | None => {
+            let handler_id: PasteHandlerId = rt.register_paste_handler(handler);
+            rt.with_instance_mut(component_id, |instance| {
+                instance.push_hook(HookSlot::Paste(handler_id));
+            });
+        }
+    }
+}
+
+/// Create reactive state that is restored from and saved to the session.
+///
+/// Works like [`use_state`], but on the first render the value is read from
+/// the runtime's [`SessionStore`](crate::session::SessionStore) under `key`,
+/// falling back to `init` when nothing is stored (or no session is attached).
+/// The signal's latest value is written back when the session is saved —
+/// [`ReactiveApp`](super::ReactiveApp) does this on exit.
+///
+/// Keys should be stable and unique across the app, e.g. `"files.tree"`.
+///
+/// # Example
+///
+/// ```ignore
+/// fn file_browser(cx: Scope) -> Element {
+///     let tree = use_session_state(cx, "files.tree", TreeState::new);
+///     // ...
+/// }
+/// ```
+///
+/// Requires the `session` feature.
+///
+/// # Panics
+///
+/// Same as [`use_state`].
+#[cfg(feature = "session")]
+pub fn use_session_state<T, F>(cx: Scope, key: &str, init: F) -> Signal<T>
+where
+    T: Clone + serde::Serialize + serde::de::DeserializeOwned + 'static,
+    F: FnOnce() -> T,
+{
+    let rt = cx.rt.clone();
+    let signal = use_state(cx, || {
+        rt.with_session(|session| session.get::<T>(key))
+            .flatten()
+            .unwrap_or_else(init)
+    });
+    rt.persist_signal::<T>(key, signal.id);
+    signal
+}
+
 /// Create a reactive timeline from a Timeline definition.
 ///
 /// On the first render, the timeline is started. On subsequent renders,
@@ -271,6 +520,321 @@ pub fn use_timeline(cx: Scope, timeline: Timeline) -> TimelineHandle {
     }
 }
 
+/// Create a [`TaskHandle`] whose reports re-render the app.
+///
+/// The handle is created on first render and reused after. Hand a clone to a
+/// worker thread (or use [`TaskHandle::spawn`] from an input handler); each
+/// progress report, message, cancel and finish schedules a re-render.
+///
+/// # Example
+///
+/// ```ignore
+/// fn export(cx: Scope) -> Element {
+///     let task = use_task(cx.clone());
+///
+///     let input_task = task.clone();
+///     use_input(cx, move |key| {
+///         if key.is_char('s') {
+///             let worker = input_task.clone();
+///             std::thread::spawn(move || run_export(worker));
+///         }
+///         input_task.handle_key(key); // Esc cancels
+///     });
+///
+///     Element::node::<Progress>(ProgressProps::from_task(&task), vec![])
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if:
+/// - Called outside of a reactive component render
+/// - Hook order changes between renders
+pub fn use_task(cx: Scope) -> TaskHandle {
+    let rt = cx.rt.clone();
+    let component_id = cx.component_id;
+
+    let cursor = rt
+        .with_instance_mut(component_id, |instance| instance.advance_cursor())
+        .expect("Component instance not found");
+
+    let existing = rt.with_instance(component_id, |instance| instance.get_hook(cursor).cloned());
+
+    match existing {
+        Some(Some(HookSlot::Task(id))) => rt.task(id).expect("Task was unexpectedly removed"),
+        Some(Some(other)) => {
+            panic!(
+                "Hook order changed: expected Task hook at position {}, found {:?}. \
+                 Hooks must be called unconditionally and in the same order every render.",
+                cursor, other
+            );
+        }
+        Some(None) | None => {
+            let task = TaskHandle::new();
+            let task_id = rt.create_task(task.clone());
+            rt.with_instance_mut(component_id, |instance| {
+                instance.push_hook(HookSlot::Task(task_id));
+            });
+            task
+        }
+    }
+}
+
+/// Create a [`ToastManager`] whose toasts re-render the app.
+///
+/// The manager is created on first render and reused after. The app ticks
+/// it every loop, re-rendering while toasts fade in or out and when they
+/// expire.
+///
+/// # Example
+///
+/// ```ignore
+/// fn editor(cx: Scope) -> Element {
+///     let toasts = use_toasts(cx.clone());
+///
+///     let input_toasts = toasts.clone();
+///     use_input(cx, move |key| {
+///         if key.is_char('s') {
+///             input_toasts.success("Saved");
+///         }
+///     });
+///
+///     let bounds = Rect::new(0, 0, 80, 24);
+///     element! {
+///         Box(width: 80.0, height: 24.0) {
+///             Text(content: "press s to save")
+///             #(toasts.overlay(ToastCorner::TopRight, bounds, Instant::now()))
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if:
+/// - Called outside of a reactive component render
+/// - Hook order changes between renders
+pub fn use_toasts(cx: Scope) -> ToastManager {
+    let rt = cx.rt.clone();
+    let component_id = cx.component_id;
+
+    let cursor = rt
+        .with_instance_mut(component_id, |instance| instance.advance_cursor())
+        .expect("Component instance not found");
+
+    let existing = rt.with_instance(component_id, |instance| instance.get_hook(cursor).cloned());
+
+    match existing {
+        Some(Some(HookSlot::Toasts(id))) => {
+            rt.toasts(id).expect("Toasts were unexpectedly removed")
+        }
+        Some(Some(other)) => {
+            panic!(
+                "Hook order changed: expected Toasts hook at position {}, found {:?}. \
+                 Hooks must be called unconditionally and in the same order every render.",
+                cursor, other
+            );
+        }
+        Some(None) | None => {
+            let toasts = ToastManager::new();
+            let toasts_id = rt.create_toasts(toasts.clone());
+            rt.with_instance_mut(component_id, |instance| {
+                instance.push_hook(HookSlot::Toasts(toasts_id));
+            });
+            toasts
+        }
+    }
+}
+
+/// Whether the user has been idle (no key press, mouse event or paste) for
+/// `timeout`.
+///
+/// The signal turns true once the timeout passes and false on the next
+/// input, re-rendering the app each time. The input that wakes the app isn't
+/// passed to input, gesture, scroll or paste handlers. The timeout is read
+/// on first render only.
+///
+/// # Example
+///
+/// ```ignore
+/// fn dashboard(cx: Scope) -> Element {
+///     let idle = use_idle(cx.clone(), Duration::from_secs(120));
+///     let color = if idle.get() { Color::DarkGray } else { Color::White };
+///
+///     element! {
+///         Text(content: "CPU 12%", color: color)
+///     }
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if:
+/// - Called outside of a reactive component render
+/// - Hook order changes between renders
+pub fn use_idle(cx: Scope, timeout: Duration) -> Signal<bool> {
+    let rt = cx.rt.clone();
+    let component_id = cx.component_id;
+
+    let cursor = rt
+        .with_instance_mut(component_id, |instance| instance.advance_cursor())
+        .expect("Component instance not found");
+
+    let existing = rt.with_instance(component_id, |instance| instance.get_hook(cursor).cloned());
+
+    let signal_id = match existing {
+        Some(Some(HookSlot::Idle(id))) => rt
+            .idle_signal(id)
+            .expect("Idle tracker was unexpectedly removed"),
+        Some(Some(other)) => {
+            panic!(
+                "Hook order changed: expected Idle hook at position {}, found {:?}. \
+                 Hooks must be called unconditionally and in the same order every render.",
+                cursor, other
+            );
+        }
+        Some(None) | None => {
+            let signal_id = rt.create_signal(false);
+            let idle_id = rt.create_idle(IdleTracker::new(timeout), signal_id);
+            rt.with_instance_mut(component_id, |instance| {
+                instance.push_hook(HookSlot::Idle(idle_id));
+            });
+            signal_id
+        }
+    };
+    Signal {
+        id: signal_id,
+        rt,
+        _marker: PhantomData,
+    }
+}
+
+/// Whether the terminal window has focus.
+///
+/// The signal turns false when the terminal reports losing focus and true
+/// when it's regained, re-rendering the app each time — for example to
+/// pause animations and dim the UI while in the background. If any
+/// component uses this on the app's first render,
+/// [`ReactiveApp`](super::ReactiveApp) turns on focus reporting. Terminals
+/// without it leave the signal true.
+///
+/// # Example
+///
+/// ```ignore
+/// fn clock(cx: Scope) -> Element {
+///     let focused = use_terminal_focus(cx.clone());
+///     let color = if focused.get() { Color::White } else { Color::DarkGray };
+///
+///     element! {
+///         Text(content: "12:00", color: color)
+///     }
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if:
+/// - Called outside of a reactive component render
+/// - Hook order changes between renders
+pub fn use_terminal_focus(cx: Scope) -> Signal<bool> {
+    let rt = cx.rt.clone();
+    let component_id = cx.component_id;
+
+    let cursor = rt
+        .with_instance_mut(component_id, |instance| instance.advance_cursor())
+        .expect("Component instance not found");
+
+    let existing = rt.with_instance(component_id, |instance| instance.get_hook(cursor).cloned());
+
+    let signal_id = match existing {
+        Some(Some(HookSlot::TerminalFocus(id))) => id,
+        Some(Some(other)) => {
+            panic!(
+                "Hook order changed: expected TerminalFocus hook at position {}, found {:?}. \
+                 Hooks must be called unconditionally and in the same order every render.",
+                cursor, other
+            );
+        }
+        Some(None) | None => {
+            let signal_id = rt.create_signal(true);
+            rt.watch_terminal_focus(signal_id);
+            rt.with_instance_mut(component_id, |instance| {
+                instance.push_hook(HookSlot::TerminalFocus(signal_id));
+            });
+            signal_id
+        }
+    };
+    Signal {
+        id: signal_id,
+        rt,
+        _marker: PhantomData,
+    }
+}
+
+/// A [`Countdown`] started on first render and ticked by the runtime,
+/// which runs its callbacks and re-renders as the time left changes.
+///
+/// `init` builds the countdown, with its callbacks, on first render only.
+///
+/// # Example
+///
+/// ```ignore
+/// fn quiz(cx: Scope) -> Element {
+///     let done = use_state(cx.clone(), || false);
+///     let setter = done.clone();
+///     let countdown = use_countdown(cx, move || {
+///         Countdown::new(Duration::from_secs(30)).on_complete(move || setter.set(true))
+///     });
+///
+///     element! {
+///         Timer(..countdown.props(Instant::now()).warn_at(Duration::from_secs(10)))
+///     }
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if:
+/// - Called outside of a reactive component render
+/// - Hook order changes between renders
+pub fn use_countdown<F>(cx: Scope, init: F) -> Countdown
+where
+    F: FnOnce() -> Countdown,
+{
+    let rt = cx.rt.clone();
+    let component_id = cx.component_id;
+
+    let cursor = rt
+        .with_instance_mut(component_id, |instance| instance.advance_cursor())
+        .expect("Component instance not found");
+
+    let existing = rt.with_instance(component_id, |instance| instance.get_hook(cursor).cloned());
+
+    match existing {
+        Some(Some(HookSlot::Countdown(id))) => rt
+            .countdown(id)
+            .expect("Countdown was unexpectedly removed"),
+        Some(Some(other)) => {
+            panic!(
+                "Hook order changed: expected Countdown hook at position {}, found {:?}. \
+                 Hooks must be called unconditionally and in the same order every render.",
+                cursor, other
+            );
+        }
+        Some(None) | None => {
+            let countdown = init();
+            if !countdown.is_started() {
+                countdown.start(Instant::now());
+            }
+            let countdown_id = rt.create_countdown(countdown.clone());
+            rt.with_instance_mut(component_id, |instance| {
+                instance.push_hook(HookSlot::Countdown(countdown_id));
+            });
+            countdown
+        }
+    }
+}
+
 /// Handle to a timeline in the reactive system.
 ///
 /// Provides access to animated values and playback controls.
@@ -479,6 +1043,101 @@ mod tests {
         (rt, scope)
     }
 
+    #[cfg(feature = "session")]
+    #[test]
+    fn test_use_session_state_restores_and_saves() {
+        use crate::session::SessionStore;
+
+        let (rt, cx) = setup_scope();
+        let mut store = SessionStore::new(1);
+        store.set("tab", &2usize);
+        rt.set_session(store);
+
+        let tab = use_session_state(cx.clone(), "tab", || 0usize);
+        let other = use_session_state(cx, "other", || String::from("init"));
+        assert_eq!(tab.get(), 2);
+        assert_eq!(other.get(), "init");
+
+        tab.set(5);
+        rt.save_session().unwrap();
+        assert_eq!(
+            rt.with_session(|s| s.get::<usize>("tab")).flatten(),
+            Some(5)
+        );
+        assert_eq!(
+            rt.with_session(|s| s.get::<String>("other"))
+                .flatten()
+                .as_deref(),
+            Some("init")
+        );
+    }
+
+    #[test]
+    fn test_use_task_marks_dirty_on_report() {
+        let (rt, cx) = setup_scope();
+        let task = use_task(cx.clone());
+        assert!(!rt.poll_tasks());
+
+        std::thread::spawn({
+            let task = task.clone();
+            move || task.report(0.25, "working")
+        })
+        .join()
+        .unwrap();
+        assert!(rt.poll_tasks());
+        assert!(rt.needs_render());
+        assert!(!rt.poll_tasks());
+
+        // Same handle on re-render
+        rt.reset_hook_cursor(cx.component_id);
+        assert_eq!(use_task(cx).progress(), 0.25);
+    }
+
+    #[test]
+    fn test_use_idle_signal_follows_activity() {
+        let (rt, cx) = setup_scope();
+        let idle = use_idle(cx.clone(), Duration::from_secs(30));
+        assert!(!idle.get());
+
+        let later = std::time::Instant::now() + Duration::from_secs(31);
+        rt.clear_dirty();
+        assert!(rt.poll_idle(later));
+        assert!(idle.get());
+        assert!(rt.needs_render());
+        assert!(!rt.poll_idle(later));
+
+        assert!(rt.record_activity(later));
+        assert!(!idle.get());
+        assert!(!rt.record_activity(later));
+
+        // Same signal on re-render
+        rt.reset_hook_cursor(cx.component_id);
+        assert_eq!(use_idle(cx, Duration::from_secs(1)).id(), idle.id());
+    }
+
+    #[test]
+    fn test_use_countdown_ticks_through_runtime() {
+        let (rt, cx) = setup_scope();
+        let done = use_state(cx.clone(), || false);
+        let setter = done.clone();
+        let countdown = use_countdown(cx.clone(), move || {
+            Countdown::new(Duration::from_secs(5)).on_complete(move || setter.set(true))
+        });
+        assert!(countdown.is_started());
+
+        let later = Instant::now() + Duration::from_secs(6);
+        rt.clear_dirty();
+        assert!(rt.poll_countdowns(later));
+        assert!(done.get());
+        assert!(rt.needs_render());
+
+        // Same countdown on re-render
+        rt.reset_hook_cursor(cx.component_id);
+        let _ = use_state(cx.clone(), || false);
+        let again = use_countdown(cx, || Countdown::new(Duration::from_secs(1)));
+        assert!(again.is_complete());
+    }
+
     #[test]
     fn test_use_state_initial() {
         let (_rt, cx) = setup_scope();
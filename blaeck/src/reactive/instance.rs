@@ -5,7 +5,10 @@
 //! - Hook cursor (for consistent hook ordering)
 //! - Cleanup callbacks (for future use_effect support)
 
-use super::runtime::{InputHandlerId, SignalId, TimelineId};
+use super::runtime::{
+    CountdownId, GestureHandlerId, IdleId, InputHandlerId, PasteHandlerId, ScrollHandlerId,
+    SignalId, TaskId, TimelineId, ToastsId,
+};
 
 /// Represents a slot in the hooks array.
 ///
@@ -19,8 +22,32 @@ pub enum HookSlot {
     /// An input handler created by `use_input`.
     Input(InputHandlerId),
 
+    /// A gesture handler created by `use_gesture`.
+    Gesture(GestureHandlerId),
+
+    /// A scroll-wheel handler created by `use_scroll`.
+    Scroll(ScrollHandlerId),
+
+    /// A paste handler created by `use_paste`.
+    Paste(PasteHandlerId),
+
     /// A timeline created by `use_timeline`.
     Timeline(TimelineId),
+
+    /// A background task created by `use_task`.
+    Task(TaskId),
+
+    /// A toast stack created by `use_toasts`.
+    Toasts(ToastsId),
+
+    /// A terminal focus signal created by `use_terminal_focus`.
+    TerminalFocus(SignalId),
+
+    /// An idle tracker created by `use_idle`.
+    Idle(IdleId),
+
+    /// A countdown created by `use_countdown`.
+    Countdown(CountdownId),
     // Future hooks (v0.3.0+):
     // Effect { cleanup: Option<Box<dyn FnOnce()>>, deps: Vec<...> },
     // Memo { value: Box<dyn Any>, deps: Vec<...> },
@@ -49,6 +49,15 @@
 //! |------|---------|
 //! | [`use_state`] | Create reactive state that triggers re-render on change |
 //! | [`use_input`] | Register keyboard input handler (runs once, persists across renders) |
+//! | [`use_gesture`] | Register mouse click, double-click and drag handler (runs once, persists across renders) |
+//! | [`use_scroll`] | Register scroll-wheel handler for a named scrollable component (runs once, persists across renders) |
+//! | [`use_paste`] | Register handler for pasted text (runs once, persists across renders) |
+//! | `use_session_state` | Create reactive state restored from and saved to the session (`session` feature) |
+//! | [`use_task`] | Create a background task handle whose progress reports re-render |
+//! | [`use_toasts`] | Create a toast manager whose toasts re-render |
+//! | [`use_idle`] | Signal that turns true after a timeout without user input |
+//! | [`use_countdown`] | Start a countdown on first render, ticked by the runtime |
+//! | [`use_terminal_focus`] | Signal tracking whether the terminal window has focus |
 //! | [`use_timeline`] | Create a declarative animation timeline with playback controls |
 //!
 //! Future hooks (v0.3.0+): `use_effect`, `use_memo`, `use_const`
@@ -130,12 +139,19 @@ mod app;
 mod hooks;
 mod instance;
 mod runtime;
+mod scheduler;
 mod scope;
 mod signal;
 
-pub use app::{ReactiveApp, ReactiveAppConfig, ReactiveAppResult};
-pub use hooks::{use_input, use_state, use_timeline, TimelineHandle};
+pub use app::{ReactiveApp, ReactiveAppConfig, ReactiveAppResult, ReactiveHotkeyAction};
+#[cfg(feature = "session")]
+pub use hooks::use_session_state;
+pub use hooks::{
+    use_countdown, use_gesture, use_idle, use_input, use_paste, use_scroll, use_state, use_task,
+    use_terminal_focus, use_timeline, use_toasts, TimelineHandle,
+};
 pub use instance::{ComponentInstance, HookSlot};
-pub use runtime::{ComponentId, RuntimeHandle, RuntimeInner};
+pub use runtime::{ComponentId, CountdownId, IdleId, RuntimeHandle, RuntimeInner, ToastsId};
+pub use scheduler::{Coalesced, FrameScheduler, Immediate, OnIdle};
 pub use scope::Scope;
 pub use signal::Signal;
@@ -4,12 +4,21 @@
 //! It uses a slot-map arena for efficient ID-based storage.
 
 use super::instance::ComponentInstance;
+use crate::components::Countdown;
+use crate::gestures::{Gesture, Wheel};
+use crate::idle::IdleTracker;
 use crate::input::Key;
+use crate::metrics::Metrics;
+#[cfg(feature = "session")]
+use crate::session::SessionStore;
+use crate::task::TaskHandle;
 use crate::timeline::PlayingTimeline;
+use crate::toast::ToastManager;
 use slotmap::{new_key_type, SlotMap};
 use std::any::Any;
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
+use std::time::Instant;
 
 // Define typed keys for the slot maps
 new_key_type! {
@@ -22,8 +31,29 @@ new_key_type! {
     /// Unique identifier for an input handler.
     pub struct InputHandlerId;
 
+    /// Unique identifier for a mouse gesture handler.
+    pub struct GestureHandlerId;
+
+    /// Unique identifier for a scroll-wheel handler.
+    pub struct ScrollHandlerId;
+
+    /// Unique identifier for a paste handler.
+    pub struct PasteHandlerId;
+
     /// Unique identifier for a timeline in the runtime.
     pub struct TimelineId;
+
+    /// Unique identifier for a background task watched by the runtime.
+    pub struct TaskId;
+
+    /// Unique identifier for a toast stack watched by the runtime.
+    pub struct ToastsId;
+
+    /// Unique identifier for an idle tracker in the runtime.
+    pub struct IdleId;
+
+    /// Unique identifier for a countdown ticked by the runtime.
+    pub struct CountdownId;
 }
 
 /// Type alias for input handler function
@@ -32,6 +62,22 @@ type InputHandler = Box<dyn Fn(&Key)>;
 /// Type alias for input handler storage
 type InputHandlerMap = SlotMap<InputHandlerId, InputHandler>;
 
+/// Type alias for gesture handler function
+type GestureHandler = Box<dyn Fn(&Gesture)>;
+
+/// Type alias for scroll handler function
+type ScrollHandler = Box<dyn Fn(&Wheel)>;
+
+/// Type alias for paste handler function
+type PasteHandler = Box<dyn Fn(&str)>;
+
+/// Type alias for signal storage
+type SignalMap = SlotMap<SignalId, Box<dyn Any>>;
+
+/// Reads a persisted signal's current value for saving.
+#[cfg(feature = "session")]
+type PersistFn = Box<dyn Fn(&SignalMap) -> Option<serde_json::Value>>;
+
 /// Handle to the runtime, cheaply clonable.
 ///
 /// This is the main entry point for accessing runtime state.
@@ -78,6 +124,7 @@ impl RuntimeHandle {
                 .get_mut(id)
                 .expect("Signal not found - invalid SignalId");
             *boxed.downcast_mut::<T>().expect("Signal type mismatch") = value;
+            inner.signal_updates += 1;
         }
         self.mark_dirty();
     }
@@ -139,6 +186,15 @@ impl RuntimeHandle {
 
     /// Dispatch a key event to all registered input handlers.
     pub fn dispatch_input(&self, key: &Key) {
+        {
+            let mut inner = self.0.borrow_mut();
+            if inner.input_handlers.is_empty() {
+                inner.events_dropped += 1;
+                return;
+            }
+            inner.events_dispatched += 1;
+        }
+
         // Collect handler pointers to avoid borrow issues during dispatch.
         // We need to collect because a handler might trigger state changes
         // that would require borrowing the runtime again.
@@ -160,6 +216,131 @@ impl RuntimeHandle {
         }
     }
 
+    /// Register a mouse gesture handler. Returns the handler ID.
+    pub fn register_gesture_handler<F>(&self, handler: F) -> GestureHandlerId
+    where
+        F: Fn(&Gesture) + 'static,
+    {
+        self.0
+            .borrow_mut()
+            .gesture_handlers
+            .insert(Box::new(handler))
+    }
+
+    /// Check if a gesture handler with the given ID exists.
+    pub fn has_gesture_handler(&self, id: GestureHandlerId) -> bool {
+        self.0.borrow().gesture_handlers.contains_key(id)
+    }
+
+    /// Whether any gesture handler is registered, so mouse capture is needed.
+    pub fn has_gesture_handlers(&self) -> bool {
+        !self.0.borrow().gesture_handlers.is_empty()
+    }
+
+    /// Dispatch a mouse gesture to all registered gesture handlers.
+    pub fn dispatch_gesture(&self, gesture: &Gesture) {
+        // Collected for the same reason as in dispatch_input
+        let handlers: Vec<*const GestureHandler> = self
+            .0
+            .borrow()
+            .gesture_handlers
+            .values()
+            .map(|h| h as *const _)
+            .collect();
+
+        // SAFETY: as in dispatch_input
+        for handler_ptr in handlers {
+            let handler = unsafe { &*handler_ptr };
+            handler(gesture);
+        }
+    }
+
+    /// Register a scroll-wheel handler for the component named `target`.
+    /// Returns the handler ID.
+    pub fn register_scroll_handler<F>(&self, target: String, handler: F) -> ScrollHandlerId
+    where
+        F: Fn(&Wheel) + 'static,
+    {
+        self.0
+            .borrow_mut()
+            .scroll_handlers
+            .insert((target, Box::new(handler)))
+    }
+
+    /// Check if a scroll handler with the given ID exists.
+    pub fn has_scroll_handler(&self, id: ScrollHandlerId) -> bool {
+        self.0.borrow().scroll_handlers.contains_key(id)
+    }
+
+    /// Whether any scroll handler is registered, so mouse capture is needed.
+    pub fn has_scroll_handlers(&self) -> bool {
+        !self.0.borrow().scroll_handlers.is_empty()
+    }
+
+    /// Dispatch a wheel notch to the handlers for the component named
+    /// `target`.
+    pub fn dispatch_scroll(&self, target: &str, wheel: &Wheel) {
+        // Collected for the same reason as in dispatch_input
+        let handlers: Vec<*const ScrollHandler> = self
+            .0
+            .borrow()
+            .scroll_handlers
+            .values()
+            .filter(|(id, _)| id == target)
+            .map(|(_, h)| h as *const _)
+            .collect();
+
+        // SAFETY: as in dispatch_input
+        for handler_ptr in handlers {
+            let handler = unsafe { &*handler_ptr };
+            handler(wheel);
+        }
+    }
+
+    /// Register a paste handler. Returns the handler ID.
+    pub fn register_paste_handler<F>(&self, handler: F) -> PasteHandlerId
+    where
+        F: Fn(&str) + 'static,
+    {
+        self.0.borrow_mut().paste_handlers.insert(Box::new(handler))
+    }
+
+    /// Check if a paste handler with the given ID exists.
+    pub fn has_paste_handler(&self, id: PasteHandlerId) -> bool {
+        self.0.borrow().paste_handlers.contains_key(id)
+    }
+
+    /// Whether any paste handler is registered, so bracketed paste is needed.
+    pub fn has_paste_handlers(&self) -> bool {
+        !self.0.borrow().paste_handlers.is_empty()
+    }
+
+    /// Dispatch pasted text to all registered paste handlers.
+    pub fn dispatch_paste(&self, text: &str) {
+        // Collected for the same reason as in dispatch_input
+        let handlers: Vec<*const PasteHandler> = self
+            .0
+            .borrow()
+            .paste_handlers
+            .values()
+            .map(|h| h as *const _)
+            .collect();
+
+        // SAFETY: as in dispatch_input
+        for handler_ptr in handlers {
+            let handler = unsafe { &*handler_ptr };
+            handler(text);
+        }
+    }
+
+    /// Copy the runtime counters (signal updates, input events) into `metrics`.
+    pub fn record_metrics(&self, metrics: &mut Metrics) {
+        let inner = self.0.borrow();
+        metrics.signal_updates = inner.signal_updates;
+        metrics.events_dispatched = inner.events_dispatched;
+        metrics.events_dropped = inner.events_dropped;
+    }
+
     /// Create a new timeline from a PlayingTimeline.
     pub fn create_timeline(&self, timeline: PlayingTimeline) -> TimelineId {
         self.0.borrow_mut().timelines.insert(timeline)
@@ -188,6 +369,181 @@ impl RuntimeHandle {
         self.0.borrow_mut().timelines.get_mut(id).map(f)
     }
 
+    /// Watch a background task, re-rendering whenever it reports.
+    pub fn create_task(&self, task: TaskHandle) -> TaskId {
+        let version = task.version();
+        self.0.borrow_mut().tasks.insert((task, version))
+    }
+
+    /// Get a watched task's handle.
+    pub fn task(&self, id: TaskId) -> Option<TaskHandle> {
+        self.0.borrow().tasks.get(id).map(|(task, _)| task.clone())
+    }
+
+    /// Mark the runtime dirty if any watched task changed since the last poll.
+    ///
+    /// Returns true if one did.
+    pub fn poll_tasks(&self) -> bool {
+        let mut changed = false;
+        for (task, seen) in self.0.borrow_mut().tasks.values_mut() {
+            let version = task.version();
+            if version != *seen {
+                *seen = version;
+                changed = true;
+            }
+        }
+        if changed {
+            self.mark_dirty();
+        }
+        changed
+    }
+
+    /// Watch a toast stack, re-rendering while it changes or animates.
+    pub fn create_toasts(&self, toasts: ToastManager) -> ToastsId {
+        let version = toasts.version();
+        self.0.borrow_mut().toasts.insert((toasts, version))
+    }
+
+    /// Get a watched toast stack.
+    pub fn toasts(&self, id: ToastsId) -> Option<ToastManager> {
+        self.0
+            .borrow()
+            .toasts
+            .get(id)
+            .map(|(toasts, _)| toasts.clone())
+    }
+
+    /// Tick every watched toast stack and mark the runtime dirty if one was
+    /// pushed to, dismissed, expired or is animating.
+    ///
+    /// Returns true if one was.
+    pub fn poll_toasts(&self, now: Instant) -> bool {
+        let mut changed = false;
+        for (toasts, seen) in self.0.borrow_mut().toasts.values_mut() {
+            let version = toasts.version();
+            if toasts.tick(now) || version != *seen {
+                *seen = version;
+                changed = true;
+            }
+        }
+        if changed {
+            self.mark_dirty();
+        }
+        changed
+    }
+
+    /// Track user idleness, writing whether the user is idle to the `bool`
+    /// signal `signal`.
+    pub fn create_idle(&self, tracker: IdleTracker, signal: SignalId) -> IdleId {
+        self.0.borrow_mut().idle.insert((tracker, signal))
+    }
+
+    /// The signal an idle tracker writes to.
+    pub fn idle_signal(&self, id: IdleId) -> Option<SignalId> {
+        self.0.borrow().idle.get(id).map(|(_, signal)| *signal)
+    }
+
+    /// Record input at `now` on every idle tracker.
+    ///
+    /// Returns true if this woke one from idle; its signal is set to false.
+    pub fn record_activity(&self, now: Instant) -> bool {
+        let woke: Vec<SignalId> = self
+            .0
+            .borrow_mut()
+            .idle
+            .values_mut()
+            .filter_map(|(tracker, signal)| tracker.activity(now).then_some(*signal))
+            .collect();
+        for &signal in &woke {
+            self.set_signal(signal, false);
+        }
+        !woke.is_empty()
+    }
+
+    /// Set the signal of every idle tracker whose timeout passed to true.
+    ///
+    /// Returns true if one went idle.
+    pub fn poll_idle(&self, now: Instant) -> bool {
+        let idle: Vec<SignalId> = self
+            .0
+            .borrow_mut()
+            .idle
+            .values_mut()
+            .filter_map(|(tracker, signal)| tracker.check(now).then_some(*signal))
+            .collect();
+        for &signal in &idle {
+            self.set_signal(signal, true);
+        }
+        !idle.is_empty()
+    }
+
+    /// Write whether the terminal window has focus to the `bool` signal
+    /// `signal`, starting with the current focus.
+    pub fn watch_terminal_focus(&self, signal: SignalId) {
+        let focused = {
+            let mut inner = self.0.borrow_mut();
+            inner.focus_signals.push(signal);
+            inner.terminal_focused
+        };
+        self.set_signal(signal, focused);
+    }
+
+    /// Whether any component watches terminal focus, so focus reporting is
+    /// needed.
+    pub fn has_focus_watchers(&self) -> bool {
+        !self.0.borrow().focus_signals.is_empty()
+    }
+
+    /// Whether the terminal window has focus, as last reported.
+    pub fn terminal_focused(&self) -> bool {
+        self.0.borrow().terminal_focused
+    }
+
+    /// Record a focus change, setting every watching signal.
+    ///
+    /// Returns true if focus changed.
+    pub fn set_terminal_focus(&self, focused: bool) -> bool {
+        let signals = {
+            let mut inner = self.0.borrow_mut();
+            if inner.terminal_focused == focused {
+                return false;
+            }
+            inner.terminal_focused = focused;
+            inner.focus_signals.clone()
+        };
+        for signal in signals {
+            self.set_signal(signal, focused);
+        }
+        true
+    }
+
+    /// Tick a countdown every poll, re-rendering as it changes.
+    pub fn create_countdown(&self, countdown: Countdown) -> CountdownId {
+        self.0.borrow_mut().countdowns.insert(countdown)
+    }
+
+    /// Get a ticked countdown.
+    pub fn countdown(&self, id: CountdownId) -> Option<Countdown> {
+        self.0.borrow().countdowns.get(id).cloned()
+    }
+
+    /// Tick every countdown, running the callbacks that are due, and mark
+    /// the runtime dirty if one needs redrawing.
+    ///
+    /// Returns true if one did.
+    pub fn poll_countdowns(&self, now: Instant) -> bool {
+        // Cloned out so callbacks can use the runtime
+        let countdowns: Vec<Countdown> = self.0.borrow().countdowns.values().cloned().collect();
+        let mut changed = false;
+        for countdown in countdowns {
+            changed |= countdown.tick(now);
+        }
+        if changed {
+            self.mark_dirty();
+        }
+        changed
+    }
+
     /// Access a component instance.
     pub fn with_instance<R, F: FnOnce(&ComponentInstance) -> R>(
         &self,
@@ -197,6 +553,54 @@ impl RuntimeHandle {
         self.0.borrow().instances.get(id).map(f)
     }
 
+    /// Attach a session store for [`use_session_state`](super::use_session_state).
+    #[cfg(feature = "session")]
+    pub fn set_session(&self, store: SessionStore) {
+        self.0.borrow_mut().session = Some(store);
+    }
+
+    /// Access the attached session store, if any.
+    #[cfg(feature = "session")]
+    pub fn with_session<R, F: FnOnce(&mut SessionStore) -> R>(&self, f: F) -> Option<R> {
+        self.0.borrow_mut().session.as_mut().map(f)
+    }
+
+    /// Register a signal to be written to the session under `key` on save.
+    ///
+    /// Registering the same key again replaces the previous signal.
+    #[cfg(feature = "session")]
+    pub fn persist_signal<T>(&self, key: impl Into<String>, id: SignalId)
+    where
+        T: serde::Serialize + 'static,
+    {
+        let key = key.into();
+        let read: PersistFn = Box::new(move |signals: &SignalMap| {
+            let value = signals.get(id)?.downcast_ref::<T>()?;
+            serde_json::to_value(value).ok()
+        });
+        let mut inner = self.0.borrow_mut();
+        inner.persisted.retain(|(k, _)| *k != key);
+        inner.persisted.push((key, read));
+    }
+
+    /// Copy persisted signals into the session store and write it to disk.
+    ///
+    /// Does nothing if no session is attached.
+    #[cfg(feature = "session")]
+    pub fn save_session(&self) -> std::io::Result<()> {
+        let mut guard = self.0.borrow_mut();
+        let inner = &mut *guard;
+        let Some(session) = inner.session.as_mut() else {
+            return Ok(());
+        };
+        for (key, read) in &inner.persisted {
+            if let Some(value) = read(&inner.signals) {
+                session.set_value(key.clone(), value);
+            }
+        }
+        session.save()
+    }
+
     /// Mutably access a component instance.
     pub fn with_instance_mut<R, F: FnOnce(&mut ComponentInstance) -> R>(
         &self,
@@ -218,7 +622,7 @@ impl Default for RuntimeHandle {
 /// This struct holds all the actual data; `RuntimeHandle` provides safe access.
 pub struct RuntimeInner {
     /// Signal storage - maps SignalId to boxed values.
-    pub(crate) signals: SlotMap<SignalId, Box<dyn Any>>,
+    pub(crate) signals: SignalMap,
 
     /// Component instances - maps ComponentId to instance data.
     pub(crate) instances: SlotMap<ComponentId, ComponentInstance>,
@@ -229,13 +633,58 @@ pub struct RuntimeInner {
     /// Input handlers - maps InputHandlerId to handler functions.
     pub(crate) input_handlers: InputHandlerMap,
 
+    /// Gesture handlers registered by `use_gesture`.
+    pub(crate) gesture_handlers: SlotMap<GestureHandlerId, GestureHandler>,
+
+    /// Scroll handlers registered by `use_scroll`, with the component they
+    /// listen to.
+    pub(crate) scroll_handlers: SlotMap<ScrollHandlerId, (String, ScrollHandler)>,
+
+    /// Paste handlers registered by `use_paste`.
+    pub(crate) paste_handlers: SlotMap<PasteHandlerId, PasteHandler>,
+
     /// Timeline storage - maps TimelineId to playing timelines.
     pub(crate) timelines: SlotMap<TimelineId, PlayingTimeline>,
 
+    /// Watched background tasks, with the version last rendered.
+    pub(crate) tasks: SlotMap<TaskId, (TaskHandle, u64)>,
+
+    /// Watched toast stacks, with the version last rendered.
+    pub(crate) toasts: SlotMap<ToastsId, (ToastManager, u64)>,
+
+    /// Idle trackers and the signals they write to.
+    pub(crate) idle: SlotMap<IdleId, (IdleTracker, SignalId)>,
+
+    /// Signals written by `use_terminal_focus`.
+    pub(crate) focus_signals: Vec<SignalId>,
+
+    /// Whether the terminal window has focus, as last reported.
+    pub(crate) terminal_focused: bool,
+
+    /// Countdowns ticked on every poll.
+    pub(crate) countdowns: SlotMap<CountdownId, Countdown>,
+
     /// Whether the UI needs to be re-rendered.
     ///
     /// Uses `Cell` for interior mutability without full borrow.
     pub(crate) needs_render: Cell<bool>,
+
+    /// Number of signal writes, for metrics.
+    pub(crate) signal_updates: u64,
+
+    /// Input events delivered to handlers, for metrics.
+    pub(crate) events_dispatched: u64,
+
+    /// Input events received with no handler registered, for metrics.
+    pub(crate) events_dropped: u64,
+
+    /// Session store attached by the app.
+    #[cfg(feature = "session")]
+    pub(crate) session: Option<SessionStore>,
+
+    /// Signals saved to the session, keyed by session key.
+    #[cfg(feature = "session")]
+    pub(crate) persisted: Vec<(String, PersistFn)>,
 }
 
 impl RuntimeInner {
@@ -246,8 +695,24 @@ impl RuntimeInner {
             instances: SlotMap::with_key(),
             current_instance: None,
             input_handlers: SlotMap::with_key(),
+            gesture_handlers: SlotMap::with_key(),
+            scroll_handlers: SlotMap::with_key(),
+            paste_handlers: SlotMap::with_key(),
             timelines: SlotMap::with_key(),
+            tasks: SlotMap::with_key(),
+            toasts: SlotMap::with_key(),
+            idle: SlotMap::with_key(),
+            focus_signals: Vec::new(),
+            terminal_focused: true,
+            countdowns: SlotMap::with_key(),
             needs_render: Cell::new(false),
+            signal_updates: 0,
+            events_dispatched: 0,
+            events_dropped: 0,
+            #[cfg(feature = "session")]
+            session: None,
+            #[cfg(feature = "session")]
+            persisted: Vec::new(),
         }
     }
 }
@@ -322,6 +787,23 @@ mod tests {
         assert_eq!(rt.get_signal::<i32>(id3), 30);
     }
 
+    #[test]
+    fn test_record_metrics() {
+        let rt = RuntimeHandle::new();
+        let id = rt.create_signal(0i32);
+        rt.set_signal(id, 1);
+        rt.set_signal(id, 2);
+        rt.dispatch_input(&Key::new(crossterm::event::KeyCode::Enter));
+        rt.register_input_handler(|_| {});
+        rt.dispatch_input(&Key::new(crossterm::event::KeyCode::Enter));
+
+        let mut metrics = Metrics::default();
+        rt.record_metrics(&mut metrics);
+        assert_eq!(metrics.signal_updates, 2);
+        assert_eq!(metrics.events_dropped, 1);
+        assert_eq!(metrics.events_dispatched, 1);
+    }
+
     #[test]
     fn test_instance_create() {
         let rt = RuntimeHandle::new();
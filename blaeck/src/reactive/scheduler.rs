@@ -0,0 +1,219 @@
+//! Frame scheduling for ReactiveApp.
+//!
+//! A [`FrameScheduler`] decides *when* a pending frame is rendered and how
+//! long the event loop may block waiting for input. Plug one in with
+//! [`ReactiveAppConfig::with_scheduler`](super::ReactiveAppConfig::with_scheduler)
+//! to coalesce bursts of state changes, wait for input to go quiet, or drive
+//! render ticks from a host loop or a test clock.
+//!
+//! Built-in policies:
+//!
+//! - [`Immediate`] — render as soon as state changes (the default)
+//! - [`Coalesced`] — at most N frames per second; changes in between are merged
+//! - [`OnIdle`] — render once input has been quiet for a while
+//!
+//! ```ignore
+//! let config = ReactiveAppConfig::default().with_scheduler(Coalesced::new(30));
+//! ReactiveApp::run_with_config(app, config)?;
+//! ```
+
+use std::time::{Duration, Instant};
+
+/// Render scheduling policy for [`ReactiveApp`](super::ReactiveApp).
+///
+/// The event loop asks [`wait_timeout`](Self::wait_timeout) how long to block
+/// for input, then — if state changed — asks
+/// [`should_render`](Self::should_render) whether to render now. A pending
+/// frame that isn't rendered stays pending and is asked about again on the
+/// next iteration.
+pub trait FrameScheduler {
+    /// The current time. Override to drive the scheduler from a test clock
+    /// or a host loop's frame time.
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    /// How long the loop may wait for input.
+    ///
+    /// `pending` is true when a frame is waiting to be rendered. The default
+    /// waits for the configured poll interval.
+    fn wait_timeout(&mut self, now: Instant, poll_interval: Duration, pending: bool) -> Duration {
+        let _ = (now, pending);
+        poll_interval
+    }
+
+    /// Whether to render the pending frame now.
+    fn should_render(&mut self, now: Instant) -> bool;
+
+    /// Called after a frame was rendered.
+    fn frame_rendered(&mut self, now: Instant) {
+        let _ = now;
+    }
+
+    /// Called when an input event arrives.
+    fn input_received(&mut self, now: Instant) {
+        let _ = now;
+    }
+}
+
+/// Render as soon as state changes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Immediate;
+
+impl FrameScheduler for Immediate {
+    fn should_render(&mut self, _now: Instant) -> bool {
+        true
+    }
+}
+
+/// Render at most `fps` frames per second, merging changes in between.
+#[derive(Debug, Clone, Copy)]
+pub struct Coalesced {
+    /// Minimum time between frames.
+    interval: Duration,
+    /// When the last frame was rendered.
+    last_frame: Option<Instant>,
+}
+
+impl Coalesced {
+    /// Create a scheduler capped at `fps` frames per second. 0 means unlimited.
+    pub fn new(fps: u32) -> Self {
+        let interval = if fps == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs(1) / fps
+        };
+        Self {
+            interval,
+            last_frame: None,
+        }
+    }
+
+    /// Time left until the next frame may be rendered.
+    fn remaining(&self, now: Instant) -> Duration {
+        match self.last_frame {
+            Some(last) => self
+                .interval
+                .saturating_sub(now.saturating_duration_since(last)),
+            None => Duration::ZERO,
+        }
+    }
+}
+
+impl FrameScheduler for Coalesced {
+    fn wait_timeout(&mut self, now: Instant, poll_interval: Duration, pending: bool) -> Duration {
+        if pending {
+            poll_interval.min(self.remaining(now))
+        } else {
+            poll_interval
+        }
+    }
+
+    fn should_render(&mut self, now: Instant) -> bool {
+        self.remaining(now).is_zero()
+    }
+
+    fn frame_rendered(&mut self, now: Instant) {
+        self.last_frame = Some(now);
+    }
+}
+
+/// Render once no input has arrived for `idle`.
+///
+/// Useful when each keystroke triggers expensive work (filtering a large
+/// list, re-running a query): typing stays responsive and the UI catches
+/// up when the user pauses. Changes not caused by input render right away.
+#[derive(Debug, Clone, Copy)]
+pub struct OnIdle {
+    /// Quiet period required before rendering.
+    idle: Duration,
+    /// When the last input event arrived.
+    last_input: Option<Instant>,
+}
+
+impl OnIdle {
+    /// Create a scheduler that waits for `idle` without input.
+    pub fn new(idle: Duration) -> Self {
+        Self {
+            idle,
+            last_input: None,
+        }
+    }
+
+    /// Time left until input counts as idle.
+    fn remaining(&self, now: Instant) -> Duration {
+        match self.last_input {
+            Some(last) => self
+                .idle
+                .saturating_sub(now.saturating_duration_since(last)),
+            None => Duration::ZERO,
+        }
+    }
+}
+
+impl FrameScheduler for OnIdle {
+    fn wait_timeout(&mut self, now: Instant, poll_interval: Duration, pending: bool) -> Duration {
+        if pending {
+            poll_interval.min(self.remaining(now))
+        } else {
+            poll_interval
+        }
+    }
+
+    fn should_render(&mut self, now: Instant) -> bool {
+        self.remaining(now).is_zero()
+    }
+
+    fn input_received(&mut self, now: Instant) {
+        self.last_input = Some(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coalesced_limits_rate() {
+        let start = Instant::now();
+        let mut sched = Coalesced::new(10);
+        let poll = Duration::from_millis(50);
+
+        assert!(sched.should_render(start));
+        sched.frame_rendered(start);
+
+        let soon = start + Duration::from_millis(30);
+        assert!(!sched.should_render(soon));
+        assert_eq!(
+            sched.wait_timeout(soon, poll, true),
+            Duration::from_millis(50)
+        );
+        let later = start + Duration::from_millis(80);
+        assert_eq!(
+            sched.wait_timeout(later, poll, true),
+            Duration::from_millis(20)
+        );
+        assert!(sched.should_render(start + Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_on_idle_waits_for_quiet_input() {
+        let start = Instant::now();
+        let mut sched = OnIdle::new(Duration::from_millis(200));
+
+        // No input yet: render right away
+        assert!(sched.should_render(start));
+
+        sched.input_received(start);
+        assert!(!sched.should_render(start + Duration::from_millis(150)));
+        assert_eq!(
+            sched.wait_timeout(
+                start + Duration::from_millis(150),
+                Duration::from_secs(1),
+                true
+            ),
+            Duration::from_millis(50)
+        );
+        assert!(sched.should_render(start + Duration::from_millis(200)));
+    }
+}
@@ -0,0 +1,245 @@
+//! Multiple independent inline render regions on one writer.
+//!
+//! A single [`Blaeck`] owns the whole block of lines it last wrote. Two
+//! renderers on the same writer would each move the cursor up by *their own*
+//! line count and erase the other's output. `Regions` solves this by stacking
+//! any number of regions vertically and tracking the line span of each:
+//!
+//! ```text
+//! ┌──────────────────────┐
+//! │ permanent output     │  ← println(): scrolls up, never re-rendered
+//! ├──────────────────────┤
+//! │ region 0 (log area)  │  ← lines 0..3
+//! ├──────────────────────┤
+//! │ region 1 (footer)    │  ← lines 3..4
+//! └──────────────────────┘
+//! ```
+//!
+//! Rendering a region only replaces that region's content; the others keep
+//! their last frame. The combined block is written through a single
+//! [`LogUpdate`], so the terminal still sees one flicker-free update.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut regions = Regions::new(std::io::stdout())?;
+//! let log = regions.add_region();
+//! let footer = regions.add_region();
+//!
+//! regions.render(footer, element! { Text(content: "Building...") })?;
+//! for line in build_output {
+//!     regions.println(&line)?;  // scrolls above both regions
+//! }
+//! regions.render(log, element! { Text(content: "3 warnings") })?;
+//! regions.unmount()?;
+//! ```
+
+use crate::element::Element;
+use crate::log_update::LogUpdate;
+use crate::renderer::{Blaeck, Result};
+use std::io::{self, Write};
+use std::ops::Range;
+
+/// Identifier for a region created by [`Regions::add_region`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegionId(usize);
+
+/// A set of vertically stacked inline render regions sharing one writer.
+pub struct Regions<W: Write> {
+    log_update: LogUpdate<W>,
+    /// Layout/paint engine used to turn elements into strings.
+    engine: Blaeck<io::Sink>,
+    /// Last rendered content of each region, lines joined by `\r\n`.
+    /// `None` means the region currently occupies no lines.
+    contents: Vec<Option<String>>,
+}
+
+impl<W: Write> Regions<W> {
+    /// Creates a new region set with the given writer.
+    ///
+    /// The terminal size is queried, falling back to 80x24 if not available.
+    pub fn new(writer: W) -> Result<Self> {
+        let (width, height) = crossterm::terminal::size().unwrap_or((80, 24));
+        Self::with_size(writer, width, height)
+    }
+
+    /// Creates a new region set with explicit dimensions.
+    pub fn with_size(writer: W, width: u16, height: u16) -> Result<Self> {
+        Ok(Self {
+            log_update: LogUpdate::new(writer),
+            engine: Blaeck::with_size(io::sink(), width, height)?,
+            contents: Vec::new(),
+        })
+    }
+
+    /// Adds a new, empty region below all existing regions.
+    pub fn add_region(&mut self) -> RegionId {
+        self.contents.push(None);
+        RegionId(self.contents.len() - 1)
+    }
+
+    /// Returns the number of regions.
+    pub fn len(&self) -> usize {
+        self.contents.len()
+    }
+
+    /// Returns true if no regions have been added.
+    pub fn is_empty(&self) -> bool {
+        self.contents.is_empty()
+    }
+
+    /// Gets the current terminal width.
+    pub fn width(&self) -> u16 {
+        self.engine.width()
+    }
+
+    /// Returns the line span a region currently occupies, relative to the
+    /// top of the live block.
+    ///
+    /// # Panics
+    /// Panics if `id` was not created by this `Regions`.
+    pub fn span(&self, id: RegionId) -> Range<usize> {
+        let start: usize = self.contents[..id.0]
+            .iter()
+            .map(|c| Self::line_count(c.as_deref()))
+            .sum();
+        start..start + Self::line_count(self.contents[id.0].as_deref())
+    }
+
+    /// Renders an element into one region, leaving the other regions intact.
+    ///
+    /// # Panics
+    /// Panics if `id` was not created by this `Regions`.
+    pub fn render(&mut self, id: RegionId, element: Element) -> Result<()> {
+        let rendered = self.engine.render_element(&element)?;
+        self.contents[id.0] = Some(rendered);
+        self.flush()
+    }
+
+    /// Removes a region's content so it occupies no lines.
+    ///
+    /// The region can be rendered into again later.
+    pub fn clear_region(&mut self, id: RegionId) -> Result<()> {
+        self.contents[id.0] = None;
+        self.flush()
+    }
+
+    /// Writes a permanent line above all regions.
+    ///
+    /// The line scrolls up with the rest of the terminal output and is never
+    /// erased; the regions are redrawn below it.
+    pub fn println(&mut self, line: &str) -> Result<()> {
+        self.log_update.clear()?;
+        self.log_update.render(line)?;
+        self.log_update.done()?;
+        self.flush()
+    }
+
+    /// Handle terminal resize event.
+    ///
+    /// Clears the live block; later renders are laid out at the new width.
+    /// A region keeps its last lines, laid out at the old width, until it's
+    /// rendered again, so re-render every region that should reflow.
+    pub fn handle_resize(&mut self, width: u16, height: u16) -> Result<()> {
+        self.engine.handle_resize(width, height)?;
+        self.log_update.handle_resize()?;
+        Ok(())
+    }
+
+    /// Finalizes rendering, leaving all regions visible.
+    pub fn unmount(&mut self) -> Result<()> {
+        self.log_update.done()?;
+        Ok(())
+    }
+
+    /// Writes the combined content of all regions.
+    fn flush(&mut self) -> Result<()> {
+        let combined: Vec<&str> = self.contents.iter().filter_map(|c| c.as_deref()).collect();
+        if combined.is_empty() {
+            self.log_update.clear()?;
+            return Ok(());
+        }
+        self.log_update.render(&combined.join("\r\n"))?;
+        Ok(())
+    }
+
+    fn line_count(content: Option<&str>) -> usize {
+        content.map_or(0, |c| c.matches('\n').count() + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{Text, TextProps};
+
+    fn text(content: &str) -> Element {
+        Element::node::<Text>(
+            TextProps {
+                content: content.into(),
+                ..Default::default()
+            },
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_regions_spans_stack() {
+        let mut regions = Regions::with_size(Vec::new(), 40, 10).unwrap();
+        let top = regions.add_region();
+        let bottom = regions.add_region();
+        assert_eq!(regions.len(), 2);
+
+        regions.render(bottom, text("footer")).unwrap();
+        assert_eq!(regions.span(top), 0..0);
+        assert_eq!(regions.span(bottom), 0..1);
+
+        regions.render(top, text("a\nb\nc")).unwrap();
+        assert_eq!(regions.span(top), 0..3);
+        assert_eq!(regions.span(bottom), 3..4);
+    }
+
+    #[test]
+    fn test_regions_render_keeps_other_regions() {
+        let mut buf = Vec::new();
+        {
+            let mut regions = Regions::with_size(&mut buf, 40, 10).unwrap();
+            let log = regions.add_region();
+            let footer = regions.add_region();
+            regions.render(log, text("log line")).unwrap();
+            regions.render(footer, text("status: 1")).unwrap();
+            regions.render(footer, text("status: 2")).unwrap();
+        }
+        let output = String::from_utf8(buf).unwrap();
+        let last_frame = output.rsplit("\x1b[?2026h").next().unwrap();
+        assert!(last_frame.contains("log line"));
+        assert!(last_frame.contains("status: 2"));
+    }
+
+    #[test]
+    fn test_regions_clear_region() {
+        let mut regions = Regions::with_size(Vec::new(), 40, 10).unwrap();
+        let a = regions.add_region();
+        let b = regions.add_region();
+        regions.render(a, text("one\ntwo")).unwrap();
+        regions.render(b, text("three")).unwrap();
+        regions.clear_region(a).unwrap();
+        assert_eq!(regions.span(a), 0..0);
+        assert_eq!(regions.span(b), 0..1);
+    }
+
+    #[test]
+    fn test_regions_println_above() {
+        let mut buf = Vec::new();
+        {
+            let mut regions = Regions::with_size(&mut buf, 40, 10).unwrap();
+            let footer = regions.add_region();
+            regions.render(footer, text("footer")).unwrap();
+            regions.println("permanent").unwrap();
+        }
+        let output = String::from_utf8(buf).unwrap();
+        let permanent = output.rfind("permanent").unwrap();
+        let footer = output.rfind("footer").unwrap();
+        assert!(permanent < footer);
+    }
+}
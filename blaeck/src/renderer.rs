@@ -16,28 +16,53 @@
 //!
 //! See `ARCHITECTURE.md` for the full mental model.
 
+use crate::buffer::Buffer;
 use crate::components::{
-    Autocomplete, Badge, BarChart, BoxProps, Breadcrumbs, Checkbox, Confirm, Diff, Divider,
-    Gradient, KeyHints, Link, LogBox, Markdown, Modal, MultiSelect, Progress, Select, Sparkline,
-    Spinner, StatusBar, SyntaxHighlight, Table, Tabs, TextInput, Timer, TreeView,
+    Autocomplete, BackdropProps, Badge, BarChart, BigText, BoxProps, Breadcrumbs, Calendar, Canvas,
+    Checkbox, Collapsible, CollapsibleProps, Confirm, ContextMenu, Diff, Divider, EmptyState,
+    ErrorState, Form, Gauge, Gradient, HyperlinkProps, Image, ImageProps, KeyHints, LineChart,
+    Link, LinkProps, LoadingState, LogBox, Markdown, Modal, MultiProgress, MultiSelect,
+    PendingKeys, Progress, RadioGroup, Reveal, RevealProps, Rule, RuleProps, ScrollView,
+    ScrollViewProps, Scrollbar, Select, SelectProps, Sparkline, Spinner, Sprite, StatusBar,
+    SyntaxHighlight, Table, TableProps, Tabs, TaskList, TextArea, TextInput, TextProps, TimePicker,
+    Timer, Toc, TreeView, Truncate, TruncateProps, ValueTree, Wizard,
 };
-use crate::element::Element;
+use crate::element::{Component, Element};
+use crate::error::BlaeckError;
+use crate::filter::FrameFilter;
+use crate::glyphs::GlyphSet;
+use crate::graphics::GraphicsProtocol;
 use crate::layout::{LayoutStyle, LayoutTree};
 use crate::log_update::LogUpdate;
-use crate::output::Output;
-use crate::style::{Color, Style};
-use std::any::TypeId;
-use std::collections::HashMap;
+use crate::metrics::{Metrics, MetricsCallback};
+use crate::output::{expand_tabs, Output, DEFAULT_TAB_WIDTH};
+use crate::pipeline::{LayoutPipeline, PaintedFrame};
+use crate::selection::SelectMode;
+use crate::style::{Color, Modifier, Style};
+use crate::truncation::Truncation;
+use crossterm::event::KeyboardEnhancementFlags;
+use std::any::{Any, TypeId};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 use std::io::Write;
 use std::time::{Duration, Instant};
 use taffy::NodeId;
 
 /// Result type for Blaeck operations.
-pub type Result<T> = std::io::Result<T>;
+pub use crate::error::Result;
 
 /// Strip ANSI and OSC escape sequences from a string for width calculation.
-/// This handles both standard ANSI escapes (\x1b[...m) and OSC 8 hyperlinks (\x1b]8;;...\x07).
-fn strip_ansi_escapes(s: &str) -> String {
+/// This handles CSI escapes (\x1b[...m), OSC 8 hyperlinks (\x1b]8;;...\x07),
+/// and DCS/APC strings used by terminal graphics protocols.
+///
+/// Returns the input unchanged, without allocating, when it has none.
+pub(crate) fn strip_ansi_escapes(s: &str) -> Cow<'_, str> {
+    if !s.contains('\x1b') {
+        return Cow::Borrowed(s);
+    }
     let mut result = String::with_capacity(s.len());
     let mut chars = s.chars().peekable();
 
@@ -50,7 +75,18 @@ fn strip_ansi_escapes(s: &str) -> String {
                     chars.next(); // consume '['
                     while let Some(&ch) = chars.peek() {
                         chars.next();
-                        if ch == 'm' {
+                        // Any final byte ends the sequence (m, C, K, ...)
+                        if ('@'..='~').contains(&ch) {
+                            break;
+                        }
+                    }
+                }
+                Some('P') | Some('_') => {
+                    // DCS / APC string (sixel, kitty graphics): \x1bP...\x1b\\
+                    chars.next();
+                    while let Some(ch) = chars.next() {
+                        if ch == '\x1b' && chars.peek() == Some(&'\\') {
+                            chars.next();
                             break;
                         }
                     }
@@ -68,6 +104,10 @@ fn strip_ansi_escapes(s: &str) -> String {
                         }
                     }
                 }
+                Some('7') | Some('8') => {
+                    // Save / restore cursor
+                    chars.next();
+                }
                 _ => {
                     // Unknown escape, skip just the ESC
                 }
@@ -77,7 +117,65 @@ fn strip_ansi_escapes(s: &str) -> String {
         }
     }
 
-    result
+    Cow::Owned(result)
+}
+
+/// The terminal modes an app loop turned on, so they can be turned off
+/// again however the loop ends.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct TerminalModes {
+    pub raw_mode: bool,
+    pub mouse_capture: bool,
+    pub focus_reporting: bool,
+    pub bracketed_paste: bool,
+    pub keyboard_enhancement: bool,
+}
+
+impl TerminalModes {
+    /// Turns the kitty keyboard protocol on in raw mode, if the terminal
+    /// supports it, asking for repeats and releases too when `releases` is
+    /// set.
+    pub fn enable_keyboard_enhancement<W: Write>(
+        &mut self,
+        blaeck: &mut Blaeck<W>,
+        releases: bool,
+    ) -> Result<()> {
+        if !self.raw_mode || !crossterm::terminal::supports_keyboard_enhancement().unwrap_or(false)
+        {
+            return Ok(());
+        }
+        let mut flags = KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES;
+        if releases {
+            flags |= KeyboardEnhancementFlags::REPORT_EVENT_TYPES;
+        }
+        self.keyboard_enhancement = true;
+        blaeck.set_keyboard_enhancement(Some(flags))
+    }
+
+    /// Turns every mode back off. Each step runs even if an earlier one
+    /// fails; the first error is returned.
+    pub fn restore<W: Write>(&self, blaeck: &mut Blaeck<W>) -> Result<()> {
+        let mut results = Vec::new();
+        if self.keyboard_enhancement {
+            results.push(blaeck.set_keyboard_enhancement(None));
+        }
+        if self.bracketed_paste {
+            results.push(blaeck.set_bracketed_paste(false));
+        }
+        if self.focus_reporting {
+            results.push(blaeck.set_focus_reporting(false));
+        }
+        if self.mouse_capture {
+            results.push(blaeck.set_mouse_capture(false));
+        }
+        if self.raw_mode {
+            results.push(
+                crossterm::terminal::disable_raw_mode()
+                    .map_err(|e| BlaeckError::terminal("disabling raw mode", e)),
+            );
+        }
+        results.into_iter().collect()
+    }
 }
 
 /// The main Blaeck renderer that manages terminal output.
@@ -102,13 +200,55 @@ pub struct Blaeck<W: Write> {
     log_update: LogUpdate<W>,
     width: u16,
     height: u16,
-    static_output: String,
+    /// Keys of Static items already printed above the live region
+    static_keys: HashSet<String>,
+    /// Whether Static nodes are left out of layout (set while rendering
+    /// the live region, since their items are printed above it)
+    hide_static: bool,
     /// Minimum duration between renders (for throttling)
     min_render_interval: Option<Duration>,
     /// Last time a render was performed
     last_render: Option<Instant>,
     /// Reusable layout tree to avoid memory growth from Taffy allocations
     layout_tree: LayoutTree,
+    /// Frame counters and timings
+    metrics: Metrics,
+    /// Called after each rendered frame
+    metrics_callback: Option<MetricsCallback>,
+    /// Whether to send only changed cells instead of redrawing every frame
+    damage_tracking: bool,
+    /// Cells of the last frame, for damage tracking
+    previous_buffer: Option<Buffer>,
+    /// Columns between tab stops when expanding `\t`
+    tab_width: u16,
+    /// Post-processing applied to each frame before it is serialized
+    filters: Vec<Box<dyn FrameFilter>>,
+    /// Hash of the last frame written, to skip identical frames
+    last_frame_hash: Option<u64>,
+    /// The last frame written, for select mode
+    last_output: Option<Output>,
+    /// Whether renders are suspended (select mode)
+    frozen: bool,
+    /// Painted cells of boxes with a cache key, reused while the key, size
+    /// and content match. Behind a RefCell because painting only borrows
+    /// `self`.
+    panel_cache: RefCell<HashMap<String, CachedPanel>>,
+    /// Static output of pipeline frames presented while frozen
+    deferred_static: Option<String>,
+    /// Terminal row of the live region's first line, for mapping mouse
+    /// positions onto the frame. Negative once the region is taller than
+    /// the screen.
+    frame_row: i32,
+}
+
+/// A box painted on an earlier frame.
+struct CachedPanel {
+    /// Width and height the box was painted at.
+    size: (u16, u16),
+    /// Hash of the box's own props.
+    props: u64,
+    /// The painted cells.
+    output: Output,
 }
 
 impl<W: Write> Blaeck<W> {
@@ -127,10 +267,23 @@ impl<W: Write> Blaeck<W> {
             log_update: LogUpdate::new(writer),
             width,
             height,
-            static_output: String::new(),
+            static_keys: HashSet::new(),
+            hide_static: false,
             min_render_interval: None,
             last_render: None,
             layout_tree: LayoutTree::new(),
+            metrics: Metrics::default(),
+            metrics_callback: None,
+            damage_tracking: false,
+            previous_buffer: None,
+            tab_width: DEFAULT_TAB_WIDTH,
+            filters: Vec::new(),
+            last_frame_hash: None,
+            last_output: None,
+            frozen: false,
+            panel_cache: RefCell::new(HashMap::new()),
+            deferred_static: None,
+            frame_row: 0,
         })
     }
 
@@ -171,6 +324,37 @@ impl<W: Write> Blaeck<W> {
         self.log_update.set_cursor_visible(visible);
     }
 
+    /// Sets the distance between tab stops used to expand `\t` in text
+    /// (default [`DEFAULT_TAB_WIDTH`]). Tab stops are counted from the start
+    /// of each text element, so measurement and drawing agree.
+    pub fn set_tab_width(&mut self, width: u16) {
+        self.tab_width = width;
+    }
+
+    /// Adds a filter that post-processes every frame before it is written.
+    ///
+    /// Filters run in the order they were added. See [`crate::filter`].
+    pub fn add_filter(&mut self, filter: impl FrameFilter + 'static) {
+        self.filters.push(Box::new(filter));
+    }
+
+    /// Removes all frame filters.
+    pub fn clear_filters(&mut self) {
+        self.filters.clear();
+    }
+
+    /// Enables or disables per-cell damage tracking.
+    ///
+    /// When enabled, the previous frame's cells are kept and re-renders with
+    /// the same size write only the cells that changed — a cursor move and a
+    /// few bytes for a ticking clock instead of the whole block. Frames whose
+    /// height changes, or that contain images or hyperlinks, are redrawn in full.
+    /// Default is `false`.
+    pub fn set_damage_tracking(&mut self, enabled: bool) {
+        self.damage_tracking = enabled;
+        self.previous_buffer = None;
+    }
+
     /// Returns whether this render would be throttled (skipped).
     ///
     /// Useful if you want to skip expensive state updates when
@@ -183,6 +367,35 @@ impl<W: Write> Blaeck<W> {
         }
     }
 
+    /// Returns the render metrics collected so far.
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    /// Mutable access to the metrics, for runtimes that record their own
+    /// counters (signal updates, input events) alongside the render stats.
+    pub fn metrics_mut(&mut self) -> &mut Metrics {
+        &mut self.metrics
+    }
+
+    /// Resets all metrics to zero.
+    pub fn reset_metrics(&mut self) {
+        self.metrics = Metrics::default();
+    }
+
+    /// Sets a callback invoked with the current metrics after each rendered frame.
+    pub fn set_metrics_callback<F>(&mut self, callback: F)
+    where
+        F: FnMut(&Metrics) + 'static,
+    {
+        self.metrics_callback = Some(Box::new(callback));
+    }
+
+    /// Removes the metrics callback.
+    pub fn clear_metrics_callback(&mut self) {
+        self.metrics_callback = None;
+    }
+
     /// Gets the current terminal width.
     pub fn width(&self) -> u16 {
         self.width
@@ -201,10 +414,15 @@ impl<W: Write> Blaeck<W> {
     /// If throttling is enabled via `set_max_fps()` or `set_throttle()`,
     /// this method will skip rendering if called too soon after the last
     /// render. Use `render_force()` to bypass throttling.
+    ///
+    /// A frame identical to the one on screen is never written, and doesn't
+    /// count as a render for throttling — so a monitor that re-renders on a
+    /// timer stays idle until its content changes, then updates right away.
     pub fn render(&mut self, element: Element) -> Result<()> {
         // Check throttling
         if let (Some(interval), Some(last)) = (self.min_render_interval, self.last_render) {
             if last.elapsed() < interval {
+                self.metrics.record_skipped();
                 return Ok(()); // Skip this render
             }
         }
@@ -217,32 +435,339 @@ impl<W: Write> Blaeck<W> {
     /// Use this when you need to force a render regardless of throttling,
     /// such as for the final render before unmounting.
     pub fn render_force(&mut self, element: Element) -> Result<()> {
-        // Update last render time
-        self.last_render = Some(Instant::now());
+        if self.frozen {
+            self.metrics.record_discarded();
+            return Ok(());
+        }
+        let started = Instant::now();
+        let (above, output) = self.paint_frame(&element)?;
+        self.write_frame(above, output, started)
+    }
+
+    /// Presents a frame laid out by a [`LayoutPipeline`]: prints its Static
+    /// items and writes it like [`render_force`](Self::render_force) would.
+    ///
+    /// Frame filters and damage tracking apply here, on this thread. A
+    /// frame laid out at a different width than the renderer's (from
+    /// before a resize) is skipped, though its Static items are printed.
+    /// While frozen, the frame is skipped and its Static items wait for the
+    /// next presented frame.
+    pub fn present(&mut self, frame: PaintedFrame) -> Result<()> {
+        let (above, mut output, started) = frame.into_parts();
+        let above = match (self.deferred_static.take(), above) {
+            (Some(mut earlier), Some(above)) => {
+                earlier.push_str(&above);
+                Some(earlier)
+            }
+            (earlier, above) => earlier.or(above),
+        };
+        if self.frozen {
+            self.deferred_static = above;
+            self.metrics.record_discarded();
+            return Ok(());
+        }
+        if output.width != self.width {
+            if let Some(above) = above {
+                self.log_update.print_above(&above)?;
+            }
+            self.metrics.record_discarded();
+            return Ok(());
+        }
+        for filter in &mut self.filters {
+            filter.apply(&mut output);
+        }
+        self.write_frame(above, output, started)
+    }
+
+    /// Lays out and paints `element`: the Static items not printed yet, and
+    /// the live region.
+    pub(crate) fn paint_frame(&mut self, element: &Element) -> Result<(Option<String>, Output)> {
+        let above = self.new_static_output(element)?;
+        self.hide_static = true;
+        let output = self.render_output(element);
+        self.hide_static = false;
+        Ok((above, output?))
+    }
+
+    /// Writes a painted frame to the terminal, below any new Static output.
+    fn write_frame(
+        &mut self,
+        above: Option<String>,
+        output: Output,
+        started: Instant,
+    ) -> Result<()> {
+        // Print Static items that haven't been printed yet above the live
+        // region. The live region is redrawn unchanged below them in the
+        // same write, so it only needs repainting if its own content changed
+        if let Some(static_content) = above {
+            self.log_update.print_above(&static_content)?;
+            self.frame_row += static_content.lines().count().max(1) as i32;
+            self.clamp_frame_row();
+        }
+
+        // Skip the frame if nothing changed
+        let rendered = output.get().output;
+        let hash = {
+            let mut hasher = DefaultHasher::new();
+            rendered.hash(&mut hasher);
+            hasher.finish()
+        };
+        if self.last_frame_hash == Some(hash) {
+            self.metrics.record_unchanged();
+            return Ok(());
+        }
+        self.last_frame_hash = Some(hash);
+        self.last_render = Some(started);
+
+        if self.damage_tracking {
+            let buffer = (!output.has_overlays()).then(|| output.to_buffer());
+            match (&self.previous_buffer, &buffer) {
+                (Some(prev), Some(next))
+                    if prev.width() == next.width() && prev.height() == next.height() =>
+                {
+                    let patch = Buffer::diff_ansi(prev, next);
+                    self.log_update.patch(&rendered, &patch)?;
+                }
+                _ => self.log_update.render(&rendered)?,
+            }
+            self.previous_buffer = buffer;
+        } else {
+            self.log_update.render(&rendered)?;
+        }
+        self.last_output = Some(output);
+        self.clamp_frame_row();
+
+        self.metrics.record_frame(started.elapsed());
+        if let Some(callback) = self.metrics_callback.as_mut() {
+            callback(&self.metrics);
+        }
+
+        Ok(())
+    }
+
+    /// Suspends or resumes rendering.
+    ///
+    /// While frozen, [`render`](Self::render) and
+    /// [`render_force`](Self::render_force) leave the screen untouched so
+    /// the user can select text from it (see [`SelectMode`]).
+    pub fn set_frozen(&mut self, frozen: bool) {
+        self.frozen = frozen;
+        if !frozen {
+            // The selection highlight is on screen; redraw the next frame
+            self.last_frame_hash = None;
+            self.previous_buffer = None;
+        }
+    }
+
+    /// Returns whether rendering is suspended.
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Plain text of the last rendered frame, one entry per row.
+    pub fn frame_lines(&self) -> Vec<String> {
+        let Some(output) = &self.last_output else {
+            return Vec::new();
+        };
+        (0..output.height)
+            .filter_map(|y| output.line_text(y))
+            .map(|line| line.trim_end().to_string())
+            .collect()
+    }
+
+    /// Cells of the last rendered frame, e.g. to send as a
+    /// [`FramePatch`](crate::patch::FramePatch) to a remote client.
+    pub fn frame_buffer(&self) -> Option<Buffer> {
+        self.last_output.as_ref().map(Output::to_buffer)
+    }
 
-        // Check for Static content
-        let (static_content, has_static) = self.check_for_static(&element);
+    /// Redraws the last frame with the selection of `select` highlighted.
+    pub fn render_selection(&mut self, select: &SelectMode) -> Result<()> {
+        let Some(mut output) = self.last_output.clone() else {
+            return Ok(());
+        };
+        output.map_styles(|x, y, style| {
+            if select.is_selected(x as usize, y as usize) {
+                style.add_modifier(Modifier::REVERSED)
+            } else {
+                style
+            }
+        });
+        self.last_frame_hash = None;
+        self.previous_buffer = None;
+        self.log_update.render(&output.get().output)?;
+        Ok(())
+    }
 
-        // If there's new static content, append it
-        if has_static && !static_content.is_empty() {
-            // Clear current output, write static, then continue
-            self.log_update.clear()?;
-            self.static_output.push_str(&static_content);
-            self.static_output.push('\n');
-            // Write static output directly (it scrolls up)
-            self.log_update.render(&self.static_output)?;
-            self.log_update.done()?;
+    /// Starts a [`LayoutPipeline`] that lays out frames at this renderer's
+    /// size and tab width on a worker thread, for [`present`](Self::present).
+    pub fn layout_pipeline(&self) -> LayoutPipeline {
+        let mut pipeline = LayoutPipeline::new(self.width, self.height);
+        pipeline.set_tab_width(self.tab_width);
+        pipeline
+    }
+
+    /// Drops the cached cells of the box with cache key `key`, so it is
+    /// painted again on the next frame.
+    pub fn invalidate_cache(&mut self, key: &str) {
+        self.panel_cache.get_mut().remove(key);
+    }
+
+    /// Drops the cached cells of every box.
+    pub fn clear_cache(&mut self) {
+        self.panel_cache.get_mut().clear();
+    }
+
+    /// Copies `text` to the system clipboard via OSC 52.
+    ///
+    /// Returns [`BlaeckError::Unsupported`] in terminals known to ignore
+    /// OSC 52 (see [`clipboard::is_supported`](crate::clipboard::is_supported)).
+    pub fn copy_to_clipboard(&mut self, text: &str) -> Result<()> {
+        if !crate::clipboard::is_supported() {
+            return Err(BlaeckError::unsupported("OSC 52 clipboard"));
         }
+        self.log_update.write_raw(&crate::clipboard::osc52(text))?;
+        Ok(())
+    }
 
-        // Render the element
-        let rendered = self.render_element(&element)?;
-        self.log_update.render(&rendered)?;
+    /// Keeps the live region on screen: output that would run past the
+    /// bottom scrolls the terminal, moving the region up.
+    fn clamp_frame_row(&mut self) {
+        let lines = self.log_update.previous_line_count() as i32;
+        self.frame_row = self.frame_row.min(i32::from(self.height) - 1 - lines);
+    }
 
+    /// Tells the renderer which terminal row the live region starts on, so
+    /// mouse positions can be mapped onto the frame. It's kept up to date
+    /// as the region grows and Static output is printed above it.
+    ///
+    /// Defaults to 0, the top of the screen. See also
+    /// [`locate_frame`](Self::locate_frame).
+    pub fn set_frame_row(&mut self, row: u16) {
+        self.frame_row = i32::from(row);
+        self.clamp_frame_row();
+    }
+
+    /// Asks the terminal where the cursor is to find the live region's
+    /// row, like [`set_frame_row`](Self::set_frame_row).
+    ///
+    /// Needs raw mode, and waits for the terminal to answer. If it doesn't,
+    /// the row is left unchanged and the error returned.
+    pub fn locate_frame(&mut self) -> Result<()> {
+        let (_, row) = crossterm::cursor::position()
+            .map_err(|e| BlaeckError::terminal("reading the cursor position", e))?;
+        // The cursor rests on the line below the rendered lines
+        let lines = self.log_update.previous_line_count() as i32;
+        self.frame_row = i32::from(row) - lines;
         Ok(())
     }
 
+    /// The named scrollable component (a [`ScrollView`], [`Select`] or
+    /// [`Table`] with a `scroll_id`) under the terminal cell at (`column`,
+    /// `row`) in the last frame, innermost first.
+    pub fn scroll_target_at(&self, column: u16, row: u16) -> Option<&str> {
+        let y = u16::try_from(i32::from(row) - self.frame_row).ok()?;
+        self.last_output.as_ref()?.scroll_target_at(column, y)
+    }
+
+    /// Turns terminal mouse reporting on or off.
+    ///
+    /// While it's on, clicks, drags and scrolls arrive as input events and
+    /// the terminal's own text selection doesn't work.
+    pub fn set_mouse_capture(&mut self, on: bool) -> Result<()> {
+        use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+
+        self.set_mode(
+            on,
+            (EnableMouseCapture, "enabling mouse capture"),
+            (DisableMouseCapture, "disabling mouse capture"),
+        )
+    }
+
+    /// Turns terminal focus reporting on or off.
+    ///
+    /// While it's on, the terminal reports its window gaining and losing
+    /// focus as [`InputEvent::Focus`](crate::InputEvent::Focus). Terminals
+    /// without support ignore it.
+    pub fn set_focus_reporting(&mut self, on: bool) -> Result<()> {
+        use crossterm::event::{DisableFocusChange, EnableFocusChange};
+
+        self.set_mode(
+            on,
+            (EnableFocusChange, "enabling focus reporting"),
+            (DisableFocusChange, "disabling focus reporting"),
+        )
+    }
+
+    /// Turns bracketed paste on or off.
+    ///
+    /// While it's on, pasted text arrives in one piece as
+    /// [`InputEvent::Paste`](crate::InputEvent::Paste) rather than typed
+    /// key by key, so its line breaks can't be mistaken for Enter.
+    pub fn set_bracketed_paste(&mut self, on: bool) -> Result<()> {
+        use crossterm::event::{DisableBracketedPaste, EnableBracketedPaste};
+
+        self.set_mode(
+            on,
+            (EnableBracketedPaste, "enabling bracketed paste"),
+            (DisableBracketedPaste, "disabling bracketed paste"),
+        )
+    }
+
+    /// Turns the kitty keyboard protocol on with `flags`, or off with `None`.
+    ///
+    /// While it's on, supporting terminals report keypad keys, Super, media
+    /// keys and F13 and up, and with
+    /// [`REPORT_EVENT_TYPES`](KeyboardEnhancementFlags::REPORT_EVENT_TYPES)
+    /// repeats and releases. Check
+    /// [`supports_keyboard_enhancement`](crossterm::terminal::supports_keyboard_enhancement)
+    /// first; other terminals may print the sequence.
+    pub fn set_keyboard_enhancement(
+        &mut self,
+        flags: Option<KeyboardEnhancementFlags>,
+    ) -> Result<()> {
+        use crossterm::event::{PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags};
+
+        self.set_mode(
+            flags.is_some(),
+            (
+                PushKeyboardEnhancementFlags(flags.unwrap_or(KeyboardEnhancementFlags::empty())),
+                "enabling keyboard enhancement",
+            ),
+            (
+                PopKeyboardEnhancementFlags,
+                "disabling keyboard enhancement",
+            ),
+        )
+    }
+
+    /// Writes the `enable` or `disable` command for a terminal mode, with
+    /// the context to report if writing it fails.
+    fn set_mode(
+        &mut self,
+        on: bool,
+        enable: (impl crossterm::Command, &'static str),
+        disable: (impl crossterm::Command, &'static str),
+    ) -> Result<()> {
+        let mut sequence = String::new();
+        // Writing to a String can't fail
+        let (_, context) = if on {
+            (enable.0.write_ansi(&mut sequence), enable.1)
+        } else {
+            (disable.0.write_ansi(&mut sequence), disable.1)
+        };
+        self.log_update
+            .write_raw(&sequence)
+            .map_err(|e| BlaeckError::terminal(context, e))
+    }
+
     /// Renders an element tree and returns the string output.
-    fn render_element(&mut self, element: &Element) -> Result<String> {
+    pub(crate) fn render_element(&mut self, element: &Element) -> Result<String> {
+        Ok(self.render_output(element)?.get().output)
+    }
+
+    /// Lays out an element tree and paints it into a fresh output grid.
+    fn render_output(&mut self, element: &Element) -> Result<Output> {
         // Reuse layout tree's memory. If tree has grown very large, recreate it
         // to release memory (prevents unbounded growth from varying tree sizes)
         let mut layout_tree = std::mem::take(&mut self.layout_tree);
@@ -262,6 +787,7 @@ impl<W: Write> Blaeck<W> {
 
         // Create output buffer
         let mut output = Output::new(self.width, output_height);
+        output.set_tab_width(self.tab_width);
 
         // Render each element to the output buffer using Taffy's computed layout
         self.render_node(
@@ -276,8 +802,51 @@ impl<W: Write> Blaeck<W> {
         // Put the layout tree back for reuse
         self.layout_tree = layout_tree;
 
-        let result = output.get();
-        Ok(result.output)
+        // Forget cached boxes that are no longer in the tree. Boxes culled
+        // this frame are still in it and keep their cells.
+        let live: HashSet<&str> = node_elements
+            .values()
+            .filter_map(|element| match element {
+                Element::Node { props, .. } => {
+                    props.downcast_ref::<BoxProps>()?.cache_key.as_deref()
+                }
+                _ => None,
+            })
+            .collect();
+        self.panel_cache
+            .get_mut()
+            .retain(|key, _| live.contains(key.as_str()));
+
+        for filter in &mut self.filters {
+            filter.apply(&mut output);
+        }
+
+        Ok(output)
+    }
+
+    /// Builds `children` into an unshrinkable column and lays it out on its
+    /// own, returning the column and its height in rows.
+    fn build_measured_content<'a>(
+        &self,
+        tree: &mut LayoutTree,
+        children: &'a [Element],
+        node_elements: &mut HashMap<NodeId, &'a Element>,
+    ) -> Result<(NodeId, usize)> {
+        let mut child_nodes = Vec::with_capacity(children.len());
+        for child in children {
+            child_nodes.push(self.build_layout_tree(tree, child, node_elements)?);
+        }
+        let content_style = LayoutStyle {
+            flex_direction: crate::layout::FlexDirection::Column,
+            flex_shrink: 0.0,
+            ..Default::default()
+        };
+        let content = tree
+            .new_with_children(content_style, &child_nodes)
+            .map_err(|e| layout_error("a measured column", e))?;
+        tree.compute(content, self.width as f32, self.height as f32);
+        let content_height = tree.get_layout(content).height.ceil() as usize;
+        Ok((content, content_height))
     }
 
     /// Builds a Taffy layout tree from an element tree.
@@ -302,9 +871,11 @@ impl<W: Write> Blaeck<W> {
         element: &'a Element,
         node_elements: &mut HashMap<NodeId, &'a Element>,
     ) -> Result<NodeId> {
+        // Layout errors name the element whose node couldn't be added
+        let node_error = |e| layout_error(&describe_element(element), e);
         match element {
             Element::Empty => {
-                let node = tree.new_leaf(LayoutStyle::default()).map_err(to_io_error)?;
+                let node = tree.new_leaf(LayoutStyle::default()).map_err(node_error)?;
                 node_elements.insert(node, element);
                 Ok(node)
             }
@@ -312,7 +883,7 @@ impl<W: Write> Blaeck<W> {
                 // Text elements take up width based on the widest line
                 let text_width = content
                     .lines()
-                    .map(|line| unicode_width::UnicodeWidthStr::width(line) as f32)
+                    .map(|line| self.text_width(line) as f32)
                     .fold(0.0_f32, |a, b| a.max(b));
                 let lines = content.lines().count().max(1);
                 let style = LayoutStyle {
@@ -320,7 +891,7 @@ impl<W: Write> Blaeck<W> {
                     height: Some(lines as f32),
                     ..Default::default()
                 };
-                let node = tree.new_leaf(style).map_err(to_io_error)?;
+                let node = tree.new_leaf(style).map_err(node_error)?;
                 node_elements.insert(node, element);
                 Ok(node)
             }
@@ -337,7 +908,7 @@ impl<W: Write> Blaeck<W> {
                 };
                 let node = tree
                     .new_with_children(style, &child_nodes)
-                    .map_err(to_io_error)?;
+                    .map_err(node_error)?;
                 node_elements.insert(node, element);
                 Ok(node)
             }
@@ -349,11 +920,24 @@ impl<W: Write> Blaeck<W> {
                 render_fn,
                 ..
             } => {
+                // Static items are printed above the live region instead
+                if self.hide_static && *type_id == TypeId::of::<Static>() {
+                    let style = LayoutStyle {
+                        display: crate::layout::Display::None,
+                        ..Default::default()
+                    };
+                    let node = tree.new_leaf(style).map_err(node_error)?;
+                    node_elements.insert(node, element);
+                    return Ok(node);
+                }
+
                 // Handle leaf components that render to Text
                 if *type_id == TypeId::of::<crate::components::Text>()
                     || *type_id == TypeId::of::<Spinner>()
                     || *type_id == TypeId::of::<Progress>()
                     || *type_id == TypeId::of::<TextInput>()
+                    || *type_id == TypeId::of::<TextArea>()
+                    || *type_id == TypeId::of::<RadioGroup>()
                     || *type_id == TypeId::of::<Checkbox>()
                     || *type_id == TypeId::of::<Select>()
                     || *type_id == TypeId::of::<Confirm>()
@@ -366,6 +950,7 @@ impl<W: Write> Blaeck<W> {
                     || *type_id == TypeId::of::<MultiSelect>()
                     || *type_id == TypeId::of::<Sparkline>()
                     || *type_id == TypeId::of::<KeyHints>()
+                    || *type_id == TypeId::of::<PendingKeys>()
                     || *type_id == TypeId::of::<Gradient>()
                     || *type_id == TypeId::of::<Breadcrumbs>()
                     || *type_id == TypeId::of::<StatusBar>()
@@ -373,33 +958,69 @@ impl<W: Write> Blaeck<W> {
                     || *type_id == TypeId::of::<Markdown>()
                     || *type_id == TypeId::of::<LogBox>()
                     || *type_id == TypeId::of::<Timer>()
+                    || *type_id == TypeId::of::<TimePicker>()
                     || *type_id == TypeId::of::<TreeView>()
                     || *type_id == TypeId::of::<BarChart>()
                     || *type_id == TypeId::of::<SyntaxHighlight>()
                     || *type_id == TypeId::of::<Modal>()
+                    || *type_id == TypeId::of::<ContextMenu>()
+                    || *type_id == TypeId::of::<Toc>()
+                    || *type_id == TypeId::of::<Scrollbar>()
+                    || *type_id == TypeId::of::<BigText>()
+                    || *type_id == TypeId::of::<LineChart>()
+                    || *type_id == TypeId::of::<Gauge>()
+                    || *type_id == TypeId::of::<Canvas>()
+                    || *type_id == TypeId::of::<Sprite>()
+                    || *type_id == TypeId::of::<EmptyState>()
+                    || *type_id == TypeId::of::<ErrorState>()
+                    || *type_id == TypeId::of::<LoadingState>()
+                    || *type_id == TypeId::of::<Calendar>()
+                    || *type_id == TypeId::of::<Form>()
+                    || *type_id == TypeId::of::<Wizard>()
+                    || *type_id == TypeId::of::<ValueTree>()
+                    || is_log_viewer(type_id)
+                    || *type_id == TypeId::of::<MultiProgress>()
+                    || *type_id == TypeId::of::<TaskList>()
                     || *type_id == TypeId::of::<crate::components::Spacer>()
                 {
                     let rendered = render_fn(props.as_ref());
-                    // Handle Fragment (for Gradient/Breadcrumbs/StatusBar/Diff/Markdown/LogBox/TreeView/BarChart/SyntaxHighlight/Modal/Spacer component)
+                    // Handle Fragment (for Gradient/Breadcrumbs/StatusBar/Diff/Markdown/LogBox/TreeView/BarChart/TextArea/RadioGroup/SyntaxHighlight/Modal/ContextMenu/Toc/Scrollbar/BigText/LineChart/Gauge/Canvas/Sprite/EmptyState/ErrorState/LoadingState/Calendar/Form/Wizard/ValueTree/LogViewer/MultiProgress/TaskList/Spacer component)
                     if let Element::Fragment(children) = &rendered {
-                        // Diff, Markdown, LogBox, TreeView, BarChart, SyntaxHighlight, Modal, Spacer render vertically - each child is a separate line
+                        // Diff, Markdown, LogBox, TreeView, BarChart, TextArea, RadioGroup, SyntaxHighlight, Modal, ContextMenu, Toc, Scrollbar, BigText, LineChart, Gauge, Canvas, Sprite, EmptyState, ErrorState, LoadingState, Calendar, Form, Wizard, ValueTree, LogViewer, MultiProgress, TaskList, Spacer render vertically - each child is a separate line
                         if *type_id == TypeId::of::<Diff>()
                             || *type_id == TypeId::of::<Markdown>()
                             || *type_id == TypeId::of::<LogBox>()
                             || *type_id == TypeId::of::<TreeView>()
                             || *type_id == TypeId::of::<BarChart>()
+                            || *type_id == TypeId::of::<TextArea>()
+                            || *type_id == TypeId::of::<RadioGroup>()
                             || *type_id == TypeId::of::<SyntaxHighlight>()
                             || *type_id == TypeId::of::<Modal>()
+                            || *type_id == TypeId::of::<ContextMenu>()
+                            || *type_id == TypeId::of::<Toc>()
+                            || *type_id == TypeId::of::<Scrollbar>()
+                            || *type_id == TypeId::of::<BigText>()
+                            || *type_id == TypeId::of::<LineChart>()
+                            || *type_id == TypeId::of::<Gauge>()
+                            || *type_id == TypeId::of::<Canvas>()
+                            || *type_id == TypeId::of::<Sprite>()
+                            || *type_id == TypeId::of::<EmptyState>()
+                            || *type_id == TypeId::of::<ErrorState>()
+                            || *type_id == TypeId::of::<LoadingState>()
+                            || *type_id == TypeId::of::<Calendar>()
+                            || *type_id == TypeId::of::<Form>()
+                            || *type_id == TypeId::of::<Wizard>()
+                            || *type_id == TypeId::of::<ValueTree>()
+                            || is_log_viewer(type_id)
+                            || *type_id == TypeId::of::<MultiProgress>()
+                            || *type_id == TypeId::of::<TaskList>()
                             || *type_id == TypeId::of::<crate::components::Spacer>()
                         {
                             let mut max_width: f32 = 0.0;
                             for child in children {
                                 match child {
                                     Element::Text { content, .. } => {
-                                        let stripped = strip_ansi_escapes(content);
-                                        let w = unicode_width::UnicodeWidthStr::width(
-                                            stripped.as_str(),
-                                        ) as f32;
+                                        let w = self.text_width(content) as f32;
                                         max_width = max_width.max(w);
                                     }
                                     Element::Fragment(inline_children) => {
@@ -407,11 +1028,7 @@ impl<W: Write> Blaeck<W> {
                                         let mut line_width: f32 = 0.0;
                                         for inline_child in inline_children {
                                             if let Element::Text { content, .. } = inline_child {
-                                                let stripped = strip_ansi_escapes(content);
-                                                line_width += unicode_width::UnicodeWidthStr::width(
-                                                    stripped.as_str(),
-                                                )
-                                                    as f32;
+                                                line_width += self.text_width(content) as f32;
                                             }
                                         }
                                         max_width = max_width.max(line_width);
@@ -424,7 +1041,7 @@ impl<W: Write> Blaeck<W> {
                                 height: Some(children.len() as f32),
                                 ..Default::default()
                             };
-                            let node = tree.new_leaf(style).map_err(to_io_error)?;
+                            let node = tree.new_leaf(style).map_err(node_error)?;
                             node_elements.insert(node, element);
                             return Ok(node);
                         }
@@ -437,10 +1054,7 @@ impl<W: Write> Blaeck<W> {
                         }
                         let text_width = total_content
                             .lines()
-                            .map(|line| {
-                                let stripped = strip_ansi_escapes(line);
-                                unicode_width::UnicodeWidthStr::width(stripped.as_str()) as f32
-                            })
+                            .map(|line| self.text_width(line) as f32)
                             .fold(0.0_f32, |a, b| a.max(b));
                         let lines = total_content.lines().count().max(1);
                         let style = LayoutStyle {
@@ -448,7 +1062,7 @@ impl<W: Write> Blaeck<W> {
                             height: Some(lines as f32),
                             ..Default::default()
                         };
-                        let node = tree.new_leaf(style).map_err(to_io_error)?;
+                        let node = tree.new_leaf(style).map_err(node_error)?;
                         node_elements.insert(node, element);
                         return Ok(node);
                     }
@@ -457,23 +1071,131 @@ impl<W: Write> Blaeck<W> {
                         // Strip escape sequences for accurate width calculation
                         let text_width = content
                             .lines()
-                            .map(|line| {
-                                let stripped = strip_ansi_escapes(line);
-                                unicode_width::UnicodeWidthStr::width(stripped.as_str()) as f32
-                            })
+                            .map(|line| self.text_width(line) as f32)
                             .fold(0.0_f32, |a, b| a.max(b));
                         let lines = content.lines().count().max(1);
+                        // Truncating text may shrink below its width
+                        let truncates = props
+                            .downcast_ref::<TextProps>()
+                            .is_some_and(|text| text.wrap.truncate_at().is_some());
                         let style = LayoutStyle {
                             width: Some(text_width),
                             height: Some(lines as f32),
+                            min_width: truncates.then_some(0.0),
                             ..Default::default()
                         };
-                        let node = tree.new_leaf(style).map_err(to_io_error)?;
+                        let node = tree.new_leaf(style).map_err(node_error)?;
                         node_elements.insert(node, element);
                         return Ok(node);
                     }
                 }
 
+                // Collapsible: a header leaf, then an indented column with
+                // only the children that are shown
+                if *type_id == TypeId::of::<Collapsible>() {
+                    let props = props
+                        .downcast_ref::<CollapsibleProps>()
+                        .cloned()
+                        .unwrap_or_default();
+                    let header = tree
+                        .new_leaf(LayoutStyle {
+                            width: Some(self.text_width(&props.header_string()) as f32),
+                            height: Some(1.0),
+                            flex_shrink: 0.0,
+                            ..Default::default()
+                        })
+                        .map_err(node_error)?;
+                    let mut sections = vec![header];
+                    let shown = props.visible_children(children.len());
+                    if shown > 0 {
+                        let mut child_nodes = Vec::with_capacity(shown);
+                        for child in &children[..shown] {
+                            child_nodes.push(self.build_layout_tree(tree, child, node_elements)?);
+                        }
+                        let content = tree
+                            .new_with_children(props.content_layout_style(), &child_nodes)
+                            .map_err(node_error)?;
+                        sections.push(content);
+                    }
+                    let node = tree
+                        .new_with_children(props.to_layout_style(), &sections)
+                        .map_err(node_error)?;
+                    node_elements.insert(node, element);
+                    return Ok(node);
+                }
+
+                // Truncate: the children are measured on their own first, so
+                // the view can be cut to the rows shown and the hint only
+                // added when something is hidden
+                if *type_id == TypeId::of::<Truncate>() {
+                    let props = props
+                        .downcast_ref::<TruncateProps>()
+                        .cloned()
+                        .unwrap_or_default();
+                    let (content, content_height) =
+                        self.build_measured_content(tree, children, node_elements)?;
+                    let view = tree
+                        .new_with_children(props.view(content_height).to_layout_style(), &[content])
+                        .map_err(node_error)?;
+                    let mut sections = vec![view];
+                    if let Some(hint) = props.hint(content_height) {
+                        sections.push(
+                            tree.new_leaf(LayoutStyle {
+                                width: Some(self.text_width(&hint) as f32),
+                                height: Some(1.0),
+                                flex_shrink: 0.0,
+                                ..Default::default()
+                            })
+                            .map_err(node_error)?,
+                        );
+                    }
+                    let node = tree
+                        .new_with_children(props.to_layout_style(), &sections)
+                        .map_err(node_error)?;
+                    node_elements.insert(node, element);
+                    return Ok(node);
+                }
+
+                // Rule: one row that grows to fill its parent
+                if let Some(rule) = props.downcast_ref::<RuleProps>() {
+                    let node = tree.new_leaf(rule.layout_style()).map_err(node_error)?;
+                    node_elements.insert(node, element);
+                    return Ok(node);
+                }
+
+                // Image: a leaf the size asked for, which may shrink
+                if *type_id == TypeId::of::<Image>() {
+                    let (width, height) = props
+                        .downcast_ref::<ImageProps>()
+                        .map(ImageProps::cells)
+                        .unwrap_or_default();
+                    let style = LayoutStyle {
+                        width: Some(f32::from(width)),
+                        height: Some(f32::from(height)),
+                        min_width: Some(0.0),
+                        min_height: Some(0.0),
+                        ..Default::default()
+                    };
+                    let node = tree.new_leaf(style).map_err(node_error)?;
+                    node_elements.insert(node, element);
+                    return Ok(node);
+                }
+
+                // Reveal: like Truncate, cut to the rows shown, with no hint
+                if *type_id == TypeId::of::<Reveal>() {
+                    let props = props
+                        .downcast_ref::<RevealProps>()
+                        .cloned()
+                        .unwrap_or_default();
+                    let (content, content_height) =
+                        self.build_measured_content(tree, children, node_elements)?;
+                    let node = tree
+                        .new_with_children(props.view(content_height).to_layout_style(), &[content])
+                        .map_err(node_error)?;
+                    node_elements.insert(node, element);
+                    return Ok(node);
+                }
+
                 // Build child nodes first
                 let mut child_nodes = Vec::new();
                 for child in children {
@@ -481,6 +1203,29 @@ impl<W: Write> Blaeck<W> {
                     child_nodes.push(child_node);
                 }
 
+                // ScrollView: the children go in an unshrinkable content node
+                // inside the fixed-height viewport, so they keep their natural
+                // height and render_scroll_view can pick the visible rows
+                if *type_id == TypeId::of::<ScrollView>() {
+                    let props = props
+                        .downcast_ref::<ScrollViewProps>()
+                        .cloned()
+                        .unwrap_or_default();
+                    let content_style = LayoutStyle {
+                        flex_direction: crate::layout::FlexDirection::Column,
+                        flex_shrink: 0.0,
+                        ..Default::default()
+                    };
+                    let content = tree
+                        .new_with_children(content_style, &child_nodes)
+                        .map_err(node_error)?;
+                    let node = tree
+                        .new_with_children(props.to_layout_style(), &[content])
+                        .map_err(node_error)?;
+                    node_elements.insert(node, element);
+                    return Ok(node);
+                }
+
                 // Get layout style from props if it's a Box
                 let style = if *type_id == TypeId::of::<crate::components::Box>() {
                     if let Some(box_props) = props.downcast_ref::<BoxProps>() {
@@ -503,10 +1248,10 @@ impl<W: Write> Blaeck<W> {
                 };
 
                 let node = if child_nodes.is_empty() {
-                    tree.new_leaf(style).map_err(to_io_error)?
+                    tree.new_leaf(style).map_err(node_error)?
                 } else {
                     tree.new_with_children(style, &child_nodes)
-                        .map_err(to_io_error)?
+                        .map_err(node_error)?
                 };
 
                 node_elements.insert(node, element);
@@ -515,6 +1260,13 @@ impl<W: Write> Blaeck<W> {
         }
     }
 
+    /// Display width of one line of text, as [`Output::write`] will draw it:
+    /// escape sequences are ignored and tabs expanded.
+    fn text_width(&self, line: &str) -> usize {
+        let stripped = strip_ansi_escapes(line);
+        unicode_width::UnicodeWidthStr::width(&*expand_tabs(&stripped, self.tab_width))
+    }
+
     /// Renders a node and its children using Taffy's computed layout.
     fn render_node(
         &self,
@@ -534,6 +1286,14 @@ impl<W: Write> Blaeck<W> {
         let x = parent_x + layout.x;
         let y = parent_y + layout.y;
 
+        // Viewport culling: a subtree entirely outside the rows the grid
+        // holds (such as the off-screen part of a ScrollView) would only be
+        // clipped, so it isn't painted at all
+        let rows = output.visible_rows();
+        if y >= f32::from(rows.end) || y + layout.content_height.max(1.0) <= f32::from(rows.start) {
+            return Ok(());
+        }
+
         match element {
             Element::Empty => {}
             Element::Text { content, style } => {
@@ -552,11 +1312,29 @@ impl<W: Write> Blaeck<W> {
                 render_fn,
                 ..
             } => {
+                if self.hide_static && *type_id == TypeId::of::<Static>() {
+                    return Ok(());
+                }
+
+                // Named scrollable components claim their area for wheel
+                // routing; anything painted inside keeps the claim
+                if let Some(id) = scroll_id(props.as_ref()) {
+                    output.mark_scroll_target(
+                        x as u16,
+                        y as u16,
+                        layout.width as u16,
+                        layout.height as u16,
+                        id,
+                    );
+                }
+
                 // Handle leaf components that render to Text
                 if *type_id == TypeId::of::<crate::components::Text>()
                     || *type_id == TypeId::of::<Spinner>()
                     || *type_id == TypeId::of::<Progress>()
                     || *type_id == TypeId::of::<TextInput>()
+                    || *type_id == TypeId::of::<TextArea>()
+                    || *type_id == TypeId::of::<RadioGroup>()
                     || *type_id == TypeId::of::<Checkbox>()
                     || *type_id == TypeId::of::<Select>()
                     || *type_id == TypeId::of::<Confirm>()
@@ -569,6 +1347,7 @@ impl<W: Write> Blaeck<W> {
                     || *type_id == TypeId::of::<MultiSelect>()
                     || *type_id == TypeId::of::<Sparkline>()
                     || *type_id == TypeId::of::<KeyHints>()
+                    || *type_id == TypeId::of::<PendingKeys>()
                     || *type_id == TypeId::of::<Gradient>()
                     || *type_id == TypeId::of::<Breadcrumbs>()
                     || *type_id == TypeId::of::<StatusBar>()
@@ -576,23 +1355,76 @@ impl<W: Write> Blaeck<W> {
                     || *type_id == TypeId::of::<Markdown>()
                     || *type_id == TypeId::of::<LogBox>()
                     || *type_id == TypeId::of::<Timer>()
+                    || *type_id == TypeId::of::<TimePicker>()
                     || *type_id == TypeId::of::<TreeView>()
                     || *type_id == TypeId::of::<BarChart>()
                     || *type_id == TypeId::of::<SyntaxHighlight>()
                     || *type_id == TypeId::of::<Modal>()
+                    || *type_id == TypeId::of::<ContextMenu>()
+                    || *type_id == TypeId::of::<Toc>()
+                    || *type_id == TypeId::of::<Scrollbar>()
+                    || *type_id == TypeId::of::<BigText>()
+                    || *type_id == TypeId::of::<LineChart>()
+                    || *type_id == TypeId::of::<Gauge>()
+                    || *type_id == TypeId::of::<Canvas>()
+                    || *type_id == TypeId::of::<Sprite>()
+                    || *type_id == TypeId::of::<EmptyState>()
+                    || *type_id == TypeId::of::<ErrorState>()
+                    || *type_id == TypeId::of::<LoadingState>()
+                    || *type_id == TypeId::of::<Calendar>()
+                    || *type_id == TypeId::of::<Form>()
+                    || *type_id == TypeId::of::<Wizard>()
+                    || *type_id == TypeId::of::<ValueTree>()
+                    || is_log_viewer(type_id)
+                    || *type_id == TypeId::of::<MultiProgress>()
+                    || *type_id == TypeId::of::<TaskList>()
                     || *type_id == TypeId::of::<crate::components::Spacer>()
                 {
+                    // Links with a URL become clickable regions
+                    if let Some(url) = props
+                        .downcast_ref::<LinkProps>()
+                        .and_then(|link| link.url.as_deref())
+                    {
+                        output.push_link(url);
+                        let rendered = render_fn(props.as_ref());
+                        if let Element::Text { content, style } = &rendered {
+                            output.write(x as u16, y as u16, content, *style);
+                        }
+                        output.pop_link();
+                        return Ok(());
+                    }
+
                     let rendered = render_fn(props.as_ref());
-                    // Handle Fragment (for Gradient/Breadcrumbs/StatusBar/Diff/Markdown/LogBox/TreeView/BarChart/SyntaxHighlight/Modal/Spacer component)
+                    // Handle Fragment (for Gradient/Breadcrumbs/StatusBar/Diff/Markdown/LogBox/TreeView/BarChart/TextArea/RadioGroup/SyntaxHighlight/Modal/ContextMenu/Toc/Scrollbar/BigText/LineChart/Gauge/Canvas/Sprite/EmptyState/ErrorState/LoadingState/Calendar/Form/Wizard/ValueTree/LogViewer/MultiProgress/TaskList/Spacer component)
                     if let Element::Fragment(children) = &rendered {
-                        // Diff, Markdown, LogBox, TreeView, BarChart, SyntaxHighlight, Modal, Spacer render vertically (each line on new row)
+                        // Diff, Markdown, LogBox, TreeView, BarChart, TextArea, RadioGroup, SyntaxHighlight, Modal, ContextMenu, Toc, Scrollbar, BigText, LineChart, Gauge, Canvas, Sprite, EmptyState, ErrorState, LoadingState, Calendar, Form, Wizard, ValueTree, LogViewer, MultiProgress, TaskList, Spacer render vertically (each line on new row)
                         if *type_id == TypeId::of::<Diff>()
                             || *type_id == TypeId::of::<Markdown>()
                             || *type_id == TypeId::of::<LogBox>()
                             || *type_id == TypeId::of::<TreeView>()
                             || *type_id == TypeId::of::<BarChart>()
+                            || *type_id == TypeId::of::<TextArea>()
+                            || *type_id == TypeId::of::<RadioGroup>()
                             || *type_id == TypeId::of::<SyntaxHighlight>()
                             || *type_id == TypeId::of::<Modal>()
+                            || *type_id == TypeId::of::<ContextMenu>()
+                            || *type_id == TypeId::of::<Toc>()
+                            || *type_id == TypeId::of::<Scrollbar>()
+                            || *type_id == TypeId::of::<BigText>()
+                            || *type_id == TypeId::of::<LineChart>()
+                            || *type_id == TypeId::of::<Gauge>()
+                            || *type_id == TypeId::of::<Canvas>()
+                            || *type_id == TypeId::of::<Sprite>()
+                            || *type_id == TypeId::of::<EmptyState>()
+                            || *type_id == TypeId::of::<ErrorState>()
+                            || *type_id == TypeId::of::<LoadingState>()
+                            || *type_id == TypeId::of::<Calendar>()
+                            || *type_id == TypeId::of::<Form>()
+                            || *type_id == TypeId::of::<Wizard>()
+                            || *type_id == TypeId::of::<ValueTree>()
+                            || is_log_viewer(type_id)
+                            || *type_id == TypeId::of::<MultiProgress>()
+                            || *type_id == TypeId::of::<TaskList>()
                             || *type_id == TypeId::of::<crate::components::Spacer>()
                         {
                             let mut line_y = y as u16;
@@ -607,13 +1439,8 @@ impl<W: Write> Blaeck<W> {
                                         let mut char_x = x as u16;
                                         for inline_child in inline_children {
                                             if let Element::Text { content, style } = inline_child {
-                                                let stripped = strip_ansi_escapes(content);
                                                 output.write(char_x, line_y, content, *style);
-                                                let char_width =
-                                                    unicode_width::UnicodeWidthStr::width(
-                                                        stripped.as_str(),
-                                                    );
-                                                char_x += char_width as u16;
+                                                char_x += self.text_width(content) as u16;
                                             }
                                         }
                                         line_y += 1;
@@ -623,24 +1450,131 @@ impl<W: Write> Blaeck<W> {
                             }
                             return Ok(());
                         }
-                        // Other components render horizontally
-                        let mut char_x = x as u16;
+                        // Other components render horizontally; a "\n" child
+                        // starts the next row (multi-line Gradient)
+                        let (mut char_x, mut line_y) = (x as u16, y as u16);
                         for child in children {
                             if let Element::Text { content, style } = child {
-                                output.write(char_x, y as u16, content, *style);
-                                let char_width =
-                                    unicode_width::UnicodeWidthStr::width(content.as_str());
-                                char_x += char_width as u16;
+                                if content == "\n" {
+                                    char_x = x as u16;
+                                    line_y += 1;
+                                    continue;
+                                }
+                                output.write(char_x, line_y, content, *style);
+                                char_x += self.text_width(content) as u16;
                             }
                         }
                         return Ok(());
                     }
                     if let Element::Text { content, style } = &rendered {
-                        output.write(x as u16, y as u16, content, *style);
+                        let at = props
+                            .downcast_ref::<TextProps>()
+                            .and_then(|text| text.wrap.truncate_at());
+                        if let Some(at) = at {
+                            // Cut to the layout width, and to the screen edge
+                            let room = (layout.width as usize)
+                                .min(usize::from(output.width).saturating_sub(x as usize));
+                            let truncation = Truncation::detect().at(at);
+                            let content: Vec<_> = content
+                                .lines()
+                                .map(|line| truncation.truncate(line, room))
+                                .collect();
+                            output.write(x as u16, y as u16, &content.join("\n"), *style);
+                        } else {
+                            output.write(x as u16, y as u16, content, *style);
+                        }
                     }
                     return Ok(());
                 }
 
+                if let Some(collapsible) = props.downcast_ref::<CollapsibleProps>() {
+                    return self.render_collapsible(
+                        output,
+                        layout_tree,
+                        node,
+                        collapsible,
+                        (x, y),
+                        node_elements,
+                    );
+                }
+
+                if let Some(truncate) = props.downcast_ref::<TruncateProps>() {
+                    return self.render_truncate(
+                        output,
+                        layout_tree,
+                        node,
+                        truncate,
+                        (x, y),
+                        node_elements,
+                    );
+                }
+
+                if let Some(image) = props.downcast_ref::<ImageProps>() {
+                    match &image.image {
+                        Some(data) => {
+                            let (cols, rows) = image.fit(layout.width as u16, layout.height as u16);
+                            let protocol = image.protocol.unwrap_or_else(GraphicsProtocol::detect);
+                            output.write_image(x as u16, y as u16, cols, rows, data, protocol);
+                        }
+                        None => output.write(x as u16, y as u16, &image.alt, Style::new()),
+                    }
+                    return Ok(());
+                }
+
+                // Rule: drawn as wide as the layout made it
+                if let Some(rule) = props.downcast_ref::<RuleProps>() {
+                    let sized = rule.clone().width(layout.width as usize);
+                    let parts = match Rule::render(&sized) {
+                        Element::Fragment(parts) => parts,
+                        other => vec![other],
+                    };
+                    let mut col = x as u16;
+                    for part in parts {
+                        if let Element::Text { content, style } = part {
+                            output.write(col, y as u16, &content, style);
+                            col += self.text_width(&content) as u16;
+                        }
+                    }
+                    return Ok(());
+                }
+
+                // Backdrop restyles what's already been drawn under it
+                if let Some(backdrop) = props.downcast_ref::<BackdropProps>() {
+                    output.map_area_styles(
+                        x as u16,
+                        y as u16,
+                        layout.width as u16,
+                        layout.height as u16,
+                        |style| backdrop.apply(style),
+                    );
+                    return Ok(());
+                }
+
+                if let Some(reveal) = props.downcast_ref::<RevealProps>() {
+                    let content_height = layout_tree.children(node).first().map_or(0, |&content| {
+                        layout_tree.get_layout(content).height.ceil() as usize
+                    });
+                    return self.render_scroll_view(
+                        output,
+                        layout_tree,
+                        node,
+                        &reveal.view(content_height),
+                        (x, y),
+                        node_elements,
+                    );
+                }
+
+                if let Some(scroll_props) = props.downcast_ref::<ScrollViewProps>() {
+                    return self.render_scroll_view(
+                        output,
+                        layout_tree,
+                        node,
+                        scroll_props,
+                        (x, y),
+                        node_elements,
+                    );
+                }
+
                 // Handle Box with border - use Taffy's computed size
                 if *type_id == TypeId::of::<crate::components::Box>() {
                     if let Some(box_props) = props.downcast_ref::<BoxProps>() {
@@ -648,21 +1582,219 @@ impl<W: Write> Blaeck<W> {
                         if !box_props.visible {
                             return Ok(());
                         }
+                        if let Some(key) = &box_props.cache_key {
+                            return self.render_cached_box(
+                                output,
+                                layout_tree,
+                                node,
+                                box_props,
+                                key,
+                                (x, y),
+                                node_elements,
+                            );
+                        }
                         self.render_box(output, box_props, x, y, layout.width, layout.height);
                     }
                 }
 
+                // Hyperlink: every cell drawn by the children carries the URL
+                let link = props
+                    .downcast_ref::<HyperlinkProps>()
+                    .and_then(HyperlinkProps::target);
+                if let Some(url) = link {
+                    output.push_link(url);
+                }
+
                 // Render children using Taffy's computed layout
                 let child_nodes = layout_tree.children(node);
                 for child_node in child_nodes {
                     self.render_node(output, layout_tree, child_node, x, y, node_elements)?;
                 }
+
+                if link.is_some() {
+                    output.pop_link();
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Renders a Collapsible's header and, when expanded, its children.
+    fn render_collapsible(
+        &self,
+        output: &mut Output,
+        layout_tree: &LayoutTree,
+        node: NodeId,
+        props: &CollapsibleProps,
+        (x, y): (f32, f32),
+        node_elements: &HashMap<NodeId, &Element>,
+    ) -> Result<()> {
+        let sections = layout_tree.children(node);
+        let Some(header) = sections.first().copied() else {
+            return Ok(());
+        };
+        let layout = layout_tree.get_layout(header);
+        let mut char_x = (x + layout.x) as u16;
+        for (text, style) in props.header_spans() {
+            output.write(char_x, (y + layout.y) as u16, &text, style);
+            char_x += self.text_width(&text) as u16;
+        }
+
+        if let Some(&content) = sections.get(1) {
+            let layout = layout_tree.get_layout(content);
+            for child in layout_tree.children(content) {
+                self.render_node(
+                    output,
+                    layout_tree,
+                    child,
+                    x + layout.x,
+                    y + layout.y,
+                    node_elements,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Renders the rows of a Truncate's children that are shown, and the
+    /// hint below them.
+    fn render_truncate(
+        &self,
+        output: &mut Output,
+        layout_tree: &LayoutTree,
+        node: NodeId,
+        props: &TruncateProps,
+        (x, y): (f32, f32),
+        node_elements: &HashMap<NodeId, &Element>,
+    ) -> Result<()> {
+        let sections = layout_tree.children(node);
+        let Some(view) = sections.first().copied() else {
+            return Ok(());
+        };
+        let Some(content) = layout_tree.children(view).first().copied() else {
+            return Ok(());
+        };
+        let content_height = layout_tree.get_layout(content).height.ceil() as usize;
+        let layout = layout_tree.get_layout(view);
+        self.render_scroll_view(
+            output,
+            layout_tree,
+            view,
+            &props.view(content_height),
+            (x + layout.x, y + layout.y),
+            node_elements,
+        )?;
+
+        if let (Some(&hint_node), Some(hint)) = (sections.get(1), props.hint(content_height)) {
+            let layout = layout_tree.get_layout(hint_node);
+            output.write(
+                (x + layout.x) as u16,
+                (y + layout.y) as u16,
+                &hint,
+                props.hint_style(),
+            );
+        }
+        Ok(())
+    }
+
+    /// Renders the visible rows of a ScrollView and its scrollbar.
+    ///
+    /// The content is painted into a grid holding only the visible rows,
+    /// which is then copied into the viewport. Children outside those rows
+    /// are culled, so painting costs the same however long the content is.
+    fn render_scroll_view(
+        &self,
+        output: &mut Output,
+        layout_tree: &LayoutTree,
+        node: NodeId,
+        props: &ScrollViewProps,
+        (x, y): (f32, f32),
+        node_elements: &HashMap<NodeId, &Element>,
+    ) -> Result<()> {
+        let Some(content) = layout_tree.children(node).first().copied() else {
+            return Ok(());
+        };
+        let view = layout_tree.get_layout(node);
+        let layout = layout_tree.get_layout(content);
+        let content_height = layout.height.ceil() as usize;
+        let rows = (props.height as usize).min(content_height);
+
+        let mut canvas = Output::window(
+            layout.width.ceil() as u16,
+            rows as u16,
+            props.clamped_offset(content_height) as u16,
+        );
+        canvas.set_tab_width(self.tab_width);
+        for child in layout_tree.children(content) {
+            self.render_node(&mut canvas, layout_tree, child, 0.0, 0.0, node_elements)?;
+        }
+        output.blit(
+            &canvas,
+            0,
+            (x + layout.x) as u16,
+            (y + layout.y) as u16,
+            rows as u16,
+        );
+
+        if let Some(cells) = props.scrollbar_cells(content_height) {
+            let bar_x = (x + view.width) as u16 - 1;
+            for (row, (ch, style)) in cells.into_iter().enumerate() {
+                output.write(bar_x, y as u16 + row as u16, &ch.to_string(), style);
+            }
+        }
+        Ok(())
+    }
+
+    /// Renders a box with a cache key, reusing the cells painted on an
+    /// earlier frame when the key, size and box props still match. The
+    /// children aren't visited on a hit, so the key must change with them.
+    #[allow(clippy::too_many_arguments)]
+    fn render_cached_box(
+        &self,
+        output: &mut Output,
+        layout_tree: &LayoutTree,
+        node: NodeId,
+        props: &BoxProps,
+        key: &str,
+        (x, y): (f32, f32),
+        node_elements: &HashMap<NodeId, &Element>,
+    ) -> Result<()> {
+        let layout = layout_tree.get_layout(node);
+        let size = (layout.width as u16, layout.height as u16);
+        let props_hash = {
+            let mut hasher = DefaultHasher::new();
+            format!("{:?}", props).hash(&mut hasher);
+            hasher.finish()
+        };
+
+        if let Some(panel) = self.panel_cache.borrow().get(key) {
+            if panel.size == size && panel.props == props_hash {
+                output.blit(&panel.output, 0, x as u16, y as u16, size.1);
+                return Ok(());
+            }
+        }
+
+        // Paint on a grid of its own (not holding the cache borrow, since
+        // the children may be cached boxes too)
+        let mut canvas = Output::new(size.0, size.1);
+        canvas.set_tab_width(self.tab_width);
+        self.render_box(&mut canvas, props, 0.0, 0.0, layout.width, layout.height);
+        for child in layout_tree.children(node) {
+            self.render_node(&mut canvas, layout_tree, child, 0.0, 0.0, node_elements)?;
+        }
+        output.blit(&canvas, 0, x as u16, y as u16, size.1);
+        self.panel_cache.borrow_mut().insert(
+            key.to_string(),
+            CachedPanel {
+                size,
+                props: props_hash,
+                output: canvas,
+            },
+        );
+        Ok(())
+    }
+
     /// Renders a box with optional background fill and border.
     fn render_box(
         &self,
@@ -696,7 +1828,10 @@ impl<W: Write> Blaeck<W> {
             return;
         }
 
-        let chars = props.border_style.chars();
+        let chars = props
+            .border_style
+            .for_glyphs(GlyphSet::resolve(props.glyphs))
+            .chars();
         let sides = props.effective_border_sides();
 
         // Get per-side colors, applying dim modifier if requested
@@ -773,6 +1908,15 @@ impl<W: Write> Blaeck<W> {
             if sides.right || sides.top {
                 output.write(x + width - 1, y, &top_right_char.to_string(), top_style);
             }
+
+            // Title, inset one cell from the corner and padded with spaces
+            if let Some(title) = props.title.as_deref().filter(|t| !t.is_empty()) {
+                let room = width.saturating_sub(4) as usize;
+                if room > 2 {
+                    let title = Truncation::resolve(props.glyphs).truncate(title, room - 2);
+                    output.write(x + 2, y, &format!(" {title} "), top_style);
+                }
+            }
         }
 
         // Side borders
@@ -839,40 +1983,16 @@ impl<W: Write> Blaeck<W> {
         }
     }
 
-    /// Checks if the element is a Static component and returns its rendered content.
-    /// Returns (static_content, has_static).
-    fn check_for_static(&mut self, element: &Element) -> (String, bool) {
-        match element {
-            Element::Node {
-                type_id, children, ..
-            } => {
-                // Check if this is a Static component
-                if *type_id == TypeId::of::<Static>() {
-                    let static_content = self.render_element(element).unwrap_or_default();
-                    return (static_content, true);
-                }
-
-                // Check children for Static components
-                let mut static_parts = Vec::new();
-
-                for child in children {
-                    if let Element::Node { type_id, .. } = child {
-                        if *type_id == TypeId::of::<Static>() {
-                            if let Ok(content) = self.render_element(child) {
-                                static_parts.push(content);
-                            }
-                        }
-                    }
-                }
-
-                if static_parts.is_empty() {
-                    (String::new(), false)
-                } else {
-                    (static_parts.join("\n"), true)
-                }
-            }
-            _ => (String::new(), false),
+    /// Renders the Static items in `element` that haven't been printed
+    /// yet, or `None` if there are none.
+    fn new_static_output(&mut self, element: &Element) -> Result<Option<String>> {
+        let mut items = Vec::new();
+        collect_new_static(element, &mut self.static_keys, &mut items);
+        if items.is_empty() {
+            return Ok(None);
         }
+        let props = items.into_iter().fold(StaticProps::new(), StaticProps::add);
+        self.render_element(&Static::render(&props)).map(Some)
     }
 
     /// Finalizes rendering, leaving the current output visible.
@@ -880,12 +2000,16 @@ impl<W: Write> Blaeck<W> {
     /// After calling unmount(), subsequent renders will write below the current
     /// content instead of replacing it.
     pub fn unmount(&mut self) -> Result<()> {
-        self.log_update.done()
+        self.last_frame_hash = None;
+        self.log_update.done()?;
+        Ok(())
     }
 
     /// Clears the current output.
     pub fn clear(&mut self) -> Result<()> {
-        self.log_update.clear()
+        self.last_frame_hash = None;
+        self.log_update.clear()?;
+        Ok(())
     }
 
     /// Handle terminal resize event.
@@ -905,18 +2029,95 @@ impl<W: Write> Blaeck<W> {
     pub fn handle_resize(&mut self, width: u16, height: u16) -> Result<()> {
         self.width = width;
         self.height = height;
+        self.last_frame_hash = None;
         // Clear our content area only, preserving scrollback above
-        self.log_update.handle_resize()
+        self.log_update.handle_resize()?;
+        Ok(())
     }
 }
 
-/// Convert a Taffy error to an io::Error.
-fn to_io_error(e: taffy::TaffyError) -> std::io::Error {
-    std::io::Error::other(format!("Layout error: {:?}", e))
+// We need to import Static for the type checking
+use crate::components::r#static::{Static, StaticItem, StaticProps};
+
+/// A layout error while adding the layout node for `node`.
+fn layout_error(node: &str, e: taffy::TaffyError) -> BlaeckError {
+    BlaeckError::layout(format!("adding the layout node for {node}: {e}"))
 }
 
-// We need to import Static for the type checking
-use crate::components::r#static::Static;
+/// A short description of `element` for error messages.
+fn describe_element(element: &Element) -> String {
+    match element {
+        Element::Empty => "an empty element".to_string(),
+        Element::Text { content, .. } => {
+            let start: String = content.chars().take(20).collect();
+            format!("text {:?}", start)
+        }
+        Element::Fragment(children) => format!("a fragment of {} elements", children.len()),
+        Element::Node { children, .. } => {
+            format!("a component with {} children", children.len())
+        }
+    }
+}
+
+/// Whether `type_id` is the [`LogViewer`](crate::components::LogViewer),
+/// which only exists with the `log-viewer` feature.
+fn is_log_viewer(type_id: &TypeId) -> bool {
+    #[cfg(feature = "log-viewer")]
+    {
+        *type_id == TypeId::of::<crate::components::LogViewer>()
+    }
+    #[cfg(not(feature = "log-viewer"))]
+    {
+        let _ = type_id;
+        false
+    }
+}
+
+/// The `scroll_id` of a scrollable component's props, if set.
+fn scroll_id(props: &dyn Any) -> Option<&str> {
+    if let Some(props) = props.downcast_ref::<ScrollViewProps>() {
+        return props.scroll_id.as_deref();
+    }
+    if let Some(props) = props.downcast_ref::<SelectProps>() {
+        return props.scroll_id.as_deref();
+    }
+    props.downcast_ref::<TableProps>()?.scroll_id.as_deref()
+}
+
+/// Collects the items of Static nodes in `element` whose keys aren't in
+/// `seen`, adding their keys.
+fn collect_new_static(element: &Element, seen: &mut HashSet<String>, items: &mut Vec<StaticItem>) {
+    match element {
+        Element::Node {
+            type_id,
+            props,
+            children,
+            ..
+        } => {
+            if *type_id == TypeId::of::<Static>() {
+                if let Some(props) = props.downcast_ref::<StaticProps>() {
+                    items.extend(
+                        props
+                            .items()
+                            .iter()
+                            .filter(|item| seen.insert(item.key.clone()))
+                            .cloned(),
+                    );
+                }
+                return;
+            }
+            for child in children {
+                collect_new_static(child, seen, items);
+            }
+        }
+        Element::Fragment(children) => {
+            for child in children {
+                collect_new_static(child, seen, items);
+            }
+        }
+        _ => {}
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -1281,6 +2482,574 @@ mod tests {
         assert!(blaeck.would_throttle());
     }
 
+    #[test]
+    fn test_blaeck_metrics() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 80, 24).unwrap();
+        let frames = std::rc::Rc::new(std::cell::Cell::new(0));
+        let seen = frames.clone();
+        blaeck.set_metrics_callback(move |m| seen.set(m.frames_rendered));
+        blaeck.set_max_fps(10);
+
+        let text = |s: &str| {
+            Element::node::<Text>(
+                TextProps {
+                    content: s.into(),
+                    ..Default::default()
+                },
+                vec![],
+            )
+        };
+        blaeck.render(text("a")).unwrap();
+        blaeck.render(text("b")).unwrap(); // throttled
+        blaeck.render_force(text("c")).unwrap();
+
+        assert_eq!(blaeck.metrics().frames_rendered, 2);
+        assert_eq!(blaeck.metrics().frames_skipped, 1);
+        assert_eq!(frames.get(), 2);
+
+        blaeck.reset_metrics();
+        assert_eq!(*blaeck.metrics(), Metrics::default());
+    }
+
+    #[test]
+    fn test_blaeck_damage_tracking_patches_changed_cells() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        struct SharedWriter(Rc<RefCell<Vec<u8>>>);
+
+        impl Write for SharedWriter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.borrow_mut().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let data = Rc::new(RefCell::new(Vec::new()));
+        let mut blaeck = Blaeck::with_size(SharedWriter(data.clone()), 20, 5).unwrap();
+        blaeck.set_damage_tracking(true);
+
+        let text = |s: &str| {
+            Element::node::<Text>(
+                TextProps {
+                    content: s.into(),
+                    ..Default::default()
+                },
+                vec![],
+            )
+        };
+        blaeck.render_force(text("12:00:01")).unwrap();
+        data.borrow_mut().clear();
+
+        blaeck.render_force(text("12:00:02")).unwrap();
+        let patch = String::from_utf8(data.borrow().clone()).unwrap();
+        assert!(patch.contains('2'));
+        assert!(!patch.contains("12:00"));
+        assert!(!patch.contains("\x1b[2K"));
+    }
+
+    #[test]
+    fn test_blaeck_skips_identical_frames() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 20, 2).unwrap();
+        blaeck.set_max_fps(1);
+
+        blaeck.render(Element::text("same")).unwrap();
+        blaeck.render_force(Element::text("same")).unwrap();
+        assert_eq!(blaeck.metrics().frames_rendered, 1);
+        assert_eq!(blaeck.metrics().frames_unchanged, 1);
+
+        // An unchanged frame doesn't start a new throttle interval
+        blaeck.last_render = None;
+        blaeck.render(Element::text("same")).unwrap();
+        assert!(!blaeck.would_throttle());
+        blaeck.render(Element::text("changed")).unwrap();
+        assert_eq!(blaeck.metrics().frames_rendered, 2);
+    }
+
+    #[test]
+    fn test_blaeck_scroll_view_clips_to_offset() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 10, 10).unwrap();
+        let lines = || {
+            (1..=5)
+                .map(|i| Element::text(format!("line {i}")))
+                .collect()
+        };
+        let view = |offset| {
+            Element::column(vec![
+                Element::node::<ScrollView>(
+                    ScrollViewProps::new(2)
+                        .width(8)
+                        .offset(offset)
+                        .glyphs(GlyphSet::Ascii),
+                    lines(),
+                ),
+                Element::text("below"),
+            ])
+        };
+
+        blaeck.render(view(1)).unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["line 2 |", "line 3 #", "below"]);
+
+        // Offset past the end shows the last page
+        blaeck.render(view(99)).unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["line 4 |", "line 5 #", "below"]);
+    }
+
+    #[test]
+    fn test_blaeck_collapsible_hides_children() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 20, 10).unwrap();
+        let section = |props: CollapsibleProps| {
+            Element::column(vec![
+                Element::node::<Collapsible>(
+                    props.glyphs(GlyphSet::Ascii),
+                    vec![Element::text("one"), Element::text("two")],
+                ),
+                Element::text("after"),
+            ])
+        };
+
+        blaeck
+            .render(section(CollapsibleProps::new("More")))
+            .unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["> More", "after"]);
+
+        blaeck
+            .render(section(CollapsibleProps::new("More").expanded(true)))
+            .unwrap();
+        assert_eq!(
+            blaeck.frame_lines(),
+            vec!["v More", "  one", "  two", "after"]
+        );
+
+        blaeck
+            .render(section(
+                CollapsibleProps::new("More").expanded(true).reveal(0.5),
+            ))
+            .unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["v More", "  one", "after"]);
+    }
+
+    #[test]
+    fn test_blaeck_truncate_cuts_and_expands() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 40, 10).unwrap();
+        let trace = |props: TruncateProps| {
+            Element::column(vec![
+                Element::node::<Truncate>(
+                    props.glyphs(GlyphSet::Ascii),
+                    (1..=5)
+                        .map(|i| Element::text(format!("frame {i}")))
+                        .collect(),
+                ),
+                Element::text("after"),
+            ])
+        };
+
+        blaeck.render(trace(TruncateProps::new(2))).unwrap();
+        assert_eq!(
+            blaeck.frame_lines(),
+            vec![
+                "frame 1",
+                "frame 2",
+                "... 3 more lines, press e to expand",
+                "after"
+            ]
+        );
+
+        blaeck
+            .render(trace(
+                TruncateProps::new(2).expanded(true).max_height(3).offset(2),
+            ))
+            .unwrap();
+        assert_eq!(
+            blaeck.frame_lines(),
+            vec![
+                "frame 3           |",
+                "frame 4           |",
+                "frame 5           #",
+                "press e to collapse",
+                "after"
+            ]
+        );
+
+        blaeck.render(trace(TruncateProps::new(8))).unwrap();
+        assert_eq!(blaeck.frame_lines().len(), 6);
+    }
+
+    #[test]
+    fn test_blaeck_reveal_grows_rows() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 20, 10).unwrap();
+        let section = |progress: f32| {
+            Element::column(vec![
+                Element::node::<Reveal>(
+                    RevealProps::new(progress),
+                    (1..=4).map(|i| Element::text(format!("row {i}"))).collect(),
+                ),
+                Element::text("after"),
+            ])
+        };
+
+        blaeck.render(section(0.0)).unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["after"]);
+        blaeck.render(section(0.5)).unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["row 1", "row 2", "after"]);
+        blaeck.render(section(1.0)).unwrap();
+        assert_eq!(blaeck.frame_lines().len(), 5);
+    }
+
+    #[test]
+    fn test_blaeck_image_half_blocks_in_layout() {
+        use crate::components::{Image, ImageProps};
+        use crate::graphics::{GraphicsProtocol, ImageData};
+        let mut blaeck = Blaeck::with_size(Vec::new(), 10, 5).unwrap();
+        let image = ImageProps::new(ImageData::solid(4, 4, [255, 0, 0, 255]))
+            .width(2)
+            .height(2)
+            .protocol(GraphicsProtocol::HalfBlocks);
+        blaeck
+            .render(Element::row(vec![
+                Element::node::<Image>(image, vec![]),
+                Element::text("hi"),
+            ]))
+            .unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["▀▀hi", "▀▀"]);
+
+        blaeck
+            .render(Element::node::<Image>(
+                ImageProps::default().alt("[img]"),
+                vec![],
+            ))
+            .unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["[img]"]);
+    }
+
+    #[test]
+    fn test_blaeck_text_truncate_modes() {
+        use crate::components::TextWrap;
+        let mut blaeck = Blaeck::with_size(Vec::new(), 10, 5).unwrap();
+        let text = |wrap| {
+            Element::node::<Text>(
+                TextProps {
+                    content: "a long file name".into(),
+                    wrap,
+                    ..Default::default()
+                },
+                vec![],
+            )
+        };
+        blaeck
+            .render(Element::column(vec![
+                text(TextWrap::Truncate),
+                text(TextWrap::TruncateStart),
+                text(TextWrap::TruncateMiddle),
+            ]))
+            .unwrap();
+        assert_eq!(
+            blaeck.frame_lines(),
+            vec!["a long fi…", "…file name", "a lon…name"]
+        );
+
+        // In a narrower row, it's cut to the width it shrinks to
+        blaeck
+            .render(Element::node::<Box>(
+                BoxProps {
+                    flex_direction: crate::layout::FlexDirection::Row,
+                    width: Some(8.0),
+                    ..Default::default()
+                },
+                vec![text(TextWrap::TruncateMiddle)],
+            ))
+            .unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["a lo…ame"]);
+    }
+
+    #[test]
+    fn test_blaeck_scroll_view_culls_to_visible_rows() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 10, 10).unwrap();
+        // A bordered box straddling the top edge, then rows far below it
+        let mut children = vec![Element::node::<Box>(
+            BoxProps::column()
+                .with_border(crate::components::BorderStyle::Single)
+                .with_glyphs(GlyphSet::Ascii),
+            vec![Element::text("a"), Element::text("b")],
+        )];
+        children.extend((0..1000).map(|i| Element::text(format!("row {i}"))));
+        blaeck
+            .render(Element::node::<ScrollView>(
+                ScrollViewProps::new(3).width(8).offset(1).scrollbar(false),
+                children,
+            ))
+            .unwrap();
+        assert_eq!(
+            blaeck.frame_lines(),
+            vec!["|a     |", "|b     |", "+------+"]
+        );
+    }
+
+    #[test]
+    fn test_blaeck_cached_box_reuses_cells() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 20, 5).unwrap();
+        let panel = |key: &str, text: &str, width: f32| {
+            Element::column(vec![
+                Element::node::<Box>(
+                    BoxProps::column().with_width(width).with_cache_key(key),
+                    vec![Element::text(text)],
+                ),
+                Element::text("live"),
+            ])
+        };
+
+        blaeck.render(panel("help-1", "first", 10.0)).unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["first", "live"]);
+        // Same key, size and content: the cached cells are drawn
+        blaeck
+            .panel_cache
+            .borrow_mut()
+            .get_mut("help-1")
+            .unwrap()
+            .output
+            .write(0, 0, "F", Style::default());
+        blaeck.render(panel("help-1", "first", 10.0)).unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["First", "live"]);
+        // Children aren't looked at while the key stays the same
+        blaeck.render(panel("help-1", "second", 10.0)).unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["First", "live"]);
+        // A new size or key repaints
+        blaeck.render(panel("help-1", "second", 12.0)).unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["second", "live"]);
+        blaeck.render(panel("help-2", "third", 12.0)).unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["third", "live"]);
+
+        blaeck.invalidate_cache("help-2");
+        blaeck.render(panel("help-2", "fourth", 12.0)).unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["fourth", "live"]);
+        assert_eq!(blaeck.panel_cache.borrow().len(), 1);
+    }
+
+    #[test]
+    fn test_blaeck_cached_box_skips_children() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static RENDERS: AtomicUsize = AtomicUsize::new(0);
+        struct Counted;
+        impl crate::element::Component for Counted {
+            type Props = ();
+            fn render(_props: &()) -> Element {
+                RENDERS.fetch_add(1, Ordering::SeqCst);
+                Element::text("counted")
+            }
+        }
+
+        let mut blaeck = Blaeck::with_size(Vec::new(), 20, 5).unwrap();
+        let panel = || {
+            Element::node::<Box>(
+                BoxProps::column()
+                    .with_width(10.0)
+                    .with_cache_key("counted"),
+                vec![Element::node::<Counted>((), vec![]), Element::text("text")],
+            )
+        };
+
+        blaeck.render(panel()).unwrap();
+        let painted = RENDERS.load(Ordering::SeqCst);
+        for _ in 0..3 {
+            blaeck.render(panel()).unwrap();
+        }
+        assert_eq!(RENDERS.load(Ordering::SeqCst), painted);
+        assert_eq!(blaeck.frame_lines()[0], "text");
+    }
+
+    #[test]
+    fn test_blaeck_cached_box_props_change_repaints() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 20, 5).unwrap();
+        let panel = |border: bool| {
+            let props = BoxProps::column()
+                .with_width(8.0)
+                .with_glyphs(GlyphSet::Ascii)
+                .with_cache_key("legend");
+            let props = if border {
+                props.with_border(crate::components::BorderStyle::Single)
+            } else {
+                props
+            };
+            Element::node::<Box>(props, vec![Element::text("key")])
+        };
+
+        blaeck.render(panel(false)).unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["key"]);
+        blaeck.render(panel(true)).unwrap();
+        assert_eq!(
+            blaeck.frame_lines(),
+            vec!["+------+", "|key   |", "+------+"]
+        );
+    }
+
+    #[test]
+    fn test_blaeck_cached_box_kept_while_culled() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 10, 10).unwrap();
+        let view = |offset: usize| {
+            let mut children = vec![Element::node::<Box>(
+                BoxProps::column().with_cache_key("top"),
+                vec![Element::text("cached")],
+            )];
+            children.extend((0..20).map(|i| Element::text(format!("row {i}"))));
+            Element::node::<ScrollView>(
+                ScrollViewProps::new(3).offset(offset).scrollbar(false),
+                children,
+            )
+        };
+
+        blaeck.render(view(0)).unwrap();
+        assert_eq!(blaeck.panel_cache.borrow().len(), 1);
+        // Scrolled out of view, the box isn't painted but keeps its cells
+        blaeck.render(view(5)).unwrap();
+        assert_eq!(blaeck.frame_lines()[0], "row 4");
+        assert_eq!(blaeck.panel_cache.borrow().len(), 1);
+        // Dropped once it leaves the tree
+        blaeck.render(Element::text("gone")).unwrap();
+        assert!(blaeck.panel_cache.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_blaeck_flex_shorthand_splits_row() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 30, 5).unwrap();
+        blaeck
+            .render(Element::node::<Box>(
+                BoxProps::row().with_width(30.0),
+                vec![
+                    Element::text("left").flex(1.0),
+                    Element::column(vec![Element::text("right")]).flex(2.0),
+                    Element::text("|"),
+                ],
+            ))
+            .unwrap();
+        // 29 free columns after "|": a third and two thirds, rounded
+        assert_eq!(
+            blaeck.frame_lines(),
+            vec![format!("left{}right{}|", " ".repeat(6), " ".repeat(14))]
+        );
+    }
+
+    #[test]
+    fn test_blaeck_terminal_errors_have_context() {
+        struct Closed;
+        impl Write for Closed {
+            fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::BrokenPipe.into())
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut blaeck = Blaeck::with_size(Closed, 20, 2).unwrap();
+        let err = blaeck.set_mouse_capture(true).unwrap_err();
+        assert!(matches!(
+            err,
+            BlaeckError::Terminal {
+                context: "enabling mouse capture",
+                ..
+            }
+        ));
+        let err = blaeck.set_bracketed_paste(false).unwrap_err();
+        assert_eq!(err.to_string(), "disabling bracketed paste: broken pipe");
+    }
+
+    #[test]
+    fn test_blaeck_keyboard_enhancement_push_and_pop() {
+        let mut buf = Vec::new();
+        {
+            let mut blaeck = Blaeck::with_size(&mut buf, 20, 2).unwrap();
+            let flags = KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+                | KeyboardEnhancementFlags::REPORT_EVENT_TYPES;
+            blaeck.set_keyboard_enhancement(Some(flags)).unwrap();
+            blaeck.set_keyboard_enhancement(None).unwrap();
+        }
+        assert_eq!(String::from_utf8(buf).unwrap(), "\x1b[>3u\x1b[<1u");
+    }
+
+    #[test]
+    fn test_layout_error_names_node() {
+        let e = taffy::TaffyError::InvalidInputNode(NodeId::from(7u64));
+        let err = layout_error(&describe_element(&Element::text("status line")), e);
+        assert!(err
+            .to_string()
+            .starts_with("layout error: adding the layout node for text \"status line\": "));
+    }
+
+    #[test]
+    fn test_blaeck_frozen_select_mode() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 20, 2).unwrap();
+        blaeck.render(Element::text("error: boom")).unwrap();
+        assert_eq!(blaeck.frame_lines(), vec!["error: boom"]);
+
+        blaeck.set_frozen(true);
+        blaeck.render(Element::text("next")).unwrap();
+        assert_eq!(blaeck.frame_lines()[0], "error: boom");
+        assert_eq!(blaeck.metrics().frames_discarded, 1);
+        assert_eq!(blaeck.metrics().frames_skipped, 0);
+
+        let select = SelectMode::new(blaeck.frame_lines());
+        blaeck.render_selection(&select).unwrap();
+        let copied = blaeck.copy_to_clipboard(&select.selected_text());
+        if crate::clipboard::is_supported() {
+            copied.unwrap();
+        } else {
+            assert!(matches!(copied, Err(BlaeckError::Unsupported(_))));
+        }
+
+        blaeck.set_frozen(false);
+        blaeck.render(Element::text("next")).unwrap();
+        assert_eq!(blaeck.frame_lines()[0], "next");
+    }
+
+    #[test]
+    fn test_blaeck_frame_filters() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 20, 2).unwrap();
+        blaeck.add_filter(crate::filter::Redact::new(["s3cret"]));
+        blaeck.add_filter(|out: &mut Output| out.write(19, 0, "W", Style::default()));
+
+        let output = blaeck.render_element(&Element::text("key s3cret")).unwrap();
+        assert_eq!(output, "key ******         W");
+
+        blaeck.clear_filters();
+        let output = blaeck.render_element(&Element::text("key s3cret")).unwrap();
+        assert_eq!(output, "key s3cret");
+    }
+
+    #[test]
+    fn test_blaeck_static_items_printed_once() {
+        use crate::components::{StaticItem, StaticProps};
+
+        let frame = |items: &[&str], footer: &str| {
+            let props = items
+                .iter()
+                .fold(StaticProps::new(), |p, i| p.add(StaticItem::new(*i, *i)));
+            Element::column(vec![
+                Element::node::<Static>(props, vec![]),
+                Element::text(footer),
+            ])
+        };
+        let mut buf = Vec::new();
+        {
+            let mut blaeck = Blaeck::with_size(&mut buf, 20, 10).unwrap();
+            blaeck.render(frame(&["log-a"], "live")).unwrap();
+            // Same items and footer: nothing new is written
+            blaeck.render(frame(&["log-a"], "live")).unwrap();
+            assert_eq!(blaeck.metrics().frames_unchanged, 1);
+            // A new item is printed above; the footer is redrawn with it
+            // and not repainted again
+            blaeck.render(frame(&["log-a", "log-b"], "live")).unwrap();
+            assert_eq!(blaeck.metrics().frames_unchanged, 2);
+            assert_eq!(blaeck.frame_lines(), vec!["live"]);
+        }
+        let output = String::from_utf8(buf).unwrap();
+        assert_eq!(output.matches("log-a").count(), 1);
+        assert_eq!(output.matches("log-b").count(), 1);
+    }
+
     #[test]
     fn test_blaeck_throttle_skips_render() {
         let mut buf = Vec::new();
@@ -1346,4 +3115,47 @@ mod tests {
         let output = String::from_utf8(buf).unwrap();
         assert!(output.contains("Forced"));
     }
+
+    #[test]
+    fn test_render_hyperlink_region() {
+        use crate::components::{Hyperlink, Link};
+
+        let mut blaeck = Blaeck::with_size(Vec::new(), 20, 5).unwrap();
+        let elem = Element::node::<Hyperlink>(
+            HyperlinkProps::new("https://example.com"),
+            vec![Element::node::<crate::components::Box>(
+                BoxProps {
+                    flex_direction: crate::layout::FlexDirection::Column,
+                    ..Default::default()
+                },
+                vec![Element::text("row one"), Element::text("row two")],
+            )],
+        );
+        let output = blaeck.render_element(&elem).unwrap();
+        let open = "\x1b]8;id=blaeck-0;https://example.com\x07";
+        for line in output.split("\r\n").take(2) {
+            assert!(line.starts_with(open));
+            assert!(line.ends_with("\x1b]8;;\x07"));
+        }
+
+        let link = Element::node::<Link>(LinkProps::with_url("docs", "https://docs.rs"), vec![]);
+        let output = blaeck.render_element(&link).unwrap();
+        assert!(output.contains("\x1b]8;id=blaeck-0;https://docs.rs\x07"));
+        assert!(output.contains("docs"));
+    }
+
+    #[test]
+    fn test_render_tabs_measured_and_drawn_alike() {
+        let mut blaeck = Blaeck::with_size(Vec::new(), 20, 2).unwrap();
+        blaeck.set_tab_width(4);
+        let elem = Element::node::<crate::components::Box>(
+            BoxProps {
+                flex_direction: crate::layout::FlexDirection::Row,
+                ..Default::default()
+            },
+            vec![Element::text("a\tb"), Element::text("|")],
+        );
+        let output = blaeck.render_element(&elem).unwrap();
+        assert_eq!(output.split("\r\n").next(), Some("a   b|"));
+    }
 }
@@ -0,0 +1,764 @@
+//! Recording input and replaying it, for scripted tests and demos.
+//!
+//! An [`InputRecorder`] logs each input event with the time since
+//! recording started. The resulting [`InputRecording`] saves to a small
+//! text format, one event per line:
+//!
+//! ```text
+//! # milliseconds, then the event
+//! 0 key j
+//! 120 key ctrl+s
+//! 300 key shift+up repeat
+//! 380 mouse left-down 12,4
+//! 420 mouse ctrl+left-drag 15,4
+//! 450 resize 100x30
+//! 900 focus out
+//! 1200 paste "two\nlines"
+//! ```
+//!
+//! Keys are written as [`KeyPattern`]s, followed by `repeat` or `release`
+//! for those kinds. Mouse events are the button and what it did
+//! (`left-down`, `right-up`, `middle-drag`), `move` or `scroll-up`,
+//! `scroll-down`, `scroll-left`, `scroll-right`, with any modifiers in
+//! front, then the cell as `column,row`. The terminal window gaining and
+//! losing focus is `focus in` and `focus out`, and pasted text is quoted
+//! with Rust string escapes. The format is easy to write
+//! by hand, so a script can drive an app without recording it first.
+//!
+//! An [`InputReplay`] feeds a recording back to [`App`](crate::App) or
+//! [`ReactiveApp`](crate::reactive::ReactiveApp) in place of the terminal:
+//!
+//! ```ignore
+//! // Record a session; the recording comes back in the result
+//! let result = App::new()?.with_recording().run(render, on_input)?;
+//! result.recording.unwrap().save("session.keys")?;
+//!
+//! // Replay it as fast as possible, then exit
+//! let replay = InputReplay::load("session.keys")?.instant();
+//! App::with_writer(Vec::new(), AppConfig::default())?
+//!     .with_replay(replay)
+//!     .run(render, on_input)?;
+//! ```
+//!
+//! While a replay is running the terminal isn't read and raw mode isn't
+//! enabled, so replays also work where there's no terminal, such as CI.
+//! Once the replay ends the app exits, unless
+//! [`then_live`](InputReplay::then_live) hands input back to the terminal.
+
+use crate::input::{poll_event, InputEvent, Key, KeyPattern, Mouse};
+use crossterm::event::{
+    KeyCode, KeyEventKind, KeyEventState, KeyModifiers, MouseButton, MouseEventKind,
+};
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Modifier names in the order they're written.
+const MODIFIERS: &[(&str, KeyModifiers)] = &[
+    ("ctrl", KeyModifiers::CONTROL),
+    ("alt", KeyModifiers::ALT),
+    ("shift", KeyModifiers::SHIFT),
+    ("super", KeyModifiers::SUPER),
+    ("hyper", KeyModifiers::HYPER),
+    ("meta", KeyModifiers::META),
+];
+
+/// Why a recording couldn't be loaded.
+#[derive(Debug)]
+pub enum RecordingError {
+    /// Reading or writing the file failed.
+    Io(io::Error),
+    /// A line isn't valid.
+    Parse {
+        /// 1-based line number.
+        line: usize,
+        /// What's wrong with it.
+        message: String,
+    },
+}
+
+impl fmt::Display for RecordingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordingError::Io(e) => write!(f, "reading recording: {e}"),
+            RecordingError::Parse { line, message } => write!(f, "line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for RecordingError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RecordingError::Io(e) => Some(e),
+            RecordingError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for RecordingError {
+    fn from(e: io::Error) -> Self {
+        RecordingError::Io(e)
+    }
+}
+
+/// An input event and when it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedEvent {
+    /// Time since the recording started.
+    pub at: Duration,
+    /// The event.
+    pub event: InputEvent,
+}
+
+/// Input events in the order they happened.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputRecording {
+    events: Vec<RecordedEvent>,
+}
+
+impl InputRecording {
+    /// An empty recording.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `event` at `at`, e.g. to build a script in code.
+    ///
+    /// Events are kept in time order.
+    #[must_use]
+    pub fn event(mut self, at: Duration, event: InputEvent) -> Self {
+        self.push(at, event);
+        self
+    }
+
+    /// Add a key press at `at`, e.g. `"ctrl+s"`.
+    ///
+    /// # Panics
+    ///
+    /// If `pattern` isn't a valid [`KeyPattern`].
+    #[must_use]
+    pub fn key(self, at: Duration, pattern: &str) -> Self {
+        let pattern: KeyPattern = pattern
+            .parse()
+            .unwrap_or_else(|e| panic!("invalid key {pattern:?}: {e}"));
+        self.event(at, InputEvent::Key(key_from_pattern(pattern)))
+    }
+
+    /// Add `event` at `at`, after any events at the same time.
+    pub fn push(&mut self, at: Duration, event: InputEvent) {
+        let index = self.events.partition_point(|e| e.at <= at);
+        self.events.insert(index, RecordedEvent { at, event });
+    }
+
+    /// The events, oldest first.
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    /// Number of events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Whether there are no events.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// How long the recording runs, up to its last event.
+    pub fn duration(&self) -> Duration {
+        self.events.last().map_or(Duration::ZERO, |e| e.at)
+    }
+
+    /// Parse the text format described in the [module docs](self).
+    ///
+    /// Blank lines and lines starting with `#` are ignored.
+    pub fn parse(source: &str) -> Result<Self, RecordingError> {
+        let mut recording = Self::new();
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (at, event) = parse_line(line).map_err(|message| RecordingError::Parse {
+                line: index + 1,
+                message,
+            })?;
+            recording.push(at, event);
+        }
+        Ok(recording)
+    }
+
+    /// Read a recording file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RecordingError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Write the recording to a file.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+}
+
+impl fmt::Display for InputRecording {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for RecordedEvent { at, event } in &self.events {
+            write!(f, "{} ", at.as_millis())?;
+            match event {
+                InputEvent::Key(key) => {
+                    write!(f, "key {}", key_name(key))?;
+                    match key.kind {
+                        KeyEventKind::Repeat => f.write_str(" repeat")?,
+                        KeyEventKind::Release => f.write_str(" release")?,
+                        KeyEventKind::Press => {}
+                    }
+                }
+                InputEvent::Mouse(mouse) => write!(
+                    f,
+                    "mouse {}{} {},{}",
+                    modifier_prefix(mouse.modifiers),
+                    mouse_kind_name(mouse.kind),
+                    mouse.column,
+                    mouse.row
+                )?,
+                InputEvent::Resize(width, height) => write!(f, "resize {width}x{height}")?,
+                InputEvent::Focus(true) => f.write_str("focus in")?,
+                InputEvent::Focus(false) => f.write_str("focus out")?,
+                InputEvent::Paste(text) => write!(f, "paste {text:?}")?,
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A key written so it parses back to the same key. Unlike
+/// [`KeyPattern`]'s `Display`, `A` stays `A` rather than `shift+a`.
+fn key_name(key: &Key) -> String {
+    let mut name = modifier_prefix(key.modifiers);
+    if key.is_keypad() {
+        name.push_str("keypad+");
+    }
+    match key.code {
+        KeyCode::Char(' ') => name.push_str("space"),
+        KeyCode::Char(c) => name.push(c),
+        code => name.push_str(&KeyPattern::new(code, KeyModifiers::NONE).to_string()),
+    }
+    name
+}
+
+/// `modifiers` as names each followed by `+`, e.g. `"ctrl+alt+"`.
+fn modifier_prefix(modifiers: KeyModifiers) -> String {
+    MODIFIERS
+        .iter()
+        .filter(|(_, modifier)| modifiers.contains(*modifier))
+        .map(|(name, _)| format!("{name}+"))
+        .collect()
+}
+
+const BUTTONS: &[(&str, MouseButton)] = &[
+    ("left", MouseButton::Left),
+    ("right", MouseButton::Right),
+    ("middle", MouseButton::Middle),
+];
+
+const MOUSE_MOTIONS: &[(&str, MouseEventKind)] = &[
+    ("move", MouseEventKind::Moved),
+    ("scroll-up", MouseEventKind::ScrollUp),
+    ("scroll-down", MouseEventKind::ScrollDown),
+    ("scroll-left", MouseEventKind::ScrollLeft),
+    ("scroll-right", MouseEventKind::ScrollRight),
+];
+
+fn mouse_kind_name(kind: MouseEventKind) -> String {
+    let (button, action) = match kind {
+        MouseEventKind::Down(button) => (button, "down"),
+        MouseEventKind::Up(button) => (button, "up"),
+        MouseEventKind::Drag(button) => (button, "drag"),
+        kind => {
+            let (name, _) = MOUSE_MOTIONS
+                .iter()
+                .find(|(_, k)| *k == kind)
+                .expect("every other mouse event kind is named");
+            return (*name).to_string();
+        }
+    };
+    let (name, _) = BUTTONS
+        .iter()
+        .find(|(_, b)| *b == button)
+        .expect("every mouse button is named");
+    format!("{name}-{action}")
+}
+
+/// Parse `ctrl+left-down 12,4`.
+fn parse_mouse(kind: &str, cell: &str) -> Result<Mouse, String> {
+    let mut modifiers = KeyModifiers::NONE;
+    let (mods, name) = kind.rsplit_once('+').unwrap_or(("", kind));
+    for m in mods.split('+').filter(|m| !m.is_empty()) {
+        match MODIFIERS.iter().find(|(n, _)| *n == m) {
+            Some((_, modifier)) => modifiers |= *modifier,
+            None => return Err(format!("unknown modifier {m:?} in {kind:?}")),
+        }
+    }
+    let kind = match MOUSE_MOTIONS.iter().find(|(n, _)| *n == name) {
+        Some((_, kind)) => *kind,
+        None => {
+            let parsed = name.split_once('-').and_then(|(button, action)| {
+                let (_, button) = BUTTONS.iter().find(|(n, _)| *n == button)?;
+                match action {
+                    "down" => Some(MouseEventKind::Down(*button)),
+                    "up" => Some(MouseEventKind::Up(*button)),
+                    "drag" => Some(MouseEventKind::Drag(*button)),
+                    _ => None,
+                }
+            });
+            parsed.ok_or_else(|| format!("unknown mouse event {name:?}"))?
+        }
+    };
+    let position = cell
+        .split_once(',')
+        .and_then(|(c, r)| Some((c.parse().ok()?, r.parse().ok()?)));
+    let Some((column, row)) = position else {
+        return Err(format!("invalid cell {cell:?}, expected <column>,<row>"));
+    };
+    let mut mouse = Mouse::new(kind, column, row);
+    mouse.modifiers = modifiers;
+    Ok(mouse)
+}
+
+fn key_from_pattern(pattern: KeyPattern) -> Key {
+    let mut key = Key::with_modifiers(pattern.code, pattern.modifiers);
+    if pattern.keypad {
+        key.state = KeyEventState::KEYPAD;
+    }
+    key
+}
+
+fn parse_line(line: &str) -> Result<(Duration, InputEvent), String> {
+    // Pasted text may hold spaces, so it's the rest of the line
+    if let Some((at, text)) = line.split_once(" paste ") {
+        return Ok((parse_time(at)?, InputEvent::Paste(unquote(text.trim())?)));
+    }
+    let mut words = line.split_whitespace();
+    let (Some(at), Some(kind), Some(value)) = (words.next(), words.next(), words.next()) else {
+        return Err(format!(
+            "expected \"<ms> key <key>\", \"<ms> mouse <event> <column>,<row>\", \
+             \"<ms> resize <w>x<h>\", \"<ms> focus in|out\" or \"<ms> paste \\\"<text>\\\"\", \
+             got {line:?}"
+        ));
+    };
+    let at = parse_time(at)?;
+    let event = match kind {
+        "key" => {
+            let pattern: KeyPattern = value.parse().map_err(|e| format!("{e}"))?;
+            let mut key = key_from_pattern(pattern);
+            key.kind = match words.next() {
+                None => KeyEventKind::Press,
+                Some("repeat") => KeyEventKind::Repeat,
+                Some("release") => KeyEventKind::Release,
+                Some(other) => return Err(format!("unknown key kind {other:?}")),
+            };
+            InputEvent::Key(key)
+        }
+        "mouse" => {
+            let Some(cell) = words.next() else {
+                return Err(format!("missing cell in {line:?}"));
+            };
+            InputEvent::Mouse(parse_mouse(value, cell)?)
+        }
+        "resize" => {
+            let size = value
+                .split_once('x')
+                .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)));
+            let Some((width, height)) = size else {
+                return Err(format!("invalid size {value:?}, expected <w>x<h>"));
+            };
+            InputEvent::Resize(width, height)
+        }
+        "focus" => match value {
+            "in" => InputEvent::Focus(true),
+            "out" => InputEvent::Focus(false),
+            other => return Err(format!("invalid focus {other:?}, expected in or out")),
+        },
+        other => return Err(format!("unknown event {other:?}")),
+    };
+    if let Some(extra) = words.next() {
+        return Err(format!("unexpected {extra:?}"));
+    }
+    Ok((at, event))
+}
+
+fn parse_time(at: &str) -> Result<Duration, String> {
+    at.parse::<u64>()
+        .map(Duration::from_millis)
+        .map_err(|_| format!("invalid time {at:?}"))
+}
+
+/// The text of a string quoted with Rust escapes, as `{:?}` writes it.
+fn unquote(quoted: &str) -> Result<String, String> {
+    let inner = quoted
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(|| format!("expected quoted text, got {quoted:?}"))?;
+    let mut text = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        let escaped = match chars.next() {
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('0') => '\0',
+            Some(c @ ('\\' | '"' | '\'')) => c,
+            Some('u') => {
+                let code: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                code.strip_prefix('{')
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| format!("invalid escape \\u{code}}}"))?
+            }
+            other => return Err(format!("invalid escape {other:?} in {quoted:?}")),
+        };
+        text.push(escaped);
+    }
+    Ok(text)
+}
+
+/// Logs input events with the time since recording started.
+#[derive(Debug, Clone)]
+pub struct InputRecorder {
+    start: Instant,
+    recording: InputRecording,
+}
+
+impl Default for InputRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InputRecorder {
+    /// Start recording now.
+    pub fn new() -> Self {
+        Self::starting_at(Instant::now())
+    }
+
+    /// Start recording at `start`.
+    pub fn starting_at(start: Instant) -> Self {
+        Self {
+            start,
+            recording: InputRecording::new(),
+        }
+    }
+
+    /// Log `event` as happening now.
+    pub fn record(&mut self, event: &InputEvent) {
+        self.record_at(event, Instant::now());
+    }
+
+    /// Log `event` as happening at `now`.
+    pub fn record_at(&mut self, event: &InputEvent, now: Instant) {
+        let at = now.saturating_duration_since(self.start);
+        self.recording.push(at, event.clone());
+    }
+
+    /// The events logged so far.
+    pub fn recording(&self) -> &InputRecording {
+        &self.recording
+    }
+
+    /// Stop recording and take the events.
+    pub fn finish(self) -> InputRecording {
+        self.recording
+    }
+}
+
+/// Plays an [`InputRecording`] back as if it were typed.
+#[derive(Debug, Clone)]
+pub struct InputReplay {
+    recording: InputRecording,
+    next: usize,
+    start: Option<Instant>,
+    speed: f64,
+    live: bool,
+}
+
+impl InputReplay {
+    /// Replay `recording` at the speed it was recorded.
+    pub fn new(recording: InputRecording) -> Self {
+        Self {
+            recording,
+            next: 0,
+            start: None,
+            speed: 1.0,
+            live: false,
+        }
+    }
+
+    /// Read a recording file to replay.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RecordingError> {
+        Ok(Self::new(InputRecording::load(path)?))
+    }
+
+    /// Play faster (`2.0`) or slower (`0.5`) than recorded.
+    #[must_use]
+    pub fn speed(mut self, speed: f64) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Ignore the timings and deliver each event as soon as it's polled.
+    ///
+    /// This is the mode for tests: every run sees the same events in the
+    /// same order without waiting.
+    #[must_use]
+    pub fn instant(self) -> Self {
+        self.speed(f64::INFINITY)
+    }
+
+    /// Read the terminal once the replay ends, instead of exiting.
+    #[must_use]
+    pub fn then_live(mut self) -> Self {
+        self.live = true;
+        self
+    }
+
+    /// Whether input goes back to the terminal after the replay.
+    pub fn is_live_after(&self) -> bool {
+        self.live
+    }
+
+    /// Whether every event has been delivered.
+    pub fn is_done(&self) -> bool {
+        self.next >= self.recording.len()
+    }
+
+    /// Number of events not delivered yet.
+    pub fn remaining(&self) -> usize {
+        self.recording.len() - self.next.min(self.recording.len())
+    }
+
+    /// When the next event is due, relative to the start of the replay.
+    fn due(&self) -> Option<Duration> {
+        let event = self.recording.events.get(self.next)?;
+        if self.speed.is_infinite() {
+            return Some(Duration::ZERO);
+        }
+        Some(event.at.div_f64(self.speed.max(f64::MIN_POSITIVE)))
+    }
+
+    /// The next event if it's due `elapsed` after the replay started.
+    pub fn next_due(&mut self, elapsed: Duration) -> Option<InputEvent> {
+        if self.due()? > elapsed {
+            return None;
+        }
+        let event = self.recording.events[self.next].event.clone();
+        self.next += 1;
+        Some(event)
+    }
+
+    /// Wait up to `timeout` for the next event, like
+    /// [`poll_event`](crate::input::poll_event).
+    ///
+    /// The replay clock starts at the first poll.
+    pub fn poll(&mut self, timeout: Duration) -> Option<InputEvent> {
+        let start = *self.start.get_or_insert_with(Instant::now);
+        let due = self.due()?;
+        let elapsed = start.elapsed();
+        if due > elapsed {
+            let wait = due - elapsed;
+            std::thread::sleep(wait.min(timeout));
+            if wait > timeout {
+                return None;
+            }
+        }
+        self.next_due(due)
+    }
+}
+
+/// Where an app's input comes from: the terminal or a replay, and
+/// optionally recorded.
+#[derive(Debug, Default)]
+pub(crate) struct InputSource {
+    replay: Option<InputReplay>,
+    recorder: Option<InputRecorder>,
+}
+
+impl InputSource {
+    pub(crate) fn set_replay(&mut self, replay: InputReplay) {
+        self.replay = Some(replay);
+    }
+
+    pub(crate) fn start_recording(&mut self) {
+        self.recorder = Some(InputRecorder::new());
+    }
+
+    /// Whether the terminal is read at some point, so raw mode is needed.
+    pub(crate) fn reads_terminal(&self) -> bool {
+        self.replay.as_ref().is_none_or(InputReplay::is_live_after)
+    }
+
+    /// Whether a replay has finished and the app should exit.
+    pub(crate) fn is_finished(&self) -> bool {
+        self.replay
+            .as_ref()
+            .is_some_and(|replay| replay.is_done() && !replay.is_live_after())
+    }
+
+    /// Poll the replay, or the terminal once there's none left.
+    pub(crate) fn poll(&mut self, timeout: Duration) -> io::Result<Option<InputEvent>> {
+        let event = match &mut self.replay {
+            Some(replay) if !replay.is_done() => replay.poll(timeout),
+            Some(replay) if !replay.is_live_after() => {
+                std::thread::sleep(timeout);
+                None
+            }
+            _ => poll_event(timeout)?,
+        };
+        if let (Some(recorder), Some(event)) = (&mut self.recorder, &event) {
+            recorder.record(event);
+        }
+        Ok(event)
+    }
+
+    /// Take the recording, if one was started.
+    pub(crate) fn take_recording(&mut self) -> Option<InputRecording> {
+        self.recorder.take().map(InputRecorder::finish)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_recording_round_trip() {
+        let mut release = Key::new(KeyCode::Char('A'));
+        release.modifiers = KeyModifiers::SHIFT;
+        release.kind = KeyEventKind::Release;
+        let recording = InputRecording::new()
+            .key(ms(0), "j")
+            .key(ms(120), "ctrl++")
+            .key(ms(200), "keypad+enter")
+            .event(ms(250), InputEvent::Key(release))
+            .event(ms(300), InputEvent::Resize(100, 30))
+            .key(ms(300), "space");
+        let text = recording.to_string();
+        assert_eq!(
+            text,
+            "0 key j\n120 key ctrl++\n200 key keypad+enter\n250 key shift+A release\n\
+             300 resize 100x30\n300 key space\n"
+        );
+        assert_eq!(InputRecording::parse(&text).unwrap(), recording);
+        assert_eq!(recording.duration(), ms(300));
+    }
+
+    #[test]
+    fn test_recording_mouse_round_trip() {
+        let mut drag = Mouse::new(MouseEventKind::Drag(MouseButton::Left), 15, 4);
+        drag.modifiers = KeyModifiers::CONTROL;
+        let recording = InputRecording::new()
+            .event(
+                ms(80),
+                InputEvent::Mouse(Mouse::new(MouseEventKind::ScrollUp, 2, 1)),
+            )
+            .event(ms(120), InputEvent::Mouse(drag));
+        let text = recording.to_string();
+        assert_eq!(
+            text,
+            "80 mouse scroll-up 2,1\n120 mouse ctrl+left-drag 15,4\n"
+        );
+        assert_eq!(InputRecording::parse(&text).unwrap(), recording);
+        assert_eq!(recording.duration(), ms(120));
+    }
+
+    #[test]
+    fn test_recording_focus_round_trip() {
+        let recording = InputRecording::new()
+            .event(ms(0), InputEvent::Focus(false))
+            .event(ms(140), InputEvent::Focus(true));
+        let text = recording.to_string();
+        assert_eq!(text, "0 focus out\n140 focus in\n");
+        assert_eq!(InputRecording::parse(&text).unwrap(), recording);
+        assert_eq!(recording.duration(), ms(140));
+    }
+
+    #[test]
+    fn test_recording_paste_round_trip() {
+        let recording = InputRecording::new()
+            .key(ms(0), "i")
+            .event(ms(60), InputEvent::Paste("say \"hi\"\n\tto\u{1b}".into()));
+        let text = recording.to_string();
+        assert_eq!(
+            text,
+            "0 key i\n60 paste \"say \\\"hi\\\"\\n\\tto\\u{1b}\"\n"
+        );
+        assert_eq!(InputRecording::parse(&text).unwrap(), recording);
+        assert_eq!(recording.duration(), ms(60));
+    }
+
+    #[test]
+    fn test_recording_parse_errors() {
+        let source = "# script\n\n0 key q\n10 key hold+q\n";
+        let err = InputRecording::parse(source).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4: unknown modifier \"hold\" in \"hold+q\""
+        );
+        assert!(InputRecording::parse("x key q").is_err());
+        assert!(InputRecording::parse("0 resize 80").is_err());
+        assert!(InputRecording::parse("0 key q twice").is_err());
+        assert!(InputRecording::parse("0 mouse left-tap 1,1").is_err());
+        assert!(InputRecording::parse("0 mouse left-down 1").is_err());
+    }
+
+    #[test]
+    fn test_recorder_timestamps() {
+        let t0 = Instant::now();
+        let mut recorder = InputRecorder::starting_at(t0);
+        let q = InputEvent::Key(Key::new(KeyCode::Char('q')));
+        recorder.record_at(&InputEvent::Resize(80, 24), t0 + ms(40));
+        recorder.record_at(&q, t0 + ms(90));
+        let recording = recorder.finish();
+        let times: Vec<_> = recording.events().iter().map(|e| e.at).collect();
+        assert_eq!(times, [ms(40), ms(90)]);
+    }
+
+    #[test]
+    fn test_replay_timing() {
+        let recording = InputRecording::new().key(ms(100), "a").key(ms(300), "b");
+        let mut replay = InputReplay::new(recording.clone()).speed(2.0);
+        assert_eq!(replay.next_due(ms(40)), None);
+        assert!(matches!(replay.next_due(ms(50)), Some(InputEvent::Key(k)) if k.is_char('a')));
+        assert_eq!(replay.next_due(ms(100)), None);
+        assert!(replay.next_due(ms(150)).is_some());
+        assert!(replay.is_done());
+
+        let mut replay = InputReplay::new(recording).instant();
+        assert_eq!(replay.remaining(), 2);
+        assert!(replay.poll(Duration::ZERO).is_some());
+        assert!(replay.poll(Duration::ZERO).is_some());
+        assert_eq!(replay.poll(Duration::ZERO), None);
+    }
+
+    #[test]
+    fn test_input_source_replay_and_record() {
+        let recording = InputRecording::new().key(ms(0), "x");
+        let mut source = InputSource::default();
+        source.set_replay(InputReplay::new(recording).instant());
+        source.start_recording();
+        assert!(!source.reads_terminal());
+        assert!(source.poll(Duration::ZERO).unwrap().is_some());
+        assert!(source.is_finished());
+        assert_eq!(source.poll(Duration::ZERO).unwrap(), None);
+        assert_eq!(source.take_recording().unwrap().len(), 1);
+    }
+}
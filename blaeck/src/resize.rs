@@ -0,0 +1,363 @@
+//! Keyboard-driven resizing for panes and columns.
+//!
+//! [`ResizeState`] implements a standard "resize mode" so layouts can be
+//! resized without a mouse: a trigger key enters the mode, arrows adjust
+//! the active target, Tab moves to the next one, Enter keeps the change and
+//! Esc restores the sizes from before the mode was entered.
+//!
+//! Two models are supported:
+//!
+//! - [`ResizeState::panes`] — adjacent panes share a fixed total. The active
+//!   target is a divider; moving it grows one side and shrinks the other.
+//! - [`ResizeState::columns`] — each size changes on its own, like table
+//!   columns. The active target is a column.
+//!
+//! ```ignore
+//! let mut resize = ResizeState::panes([30, 50]).min_size(10);
+//!
+//! // In the input handler:
+//! if resize.handle_key(&key) == ResizeAction::Ignored {
+//!     // not a resize key; handle normally
+//! }
+//!
+//! // In render:
+//! let [left, right] = [resize.size(0), resize.size(1)];
+//! let table = TableProps::new(rows).resize(&columns);
+//! if let Some(hints) = resize.key_hints() {
+//!     // show KeyHints(hints) in the status bar
+//! }
+//! ```
+
+use crate::components::KeyHint;
+use crate::input::Key;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// How sizes relate to each other while resizing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResizeModel {
+    /// Adjacent sizes trade space; the total stays constant.
+    Panes,
+    /// Each size changes independently.
+    Columns,
+}
+
+/// Result of handling a key with [`ResizeState::handle_key`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResizeAction {
+    /// The key isn't a resize key (or resize mode is off).
+    Ignored,
+    /// Resize mode was entered.
+    Entered,
+    /// A size changed.
+    Adjusted,
+    /// The active target changed.
+    Moved,
+    /// Resize mode ended, keeping the new sizes.
+    Confirmed,
+    /// Resize mode ended, restoring the previous sizes.
+    Cancelled,
+}
+
+/// State for keyboard resizing of panes or columns.
+#[derive(Debug, Clone)]
+pub struct ResizeState {
+    /// Current sizes, in cells.
+    sizes: Vec<u16>,
+    /// Sizing model.
+    model: ResizeModel,
+    /// Smallest size any item may shrink to.
+    min_size: u16,
+    /// Cells per arrow press.
+    step: u16,
+    /// Cells per Shift+arrow press.
+    large_step: u16,
+    /// Key that enters resize mode.
+    trigger: char,
+    /// Active divider (panes) or column while in resize mode.
+    active: Option<usize>,
+    /// Sizes from before resize mode, restored on cancel.
+    saved: Vec<u16>,
+}
+
+impl ResizeState {
+    /// Create state for panes that share their total size.
+    ///
+    /// The active target is a divider: divider `i` sits between pane `i`
+    /// and pane `i + 1`.
+    pub fn panes(sizes: impl IntoIterator<Item = u16>) -> Self {
+        Self::with_model(sizes, ResizeModel::Panes)
+    }
+
+    /// Create state for independently sized columns.
+    pub fn columns(sizes: impl IntoIterator<Item = u16>) -> Self {
+        Self::with_model(sizes, ResizeModel::Columns)
+    }
+
+    fn with_model(sizes: impl IntoIterator<Item = u16>, model: ResizeModel) -> Self {
+        Self {
+            sizes: sizes.into_iter().collect(),
+            model,
+            min_size: 1,
+            step: 1,
+            large_step: 5,
+            trigger: 'r',
+            active: None,
+            saved: Vec::new(),
+        }
+    }
+
+    /// Set the smallest size an item may shrink to (default 1).
+    #[must_use]
+    pub fn min_size(mut self, min: u16) -> Self {
+        self.min_size = min;
+        self
+    }
+
+    /// Set the cells moved per arrow press and per Shift+arrow press
+    /// (default 1 and 5).
+    #[must_use]
+    pub fn step(mut self, step: u16, large_step: u16) -> Self {
+        self.step = step.max(1);
+        self.large_step = large_step.max(1);
+        self
+    }
+
+    /// Set the key that enters resize mode (default `r`).
+    #[must_use]
+    pub fn trigger(mut self, key: char) -> Self {
+        self.trigger = key;
+        self
+    }
+
+    /// The sizing model.
+    pub fn model(&self) -> ResizeModel {
+        self.model
+    }
+
+    /// All current sizes.
+    pub fn sizes(&self) -> &[u16] {
+        &self.sizes
+    }
+
+    /// Size of one item, or 0 if out of range.
+    pub fn size(&self, index: usize) -> u16 {
+        self.sizes.get(index).copied().unwrap_or(0)
+    }
+
+    /// Replace the sizes, e.g. after the terminal was resized.
+    ///
+    /// Leaves resize mode if the number of items changed.
+    pub fn set_sizes(&mut self, sizes: impl IntoIterator<Item = u16>) {
+        let sizes: Vec<u16> = sizes.into_iter().collect();
+        if sizes.len() != self.sizes.len() {
+            self.active = None;
+        }
+        self.sizes = sizes;
+    }
+
+    /// Whether resize mode is on.
+    pub fn is_active(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// The active divider (panes) or column, while in resize mode.
+    pub fn active(&self) -> Option<usize> {
+        self.active
+    }
+
+    /// Number of targets that can be resized.
+    fn target_count(&self) -> usize {
+        match self.model {
+            ResizeModel::Panes => self.sizes.len().saturating_sub(1),
+            ResizeModel::Columns => self.sizes.len(),
+        }
+    }
+
+    /// Enter resize mode with `target` active.
+    ///
+    /// Returns false if there is nothing to resize.
+    pub fn enter(&mut self, target: usize) -> bool {
+        let count = self.target_count();
+        if count == 0 {
+            return false;
+        }
+        if self.active.is_none() {
+            self.saved = self.sizes.clone();
+        }
+        self.active = Some(target.min(count - 1));
+        true
+    }
+
+    /// Leave resize mode, keeping the new sizes.
+    pub fn confirm(&mut self) {
+        self.active = None;
+        self.saved.clear();
+    }
+
+    /// Leave resize mode, restoring the sizes from before it was entered.
+    pub fn cancel(&mut self) {
+        if self.active.take().is_some() {
+            self.sizes = std::mem::take(&mut self.saved);
+        }
+    }
+
+    /// Move to the next target, wrapping around.
+    pub fn next_target(&mut self) {
+        if let Some(active) = self.active {
+            self.active = Some((active + 1) % self.target_count());
+        }
+    }
+
+    /// Move to the previous target, wrapping around.
+    pub fn prev_target(&mut self) {
+        if let Some(active) = self.active {
+            let count = self.target_count();
+            self.active = Some((active + count - 1) % count);
+        }
+    }
+
+    /// Grow (positive) or shrink (negative) the active target.
+    ///
+    /// For panes this moves the active divider: the pane before it changes
+    /// by `delta` and the pane after it by `-delta`. Sizes never go below
+    /// the minimum. Returns true if anything changed.
+    pub fn adjust(&mut self, delta: i32) -> bool {
+        let Some(active) = self.active else {
+            return false;
+        };
+        let min = self.min_size as i32;
+        match self.model {
+            ResizeModel::Panes => {
+                let (a, b) = (self.sizes[active] as i32, self.sizes[active + 1] as i32);
+                let delta = delta.clamp((min - a).min(0), (b - min).max(0));
+                if delta == 0 {
+                    return false;
+                }
+                self.sizes[active] = (a + delta) as u16;
+                self.sizes[active + 1] = (b - delta) as u16;
+            }
+            ResizeModel::Columns => {
+                let a = self.sizes[active] as i32;
+                let next = (a + delta).clamp(min.min(a), u16::MAX as i32);
+                if next == a {
+                    return false;
+                }
+                self.sizes[active] = next as u16;
+            }
+        }
+        true
+    }
+
+    /// Handle a key press.
+    ///
+    /// Outside resize mode only the trigger key is handled. In resize mode:
+    /// Left/Up shrink, Right/Down grow (Shift for a larger step), Tab and
+    /// Shift+Tab pick the target, Enter confirms and Esc cancels. Other keys
+    /// are ignored and stay available to the app.
+    pub fn handle_key(&mut self, key: &Key) -> ResizeAction {
+        if self.active.is_none() {
+            if key.is_char(self.trigger) && self.enter(0) {
+                return ResizeAction::Entered;
+            }
+            return ResizeAction::Ignored;
+        }
+
+        let step = if key.modifiers.contains(KeyModifiers::SHIFT) {
+            self.large_step
+        } else {
+            self.step
+        } as i32;
+        match key.code {
+            KeyCode::Left | KeyCode::Up => {
+                self.adjust(-step);
+                ResizeAction::Adjusted
+            }
+            KeyCode::Right | KeyCode::Down => {
+                self.adjust(step);
+                ResizeAction::Adjusted
+            }
+            KeyCode::Tab => {
+                self.next_target();
+                ResizeAction::Moved
+            }
+            KeyCode::BackTab => {
+                self.prev_target();
+                ResizeAction::Moved
+            }
+            KeyCode::Enter => {
+                self.confirm();
+                ResizeAction::Confirmed
+            }
+            KeyCode::Esc => {
+                self.cancel();
+                ResizeAction::Cancelled
+            }
+            _ => ResizeAction::Ignored,
+        }
+    }
+
+    /// Key hints describing resize mode, or `None` when it is off.
+    ///
+    /// Pass them to [`KeyHints`](crate::components::KeyHints) as visual
+    /// feedback while the mode is on.
+    pub fn key_hints(&self) -> Option<Vec<KeyHint>> {
+        self.active?;
+        let mut hints = vec![KeyHint::new("←→", "resize")];
+        if self.target_count() > 1 {
+            hints.push(KeyHint::new("Tab", "next"));
+        }
+        hints.push(KeyHint::new("Enter", "keep"));
+        hints.push(KeyHint::new("Esc", "cancel"));
+        Some(hints)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resize_panes_keep_total() {
+        let mut state = ResizeState::panes([30, 50]).min_size(10);
+        assert_eq!(
+            state.handle_key(&Key::new(KeyCode::Right)),
+            ResizeAction::Ignored
+        );
+        assert_eq!(
+            state.handle_key(&Key::new(KeyCode::Char('r'))),
+            ResizeAction::Entered
+        );
+
+        state.handle_key(&Key::new(KeyCode::Right));
+        assert_eq!(state.sizes(), &[31, 49]);
+
+        // Clamped so the right pane keeps its minimum
+        state.adjust(100);
+        assert_eq!(state.sizes(), &[70, 10]);
+
+        assert_eq!(
+            state.handle_key(&Key::new(KeyCode::Enter)),
+            ResizeAction::Confirmed
+        );
+        assert!(!state.is_active());
+        assert_eq!(state.sizes(), &[70, 10]);
+    }
+
+    #[test]
+    fn test_resize_cancel_restores() {
+        let mut state = ResizeState::columns([8, 12, 6]).step(1, 4);
+        state.enter(0);
+        state.handle_key(&Key::new(KeyCode::Tab));
+        assert_eq!(state.active(), Some(1));
+        state.handle_key(&Key::with_modifiers(KeyCode::Right, KeyModifiers::SHIFT));
+        assert_eq!(state.sizes(), &[8, 16, 6]);
+        assert!(state.key_hints().is_some());
+
+        assert_eq!(
+            state.handle_key(&Key::new(KeyCode::Esc)),
+            ResizeAction::Cancelled
+        );
+        assert_eq!(state.sizes(), &[8, 12, 6]);
+        assert!(state.key_hints().is_none());
+    }
+}
@@ -0,0 +1,132 @@
+//! The `main` function every reactive binary needs.
+//!
+//! [`app!`](crate::app!) writes `fn main` for a [`ReactiveApp`]: it installs
+//! a panic hook that restores the terminal, applies the palette, registers
+//! the keymap and runs the root component, printing any error and exiting
+//! with a failure code. Resize handling and Ctrl+C come from the app's
+//! defaults.
+//!
+//! ```ignore
+//! use blaeck::prelude::*;
+//! use blaeck::reactive::*;
+//!
+//! fn counter(cx: Scope) -> Element { /* ... */ }
+//!
+//! blaeck::app! {
+//!     root: counter,
+//!     palette: Palette::deuteranopia(),
+//!     config: ReactiveAppConfig {
+//!         poll_interval: Duration::from_millis(16),
+//!         ..Default::default()
+//!     },
+//!     keys: {
+//!         "q": "quit" => |app| app.exit(),
+//!     },
+//! }
+//! ```
+//!
+//! Only `root` is required; the others must come in this order.
+//!
+//! [`ReactiveApp`]: crate::reactive::ReactiveApp
+
+use crossterm::event::{
+    DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, PopKeyboardEnhancementFlags,
+};
+use crossterm::{cursor, execute, terminal};
+use std::io;
+
+/// Restore the terminal before a panic message is printed.
+///
+/// Without this, a panic in raw mode prints a garbled message with the
+/// cursor hidden, and leaves mouse, focus, paste and keyboard reporting
+/// on. The previous hook still runs, after the terminal is restored.
+/// [`app!`](crate::app!) installs this for you.
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = terminal::disable_raw_mode();
+        let _ = execute!(
+            io::stderr(),
+            PopKeyboardEnhancementFlags,
+            DisableBracketedPaste,
+            DisableFocusChange,
+            DisableMouseCapture,
+            cursor::Show
+        );
+        eprintln!();
+        previous(info);
+    }));
+}
+
+/// Write `fn main` for a reactive app. See [`crate::scaffold`].
+#[macro_export]
+macro_rules! app {
+    (
+        root: $root:expr
+        $(, palette: $palette:expr)?
+        $(, config: $config:expr)?
+        $(, keys: { $($pattern:literal : $description:literal => $action:expr),* $(,)? })?
+        $(,)?
+    ) => {
+        fn main() -> ::std::process::ExitCode {
+            $crate::scaffold::install_panic_hook();
+            $($crate::palette::Palette::install($palette);)?
+            let config: $crate::reactive::ReactiveAppConfig =
+                None$(.or(Some($config)))?.unwrap_or_default();
+            let run = || -> $crate::error::Result<()> {
+                #[allow(unused_mut)]
+                let mut app = $crate::reactive::ReactiveApp::new(config)?;
+                $($(
+                    if let Err(e) = app.register_hotkey($pattern, $description, $action) {
+                        panic!("invalid hotkey {:?}: {}", $pattern, e);
+                    }
+                )*)?
+                app.start($root)?;
+                Ok(())
+            };
+            match run() {
+                Ok(()) => ::std::process::ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("error: {e}");
+                    ::std::process::ExitCode::FAILURE
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::element::Element;
+    use crate::reactive::Scope;
+
+    fn root(_cx: Scope) -> Element {
+        Element::text("hi")
+    }
+
+    // Expansions only need to compile; running them would take the terminal
+    #[allow(dead_code)]
+    mod minimal {
+        crate::app! { root: super::root }
+    }
+
+    #[allow(dead_code)]
+    mod full {
+        use crate::palette::Palette;
+        use crate::reactive::ReactiveAppConfig;
+        use std::time::Duration;
+
+        crate::app! {
+            root: super::root,
+            palette: Palette::tritanopia(),
+            config: ReactiveAppConfig {
+                poll_interval: Duration::from_millis(16),
+                ..Default::default()
+            },
+            keys: {
+                "q": "quit" => |app| app.exit(),
+                "ctrl+r": "redraw" => |app| app.runtime().mark_dirty(),
+            },
+        }
+    }
+}
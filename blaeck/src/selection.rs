@@ -0,0 +1,321 @@
+//! Keyboard text selection over a frozen frame.
+//!
+//! Inline UIs redraw in place, which breaks the terminal's own mouse
+//! selection: the text moves or disappears mid-drag. [`SelectMode`] is the
+//! app-level alternative — freeze the UI, move a cursor over the last frame
+//! with the keyboard, select characters or whole lines, and copy them to the
+//! clipboard with OSC 52.
+//!
+//! With [`ReactiveApp`](crate::reactive::ReactiveApp), enable it with
+//! [`with_select_key`](crate::reactive::ReactiveApp::with_select_key). With
+//! a plain [`Blaeck`](crate::Blaeck):
+//!
+//! ```ignore
+//! blaeck.set_frozen(true);
+//! let mut select = SelectMode::new(blaeck.frame_lines());
+//! blaeck.render_selection(&select)?;
+//! loop {
+//!     match select.handle_key(&read_key()?) {
+//!         SelectAction::Copied(text) => { blaeck.copy_to_clipboard(&text)?; break; }
+//!         SelectAction::Exited => break,
+//!         _ => blaeck.render_selection(&select)?,
+//!     }
+//! }
+//! blaeck.set_frozen(false);
+//! ```
+//!
+//! Keys: arrows or `hjkl` move, `0`/`$` jump to line start/end, `g`/`G` to
+//! the first/last line, `v` starts a character selection, `V` a line
+//! selection, `y` or Enter copies (the current line if nothing is selected),
+//! Esc or `q` leaves.
+
+use crate::components::KeyHint;
+use crate::input::Key;
+use crossterm::event::KeyCode;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Result of handling a key with [`SelectMode::handle_key`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectAction {
+    /// Not a select-mode key.
+    Ignored,
+    /// The cursor or selection changed; redraw the highlight.
+    Moved,
+    /// Text was chosen for copying. Select mode should end.
+    Copied(String),
+    /// The user left select mode without copying.
+    Exited,
+}
+
+/// Cursor and selection over a snapshot of rendered lines.
+///
+/// Positions are `(column, row)` in terminal cells, so wide characters
+/// span two columns.
+#[derive(Debug, Clone)]
+pub struct SelectMode {
+    /// Plain text of the frozen frame, one entry per row.
+    lines: Vec<String>,
+    /// Cursor position.
+    cursor: (usize, usize),
+    /// Where the selection started, if one is active.
+    anchor: Option<(usize, usize)>,
+    /// Whether whole lines are selected.
+    linewise: bool,
+}
+
+impl SelectMode {
+    /// Start select mode over `lines`, with the cursor at the start of the
+    /// last non-empty line (usually the most recent output).
+    pub fn new<I, S>(lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let lines: Vec<String> = lines.into_iter().map(Into::into).collect();
+        let row = lines
+            .iter()
+            .rposition(|l| !l.trim().is_empty())
+            .unwrap_or(0);
+        Self {
+            lines,
+            cursor: (0, row),
+            anchor: None,
+            linewise: false,
+        }
+    }
+
+    /// The frozen lines.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Cursor position as `(column, row)`.
+    pub fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    /// Whether a selection is active.
+    pub fn is_selecting(&self) -> bool {
+        self.anchor.is_some()
+    }
+
+    /// Display width of a row.
+    fn row_width(&self, row: usize) -> usize {
+        self.lines.get(row).map_or(0, |l| l.width())
+    }
+
+    /// Columns where each grapheme of a row starts, with its text.
+    fn cells(&self, row: usize) -> impl Iterator<Item = (usize, &str)> {
+        let mut col = 0;
+        self.lines
+            .get(row)
+            .map(String::as_str)
+            .unwrap_or("")
+            .graphemes(true)
+            .map(move |g| {
+                let start = col;
+                col += g.width();
+                (start, g)
+            })
+    }
+
+    /// Snaps a column to the start of the grapheme under it.
+    fn snap(&self, col: usize, row: usize) -> usize {
+        let max = self.row_width(row).saturating_sub(1);
+        let col = col.min(max);
+        self.cells(row)
+            .take_while(|(start, _)| *start <= col)
+            .last()
+            .map_or(0, |(start, _)| start)
+    }
+
+    /// Move the cursor one grapheme left.
+    pub fn move_left(&mut self) {
+        let (col, row) = self.cursor;
+        let prev = self
+            .cells(row)
+            .take_while(|(start, _)| *start < col)
+            .last()
+            .map_or(0, |(start, _)| start);
+        self.cursor.0 = prev;
+    }
+
+    /// Move the cursor one grapheme right.
+    pub fn move_right(&mut self) {
+        let (col, row) = self.cursor;
+        let next = self.cells(row).find(|(start, _)| *start > col);
+        if let Some((start, _)) = next {
+            self.cursor.0 = start;
+        }
+    }
+
+    /// Move the cursor up one row.
+    pub fn move_up(&mut self) {
+        let row = self.cursor.1.saturating_sub(1);
+        self.cursor = (self.snap(self.cursor.0, row), row);
+    }
+
+    /// Move the cursor down one row.
+    pub fn move_down(&mut self) {
+        let row = (self.cursor.1 + 1).min(self.lines.len().saturating_sub(1));
+        self.cursor = (self.snap(self.cursor.0, row), row);
+    }
+
+    /// Start or cancel a selection at the cursor.
+    ///
+    /// `linewise` selects whole lines. Toggling to the other kind while a
+    /// selection is active switches its kind instead of cancelling it.
+    pub fn toggle_selection(&mut self, linewise: bool) {
+        if self.anchor.is_some() && self.linewise == linewise {
+            self.anchor = None;
+        } else {
+            self.anchor.get_or_insert(self.cursor);
+            self.linewise = linewise;
+        }
+    }
+
+    /// Selection bounds in reading order, as `(start, end)` inclusive.
+    fn bounds(&self) -> Option<((usize, usize), (usize, usize))> {
+        let anchor = self.anchor?;
+        let (a, b) = if (anchor.1, anchor.0) <= (self.cursor.1, self.cursor.0) {
+            (anchor, self.cursor)
+        } else {
+            (self.cursor, anchor)
+        };
+        if self.linewise {
+            Some(((0, a.1), (usize::MAX, b.1)))
+        } else {
+            Some((a, b))
+        }
+    }
+
+    /// Whether the cell at `(col, row)` is selected.
+    ///
+    /// Without a selection, the cell under the cursor counts as selected so
+    /// the cursor is visible.
+    pub fn is_selected(&self, col: usize, row: usize) -> bool {
+        let Some((start, end)) = self.bounds() else {
+            let (c, r) = self.cursor;
+            let width = self
+                .cells(r)
+                .find(|(s, _)| *s == c)
+                .map_or(1, |(_, g)| g.width().max(1));
+            return row == r && col >= c && col < c + width;
+        };
+        if row < start.1 || row > end.1 {
+            return false;
+        }
+        let from = if row == start.1 { start.0 } else { 0 };
+        let to = if row == end.1 { end.0 } else { usize::MAX };
+        // Include the whole of a wide character under the end column
+        let to = self
+            .cells(row)
+            .find(|(s, _)| *s == to)
+            .map_or(to, |(s, g)| s + g.width().max(1) - 1);
+        col >= from && col <= to
+    }
+
+    /// The selected text, or the cursor's line if nothing is selected.
+    ///
+    /// Lines are joined with `\n` and trailing spaces trimmed.
+    pub fn selected_text(&self) -> String {
+        let Some((start, end)) = self.bounds() else {
+            return self
+                .lines
+                .get(self.cursor.1)
+                .map(|l| l.trim_end().to_string())
+                .unwrap_or_default();
+        };
+        (start.1..=end.1.min(self.lines.len().saturating_sub(1)))
+            .map(|row| {
+                let from = if row == start.1 { start.0 } else { 0 };
+                let to = if row == end.1 { end.0 } else { usize::MAX };
+                let text: String = self
+                    .cells(row)
+                    .filter(|(s, _)| *s >= from && *s <= to)
+                    .map(|(_, g)| g)
+                    .collect();
+                text.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Handle a key press. See the [module docs](self) for the bindings.
+    pub fn handle_key(&mut self, key: &Key) -> SelectAction {
+        match key.code {
+            KeyCode::Left | KeyCode::Char('h') => self.move_left(),
+            KeyCode::Right | KeyCode::Char('l') => self.move_right(),
+            KeyCode::Up | KeyCode::Char('k') => self.move_up(),
+            KeyCode::Down | KeyCode::Char('j') => self.move_down(),
+            KeyCode::Home | KeyCode::Char('0') => self.cursor.0 = 0,
+            KeyCode::End | KeyCode::Char('$') => {
+                let row = self.cursor.1;
+                self.cursor.0 = self.snap(usize::MAX, row);
+            }
+            KeyCode::Char('g') => self.cursor = (0, 0),
+            KeyCode::Char('G') => {
+                let row = self.lines.len().saturating_sub(1);
+                self.cursor = (0, row);
+            }
+            KeyCode::Char('v') => self.toggle_selection(false),
+            KeyCode::Char('V') => self.toggle_selection(true),
+            KeyCode::Char('y') | KeyCode::Enter => {
+                return SelectAction::Copied(self.selected_text())
+            }
+            KeyCode::Esc | KeyCode::Char('q') => return SelectAction::Exited,
+            _ => return SelectAction::Ignored,
+        }
+        SelectAction::Moved
+    }
+
+    /// Key hints for a status line while select mode is on.
+    pub fn key_hints(&self) -> Vec<KeyHint> {
+        vec![
+            KeyHint::new("←↑↓→", "move"),
+            KeyHint::new("v/V", "select"),
+            KeyHint::new("y", "copy"),
+            KeyHint::new("Esc", "cancel"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(mode: &mut SelectMode, code: KeyCode) -> SelectAction {
+        mode.handle_key(&Key::new(code))
+    }
+
+    #[test]
+    fn test_select_mode_copies_current_line() {
+        let mut mode = SelectMode::new(["status: ok   ", "error: refused   ", "   "]);
+        assert_eq!(mode.cursor(), (0, 1));
+        assert_eq!(
+            press(&mut mode, KeyCode::Char('y')),
+            SelectAction::Copied("error: refused".into())
+        );
+    }
+
+    #[test]
+    fn test_select_mode_charwise_and_linewise() {
+        let mut mode = SelectMode::new(["abc def", "日本 xyz"]);
+        press(&mut mode, KeyCode::Char('g'));
+        for _ in 0..4 {
+            press(&mut mode, KeyCode::Right);
+        }
+        press(&mut mode, KeyCode::Char('v'));
+        press(&mut mode, KeyCode::Down);
+        // 日本 fills columns 0-3, so column 4 is the space after it
+        assert_eq!(mode.cursor(), (4, 1));
+        assert_eq!(mode.selected_text(), "def\n日本");
+        assert!(mode.is_selected(0, 1));
+        assert!(!mode.is_selected(5, 1));
+
+        press(&mut mode, KeyCode::Char('V'));
+        assert_eq!(mode.selected_text(), "abc def\n日本 xyz");
+        assert_eq!(press(&mut mode, KeyCode::Esc), SelectAction::Exited);
+    }
+}
@@ -0,0 +1,272 @@
+//! Session persistence for UI state.
+//!
+//! A [`SessionStore`] remembers small pieces of UI state between launches —
+//! scroll positions, the selected tab, which tree nodes were expanded — keyed
+//! by stable string ids chosen by the app:
+//!
+//! ```ignore
+//! use blaeck::session::SessionStore;
+//!
+//! let mut session = SessionStore::open(SessionStore::default_path("myapp").unwrap(), 1)?;
+//! let mut tabs: TabsState = session.get("main.tabs").unwrap_or_else(|| TabsState::new(3));
+//! // ... run the app ...
+//! session.set("main.tabs", &tabs);
+//! session.save()?;
+//! ```
+//!
+//! With [`ReactiveApp`](crate::reactive::ReactiveApp), attach the store with
+//! [`ReactiveApp::with_session`](crate::reactive::ReactiveApp::with_session) and use
+//! [`use_session_state`](crate::reactive::use_session_state) instead of
+//! `use_state`; values are restored on first render and saved on exit.
+//!
+//! # Format
+//!
+//! The file is JSON with a format version (owned by blaeck) and an app
+//! version (owned by you):
+//!
+//! ```text
+//! {"format": 1, "version": 3, "entries": {"main.tabs": {"selected": 2, "tab_count": 3}}}
+//! ```
+//!
+//! Bump the app version whenever stored shapes change; a file written with a
+//! different version is discarded on load instead of failing to deserialize.
+//!
+//! Requires the `session` feature.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Version of the on-disk envelope. Changed only by blaeck.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// On-disk representation.
+#[derive(Debug, Serialize, Deserialize)]
+struct SessionFile {
+    format: u32,
+    version: u32,
+    entries: BTreeMap<String, Value>,
+}
+
+/// Persistent key-value store for UI state.
+#[derive(Debug, Clone, Default)]
+pub struct SessionStore {
+    /// Where [`save`](Self::save) writes. `None` for in-memory stores.
+    path: Option<PathBuf>,
+    /// App-defined schema version.
+    version: u32,
+    /// Stored values, serialized.
+    entries: BTreeMap<String, Value>,
+}
+
+impl SessionStore {
+    /// Create an empty in-memory store.
+    ///
+    /// [`save`](Self::save) is a no-op until a path is set with [`with_path`](Self::with_path).
+    pub fn new(version: u32) -> Self {
+        Self {
+            path: None,
+            version,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Load a store from `path`, or start empty.
+    ///
+    /// A missing file, an unreadable file, or a file written with a different
+    /// format or app version all yield an empty store bound to `path`.
+    /// Only I/O errors other than "not found" are returned.
+    pub fn open(path: impl Into<PathBuf>, version: u32) -> io::Result<Self> {
+        let path = path.into();
+        let store = match fs::read_to_string(&path) {
+            Ok(json) => Self::from_json(&json, version),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Self::new(version),
+            Err(e) => return Err(e),
+        };
+        Ok(store.with_path(path))
+    }
+
+    /// Parse a store from JSON, discarding it if the versions don't match.
+    pub fn from_json(json: &str, version: u32) -> Self {
+        match serde_json::from_str::<SessionFile>(json) {
+            Ok(file) if file.format == FORMAT_VERSION && file.version == version => Self {
+                path: None,
+                version,
+                entries: file.entries,
+            },
+            _ => Self::new(version),
+        }
+    }
+
+    /// Serialize the store to JSON.
+    pub fn to_json(&self) -> String {
+        let file = SessionFile {
+            format: FORMAT_VERSION,
+            version: self.version,
+            entries: self.entries.clone(),
+        };
+        serde_json::to_string_pretty(&file).unwrap_or_default()
+    }
+
+    /// Set the file the store is saved to.
+    #[must_use]
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// The conventional location for an app's session file.
+    ///
+    /// Uses `$XDG_STATE_HOME/<app>/session.json`, falling back to
+    /// `~/.local/state/<app>/session.json`. Returns `None` if neither
+    /// variable is set.
+    pub fn default_path(app_name: &str) -> Option<PathBuf> {
+        let base = std::env::var_os("XDG_STATE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|h| Path::new(&h).join(".local/state")))?;
+        Some(base.join(app_name).join("session.json"))
+    }
+
+    /// The file this store saves to, if any.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// The app schema version.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Get a stored value.
+    ///
+    /// Returns `None` if the key is missing or the stored value doesn't
+    /// deserialize as `T`.
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        self.entries
+            .get(key)
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+    }
+
+    /// Store a value, replacing any previous value for the key.
+    ///
+    /// Values that fail to serialize are ignored.
+    pub fn set<T: Serialize + ?Sized>(&mut self, key: impl Into<String>, value: &T) {
+        if let Ok(value) = serde_json::to_value(value) {
+            self.entries.insert(key.into(), value);
+        }
+    }
+
+    /// Store an already-serialized value.
+    pub fn set_value(&mut self, key: impl Into<String>, value: Value) {
+        self.entries.insert(key.into(), value);
+    }
+
+    /// Remove a value. Returns true if it was present.
+    pub fn remove(&mut self, key: &str) -> bool {
+        self.entries.remove(key).is_some()
+    }
+
+    /// Check whether a key is stored.
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Iterate over stored keys in sorted order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// Number of stored values.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if nothing is stored.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove all stored values.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Write the store to its path, creating parent directories.
+    ///
+    /// The file is written to a temporary sibling and renamed into place so
+    /// a crash mid-write never leaves a truncated session behind.
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, self.to_json())?;
+        fs::rename(tmp, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{TabsState, TreeState};
+
+    #[test]
+    fn test_session_get_set() {
+        let mut store = SessionStore::new(1);
+        store.set("scroll", &42usize);
+        assert_eq!(store.get::<usize>("scroll"), Some(42));
+        assert_eq!(store.get::<String>("scroll"), None);
+        assert_eq!(store.get::<usize>("missing"), None);
+        assert!(store.remove("scroll"));
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn test_session_json_roundtrip() {
+        let mut store = SessionStore::new(3);
+        let tree = TreeState::new().expand("src");
+        store.set("tree", &tree);
+        store.set("tabs", &TabsState::new(4));
+
+        let restored = SessionStore::from_json(&store.to_json(), 3);
+        assert_eq!(restored.len(), 2);
+        let tree: TreeState = restored.get("tree").unwrap();
+        assert!(tree.is_expanded("src"));
+        assert_eq!(restored.get::<TabsState>("tabs").unwrap().tab_count, 4);
+    }
+
+    #[test]
+    fn test_session_version_mismatch_discards() {
+        let mut store = SessionStore::new(1);
+        store.set("a", &1);
+        assert!(SessionStore::from_json(&store.to_json(), 2).is_empty());
+        assert!(SessionStore::from_json("not json", 1).is_empty());
+    }
+
+    #[test]
+    fn test_session_save_and_open() {
+        let dir = std::env::temp_dir().join(format!("blaeck-session-{}", std::process::id()));
+        let path = dir.join("nested/session.json");
+
+        let mut store = SessionStore::open(&path, 1).unwrap();
+        assert!(store.is_empty());
+        store.set("selected", &"item-7");
+        store.save().unwrap();
+
+        let reopened = SessionStore::open(&path, 1).unwrap();
+        assert_eq!(
+            reopened.get::<String>("selected").as_deref(),
+            Some("item-7")
+        );
+        assert_eq!(reopened.path(), Some(path.as_path()));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
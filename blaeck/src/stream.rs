@@ -0,0 +1,167 @@
+//! Streaming output for very tall static content.
+//!
+//! [`crate::print`] lays out the whole element tree and paints it into one
+//! grid before writing anything, so a report with thousands of rows holds
+//! thousands of rows of cells in memory. [`print_streamed`] splits the tree
+//! into rows instead — the children of column boxes, the lines of text and
+//! tables — and lays out, paints and flushes one row at a time.
+//!
+//! ```ignore
+//! use blaeck::prelude::*;
+//!
+//! let rows = records.iter().map(|r| Element::text(r.to_string())).collect();
+//! blaeck::print_streamed(Element::column(rows))?;
+//! ```
+//!
+//! Each row is laid out on its own at the full width, so layout that depends
+//! on siblings (flex grow, shared column widths across separate boxes) isn't
+//! available across rows. Column boxes with a border or padding can't be
+//! split and are rendered whole; their contents still stream when they are
+//! nested inside a splittable box.
+
+use crate::components::{Box, BoxProps, Table};
+use crate::element::Element;
+use crate::layout::FlexDirection;
+use crate::renderer::{Blaeck, Result};
+use std::any::TypeId;
+use std::io::{self, Write};
+
+/// Print an element to stdout row by row.
+///
+/// Renders at the terminal width (80 columns when stdout isn't a terminal).
+/// See the [module docs](self) for how the tree is split.
+pub fn print_streamed(element: Element) -> Result<()> {
+    let width = crossterm::terminal::size().map_or(80, |(w, _)| w);
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    render_streamed(&mut out, &element, width)
+}
+
+/// Render an element into `writer` row by row at the given width.
+///
+/// Lines end with `\n`; the writer is flushed after each row.
+pub fn render_streamed<W: Write>(writer: &mut W, element: &Element, width: u16) -> Result<()> {
+    let mut engine = Blaeck::with_size(io::sink(), width, u16::MAX)?;
+    stream_element(&mut engine, writer, element)
+}
+
+/// Render one element, splitting it into rows where possible.
+fn stream_element<W: Write>(
+    engine: &mut Blaeck<io::Sink>,
+    writer: &mut W,
+    element: &Element,
+) -> Result<()> {
+    match element {
+        Element::Empty => Ok(()),
+        Element::Text { content, style } if content.contains('\n') => {
+            for line in content.split('\n') {
+                write_row(engine, writer, &Element::styled_text(line, *style))?;
+            }
+            Ok(())
+        }
+        Element::Node {
+            type_id,
+            props,
+            children,
+            ..
+        } if *type_id == TypeId::of::<Box>() => match props.downcast_ref::<BoxProps>() {
+            Some(props) if is_splittable(props) => {
+                let gap = props.gap.max(0.0).round() as usize;
+                for (i, child) in children.iter().enumerate() {
+                    if i > 0 && gap > 0 {
+                        writer.write_all("\n".repeat(gap).as_bytes())?;
+                    }
+                    stream_element(engine, writer, child)?;
+                }
+                Ok(())
+            }
+            _ => write_row(engine, writer, element),
+        },
+        // Tables render to one multi-line text; stream its lines
+        Element::Node { type_id, .. } if *type_id == TypeId::of::<Table>() => {
+            match element.render_component() {
+                Some(rendered @ Element::Text { .. }) => stream_element(engine, writer, &rendered),
+                _ => write_row(engine, writer, element),
+            }
+        }
+        _ => write_row(engine, writer, element),
+    }
+}
+
+/// Whether a box's children can be rendered one at a time.
+///
+/// Only a plain, visible column qualifies: anything drawn around or between
+/// the children, or sizing that depends on them, needs the whole box.
+fn is_splittable(props: &BoxProps) -> bool {
+    props.visible
+        && props.flex_direction == FlexDirection::Column
+        && !props.border_style.has_border()
+        && props.background_color.is_none()
+        && props.padding == 0.0
+        && [
+            props.padding_top,
+            props.padding_bottom,
+            props.padding_left,
+            props.padding_right,
+        ]
+        .iter()
+        .all(|p| p.unwrap_or(0.0) == 0.0)
+        && props.height.is_none()
+        && props.min_height.is_none()
+        && props.max_height.is_none()
+}
+
+/// Lay out, paint and flush a single row.
+fn write_row<W: Write>(
+    engine: &mut Blaeck<io::Sink>,
+    writer: &mut W,
+    element: &Element,
+) -> Result<()> {
+    let rendered = engine.render_element(element)?;
+    for line in rendered.split("\r\n") {
+        writer.write_all(line.as_bytes())?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{with_border, BorderStyle, TableProps};
+    use crate::pager::render_to_string;
+
+    fn streamed(element: &Element, width: u16) -> String {
+        let mut out = Vec::new();
+        render_streamed(&mut out, element, width).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_streamed_matches_full_render() {
+        let element = Element::column(vec![
+            Element::text("header"),
+            Element::text("a\nb"),
+            Element::node::<Table>(
+                TableProps::new(vec![vec!["1", "one"], vec!["2", "two"]]),
+                vec![],
+            ),
+            Element::column(vec![Element::text("nested")]),
+        ]);
+        let full = render_to_string(&element, 20).unwrap();
+        assert_eq!(streamed(&element, 20), format!("{full}\n"));
+    }
+
+    #[test]
+    fn test_streamed_gap_and_bordered_box() {
+        let element = Element::node::<Box>(
+            BoxProps::column().with_gap(1.0),
+            vec![
+                Element::text("x"),
+                with_border(Element::text("y"), BorderStyle::Single),
+            ],
+        );
+        assert_eq!(streamed(&element, 10), "x\n\n┌─┐\n│y│\n└─┘\n");
+    }
+}
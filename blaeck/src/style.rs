@@ -22,10 +22,7 @@ pub fn supports_truecolor() -> bool {
             }
         }
         if let Ok(prog) = std::env::var("TERM_PROGRAM") {
-            return matches!(
-                prog.as_str(),
-                "iTerm.app" | "WezTerm" | "Hyper" | "vscode"
-            );
+            return matches!(prog.as_str(), "iTerm.app" | "WezTerm" | "Hyper" | "vscode");
         }
         false
     })
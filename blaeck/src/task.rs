@@ -0,0 +1,253 @@
+//! Progress reporting and cancellation for background tasks.
+//!
+//! A [`TaskHandle`] is shared between a worker thread and the UI. The worker
+//! reports progress and a status message and checks whether it was
+//! cancelled; the UI reads the same handle to draw a progress bar and flips
+//! the cancel flag when the user presses the cancel key.
+//!
+//! ```ignore
+//! let (task, worker) = TaskHandle::spawn(|task| {
+//!     for (i, file) in files.iter().enumerate() {
+//!         if task.is_cancelled() {
+//!             return Err("cancelled");
+//!         }
+//!         task.report(i as f32 / files.len() as f32, format!("copying {file}"));
+//!         copy(file);
+//!     }
+//!     task.finish();
+//!     Ok(())
+//! });
+//!
+//! // In the input handler:
+//! task.handle_key(&key); // Esc cancels
+//!
+//! // In render:
+//! Element::node::<Progress>(ProgressProps::from_task(&task), vec![])
+//! ```
+//!
+//! In a [`ReactiveApp`](crate::reactive::ReactiveApp), create the handle with
+//! [`use_task`](crate::reactive::use_task) so the UI re-renders whenever the
+//! task reports.
+
+use crate::components::KeyHint;
+use crate::input::Key;
+use crossterm::event::KeyCode;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+/// Where a task is in its life.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskStatus {
+    /// Still working.
+    Running,
+    /// Cancellation was requested; the task hasn't finished yet.
+    Cancelling,
+    /// Cancelled and stopped.
+    Cancelled,
+    /// Completed.
+    Finished,
+}
+
+/// State shared between the task and the UI.
+#[derive(Debug, Default)]
+struct Shared {
+    /// Progress as `f32` bits, 0.0 to 1.0.
+    progress: AtomicU32,
+    /// Latest status message.
+    message: Mutex<String>,
+    /// Set when the user asks the task to stop.
+    cancelled: AtomicBool,
+    /// Set when the task is done, whether it completed or stopped early.
+    finished: AtomicBool,
+    /// Bumped on every change, so the UI can tell when to redraw.
+    version: AtomicU64,
+}
+
+/// Cloneable, thread-safe handle to a background task's progress and
+/// cancel flag. See the [module docs](self).
+#[derive(Debug, Clone, Default)]
+pub struct TaskHandle {
+    shared: Arc<Shared>,
+}
+
+impl TaskHandle {
+    /// Create a handle for a task that hasn't started reporting yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `f` on a new thread with a handle to this task.
+    ///
+    /// Returns the UI's handle and the thread's join handle. The handle is
+    /// marked finished when `f` returns, even if it didn't call
+    /// [`finish`](Self::finish).
+    pub fn spawn<F, T>(f: F) -> (Self, JoinHandle<T>)
+    where
+        F: FnOnce(TaskHandle) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let handle = Self::new();
+        let worker = handle.clone();
+        let join = std::thread::spawn(move || {
+            let result = f(worker.clone());
+            worker.mark_finished();
+            result
+        });
+        (handle, join)
+    }
+
+    fn changed(&self) {
+        self.shared.version.fetch_add(1, Ordering::Release);
+    }
+
+    /// Set progress, from 0.0 to 1.0.
+    pub fn set_progress(&self, progress: f32) {
+        let progress = progress.clamp(0.0, 1.0);
+        self.shared
+            .progress
+            .store(progress.to_bits(), Ordering::Relaxed);
+        self.changed();
+    }
+
+    /// Current progress, from 0.0 to 1.0.
+    pub fn progress(&self) -> f32 {
+        f32::from_bits(self.shared.progress.load(Ordering::Relaxed))
+    }
+
+    /// Set the status message.
+    pub fn set_message(&self, message: impl Into<String>) {
+        *self
+            .shared
+            .message
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = message.into();
+        self.changed();
+    }
+
+    /// Current status message.
+    pub fn message(&self) -> String {
+        self.shared
+            .message
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Set progress and message together.
+    pub fn report(&self, progress: f32, message: impl Into<String>) {
+        self.set_message(message);
+        self.set_progress(progress);
+    }
+
+    /// Ask the task to stop. The task sees it through
+    /// [`is_cancelled`](Self::is_cancelled).
+    pub fn cancel(&self) {
+        if !self.shared.cancelled.swap(true, Ordering::AcqRel) {
+            self.changed();
+        }
+    }
+
+    /// Whether cancellation was requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Mark the task complete, setting progress to 1.0 unless it was cancelled.
+    pub fn finish(&self) {
+        if !self.is_cancelled() {
+            self.shared
+                .progress
+                .store(1.0f32.to_bits(), Ordering::Relaxed);
+        }
+        self.mark_finished();
+    }
+
+    fn mark_finished(&self) {
+        if !self.shared.finished.swap(true, Ordering::AcqRel) {
+            self.changed();
+        }
+    }
+
+    /// Whether the task is done, either completed or stopped after a cancel.
+    pub fn is_finished(&self) -> bool {
+        self.shared.finished.load(Ordering::Acquire)
+    }
+
+    /// Current status.
+    pub fn status(&self) -> TaskStatus {
+        match (self.is_cancelled(), self.is_finished()) {
+            (false, false) => TaskStatus::Running,
+            (true, false) => TaskStatus::Cancelling,
+            (true, true) => TaskStatus::Cancelled,
+            (false, true) => TaskStatus::Finished,
+        }
+    }
+
+    /// Change counter, bumped on every report, cancel and finish.
+    ///
+    /// Compare against a previous value to decide whether to redraw.
+    pub fn version(&self) -> u64 {
+        self.shared.version.load(Ordering::Acquire)
+    }
+
+    /// Cancel on Esc while the task is running.
+    ///
+    /// Returns true if the key cancelled the task.
+    pub fn handle_key(&self, key: &Key) -> bool {
+        if key.code == KeyCode::Esc && self.status() == TaskStatus::Running {
+            self.cancel();
+            return true;
+        }
+        false
+    }
+
+    /// Key hints for a status line while the task can be cancelled.
+    pub fn key_hints(&self) -> Vec<KeyHint> {
+        if self.status() == TaskStatus::Running {
+            vec![KeyHint::new("Esc", "cancel")]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_handle_reports_and_cancels() {
+        let (task, worker) = TaskHandle::spawn(|task| {
+            task.report(0.5, "halfway");
+            while !task.is_cancelled() {
+                std::thread::yield_now();
+            }
+            "stopped"
+        });
+        while task.progress() < 0.5 {
+            std::thread::yield_now();
+        }
+        assert_eq!(task.message(), "halfway");
+        assert_eq!(task.status(), TaskStatus::Running);
+        let version = task.version();
+
+        assert!(task.handle_key(&Key::new(KeyCode::Esc)));
+        assert!(task.version() > version);
+        assert_eq!(worker.join().unwrap(), "stopped");
+        assert_eq!(task.status(), TaskStatus::Cancelled);
+        assert!(!task.handle_key(&Key::new(KeyCode::Esc)));
+        assert!(task.key_hints().is_empty());
+    }
+
+    #[test]
+    fn test_task_handle_finish() {
+        let task = TaskHandle::new();
+        task.set_progress(2.0);
+        assert_eq!(task.progress(), 1.0);
+        task.set_progress(0.3);
+        task.finish();
+        assert_eq!(task.progress(), 1.0);
+        assert_eq!(task.status(), TaskStatus::Finished);
+    }
+}
@@ -0,0 +1,172 @@
+//! Elm-style model/update/view programs.
+//!
+//! Some apps read best as a single model changed only by messages. Implement
+//! [`Program`] on the model: [`key`](Program::key) turns key presses into
+//! messages, [`update`](Program::update) applies them and
+//! [`view`](Program::view) draws the model. [`run`] drives it with the
+//! imperative [`App`], so hotkeys, Ctrl+C and resize handling work as usual.
+//!
+//! ```ignore
+//! use blaeck::prelude::*;
+//! use blaeck::tea::{self, Command, Program};
+//!
+//! struct Counter(i32);
+//!
+//! enum Msg { Up, Down, Quit }
+//!
+//! impl Program for Counter {
+//!     type Msg = Msg;
+//!
+//!     fn update(&mut self, msg: Msg) -> Command {
+//!         match msg {
+//!             Msg::Up => self.0 += 1,
+//!             Msg::Down => self.0 -= 1,
+//!             Msg::Quit => return Command::Quit,
+//!         }
+//!         Command::None
+//!     }
+//!
+//!     fn view(&self) -> Element {
+//!         Element::text(format!("Count: {}", self.0))
+//!     }
+//!
+//!     fn key(&self, key: &Key) -> Option<Msg> {
+//!         match key.code {
+//!             KeyCode::Up => Some(Msg::Up),
+//!             KeyCode::Down => Some(Msg::Down),
+//!             KeyCode::Char('q') => Some(Msg::Quit),
+//!             _ => None,
+//!         }
+//!     }
+//! }
+//!
+//! let counter = tea::run(Counter(0))?;
+//! println!("final count: {}", counter.0);
+//! ```
+
+use crate::app::App;
+use crate::element::Element;
+use crate::error::Result;
+use crate::input::Key;
+use std::cell::RefCell;
+use std::io::Write;
+
+/// What the runtime should do after an update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Command {
+    /// Keep running.
+    #[default]
+    None,
+    /// Exit the app.
+    Quit,
+}
+
+/// A model with its messages, update and view.
+pub trait Program {
+    /// Messages that change the model.
+    type Msg;
+
+    /// Apply `msg` to the model.
+    fn update(&mut self, msg: Self::Msg) -> Command;
+
+    /// Draw the model.
+    fn view(&self) -> Element;
+
+    /// The message for a key press, if any. Unmapped keys are ignored.
+    fn key(&self, key: &Key) -> Option<Self::Msg>;
+}
+
+/// Map `key` to a message and apply it.
+///
+/// Returns [`Command::None`] for unmapped keys.
+pub fn handle_key<P: Program>(program: &mut P, key: &Key) -> Command {
+    match program.key(key) {
+        Some(msg) => program.update(msg),
+        None => Command::None,
+    }
+}
+
+/// Run `program` on stdout until it quits or Ctrl+C is pressed, returning
+/// the final model.
+pub fn run<P: Program>(program: P) -> Result<P> {
+    run_with(App::new()?, program)
+}
+
+/// Run `program` with a configured app, e.g. one with hotkeys registered.
+pub fn run_with<P: Program, W: Write>(app: App<W>, program: P) -> Result<P> {
+    let program = RefCell::new(program);
+    app.run(
+        |_app| program.borrow().view(),
+        |app, key| {
+            if handle_key(&mut *program.borrow_mut(), &key) == Command::Quit {
+                app.exit();
+            }
+        },
+    )?;
+    Ok(program.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyCode;
+
+    struct Counter(i32);
+
+    enum Msg {
+        Add(i32),
+        Quit,
+    }
+
+    impl Program for Counter {
+        type Msg = Msg;
+
+        fn update(&mut self, msg: Msg) -> Command {
+            match msg {
+                Msg::Add(n) => self.0 += n,
+                Msg::Quit => return Command::Quit,
+            }
+            Command::None
+        }
+
+        fn view(&self) -> Element {
+            Element::text(format!("Count: {}", self.0))
+        }
+
+        fn key(&self, key: &Key) -> Option<Msg> {
+            match key.code {
+                KeyCode::Up => Some(Msg::Add(1)),
+                KeyCode::Down => Some(Msg::Add(-1)),
+                KeyCode::Char('q') => Some(Msg::Quit),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_handle_key_updates_model() {
+        let mut counter = Counter(0);
+        assert_eq!(
+            handle_key(&mut counter, &Key::new(KeyCode::Up)),
+            Command::None
+        );
+        handle_key(&mut counter, &Key::new(KeyCode::Up));
+        handle_key(&mut counter, &Key::new(KeyCode::Down));
+        handle_key(&mut counter, &Key::new(KeyCode::Left));
+        assert_eq!(counter.0, 1);
+        assert_eq!(
+            handle_key(&mut counter, &Key::new(KeyCode::Char('q'))),
+            Command::Quit
+        );
+    }
+
+    #[test]
+    fn test_view_renders_model() {
+        let mut buf = Vec::new();
+        {
+            let mut blaeck = crate::Blaeck::with_size(&mut buf, 80, 24).unwrap();
+            blaeck.render(Counter(3).view()).unwrap();
+        }
+        assert!(String::from_utf8_lossy(&buf).contains("Count: 3"));
+    }
+}
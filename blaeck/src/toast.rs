@@ -0,0 +1,431 @@
+//! Toast notifications that stack in a corner and dismiss themselves.
+//!
+//! A [`ToastManager`] is a cloneable, thread-safe queue of short messages.
+//! Anything holding a clone (an input handler, a worker thread) can push a
+//! toast; the UI draws the stack with [`ToastManager::overlay`]:
+//!
+//! ```ignore
+//! let toasts = ToastManager::new().ttl(Duration::from_secs(3));
+//! toasts.success("Saved");
+//! toasts.error("Upload failed");
+//!
+//! // In render, over the rest of the UI:
+//! let bounds = Rect::new(0, 0, width, height);
+//! element! {
+//!     Box(width: width as f32, height: height as f32) {
+//!         #(main_ui)
+//!         #(toasts.overlay(ToastCorner::TopRight, bounds, Instant::now()))
+//!     }
+//! }
+//! ```
+//!
+//! Each toast fades in, stays for its time to live, then fades out and is
+//! removed by [`ToastManager::tick`]. In a
+//! [`ReactiveApp`](crate::reactive::ReactiveApp), create the manager with
+//! [`use_toasts`](crate::reactive::use_toasts) and the app ticks it and
+//! re-renders while toasts are animating or expire.
+
+use crate::components::icons;
+use crate::components::{BorderStyle, Box, BoxProps};
+use crate::element::Element;
+use crate::overlay::{float, Placement, Rect, Side};
+use crate::palette::Palette;
+use crate::style::{Color, Modifier, Style};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// What a toast is about; sets its color and icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastKind {
+    /// Neutral information.
+    #[default]
+    Info,
+    /// Something completed.
+    Success,
+    /// Something needs attention.
+    Warning,
+    /// Something failed.
+    Error,
+}
+
+impl ToastKind {
+    /// The color for this kind from `palette`.
+    pub fn color(self, palette: &Palette) -> Color {
+        match self {
+            ToastKind::Info => palette.info,
+            ToastKind::Success => palette.success,
+            ToastKind::Warning => palette.warning,
+            ToastKind::Error => palette.error,
+        }
+    }
+
+    /// The icon drawn before the message.
+    pub fn icon(self) -> &'static str {
+        match self {
+            ToastKind::Info => icons::INFO,
+            ToastKind::Success => icons::CHECK,
+            ToastKind::Warning => icons::WARNING,
+            ToastKind::Error => icons::CROSS,
+        }
+    }
+}
+
+/// Corner of the screen the toast stack sits in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastCorner {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Identifies a pushed toast, for [`ToastManager::dismiss`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ToastId(u64);
+
+/// A single notification.
+#[derive(Debug, Clone)]
+pub struct Toast {
+    /// Identifier returned when the toast was pushed.
+    pub id: ToastId,
+    /// Kind of message.
+    pub kind: ToastKind,
+    /// The message text.
+    pub message: String,
+    /// When the toast was pushed.
+    pub created: Instant,
+    /// When the toast starts fading out.
+    pub expires: Instant,
+}
+
+impl Toast {
+    /// How visible the toast is at `now`, from 0.0 (hidden) to 1.0, given
+    /// the length of the fade in and out.
+    pub fn visibility(&self, now: Instant, fade: Duration) -> f64 {
+        let fraction = |elapsed: Duration| {
+            if fade.is_zero() {
+                1.0
+            } else {
+                (elapsed.as_secs_f64() / fade.as_secs_f64()).min(1.0)
+            }
+        };
+        if now >= self.expires {
+            1.0 - fraction(now - self.expires)
+        } else {
+            fraction(now.saturating_duration_since(self.created))
+        }
+    }
+}
+
+/// State shared between clones of a manager.
+#[derive(Debug)]
+struct Shared {
+    toasts: Vec<Toast>,
+    next_id: u64,
+    /// Bumped on every push and dismiss, so the UI can tell when to redraw.
+    version: u64,
+    ttl: Duration,
+    fade: Duration,
+    width: u16,
+    max_visible: usize,
+}
+
+/// Cloneable, thread-safe stack of toasts. See the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct ToastManager {
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl Default for ToastManager {
+    fn default() -> Self {
+        Self {
+            shared: Arc::new(Mutex::new(Shared {
+                toasts: Vec::new(),
+                next_id: 0,
+                version: 0,
+                ttl: Duration::from_secs(4),
+                fade: Duration::from_millis(150),
+                width: 36,
+                max_visible: 4,
+            })),
+        }
+    }
+}
+
+impl ToastManager {
+    /// Create an empty manager.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Shared> {
+        // A panic while holding the lock can't leave the list half-updated
+        self.shared.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Set how long toasts stay before fading out (default: 4s).
+    #[must_use]
+    pub fn ttl(self, ttl: Duration) -> Self {
+        self.lock().ttl = ttl;
+        self
+    }
+
+    /// Set how long the fade in and out takes (default: 150ms).
+    #[must_use]
+    pub fn fade(self, fade: Duration) -> Self {
+        self.lock().fade = fade;
+        self
+    }
+
+    /// Set the width of each toast in columns (default: 36).
+    #[must_use]
+    pub fn width(self, width: u16) -> Self {
+        self.lock().width = width;
+        self
+    }
+
+    /// Set how many toasts are shown at once (default: 4). Older ones wait
+    /// off screen.
+    #[must_use]
+    pub fn max_visible(self, max: usize) -> Self {
+        self.lock().max_visible = max.max(1);
+        self
+    }
+
+    /// Push a toast with the default time to live.
+    pub fn push(&self, kind: ToastKind, message: impl Into<String>) -> ToastId {
+        let ttl = self.lock().ttl;
+        self.push_with_ttl(kind, message, ttl)
+    }
+
+    /// Push a toast that stays for `ttl`.
+    pub fn push_with_ttl(
+        &self,
+        kind: ToastKind,
+        message: impl Into<String>,
+        ttl: Duration,
+    ) -> ToastId {
+        let mut shared = self.lock();
+        let id = ToastId(shared.next_id);
+        shared.next_id += 1;
+        shared.version += 1;
+        let created = Instant::now();
+        shared.toasts.push(Toast {
+            id,
+            kind,
+            message: message.into(),
+            created,
+            expires: created + ttl,
+        });
+        id
+    }
+
+    /// Push an info toast.
+    pub fn info(&self, message: impl Into<String>) -> ToastId {
+        self.push(ToastKind::Info, message)
+    }
+
+    /// Push a success toast.
+    pub fn success(&self, message: impl Into<String>) -> ToastId {
+        self.push(ToastKind::Success, message)
+    }
+
+    /// Push a warning toast.
+    pub fn warning(&self, message: impl Into<String>) -> ToastId {
+        self.push(ToastKind::Warning, message)
+    }
+
+    /// Push an error toast.
+    pub fn error(&self, message: impl Into<String>) -> ToastId {
+        self.push(ToastKind::Error, message)
+    }
+
+    /// Start fading out a toast now.
+    pub fn dismiss(&self, id: ToastId) {
+        let mut shared = self.lock();
+        let now = Instant::now();
+        if let Some(toast) = shared.toasts.iter_mut().find(|t| t.id == id) {
+            toast.expires = toast.expires.min(now);
+        }
+        shared.version += 1;
+    }
+
+    /// Remove every toast without fading.
+    pub fn clear(&self) {
+        let mut shared = self.lock();
+        shared.toasts.clear();
+        shared.version += 1;
+    }
+
+    /// The toasts not yet removed, oldest first.
+    pub fn toasts(&self) -> Vec<Toast> {
+        self.lock().toasts.clone()
+    }
+
+    /// Number of toasts not yet removed.
+    pub fn len(&self) -> usize {
+        self.lock().toasts.len()
+    }
+
+    /// Whether there are no toasts.
+    pub fn is_empty(&self) -> bool {
+        self.lock().toasts.is_empty()
+    }
+
+    /// Counter bumped on every push and dismiss.
+    pub fn version(&self) -> u64 {
+        self.lock().version
+    }
+
+    /// Remove toasts that finished fading out by `now`.
+    ///
+    /// Returns true if the stack looks different than it did, i.e. a toast
+    /// was removed or one is fading in or out.
+    pub fn tick(&self, now: Instant) -> bool {
+        let mut shared = self.lock();
+        let fade = shared.fade;
+        let before = shared.toasts.len();
+        shared.toasts.retain(|t| now < t.expires + fade);
+        let max_visible = shared.max_visible;
+        let start = shared.toasts.len().saturating_sub(max_visible);
+        shared.toasts.len() != before
+            || shared.toasts[start..]
+                .iter()
+                .any(|t| t.visibility(now, fade) < 1.0)
+    }
+
+    /// The stack as a column of bordered toasts, as it looks at `now`.
+    pub fn render(&self, now: Instant) -> Element {
+        let shared = self.lock();
+        let palette = Palette::detect();
+        let start = shared.toasts.len().saturating_sub(shared.max_visible);
+        let children = shared.toasts[start..]
+            .iter()
+            .map(|toast| {
+                let color = toast.kind.color(&palette);
+                // Drawn dim while fading in or out
+                let fading = toast.visibility(now, shared.fade) < 1.0;
+                let mut style = Style::new().fg(color);
+                if fading {
+                    style = style.add_modifier(Modifier::DIM);
+                }
+                let props = BoxProps {
+                    width: Some(f32::from(shared.width)),
+                    padding_left: Some(1.0),
+                    padding_right: Some(1.0),
+                    border_style: BorderStyle::Round,
+                    border_color: Some(color),
+                    border_dim: fading,
+                    ..Default::default()
+                };
+                let text = format!("{} {}", toast.kind.icon(), toast.message);
+                Element::node::<Box>(props, vec![Element::styled_text(text, style)])
+            })
+            .collect();
+        Element::node::<Box>(BoxProps::column(), children)
+    }
+
+    /// The stack floated into `corner` of `bounds`. See
+    /// [`Placement::apply`] for where the parent must start.
+    pub fn overlay(&self, corner: ToastCorner, bounds: Rect, now: Instant) -> Element {
+        let (width, shown) = {
+            let shared = self.lock();
+            (shared.width, shared.toasts.len().min(shared.max_visible))
+        };
+        if shown == 0 {
+            return Element::Empty;
+        }
+        // Each toast is one line of text inside a border
+        let width = width.min(bounds.width);
+        let height = (shown as u16).saturating_mul(3).min(bounds.height);
+        let x = match corner {
+            ToastCorner::TopLeft | ToastCorner::BottomLeft => bounds.x,
+            ToastCorner::TopRight | ToastCorner::BottomRight => bounds.x + bounds.width - width,
+        };
+        let y = match corner {
+            ToastCorner::TopLeft | ToastCorner::TopRight => bounds.y,
+            ToastCorner::BottomLeft | ToastCorner::BottomRight => bounds.y + bounds.height - height,
+        };
+        let placement = Placement {
+            rect: Rect::new(x, y, width, height),
+            side: Side::Bottom,
+            clipped: height < shown as u16 * 3,
+        };
+        float(placement, self.render(now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toast_visibility_fades_in_and_out() {
+        let created = Instant::now();
+        let toast = Toast {
+            id: ToastId(0),
+            kind: ToastKind::Info,
+            message: "hi".into(),
+            created,
+            expires: created + Duration::from_secs(1),
+        };
+        let fade = Duration::from_millis(100);
+        assert_eq!(toast.visibility(created, fade), 0.0);
+        assert!((toast.visibility(created + Duration::from_millis(50), fade) - 0.5).abs() < 1e-9);
+        assert_eq!(
+            toast.visibility(created + Duration::from_millis(500), fade),
+            1.0
+        );
+        assert!((toast.visibility(created + Duration::from_millis(1050), fade) - 0.5).abs() < 1e-9);
+        assert_eq!(toast.visibility(created, Duration::ZERO), 1.0);
+    }
+
+    #[test]
+    fn test_toast_manager_tick_removes_expired() {
+        let toasts = ToastManager::new()
+            .ttl(Duration::from_millis(100))
+            .fade(Duration::from_millis(10));
+        let first = toasts.success("saved");
+        toasts.push_with_ttl(ToastKind::Error, "failed", Duration::from_secs(60));
+        assert_eq!(toasts.len(), 2);
+        let version = toasts.version();
+
+        let later = Instant::now() + Duration::from_millis(200);
+        assert!(toasts.tick(later));
+        assert_eq!(toasts.toasts()[0].message, "failed");
+        assert!(!toasts.tick(later));
+
+        toasts.dismiss(first);
+        assert!(toasts.version() > version);
+        toasts.clear();
+        assert!(toasts.is_empty());
+    }
+
+    #[test]
+    fn test_toast_overlay_in_corner() {
+        let toasts = ToastManager::new().width(20).max_visible(2);
+        let bounds = Rect::new(0, 0, 80, 24);
+        assert!(toasts
+            .overlay(ToastCorner::TopRight, bounds, Instant::now())
+            .is_empty());
+
+        for i in 0..3 {
+            toasts.info(format!("toast {i}"));
+        }
+        let Element::Node {
+            props, children, ..
+        } = toasts.overlay(ToastCorner::BottomRight, bounds, Instant::now())
+        else {
+            panic!("Expected Node");
+        };
+        let props = props.downcast_ref::<BoxProps>().unwrap();
+        assert_eq!(props.inset_left, Some(60.0));
+        assert_eq!(props.inset_top, Some(18.0));
+        // Only the newest two are drawn
+        let Element::Node { children, .. } = &children[0] else {
+            panic!("Expected Node");
+        };
+        assert_eq!(children.len(), 2);
+    }
+}
@@ -0,0 +1,198 @@
+//! How text that doesn't fit is cut: the ellipsis and where it goes.
+//!
+//! Box titles, Text with a truncating [`TextWrap`], Table fit columns, Tabs
+//! labels, Breadcrumbs and the Truncate hint all take their ellipsis from
+//! [`Truncation::detect`], so it can be changed for the whole app in one
+//! place:
+//!
+//! ```ignore
+//! // At startup, before anything renders
+//! Truncation::install(Truncation::new("~"));
+//!
+//! // Or from the environment: BLAECK_ELLIPSIS=">"
+//! ```
+//!
+//! The default is `…`, or `...` when only ASCII can be shown (see
+//! [`crate::glyphs`]).
+//!
+//! [`TextWrap`]: crate::components::TextWrap
+
+use crate::glyphs::GlyphSet;
+use std::borrow::Cow;
+use std::sync::OnceLock;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// The app's truncation, installed or from `BLAECK_ELLIPSIS`.
+static TRUNCATION: OnceLock<Option<Truncation>> = OnceLock::new();
+
+/// Which part of the text is cut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TruncateAt {
+    /// Keep the start: `"long na…"`.
+    #[default]
+    End,
+    /// Keep the end: `"…ng name"`.
+    Start,
+    /// Keep both ends: `"long…ame"`.
+    Middle,
+}
+
+/// The ellipsis and where text is cut.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Truncation {
+    /// Marks where text was cut.
+    pub ellipsis: String,
+    /// Which part of the text is cut.
+    pub at: TruncateAt,
+}
+
+impl Default for Truncation {
+    fn default() -> Self {
+        Self::unicode()
+    }
+}
+
+impl Truncation {
+    /// Cut at the end with `ellipsis`.
+    pub fn new(ellipsis: impl Into<String>) -> Self {
+        Self {
+            ellipsis: ellipsis.into(),
+            at: TruncateAt::End,
+        }
+    }
+
+    /// `…`, the default.
+    pub fn unicode() -> Self {
+        Self::new("…")
+    }
+
+    /// `...`, for ASCII-only terminals.
+    pub fn ascii() -> Self {
+        Self::new("...")
+    }
+
+    /// Set which part of the text is cut.
+    #[must_use]
+    pub fn at(mut self, at: TruncateAt) -> Self {
+        self.at = at;
+        self
+    }
+
+    /// Use `truncation` for the whole app, instead of the default or
+    /// `BLAECK_ELLIPSIS`. Must be called before anything calls
+    /// [`detect`](Self::detect); returns false (and changes nothing) after.
+    pub fn install(truncation: Truncation) -> bool {
+        TRUNCATION.set(Some(truncation)).is_ok()
+    }
+
+    /// The app's truncation for the detected glyph set.
+    pub fn detect() -> Self {
+        Self::resolve(None)
+    }
+
+    /// The app's truncation under a component's glyph override: the
+    /// installed one, or `BLAECK_ELLIPSIS`, or the default. A non-ASCII
+    /// ellipsis becomes `...` when only ASCII can be shown.
+    pub fn resolve(glyphs: Option<GlyphSet>) -> Self {
+        let installed = TRUNCATION.get_or_init(|| {
+            std::env::var("BLAECK_ELLIPSIS")
+                .ok()
+                .filter(|e| !e.is_empty())
+                .map(Self::new)
+        });
+        let ascii = GlyphSet::resolve(glyphs).is_ascii();
+        match installed {
+            Some(t) if !ascii || t.ellipsis.is_ascii() => t.clone(),
+            Some(t) => Self::ascii().at(t.at),
+            None if ascii => Self::ascii(),
+            None => Self::unicode(),
+        }
+    }
+
+    /// Cut `s` to at most `max` display columns, marking the cut with the
+    /// ellipsis. Text that fits is returned as is; when even the ellipsis
+    /// doesn't fit, the text is cut without one.
+    pub fn truncate<'a>(&self, s: &'a str, max: usize) -> Cow<'a, str> {
+        if s.width() <= max {
+            return s.into();
+        }
+        let ellipsis_width = self.ellipsis.width();
+        if ellipsis_width > max {
+            return take_width(s.graphemes(true), max).into();
+        }
+        let room = max - ellipsis_width;
+        match self.at {
+            TruncateAt::End => format!("{}{}", take_width(s.graphemes(true), room), self.ellipsis),
+            TruncateAt::Start => {
+                format!("{}{}", self.ellipsis, take_width_rev(s, room))
+            }
+            TruncateAt::Middle => format!(
+                "{}{}{}",
+                take_width(s.graphemes(true), room.div_ceil(2)),
+                self.ellipsis,
+                take_width_rev(s, room / 2)
+            ),
+        }
+        .into()
+    }
+}
+
+/// The leading graphemes that fit in `max` columns.
+fn take_width<'a>(graphemes: impl Iterator<Item = &'a str>, max: usize) -> String {
+    let mut out = String::new();
+    let mut width = 0;
+    for g in graphemes {
+        if width + g.width() > max {
+            break;
+        }
+        width += g.width();
+        out.push_str(g);
+    }
+    out
+}
+
+/// The trailing graphemes of `s` that fit in `max` columns.
+fn take_width_rev(s: &str, max: usize) -> String {
+    let tail: Vec<&str> = {
+        let mut width = 0;
+        s.graphemes(true)
+            .rev()
+            .take_while(|g| {
+                width += g.width();
+                width <= max
+            })
+            .collect()
+    };
+    tail.into_iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_positions() {
+        let t = Truncation::unicode();
+        assert_eq!(t.truncate("short", 10), "short");
+        assert_eq!(t.truncate("long name", 6), "long …");
+        assert_eq!(
+            t.clone().at(TruncateAt::Start).truncate("long name", 6),
+            "… name"
+        );
+        assert_eq!(t.at(TruncateAt::Middle).truncate("long name", 6), "lon…me");
+    }
+
+    #[test]
+    fn test_truncate_wide_and_tiny() {
+        let t = Truncation::ascii();
+        assert_eq!(t.truncate("日本語テキスト", 7), "日本...");
+        assert_eq!(t.truncate("abcdef", 2), "ab");
+        assert_eq!(Truncation::new("").truncate("abcdef", 3), "abc");
+    }
+
+    #[test]
+    fn test_resolve_ascii_ellipsis() {
+        assert_eq!(Truncation::resolve(Some(GlyphSet::Ascii)).ellipsis, "...");
+    }
+}